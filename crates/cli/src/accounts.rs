@@ -26,6 +26,7 @@ pub struct FieldData {
 #[template(path = "accounts_struct.askama", escape = "none", ext = ".askama")]
 pub struct AccountsStructTemplate<'a> {
     pub account: &'a AccountData,
+    pub lenient: bool,
 }
 
 #[derive(Template)]
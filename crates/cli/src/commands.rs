@@ -20,6 +20,15 @@ pub enum Commands {
     #[command(name = "scaffold")]
     #[command(about = "Generate skeleton of the project.")]
     Scaffold(ScaffoldOptions),
+    #[command(name = "detect")]
+    #[command(about = "List the Carbon decoders matching the programs of a transaction.")]
+    Detect(DetectOptions),
+    #[command(name = "bench")]
+    #[command(about = "Measure the decoding throughput of a decoder over captured fixtures.")]
+    Bench(BenchOptions),
+    #[command(name = "top")]
+    #[command(about = "Print live leaderboards of the busiest programs, accounts and fee payers.")]
+    Top(TopOptions),
 }
 
 #[derive(Parser)]
@@ -44,6 +53,10 @@ pub struct ParseOptions {
     #[arg(help = "Comma-separated names of defined types to parse as CPI Events.")]
     pub event_hints: Option<String>,
 
+    #[arg(short = 'l', long = "lenient-accounts", default_value_t = false)]
+    #[arg(help = "Keep trailing account bytes in a `remaining_data` field instead of failing.")]
+    pub lenient_accounts: bool,
+
     #[arg(short, long, required_if_eq("idl", "ProgramAddress"))]
     #[arg(help = "Network URL to fetch the IDL from. Required if input is a program address.")]
     pub url: Option<Url>,
@@ -72,6 +85,55 @@ pub struct ScaffoldOptions {
     pub metrics: String,
 }
 
+#[derive(Parser)]
+pub struct DetectOptions {
+    #[arg(short, long, required = true)]
+    #[arg(help = "Signature of the transaction.")]
+    pub signature: String,
+
+    #[arg(short, long, default_value = "mainnet-beta")]
+    #[arg(help = "Network URL to fetch the transaction from.")]
+    pub url: Url,
+}
+
+#[derive(Parser)]
+pub struct BenchOptions {
+    #[arg(short, long, required = true)]
+    #[arg(help = "Name of the decoder to benchmark.")]
+    pub decoder: Decoder,
+
+    #[arg(short, long, required = true)]
+    #[arg(help = "Path to a directory of `*_ix.json` and `*_account.json` fixtures.")]
+    pub fixtures: String,
+
+    #[arg(short, long, default_value_t = 10_000)]
+    #[arg(help = "Number of times every fixture is decoded.")]
+    pub iterations: usize,
+
+    #[arg(short, long)]
+    #[arg(help = "Number of threads of the multi-threaded run. Defaults to the number of CPUs.")]
+    pub threads: Option<usize>,
+}
+
+#[derive(Parser)]
+pub struct TopOptions {
+    #[arg(short, long, required = true)]
+    #[arg(help = "Yellowstone gRPC endpoint to subscribe to.")]
+    pub endpoint: String,
+
+    #[arg(short = 't', long)]
+    #[arg(help = "X-Token of the Yellowstone gRPC endpoint.")]
+    pub x_token: Option<String>,
+
+    #[arg(short, long, default_value_t = 5)]
+    #[arg(help = "Number of seconds between two leaderboards.")]
+    pub interval: u64,
+
+    #[arg(short = 'n', long, default_value_t = 10)]
+    #[arg(help = "Number of entries of each leaderboard.")]
+    pub limit: usize,
+}
+
 #[derive(Clone, Debug)]
 pub enum IdlSource {
     FilePath(String),
@@ -0,0 +1,43 @@
+use {
+    crate::{idl::Idl, legacy_idl::LegacyIdl},
+    askama::Template,
+    heck::ToUpperCamelCase,
+};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ErrorData {
+    pub variant_name: String,
+    pub code: u32,
+    pub message: String,
+}
+
+#[derive(Template)]
+#[template(path = "errors_mod.askama", escape = "none", ext = ".askama")]
+pub struct ErrorsModTemplate<'a> {
+    pub errors: &'a Vec<ErrorData>,
+    pub program_error_enum: String,
+}
+
+pub fn legacy_process_errors(idl: &LegacyIdl) -> Vec<ErrorData> {
+    idl.errors
+        .iter()
+        .map(|error| error_data(&error.name, error.code as u32, error.msg.as_deref()))
+        .collect()
+}
+
+pub fn process_errors(idl: &Idl) -> Vec<ErrorData> {
+    idl.errors
+        .iter()
+        .map(|error| error_data(&error.name, error.code, error.msg.as_deref()))
+        .collect()
+}
+
+fn error_data(name: &str, code: u32, msg: Option<&str>) -> ErrorData {
+    ErrorData {
+        variant_name: name.to_upper_camel_case(),
+        code,
+        // Escaped as a Rust string literal, quotes included.
+        message: format!("{:?}", msg.unwrap_or(name)),
+    }
+}
@@ -0,0 +1,241 @@
+use {crate::commands::Decoder, anyhow::Result};
+
+#[cfg(not(feature = "bench"))]
+pub fn bench(
+    _decoder: Decoder,
+    _fixtures: String,
+    _iterations: usize,
+    _threads: Option<usize>,
+) -> Result<()> {
+    anyhow::bail!("The bench command requires carbon-cli to be built with the `bench` feature")
+}
+
+#[cfg(feature = "bench")]
+pub fn bench(
+    decoder: Decoder,
+    fixtures: String,
+    iterations: usize,
+    threads: Option<usize>,
+) -> Result<()> {
+    use anyhow::Context;
+
+    let threads = match threads {
+        Some(threads) => threads.max(1),
+        None => std::thread::available_parallelism()
+            .map(|threads| threads.get())
+            .unwrap_or(1),
+    };
+    let iterations = iterations.max(1);
+
+    let instructions = carbon_test_utils::read_instructions(&fixtures)
+        .context("Couldn't read instruction fixtures")?;
+    let accounts =
+        carbon_test_utils::read_accounts(&fixtures).context("Couldn't read account fixtures")?;
+    if instructions.is_empty() && accounts.is_empty() {
+        anyhow::bail!(
+            "No `*_ix.json` or `*_account.json` fixture found in {}",
+            fixtures
+        );
+    }
+
+    let (decode_instruction, decode_account) = fixture_decoders::decoders(decoder);
+
+    println!(
+        "Benchmarking {} over {} instruction and {} account fixtures, {} iterations, {} threads",
+        decoder,
+        instructions.len(),
+        accounts.len(),
+        iterations,
+        threads
+    );
+
+    report(
+        "instructions",
+        &instructions,
+        &*decode_instruction,
+        iterations,
+        threads,
+    );
+    match decode_account {
+        Some(decode_account) => {
+            report("accounts", &accounts, &*decode_account, iterations, threads)
+        }
+        None if !accounts.is_empty() => {
+            println!("  accounts: skipped, {} doesn't decode accounts", decoder)
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+/// Measures the throughput of `decode` over `fixtures` on one thread, then on
+/// `threads` threads, and prints it.
+#[cfg(feature = "bench")]
+fn report<T: Sync>(
+    kind: &str,
+    fixtures: &[(String, T)],
+    decode: &(dyn Fn(&T) -> bool + Sync),
+    iterations: usize,
+    threads: usize,
+) {
+    if fixtures.is_empty() {
+        return;
+    }
+
+    let undecoded: Vec<&str> = fixtures
+        .iter()
+        .filter(|(_, fixture)| !decode(fixture))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !undecoded.is_empty() {
+        println!(
+            "  {}: {} fixtures don't decode: {}",
+            kind,
+            undecoded.len(),
+            undecoded.join(", ")
+        );
+    }
+
+    let single_threaded = measure(fixtures, decode, iterations, 1);
+    let multi_threaded = measure(fixtures, decode, iterations, threads);
+
+    println!(
+        "  {:<12} {:>14.0} decodes/s single-threaded, {:>14.0} decodes/s on {} threads ({:.1}x)",
+        format!("{}:", kind),
+        single_threaded,
+        multi_threaded,
+        threads,
+        multi_threaded / single_threaded
+    );
+}
+
+/// Decodes every fixture `iterations` times, split across `threads` threads,
+/// and returns the number of decodes per second.
+#[cfg(feature = "bench")]
+fn measure<T: Sync>(
+    fixtures: &[(String, T)],
+    decode: &(dyn Fn(&T) -> bool + Sync),
+    iterations: usize,
+    threads: usize,
+) -> f64 {
+    let threads = threads.min(iterations);
+    let start = std::time::Instant::now();
+
+    std::thread::scope(|scope| {
+        for thread in 0..threads {
+            let thread_iterations =
+                iterations / threads + usize::from(thread < iterations % threads);
+            scope.spawn(move || {
+                for _ in 0..thread_iterations {
+                    for (_, fixture) in fixtures {
+                        std::hint::black_box(decode(std::hint::black_box(fixture)));
+                    }
+                }
+            });
+        }
+    });
+
+    (iterations * fixtures.len()) as f64 / start.elapsed().as_secs_f64()
+}
+
+#[cfg(feature = "bench")]
+mod fixture_decoders {
+    use {
+        crate::commands::Decoder,
+        carbon_core::{account::AccountDecoder, instruction::InstructionDecoder},
+        solana_sdk::{account::Account, instruction::Instruction},
+    };
+
+    pub type Decode<T> = Box<dyn Fn(&T) -> bool + Sync>;
+
+    macro_rules! decoders {
+        ($decoder:expr) => {
+            (
+                Box::new(|instruction: &Instruction| {
+                    $decoder.decode_instruction(instruction).is_some()
+                }) as Decode<Instruction>,
+                Some(
+                    Box::new(|account: &Account| $decoder.decode_account(account).is_some())
+                        as Decode<Account>,
+                ),
+            )
+        };
+        ($decoder:expr, instructions) => {
+            (
+                Box::new(|instruction: &Instruction| {
+                    $decoder.decode_instruction(instruction).is_some()
+                }) as Decode<Instruction>,
+                None,
+            )
+        };
+    }
+
+    /// Returns closures decoding instructions and, if the decoder decodes
+    /// them, accounts with `decoder`.
+    pub fn decoders(decoder: Decoder) -> (Decode<Instruction>, Option<Decode<Account>>) {
+        match decoder {
+            Decoder::Drift => decoders!(carbon_drift_v2_decoder::DriftDecoder),
+            Decoder::Fluxbeam => decoders!(carbon_fluxbeam_decoder::FluxbeamDecoder),
+            Decoder::JupiterDCA => decoders!(carbon_jupiter_dca_decoder::JupiterDcaDecoder),
+            Decoder::JupiterLimitOrder => {
+                decoders!(carbon_jupiter_limit_order_decoder::JupiterLimitOrderDecoder)
+            }
+            Decoder::JupiterLimitOrder2 => {
+                decoders!(carbon_jupiter_limit_order_2_decoder::JupiterLimitOrder2Decoder)
+            }
+            Decoder::JupiterPerpetuals => {
+                decoders!(carbon_jupiter_perpetuals_decoder::PerpetualsDecoder)
+            }
+            Decoder::JupiterSwap => decoders!(carbon_jupiter_swap_decoder::JupiterSwapDecoder),
+            Decoder::KaminoLending => {
+                decoders!(carbon_kamino_lending_decoder::KaminoLendingDecoder)
+            }
+            Decoder::KaminoVault => decoders!(carbon_kamino_vault_decoder::KaminoVaultDecoder),
+            Decoder::LifinityAMM => decoders!(carbon_lifinity_amm_v2_decoder::LifinityAmmV2Decoder),
+            Decoder::MemoProgram => {
+                decoders!(
+                    carbon_memo_program_decoder::MemoProgramDecoder,
+                    instructions
+                )
+            }
+            Decoder::MeteoraDLMM => decoders!(carbon_meteora_dlmm_decoder::MeteoraDlmmDecoder),
+            Decoder::Moonshot => decoders!(carbon_moonshot_decoder::MoonshotDecoder),
+            Decoder::MPLCore => decoders!(carbon_mpl_core_decoder::MplCoreProgramDecoder),
+            Decoder::MPLTokenMetadata => {
+                decoders!(carbon_mpl_token_metadata_decoder::TokenMetadataDecoder)
+            }
+            Decoder::NameService => decoders!(carbon_name_service_decoder::NameDecoder),
+            Decoder::OKXDEX => decoders!(carbon_okx_dex_decoder::OkxDexDecoder),
+            Decoder::Openbook => decoders!(carbon_openbook_v2_decoder::OpenbookV2Decoder),
+            Decoder::OrcaWhirlpool => {
+                decoders!(carbon_orca_whirlpool_decoder::OrcaWhirlpoolDecoder)
+            }
+            Decoder::Phoenix => decoders!(carbon_phoenix_v1_decoder::PhoenixDecoder),
+            Decoder::Pumpfun => decoders!(carbon_pumpfun_decoder::PumpfunDecoder),
+            Decoder::RaydiumAMM => decoders!(carbon_raydium_amm_v4_decoder::RaydiumAmmV4Decoder),
+            Decoder::RaydiumCLMM => decoders!(carbon_raydium_clmm_decoder::RaydiumClmmDecoder),
+            Decoder::RaydiumCPMM => decoders!(carbon_raydium_cpmm_decoder::RaydiumCpmmDecoder),
+            Decoder::RaydiumLiquidityLocking => {
+                decoders!(carbon_raydium_liquidity_locking_decoder::RaydiumLiquidityLockingDecoder)
+            }
+            Decoder::Sharky => decoders!(carbon_sharky_decoder::SharkyDecoder),
+            Decoder::SPLAssociatedTokenAccount => {
+                decoders!(carbon_associated_token_account_decoder::SplAssociatedTokenAccountDecoder)
+            }
+            Decoder::StabbleStableSwap => {
+                decoders!(carbon_stabble_stable_swap_decoder::StableSwapDecoder)
+            }
+            Decoder::StabbleWeightedSwap => {
+                decoders!(carbon_stabble_weighted_swap_decoder::WeightedSwapDecoder)
+            }
+            Decoder::StakeProgram => decoders!(carbon_stake_program_decoder::StakeProgramDecoder),
+            Decoder::SystemProgram => {
+                decoders!(carbon_system_program_decoder::SystemProgramDecoder)
+            }
+            Decoder::TokenProgram => decoders!(carbon_token_program_decoder::TokenProgramDecoder),
+            Decoder::Token2022Program => decoders!(carbon_token_2022_decoder::Token2022Decoder),
+            Decoder::Zeta => decoders!(carbon_zeta_decoder::ZetaDecoder),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use {
         },
         instructions::{InstructionsModTemplate, InstructionsStructTemplate},
         types::TypeStructTemplate,
-        util::is_big_array,
+        util::{is_big_array, program_id_const},
     },
     anyhow::{bail, Result},
     askama::Template,
@@ -22,9 +22,10 @@ pub fn parse_codama(
     path: String,
     output: String,
     as_crate: bool,
+    lenient_accounts: bool,
     event_hints: Option<String>,
 ) -> Result<()> {
-    let (accounts_data, instructions_data, types_data, events_data, program_name) =
+    let (accounts_data, instructions_data, types_data, events_data, program_name, program_id) =
         match read_codama_idl(&path) {
             Ok(idl) => {
                 let accounts_data = process_codama_accounts(&idl.program);
@@ -34,6 +35,7 @@ pub fn parse_codama(
                 let (types_data, events_data) =
                     process_codama_defined_types(&idl.program, &event_hints);
                 let program_name = idl.program.name;
+                let program_id = idl.program.public_key;
 
                 (
                     accounts_data,
@@ -41,6 +43,7 @@ pub fn parse_codama(
                     types_data,
                     events_data,
                     program_name,
+                    program_id,
                 )
             }
             Err(error) => {
@@ -117,7 +120,10 @@ pub fn parse_codama(
     fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
 
     for account in &accounts_data {
-        let template = AccountsStructTemplate { account };
+        let template = AccountsStructTemplate {
+            account,
+            lenient: lenient_accounts,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
         fs::write(&filename, rendered).expect("Failed to write account struct file");
@@ -162,6 +168,8 @@ pub fn parse_codama(
         decoder_name: decoder_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
         events: &events_data,
+        feature_gated: as_crate,
+        has_program_id: program_id.is_some(),
     };
     let instructions_mod_rendered = instructions_mod_template.render().unwrap();
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
@@ -173,8 +181,9 @@ pub fn parse_codama(
 
     if as_crate {
         let lib_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+            "pub struct {decoder_name};\n#[cfg(feature = \"accounts\")]\npub mod accounts;\n#[cfg(feature = \"instructions\")]\npub mod instructions;\npub mod types;\n{program_id_const}",
+            decoder_name = decoder_name,
+            program_id_const = program_id_const(program_id.as_deref()),
         );
         let lib_rs_filename = format!("{}/lib.rs", src_dir);
         fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
@@ -189,6 +198,11 @@ edition = {{ workspace = true }}
 [lib]
 crate-type = ["rlib"]
 
+[features]
+default = ["accounts", "instructions"{default_events}]
+accounts = []
+instructions = []
+{events_feature}
 [dependencies]
 carbon-core = {{ workspace = true }}
 carbon-proc-macros = {{ workspace = true }}
@@ -199,6 +213,16 @@ serde = {{ workspace = true }}
 {big_array}
 "#,
             decoder_name_kebab = decoder_name_kebab,
+            default_events = if events_data.is_empty() {
+                ""
+            } else {
+                ", \"events\""
+            },
+            events_feature = if events_data.is_empty() {
+                ""
+            } else {
+                "events = [\"instructions\"]\n"
+            },
             big_array = if needs_big_array {
                 "serde-big-array = { workspace = true }"
             } else {
@@ -211,8 +235,9 @@ serde = {{ workspace = true }}
         println!("Generated {}", cargo_toml_filename);
     } else {
         let mod_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;\n{program_id_const}",
+            decoder_name = decoder_name,
+            program_id_const = program_id_const(program_id.as_deref()),
         );
         let mod_rs_filename = format!("{}/mod.rs", src_dir);
         fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
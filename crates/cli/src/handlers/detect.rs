@@ -0,0 +1,54 @@
+use {
+    crate::commands::Url,
+    anyhow::{Context, Result},
+    carbon_core::{registry, transformers::transaction_update_from_confirmed_transaction},
+    solana_client::{rpc_client::RpcClient, rpc_config::RpcTransactionConfig},
+    solana_sdk::{commitment_config::CommitmentConfig, signature::Signature},
+    solana_transaction_status::UiTransactionEncoding,
+    std::str::FromStr,
+};
+
+pub fn detect(signature: String, url: &Url) -> Result<()> {
+    let rpc_url = match url {
+        Url::Mainnet => "https://api.mainnet-beta.solana.com",
+        Url::Devnet => "https://api.devnet.solana.com",
+        Url::CustomRpc(custom_url) => custom_url,
+    };
+
+    let signature =
+        Signature::from_str(&signature).context("Couldn't parse transaction signature")?;
+
+    println!("Fetching transaction: {} from {}", signature, rpc_url);
+
+    let client = RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::confirmed());
+    let fetched_transaction = client
+        .get_transaction_with_config(
+            &signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .context("Couldn't fetch transaction")?;
+
+    let transaction_update =
+        transaction_update_from_confirmed_transaction(signature, fetched_transaction)
+            .context("Couldn't read transaction")?;
+
+    let decoders = registry::detect(&transaction_update);
+    if decoders.is_empty() {
+        println!("No registered decoder matches this transaction.");
+        return Ok(());
+    }
+
+    println!("Matching decoders:");
+    for decoder in decoders {
+        println!(
+            "  {:<44} {:<28} {}",
+            decoder.program_id, decoder.program_name, decoder.crate_name
+        );
+    }
+
+    Ok(())
+}
@@ -1,3 +1,9 @@
+mod bench;
+pub use bench::*;
+
+mod detect;
+pub use detect::*;
+
 mod parse;
 pub use parse::*;
 
@@ -6,3 +12,6 @@ pub use codama::*;
 
 mod process_pda_idl;
 pub use process_pda_idl::*;
+
+mod top;
+pub use top::*;
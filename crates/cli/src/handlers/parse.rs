@@ -3,6 +3,7 @@ use {
         accounts::{
             legacy_process_accounts, process_accounts, AccountsModTemplate, AccountsStructTemplate,
         },
+        errors::{legacy_process_errors, process_errors, ErrorsModTemplate},
         events::{legacy_process_events, process_events, EventsStructTemplate},
         instructions::{
             legacy_process_instructions, process_instructions, InstructionsModTemplate,
@@ -10,7 +11,7 @@ use {
         },
         project::{DataSourceData, DecoderData, MetricsData, ProjectTemplate},
         types::{legacy_process_types, process_types, TypeStructTemplate},
-        util::{is_big_array, legacy_read_idl, read_idl},
+        util::{is_big_array, legacy_read_idl, program_id_const, read_idl},
     },
     anyhow::{bail, Result},
     askama::Template,
@@ -21,50 +22,66 @@ use {
     },
 };
 
-pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
-    let (accounts_data, instructions_data, types_data, events_data, program_name) =
-        match read_idl(&path) {
+pub fn parse(path: String, output: String, as_crate: bool, lenient_accounts: bool) -> Result<()> {
+    let (
+        accounts_data,
+        instructions_data,
+        types_data,
+        events_data,
+        errors_data,
+        program_name,
+        program_id,
+    ) = match read_idl(&path) {
+        Ok(idl) => {
+            let accounts_data = process_accounts(&idl);
+            let instructions_data = process_instructions(&idl);
+            let types_data = process_types(&idl);
+            let events_data = process_events(&idl);
+            let errors_data = process_errors(&idl);
+            let program_name = idl.metadata.name;
+            let program_id = Some(idl.address);
+
+            (
+                accounts_data,
+                instructions_data,
+                types_data,
+                events_data,
+                errors_data,
+                program_name,
+                program_id,
+            )
+        }
+        Err(_legacy_idl_err) => match legacy_read_idl(&path) {
             Ok(idl) => {
-                let accounts_data = process_accounts(&idl);
-                let instructions_data = process_instructions(&idl);
-                let types_data = process_types(&idl);
-                let events_data = process_events(&idl);
-                let program_name = idl.metadata.name;
+                let accounts_data = legacy_process_accounts(&idl);
+                let instructions_data = legacy_process_instructions(&idl);
+                let types_data = legacy_process_types(&idl);
+                let events_data = legacy_process_events(&idl);
+                let errors_data = legacy_process_errors(&idl);
+                let program_name = idl.name;
+                let program_id = idl.metadata.and_then(|metadata| metadata.address);
 
                 (
                     accounts_data,
                     instructions_data,
                     types_data,
                     events_data,
+                    errors_data,
                     program_name,
+                    program_id,
                 )
             }
-            Err(_legacy_idl_err) => match legacy_read_idl(&path) {
-                Ok(idl) => {
-                    let accounts_data = legacy_process_accounts(&idl);
-                    let instructions_data = legacy_process_instructions(&idl);
-                    let types_data = legacy_process_types(&idl);
-                    let events_data = legacy_process_events(&idl);
-                    let program_name = idl.name;
-
-                    (
-                        accounts_data,
-                        instructions_data,
-                        types_data,
-                        events_data,
-                        program_name,
-                    )
-                }
-                Err(idl_err) => {
-                    bail!("{idl_err}");
-                }
-            },
-        };
+            Err(idl_err) => {
+                bail!("{idl_err}");
+            }
+        },
+    };
 
     let decoder_name = format!("{}Decoder", program_name.to_upper_camel_case());
     let decoder_name_kebab = program_name.to_kebab_case();
     let program_struct_name = format!("{}Account", program_name.to_upper_camel_case());
     let program_instruction_enum = format!("{}Instruction", program_name.to_upper_camel_case());
+    let program_error_enum = format!("{}Error", program_name.to_upper_camel_case());
 
     let crate_dir = if output.ends_with("/") {
         if as_crate {
@@ -130,7 +147,10 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
 
     for account in &accounts_data {
-        let template = AccountsStructTemplate { account };
+        let template = AccountsStructTemplate {
+            account,
+            lenient: lenient_accounts,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
         fs::write(&filename, rendered).expect("Failed to write account struct file");
@@ -175,6 +195,8 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
         decoder_name: decoder_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
         events: &events_data,
+        feature_gated: as_crate,
+        has_program_id: program_id.is_some(),
     };
     let instructions_mod_rendered = instructions_mod_template.render().unwrap();
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
@@ -184,10 +206,33 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
 
     println!("Generated {}", instructions_mod_filename);
 
+    // Generate Errors
+
+    if !errors_data.is_empty() {
+        let errors_mod_template = ErrorsModTemplate {
+            errors: &errors_data,
+            program_error_enum,
+        };
+        let errors_mod_rendered = errors_mod_template.render().unwrap();
+        let errors_mod_filename = format!("{}/errors.rs", src_dir);
+
+        fs::write(&errors_mod_filename, errors_mod_rendered)
+            .expect("Failed to write errors mod file");
+
+        println!("Generated {}", errors_mod_filename);
+    }
+
+    let errors_mod = if errors_data.is_empty() {
+        ""
+    } else {
+        "\npub mod errors;"
+    };
+
     if as_crate {
         let lib_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+            "pub struct {decoder_name};\n#[cfg(feature = \"accounts\")]\npub mod accounts;{errors_mod}\n#[cfg(feature = \"instructions\")]\npub mod instructions;\npub mod types;\n{program_id_const}",
+            decoder_name = decoder_name,
+            program_id_const = program_id_const(program_id.as_deref()),
         );
         let lib_rs_filename = format!("{}/lib.rs", src_dir);
         fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
@@ -202,6 +247,11 @@ edition = {{ workspace = true }}
 [lib]
 crate-type = ["rlib"]
 
+[features]
+default = ["accounts", "instructions"{default_events}]
+accounts = []
+instructions = []
+{events_feature}
 [dependencies]
 carbon-core = {{ workspace = true }}
 carbon-proc-macros = {{ workspace = true }}
@@ -212,6 +262,16 @@ serde = {{ workspace = true }}
 {big_array}
 "#,
             decoder_name_kebab = decoder_name_kebab,
+            default_events = if events_data.is_empty() {
+                ""
+            } else {
+                ", \"events\""
+            },
+            events_feature = if events_data.is_empty() {
+                ""
+            } else {
+                "events = [\"instructions\"]\n"
+            },
             big_array = if needs_big_array {
                 "serde-big-array = { workspace = true }"
             } else {
@@ -224,8 +284,9 @@ serde = {{ workspace = true }}
         println!("Generated {}", cargo_toml_filename);
     } else {
         let mod_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
+            "pub struct {decoder_name};\npub mod accounts;{errors_mod}\npub mod instructions;\npub mod types;\n{program_id_const}",
+            decoder_name = decoder_name,
+            program_id_const = program_id_const(program_id.as_deref()),
         );
         let mod_rs_filename = format!("{}/mod.rs", src_dir);
         fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
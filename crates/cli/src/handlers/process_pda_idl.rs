@@ -14,6 +14,7 @@ pub fn process_pda_idl(
     url: &Url,
     output: String,
     as_crate: bool,
+    lenient_accounts: bool,
 ) -> Result<()> {
     let rpc_url = match url {
         Url::Mainnet => "https://api.mainnet-beta.solana.com",
@@ -37,7 +38,8 @@ pub fn process_pda_idl(
 
     fs::write(&idl_path, idl)?;
 
-    handlers::parse(idl_path.clone(), output, as_crate).context("Couldn't parse IDL")?;
+    handlers::parse(idl_path.clone(), output, as_crate, lenient_accounts)
+        .context("Couldn't parse IDL")?;
 
     // Clean up: Delete the IDL file after parsing
     if Path::new(&idl_path).exists() {
@@ -0,0 +1,270 @@
+use {crate::commands::TopOptions, anyhow::Result};
+
+#[cfg(not(feature = "top"))]
+pub fn top(_options: TopOptions) -> Result<()> {
+    anyhow::bail!("The top command requires carbon-cli to be built with the `top` feature")
+}
+
+/// Subscribes to the non-vote transactions of a Yellowstone gRPC endpoint and
+/// prints the busiest programs, accounts and fee payers every interval.
+#[cfg(feature = "top")]
+pub fn top(options: TopOptions) -> Result<()> {
+    use {
+        anyhow::Context,
+        carbon_core::pipeline::Pipeline,
+        carbon_yellowstone_grpc_datasource::{config::GeyserEndpoint, YellowstoneGrpcGeyserClient},
+        std::{
+            collections::HashMap,
+            sync::{Arc, Mutex},
+            time::Duration,
+        },
+        yellowstone_grpc_proto::geyser::{CommitmentLevel, SubscribeRequestFilterTransactions},
+    };
+
+    let runtime = tokio::runtime::Runtime::new().context("Couldn't start the Tokio runtime")?;
+
+    runtime.block_on(async move {
+        let endpoint = GeyserEndpoint::new(options.endpoint, options.x_token)
+            .context("Invalid Yellowstone gRPC endpoint")?;
+        let transaction_filter = SubscribeRequestFilterTransactions {
+            vote: Some(false),
+            ..Default::default()
+        };
+        let datasource = YellowstoneGrpcGeyserClient::new(
+            endpoint,
+            Some(CommitmentLevel::Processed),
+            HashMap::new(),
+            HashMap::from([("carbon_top".to_string(), transaction_filter)]),
+            Default::default(),
+        );
+
+        let leaderboards = Arc::new(Mutex::new(leaderboards::Leaderboards::default()));
+        let interval = Duration::from_secs(options.interval.max(1));
+        tokio::spawn(leaderboards::print_every(
+            leaderboards.clone(),
+            interval,
+            options.limit,
+        ));
+
+        Pipeline::builder()
+            .datasource(datasource)
+            .instruction(
+                leaderboards::RawInstructionDecoder,
+                leaderboards::LeaderboardsProcessor(leaderboards),
+            )
+            .build()
+            .context("Couldn't build the pipeline")?
+            .run()
+            .await
+            .context("Pipeline failed")
+    })
+}
+
+#[cfg(feature = "top")]
+mod leaderboards {
+    use {
+        async_trait::async_trait,
+        carbon_core::{
+            error::CarbonResult,
+            instruction::{DecodedInstruction, InstructionDecoder, InstructionProcessorInputType},
+            metrics::MetricsCollection,
+            processor::Processor,
+            registry,
+            transaction::TransactionMetadata,
+        },
+        solana_pubkey::Pubkey,
+        solana_sdk::signature::Signature,
+        std::{
+            collections::HashMap,
+            sync::{Arc, Mutex},
+            time::{Duration, Instant},
+        },
+    };
+
+    /// Decodes every instruction, whatever its program, without reading its
+    /// data.
+    pub struct RawInstructionDecoder;
+
+    impl<'a> InstructionDecoder<'a> for RawInstructionDecoder {
+        type InstructionType = ();
+
+        fn decode_instruction(
+            &self,
+            instruction: &'a solana_sdk::instruction::Instruction,
+        ) -> Option<DecodedInstruction<Self::InstructionType>> {
+            Some(DecodedInstruction {
+                program_id: instruction.program_id,
+                data: (),
+                accounts: Vec::new(),
+                audit: None,
+            })
+        }
+    }
+
+    /// The counts gathered since the leaderboards were last printed.
+    pub struct Leaderboards {
+        since: Instant,
+        transactions: u64,
+        /// The number of transactions invoking each program.
+        programs: HashMap<Pubkey, u64>,
+        /// The last transaction counted for each program, as a program can
+        /// be invoked several times by a transaction.
+        last_signatures: HashMap<Pubkey, Signature>,
+        /// The number of transactions write-locking each account.
+        accounts: HashMap<Pubkey, u64>,
+        /// The lamports paid in fees by each fee payer.
+        fee_payers: HashMap<Pubkey, u64>,
+    }
+
+    impl Default for Leaderboards {
+        fn default() -> Self {
+            Self {
+                since: Instant::now(),
+                transactions: 0,
+                programs: HashMap::new(),
+                last_signatures: HashMap::new(),
+                accounts: HashMap::new(),
+                fee_payers: HashMap::new(),
+            }
+        }
+    }
+
+    impl Leaderboards {
+        /// Counts the accounts and fee payer of a transaction.
+        fn record_transaction(&mut self, transaction: &TransactionMetadata) {
+            self.transactions += 1;
+            *self.fee_payers.entry(transaction.fee_payer).or_default() += transaction.meta.fee;
+            for account in writable_accounts(transaction) {
+                *self.accounts.entry(account).or_default() += 1;
+            }
+        }
+
+        /// Counts the transaction invoking `program_id`, unless it was
+        /// already counted.
+        fn record_program(&mut self, program_id: Pubkey, signature: Signature) {
+            if self.last_signatures.insert(program_id, signature) != Some(signature) {
+                *self.programs.entry(program_id).or_default() += 1;
+            }
+        }
+    }
+
+    /// Returns the accounts write-locked by a transaction.
+    fn writable_accounts(transaction: &TransactionMetadata) -> Vec<Pubkey> {
+        let header = transaction.message.header();
+        let static_keys = transaction.message.static_account_keys();
+        let num_signed = header.num_required_signatures as usize;
+        let num_writable_signed =
+            num_signed.saturating_sub(header.num_readonly_signed_accounts as usize);
+        let num_writable_unsigned = static_keys
+            .len()
+            .saturating_sub(header.num_readonly_unsigned_accounts as usize);
+
+        static_keys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                *index < num_writable_signed
+                    || (*index >= num_signed && *index < num_writable_unsigned)
+            })
+            .map(|(_, account)| *account)
+            .chain(transaction.meta.loaded_addresses.writable.iter().copied())
+            .collect()
+    }
+
+    /// Counts the instructions of every transaction into the leaderboards.
+    pub struct LeaderboardsProcessor(pub Arc<Mutex<Leaderboards>>);
+
+    #[async_trait]
+    impl Processor for LeaderboardsProcessor {
+        type InputType = InstructionProcessorInputType<()>;
+
+        async fn process(
+            &mut self,
+            (metadata, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let mut leaderboards = self.0.lock().unwrap();
+
+            // The first instruction of a transaction counts the transaction.
+            if metadata.absolute_path == [0] {
+                leaderboards.record_transaction(&metadata.transaction_metadata);
+            }
+            leaderboards.record_program(
+                instruction.program_id,
+                metadata.transaction_metadata.signature,
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Prints and resets the leaderboards every `interval`.
+    pub async fn print_every(
+        leaderboards: Arc<Mutex<Leaderboards>>,
+        interval: Duration,
+        limit: usize,
+    ) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            let leaderboards = std::mem::take(&mut *leaderboards.lock().unwrap());
+            print(&leaderboards, limit);
+        }
+    }
+
+    fn print(leaderboards: &Leaderboards, limit: usize) {
+        let elapsed = leaderboards.since.elapsed().as_secs_f64();
+
+        println!(
+            "\n{} transactions over {:.1}s ({:.1} TPS)",
+            leaderboards.transactions,
+            elapsed,
+            leaderboards.transactions as f64 / elapsed
+        );
+
+        println!("Programs by TPS:");
+        for (program_id, transactions) in ranked(&leaderboards.programs, limit) {
+            let name = registry::find(&program_id)
+                .map(|decoder| decoder.program_name)
+                .unwrap_or("");
+            println!(
+                "  {:<44} {:<28} {:>10.1} TPS",
+                program_id,
+                name,
+                transactions as f64 / elapsed
+            );
+        }
+
+        println!("Hottest accounts:");
+        for (account, transactions) in ranked(&leaderboards.accounts, limit) {
+            println!(
+                "  {:<44} {:>10.1} write locks/s",
+                account,
+                transactions as f64 / elapsed
+            );
+        }
+
+        println!("Biggest fee payers:");
+        for (fee_payer, fees) in ranked(&leaderboards.fee_payers, limit) {
+            println!(
+                "  {:<44} {:>14.6} SOL",
+                fee_payer,
+                fees as f64 / 1_000_000_000.0
+            );
+        }
+    }
+
+    /// Returns the `limit` highest counts, highest first.
+    fn ranked(counts: &HashMap<Pubkey, u64>, limit: usize) -> Vec<(Pubkey, u64)> {
+        let mut ranked: Vec<(Pubkey, u64)> = counts
+            .iter()
+            .map(|(pubkey, count)| (*pubkey, *count))
+            .collect();
+        ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+        ranked.truncate(limit);
+
+        ranked
+    }
+}
@@ -47,6 +47,8 @@ pub struct IdlInstructionAccount {
     #[serde(default)]
     pub signer: Option<bool>,
     #[serde(default)]
+    pub optional: Option<bool>,
+    #[serde(default)]
     pub pda: Option<IdlPda>,
     #[serde(default)]
     pub address: Option<String>,
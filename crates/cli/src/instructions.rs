@@ -50,6 +50,10 @@ pub struct InstructionsModTemplate<'a> {
     pub decoder_name: String,
     pub program_instruction_enum: String,
     pub events: &'a Vec<EventData>,
+    /// Whether events are behind the `events` feature of the generated crate.
+    pub feature_gated: bool,
+    /// Whether the IDL gives the program address, generated as `PROGRAM_ID`.
+    pub has_program_id: bool,
 }
 
 pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
@@ -126,8 +130,7 @@ pub fn process_instructions(idl: &Idl) -> Vec<InstructionData> {
                 name: account.name.to_snake_case(),
                 is_mut: account.writable.unwrap_or(false),
                 is_signer: account.signer.unwrap_or(false),
-                // TODO: Check
-                is_optional: false,
+                is_optional: account.optional.unwrap_or(false),
             });
         }
 
@@ -17,6 +17,15 @@ pub struct LegacyIdl {
     pub events: Vec<LegacyIdlEvent>,
     #[serde(default)]
     pub errors: Vec<LegacyIdlError>,
+    #[serde(default)]
+    pub metadata: Option<LegacyIdlMetadata>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyIdlMetadata {
+    #[serde(default)]
+    pub address: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -5,6 +5,7 @@ use {
 
 pub mod accounts;
 pub mod commands;
+pub mod errors;
 pub mod events;
 pub mod handlers;
 pub mod idl;
@@ -14,7 +15,7 @@ pub mod project;
 pub mod types;
 pub mod util;
 
-use commands::{Datasource, Decoder, Metrics, Url};
+use commands::{Datasource, Decoder, Metrics, TopOptions, Url};
 use inquire::{
     error::InquireResult, required, Confirm, CustomType, InquireError, MultiSelect, Select, Text,
 };
@@ -27,7 +28,11 @@ fn main() -> InquireResult<()> {
 }
 
 fn process_prompts() -> InquireResult<()> {
-    let cmd = Select::new("Chose mode:", vec!["parse", "scaffold"]).prompt()?;
+    let cmd = Select::new(
+        "Chose mode:",
+        vec!["parse", "scaffold", "detect", "bench", "top"],
+    )
+    .prompt()?;
 
     match cmd {
         "parse" => {
@@ -49,8 +54,12 @@ fn process_prompts() -> InquireResult<()> {
                                 .with_validator(required!("Please type a path to output folder"))
                                 .prompt()?;
                             let as_crate = Confirm::new("Generate as crate?").prompt()?;
+                            let lenient_accounts =
+                                Confirm::new("Keep trailing account bytes (lenient accounts)?")
+                                    .with_default(false)
+                                    .prompt()?;
 
-                            handlers::parse(path, output_dir, as_crate)
+                            handlers::parse(path, output_dir, as_crate, lenient_accounts)
                                 .map_err(|e| InquireError::Custom(e.into()))?;
                         }
                         IdlStandard::Codama => {
@@ -64,8 +73,18 @@ fn process_prompts() -> InquireResult<()> {
                                 .with_validator(required!("Please type a path to output folder"))
                                 .prompt()?;
                             let as_crate = Confirm::new("Generate as crate?").prompt()?;
-                            handlers::parse_codama(path, output_dir, as_crate, Some(event_hints))
-                                .map_err(|e| InquireError::Custom(e.into()))?;
+                            let lenient_accounts =
+                                Confirm::new("Keep trailing account bytes (lenient accounts)?")
+                                    .with_default(false)
+                                    .prompt()?;
+                            handlers::parse_codama(
+                                path,
+                                output_dir,
+                                as_crate,
+                                lenient_accounts,
+                                Some(event_hints),
+                            )
+                            .map_err(|e| InquireError::Custom(e.into()))?;
                         }
                     }
                 }
@@ -76,9 +95,19 @@ fn process_prompts() -> InquireResult<()> {
                         .with_validator(required!("Please type a path to output folder"))
                         .prompt()?;
                     let as_crate = Confirm::new("Generate as crate?").prompt()?;
+                    let lenient_accounts =
+                        Confirm::new("Keep trailing account bytes (lenient accounts)?")
+                            .with_default(false)
+                            .prompt()?;
 
-                    handlers::process_pda_idl(program_address, &url, output_dir, as_crate)
-                        .map_err(|e| InquireError::Custom(e.into()))?;
+                    handlers::process_pda_idl(
+                        program_address,
+                        &url,
+                        output_dir,
+                        as_crate,
+                        lenient_accounts,
+                    )
+                    .map_err(|e| InquireError::Custom(e.into()))?;
                 }
                 _ => unreachable!(),
             }
@@ -160,6 +189,46 @@ fn process_prompts() -> InquireResult<()> {
             )
             .map_err(|e| InquireError::Custom(e.into()))?;
         }
+        "detect" => {
+            let signature = Text::new("Transaction signature:")
+                .with_validator(required!("Please type a transaction signature"))
+                .prompt()?;
+            let url = CustomType::<Url>::new("Network URL:").prompt()?;
+
+            handlers::detect(signature, &url).map_err(|e| InquireError::Custom(e.into()))?;
+        }
+        "bench" => {
+            let decoder = CustomType::<Decoder>::new("Decoder:").prompt()?;
+            let fixtures = Text::new("Fixtures directory:")
+                .with_validator(required!("Please type a path to a fixtures folder"))
+                .prompt()?;
+            let iterations = CustomType::<usize>::new("Iterations:")
+                .with_default(10_000)
+                .prompt()?;
+
+            handlers::bench(decoder, fixtures, iterations, None)
+                .map_err(|e| InquireError::Custom(e.into()))?;
+        }
+        "top" => {
+            let endpoint = Text::new("Yellowstone gRPC endpoint:")
+                .with_validator(required!("Please type a Yellowstone gRPC endpoint"))
+                .prompt()?;
+            let x_token = Text::new("X-Token (optional):").prompt()?;
+            let interval = CustomType::<u64>::new("Interval (seconds):")
+                .with_default(5)
+                .prompt()?;
+            let limit = CustomType::<usize>::new("Entries per leaderboard:")
+                .with_default(10)
+                .prompt()?;
+
+            handlers::top(TopOptions {
+                endpoint,
+                x_token: Some(x_token).filter(|x_token| !x_token.is_empty()),
+                interval,
+                limit,
+            })
+            .map_err(|e| InquireError::Custom(e.into()))?;
+        }
         _ => unreachable!(),
     }
 
@@ -175,6 +244,7 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                         path,
                         options.output,
                         options.as_crate,
+                        options.lenient_accounts,
                         options.event_hints,
                     )
                     .map_err(|e| InquireError::Custom(e.into()))?;
@@ -186,8 +256,13 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                                 .to_string(),
                         ));
                     }
-                    handlers::parse(path, options.output, options.as_crate)
-                        .map_err(|e| InquireError::Custom(e.into()))?;
+                    handlers::parse(
+                        path,
+                        options.output,
+                        options.as_crate,
+                        options.lenient_accounts,
+                    )
+                    .map_err(|e| InquireError::Custom(e.into()))?;
                 }
             },
             IdlSource::ProgramAddress(program_address) => {
@@ -199,8 +274,14 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                             .to_string(),
                     ))?;
 
-                handlers::process_pda_idl(program_address, url, options.output, options.as_crate)
-                    .map_err(|e| InquireError::Custom(e.into()))?;
+                handlers::process_pda_idl(
+                    program_address,
+                    url,
+                    options.output,
+                    options.as_crate,
+                    options.lenient_accounts,
+                )
+                .map_err(|e| InquireError::Custom(e.into()))?;
             }
         },
         Commands::Scaffold(options) => {
@@ -213,6 +294,22 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
             )
             .map_err(|e| InquireError::Custom(e.into()))?;
         }
+        Commands::Detect(options) => {
+            handlers::detect(options.signature, &options.url)
+                .map_err(|e| InquireError::Custom(e.into()))?;
+        }
+        Commands::Bench(options) => {
+            handlers::bench(
+                options.decoder,
+                options.fixtures,
+                options.iterations,
+                options.threads,
+            )
+            .map_err(|e| InquireError::Custom(e.into()))?;
+        }
+        Commands::Top(options) => {
+            handlers::top(options).map_err(|e| InquireError::Custom(e.into()))?;
+        }
     };
 
     Ok(())
@@ -117,6 +117,30 @@ pub fn idl_type_to_rust_type(idl_type: &LegacyIdlType) -> (String, bool) {
     }
 }
 
+/// Returns the `serialize_as` encoding of a field of the given type: `base58`
+/// for pubkeys, including options and collections of them, and `hex` for byte
+/// arrays.
+pub fn serialize_as(rust_type: &str) -> Option<&'static str> {
+    let is_array = rust_type.starts_with('[');
+    let inner = rust_type
+        .strip_prefix("Option<")
+        .or_else(|| rust_type.strip_prefix("Vec<"))
+        .and_then(|inner| inner.strip_suffix('>'))
+        .or_else(|| {
+            rust_type
+                .strip_prefix('[')
+                .and_then(|inner| inner.split_once(';'))
+                .map(|(inner, _)| inner)
+        })
+        .unwrap_or(rust_type);
+
+    match inner {
+        "solana_pubkey::Pubkey" => Some("base58"),
+        "u8" if is_array => Some("hex"),
+        _ => None,
+    }
+}
+
 pub fn is_big_array(rust_type: &str) -> bool {
     if rust_type.starts_with("[") && rust_type.ends_with("]") {
         if let Some(semicolon_index) = rust_type.find(';') {
@@ -130,3 +154,15 @@ pub fn is_big_array(rust_type: &str) -> bool {
     }
     false
 }
+
+/// Returns the `PROGRAM_ID` constant of a generated decoder, or nothing if the
+/// IDL does not give the program address.
+pub fn program_id_const(program_id: Option<&str>) -> String {
+    program_id
+        .map(|program_id| {
+            format!(
+                "\npub const PROGRAM_ID: solana_pubkey::Pubkey =\n    solana_pubkey::Pubkey::from_str_const(\"{program_id}\");"
+            )
+        })
+        .unwrap_or_default()
+}
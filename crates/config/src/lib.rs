@@ -0,0 +1,338 @@
+//! Builds a Carbon `Pipeline` from a declarative configuration file.
+//!
+//! The configuration describes the datasources, decoders and sinks of a
+//! pipeline, along with the pipeline settings, in TOML or YAML. Components are
+//! referred to by name and resolved through a [`ComponentRegistry`], so a
+//! deployment can enable decoders or point at a different endpoint without
+//! being recompiled.
+//!
+//! # Example
+//!
+//! ```toml
+//! [pipeline]
+//! channel_buffer_size = 50000
+//! metrics_flush_interval = 5
+//! shutdown_strategy = "immediate"
+//!
+//! [[datasources]]
+//! type = "rpc-block-subscribe"
+//! endpoint = "wss://api.mainnet-beta.solana.com"
+//! commitment = "confirmed"
+//! filters = { mentions = ["TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"] }
+//!
+//! [[decoders]]
+//! name = "token-program"
+//!
+//! [[sinks]]
+//! type = "log"
+//! ```
+//!
+//! ```ignore
+//! let config = PipelineConfig::from_path("pipeline.toml")?;
+//! let mut pipeline = config.build(&registry)?;
+//! pipeline.run().await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The format is chosen from the file extension: `.toml`, `.yaml` or `.yml`.
+//! - Settings that are not known to the pipeline itself, such as `filters` or
+//!   any extra key of an entry, are passed as-is to the registered factories.
+
+pub mod registry;
+
+pub use registry::*;
+
+use {
+    carbon_core::{
+        error::{CarbonResult, Error},
+        pipeline::{Pipeline, PipelineBuilder, ShutdownStrategy},
+    },
+    serde::Deserialize,
+    std::{collections::HashMap, path::Path},
+};
+
+/// The root of a pipeline configuration file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    #[serde(default)]
+    pub pipeline: PipelineSettings,
+    #[serde(default)]
+    pub datasources: Vec<DatasourceConfig>,
+    #[serde(default)]
+    pub decoders: Vec<DecoderConfig>,
+    #[serde(default)]
+    pub sinks: Vec<SinkConfig>,
+}
+
+/// Settings applied directly to the `PipelineBuilder`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineSettings {
+    pub channel_buffer_size: Option<usize>,
+    pub metrics_flush_interval: Option<u64>,
+    pub shutdown_strategy: Option<ShutdownStrategyConfig>,
+    #[serde(default)]
+    pub skip_unchanged_accounts: bool,
+}
+
+/// The configurable counterpart of [`ShutdownStrategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownStrategyConfig {
+    Immediate,
+    ProcessPending,
+}
+
+impl From<ShutdownStrategyConfig> for ShutdownStrategy {
+    fn from(value: ShutdownStrategyConfig) -> Self {
+        match value {
+            ShutdownStrategyConfig::Immediate => ShutdownStrategy::Immediate,
+            ShutdownStrategyConfig::ProcessPending => ShutdownStrategy::ProcessPending,
+        }
+    }
+}
+
+/// A datasource entry, resolved by its `type`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatasourceConfig {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub endpoint: String,
+    pub commitment: Option<String>,
+    #[serde(default)]
+    pub filters: serde_json::Value,
+    #[serde(flatten)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+/// A decoder entry, resolved by its `name`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DecoderConfig {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(flatten)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+/// A sink entry, resolved by its `type`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SinkConfig {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(flatten)]
+    pub settings: HashMap<String, serde_json::Value>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl PipelineConfig {
+    /// Parses a configuration from a TOML string.
+    pub fn from_toml_str(content: &str) -> CarbonResult<Self> {
+        toml::from_str(content)
+            .map_err(|err| Error::Custom(format!("Failed to parse TOML config: {err}")))
+    }
+
+    /// Parses a configuration from a YAML string.
+    pub fn from_yaml_str(content: &str) -> CarbonResult<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|err| Error::Custom(format!("Failed to parse YAML config: {err}")))
+    }
+
+    /// Reads and parses a configuration file, picking the format from its
+    /// extension.
+    pub fn from_path(path: impl AsRef<Path>) -> CarbonResult<Self> {
+        let path = path.as_ref();
+        log::trace!("PipelineConfig::from_path(path: {:?})", path);
+
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            Error::Custom(format!("Failed to read config {}: {err}", path.display()))
+        })?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(Error::Custom(format!(
+                "Unsupported config format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Creates a `PipelineBuilder` with every configured component resolved
+    /// through the registry.
+    ///
+    /// Decoders with `enabled = false` are skipped. An error is returned if a
+    /// datasource, decoder or sink is not registered.
+    pub fn into_builder(&self, registry: &ComponentRegistry) -> CarbonResult<PipelineBuilder> {
+        let mut builder = Pipeline::builder();
+
+        if let Some(size) = self.pipeline.channel_buffer_size {
+            builder = builder.channel_buffer_size(size);
+        }
+        if let Some(interval) = self.pipeline.metrics_flush_interval {
+            builder = builder.metrics_flush_interval(interval);
+        }
+        if let Some(strategy) = self.pipeline.shutdown_strategy {
+            builder = builder.shutdown_strategy(strategy.into());
+        }
+        builder = builder.skip_unchanged_accounts(self.pipeline.skip_unchanged_accounts);
+
+        for datasource in &self.datasources {
+            builder = builder.datasource(registry.build_datasource(datasource)?);
+        }
+
+        for decoder in self.decoders.iter().filter(|decoder| decoder.enabled) {
+            builder = registry.apply_decoder(builder, decoder)?;
+        }
+
+        for sink in &self.sinks {
+            builder = registry.apply_sink(builder, sink)?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Builds the `Pipeline` described by this configuration.
+    pub fn build(&self, registry: &ComponentRegistry) -> CarbonResult<Pipeline> {
+        self.into_builder(registry)?.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        async_trait::async_trait,
+        carbon_core::{
+            datasource::{Capabilities, Capability, Datasource, Update},
+            metrics::MetricsCollection,
+        },
+        std::sync::Arc,
+        tokio_util::sync::CancellationToken,
+    };
+
+    struct MockDatasource;
+
+    #[async_trait]
+    impl Datasource for MockDatasource {
+        async fn consume(
+            &self,
+            _sender: &tokio::sync::mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new().with(Capability::Transactions)
+        }
+    }
+
+    const TOML_CONFIG: &str = r#"
+[pipeline]
+channel_buffer_size = 500
+shutdown_strategy = "immediate"
+
+[[datasources]]
+type = "mock"
+endpoint = "wss://localhost:8900"
+commitment = "confirmed"
+filters = { mentions = ["11111111111111111111111111111111"] }
+
+[[decoders]]
+name = "system-program"
+
+[[decoders]]
+name = "token-program"
+enabled = false
+"#;
+
+    fn registry() -> ComponentRegistry {
+        ComponentRegistry::new()
+            .datasource("mock", |_config| Ok(Arc::new(MockDatasource)))
+            .decoder("system-program", |builder, _config| Ok(builder))
+    }
+
+    #[test]
+    fn test_parse_toml_config() {
+        // Arrange
+        let expected_filters = serde_json::json!({
+            "mentions": ["11111111111111111111111111111111"]
+        });
+
+        // Act
+        let config = PipelineConfig::from_toml_str(TOML_CONFIG).expect("parse toml");
+
+        // Assert
+        assert_eq!(config.pipeline.channel_buffer_size, Some(500));
+        assert_eq!(
+            config.pipeline.shutdown_strategy,
+            Some(ShutdownStrategyConfig::Immediate)
+        );
+        assert_eq!(config.datasources[0].kind, "mock");
+        assert_eq!(
+            config.datasources[0].commitment.as_deref(),
+            Some("confirmed")
+        );
+        assert_eq!(config.datasources[0].filters, expected_filters);
+        assert!(!config.decoders[1].enabled);
+    }
+
+    #[test]
+    fn test_parse_yaml_config() {
+        // Arrange
+        let content = r#"
+datasources:
+  - type: mock
+    endpoint: wss://localhost:8900
+    from_slot: 100
+decoders:
+  - name: system-program
+"#;
+
+        // Act
+        let config = PipelineConfig::from_yaml_str(content).expect("parse yaml");
+
+        // Assert
+        assert_eq!(config.datasources[0].endpoint, "wss://localhost:8900");
+        assert_eq!(
+            config.datasources[0].settings.get("from_slot"),
+            Some(&serde_json::json!(100))
+        );
+        assert_eq!(config.decoders[0].name, "system-program");
+    }
+
+    #[test]
+    fn test_build_pipeline_from_config() {
+        // Arrange
+        let config = PipelineConfig::from_toml_str(TOML_CONFIG).expect("parse toml");
+
+        // Act
+        let pipeline = config.build(&registry()).expect("build pipeline");
+
+        // Assert
+        assert_eq!(pipeline.datasources.len(), 1);
+        assert_eq!(pipeline.datasource_names.len(), 1);
+        assert_eq!(pipeline.channel_buffer_size, 500);
+        assert_eq!(pipeline.shutdown_strategy, ShutdownStrategy::Immediate);
+    }
+
+    #[test]
+    fn test_unknown_decoder_is_rejected() {
+        // Arrange
+        let mut config = PipelineConfig::from_toml_str(TOML_CONFIG).expect("parse toml");
+        config.decoders[1].enabled = true;
+
+        // Act
+        let result = config.build(&registry());
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
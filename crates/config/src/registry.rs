@@ -0,0 +1,136 @@
+//! Resolves the components named in a pipeline configuration.
+//!
+//! A configuration file only refers to datasources, decoders and sinks by
+//! name. The `ComponentRegistry` maps those names to factories registered by
+//! the application, which turn the settings of each entry into concrete
+//! pipeline components.
+
+use {
+    crate::{DatasourceConfig, DecoderConfig, SinkConfig},
+    carbon_core::{
+        datasource::Datasource,
+        error::{CarbonResult, Error},
+        pipeline::PipelineBuilder,
+    },
+    std::{collections::HashMap, sync::Arc},
+};
+
+/// Builds a datasource from its configuration entry.
+pub type DatasourceFactory =
+    Box<dyn Fn(&DatasourceConfig) -> CarbonResult<Arc<dyn Datasource + Send + Sync>> + Send + Sync>;
+
+/// Adds the pipes of a decoder to the builder, based on its configuration
+/// entry.
+pub type DecoderFactory =
+    Box<dyn Fn(PipelineBuilder, &DecoderConfig) -> CarbonResult<PipelineBuilder> + Send + Sync>;
+
+/// Adds a sink, such as a metrics backend or a processor writing to external
+/// storage, to the builder based on its configuration entry.
+pub type SinkFactory =
+    Box<dyn Fn(PipelineBuilder, &SinkConfig) -> CarbonResult<PipelineBuilder> + Send + Sync>;
+
+/// Maps component names used in configuration files to their factories.
+///
+/// # Example
+///
+/// ```ignore
+/// let registry = ComponentRegistry::new()
+///     .datasource("rpc-block-subscribe", |config| {
+///         Ok(Arc::new(RpcBlockSubscribe::new(
+///             config.endpoint.clone(),
+///             Filters::new(RpcBlockSubscribeFilter::All, None),
+///         )))
+///     })
+///     .decoder("token-program", |builder, _config| {
+///         Ok(builder.instruction(TokenProgramDecoder, TokenProgramProcessor))
+///     });
+/// ```
+#[derive(Default)]
+pub struct ComponentRegistry {
+    datasources: HashMap<String, DatasourceFactory>,
+    decoders: HashMap<String, DecoderFactory>,
+    sinks: HashMap<String, SinkFactory>,
+}
+
+impl ComponentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a datasource factory under the given `type` name.
+    pub fn datasource(
+        mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&DatasourceConfig) -> CarbonResult<Arc<dyn Datasource + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.datasources.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Registers a decoder factory under the given name.
+    pub fn decoder(
+        mut self,
+        name: impl Into<String>,
+        factory: impl Fn(PipelineBuilder, &DecoderConfig) -> CarbonResult<PipelineBuilder>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.decoders.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Registers a sink factory under the given `type` name.
+    pub fn sink(
+        mut self,
+        name: impl Into<String>,
+        factory: impl Fn(PipelineBuilder, &SinkConfig) -> CarbonResult<PipelineBuilder>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.sinks.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    pub(crate) fn build_datasource(
+        &self,
+        config: &DatasourceConfig,
+    ) -> CarbonResult<Arc<dyn Datasource + Send + Sync>> {
+        let factory = self
+            .datasources
+            .get(&config.kind)
+            .ok_or_else(|| Error::Custom(format!("Unknown datasource type: {}", config.kind)))?;
+
+        factory(config)
+    }
+
+    pub(crate) fn apply_decoder(
+        &self,
+        builder: PipelineBuilder,
+        config: &DecoderConfig,
+    ) -> CarbonResult<PipelineBuilder> {
+        let factory = self
+            .decoders
+            .get(&config.name)
+            .ok_or_else(|| Error::Custom(format!("Unknown decoder: {}", config.name)))?;
+
+        factory(builder, config)
+    }
+
+    pub(crate) fn apply_sink(
+        &self,
+        builder: PipelineBuilder,
+        config: &SinkConfig,
+    ) -> CarbonResult<PipelineBuilder> {
+        let factory = self
+            .sinks
+            .get(&config.kind)
+            .ok_or_else(|| Error::Custom(format!("Unknown sink type: {}", config.kind)))?;
+
+        factory(builder, config)
+    }
+}
@@ -0,0 +1,206 @@
+//! Checks every decoder of the workspace against its fixtures.
+//!
+//! Each decoder keeps fixtures of real instructions and accounts in its
+//! `tests/fixtures` directory, named `<name>_ix.json` and
+//! `<name>_account.json`. The conformance suite decodes all of them in a
+//! single workspace test, so upgrading `solana-sdk`, `borsh` or the derive
+//! macros cannot silently change what a decoder produces:
+//!
+//! - every fixture must decode,
+//! - every decoded instruction must round-trip through JSON,
+//! - every decoded instruction must equal its expected JSON, kept next to
+//!   the fixture as `<name>_ix.expected.json`.
+//!
+//! # Example
+//!
+//! Adding a decoder to the suite is one line of `tests/decoders.rs`, naming
+//! its directory and the checks it supports:
+//!
+//! ```ignore
+//! conformance_suite! {
+//!     pumpfun: "pumpfun-decoder", PumpfunDecoder, [check_instructions, check_accounts];
+//!     pyth_oracle: "pyth-oracle-decoder", PythOracleDecoder, [check_accounts];
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Missing expected files are recorded from the decoded output on the
+//!   first run, to be reviewed and committed with the fixtures. Set
+//!   `CARBON_BLESS=1` to record them again after an intended change of the
+//!   decoded output.
+//! - Decoded accounts are only checked to decode, as the account types of
+//!   the decoders are not serializable.
+//! - A decoder directory missing from the suite fails the
+//!   `test_every_decoder_is_checked` test, so new decoders must come with
+//!   fixtures.
+
+use {
+    anyhow::{anyhow, bail},
+    carbon_core::{account::AccountDecoder, instruction::InstructionDecoder},
+    serde::{de::DeserializeOwned, Serialize},
+    serde_json::Value,
+    std::{
+        env,
+        fmt::Debug,
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+/// The environment variable recording the expected files again when set.
+pub const BLESS_ENV_VAR: &str = "CARBON_BLESS";
+
+/// Returns the fixtures directory of the decoder in `decoders/<decoder_dir>`.
+pub fn fixtures_dir(decoder_dir: &str) -> PathBuf {
+    decoders_dir()
+        .join(decoder_dir)
+        .join("tests")
+        .join("fixtures")
+}
+
+/// Returns the `decoders` directory of the workspace.
+pub fn decoders_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../decoders")
+}
+
+/// Decodes every instruction fixture of `fixtures_dir` and compares it to
+/// its expected JSON, returning the number of fixtures checked.
+pub fn check_instructions<D, T>(decoder: &D, fixtures_dir: &Path) -> anyhow::Result<usize>
+where
+    D: for<'a> InstructionDecoder<'a, InstructionType = T>,
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let fixtures = carbon_test_utils::read_instructions(fixtures_dir)?;
+
+    for (name, instruction) in &fixtures {
+        let decoded = decoder
+            .decode_instruction(instruction)
+            .ok_or_else(|| anyhow!("{name}: instruction was not decoded"))?;
+        if decoded.program_id != instruction.program_id {
+            bail!(
+                "{name}: decoded program {} differs from the fixture's {}",
+                decoded.program_id,
+                instruction.program_id
+            );
+        }
+
+        let json = serde_json::to_value(&decoded.data)
+            .map_err(|e| anyhow!("{name}: couldn't serialize decoded instruction: {e}"))?;
+        let round_trip = serde_json::from_value::<T>(json.clone())
+            .map_err(|e| anyhow!("{name}: couldn't deserialize decoded instruction: {e}"))?;
+        if round_trip != decoded.data {
+            bail!(
+                "{name}: decoded instruction {:?} does not round-trip through JSON, got {:?}",
+                decoded.data,
+                round_trip
+            );
+        }
+
+        check_expected(
+            &fixtures_dir.join(format!("{name}_ix.expected.json")),
+            &json,
+        )?;
+    }
+
+    Ok(fixtures.len())
+}
+
+/// Decodes every account fixture of `fixtures_dir`, returning the number of
+/// fixtures checked.
+pub fn check_accounts<D>(decoder: &D, fixtures_dir: &Path) -> anyhow::Result<usize>
+where
+    D: for<'a> AccountDecoder<'a>,
+{
+    let fixtures = carbon_test_utils::read_accounts(fixtures_dir)?;
+
+    for (name, account) in &fixtures {
+        if decoder.decode_account(account).is_none() {
+            bail!("{name}: account was not decoded");
+        }
+    }
+
+    Ok(fixtures.len())
+}
+
+/// Compares `actual` to the JSON of `path`, recording it if the file is
+/// missing or if `CARBON_BLESS` is set.
+fn check_expected(path: &Path, actual: &Value) -> anyhow::Result<()> {
+    let bless = env::var_os(BLESS_ENV_VAR).is_some();
+
+    if bless || !path.exists() {
+        let json = serde_json::to_string_pretty(actual)?;
+        fs::write(path, json + "\n")
+            .map_err(|e| anyhow!("Couldn't write {}: {e}", path.display()))?;
+        eprintln!("Recorded {}", path.display());
+        return Ok(());
+    }
+
+    let expected = fs::read(path)
+        .map_err(|e| anyhow!("Couldn't read {}: {e}", path.display()))
+        .and_then(|data| {
+            serde_json::from_slice::<Value>(&data)
+                .map_err(|e| anyhow!("Couldn't deserialize {}: {e}", path.display()))
+        })?;
+    if &expected != actual {
+        bail!(
+            "{}: decoded output differs from the expected JSON, set {}=1 to record it again if \
+             the change is intended\nexpected: {}\nactual: {}",
+            path.display(),
+            BLESS_ENV_VAR,
+            expected,
+            actual
+        );
+    }
+
+    Ok(())
+}
+
+/// Defines a test per decoder running the given checks on its fixtures, and
+/// `CHECKED_DECODERS`, the directories of the decoders checked.
+#[macro_export]
+macro_rules! conformance_suite {
+    ($($test:ident: $dir:literal, $decoder:expr, [$($check:ident),+ $(,)?]);+ $(;)?) => {
+        /// The directories of the decoders checked by the suite.
+        pub const CHECKED_DECODERS: &[&str] = &[$($dir),+];
+
+        $(
+            #[test]
+            fn $test() {
+                let fixtures_dir = $crate::fixtures_dir($dir);
+                let checked = 0 $(
+                    + $crate::$check(&$decoder, &fixtures_dir)
+                        .unwrap_or_else(|e| panic!("{}: {e}", $dir))
+                )+;
+
+                assert!(checked > 0, "{}: no fixtures found", $dir);
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_check_expected_records_then_compares() {
+        // Arrange
+        let path = env::temp_dir().join(format!(
+            "carbon_conformance_{}_ix.expected.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        // Act
+        let recorded = check_expected(&path, &json!({ "Buy": { "amount": 1 } }));
+        let matching = check_expected(&path, &json!({ "Buy": { "amount": 1 } }));
+        let differing = check_expected(&path, &json!({ "Buy": { "amount": 2 } }));
+        let _ = fs::remove_file(&path);
+
+        // Assert
+        assert!(recorded.is_ok());
+        assert!(matching.is_ok());
+        assert_eq!(differing.is_err(), env::var_os(BLESS_ENV_VAR).is_none());
+    }
+}
@@ -0,0 +1,94 @@
+use {
+    carbon_conformance::{conformance_suite, decoders_dir},
+    std::fs,
+};
+
+conformance_suite! {
+    alldomains: "alldomains-decoder", carbon_alldomains_decoder::AllDomainsDecoder, [check_accounts];
+    associated_token_account: "associated-token-account-decoder", carbon_associated_token_account_decoder::SplAssociatedTokenAccountDecoder, [check_instructions];
+    crema_clmm: "crema-clmm-decoder", carbon_crema_clmm_decoder::CremaClmmDecoder, [check_instructions, check_accounts];
+    fluxbeam: "fluxbeam-decoder", carbon_fluxbeam_decoder::FluxbeamDecoder, [check_instructions, check_accounts];
+    goosefx_gamma: "goosefx-gamma-decoder", carbon_goosefx_gamma_decoder::GooseFxGammaDecoder, [check_instructions, check_accounts];
+    invariant: "invariant-decoder", carbon_invariant_decoder::InvariantDecoder, [check_instructions, check_accounts];
+    jupiter_dca: "jupiter-dca-decoder", carbon_jupiter_dca_decoder::JupiterDcaDecoder, [check_instructions, check_accounts];
+    lifinity_amm_v2: "lifinity-amm-v2-decoder", carbon_lifinity_amm_v2_decoder::LifinityAmmV2Decoder, [check_instructions, check_accounts];
+    mercurial_stable_swap: "mercurial-stable-swap-decoder", carbon_mercurial_stable_swap_decoder::MercurialStableSwapDecoder, [check_instructions, check_accounts];
+    moonshot: "moonshot-decoder", carbon_moonshot_decoder::MoonshotDecoder, [check_instructions, check_accounts];
+    mpl_candy_guard: "mpl-candy-guard-decoder", carbon_mpl_candy_guard_decoder::CandyGuardDecoder, [check_instructions, check_accounts];
+    mpl_candy_machine_core: "mpl-candy-machine-core-decoder", carbon_mpl_candy_machine_core_decoder::CandyMachineCoreDecoder, [check_instructions, check_accounts];
+    name_service: "name-service-decoder", carbon_name_service_decoder::NameDecoder, [check_accounts];
+    pumpfun: "pumpfun-decoder", carbon_pumpfun_decoder::PumpfunDecoder, [check_instructions, check_accounts];
+    pyth_oracle: "pyth-oracle-decoder", carbon_pyth_oracle_decoder::PythOracleDecoder, [check_accounts];
+    pyth_receiver: "pyth-receiver-decoder", carbon_pyth_receiver_decoder::PythReceiverDecoder, [check_accounts];
+    saber_stable_swap: "saber-stable-swap-decoder", carbon_saber_stable_swap_decoder::SaberStableSwapDecoder, [check_instructions, check_accounts];
+    stake_program: "stake-program-decoder", carbon_stake_program_decoder::StakeProgramDecoder, [check_instructions, check_accounts];
+    switchboard_v2: "switchboard-v2-decoder", carbon_switchboard_v2_decoder::SwitchboardV2Decoder, [check_accounts];
+    system_program: "system-program-decoder", carbon_system_program_decoder::SystemProgramDecoder, [check_instructions];
+    vote_program: "vote-program-decoder", carbon_vote_program_decoder::VoteProgramDecoder, [check_instructions, check_accounts];
+}
+
+/// The decoders without fixtures yet. Do not add new decoders here: give
+/// them fixtures and add them to the suite instead.
+const WITHOUT_FIXTURES: &[&str] = &[
+    "drift-v2-decoder",
+    "jupiter-limit-order-2-decoder",
+    "jupiter-limit-order-decoder",
+    "jupiter-perpetuals-decoder",
+    "jupiter-swap-decoder",
+    "kamino-farms-decoder",
+    "kamino-lending-decoder",
+    "kamino-limit-order-decoder",
+    "kamino-vault-decoder",
+    "marginfi-v2-decoder",
+    "marinade-finance-decoder",
+    "memo-program-decoder",
+    "meteora-dlmm-decoder",
+    "meteora-pools-decoder",
+    "mpl-core-decoder",
+    "mpl-token-metadata-decoder",
+    "okx-dex-decoder",
+    "openbook-v2-decoder",
+    "orca-whirlpool-decoder",
+    "phoenix-v1-decoder",
+    "pump-swap-decoder",
+    "raydium-amm-v4-decoder",
+    "raydium-clmm-decoder",
+    "raydium-cpmm-decoder",
+    "raydium-liquidity-locking-decoder",
+    "sharky-decoder",
+    "solayer-restaking-program-decoder",
+    "spl-account-compression-decoder",
+    "spl-noop-decoder",
+    "stabble-stable-swap-decoder",
+    "stabble-weighted-swap-decoder",
+    "token-2022-decoder",
+    "token-program-decoder",
+    "virtuals-decoder",
+    "zeta-decoder",
+];
+
+#[test]
+fn test_every_decoder_is_checked() {
+    let mut unchecked = Vec::new();
+
+    for entry in fs::read_dir(decoders_dir()).expect("read decoders directory") {
+        let path = entry.expect("read decoders directory").path();
+        let Some(dir) = path.file_name().and_then(|dir| dir.to_str()) else {
+            continue;
+        };
+        if !path.join("Cargo.toml").exists() {
+            continue;
+        }
+
+        if !CHECKED_DECODERS.contains(&dir) && !WITHOUT_FIXTURES.contains(&dir) {
+            unchecked.push(dir.to_string());
+        }
+    }
+
+    unchecked.sort();
+    assert!(
+        unchecked.is_empty(),
+        "decoders missing from the conformance suite: {:?}",
+        unchecked
+    );
+}
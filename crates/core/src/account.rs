@@ -10,8 +10,8 @@
 //!
 //! The `account` module supports various tasks related to Solana account
 //! processing:
-//! - **Account Metadata**: Metadata about accounts, including slot and public
-//!   key information.
+//! - **Account Metadata**: Metadata about accounts, including slot, public
+//!   key, lamports and owner information.
 //! - **Decoded Account**: Holds detailed account data after decoding, such as
 //!   lamports, owner, and rent epoch.
 //! - **Account Decoders**: A trait-based mechanism to decode raw Solana account
@@ -52,27 +52,71 @@
 //!   handling in the pipeline.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection, processor::Processor},
+    crate::{
+        error::CarbonResult,
+        idempotency::IdempotencyKey,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+        trace::TraceId,
+    },
     async_trait::async_trait,
     solana_pubkey::Pubkey,
-    std::sync::Arc,
+    solana_sdk::commitment_config::CommitmentLevel,
+    std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+        sync::Arc,
+    },
 };
 
-/// Holds metadata for an account update, including the slot and public key.
+/// Holds metadata for an account update, including the slot, public key,
+/// lamports and owner.
 ///
 /// `AccountMetadata` provides essential information about an account update,
-/// such as the slot number where the account was updated and the account's
-/// public key. This metadata is used within the pipeline to identify and
-/// process account updates.
+/// such as the slot number where the account was updated, the account's
+/// public key and the state of the raw account. This metadata is used within
+/// the pipeline to identify and process account updates, and is available
+/// even to decoders' processors whose decoded data does not carry it.
 ///
 /// # Fields
 ///
 /// - `slot`: The Solana slot number where the account was updated.
 /// - `pubkey`: The public key of the account.
+/// - `trace_id`: The `TraceId` assigned to the update when it entered the
+///   pipeline.
+/// - `commitment`: The commitment the update was received at, when tagged by
+///   the datasource.
+/// - `write_version`: The order of the write within the slot, when provided by
+///   the datasource.
+/// - `lamports`: The number of lamports in the account. An account with zero
+///   lamports has been closed.
+/// - `owner`: The public key of the program owning the account.
+/// - `executable`: Whether the account is executable.
+/// - `rent_epoch`: The rent epoch of the account.
 #[derive(Debug, Clone)]
 pub struct AccountMetadata {
     pub slot: u64,
     pub pubkey: Pubkey,
+    pub trace_id: TraceId,
+    pub commitment: Option<CommitmentLevel>,
+    pub write_version: Option<u64>,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub executable: bool,
+    pub rent_epoch: u64,
+}
+
+impl AccountMetadata {
+    /// Returns the deterministic key identifying this account update, made of
+    /// the account's public key, the slot of the update and its write version,
+    /// if any.
+    pub fn idempotency_key(&self) -> IdempotencyKey {
+        IdempotencyKey::Account {
+            pubkey: self.pubkey,
+            slot: self.slot,
+            write_version: self.write_version,
+        }
+    }
 }
 
 /// Represents the decoded data of a Solana account, including account-specific
@@ -114,6 +158,12 @@ pub struct DecodedAccount<T> {
 ///
 /// - `AccountType`: The data type resulting from decoding the account, specific
 ///   to the application.
+///
+/// # Provided Methods
+///
+/// - `decode_account_at_slot`: Decodes the account knowing the slot of the
+///   update. Defaults to `decode_account`; decoders supporting several layout
+///   versions, such as `VersionedAccountDecoder`, override it.
 pub trait AccountDecoder<'a> {
     type AccountType;
 
@@ -121,6 +171,14 @@ pub trait AccountDecoder<'a> {
         &self,
         account: &'a solana_account::Account,
     ) -> Option<DecodedAccount<Self::AccountType>>;
+
+    fn decode_account_at_slot(
+        &self,
+        account: &'a solana_account::Account,
+        _slot: u64,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        self.decode_account(account)
+    }
 }
 
 /// The input type for the account processor.
@@ -185,6 +243,20 @@ pub trait AccountPipes: Send + Sync {
         account_with_metadata: (AccountMetadata, solana_account::Account),
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Decodes the account of `slot` without running the processor,
+    /// returning whether the decoder recognized it. Used by `Pipeline::check`.
+    fn decode_only(&self, account: &solana_account::Account, slot: u64) -> bool;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -199,11 +271,207 @@ impl<T: Send> AccountPipes for AccountPipe<T> {
             account_with_metadata,
         );
 
-        if let Some(decoded_account) = self.decoder.decode_account(&account_with_metadata.1) {
+        if let Some(decoded_account) = self
+            .decoder
+            .decode_account_at_slot(&account_with_metadata.1, account_with_metadata.0.slot)
+        {
             self.processor
                 .process((account_with_metadata.0, decoded_account), metrics)
                 .await?;
         }
         Ok(())
     }
+
+    fn decode_only(&self, account: &solana_account::Account, slot: u64) -> bool {
+        self.decoder.decode_account_at_slot(account, slot).is_some()
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+/// Remembers a hash of the last state seen for each account, so updates that
+/// rewrite an account with identical data can be skipped.
+///
+/// Some programs rewrite the same accounts every slot without changing their
+/// contents. When enabled on the pipeline, such updates are suppressed before
+/// they reach the decoders and processors of the account pipes.
+///
+/// # Notes
+///
+/// - The hash covers the account's data, lamports, owner, executable flag and
+///   rent epoch, so any change to one of them is still processed.
+/// - One entry is kept per account seen; entries are removed when the account
+///   is deleted.
+#[derive(Debug, Default)]
+pub struct AccountDataCache {
+    hashes: HashMap<Pubkey, u64>,
+}
+
+impl AccountDataCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the account's state and returns `true` if it is identical to
+    /// the state recorded by the previous update of the same account.
+    pub fn is_unchanged(&mut self, pubkey: &Pubkey, account: &solana_account::Account) -> bool {
+        let mut hasher = DefaultHasher::new();
+        account.data.hash(&mut hasher);
+        account.lamports.hash(&mut hasher);
+        account.owner.hash(&mut hasher);
+        account.executable.hash(&mut hasher);
+        account.rent_epoch.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        self.hashes.insert(*pubkey, hash) == Some(hash)
+    }
+
+    /// Forgets the account, so its next update is processed.
+    pub fn remove(&mut self, pubkey: &Pubkey) {
+        self.hashes.remove(pubkey);
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+}
+
+/// Remembers the slot and write version of the last update processed for each
+/// account, so updates older than it can be skipped.
+///
+/// When a backfill and a live datasource run together, the backfill may
+/// deliver account states older than those already processed from the live
+/// stream. When enabled on the pipeline, such updates are dropped before they
+/// reach the decoders and processors of the account pipes.
+///
+/// # Notes
+///
+/// - An update is stale when its slot is lower than the watermark of the
+///   account, or when it has the same slot and a write version no higher than
+///   the watermark's. Updates of the same slot without write versions are
+///   processed, as their order is unknown.
+/// - One entry is kept per account seen. A deleted account keeps its entry,
+///   moved to the slot of the deletion by `delete`, so an older update
+///   delivered after the deletion does not bring the closed account back.
+/// - A deletion older than the watermark of its account is stale too.
+#[derive(Debug, Default)]
+pub struct AccountWatermark {
+    watermarks: HashMap<Pubkey, (u64, Option<u64>)>,
+}
+
+impl AccountWatermark {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the update is older than the last update processed
+    /// for the account. Otherwise, records the update as the new watermark.
+    pub fn is_stale(&mut self, pubkey: &Pubkey, slot: u64, write_version: Option<u64>) -> bool {
+        if let Some((last_slot, last_write_version)) = self.watermarks.get(pubkey) {
+            let stale = match (last_write_version, write_version) {
+                (Some(last_write_version), Some(write_version)) if slot == *last_slot => {
+                    write_version <= *last_write_version
+                }
+                _ => slot < *last_slot,
+            };
+
+            if stale {
+                return true;
+            }
+        }
+
+        self.watermarks.insert(*pubkey, (slot, write_version));
+        false
+    }
+
+    /// Returns `true` if the deletion of the account at `slot` is older than
+    /// the last update processed for it. Otherwise, records the deletion as
+    /// the new watermark, skipping the updates of earlier slots.
+    pub fn delete(&mut self, pubkey: &Pubkey, slot: u64) -> bool {
+        self.is_stale(pubkey, slot, None)
+    }
+
+    /// Forgets the account, so its next update is processed.
+    pub fn remove(&mut self, pubkey: &Pubkey) {
+        self.watermarks.remove(pubkey);
+    }
+
+    pub fn len(&self) -> usize {
+        self.watermarks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.watermarks.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_data_cache_suppresses_identical_updates() {
+        let mut cache = AccountDataCache::new();
+        let pubkey = Pubkey::new_unique();
+        let mut account = solana_account::Account {
+            lamports: 1_000,
+            data: vec![1, 2, 3],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        assert!(!cache.is_unchanged(&pubkey, &account));
+        assert!(cache.is_unchanged(&pubkey, &account));
+
+        account.data[0] = 4;
+        assert!(!cache.is_unchanged(&pubkey, &account));
+
+        cache.remove(&pubkey);
+        assert!(!cache.is_unchanged(&pubkey, &account));
+    }
+
+    #[test]
+    fn test_account_watermark_skips_stale_updates() {
+        let mut watermark = AccountWatermark::new();
+        let pubkey = Pubkey::new_unique();
+
+        assert!(!watermark.is_stale(&pubkey, 10, Some(5)));
+        assert!(watermark.is_stale(&pubkey, 9, Some(8)));
+        assert!(watermark.is_stale(&pubkey, 10, Some(5)));
+        assert!(!watermark.is_stale(&pubkey, 10, Some(6)));
+        assert!(!watermark.is_stale(&pubkey, 10, None));
+        assert!(watermark.is_stale(&pubkey, 9, None));
+        assert!(!watermark.is_stale(&pubkey, 11, None));
+
+        watermark.remove(&pubkey);
+        assert!(!watermark.is_stale(&pubkey, 1, None));
+    }
+
+    #[test]
+    fn test_account_watermark_skips_stale_updates_after_deletion() {
+        let mut watermark = AccountWatermark::new();
+        let pubkey = Pubkey::new_unique();
+
+        // A live update, then the live deletion of the account.
+        assert!(!watermark.is_stale(&pubkey, 10, Some(1)));
+        assert!(!watermark.delete(&pubkey, 12));
+
+        // A backfill replaying the account before its deletion.
+        assert!(watermark.is_stale(&pubkey, 11, Some(7)));
+        assert!(watermark.delete(&pubkey, 9));
+
+        // The account is created again.
+        assert!(!watermark.is_stale(&pubkey, 13, Some(1)));
+    }
 }
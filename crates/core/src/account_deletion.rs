@@ -10,8 +10,10 @@
 
 use {
     crate::{
-        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
-        processor::Processor,
+        datasource::AccountDeletion,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
     },
     async_trait::async_trait,
     std::sync::Arc,
@@ -149,6 +151,16 @@ pub trait AccountDeletionPipes: Send + Sync {
         account_deletion: AccountDeletion,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -167,4 +179,12 @@ impl AccountDeletionPipes for AccountDeletionPipe {
 
         Ok(())
     }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
 }
@@ -0,0 +1,151 @@
+//! Builds the filters selecting the accounts of a decoded type, and their rent.
+//!
+//! Derived `CarbonDeserialize` implementations expose the discriminator of
+//! each account type and, when fixed, its serialized length. An
+//! `AccountTypeFilter` turns them into the filters of a `getProgramAccounts`
+//! bootstrap or a `programSubscribe` subscription, matching the discriminator
+//! at offset 0 and, if asked, the data size, so the accounts of a program can
+//! be fetched per type without writing down discriminators and sizes by hand.
+//! The Yellowstone datasource converts them into Geyser account filters.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let config = RpcProgramAccountsConfig {
+//!     filters: Some(AccountTypeFilter::of::<BondingCurve>().rpc_filters()),
+//!     ..Default::default()
+//! };
+//! let bonding_curves = rpc_client
+//!     .get_program_accounts_with_config(&PUMPFUN_PROGRAM_ID, config)
+//!     .await?;
+//!
+//! let rent = rent_exempt_minimum::<BondingCurve>(&Rent::default());
+//! ```
+//!
+//! # Notes
+//!
+//! - Only the discriminator is matched by default, as accounts can be
+//!   allocated larger than their type, such as accounts reallocated by a
+//!   program upgrade. Use `with_data_size` to also match the size of types
+//!   whose accounts are never larger.
+//! - Types without a discriminator, such as the accounts of native programs,
+//!   can only be told apart by their size, with `with_data_size`, and types
+//!   of a variable length only by their discriminator.
+
+use {
+    crate::deserialize::CarbonDeserialize,
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_sdk::rent::Rent,
+};
+
+/// The filters matching the accounts of a type.
+///
+/// # Fields
+///
+/// - `discriminator`: The discriminator the data starts with, empty if the
+///   type has none.
+/// - `data_size`: The size of the data, if matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTypeFilter {
+    pub discriminator: Vec<u8>,
+    pub data_size: Option<u64>,
+    size: Option<u64>,
+}
+
+impl AccountTypeFilter {
+    /// Returns the filters matching the discriminator of type `T`.
+    pub fn of<T: CarbonDeserialize>() -> Self {
+        Self {
+            discriminator: T::DISCRIMINATOR.to_vec(),
+            data_size: None,
+            size: T::SIZE.map(|size| size as u64),
+        }
+    }
+
+    /// Also matches the size of the type, if fixed, for types whose accounts
+    /// are never allocated larger.
+    pub fn with_data_size(mut self) -> Self {
+        self.data_size = self.size;
+        self
+    }
+
+    /// Returns the filters of a `getProgramAccounts` request or a
+    /// `programSubscribe` subscription.
+    pub fn rpc_filters(&self) -> Vec<RpcFilterType> {
+        let mut filters = Vec::new();
+
+        if !self.discriminator.is_empty() {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                self.discriminator.clone(),
+            )));
+        }
+        if let Some(data_size) = self.data_size {
+            filters.push(RpcFilterType::DataSize(data_size));
+        }
+
+        filters
+    }
+}
+
+/// Returns the minimum balance for an account of type `T` to be rent exempt,
+/// if the type has a fixed size.
+pub fn rent_exempt_minimum<T: CarbonDeserialize>(rent: &Rent) -> Option<u64> {
+    T::SIZE.map(|size| rent.minimum_balance(size))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::borsh};
+
+    #[derive(borsh::BorshDeserialize)]
+    struct Pool {
+        _reserves: [u64; 2],
+    }
+
+    impl CarbonDeserialize for Pool {
+        const DISCRIMINATOR: &'static [u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+        const LEN: Option<usize> = Some(16);
+
+        fn deserialize(data: &[u8]) -> Option<Self> {
+            borsh::BorshDeserialize::try_from_slice(data.get(8..)?).ok()
+        }
+    }
+
+    #[derive(borsh::BorshDeserialize)]
+    struct Metadata {
+        _name: String,
+    }
+
+    impl CarbonDeserialize for Metadata {
+        const DISCRIMINATOR: &'static [u8] = &[8, 7, 6, 5, 4, 3, 2, 1];
+
+        fn deserialize(data: &[u8]) -> Option<Self> {
+            borsh::BorshDeserialize::try_from_slice(data.get(8..)?).ok()
+        }
+    }
+
+    #[test]
+    fn test_account_type_filters() {
+        // Arrange
+        let rent = Rent::default();
+
+        // Act
+        let pool = AccountTypeFilter::of::<Pool>();
+        let metadata = AccountTypeFilter::of::<Metadata>();
+
+        // Assert
+        assert_eq!(pool.discriminator, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(pool.data_size, None);
+        assert_eq!(pool.rpc_filters().len(), 1);
+        assert_eq!(pool.clone().with_data_size().data_size, Some(24));
+        assert_eq!(pool.with_data_size().rpc_filters().len(), 2);
+        assert_eq!(metadata.clone().with_data_size().data_size, None);
+        assert_eq!(metadata.rpc_filters().len(), 1);
+        assert_eq!(
+            rent_exempt_minimum::<Pool>(&rent),
+            Some(rent.minimum_balance(24))
+        );
+        assert_eq!(rent_exempt_minimum::<Metadata>(&rent), None);
+    }
+}
@@ -0,0 +1,315 @@
+//! Detects the ownership changes and closures of accounts.
+//!
+//! An account is closed by draining its lamports, but datasources do not all
+//! report it the same way: some send an `AccountDeletion`, others an account
+//! update with zero lamports, and an account reassigned to another program
+//! keeps its address with an owner its decoders no longer recognize. An
+//! `AccountLifecyclePipe` follows the owner and lamports of every account
+//! update the pipeline receives, and passes an `AccountLifecycleEvent` to its
+//! processor when an account changes owner, is closed or is deleted.
+//!
+//! Each event makes the state decoded from the account stale, so sinks
+//! keeping the latest state of accounts provide processors deleting it on
+//! these events, such as `RedisAccountSink::deletions`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct ClosedPositions;
+//!
+//! #[async_trait]
+//! impl Processor for ClosedPositions {
+//!     type InputType = AccountLifecycleEvent;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         event: AccountLifecycleEvent,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         if !matches!(event, AccountLifecycleEvent::OwnerChanged { .. }) {
+//!             log::info!("position {} closed", event.pubkey());
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account_lifecycle(ClosedPositions)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - An account update with zero lamports is a closure, even for an account
+//!   seen for the first time. Further updates with zero lamports are not
+//!   reported again until the account is funded.
+//! - An `AccountDeletion` is passed on as a `Deleted` event, and the account
+//!   forgotten. A closed account can be reported by both a `Closed` and a
+//!   `Deleted` event, so processors must handle them idempotently.
+//! - Ownership changes are only detected for accounts seen before. The
+//!   tracker keeps the owner and lamports of every account seen until it is
+//!   deleted, so filter the datasource to the accounts of interest.
+
+use {
+    crate::{
+        account::AccountMetadata,
+        datasource::AccountDeletion,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, sync::Arc},
+};
+
+/// A change in the lifecycle of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountLifecycleEvent {
+    /// The account was assigned to another program.
+    OwnerChanged {
+        pubkey: Pubkey,
+        slot: u64,
+        previous_owner: Pubkey,
+        owner: Pubkey,
+    },
+    /// The lamports of the account dropped to zero.
+    Closed {
+        pubkey: Pubkey,
+        slot: u64,
+        /// The owner of the account when it was closed.
+        owner: Pubkey,
+    },
+    /// The account was deleted, as reported by an `AccountDeletion`.
+    Deleted { pubkey: Pubkey, slot: u64 },
+}
+
+impl AccountLifecycleEvent {
+    pub fn pubkey(&self) -> Pubkey {
+        match self {
+            AccountLifecycleEvent::OwnerChanged { pubkey, .. }
+            | AccountLifecycleEvent::Closed { pubkey, .. }
+            | AccountLifecycleEvent::Deleted { pubkey, .. } => *pubkey,
+        }
+    }
+
+    pub fn slot(&self) -> u64 {
+        match self {
+            AccountLifecycleEvent::OwnerChanged { slot, .. }
+            | AccountLifecycleEvent::Closed { slot, .. }
+            | AccountLifecycleEvent::Deleted { slot, .. } => *slot,
+        }
+    }
+}
+
+/// Follows the owner and lamports of accounts.
+#[derive(Debug, Clone, Default)]
+pub struct AccountLifecycleTracker {
+    /// The owner and lamports of each account seen.
+    accounts: HashMap<Pubkey, (Pubkey, u64)>,
+}
+
+impl AccountLifecycleTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of accounts tracked.
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Takes the next update of an account, returning the lifecycle events it
+    /// leads to.
+    pub fn push(&mut self, metadata: &AccountMetadata) -> Vec<AccountLifecycleEvent> {
+        let previous = self
+            .accounts
+            .insert(metadata.pubkey, (metadata.owner, metadata.lamports));
+        let mut events = Vec::new();
+
+        if let Some((previous_owner, _)) =
+            previous.filter(|(previous_owner, _)| *previous_owner != metadata.owner)
+        {
+            events.push(AccountLifecycleEvent::OwnerChanged {
+                pubkey: metadata.pubkey,
+                slot: metadata.slot,
+                previous_owner,
+                owner: metadata.owner,
+            });
+        }
+        if metadata.lamports == 0 && previous.is_none_or(|(_, lamports)| lamports > 0) {
+            events.push(AccountLifecycleEvent::Closed {
+                pubkey: metadata.pubkey,
+                slot: metadata.slot,
+                owner: metadata.owner,
+            });
+        }
+
+        events
+    }
+
+    /// Forgets a deleted account, returning its `Deleted` event.
+    pub fn delete(&mut self, account_deletion: &AccountDeletion) -> AccountLifecycleEvent {
+        self.accounts.remove(&account_deletion.pubkey);
+
+        AccountLifecycleEvent::Deleted {
+            pubkey: account_deletion.pubkey,
+            slot: account_deletion.slot,
+        }
+    }
+}
+
+/// A processing pipe passing the lifecycle events of accounts to a processor.
+pub struct AccountLifecyclePipe {
+    pub tracker: AccountLifecycleTracker,
+    pub processor: Box<dyn Processor<InputType = AccountLifecycleEvent> + Send + Sync>,
+}
+
+/// A trait for the pipes following the lifecycle of accounts.
+///
+/// # Required Methods
+///
+/// - `run`: Takes the metadata of the next account update, processing the
+///   lifecycle events it leads to, if any.
+/// - `run_deletion`: Takes the next account deletion, processing its
+///   `Deleted` event.
+#[async_trait]
+pub trait AccountLifecyclePipes: Send + Sync {
+    async fn run(
+        &mut self,
+        metadata: &AccountMetadata,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    async fn run_deletion(
+        &mut self,
+        account_deletion: &AccountDeletion,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccountLifecyclePipes for AccountLifecyclePipe {
+    async fn run(
+        &mut self,
+        metadata: &AccountMetadata,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "AccountLifecyclePipe::run(metadata: {:?}, metrics)",
+            metadata
+        );
+
+        for event in self.tracker.push(metadata) {
+            let counter = match event {
+                AccountLifecycleEvent::OwnerChanged { .. } => "account_owner_changes",
+                AccountLifecycleEvent::Closed { .. } => "account_closures",
+                AccountLifecycleEvent::Deleted { .. } => "account_deletions_tracked",
+            };
+            metrics.increment_counter(counter, 1).await?;
+            self.processor.process(event, metrics.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn run_deletion(
+        &mut self,
+        account_deletion: &AccountDeletion,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "AccountLifecyclePipe::run_deletion(account_deletion: {:?}, metrics)",
+            account_deletion
+        );
+
+        let event = self.tracker.delete(account_deletion);
+        metrics
+            .increment_counter("account_deletions_tracked", 1)
+            .await?;
+        self.processor.process(event, metrics).await
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::trace::TraceId};
+
+    fn metadata(pubkey: Pubkey, slot: u64, owner: Pubkey, lamports: u64) -> AccountMetadata {
+        AccountMetadata {
+            slot,
+            pubkey,
+            trace_id: TraceId::new(),
+            commitment: None,
+            write_version: None,
+            lamports,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_tracker_detects_owner_changes_and_closures() {
+        // Arrange
+        let mut tracker = AccountLifecycleTracker::new();
+        let pubkey = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+
+        // Act
+        let created = tracker.push(&metadata(pubkey, 1, program, 1_000));
+        let reassigned = tracker.push(&metadata(pubkey, 2, other_program, 1_000));
+        let closed = tracker.push(&metadata(pubkey, 3, other_program, 0));
+        let closed_again = tracker.push(&metadata(pubkey, 4, other_program, 0));
+        let deleted = tracker.delete(&AccountDeletion { pubkey, slot: 5 });
+
+        // Assert
+        assert!(created.is_empty());
+        assert_eq!(
+            reassigned,
+            vec![AccountLifecycleEvent::OwnerChanged {
+                pubkey,
+                slot: 2,
+                previous_owner: program,
+                owner: other_program,
+            }]
+        );
+        assert_eq!(
+            closed,
+            vec![AccountLifecycleEvent::Closed {
+                pubkey,
+                slot: 3,
+                owner: other_program,
+            }]
+        );
+        assert!(closed_again.is_empty());
+        assert_eq!(deleted, AccountLifecycleEvent::Deleted { pubkey, slot: 5 });
+        assert!(tracker.is_empty());
+    }
+}
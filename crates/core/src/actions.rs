@@ -0,0 +1,172 @@
+//! Detects the Solana Actions behind transactions, such as transactions built
+//! through a Blink.
+//!
+//! Action providers identify the transactions they build with a memo
+//! instruction of the form
+//! `solana-action:<identity>:<reference>:<identity signature>`, where the
+//! identity is the provider's key, the reference a key unique to the
+//! transaction, and the signature the identity's signature of the reference.
+//! With `PipelineBuilder::detect_actions`, every `TransactionMetadata` gets
+//! the `ActionIdentifier`s parsed from its memos, which processors read
+//! through `InstructionMetadata::actions`, for example to attribute swaps to
+//! the Blink that drove them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(JupiterSwapDecoder, SwapAttributionProcessor)
+//!     .detect_actions()
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! // In the processor:
+//! for action in metadata.actions().iter().filter(|action| action.verify()) {
+//!     log::info!("swap driven by action provider {}", action.identity);
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Only top-level memo instructions are parsed, as action providers add
+//!   their memo to the transaction itself.
+//! - A memo can be written by anyone: check `verify` and that the identity is
+//!   one you know before trusting the identifier.
+
+use {
+    crate::transaction::TransactionMetadata, solana_pubkey::Pubkey,
+    solana_sdk::signature::Signature, std::str::FromStr,
+};
+
+/// The scheme prefixing action identifier memos.
+pub const ACTION_MEMO_PREFIX: &str = "solana-action:";
+
+/// The memo programs, v2 then v1.
+pub const MEMO_PROGRAM_IDS: [Pubkey; 2] = [
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    Pubkey::from_str_const("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"),
+];
+
+/// The identifier an action provider attached to a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionIdentifier {
+    /// The key of the action provider.
+    pub identity: Pubkey,
+    /// The key identifying the transaction for the provider.
+    pub reference: Pubkey,
+    /// The signature of the reference by the identity.
+    pub signature: Signature,
+    /// The index of the memo instruction in the transaction.
+    pub instruction_index: usize,
+}
+
+impl ActionIdentifier {
+    /// Parses an action identifier memo, returning `None` if `memo` is not
+    /// one.
+    pub fn parse(memo: &str, instruction_index: usize) -> Option<Self> {
+        let mut parts = memo.trim().strip_prefix(ACTION_MEMO_PREFIX)?.split(':');
+
+        let identifier = Self {
+            identity: Pubkey::from_str(parts.next()?).ok()?,
+            reference: Pubkey::from_str(parts.next()?).ok()?,
+            signature: Signature::from_str(parts.next()?).ok()?,
+            instruction_index,
+        };
+
+        parts.next().is_none().then_some(identifier)
+    }
+
+    /// Returns whether `signature` is the identity's signature of the
+    /// reference.
+    pub fn verify(&self) -> bool {
+        self.signature
+            .verify(self.identity.as_ref(), self.reference.as_ref())
+    }
+}
+
+/// Returns the action identifiers of the memo instructions of a transaction.
+pub fn extract(transaction_metadata: &TransactionMetadata) -> Vec<ActionIdentifier> {
+    let account_keys = transaction_metadata.account_keys();
+
+    transaction_metadata
+        .message
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| {
+            account_keys
+                .get(instruction.program_id_index as usize)
+                .is_some_and(|program_id| MEMO_PROGRAM_IDS.contains(program_id))
+        })
+        .filter_map(|(instruction_index, instruction)| {
+            let memo = std::str::from_utf8(&instruction.data).ok()?;
+            ActionIdentifier::parse(memo, instruction_index)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_program::message::{legacy, VersionedMessage},
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            signature::{Keypair, Signer},
+        },
+    };
+
+    #[test]
+    fn test_extract_action_identifier_memo() {
+        // Arrange
+        let identity = Keypair::new();
+        let reference = Pubkey::new_unique();
+        let signature = identity.sign_message(reference.as_ref());
+        let memo = format!(
+            "solana-action:{}:{}:{}",
+            identity.pubkey(),
+            reference,
+            signature
+        );
+
+        let transaction_metadata = TransactionMetadata {
+            message: VersionedMessage::Legacy(legacy::Message {
+                account_keys: vec![Pubkey::new_unique(), MEMO_PROGRAM_IDS[1]],
+                instructions: vec![
+                    CompiledInstruction::new_from_raw_parts(1, b"gm".to_vec(), vec![]),
+                    CompiledInstruction::new_from_raw_parts(1, memo.into_bytes(), vec![]),
+                ],
+                ..legacy::Message::default()
+            }),
+            ..TransactionMetadata::default()
+        };
+
+        // Act
+        let actions = extract(&transaction_metadata);
+
+        // Assert
+        assert_eq!(
+            actions,
+            vec![ActionIdentifier {
+                identity: identity.pubkey(),
+                reference,
+                signature,
+                instruction_index: 1,
+            }]
+        );
+        assert!(actions[0].verify());
+        assert!(!ActionIdentifier {
+            reference: Pubkey::new_unique(),
+            ..actions[0].clone()
+        }
+        .verify());
+    }
+
+    #[test]
+    fn test_parse_rejects_other_memos() {
+        assert_eq!(ActionIdentifier::parse("solana-action:not-a-key", 0), None);
+        assert_eq!(ActionIdentifier::parse("hello", 0), None);
+    }
+}
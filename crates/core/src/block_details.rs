@@ -0,0 +1,103 @@
+//! Provides structures and traits for handling block details within the
+//! pipeline.
+//!
+//! This module defines the `BlockDetailsPipe` struct and `BlockDetailsPipes`
+//! trait, which process `BlockDetails` events. Block details carry the
+//! metadata of a block, such as its parent slot, height, time and rewards,
+//! without its transactions, which are delivered separately as transaction
+//! updates.
+
+use {
+    crate::{
+        datasource::BlockDetails,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+};
+
+/// A processing pipe for handling block details.
+///
+/// The `BlockDetailsPipe` passes each `BlockDetails` to a user-defined
+/// `Processor`.
+///
+/// # Example
+///
+/// ```rust
+/// struct MyBlockProcessor;
+///
+/// #[async_trait]
+/// impl Processor for MyBlockProcessor {
+///     type InputType = BlockDetails;
+///
+///     async fn process(
+///         &mut self,
+///         block_details: BlockDetails,
+///         metrics: Arc<MetricsCollection>,
+///     ) -> CarbonResult<()> {
+///         if let Some(leader) = block_details.leader() {
+///             // Record the rewards earned by the leader
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// ## Fields
+///
+/// - `processor`: A boxed `Processor` that handles block details.
+pub struct BlockDetailsPipe {
+    pub processor: Box<dyn Processor<InputType = BlockDetails> + Send + Sync>,
+}
+
+/// A trait for handling block details in the pipeline.
+///
+/// # Required Methods
+///
+/// - `run`: Processes a `BlockDetails`, tracking the operation with metrics.
+#[async_trait]
+pub trait BlockDetailsPipes: Send + Sync {
+    async fn run(
+        &mut self,
+        block_details: BlockDetails,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BlockDetailsPipes for BlockDetailsPipe {
+    async fn run(
+        &mut self,
+        block_details: BlockDetails,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "BlockDetailsPipe::run(block_details: {:?}, metrics)",
+            block_details,
+        );
+
+        self.processor.process(block_details, metrics).await?;
+
+        Ok(())
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
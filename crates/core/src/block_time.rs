@@ -0,0 +1,246 @@
+//! Fills in the block time of transactions delivered without one.
+//!
+//! Some datasources deliver transactions without their block time, while
+//! sinks almost always need a timestamp. A `BlockTimeEnricher` wraps such a
+//! datasource and resolves the missing block times through the RPC
+//! `getBlockTime` method, so every `TransactionMetadata` carries one.
+//!
+//! Lookups are cached by slot and batched: the updates already waiting in the
+//! channel are taken together, and each missing slot among them is resolved
+//! once, concurrently with the others.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let datasource = BlockTimeEnricher::new(
+//!     my_datasource,
+//!     "https://api.mainnet-beta.solana.com".to_string(),
+//! );
+//!
+//! Pipeline::builder()
+//!     .datasource(datasource)
+//!     .transaction(MY_SCHEMA.clone(), MyTransactionProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Transactions whose block time could not be resolved are forwarded
+//!   without one, and counted by the `block_time_lookups_failed` counter.
+//! - Updates are forwarded in the order they were received.
+
+use {
+    crate::{
+        datasource::{Capabilities, Datasource, Update},
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    std::{
+        collections::{HashMap, HashSet, VecDeque},
+        sync::Arc,
+    },
+    tokio::sync::mpsc,
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default number of block times kept in the cache.
+pub const DEFAULT_BLOCK_TIME_CACHE_SIZE: usize = 1_024;
+
+/// The default maximum number of updates enriched together.
+pub const DEFAULT_BLOCK_TIME_BATCH_SIZE: usize = 256;
+
+/// A datasource resolving the block time of transactions missing one.
+pub struct BlockTimeEnricher<D> {
+    datasource: D,
+    rpc_url: String,
+    cache_size: usize,
+    batch_size: usize,
+}
+
+impl<D: Datasource> BlockTimeEnricher<D> {
+    pub fn new(datasource: D, rpc_url: String) -> Self {
+        Self {
+            datasource,
+            rpc_url,
+            cache_size: DEFAULT_BLOCK_TIME_CACHE_SIZE,
+            batch_size: DEFAULT_BLOCK_TIME_BATCH_SIZE,
+        }
+    }
+
+    /// Sets how many block times are cached. Defaults to 1024.
+    pub fn cache_size(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of updates enriched together. Defaults to 256.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+/// A cache of block times by slot, evicting the oldest slots first.
+#[derive(Debug)]
+pub(crate) struct BlockTimeCache {
+    block_times: HashMap<u64, i64>,
+    slots: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl BlockTimeCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            block_times: HashMap::new(),
+            slots: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    pub(crate) fn get(&self, slot: u64) -> Option<i64> {
+        self.block_times.get(&slot).copied()
+    }
+
+    pub(crate) fn insert(&mut self, slot: u64, block_time: i64) {
+        if self.block_times.insert(slot, block_time).is_some() {
+            return;
+        }
+
+        self.slots.push_back(slot);
+        if self.slots.len() > self.capacity {
+            if let Some(evicted) = self.slots.pop_front() {
+                self.block_times.remove(&evicted);
+            }
+        }
+    }
+}
+
+/// Resolves the block times missing from the batch and sets them on its
+/// transactions.
+async fn enrich(
+    batch: &mut [Update],
+    rpc_client: &RpcClient,
+    cache: &mut BlockTimeCache,
+    metrics: &MetricsCollection,
+) -> CarbonResult<()> {
+    let missing_slots: HashSet<u64> = batch
+        .iter()
+        .filter_map(|update| match update {
+            Update::Transaction(transaction_update) if transaction_update.block_time.is_none() => {
+                Some(transaction_update.slot)
+            }
+            _ => None,
+        })
+        .filter(|slot| cache.get(*slot).is_none())
+        .collect();
+
+    let lookups = futures::future::join_all(
+        missing_slots
+            .into_iter()
+            .map(|slot| async move { (slot, rpc_client.get_block_time(slot).await) }),
+    )
+    .await;
+
+    for (slot, result) in lookups {
+        match result {
+            Ok(block_time) => cache.insert(slot, block_time),
+            Err(error) => {
+                log::warn!("Failed to fetch block time of slot {}: {}", slot, error);
+                metrics
+                    .increment_counter("block_time_lookups_failed", 1)
+                    .await?;
+            }
+        }
+    }
+
+    for update in batch.iter_mut() {
+        if let Update::Transaction(transaction_update) = update {
+            if transaction_update.block_time.is_none() {
+                transaction_update.block_time = cache.get(transaction_update.slot);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<D: Datasource> Datasource for BlockTimeEnricher<D> {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (inner_sender, mut inner_receiver) = mpsc::channel::<Update>(sender.max_capacity());
+        let rpc_client = RpcClient::new(self.rpc_url.clone());
+        let mut cache = BlockTimeCache::new(self.cache_size);
+
+        let forward = async {
+            let mut batch = Vec::with_capacity(self.batch_size);
+
+            while inner_receiver.recv_many(&mut batch, self.batch_size).await > 0 {
+                enrich(&mut batch, &rpc_client, &mut cache, &metrics).await?;
+
+                for update in batch.drain(..) {
+                    if sender.send(update).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            Ok::<(), Error>(())
+        };
+
+        let consume = async {
+            let result = self
+                .datasource
+                .consume(&inner_sender, cancellation_token, metrics.clone())
+                .await;
+            drop(inner_sender);
+            result
+        };
+
+        let (consume_result, forward_result) = tokio::join!(consume, forward);
+        consume_result?;
+        forward_result
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.datasource.capabilities()
+    }
+
+    fn validate(&self) -> CarbonResult<()> {
+        self.datasource.validate()
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.datasource.is_bounded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_time_cache_evicts_oldest_slot() {
+        // Arrange
+        let mut cache = BlockTimeCache::new(2);
+
+        // Act
+        cache.insert(1, 100);
+        cache.insert(2, 101);
+        cache.insert(1, 100);
+        cache.insert(3, 102);
+
+        // Assert
+        assert_eq!(cache.get(1), None);
+        assert_eq!(cache.get(2), Some(101));
+        assert_eq!(cache.get(3), Some(102));
+    }
+}
@@ -0,0 +1,325 @@
+//! Groups the transactions of Jito bundles, for searcher analytics.
+//!
+//! Jito bundles are sequences of up to five transactions landed together and
+//! in order, paying a tip to one of the Jito tip accounts. A `BundlePipe`
+//! detects them in the transactions of the pipeline: a successful transaction
+//! increasing the balance of a tip account closes a bundle made of itself and
+//! the transactions received right before it in the same slot. Each bundle is
+//! passed to the pipe's processor as a `BundleUpdate`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct BundleProcessor;
+//!
+//! #[async_trait]
+//! impl Processor for BundleProcessor {
+//!     type InputType = BundleUpdate;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         bundle: BundleUpdate,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         log::info!(
+//!             "bundle of {} transactions tipping {} lamports in slot {}",
+//!             bundle.transactions.len(),
+//!             bundle.tip_lamports,
+//!             bundle.slot
+//!         );
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_block_datasource)
+//!     .bundles(BundleProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Bundles are not recorded on chain, so detection is a heuristic. It
+//!   expects transactions in block order, as block datasources deliver them,
+//!   and the tip in the last transaction of the bundle, as Jito recommends.
+//!   A bundle takes at most the `max_bundle_size - 1` transactions before its
+//!   tip transaction, and stops at a vote, a failed transaction, another tip
+//!   transaction or the start of the slot.
+//! - Transactions skipped by the `TransactionLimits` are not seen by bundle
+//!   pipes.
+
+use {
+    crate::{
+        datasource::TransactionUpdate,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{collections::VecDeque, sync::Arc},
+};
+
+/// The maximum number of transactions in a Jito bundle.
+pub const DEFAULT_MAX_BUNDLE_SIZE: usize = 5;
+
+/// The accounts receiving Jito tips on mainnet-beta.
+pub const JITO_TIP_ACCOUNTS: [Pubkey; 8] = [
+    Pubkey::from_str_const("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5"),
+    Pubkey::from_str_const("HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe"),
+    Pubkey::from_str_const("Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY"),
+    Pubkey::from_str_const("ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49"),
+    Pubkey::from_str_const("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh"),
+    Pubkey::from_str_const("ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt"),
+    Pubkey::from_str_const("DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL"),
+    Pubkey::from_str_const("3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT"),
+];
+
+/// The transactions of a detected bundle, in block order, the tip transaction
+/// last.
+#[derive(Debug, Clone)]
+pub struct BundleUpdate {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub transactions: Vec<TransactionUpdate>,
+    pub tip_account: Pubkey,
+    pub tip_lamports: u64,
+}
+
+/// Returns the tip account a successful transaction paid, and the tip, if
+/// any.
+pub fn jito_tip(transaction_update: &TransactionUpdate) -> Option<(Pubkey, u64)> {
+    if transaction_update.meta.status.is_err() {
+        return None;
+    }
+
+    let meta = &transaction_update.meta;
+    transaction_update
+        .transaction
+        .message
+        .static_account_keys()
+        .iter()
+        .chain(meta.loaded_addresses.writable.iter())
+        .zip(meta.pre_balances.iter().zip(meta.post_balances.iter()))
+        .find_map(|(pubkey, (pre_balance, post_balance))| {
+            (JITO_TIP_ACCOUNTS.contains(pubkey) && post_balance > pre_balance)
+                .then(|| (*pubkey, post_balance - pre_balance))
+        })
+}
+
+/// Detects bundles in a stream of transactions, in block order.
+#[derive(Debug)]
+pub struct BundleDetector {
+    max_bundle_size: usize,
+    slot: Option<u64>,
+    pending: VecDeque<TransactionUpdate>,
+}
+
+impl BundleDetector {
+    pub fn new() -> Self {
+        Self {
+            max_bundle_size: DEFAULT_MAX_BUNDLE_SIZE,
+            slot: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Sets the maximum number of transactions of a bundle, tip transaction
+    /// included. Defaults to 5.
+    pub fn max_bundle_size(mut self, max_bundle_size: usize) -> Self {
+        self.max_bundle_size = max_bundle_size.max(1);
+        self
+    }
+
+    /// Takes the next transaction, returning the bundle it closes, if any.
+    pub fn push(&mut self, transaction_update: &TransactionUpdate) -> Option<BundleUpdate> {
+        if self.slot != Some(transaction_update.slot) {
+            self.slot = Some(transaction_update.slot);
+            self.pending.clear();
+        }
+
+        if transaction_update.is_vote || transaction_update.meta.status.is_err() {
+            self.pending.clear();
+            return None;
+        }
+
+        let Some((tip_account, tip_lamports)) = jito_tip(transaction_update) else {
+            self.pending.push_back(transaction_update.clone());
+            if self.pending.len() >= self.max_bundle_size {
+                self.pending.pop_front();
+            }
+            return None;
+        };
+
+        let mut transactions: Vec<TransactionUpdate> = self.pending.drain(..).collect();
+        transactions.push(transaction_update.clone());
+
+        Some(BundleUpdate {
+            slot: transaction_update.slot,
+            block_time: transaction_update.block_time,
+            transactions,
+            tip_account,
+            tip_lamports,
+        })
+    }
+}
+
+impl Default for BundleDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A processing pipe passing the detected bundles to a processor.
+pub struct BundlePipe {
+    pub detector: BundleDetector,
+    pub processor: Box<dyn Processor<InputType = BundleUpdate> + Send + Sync>,
+}
+
+/// A trait for the pipes grouping transactions into bundles.
+///
+/// # Required Methods
+///
+/// - `run`: Takes the next transaction, processing the bundle it closes, if
+///   any.
+#[async_trait]
+pub trait BundlePipes: Send + Sync {
+    async fn run(
+        &mut self,
+        transaction_update: &TransactionUpdate,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BundlePipes for BundlePipe {
+    async fn run(
+        &mut self,
+        transaction_update: &TransactionUpdate,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "BundlePipe::run(transaction_update: {:?}, metrics)",
+            transaction_update.signature,
+        );
+
+        if let Some(bundle) = self.detector.push(transaction_update) {
+            metrics.increment_counter("bundles_detected", 1).await?;
+            self.processor.process(bundle, metrics).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            message::{Message, VersionedMessage},
+            signature::Signature,
+            transaction::{TransactionError, VersionedTransaction},
+        },
+        solana_transaction_status::TransactionStatusMeta,
+    };
+
+    fn transaction_update(slot: u64, tip: Option<u64>, failed: bool) -> TransactionUpdate {
+        let tip_account = JITO_TIP_ACCOUNTS[3];
+
+        TransactionUpdate {
+            signature: Signature::new_unique(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(Message {
+                    account_keys: vec![Pubkey::new_unique(), tip_account],
+                    ..Message::default()
+                }),
+            },
+            meta: TransactionStatusMeta {
+                status: if failed {
+                    Err(TransactionError::AccountInUse)
+                } else {
+                    Ok(())
+                },
+                pre_balances: vec![1_000_000, 5_000],
+                post_balances: vec![1_000_000, 5_000 + tip.unwrap_or(0)],
+                ..TransactionStatusMeta::default()
+            },
+            is_vote: false,
+            slot,
+            block_time: None,
+            commitment: None,
+        }
+    }
+
+    #[test]
+    fn test_detector_groups_transactions_before_the_tip() {
+        // Arrange
+        let mut detector = BundleDetector::new().max_bundle_size(3);
+        let transactions = [
+            transaction_update(9, None, false),
+            transaction_update(10, None, false),
+            transaction_update(10, None, false),
+            transaction_update(10, None, false),
+            transaction_update(10, Some(10_000), false),
+            transaction_update(10, None, true),
+            transaction_update(10, Some(2_000), false),
+        ];
+
+        // Act
+        let bundles: Vec<BundleUpdate> = transactions
+            .iter()
+            .filter_map(|transaction_update| detector.push(transaction_update))
+            .collect();
+
+        // Assert
+        assert_eq!(bundles.len(), 2);
+        assert_eq!(
+            bundles[0]
+                .transactions
+                .iter()
+                .map(|transaction| transaction.signature)
+                .collect::<Vec<_>>(),
+            vec![
+                transactions[2].signature,
+                transactions[3].signature,
+                transactions[4].signature
+            ]
+        );
+        assert_eq!(
+            (bundles[0].tip_account, bundles[0].tip_lamports),
+            (JITO_TIP_ACCOUNTS[3], 10_000)
+        );
+        assert_eq!(bundles[1].transactions.len(), 1);
+    }
+
+    #[test]
+    fn test_failed_transactions_pay_no_tip() {
+        assert_eq!(jito_tip(&transaction_update(1, Some(1_000), true)), None);
+        assert_eq!(
+            jito_tip(&transaction_update(1, Some(1_000), false)),
+            Some((JITO_TIP_ACCOUNTS[3], 1_000))
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Stops calling a failing processor for a while, so a broken external sink
+//! degrades one pipe instead of the whole pipeline.
+//!
+//! A `CircuitBreaker` wraps a processor and counts its failures over a
+//! window of time. When the error rate of a window exceeds a threshold, the
+//! circuit opens: updates are skipped rather than passed to the processor,
+//! until a retry delay elapses. The next update is then passed as a trial:
+//! the circuit closes if it succeeds, and opens again if it fails.
+//!
+//! Each opening and closing is logged and counted by the
+//! `circuit_breaker_opened` and `circuit_breaker_closed` counters, the
+//! `circuit_breaker_open` gauge is 1 while the circuit is open, and the
+//! skipped updates are counted by `circuit_breaker_skipped`. Given an
+//! `EventBus` with `event_bus`, the breaker also emits a `CircuitOpened` or
+//! `CircuitClosed` event naming its pipe.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = CircuitBreaker::new(PostgresSink::new(pool))
+//!     .error_rate(0.5)
+//!     .window(Duration::from_secs(30))
+//!     .retry_after(Duration::from_secs(60));
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, sink)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates skipped while the circuit is open are lost for the wrapped
+//!   processor. Pair the breaker with a dead letter processor or a replay
+//!   from a checkpoint if the sink must see every update.
+//! - Failures while the circuit is closed are still returned, so they are
+//!   counted and logged by the pipeline as before.
+
+use {
+    crate::{
+        clock::{system_clock, Clock},
+        error::CarbonResult,
+        event_bus::{EventBus, PipelineEvent},
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+};
+
+/// The default error rate of a window opening the circuit.
+pub const DEFAULT_ERROR_RATE: f64 = 0.5;
+
+/// The default minimum number of calls in a window before it can open the
+/// circuit.
+pub const DEFAULT_MIN_CALLS: u64 = 20;
+
+/// The default duration of a window.
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(60);
+
+/// The default delay before a trial call to an open circuit.
+pub const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// The state of a circuit.
+///
+/// - `Closed`: Updates are passed to the processor.
+/// - `Open`: Updates are skipped until `retry_at`.
+/// - `HalfOpen`: The next update is passed to the processor as a trial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open { retry_at: Instant },
+    HalfOpen,
+}
+
+/// A `Processor` wrapper skipping its processor while it fails too often.
+pub struct CircuitBreaker<P> {
+    processor: P,
+    error_rate: f64,
+    min_calls: u64,
+    window: Duration,
+    retry_after: Duration,
+    clock: Arc<dyn Clock>,
+    events: Option<(EventBus, String)>,
+    state: CircuitState,
+    window_start: Instant,
+    calls: u64,
+    errors: u64,
+}
+
+impl<P> CircuitBreaker<P> {
+    pub fn new(processor: P) -> Self {
+        let clock = system_clock();
+
+        Self {
+            processor,
+            error_rate: DEFAULT_ERROR_RATE,
+            min_calls: DEFAULT_MIN_CALLS,
+            window: DEFAULT_WINDOW,
+            retry_after: DEFAULT_RETRY_AFTER,
+            window_start: clock.now(),
+            clock,
+            events: None,
+            state: CircuitState::Closed,
+            calls: 0,
+            errors: 0,
+        }
+    }
+
+    /// Sets the error rate of a window, between 0 and 1, opening the circuit.
+    /// Defaults to 0.5.
+    pub fn error_rate(mut self, error_rate: f64) -> Self {
+        self.error_rate = error_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the minimum number of calls in a window before it can open the
+    /// circuit, so a few failures alone do not. Defaults to 20.
+    pub fn min_calls(mut self, min_calls: u64) -> Self {
+        self.min_calls = min_calls.max(1);
+        self
+    }
+
+    /// Sets the duration of the windows the error rate is measured over.
+    /// Defaults to 60 seconds.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets the delay before a trial call to an open circuit. Defaults to 30
+    /// seconds.
+    pub fn retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// Measures the windows and delays with `clock` rather than the system
+    /// time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.window_start = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Emits a `CircuitOpened` or `CircuitClosed` event on `event_bus` each
+    /// time the circuit opens or closes, naming the pipe `pipe`.
+    pub fn event_bus(mut self, event_bus: EventBus, pipe: impl Into<String>) -> Self {
+        self.events = Some((event_bus, pipe.into()));
+        self
+    }
+
+    /// Returns the state of the circuit.
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    fn reset_window(&mut self, now: Instant) {
+        self.window_start = now;
+        self.calls = 0;
+        self.errors = 0;
+    }
+
+    /// Records the outcome of a call, returning whether it opened the
+    /// circuit.
+    fn record(&mut self, failed: bool) -> bool {
+        let now = self.clock.now();
+
+        if self.state == CircuitState::HalfOpen {
+            self.reset_window(now);
+            if failed {
+                self.state = CircuitState::Open {
+                    retry_at: now + self.retry_after,
+                };
+                return true;
+            }
+            self.state = CircuitState::Closed;
+            return false;
+        }
+
+        if now.saturating_duration_since(self.window_start) >= self.window {
+            self.reset_window(now);
+        }
+        self.calls += 1;
+        if failed {
+            self.errors += 1;
+        }
+
+        if self.calls >= self.min_calls
+            && self.errors as f64 >= self.error_rate * self.calls as f64
+            && self.errors > 0
+        {
+            self.state = CircuitState::Open {
+                retry_at: now + self.retry_after,
+            };
+            self.reset_window(now);
+            return true;
+        }
+
+        false
+    }
+}
+
+#[async_trait]
+impl<P> Processor for CircuitBreaker<P>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if let CircuitState::Open { retry_at } = self.state {
+            if self.clock.now() < retry_at {
+                metrics
+                    .increment_counter("circuit_breaker_skipped", 1)
+                    .await?;
+                return Ok(());
+            }
+            self.state = CircuitState::HalfOpen;
+        }
+
+        let trial = self.state == CircuitState::HalfOpen;
+        let result = self.processor.process(data, metrics.clone()).await;
+
+        if self.record(result.is_err()) {
+            log::warn!(
+                "Circuit opened after {}, skipping updates for {:?}",
+                if trial {
+                    "a failed trial"
+                } else {
+                    "too many failures"
+                },
+                self.retry_after
+            );
+            metrics
+                .increment_counter("circuit_breaker_opened", 1)
+                .await?;
+            metrics.update_gauge("circuit_breaker_open", 1.0).await?;
+            if let Some((events, pipe)) = &self.events {
+                events.emit(PipelineEvent::CircuitOpened { pipe: pipe.clone() });
+            }
+        } else if trial {
+            log::info!("Circuit closed after a successful trial");
+            metrics
+                .increment_counter("circuit_breaker_closed", 1)
+                .await?;
+            metrics.update_gauge("circuit_breaker_open", 0.0).await?;
+            if let Some((events, pipe)) = &self.events {
+                events.emit(PipelineEvent::CircuitClosed { pipe: pipe.clone() });
+            }
+        }
+
+        result
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clock::ManualClock, error::Error},
+    };
+
+    /// Fails while its flag is set.
+    struct FlakySink(Arc<std::sync::atomic::AtomicBool>, u64);
+
+    #[async_trait]
+    impl Processor for FlakySink {
+        type InputType = ();
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.1 += 1;
+            if self.0.load(std::sync::atomic::Ordering::Relaxed) {
+                return Err(Error::Custom("sink unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_opens_and_closes_after_a_trial() {
+        // Arrange
+        let clock = ManualClock::new(0);
+        let failing = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let mut breaker = CircuitBreaker::new(FlakySink(failing.clone(), 0))
+            .min_calls(3)
+            .retry_after(Duration::from_secs(10))
+            .with_clock(Arc::new(clock.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        for _ in 0..3 {
+            assert!(breaker.process((), metrics.clone()).await.is_err());
+        }
+        let opened = breaker.state();
+        breaker.process((), metrics.clone()).await.unwrap();
+        let calls_while_open = breaker.processor.1;
+
+        clock.advance(Duration::from_secs(10));
+        failing.store(false, std::sync::atomic::Ordering::Relaxed);
+        breaker.process((), metrics.clone()).await.unwrap();
+
+        // Assert
+        assert!(matches!(opened, CircuitState::Open { .. }));
+        assert_eq!(calls_while_open, 3);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.processor.1, 4);
+    }
+}
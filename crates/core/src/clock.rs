@@ -0,0 +1,198 @@
+//! Abstracts the current time and sleeping, so time-based features can be
+//! tested deterministically.
+//!
+//! Components measuring time, such as the flush timer of the pipeline, the
+//! one-second windows of `SampledProcessor`, the windows of
+//! `RateOfChangeProcessor` and the buckets of `InstructionStatsProcessor`,
+//! read it from a `Clock`. `SystemClock`, used by default, reads the system
+//! time. `ManualClock` only moves when advanced, which lets tests step
+//! through windows and timers without waiting.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let clock = ManualClock::new(1_700_000_000);
+//! let mut processor = SampledProcessor::new(MyProcessor, Sampling::MaxPerSecond(10))
+//!     .with_clock(Arc::new(clock.clone()));
+//!
+//! // ... process 10 updates, the 11th is sampled out ...
+//!
+//! clock.advance(Duration::from_secs(1));
+//!
+//! // ... the next update starts a new window ...
+//! ```
+
+use {
+    async_trait::async_trait,
+    std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
+    tokio::sync::oneshot,
+};
+
+/// A source of time.
+#[async_trait]
+pub trait Clock: Send + Sync {
+    /// Returns the current monotonic time, used to measure durations.
+    fn now(&self) -> Instant;
+
+    /// Returns the current time, in seconds since the Unix epoch.
+    fn unix_timestamp(&self) -> i64;
+
+    /// Waits until `duration` has elapsed on this clock.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// A `Clock` reading the system time and sleeping with Tokio.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn unix_timestamp(&self) -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+struct ManualClockState {
+    start: Instant,
+    start_unix_timestamp: i64,
+    elapsed: Duration,
+    /// The pending sleeps, by the elapsed time they end at.
+    sleepers: Vec<(Duration, oneshot::Sender<()>)>,
+}
+
+/// A `Clock` moving only when advanced, for tests.
+///
+/// Clones share the same time, so a test can keep a clone to advance the
+/// clock given to the tested component.
+#[derive(Clone)]
+pub struct ManualClock {
+    state: Arc<Mutex<ManualClockState>>,
+}
+
+impl ManualClock {
+    /// Creates a clock starting at `unix_timestamp`, in seconds since the Unix
+    /// epoch.
+    pub fn new(unix_timestamp: i64) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ManualClockState {
+                start: Instant::now(),
+                start_unix_timestamp: unix_timestamp,
+                elapsed: Duration::ZERO,
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `duration`, waking the sleeps ending by
+    /// then.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.elapsed += duration;
+
+        let elapsed = state.elapsed;
+        let (woken, pending) = std::mem::take(&mut state.sleepers)
+            .into_iter()
+            .partition(|(until, _)| *until <= elapsed);
+        state.sleepers = pending;
+
+        for (_, sender) in woken {
+            let _ = sender.send(());
+        }
+    }
+
+    /// Returns how far the clock was advanced since it was created.
+    pub fn elapsed(&self) -> Duration {
+        self.lock().elapsed
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ManualClockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl std::fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManualClock")
+            .field("elapsed", &self.elapsed())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        let state = self.lock();
+        state.start + state.elapsed
+    }
+
+    fn unix_timestamp(&self) -> i64 {
+        let state = self.lock();
+        state.start_unix_timestamp + state.elapsed.as_secs() as i64
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        if duration.is_zero() {
+            return;
+        }
+
+        let receiver = {
+            let mut state = self.lock();
+            let (sender, receiver) = oneshot::channel();
+            let until = state.elapsed + duration;
+            state.sleepers.push((until, sender));
+            receiver
+        };
+
+        let _ = receiver.await;
+    }
+}
+
+/// Returns the default clock, reading the system time.
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_manual_clock_wakes_sleeps_when_advanced() {
+        // Arrange
+        let clock = ManualClock::new(1_000);
+        let start = clock.now();
+        let sleep = tokio::spawn({
+            let clock = clock.clone();
+            async move { clock.sleep(Duration::from_secs(10)).await }
+        });
+        tokio::task::yield_now().await;
+
+        // Act
+        clock.advance(Duration::from_secs(5));
+        tokio::task::yield_now().await;
+        let woken_early = sleep.is_finished();
+        clock.advance(Duration::from_secs(5));
+
+        // Assert
+        assert!(!woken_early);
+        sleep.await.unwrap();
+        assert_eq!(clock.now() - start, Duration::from_secs(10));
+        assert_eq!(clock.unix_timestamp(), 1_010);
+    }
+}
@@ -0,0 +1,252 @@
+//! Processes the updates of a pipe concurrently, with a limit on the updates
+//! in flight.
+//!
+//! The pipeline processes updates one at a time, so a slow destination, such
+//! as a database written to over the network, bounds the throughput of the
+//! whole pipeline. `Concurrent` wraps the processor of a pipe and processes
+//! each update in a task of its own, up to the number of updates allowed in
+//! flight by its `ConcurrencyLimit`. Once the limit is reached, the pipeline
+//! waits for an update to complete before passing on the next one.
+//!
+//! A `ConcurrencyLimit` is a shared semaphore: its clones share the same
+//! permits, so the pipes writing to the same destination can be given one
+//! limit together, and the pipes of other destinations limits of their own.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let database = ConcurrencyLimit::new(8);
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, Concurrent::new(pumpfun_sink, database.clone()))
+//!     .instruction(RaydiumAmmV4Decoder, Concurrent::new(raydium_sink, database))
+//!     .instruction(PumpfunDecoder, Concurrent::new(alerts, ConcurrencyLimit::new(100)))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates are processed by clones of the processor, which must share
+//!   their destination, as sinks holding a connection pool do. Lifecycle
+//!   hooks are run on the processor itself.
+//! - Updates in flight complete in any order: processors relying on the
+//!   order of updates, such as those keeping the latest state of accounts,
+//!   should not be wrapped.
+//! - The error of an update is returned when processing a later update or
+//!   when flushing, as is a panic. Flushing waits for every update in flight.
+//! - Updates that had to wait for a permit are counted by the
+//!   `concurrency_limit_waits` counter.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    tokio::{
+        sync::{OwnedSemaphorePermit, Semaphore},
+        task::{JoinError, JoinSet},
+    },
+};
+
+/// A limit on the number of updates in flight, shared by its clones.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimit {
+    semaphore: Arc<Semaphore>,
+    max_in_flight: usize,
+}
+
+impl ConcurrencyLimit {
+    /// Creates a limit allowing `max_in_flight` updates in flight, at least
+    /// one.
+    pub fn new(max_in_flight: usize) -> Self {
+        let max_in_flight = max_in_flight.max(1);
+
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_in_flight)),
+            max_in_flight,
+        }
+    }
+
+    /// Returns the number of updates allowed in flight.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Returns the number of updates in flight, across the clones of the
+    /// limit.
+    pub fn in_flight(&self) -> usize {
+        self.max_in_flight - self.semaphore.available_permits()
+    }
+
+    /// Waits for a permit, returning it with whether it had to wait.
+    async fn acquire(&self) -> (OwnedSemaphorePermit, bool) {
+        if let Ok(permit) = self.semaphore.clone().try_acquire_owned() {
+            return (permit, false);
+        }
+
+        let permit = self
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("the semaphore is never closed");
+
+        (permit, true)
+    }
+}
+
+/// A processor wrapper processing updates concurrently, up to the updates in
+/// flight allowed by its `ConcurrencyLimit`.
+pub struct Concurrent<P> {
+    processor: P,
+    limit: ConcurrencyLimit,
+    tasks: JoinSet<CarbonResult<()>>,
+}
+
+impl<P> Concurrent<P> {
+    pub fn new(processor: P, limit: ConcurrencyLimit) -> Self {
+        Self {
+            processor,
+            limit,
+            tasks: JoinSet::new(),
+        }
+    }
+
+    /// Returns the first error of the completed updates, if any, without
+    /// waiting for the others.
+    fn completed(&mut self) -> CarbonResult<()> {
+        while let Some(result) = self.tasks.try_join_next() {
+            result.map_err(task_error)??;
+        }
+
+        Ok(())
+    }
+
+    /// Waits for every update in flight, returning the first error, if any.
+    async fn wait_in_flight(&mut self) -> CarbonResult<()> {
+        let mut first_error = None;
+
+        while let Some(result) = self.tasks.join_next().await {
+            if let Err(error) = result.map_err(task_error).and_then(|result| result) {
+                first_error.get_or_insert(error);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+fn task_error(error: JoinError) -> Error {
+    Error::Custom(format!("Concurrent processing task failed: {}", error))
+}
+
+#[async_trait]
+impl<P> Processor for Concurrent<P>
+where
+    P: Processor + Clone + Send + Sync + 'static,
+    P::InputType: Send + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        self.completed()?;
+
+        let (permit, waited) = self.limit.acquire().await;
+        if waited {
+            metrics
+                .increment_counter("concurrency_limit_waits", 1)
+                .await?;
+        }
+
+        let mut processor = self.processor.clone();
+        self.tasks.spawn(async move {
+            let _permit = permit;
+            processor.process(data, metrics).await
+        });
+
+        Ok(())
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.wait_in_flight().await?;
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.wait_in_flight().await?;
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{
+            sync::atomic::{AtomicUsize, Ordering},
+            time::Duration,
+        },
+    };
+
+    #[derive(Clone, Default)]
+    struct Slow {
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+        processed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Processor for Slow {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.processed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_respects_the_shared_limit() {
+        // Arrange
+        let slow = Slow::default();
+        let limit = ConcurrencyLimit::new(3);
+        let mut first = Concurrent::new(slow.clone(), limit.clone());
+        let mut second = Concurrent::new(slow.clone(), limit.clone());
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        for update in 0..10 {
+            first.process(update, metrics.clone()).await.unwrap();
+            second.process(update, metrics.clone()).await.unwrap();
+        }
+        first.flush(metrics.clone()).await.unwrap();
+        second.flush(metrics).await.unwrap();
+
+        // Assert
+        assert_eq!(slow.processed.load(Ordering::SeqCst), 20);
+        assert_eq!(slow.max_in_flight.load(Ordering::SeqCst), 3);
+        assert_eq!(limit.in_flight(), 0);
+    }
+}
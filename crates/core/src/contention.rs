@@ -0,0 +1,300 @@
+//! Reports the accounts write-locked by the most transactions of each slot.
+//!
+//! Transactions writing the same account cannot run in parallel, so an
+//! account write-locked by many transactions of a slot serializes them and
+//! delays its program's users. A `ContentionPipe` counts, per slot, the
+//! transactions write-locking each account, and passes a `ContentionReport`
+//! of the most contended accounts to its processor when the slot ends, for
+//! protocol teams to find the hot accounts worth splitting.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct HotAccounts;
+//!
+//! #[async_trait]
+//! impl Processor for HotAccounts {
+//!     type InputType = ContentionReport;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         report: ContentionReport,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         for account in &report.accounts {
+//!             log::info!(
+//!                 "{} write-locked by {} of the {} transactions of slot {}",
+//!                 account.pubkey,
+//!                 account.write_locks,
+//!                 report.transactions,
+//!                 report.slot
+//!             );
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_block_datasource)
+//!     .write_lock_contention(10, HotAccounts)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - A slot ends when a transaction of another slot is received, and the last
+//!   slot when the pipeline shuts down. The datasource must deliver the
+//!   transactions of a slot together, as block datasources do.
+//! - Failed transactions are counted, as they hold their locks too, and
+//!   counted again in `failed_write_locks`. Transactions skipped by the
+//!   `TransactionLimits` are not seen by the pipe.
+
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::NestedInstruction,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+        transaction::{TransactionMetadata, TransactionPipes},
+    },
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, sync::Arc},
+};
+
+/// The default number of accounts in a `ContentionReport`.
+pub const DEFAULT_CONTENDED_ACCOUNTS: usize = 10;
+
+/// The transactions of a slot write-locking an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountContention {
+    pub pubkey: Pubkey,
+    pub write_locks: u64,
+    /// The write locks held by failed transactions.
+    pub failed_write_locks: u64,
+}
+
+/// The most write-locked accounts of a slot, most locked first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentionReport {
+    pub slot: u64,
+    /// The number of transactions of the slot received.
+    pub transactions: u64,
+    pub accounts: Vec<AccountContention>,
+}
+
+/// Counts the write locks of each account over the transactions of a slot.
+#[derive(Debug)]
+pub struct ContentionTracker {
+    max_accounts: usize,
+    slot: Option<u64>,
+    transactions: u64,
+    write_locks: HashMap<Pubkey, (u64, u64)>,
+}
+
+impl ContentionTracker {
+    /// Creates a tracker reporting the `max_accounts` most write-locked
+    /// accounts of each slot.
+    pub fn new(max_accounts: usize) -> Self {
+        Self {
+            max_accounts,
+            slot: None,
+            transactions: 0,
+            write_locks: HashMap::new(),
+        }
+    }
+
+    /// Takes the next transaction, returning the report of the slot it ends,
+    /// if any.
+    pub fn push(&mut self, transaction_metadata: &TransactionMetadata) -> Option<ContentionReport> {
+        let report = match self.slot {
+            Some(slot) if slot != transaction_metadata.slot => self.finish(),
+            _ => None,
+        };
+
+        self.slot = Some(transaction_metadata.slot);
+        self.transactions += 1;
+
+        let failed = transaction_metadata.meta.status.is_err();
+        for pubkey in transaction_metadata.writable_accounts() {
+            let (write_locks, failed_write_locks) = self.write_locks.entry(pubkey).or_default();
+            *write_locks += 1;
+            if failed {
+                *failed_write_locks += 1;
+            }
+        }
+
+        report
+    }
+
+    /// Ends the current slot, returning its report, if any transaction was
+    /// received.
+    pub fn finish(&mut self) -> Option<ContentionReport> {
+        let slot = self.slot.take()?;
+
+        let mut accounts: Vec<AccountContention> = self
+            .write_locks
+            .drain()
+            .map(
+                |(pubkey, (write_locks, failed_write_locks))| AccountContention {
+                    pubkey,
+                    write_locks,
+                    failed_write_locks,
+                },
+            )
+            .collect();
+        accounts.sort_by(|a, b| {
+            b.write_locks
+                .cmp(&a.write_locks)
+                .then_with(|| a.pubkey.cmp(&b.pubkey))
+        });
+        accounts.truncate(self.max_accounts);
+
+        Some(ContentionReport {
+            slot,
+            transactions: std::mem::take(&mut self.transactions),
+            accounts,
+        })
+    }
+}
+
+impl Default for ContentionTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONTENDED_ACCOUNTS)
+    }
+}
+
+/// A transaction pipe passing the contention report of each slot to a
+/// processor.
+pub struct ContentionPipe {
+    pub tracker: ContentionTracker,
+    pub processor: Box<dyn Processor<InputType = ContentionReport> + Send + Sync>,
+}
+
+impl ContentionPipe {
+    async fn report(
+        &mut self,
+        report: Option<ContentionReport>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(report) = report else {
+            return Ok(());
+        };
+
+        metrics.increment_counter("contention_reports", 1).await?;
+        self.processor.process(report, metrics).await
+    }
+}
+
+#[async_trait]
+impl TransactionPipes<'_> for ContentionPipe {
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        _instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "ContentionPipe::run(transaction_metadata: {:?}, metrics)",
+            transaction_metadata.signature,
+        );
+
+        let report = self.tracker.push(&transaction_metadata);
+        self.report(report, metrics).await
+    }
+
+    fn decode_only(&self, _instructions: &[NestedInstruction]) -> usize {
+        0
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if lifecycle == Lifecycle::Teardown {
+            let report = self.tracker.finish();
+            self.report(report, metrics.clone()).await?;
+        }
+
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_program::message::{legacy, MessageHeader, VersionedMessage},
+        solana_sdk::transaction::TransactionError,
+    };
+
+    fn transaction(slot: u64, writable: &[Pubkey], failed: bool) -> TransactionMetadata {
+        let mut transaction_metadata = TransactionMetadata {
+            slot,
+            message: VersionedMessage::Legacy(legacy::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 0,
+                },
+                account_keys: writable.to_vec(),
+                ..legacy::Message::default()
+            }),
+            ..TransactionMetadata::default()
+        };
+        if failed {
+            transaction_metadata.meta.status = Err(TransactionError::AccountInUse);
+        }
+        transaction_metadata
+    }
+
+    #[test]
+    fn test_tracker_reports_most_locked_accounts_per_slot() {
+        // Arrange
+        let mut tracker = ContentionTracker::new(2);
+        let (payer, pool, vault, other) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        // Act
+        let mut reports: Vec<ContentionReport> = [
+            transaction(7, &[payer, pool, vault], false),
+            transaction(7, &[other, pool], true),
+            transaction(7, &[payer, pool], false),
+            transaction(8, &[other], false),
+        ]
+        .iter()
+        .filter_map(|transaction_metadata| tracker.push(transaction_metadata))
+        .collect();
+        reports.extend(tracker.finish());
+
+        // Assert
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports[0],
+            ContentionReport {
+                slot: 7,
+                transactions: 3,
+                accounts: vec![
+                    AccountContention {
+                        pubkey: pool,
+                        write_locks: 3,
+                        failed_write_locks: 1,
+                    },
+                    AccountContention {
+                        pubkey: payer,
+                        write_locks: 2,
+                        failed_write_locks: 0,
+                    },
+                ],
+            }
+        );
+        assert_eq!((reports[1].slot, reports[1].transactions), (8, 1));
+    }
+}
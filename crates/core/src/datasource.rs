@@ -20,9 +20,14 @@
 //!   details, signature, and status metadata.
 //! - `AccountDeletion`: Represents account deletion events, indicating when an
 //!   account is removed from the blockchain state.
+//! - `TransactionStatusUpdate`: Represents the status of a transaction
+//!   (signature, slot and error) without its full payload.
+//! - `BlockDetails`: Represents the metadata of a block, including its rewards,
+//!   parent slot, height and time.
 //!
-//! The module also includes the `UpdateType` enum to categorize the kinds of
-//! updates that a data source can provide.
+//! The module also includes the `Capability` enum and the `Capabilities` set,
+//! declaring what a data source can provide, which the pipeline checks against
+//! its pipes when it is built.
 //!
 //! # Notes
 //!
@@ -36,12 +41,16 @@
 use {
     crate::{error::CarbonResult, metrics::MetricsCollection},
     async_trait::async_trait,
+    futures::stream::{FuturesUnordered, StreamExt},
     solana_account::Account,
     solana_pubkey::Pubkey,
-    solana_sdk::transaction::VersionedTransaction,
+    solana_sdk::commitment_config::CommitmentLevel,
+    solana_sdk::reward_type::RewardType,
+    solana_sdk::transaction::{TransactionError, VersionedTransaction},
     solana_signature::Signature,
-    solana_transaction_status::TransactionStatusMeta,
-    std::sync::Arc,
+    solana_transaction_status::{Reward, TransactionStatusMeta},
+    std::{str::FromStr, sync::Arc},
+    tokio::task::{AbortHandle, JoinHandle},
     tokio_util::sync::CancellationToken,
 };
 
@@ -51,15 +60,15 @@ use {
 /// The `Datasource` trait represents a data source that can be consumed
 /// asynchronously within a pipeline. Implementations of this trait are
 /// responsible for fetching updates and sending them through a channel to be
-/// processed further. Each datasource specifies the types of updates it
-/// supports by implementing the `update_types` method.
+/// processed further. Each datasource declares what it can provide by
+/// implementing the `capabilities` method.
 ///
 /// # Required Methods
 ///
 /// - `consume`: Initiates the asynchronous consumption of updates. This method
 ///   should send updates through the provided `sender` channel.
-/// - `update_types`: Returns a list of `UpdateType` variants indicating the
-///   types of updates the datasource can provide.
+/// - `capabilities`: Returns the `Capabilities` of the datasource, such as
+///   account streaming or historical transactions.
 ///
 /// # Example
 ///
@@ -68,15 +77,17 @@ use {
 /// impl Datasource for MyDatasource {
 ///     async fn consume(
 ///         &self,
-///         sender: &tokio::sync::mpsc::UnboundedSender<Update>,
+///         sender: &tokio::sync::mpsc::Sender<Update>,
 ///         cancellation_token: CancellationToken,
 ///         metrics: Arc<MetricsCollection>,
-///     ) -> CarbonResult<tokio::task::AbortHandle> {
+///     ) -> CarbonResult<()> {
 ///         // Implement update fetching logic
 ///     }
 ///
-///     fn update_types(&self) -> Vec<UpdateType> {
-///         vec![UpdateType::AccountUpdate, UpdateType::Transaction]
+///     fn capabilities(&self) -> Capabilities {
+///         Capabilities::new()
+///             .with(Capability::AccountUpdates)
+///             .with(Capability::Transactions)
 ///     }
 /// }
 /// ```
@@ -87,6 +98,10 @@ use {
 ///   asynchronous.
 /// - The `consume` method should handle errors and retries to ensure robust
 ///   update delivery.
+/// - `consume` should only return once the datasource is done or cancelled,
+///   since the pipeline supervises the task consuming it. Tasks spawned by
+///   the datasource should be awaited with `join_tasks`, so that their panics
+///   reach the supervisor and the datasource is restarted.
 #[async_trait]
 pub trait Datasource: Send + Sync {
     async fn consume(
@@ -96,7 +111,68 @@ pub trait Datasource: Send + Sync {
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
 
-    fn update_types(&self) -> Vec<UpdateType>;
+    fn capabilities(&self) -> Capabilities;
+
+    /// Checks the datasource configuration, such as its filters, without
+    /// connecting. Called by `PipelineBuilder::validate`.
+    fn validate(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Returns `true` if the datasource ends, sending
+    /// `Update::DatasourceFinished` after its last update, such as a backfill
+    /// of a slot range. Defaults to `false`, for live datasources.
+    fn is_bounded(&self) -> bool {
+        false
+    }
+}
+
+/// Lets a shared datasource, such as one built from a configuration, be
+/// added to a pipeline.
+#[async_trait]
+impl<D: Datasource + ?Sized> Datasource for Arc<D> {
+    async fn consume(
+        &self,
+        sender: &tokio::sync::mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        (**self).consume(sender, cancellation_token, metrics).await
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        (**self).capabilities()
+    }
+
+    fn validate(&self) -> CarbonResult<()> {
+        (**self).validate()
+    }
+
+    fn is_bounded(&self) -> bool {
+        (**self).is_bounded()
+    }
+}
+
+/// Waits for the tasks spawned by a datasource.
+///
+/// When a task panics, the other tasks are aborted and the panic is resumed,
+/// so the datasource panics as its task did.
+pub async fn join_tasks(tasks: Vec<JoinHandle<()>>) {
+    let abort_handles: Vec<AbortHandle> = tasks.iter().map(JoinHandle::abort_handle).collect();
+    let mut tasks: FuturesUnordered<JoinHandle<()>> = tasks.into_iter().collect();
+
+    while let Some(result) = tasks.next().await {
+        let Err(error) = result else {
+            continue;
+        };
+
+        abort_handles.iter().for_each(AbortHandle::abort);
+        if error.is_panic() {
+            std::panic::resume_unwind(error.into_panic());
+        }
+        log::error!("Datasource task failed: {:?}", error);
+        return;
+    }
 }
 
 /// Represents a data update in the `carbon-core` pipeline, encompassing
@@ -106,25 +182,128 @@ pub trait Datasource: Send + Sync {
 /// - `Transaction`: Represents a transaction-related update, including
 ///   transaction metadata.
 /// - `AccountDeletion`: Represents an event where an account has been deleted.
+/// - `TransactionStatus`: Represents the status of a transaction, without the
+///   transaction itself.
+/// - `BlockDetails`: Represents the metadata and rewards of a block.
+/// - `DatasourceFinished`: Sent by a bounded datasource, such as a slot range
+///   backfill, after its last update. The pipeline shuts down once every
+///   bounded datasource has sent it, after processing the updates queued
+///   before it, and cancels the live datasources. Without bounded
+///   datasources, it waits for every datasource to send it.
 #[derive(Debug, Clone)]
 pub enum Update {
     Account(AccountUpdate),
     Transaction(Box<TransactionUpdate>),
     AccountDeletion(AccountDeletion),
+    TransactionStatus(TransactionStatusUpdate),
+    BlockDetails(BlockDetails),
+    DatasourceFinished,
+}
+
+impl Update {
+    /// Returns the slot of the update, if it has one.
+    pub fn slot(&self) -> Option<u64> {
+        match self {
+            Update::Account(account_update) => Some(account_update.slot),
+            Update::Transaction(transaction_update) => Some(transaction_update.slot),
+            Update::AccountDeletion(account_deletion) => Some(account_deletion.slot),
+            Update::TransactionStatus(transaction_status) => Some(transaction_status.slot),
+            Update::BlockDetails(block_details) => Some(block_details.slot),
+            Update::DatasourceFinished => None,
+        }
+    }
+}
+
+/// Enumerates what a datasource can provide.
+///
+/// - `AccountUpdates`: Streams account updates.
+/// - `AccountDeletions`: Streams account deletion events.
+/// - `Transactions`: Streams transactions with their instructions.
+/// - `TransactionStatuses`: Streams transaction statuses.
+/// - `BlockDetails`: Streams block metadata and rewards.
+/// - `HistoricalRange`: Fetches past data, such as a range of blocks or the
+///   history of an account, rather than only following the chain tip. A
+///   `slot_range` needs it, unless a datasource provides `BlockDetails` to
+///   tell when the range is complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    AccountUpdates,
+    AccountDeletions,
+    Transactions,
+    TransactionStatuses,
+    BlockDetails,
+    HistoricalRange,
 }
 
-/// Enumerates the types of updates a datasource can provide.
+impl Capability {
+    pub const ALL: [Capability; 6] = [
+        Capability::AccountUpdates,
+        Capability::AccountDeletions,
+        Capability::Transactions,
+        Capability::TransactionStatuses,
+        Capability::BlockDetails,
+        Capability::HistoricalRange,
+    ];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// The set of `Capability`s of a datasource.
+///
+/// # Example
+///
+/// ```rust
+/// const CAPABILITIES: Capabilities = Capabilities::new()
+///     .with(Capability::Transactions)
+///     .with(Capability::HistoricalRange);
 ///
-/// The `UpdateType` enum categorizes updates into three types:
-/// - `AccountUpdate`: Indicates that the datasource provides account updates.
-/// - `Transaction`: Indicates that the datasource provides transaction updates.
-/// - `AccountDeletion`: Indicates that the datasource provides account deletion
-///   events.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum UpdateType {
-    AccountUpdate,
-    Transaction,
-    AccountDeletion,
+/// assert!(CAPABILITIES.contains(Capability::Transactions));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    /// Returns an empty set.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns the set with `capability` added.
+    pub const fn with(self, capability: Capability) -> Self {
+        Self(self.0 | capability.bit())
+    }
+
+    pub const fn contains(self, capability: Capability) -> bool {
+        self.0 & capability.bit() != 0
+    }
+
+    /// Returns the capabilities in either set.
+    pub const fn union(self, other: Capabilities) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Capability> {
+        Capability::ALL
+            .into_iter()
+            .filter(move |capability| self.contains(*capability))
+    }
+}
+
+impl FromIterator<Capability> for Capabilities {
+    fn from_iter<I: IntoIterator<Item = Capability>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Capabilities::new(), |capabilities, capability| {
+                capabilities.with(capability)
+            })
+    }
+}
+
+impl std::fmt::Debug for Capabilities {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
 }
 
 /// Represents an update to a Solana account, including its public key, data,
@@ -137,11 +316,17 @@ pub enum UpdateType {
 /// - `pubkey`: The public key of the account being updated.
 /// - `account`: The new state of the account.
 /// - `slot`: The slot number in which this account update was recorded.
+/// - `write_version`: The order of the write within the slot, when provided by
+///   the datasource, such as with Geyser. Later writes have higher versions.
+/// - `commitment`: The commitment the update was received at, when tagged by
+///   the datasource.
 #[derive(Debug, Clone)]
 pub struct AccountUpdate {
     pub pubkey: Pubkey,
     pub account: Account,
     pub slot: u64,
+    pub write_version: Option<u64>,
+    pub commitment: Option<CommitmentLevel>,
 }
 
 /// Represents the deletion of a Solana account, containing the account's public
@@ -159,6 +344,66 @@ pub struct AccountDeletion {
     pub slot: u64,
 }
 
+/// Represents the status of a transaction, without its instructions or
+/// metadata.
+///
+/// The `TransactionStatusUpdate` struct is a lightweight alternative to
+/// `TransactionUpdate` for applications that only need to track whether
+/// transactions landed, such as confirming transactions they submitted.
+///
+/// - `signature`: The unique signature of the transaction.
+/// - `slot`: The slot number in which the transaction was recorded.
+/// - `is_vote`: A boolean indicating whether the transaction is a vote.
+/// - `err`: The error of the transaction if it failed, `None` if it succeeded.
+#[derive(Debug, Clone)]
+pub struct TransactionStatusUpdate {
+    pub signature: Signature,
+    pub slot: u64,
+    pub is_vote: bool,
+    pub err: Option<TransactionError>,
+}
+
+/// Represents the metadata of a block, without its transactions.
+///
+/// The `BlockDetails` struct is sent once per block by block-based
+/// datasources, alongside the transaction updates of the block. It gives
+/// access to the block rewards, which makes it suitable for tracking validator
+/// economics.
+///
+/// - `slot`: The slot of the block.
+/// - `parent_slot`: The slot of the parent block.
+/// - `block_hash`: The hash of the block, encoded in base-58.
+/// - `previous_block_hash`: The hash of the parent block, encoded in base-58.
+/// - `block_height`: The number of blocks beneath this block, if available.
+/// - `block_time`: The Unix timestamp of when the block was produced, if
+///   available.
+/// - `rewards`: The rewards credited in this block, including the fees earned
+///   by the leader. Empty if the datasource did not request rewards.
+#[derive(Debug, Clone)]
+pub struct BlockDetails {
+    pub slot: u64,
+    pub parent_slot: u64,
+    pub block_hash: String,
+    pub previous_block_hash: String,
+    pub block_height: Option<u64>,
+    pub block_time: Option<i64>,
+    pub rewards: Vec<Reward>,
+}
+
+impl BlockDetails {
+    /// Returns the leader that produced the block, identified as the recipient
+    /// of the fee reward.
+    ///
+    /// Returns `None` if the block has no fee reward, for example when rewards
+    /// were not requested from the datasource.
+    pub fn leader(&self) -> Option<Pubkey> {
+        self.rewards
+            .iter()
+            .find(|reward| reward.reward_type == Some(RewardType::Fee))
+            .and_then(|reward| Pubkey::from_str(&reward.pubkey).ok())
+    }
+}
+
 /// Represents a transaction update in the Solana network, including transaction
 /// metadata, status, slot information and block time.
 ///
@@ -175,6 +420,8 @@ pub struct AccountDeletion {
 /// - `is_vote`: A boolean indicating whether the transaction is a vote.
 /// - `slot`: The slot number in which the transaction was recorded.
 /// - `block_time`: The Unix timestamp of when the transaction was processed.
+/// - `commitment`: The commitment the update was received at, when tagged by
+///   the datasource.
 ///
 /// Note: The `block_time` field may not be returned in all scenarios.
 #[derive(Debug, Clone)]
@@ -185,4 +432,242 @@ pub struct TransactionUpdate {
     pub is_vote: bool,
     pub slot: u64,
     pub block_time: Option<i64>,
+    pub commitment: Option<CommitmentLevel>,
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            account::{AccountDecoder, AccountProcessorInputType, DecodedAccount},
+            pipeline::Pipeline,
+            processor::Processor,
+        },
+        std::{sync::Mutex, time::Duration},
+        tokio::sync::mpsc,
+    };
+
+    fn reward(pubkey: Pubkey, reward_type: RewardType) -> Reward {
+        Reward {
+            pubkey: pubkey.to_string(),
+            lamports: 5000,
+            post_balance: 10_000,
+            reward_type: Some(reward_type),
+            commission: None,
+        }
+    }
+
+    #[test]
+    fn test_block_details_leader_is_fee_recipient() {
+        // Arrange
+        let leader = Pubkey::new_unique();
+        let block_details = BlockDetails {
+            slot: 2,
+            parent_slot: 1,
+            block_hash: String::new(),
+            previous_block_hash: String::new(),
+            block_height: Some(2),
+            block_time: None,
+            rewards: vec![
+                reward(Pubkey::new_unique(), RewardType::Staking),
+                reward(leader, RewardType::Fee),
+            ],
+        };
+
+        // Act
+        let result = block_details.leader();
+
+        // Assert
+        assert_eq!(result, Some(leader));
+    }
+    #[test]
+    fn test_capabilities_union() {
+        // Arrange
+        let streaming = Capabilities::new()
+            .with(Capability::AccountUpdates)
+            .with(Capability::Transactions);
+        let crawler: Capabilities = [Capability::Transactions, Capability::HistoricalRange]
+            .into_iter()
+            .collect();
+
+        // Act
+        let result = streaming.union(crawler);
+
+        // Assert
+        assert!(result.contains(Capability::HistoricalRange));
+        assert!(!result.contains(Capability::AccountDeletions));
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![
+                Capability::AccountUpdates,
+                Capability::Transactions,
+                Capability::HistoricalRange,
+            ]
+        );
+    }
+
+    /// Sends an account update at each of its slots, then finishes, unless
+    /// it is live and waits to be cancelled.
+    struct Accounts {
+        slots: Vec<u64>,
+        bounded: bool,
+    }
+
+    #[async_trait]
+    impl Datasource for Accounts {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in &self.slots {
+                let update = Update::Account(AccountUpdate {
+                    pubkey: Pubkey::new_unique(),
+                    account: Account::default(),
+                    slot: *slot,
+                    write_version: None,
+                    commitment: None,
+                });
+                let _ = sender.send(update).await;
+            }
+
+            if self.bounded {
+                let _ = sender.send(Update::DatasourceFinished).await;
+            } else {
+                cancellation_token.cancelled().await;
+            }
+
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new().with(Capability::AccountUpdates)
+        }
+
+        fn is_bounded(&self) -> bool {
+            self.bounded
+        }
+    }
+
+    struct AnyAccount;
+
+    impl AccountDecoder<'_> for AnyAccount {
+        type AccountType = ();
+
+        fn decode_account(&self, account: &Account) -> Option<DecodedAccount<()>> {
+            Some(DecodedAccount {
+                lamports: account.lamports,
+                data: (),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+        }
+    }
+
+    /// Records the slots processed, and the lifecycle calls after them.
+    #[derive(Clone, Default)]
+    struct Calls(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Processor for Calls {
+        type InputType = AccountProcessorInputType<()>;
+
+        async fn process(
+            &mut self,
+            (metadata, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(metadata.slot.to_string());
+            Ok(())
+        }
+
+        async fn flush(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+            self.0.lock().unwrap().push("flush".to_string());
+            Ok(())
+        }
+
+        async fn teardown(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+            self.0.lock().unwrap().push("teardown".to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_shuts_down_once_its_bounded_datasources_finish() {
+        // Arrange
+        let calls = Calls::default();
+        let mut pipeline = Pipeline::builder()
+            .datasource(Accounts {
+                slots: vec![1, 2],
+                bounded: true,
+            })
+            .datasource(Accounts {
+                slots: Vec::new(),
+                bounded: false,
+            })
+            .account(AnyAccount, calls.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let result = tokio::time::timeout(Duration::from_secs(5), pipeline.run()).await;
+
+        // Assert
+        assert!(matches!(result, Ok(Ok(()))));
+        assert_eq!(
+            *calls.0.lock().unwrap(),
+            vec!["1", "2", "flush", "teardown"]
+        );
+    }
+
+    /// A backfill failing before its last update.
+    struct FailingBackfill;
+
+    #[async_trait]
+    impl Datasource for FailingBackfill {
+        async fn consume(
+            &self,
+            _sender: &mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Err(crate::error::Error::Custom("RPC unavailable".to_string()))
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new().with(Capability::AccountUpdates)
+        }
+
+        fn is_bounded(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_shuts_down_once_its_bounded_datasources_fail() {
+        // Arrange
+        let calls = Calls::default();
+        let mut pipeline = Pipeline::builder()
+            .datasource(FailingBackfill)
+            .datasource(Accounts {
+                slots: vec![1],
+                bounded: false,
+            })
+            .account(AnyAccount, calls.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let result = tokio::time::timeout(Duration::from_secs(5), pipeline.run()).await;
+
+        // Assert
+        assert!(matches!(result, Ok(Ok(()))));
+        assert_eq!(
+            calls.0.lock().unwrap().last().map(String::as_str),
+            Some("teardown")
+        );
+    }
 }
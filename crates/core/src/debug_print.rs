@@ -0,0 +1,95 @@
+//! Prints decoded instructions to stdout, for quick debugging.
+//!
+//! Validating a new decoder usually starts with a throwaway processor printing
+//! what was decoded. `PipelineBuilder::debug_print` attaches the built-in
+//! `DebugPrintProcessor` to a decoder instead, printing every Nth decoded
+//! instruction as pretty JSON.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .debug_print(PumpfunDecoder, 10)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! Each printed instruction looks like:
+//!
+//! ```json
+//! {
+//!   "signature": "5h6x...",
+//!   "slot": 312345678,
+//!   "program_id": "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+//!   "path": [0, 2],
+//!   "data": { "Buy": { "amount": 1000, "max_sol_cost": 50000 } }
+//! }
+//! ```
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    serde::Serialize,
+    std::{marker::PhantomData, sync::Arc},
+};
+
+/// A processor printing each decoded instruction as pretty JSON to stdout.
+///
+/// Use `PipelineBuilder::debug_print` to print only a sample of the
+/// instructions.
+pub struct DebugPrintProcessor<T> {
+    _instruction: PhantomData<fn(T)>,
+}
+
+impl<T> DebugPrintProcessor<T> {
+    pub fn new() -> Self {
+        Self {
+            _instruction: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DebugPrintProcessor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Serialize + Send + Sync + 'static> Processor for DebugPrintProcessor<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (metadata, instruction, _nested_instructions) = data;
+
+        let data = serde_json::to_value(&instruction.data).map_err(|error| {
+            Error::Custom(format!("Failed to serialize instruction: {}", error))
+        })?;
+
+        let event = serde_json::json!({
+            "signature": metadata.transaction_metadata.signature.to_string(),
+            "slot": metadata.transaction_metadata.slot,
+            "program_id": instruction.program_id.to_string(),
+            "path": metadata.absolute_path,
+            "data": data,
+        });
+
+        let pretty = serde_json::to_string_pretty(&event).map_err(|error| {
+            Error::Custom(format!("Failed to serialize instruction: {}", error))
+        })?;
+        println!("{}", pretty);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+//! Feeds the output of a pipe into other pipes of the same pipeline.
+//!
+//! Multi-stage dataflows, such as normalizing the swaps of several DEXes and
+//! aggregating them into candles, usually glue several processes together
+//! through a queue. With a derived channel, a processor emits derived updates
+//! of any type on a `DerivedSender`, and `PipelineBuilder::derived` adds a
+//! pipe processing them from the matching `DerivedReceiver`, within the same
+//! pipeline.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let (swaps, swaps_receiver) = derived_channel::<NormalizedSwap>();
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(RaydiumAmmV4Decoder, RaydiumSwapNormalizer::new(swaps.clone()))
+//!     .instruction(OrcaWhirlpoolDecoder, OrcaSwapNormalizer::new(swaps))
+//!     .derived(swaps_receiver, CandleAggregator::new(Duration::from_secs(60)))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The updates derived while processing an update are processed right
+//!   after it, before the next update, in the order they were emitted.
+//!   Derived pipes can emit on other derived channels, forming chains of
+//!   stages processed in turn.
+//! - The updates derived while flushing the processors are processed before
+//!   the derived pipes are flushed, so an aggregation pipe sees the last
+//!   updates of the stages feeding it.
+//! - Emitting never blocks: updates are queued until the pipeline processes
+//!   them. Derived pipes emitting back into their own channel, directly or
+//!   not, must stop doing so eventually.
+
+use {
+    crate::{
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    tokio::sync::mpsc,
+};
+
+/// Creates a channel of derived updates of type `T`.
+pub fn derived_channel<T>() -> (DerivedSender<T>, DerivedReceiver<T>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    (DerivedSender { sender }, DerivedReceiver { receiver })
+}
+
+/// The sending side of a derived channel, given to the processors deriving
+/// updates.
+#[derive(Debug)]
+pub struct DerivedSender<T> {
+    sender: mpsc::UnboundedSender<T>,
+}
+
+impl<T> Clone for DerivedSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> DerivedSender<T> {
+    /// Emits a derived update, processed by the pipe of the receiver once the
+    /// current update is processed. Updates emitted after the pipeline stopped
+    /// are dropped.
+    pub fn emit(&self, update: T) {
+        if self.sender.send(update).is_err() {
+            log::warn!("Derived update dropped, the pipeline has stopped");
+        }
+    }
+}
+
+/// The receiving side of a derived channel, given to
+/// `PipelineBuilder::derived`.
+#[derive(Debug)]
+pub struct DerivedReceiver<T> {
+    receiver: mpsc::UnboundedReceiver<T>,
+}
+
+/// A pipe processing the updates of a derived channel.
+pub struct DerivedPipe<T> {
+    pub receiver: DerivedReceiver<T>,
+    pub processor: Box<dyn Processor<InputType = T> + Send + Sync>,
+}
+
+/// A trait for the pipes processing derived updates.
+///
+/// # Required Methods
+///
+/// - `run`: Processes the derived updates emitted so far, returning how many
+///   were processed.
+/// - `lifecycle`: Runs the lifecycle hook of the pipe's processor.
+#[async_trait]
+pub trait DerivedPipes: Send + Sync {
+    async fn run(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<usize>;
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> DerivedPipes for DerivedPipe<T> {
+    async fn run(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<usize> {
+        let mut processed = 0;
+
+        while let Ok(update) = self.receiver.receiver.try_recv() {
+            self.processor.process(update, metrics.clone()).await?;
+            processed += 1;
+        }
+        if processed > 0 {
+            metrics
+                .increment_counter("derived_updates_processed", processed as u64)
+                .await?;
+        }
+
+        Ok(processed)
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::sync::Mutex};
+
+    struct Collector(Arc<Mutex<Vec<u64>>>);
+
+    #[async_trait]
+    impl Processor for Collector {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(data);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_derived_pipe_processes_emitted_updates_in_order() {
+        // Arrange
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let (sender, receiver) = derived_channel();
+        let mut pipe = DerivedPipe {
+            receiver,
+            processor: Box::new(Collector(collected.clone())),
+        };
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        sender.emit(1);
+        sender.clone().emit(2);
+        let first = pipe.run(metrics.clone()).await.unwrap();
+        let second = pipe.run(metrics).await.unwrap();
+
+        // Assert
+        assert_eq!(first, 2);
+        assert_eq!(second, 0);
+        assert_eq!(*collected.lock().unwrap(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,395 @@
+//! Describes the components of a pipeline, for logs, metrics labels and
+//! status endpoints.
+//!
+//! Every pipe added through the `PipelineBuilder` is recorded with its kind,
+//! the type names of its decoder and processor, and a name. Pipes are named
+//! after their kind and position, such as `instruction_0`, unless named with
+//! `PipelineBuilder::named`, which names the pipe added last.
+//!
+//! `Pipeline::describe` returns a `PipelineDescription` of the datasources,
+//! pipes, filters and settings of the pipeline, serializable to JSON.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, PostgresSink::new(...))
+//!     .named("pumpfun_trades")
+//!     .account(PumpfunDecoder, PumpfunAccountProcessor)
+//!     .build()?;
+//!
+//! for pipe in pipeline.describe().pipes {
+//!     log::info!("{} ({}): {:?}", pipe.name, pipe.kind, pipe.processor);
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Decoders and processors are described by their type name, without the
+//!   module path, such as `SampledProcessor<MyProcessor>`. Processors do not
+//!   report their configuration, so a sink is described by its type.
+//! - Pipes pushed directly into the fields of the builder are described by
+//!   their kind and position only.
+
+use {
+    crate::{
+        datasource::Capability, guardrails::TransactionLimits, pipeline::ShutdownStrategy,
+        verification::VerificationMode,
+    },
+    serde::Serialize,
+    std::{fmt, ops::RangeInclusive},
+};
+
+/// The kind of a pipe, by the updates it processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipeKind {
+    Account,
+    AccountDeletion,
+    AccountLifecycle,
+    Instruction,
+    Transaction,
+    TransactionStatus,
+    BlockDetails,
+    Bundle,
+    Epoch,
+    Derived,
+}
+
+impl PipeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PipeKind::Account => "account",
+            PipeKind::AccountDeletion => "account_deletion",
+            PipeKind::AccountLifecycle => "account_lifecycle",
+            PipeKind::Instruction => "instruction",
+            PipeKind::Transaction => "transaction",
+            PipeKind::TransactionStatus => "transaction_status",
+            PipeKind::BlockDetails => "block_details",
+            PipeKind::Bundle => "bundle",
+            PipeKind::Epoch => "epoch",
+            PipeKind::Derived => "derived",
+        }
+    }
+
+    /// Returns the `Capability` a datasource needs for the pipe to receive
+    /// updates, or `None` for pipes fed by the pipeline itself.
+    pub fn capability(self) -> Option<Capability> {
+        match self {
+            PipeKind::Account | PipeKind::AccountLifecycle => Some(Capability::AccountUpdates),
+            PipeKind::AccountDeletion => Some(Capability::AccountDeletions),
+            PipeKind::Instruction | PipeKind::Transaction | PipeKind::Bundle => {
+                Some(Capability::Transactions)
+            }
+            PipeKind::TransactionStatus => Some(Capability::TransactionStatuses),
+            PipeKind::BlockDetails => Some(Capability::BlockDetails),
+            PipeKind::Epoch | PipeKind::Derived => None,
+        }
+    }
+}
+
+impl fmt::Display for PipeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A pipe of the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PipeDescription {
+    /// The name of the pipe, unique within the pipeline.
+    pub name: String,
+    pub kind: PipeKind,
+    /// The type name of the decoder, for pipes decoding their updates.
+    pub decoder: Option<String>,
+    /// The type name of the processor, when known.
+    pub processor: Option<String>,
+}
+
+impl PipeDescription {
+    /// Describes a pipe named after its kind and its position among the pipes
+    /// of that kind.
+    pub fn new(kind: PipeKind, index: usize) -> Self {
+        Self {
+            name: default_pipe_name(kind, index),
+            kind,
+            decoder: None,
+            processor: None,
+        }
+    }
+}
+
+/// A datasource of the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DatasourceDescription {
+    /// The type name of the datasource.
+    pub name: String,
+    /// The updates the datasource provides, such as `Transactions`.
+    pub capabilities: Vec<String>,
+}
+
+/// The filters skipping updates before they reach the pipes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FilterDescription {
+    pub skip_unchanged_accounts: bool,
+    pub skip_stale_accounts: bool,
+    pub slot_range: Option<RangeInclusive<u64>>,
+    pub transaction_limits: Option<TransactionLimits>,
+    pub transaction_verification: Option<VerificationMode>,
+}
+
+/// A summary of the components and settings of a pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PipelineDescription {
+    pub datasources: Vec<DatasourceDescription>,
+    pub pipes: Vec<PipeDescription>,
+    pub filters: FilterDescription,
+    /// The number of `Metrics` backends.
+    pub metrics: usize,
+    pub metrics_flush_interval: Option<u64>,
+    pub channel_buffer_size: usize,
+    pub shutdown_strategy: String,
+}
+
+impl PipelineDescription {
+    /// Returns the pipe named `name`, if any.
+    pub fn pipe(&self, name: &str) -> Option<&PipeDescription> {
+        self.pipes.iter().find(|pipe| pipe.name == name)
+    }
+}
+
+pub(crate) fn default_pipe_name(kind: PipeKind, index: usize) -> String {
+    format!("{}_{}", kind, index)
+}
+
+pub(crate) fn describe_capabilities(capabilities: impl Iterator<Item = Capability>) -> Vec<String> {
+    capabilities
+        .map(|capability| format!("{:?}", capability))
+        .collect()
+}
+
+pub(crate) fn describe_shutdown_strategy(shutdown_strategy: &ShutdownStrategy) -> String {
+    format!("{:?}", shutdown_strategy)
+}
+
+/// Returns the type name of `value` without its module paths, such as
+/// `SampledProcessor<MyProcessor>` for
+/// `carbon_core::processor::SampledProcessor<my_app::MyProcessor>`.
+pub(crate) fn short_type_name_of<T: ?Sized>(value: &T) -> String {
+    short_type_name(std::any::type_name_of_val(value))
+}
+
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut short_name = String::with_capacity(name.len());
+    let mut path = String::new();
+
+    for char in name.chars() {
+        if char.is_alphanumeric() || char == '_' || char == ':' {
+            path.push(char);
+        } else {
+            short_name.push_str(last_segment(&path));
+            path.clear();
+            short_name.push(char);
+        }
+    }
+    short_name.push_str(last_segment(&path));
+
+    short_name
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            datasource::{
+                AccountDeletion, BlockDetails, Capabilities, Datasource, TransactionStatusUpdate,
+                Update,
+            },
+            error::{CarbonResult, Error},
+            metrics::MetricsCollection,
+            pipeline::Pipeline,
+            processor::Processor,
+        },
+        async_trait::async_trait,
+        std::sync::Arc,
+        tokio_util::sync::CancellationToken,
+    };
+
+    struct BlockDatasource;
+
+    #[async_trait]
+    impl Datasource for BlockDatasource {
+        async fn consume(
+            &self,
+            _sender: &tokio::sync::mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new()
+                .with(Capability::BlockDetails)
+                .with(Capability::AccountDeletions)
+        }
+    }
+
+    struct NoopProcessor<T>(std::marker::PhantomData<T>);
+
+    #[async_trait]
+    impl<T: Send + Sync> Processor for NoopProcessor<T> {
+        type InputType = T;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_describe_names_pipes() {
+        // Arrange
+        let pipeline = Pipeline::builder()
+            .datasource(BlockDatasource)
+            .block_details(NoopProcessor::<BlockDetails>(Default::default()))
+            .named("blocks")
+            .account_deletions(NoopProcessor::<AccountDeletion>(Default::default()))
+            .slot_range(10, 20)
+            .build()
+            .expect("build pipeline");
+
+        // Act
+        let description = pipeline.describe();
+
+        // Assert
+        assert_eq!(
+            description.datasources,
+            vec![DatasourceDescription {
+                name: "BlockDatasource".to_string(),
+                capabilities: vec!["AccountDeletions".to_string(), "BlockDetails".to_string()],
+            }]
+        );
+        assert_eq!(
+            description.pipes,
+            vec![
+                PipeDescription {
+                    name: "blocks".to_string(),
+                    kind: PipeKind::BlockDetails,
+                    decoder: None,
+                    processor: Some("NoopProcessor<BlockDetails>".to_string()),
+                },
+                PipeDescription {
+                    name: "account_deletion_0".to_string(),
+                    kind: PipeKind::AccountDeletion,
+                    decoder: None,
+                    processor: Some("NoopProcessor<AccountDeletion>".to_string()),
+                },
+            ]
+        );
+        assert_eq!(description.filters.slot_range, Some(10..=20));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_pipe_names() {
+        let result = Pipeline::builder()
+            .datasource(BlockDatasource)
+            .block_details(NoopProcessor::<BlockDetails>(Default::default()))
+            .named("blocks")
+            .account_deletions(NoopProcessor::<AccountDeletion>(Default::default()))
+            .named("blocks")
+            .validate();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_rejects_a_pipe_no_datasource_feeds() {
+        // Arrange
+        let builder = Pipeline::builder()
+            .datasource(BlockDatasource)
+            .block_details(NoopProcessor::<BlockDetails>(Default::default()))
+            .transaction_statuses(NoopProcessor::<TransactionStatusUpdate>(Default::default()))
+            .named("statuses");
+
+        // Act
+        let result = builder.build();
+
+        // Assert
+        match result {
+            Err(Error::IncompatiblePipe {
+                pipe,
+                capability,
+                datasources,
+            }) => {
+                assert_eq!(pipe, "statuses");
+                assert_eq!(capability, Capability::TransactionStatuses);
+                assert_eq!(
+                    datasources,
+                    vec![(
+                        "BlockDatasource".to_string(),
+                        BlockDatasource.capabilities()
+                    )]
+                );
+            }
+            _ => panic!("expected an incompatible pipe"),
+        }
+    }
+
+    struct DeletionDatasource;
+
+    #[async_trait]
+    impl Datasource for DeletionDatasource {
+        async fn consume(
+            &self,
+            _sender: &tokio::sync::mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new().with(Capability::AccountDeletions)
+        }
+    }
+
+    #[test]
+    fn test_build_rejects_a_slot_range_no_datasource_can_complete() {
+        // Arrange
+        let builder = Pipeline::builder()
+            .datasource(DeletionDatasource)
+            .account_deletions(NoopProcessor::<AccountDeletion>(Default::default()))
+            .slot_range(10, 20);
+
+        // Act
+        let result = builder.build();
+
+        // Assert
+        assert!(
+            matches!(result, Err(Error::Custom(message)) if message.contains("HistoricalRange"))
+        );
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(
+            short_type_name("carbon_core::processor::SampledProcessor<my_app::MyProcessor>"),
+            "SampledProcessor<MyProcessor>"
+        );
+        assert_eq!(
+            short_type_name("my_app::Sink<(u8, alloc::string::String)>"),
+            "Sink<(u8, String)>"
+        );
+        assert_eq!(short_type_name("PumpfunDecoder"), "PumpfunDecoder");
+    }
+}
@@ -16,6 +16,12 @@
 //! - **`ArrangeAccounts`**: A trait that allows for defining a specific
 //!   arrangement of accounts, suitable for handling Solana account metadata in
 //!   a customized way.
+//! - **Strict decoding**: An opt-in mode, scoped to a decode with
+//!   `with_strict_decoding`, in which types tolerating bytes beyond their
+//!   decoded fields reject them instead.
+//! - **`LazyArray`**: A fixed-size array of Borsh-encoded elements decoded on
+//!   demand, for large accounts such as bin or tick arrays of which most
+//!   consumers only read a few entries.
 //!
 //! # Notes
 //!
@@ -26,9 +32,13 @@
 //! - Implement `ArrangeAccounts` when you need to access account metadata for
 //!   Solana instructions.
 
-use std::{
-    io::{Error, ErrorKind, Read, Result},
-    ops::Deref,
+use {
+    crate::{description::short_type_name, error::CarbonResult},
+    std::{
+        io::{Error, ErrorKind, Read, Result},
+        marker::PhantomData,
+        ops::Deref,
+    },
 };
 /// A trait for custom deserialization of types from byte slices.
 ///
@@ -44,13 +54,83 @@ use std::{
 ///   which is useful for processing raw blockchain data.
 /// - Ensure the data slice passed to `deserialize` is valid and of appropriate
 ///   length to avoid errors.
+/// - Derived implementations also set `DISCRIMINATOR` and `LEN`, from which
+///   the RPC and Geyser filters of an account type are built, see
+///   `account_filter`. Implementations written by hand keep the defaults: no
+///   discriminator and a variable length.
 pub trait CarbonDeserialize
 where
     Self: Sized + crate::borsh::BorshDeserialize,
 {
+    /// The discriminator prefixing the serialized data, empty if none.
+    const DISCRIMINATOR: &'static [u8] = &[];
+
+    /// The length of the serialized data after the discriminator, if it is
+    /// the same for every value, as for structs of integers, public keys and
+    /// arrays of them.
+    const LEN: Option<usize> = None;
+
+    /// The size of the serialized data, discriminator included, if fixed.
+    const SIZE: Option<usize> = match Self::LEN {
+        Some(len) => Some(Self::DISCRIMINATOR.len() + len),
+        None => None,
+    };
+
     fn deserialize(data: &[u8]) -> Option<Self>;
 }
 
+/// Returns the total length of fields of the given lengths, if all are fixed.
+///
+/// Used by the `CarbonDeserialize` derive to compute `LEN`.
+pub const fn fields_len(lens: &[Option<usize>]) -> Option<usize> {
+    let mut total = 0;
+    let mut i = 0;
+    while i < lens.len() {
+        match lens[i] {
+            Some(len) => total += len,
+            None => return None,
+        }
+        i += 1;
+    }
+
+    Some(total)
+}
+
+/// Returns the length of an array of `count` elements of length `len`.
+///
+/// Used by the `CarbonDeserialize` derive to compute `LEN`.
+pub const fn array_len(len: Option<usize>, count: usize) -> Option<usize> {
+    match len {
+        Some(len) => Some(len * count),
+        None => None,
+    }
+}
+
+/// Returns the length of an enum with variants of the given lengths, if they
+/// all have the same, including the byte of the variant index.
+///
+/// Used by the `CarbonDeserialize` derive to compute `LEN`.
+pub const fn variants_len(lens: &[Option<usize>]) -> Option<usize> {
+    if lens.is_empty() {
+        return None;
+    }
+
+    let first = match lens[0] {
+        Some(len) => len,
+        None => return None,
+    };
+    let mut i = 1;
+    while i < lens.len() {
+        match lens[i] {
+            Some(len) if len == first => {}
+            _ => return None,
+        }
+        i += 1;
+    }
+
+    Some(1 + first)
+}
+
 /// Extracts a discriminator from the beginning of a byte slice and returns the
 /// discriminator and remaining data.
 ///
@@ -91,6 +171,69 @@ pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8]
     Some((&data[..length], &data[length..]))
 }
 
+tokio::task_local! {
+    static STRICT_DECODING: bool;
+}
+
+/// Runs `decode` with strict decoding enabled or disabled, for the decodes it
+/// makes only.
+///
+/// Derived `CarbonDeserialize` implementations fail when the data holds bytes
+/// after the decoded type. Types declaring a partial layout opt out of this
+/// check, with a `#[carbon(remaining_data)]` field or a `deserialize_with`
+/// function built on `deserialize_prefix`. In strict mode, these fail on
+/// trailing bytes too, so a decode consuming only part of the data, such as a
+/// discriminator collision or a wrong layout, is rejected rather than
+/// producing silently wrong data.
+///
+/// Accounts with a partial layout no longer decode in strict mode, so it is
+/// meant for checking decoders against real data rather than for production
+/// indexing.
+///
+/// # Example
+///
+/// ```ignore
+/// let strict = with_strict_decoding(true, || PumpfunDecoder.decode_account(&account));
+/// ```
+pub fn with_strict_decoding<T>(strict: bool, decode: impl FnOnce() -> T) -> T {
+    STRICT_DECODING.sync_scope(strict, decode)
+}
+
+/// Runs `future` with strict decoding enabled or disabled, for the decodes it
+/// makes, as `with_strict_decoding` does for a synchronous decode.
+///
+/// Tasks spawned by the future do not inherit the mode.
+pub async fn strict_decoding_scope<F: std::future::Future>(strict: bool, future: F) -> F::Output {
+    STRICT_DECODING.scope(strict, future).await
+}
+
+/// Returns whether the current decode is in strict decoding mode. Decodes
+/// outside of `with_strict_decoding` and `strict_decoding_scope` are lenient.
+pub fn is_strict_decoding() -> bool {
+    STRICT_DECODING.try_with(|strict| *strict).unwrap_or(false)
+}
+
+/// Decodes a `T` from the start of `data`, ignoring the bytes after it unless
+/// strict decoding is enabled.
+///
+/// This is the building block of the `deserialize_with` functions of types
+/// whose accounts hold more data than the decoded fields.
+pub fn deserialize_prefix<T: crate::borsh::BorshDeserialize>(data: &[u8]) -> Option<T> {
+    let mut remaining = data;
+    let value = T::deserialize(&mut remaining).ok()?;
+
+    if is_strict_decoding() && !remaining.is_empty() {
+        log::debug!(
+            "Rejected {} with {} trailing bytes in strict decoding mode",
+            short_type_name(std::any::type_name::<T>()),
+            remaining.len()
+        );
+        return None;
+    }
+
+    Some(value)
+}
+
 /// A trait for defining a custom arrangement of Solana account metadata.
 ///
 /// The `ArrangeAccounts` trait provides an interface for structuring account
@@ -100,17 +243,66 @@ pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8]
 ///
 /// - `ArrangedAccounts`: The output type representing the custom arrangement of
 ///   accounts.
+///
+/// # Notes
+///
+/// The accounts structs generated by the CLI end with a `remaining_accounts`
+/// field holding the accounts passed after the ones named in the IDL, such as
+/// the bin arrays of a Meteora DLMM swap or the route accounts of a Jupiter
+/// swap.
+///
+/// Rather than implemented by hand, the trait is usually derived on the
+/// accounts struct with `#[derive(carbon_core::ArrangeAccounts)]`, which
+/// takes the accounts in the order of the struct's fields:
+///
+/// ```ignore
+/// #[derive(carbon_core::ArrangeAccounts)]
+/// #[carbon(instruction = "Deposit")]
+/// pub struct DepositInstructionAccounts {
+///     pub owner: solana_pubkey::Pubkey,
+///     pub vault: solana_pubkey::Pubkey,
+///     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+/// }
+/// ```
 pub trait ArrangeAccounts {
     type ArrangedAccounts;
 
+    /// The number of accounts the instruction requires. The accounts after
+    /// them are optional or remaining accounts.
+    const MIN_ACCOUNTS: usize = 0;
+
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts>;
+
+    /// Arranges `accounts`, failing with `Error::NotEnoughAccounts` when
+    /// fewer than `MIN_ACCOUNTS` are passed, such as for a truncated account
+    /// list, rather than returning `None`.
+    fn try_arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> CarbonResult<Self::ArrangedAccounts> {
+        let instruction = || short_type_name(std::any::type_name::<Self>());
+
+        if accounts.len() < Self::MIN_ACCOUNTS {
+            return Err(crate::error::Error::NotEnoughAccounts {
+                instruction: instruction(),
+                expected: Self::MIN_ACCOUNTS,
+                found: accounts.len(),
+            });
+        }
+
+        Self::arrange_accounts(accounts).ok_or_else(|| {
+            crate::error::Error::Custom(format!(
+                "Failed to arrange the accounts of {}",
+                instruction()
+            ))
+        })
+    }
 }
 
 /// A wrapper type for strings that are prefixed with their length.
 
-#[derive(serde::Serialize, serde::Deserialize, Default, PartialEq, Eq, Clone)]
+#[derive(serde::Serialize, serde::Deserialize, Default, PartialEq, Eq, Clone, Hash)]
 pub struct PrefixString(pub String);
 
 impl Deref for PrefixString {
@@ -152,7 +344,7 @@ impl crate::borsh::BorshDeserialize for PrefixString {
 
 /// A wrapper type for strings that are prefixed with their length.
 
-#[derive(serde::Serialize, Default, serde::Deserialize, PartialEq, Eq, Clone)]
+#[derive(serde::Serialize, Default, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
 pub struct U64PrefixString(pub String);
 
 impl Deref for U64PrefixString {
@@ -191,3 +383,207 @@ impl crate::borsh::BorshDeserialize for U64PrefixString {
         })?))
     }
 }
+
+/// A wrapper type for vectors that are prefixed with a `u64` length, as
+/// produced by `bincode` for the native programs.
+
+#[derive(serde::Serialize, Default, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct U64PrefixVec<T>(pub Vec<T>);
+
+impl<T> Deref for U64PrefixVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<U64PrefixVec<T>> for Vec<T> {
+    fn from(val: U64PrefixVec<T>) -> Self {
+        val.0
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for U64PrefixVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+/// Implements the `CarbonDeserialize` trait for `U64PrefixVec`.
+impl<T: crate::borsh::BorshDeserialize> crate::borsh::BorshDeserialize for U64PrefixVec<T> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // read the length of the Vec
+        let mut buffer = vec![0u8; 8];
+        reader.read_exact(&mut buffer)?;
+        let length = u64::deserialize(&mut buffer.as_slice())?;
+        let mut items = Vec::with_capacity((length as usize).min(4096));
+        for _ in 0..length {
+            items.push(T::deserialize_reader(reader)?);
+        }
+
+        Ok(Self(items))
+    }
+}
+
+/// A fixed-size array of Borsh-encoded elements of `element_size` bytes each,
+/// decoded one at a time when read.
+///
+/// Large accounts, such as the bin arrays of Meteora DLMM or the tick arrays
+/// of Orca Whirlpool, hold dozens of entries of which most consumers only
+/// read a few. Keeping the entries encoded avoids decoding and allocating the
+/// others on every update of the account.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LazyArray<T> {
+    data: Vec<u8>,
+    element_size: usize,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T: crate::borsh::BorshDeserialize> LazyArray<T> {
+    /// Reads `len` elements of `element_size` bytes from the start of `data`,
+    /// advancing it past them, as Borsh does. Returns `None` if `data` is too
+    /// short.
+    pub fn from_slice(data: &mut &[u8], element_size: usize, len: usize) -> Option<Self> {
+        let size = element_size.checked_mul(len)?;
+        if data.len() < size {
+            return None;
+        }
+
+        let (elements, rest) = data.split_at(size);
+        *data = rest;
+
+        Some(Self {
+            data: elements.to_vec(),
+            element_size,
+            _element: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.data.len().checked_div(self.element_size).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decodes the element at `index`, or returns `None` if it is out of
+    /// bounds or invalid.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let start = index.checked_mul(self.element_size)?;
+        let element = self
+            .data
+            .get(start..start.checked_add(self.element_size)?)?;
+
+        T::try_from_slice(element).ok()
+    }
+
+    /// Decodes the elements in order, stopping at the first invalid one.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len()).map_while(|index| self.get(index))
+    }
+
+    /// Decodes all the elements, or returns `None` if one is invalid.
+    pub fn to_vec(&self) -> Option<Vec<T>> {
+        (0..self.len()).map(|index| self.get(index)).collect()
+    }
+}
+
+impl<T> std::fmt::Debug for LazyArray<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyArray")
+            .field("element_size", &self.element_size)
+            .field("bytes", &self.data.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lazy_array_decodes_elements_on_demand() {
+        // Arrange
+        let data: Vec<u8> = [1u32, 2, 3]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .chain([9])
+            .collect();
+        let mut rest = data.as_slice();
+
+        // Act
+        let array = LazyArray::<u32>::from_slice(&mut rest, 4, 3).unwrap();
+
+        // Assert
+        assert_eq!(rest, &[9]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.get(1), Some(2));
+        assert_eq!(array.get(3), None);
+        assert_eq!(array.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(LazyArray::<u32>::from_slice(&mut data.as_slice(), 4, 4).is_none());
+    }
+
+    #[test]
+    fn test_strict_decoding_rejects_trailing_bytes() {
+        // Arrange
+        let data = [7u8, 0, 0, 0, 0xff];
+
+        // Act
+        let lenient = deserialize_prefix::<u32>(&data);
+        let (strict, strict_exact) = with_strict_decoding(true, || {
+            (
+                deserialize_prefix::<u32>(&data),
+                deserialize_prefix::<u32>(&data[..4]),
+            )
+        });
+        let lenient_after = deserialize_prefix::<u32>(&data);
+
+        // Assert
+        assert_eq!(lenient, Some(7));
+        assert_eq!(strict, None);
+        assert_eq!(strict_exact, Some(7));
+        assert_eq!(lenient_after, Some(7));
+    }
+
+    struct Transfer;
+
+    impl ArrangeAccounts for Transfer {
+        type ArrangedAccounts = (solana_pubkey::Pubkey, Option<solana_pubkey::Pubkey>);
+
+        const MIN_ACCOUNTS: usize = 2;
+
+        fn arrange_accounts(
+            accounts: &[solana_instruction::AccountMeta],
+        ) -> Option<Self::ArrangedAccounts> {
+            Some((
+                accounts.get(1)?.pubkey,
+                accounts.get(2).map(|account| account.pubkey),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_try_arrange_accounts_rejects_truncated_accounts() {
+        // Arrange
+        let accounts: Vec<solana_instruction::AccountMeta> = (0..2)
+            .map(|_| {
+                solana_instruction::AccountMeta::new(solana_pubkey::Pubkey::new_unique(), false)
+            })
+            .collect();
+
+        // Act
+        let arranged = Transfer::try_arrange_accounts(&accounts);
+        let truncated = Transfer::try_arrange_accounts(&accounts[..1]);
+
+        // Assert
+        assert_eq!(arranged.ok(), Some((accounts[1].pubkey, None)));
+        assert_eq!(
+            truncated.err().map(|error| error.to_string()),
+            Some("Transfer needs at least 2 accounts, but 1 were passed".to_string())
+        );
+    }
+}
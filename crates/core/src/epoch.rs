@@ -0,0 +1,293 @@
+//! Detects epoch boundaries in the slots of the pipeline's updates.
+//!
+//! Staking and validator analytics aggregate by epoch, and need to know when
+//! an epoch ends to close their aggregations. An `EpochPipe` follows the slot
+//! of every update the pipeline receives and, when it moves into a new epoch,
+//! passes an `EpochChange` to its processor before the first update of that
+//! epoch reaches the other pipes. The leader schedule of the new epoch can be
+//! fetched along with the change.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct EpochRollover;
+//!
+//! #[async_trait]
+//! impl Processor for EpochRollover {
+//!     type InputType = EpochChange;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         change: EpochChange,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         log::info!("epoch {} started at slot {}", change.epoch, change.first_slot);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .epochs(
+//!         EpochTracker::mainnet().leader_schedule("https://api.mainnet-beta.solana.com".to_string()),
+//!         EpochRollover,
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The first slot received only sets the current epoch: no change is
+//!   emitted for it, as the epoch may have started long before.
+//! - Epochs only move forward. Updates of an earlier epoch received after a
+//!   change, as datasources lagging behind others deliver them, are ignored.
+//! - A change is emitted for every epoch boundary crossed, including the
+//!   epochs without any update in a gap of the stream.
+//! - A leader schedule that could not be fetched is counted by the
+//!   `leader_schedule_fetches_failed` counter, and the change is emitted
+//!   without it.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_pubkey::Pubkey,
+    solana_sdk::epoch_schedule::EpochSchedule,
+    std::{collections::HashMap, str::FromStr, sync::Arc},
+};
+
+/// The leaders of the slots of an epoch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaderSchedule {
+    /// The indexes within the epoch of the slots of each leader.
+    pub slot_indexes: HashMap<Pubkey, Vec<u64>>,
+}
+
+impl LeaderSchedule {
+    /// Returns the leader of the slot at `slot_index` within the epoch.
+    pub fn leader(&self, slot_index: u64) -> Option<Pubkey> {
+        self.slot_indexes
+            .iter()
+            .find(|(_, slot_indexes)| slot_indexes.contains(&slot_index))
+            .map(|(leader, _)| *leader)
+    }
+}
+
+/// The start of a new epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpochChange {
+    pub previous_epoch: u64,
+    pub epoch: u64,
+    pub first_slot: u64,
+    pub last_slot: u64,
+    /// The slot of the update that moved the pipeline into the epoch.
+    pub slot: u64,
+    /// The leader schedule of the epoch, if fetched.
+    pub leader_schedule: Option<LeaderSchedule>,
+}
+
+/// Follows the epoch of a stream of slots.
+#[derive(Debug, Clone)]
+pub struct EpochTracker {
+    epoch_schedule: EpochSchedule,
+    rpc_url: Option<String>,
+    epoch: Option<u64>,
+}
+
+impl EpochTracker {
+    pub fn new(epoch_schedule: EpochSchedule) -> Self {
+        Self {
+            epoch_schedule,
+            rpc_url: None,
+            epoch: None,
+        }
+    }
+
+    /// Tracks the epochs of mainnet-beta: 432,000 slots per epoch, without
+    /// warmup.
+    pub fn mainnet() -> Self {
+        Self::new(EpochSchedule::without_warmup())
+    }
+
+    /// Fetches the leader schedule of each new epoch through the RPC
+    /// `getLeaderSchedule` method of `rpc_url`.
+    pub fn leader_schedule(mut self, rpc_url: String) -> Self {
+        self.rpc_url = Some(rpc_url);
+        self
+    }
+
+    /// Returns the current epoch, if a slot was received.
+    pub fn epoch(&self) -> Option<u64> {
+        self.epoch
+    }
+
+    /// Takes the next slot, returning the changes of epoch it leads to,
+    /// without their leader schedule.
+    pub fn push(&mut self, slot: u64) -> Vec<EpochChange> {
+        let epoch = self.epoch_schedule.get_epoch(slot);
+
+        let Some(previous_epoch) = self.epoch else {
+            self.epoch = Some(epoch);
+            return Vec::new();
+        };
+        if epoch <= previous_epoch {
+            return Vec::new();
+        }
+        self.epoch = Some(epoch);
+
+        (previous_epoch + 1..=epoch)
+            .map(|new_epoch| EpochChange {
+                previous_epoch: new_epoch - 1,
+                epoch: new_epoch,
+                first_slot: self.epoch_schedule.get_first_slot_in_epoch(new_epoch),
+                last_slot: self.epoch_schedule.get_last_slot_in_epoch(new_epoch),
+                slot,
+                leader_schedule: None,
+            })
+            .collect()
+    }
+}
+
+/// Fetches the leader schedule of the epoch starting at `first_slot`.
+async fn fetch_leader_schedule(
+    rpc_client: &RpcClient,
+    first_slot: u64,
+) -> CarbonResult<Option<LeaderSchedule>> {
+    let Some(schedule) = rpc_client
+        .get_leader_schedule(Some(first_slot))
+        .await
+        .map_err(|error| Error::Custom(error.to_string()))?
+    else {
+        return Ok(None);
+    };
+
+    let slot_indexes = schedule
+        .into_iter()
+        .filter_map(|(leader, slot_indexes)| {
+            Some((
+                Pubkey::from_str(&leader).ok()?,
+                slot_indexes.into_iter().map(|index| index as u64).collect(),
+            ))
+        })
+        .collect();
+
+    Ok(Some(LeaderSchedule { slot_indexes }))
+}
+
+/// A processing pipe passing the epoch changes to a processor.
+pub struct EpochPipe {
+    pub tracker: EpochTracker,
+    pub processor: Box<dyn Processor<InputType = EpochChange> + Send + Sync>,
+    pub rpc_client: Option<RpcClient>,
+}
+
+impl EpochPipe {
+    pub fn new(
+        tracker: EpochTracker,
+        processor: Box<dyn Processor<InputType = EpochChange> + Send + Sync>,
+    ) -> Self {
+        let rpc_client = tracker.rpc_url.clone().map(RpcClient::new);
+
+        Self {
+            tracker,
+            processor,
+            rpc_client,
+        }
+    }
+}
+
+/// A trait for the pipes following the epoch of the pipeline.
+///
+/// # Required Methods
+///
+/// - `run`: Takes the slot of the next update, processing the epoch changes
+///   it leads to, if any.
+#[async_trait]
+pub trait EpochPipes: Send + Sync {
+    async fn run(&mut self, slot: u64, metrics: Arc<MetricsCollection>) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EpochPipes for EpochPipe {
+    async fn run(&mut self, slot: u64, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        log::trace!("EpochPipe::run(slot: {:?}, metrics)", slot);
+
+        for mut change in self.tracker.push(slot) {
+            if let Some(rpc_client) = &self.rpc_client {
+                match fetch_leader_schedule(rpc_client, change.first_slot).await {
+                    Ok(leader_schedule) => change.leader_schedule = leader_schedule,
+                    Err(error) => {
+                        log::warn!(
+                            "Failed to fetch the leader schedule of epoch {}: {}",
+                            change.epoch,
+                            error
+                        );
+                        metrics
+                            .increment_counter("leader_schedule_fetches_failed", 1)
+                            .await?;
+                    }
+                }
+            }
+
+            metrics.increment_counter("epoch_changes", 1).await?;
+            self.processor.process(change, metrics.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_emits_each_boundary_crossed() {
+        // Arrange
+        let mut tracker = EpochTracker::mainnet();
+
+        // Act
+        let first = tracker.push(432_000 * 700 + 5);
+        let same_epoch = tracker.push(432_000 * 700 + 10);
+        let changes = tracker.push(432_000 * 702 + 3);
+        let late = tracker.push(432_000 * 701);
+
+        // Assert
+        assert!(first.is_empty());
+        assert!(same_epoch.is_empty());
+        assert!(late.is_empty());
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.previous_epoch, change.epoch, change.first_slot))
+                .collect::<Vec<_>>(),
+            vec![(700, 701, 432_000 * 701), (701, 702, 432_000 * 702)]
+        );
+        assert_eq!(changes[1].last_slot, 432_000 * 703 - 1);
+        assert_eq!(tracker.epoch(), Some(702));
+    }
+}
@@ -15,9 +15,9 @@
 //!   successful return type.
 //!
 //! These errors are essential for handling various scenarios that may arise
-//! during data processing in the `carbon-core` pipeline, including missing
-//! update types, missing transaction components, and custom errors for more
-//! flexible error management.
+//! during data processing in the `carbon-core` pipeline, including pipes
+//! incompatible with the datasources, missing transaction components, and
+//! custom errors for more flexible error management.
 //!
 //! # Notes
 //!
@@ -26,12 +26,23 @@
 //! - Each error variant corresponds to a unique error scenario within the
 //!   `carbon-core` framework.
 
-use {crate::datasource::UpdateType, thiserror::Error};
+use {
+    crate::datasource::{Capabilities, Capability},
+    thiserror::Error,
+};
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Missing update type in datasource")]
-    MissingUpdateTypeInDatasource(UpdateType),
+    #[error(
+        "Pipe {pipe} needs {capability:?}, which none of the datasources provide ({})",
+        describe_datasources(.datasources)
+    )]
+    IncompatiblePipe {
+        pipe: String,
+        capability: Capability,
+        /// The name and the capabilities of each datasource.
+        datasources: Vec<(String, Capabilities)>,
+    },
     #[error("Failed to receive updates({0})")]
     FailedToReceiveUpdates(String),
     #[error("Transaction missing fee payer")]
@@ -42,6 +53,12 @@ pub enum Error {
     MissingAccountInTransaction,
     #[error("Missing instruction data")]
     MissingInstructionData,
+    #[error("{instruction} needs at least {expected} accounts, but {found} were passed")]
+    NotEnoughAccounts {
+        instruction: String,
+        expected: usize,
+        found: usize,
+    },
     #[error("Failed to consume datasource ({0})")]
     FailedToConsumeDatasource(String),
     #[error("Custom error: {0}")]
@@ -73,3 +90,11 @@ pub enum Error {
 /// }
 /// ```
 pub type CarbonResult<T> = Result<T, Error>;
+
+fn describe_datasources(datasources: &[(String, Capabilities)]) -> String {
+    datasources
+        .iter()
+        .map(|(name, capabilities)| format!("{} provides {:?}", name, capabilities))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
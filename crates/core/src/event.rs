@@ -0,0 +1,678 @@
+//! Extracts the Anchor events emitted by a transaction, whichever way they
+//! were emitted.
+//!
+//! Anchor programs emit events either with `emit!`, logging them as
+//! `Program data: <base64>` lines, or with `emit_cpi!`, invoking themselves
+//! with an instruction whose data starts with [`EVENT_IX_TAG`]. Both carry the
+//! same bytes: the 8-byte discriminator of the event followed by its fields.
+//!
+//! `PipelineBuilder::event` adds an `EventPipe` checking both paths of each
+//! transaction, so the events of a program are processed the same way whether
+//! a version of the program logs them or emits them through a self-CPI.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .event(PumpfunDecoder, TradeEventProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Events are decoded by an `InstructionDecoder`, as the decoders generated
+//!   by the CLI already decode self-CPI events as instructions. Log events are
+//!   prefixed with `EVENT_IX_TAG` before decoding.
+//! - An event found through both paths, as emitted by programs doing both, is
+//!   processed once.
+//! - Events of failed transactions are not processed, as they were reverted.
+//!
+//! # Correlation
+//!
+//! Protocols often execute an instruction and emit an event describing it,
+//! such as a `Buy` instruction and its `BuyEvent`. [`correlate_events`] pairs
+//! each event with the instruction invocation that emitted it: the parent of
+//! the self-CPI instruction for `emit_cpi!`, or the invocation executing when
+//! the `Program data:` line was logged for `emit!`. `PipelineBuilder::
+//! correlated_events` adds a `CorrelatedEventPipe` processing both together as
+//! a `CorrelatedEvent`.
+
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::{
+            DecodedInstruction, InstructionDecoder, InstructionMetadata, NestedInstruction,
+        },
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+        transaction::{TransactionMetadata, TransactionPipes},
+    },
+    async_trait::async_trait,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, str::FromStr, sync::Arc},
+};
+
+/// The tag prefixing the data of the self-CPI instructions emitted by
+/// `emit_cpi!`.
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// How an event was emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventSource {
+    /// Logged with `emit!`.
+    Log,
+    /// Emitted through a self-CPI with `emit_cpi!`.
+    SelfCpi,
+}
+
+/// An event emitted by a program, before decoding.
+///
+/// # Fields
+///
+/// - `program_id`: The program that emitted the event.
+/// - `data`: The discriminator of the event followed by its fields, without
+///   `EVENT_IX_TAG`.
+/// - `source`: How the event was emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawEvent {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+    pub source: EventSource,
+}
+
+/// Metadata about a decoded event.
+///
+/// # Fields
+///
+/// - `transaction_metadata`: The transaction that emitted the event.
+/// - `program_id`: The program that emitted the event.
+/// - `source`: How the event was emitted.
+/// - `index`: The position of the event among the events of the transaction.
+#[derive(Debug, Clone)]
+pub struct EventMetadata {
+    pub transaction_metadata: TransactionMetadata,
+    pub program_id: Pubkey,
+    pub source: EventSource,
+    pub index: u32,
+}
+
+/// The input type for the event processor.
+pub type EventProcessorInputType<T> = (EventMetadata, T);
+
+/// Extracts the events logged with `emit!` from the log messages of a
+/// transaction.
+///
+/// `Program data:` lines are attributed to the program executing when they
+/// were logged, following the `invoke` and `success` (or `failed`) lines.
+pub fn extract_log_events(log_messages: &[String]) -> Vec<RawEvent> {
+    log::trace!("extract_log_events(log_messages: {:?})", log_messages);
+
+    extract_invocation_log_events(log_messages)
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// Extracts the events logged with `emit!`, each with the index of the
+/// invocation that logged it, counting the `invoke` lines from 0. Invocations
+/// are logged in the order of a pre-order traversal of the instructions.
+fn extract_invocation_log_events(log_messages: &[String]) -> Vec<(usize, RawEvent)> {
+    let mut events = Vec::new();
+    let mut stack = Vec::<(Pubkey, usize)>::new();
+    let mut invocations = 0;
+
+    for log_message in log_messages {
+        if let Some(data) = log_message.strip_prefix("Program data: ") {
+            let (Some((program_id, invocation)), Ok(data)) =
+                (stack.last(), STANDARD.decode(data.trim()))
+            else {
+                continue;
+            };
+
+            events.push((
+                *invocation,
+                RawEvent {
+                    program_id: *program_id,
+                    data,
+                    source: EventSource::Log,
+                },
+            ));
+            continue;
+        }
+
+        let Some(log_message) = log_message.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut parts = log_message.split_whitespace();
+        let (Some(program_id), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let Ok(program_id) = Pubkey::from_str(program_id) else {
+            continue;
+        };
+
+        match action {
+            "invoke" => {
+                stack.push((program_id, invocations));
+                invocations += 1;
+            }
+            "success" | "failed" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
+
+/// Extracts the events emitted with `emit_cpi!` from the instructions of a
+/// transaction, in execution order.
+///
+/// Only instructions invoked by their own program, with data starting with
+/// `EVENT_IX_TAG`, are events.
+pub fn extract_self_cpi_events(instructions: &[NestedInstruction]) -> Vec<RawEvent> {
+    fn visit(
+        instructions: &[NestedInstruction],
+        invoking_program_id: Option<Pubkey>,
+        events: &mut Vec<RawEvent>,
+    ) {
+        for nested_instruction in instructions {
+            let instruction = &nested_instruction.instruction;
+
+            if invoking_program_id == Some(instruction.program_id) {
+                if let Some(data) = instruction.data.strip_prefix(EVENT_IX_TAG.as_slice()) {
+                    events.push(RawEvent {
+                        program_id: instruction.program_id,
+                        data: data.to_vec(),
+                        source: EventSource::SelfCpi,
+                    });
+                }
+            }
+
+            visit(
+                &nested_instruction.inner_instructions.0,
+                Some(instruction.program_id),
+                events,
+            );
+        }
+    }
+
+    let mut events = Vec::new();
+    visit(instructions, None, &mut events);
+    events
+}
+
+/// Extracts the events of a transaction from both its self-CPI instructions
+/// and its logs.
+///
+/// Self-CPI events come first, followed by the log events not also emitted
+/// through a self-CPI. Self-CPI events are preferred as logs may be truncated
+/// by the runtime.
+pub fn extract_events(
+    transaction_metadata: &TransactionMetadata,
+    instructions: &[NestedInstruction],
+) -> Vec<RawEvent> {
+    let mut events = extract_self_cpi_events(instructions);
+
+    let Some(log_messages) = &transaction_metadata.meta.log_messages else {
+        return events;
+    };
+
+    let mut seen = HashMap::<(Pubkey, &[u8]), usize>::new();
+    for event in &events {
+        *seen
+            .entry((event.program_id, event.data.as_slice()))
+            .or_insert(0) += 1;
+    }
+
+    let mut log_events = Vec::new();
+    for event in extract_log_events(log_messages) {
+        match seen.get_mut(&(event.program_id, event.data.as_slice())) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => log_events.push(event),
+        }
+    }
+
+    events.extend(log_events);
+    events
+}
+
+/// Pairs each event of a transaction with the instruction invocation that
+/// emitted it, in the order of the invocations.
+///
+/// Self-CPI events are paired with the instruction invoking the self-CPI, and
+/// log events with the invocation executing when they were logged. For each
+/// invocation, self-CPI events come first, followed by the log events not
+/// also emitted through a self-CPI.
+pub fn correlate_events<'a>(
+    transaction_metadata: &TransactionMetadata,
+    instructions: &'a [NestedInstruction],
+) -> Vec<(&'a NestedInstruction, RawEvent)> {
+    fn flatten<'a>(
+        instructions: &'a [NestedInstruction],
+        invocations: &mut Vec<&'a NestedInstruction>,
+    ) {
+        for nested_instruction in instructions {
+            invocations.push(nested_instruction);
+            flatten(&nested_instruction.inner_instructions.0, invocations);
+        }
+    }
+
+    let mut invocations = Vec::new();
+    flatten(instructions, &mut invocations);
+
+    let mut events: Vec<Vec<RawEvent>> = invocations
+        .iter()
+        .map(|invocation| {
+            invocation
+                .inner_instructions
+                .iter()
+                .filter(|inner_instruction| {
+                    inner_instruction.instruction.program_id == invocation.instruction.program_id
+                })
+                .filter_map(|inner_instruction| {
+                    inner_instruction
+                        .instruction
+                        .data
+                        .strip_prefix(EVENT_IX_TAG.as_slice())
+                })
+                .map(|data| RawEvent {
+                    program_id: invocation.instruction.program_id,
+                    data: data.to_vec(),
+                    source: EventSource::SelfCpi,
+                })
+                .collect()
+        })
+        .collect();
+
+    if let Some(log_messages) = &transaction_metadata.meta.log_messages {
+        let mut matched: Vec<Vec<bool>> = events
+            .iter()
+            .map(|events| vec![false; events.len()])
+            .collect();
+        let mut log_events: Vec<Vec<RawEvent>> = vec![Vec::new(); invocations.len()];
+
+        for (invocation, event) in extract_invocation_log_events(log_messages) {
+            // Skip events whose invocation does not line up with the
+            // instructions, such as after the logs were truncated.
+            if invocations
+                .get(invocation)
+                .is_none_or(|instruction| instruction.instruction.program_id != event.program_id)
+            {
+                continue;
+            }
+
+            let duplicate = events[invocation]
+                .iter()
+                .zip(matched[invocation].iter_mut())
+                .find(|(self_cpi_event, matched)| !**matched && self_cpi_event.data == event.data);
+
+            match duplicate {
+                Some((_, matched)) => *matched = true,
+                None => log_events[invocation].push(event),
+            }
+        }
+
+        for (events, log_events) in events.iter_mut().zip(log_events) {
+            events.extend(log_events);
+        }
+    }
+
+    invocations
+        .into_iter()
+        .zip(events)
+        .flat_map(|(invocation, events)| events.into_iter().map(move |event| (invocation, event)))
+        .collect()
+}
+
+/// A pipe decoding the events of each transaction, whether logged or emitted
+/// through a self-CPI, and processing them with a single processor.
+pub struct EventPipe<T: Send> {
+    pub decoder:
+        Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
+    pub processor:
+        Box<dyn Processor<InputType = EventProcessorInputType<T>> + Send + Sync + 'static>,
+}
+
+impl<T: Send> EventPipe<T> {
+    /// Decodes an event of `slot` with the decoder, as the self-CPI
+    /// instruction emitting it.
+    fn decode(&self, event: &RawEvent, slot: u64) -> Option<T> {
+        let instruction = solana_instruction::Instruction {
+            program_id: event.program_id,
+            accounts: Vec::new(),
+            data: [EVENT_IX_TAG.as_slice(), &event.data].concat(),
+        };
+
+        self.decoder
+            .decode_instruction_at_slot(&instruction, slot)
+            .map(|decoded_instruction| decoded_instruction.data)
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> TransactionPipes<'_> for EventPipe<T> {
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!("EventPipe::run(instructions: {:?}, metrics)", instructions);
+
+        if transaction_metadata.failure_reason.is_some() {
+            return Ok(());
+        }
+
+        let mut index = 0;
+        for event in extract_events(&transaction_metadata, instructions) {
+            let Some(decoded_event) = self.decode(&event, transaction_metadata.slot) else {
+                continue;
+            };
+
+            let metadata = EventMetadata {
+                transaction_metadata: transaction_metadata.clone(),
+                program_id: event.program_id,
+                source: event.source,
+                index,
+            };
+            index += 1;
+
+            self.processor
+                .process((metadata, decoded_event), metrics.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn decode_only(&self, instructions: &[NestedInstruction]) -> usize {
+        let Some(first) = instructions.first() else {
+            return 0;
+        };
+        let slot = first.metadata.transaction_metadata.slot;
+
+        extract_self_cpi_events(instructions)
+            .iter()
+            .filter(|event| self.decode(event, slot).is_some())
+            .count()
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+/// An instruction and an event it emitted, decoded by the same decoder.
+///
+/// # Fields
+///
+/// - `instruction_metadata`: The metadata of the instruction.
+/// - `instruction`: The decoded instruction.
+/// - `event`: The decoded event.
+/// - `source`: How the event was emitted.
+#[derive(Debug, Clone)]
+pub struct CorrelatedEvent<T> {
+    pub instruction_metadata: InstructionMetadata,
+    pub instruction: DecodedInstruction<T>,
+    pub event: T,
+    pub source: EventSource,
+}
+
+/// A pipe pairing the events of each transaction with the instructions that
+/// emitted them, and processing each pair with a single processor.
+///
+/// Events emitted by an instruction the decoder does not decode are skipped.
+pub struct CorrelatedEventPipe<T: Send> {
+    pub decoder:
+        Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
+    pub processor: Box<dyn Processor<InputType = CorrelatedEvent<T>> + Send + Sync + 'static>,
+}
+
+impl<T: Send> CorrelatedEventPipe<T> {
+    fn decode(
+        &self,
+        nested_instruction: &NestedInstruction,
+        event: &RawEvent,
+    ) -> Option<(DecodedInstruction<T>, T)> {
+        let slot = nested_instruction.metadata.transaction_metadata.slot;
+        let instruction = self
+            .decoder
+            .decode_instruction_at_slot(&nested_instruction.instruction, slot)?;
+        let event = self.decoder.decode_instruction_at_slot(
+            &solana_instruction::Instruction {
+                program_id: event.program_id,
+                accounts: Vec::new(),
+                data: [EVENT_IX_TAG.as_slice(), &event.data].concat(),
+            },
+            slot,
+        )?;
+
+        Some((instruction, event.data))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> TransactionPipes<'_> for CorrelatedEventPipe<T> {
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "CorrelatedEventPipe::run(instructions: {:?}, metrics)",
+            instructions
+        );
+
+        if transaction_metadata.failure_reason.is_some() {
+            return Ok(());
+        }
+
+        for (nested_instruction, event) in correlate_events(&transaction_metadata, instructions) {
+            let Some((instruction, decoded_event)) = self.decode(nested_instruction, &event) else {
+                continue;
+            };
+
+            self.processor
+                .process(
+                    CorrelatedEvent {
+                        instruction_metadata: nested_instruction.metadata.clone(),
+                        instruction,
+                        event: decoded_event,
+                        source: event.source,
+                    },
+                    metrics.clone(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn decode_only(&self, instructions: &[NestedInstruction]) -> usize {
+        let Some(first) = instructions.first() else {
+            return 0;
+        };
+
+        correlate_events(&first.metadata.transaction_metadata, instructions)
+            .iter()
+            .filter(|(nested_instruction, event)| self.decode(nested_instruction, event).is_some())
+            .count()
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::instruction::{InstructionMetadata, NestedInstructions},
+    };
+
+    fn nested_instruction(
+        program_id: Pubkey,
+        data: Vec<u8>,
+        inner_instructions: Vec<NestedInstruction>,
+    ) -> NestedInstruction {
+        NestedInstruction {
+            metadata: InstructionMetadata {
+                transaction_metadata: TransactionMetadata::default(),
+                stack_height: 1,
+                index: 0,
+                absolute_path: vec![],
+                compute_units_consumed: None,
+            },
+            instruction: solana_instruction::Instruction {
+                program_id,
+                accounts: vec![],
+                data,
+            },
+            inner_instructions: NestedInstructions(inner_instructions),
+        }
+    }
+
+    #[test]
+    fn test_extract_log_events() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let log_messages = vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program {} invoke [2]", other_program_id),
+            format!("Program data: {}", STANDARD.encode([9, 9])),
+            format!("Program {} success", other_program_id),
+            format!("Program data: {}", STANDARD.encode([1, 2, 3])),
+            format!("Program {} success", program_id),
+        ];
+
+        assert_eq!(
+            extract_log_events(&log_messages),
+            vec![
+                RawEvent {
+                    program_id: other_program_id,
+                    data: vec![9, 9],
+                    source: EventSource::Log,
+                },
+                RawEvent {
+                    program_id,
+                    data: vec![1, 2, 3],
+                    source: EventSource::Log,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_events_deduplicates_both_paths() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let instructions = vec![nested_instruction(
+            program_id,
+            vec![0],
+            vec![
+                nested_instruction(
+                    program_id,
+                    [EVENT_IX_TAG.as_slice(), &[1, 2]].concat(),
+                    vec![],
+                ),
+                nested_instruction(Pubkey::new_unique(), EVENT_IX_TAG.to_vec(), vec![]),
+            ],
+        )];
+
+        let mut transaction_metadata = TransactionMetadata::default();
+        transaction_metadata.meta.log_messages = Some(vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program data: {}", STANDARD.encode([1, 2])),
+            format!("Program data: {}", STANDARD.encode([3, 4])),
+            format!("Program {} success", program_id),
+        ]);
+
+        // Act
+        let events = extract_events(&transaction_metadata, &instructions);
+
+        // Assert
+        assert_eq!(
+            events,
+            vec![
+                RawEvent {
+                    program_id,
+                    data: vec![1, 2],
+                    source: EventSource::SelfCpi,
+                },
+                RawEvent {
+                    program_id,
+                    data: vec![3, 4],
+                    source: EventSource::Log,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_correlate_events_pairs_events_with_invocations() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let instructions = vec![
+            nested_instruction(
+                program_id,
+                vec![1],
+                vec![nested_instruction(
+                    program_id,
+                    [EVENT_IX_TAG.as_slice(), &[10]].concat(),
+                    vec![],
+                )],
+            ),
+            nested_instruction(program_id, vec![2], vec![]),
+        ];
+
+        let mut transaction_metadata = TransactionMetadata::default();
+        transaction_metadata.meta.log_messages = Some(vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program data: {}", STANDARD.encode([10])),
+            format!("Program {} invoke [2]", program_id),
+            format!("Program {} success", program_id),
+            format!("Program {} success", program_id),
+            format!("Program {} invoke [1]", program_id),
+            format!("Program data: {}", STANDARD.encode([20])),
+            format!("Program {} success", program_id),
+        ]);
+
+        // Act
+        let events = correlate_events(&transaction_metadata, &instructions);
+
+        // Assert
+        let events: Vec<(Vec<u8>, Vec<u8>, EventSource)> = events
+            .into_iter()
+            .map(|(instruction, event)| {
+                (
+                    instruction.instruction.data.clone(),
+                    event.data,
+                    event.source,
+                )
+            })
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (vec![1], vec![10], EventSource::SelfCpi),
+                (vec![2], vec![20], EventSource::Log),
+            ]
+        );
+    }
+}
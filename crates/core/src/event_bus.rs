@@ -0,0 +1,244 @@
+//! Notifies subscribers of the lifecycle of a pipeline.
+//!
+//! The pipeline emits a `PipelineEvent` on its `EventBus` when it starts and
+//! stops, when a datasource task connects or disconnects, when its checkpoint
+//! advances and when a shutdown is initiated. Circuit breakers given the bus
+//! emit an event when their circuit opens or closes. Supervision and alerting
+//! logic can subscribe to the bus and react to these events, such as paging
+//! when a datasource disconnects with an error, without patching the
+//! pipeline.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let events = EventBus::default();
+//! let mut receiver = events.subscribe();
+//!
+//! let handle = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         CircuitBreaker::new(PostgresSink::new(pool)).event_bus(events.clone(), "postgres"),
+//!     )
+//!     .event_bus(events)
+//!     .build()?
+//!     .spawn();
+//!
+//! while let Ok(event) = receiver.recv().await {
+//!     if let PipelineEvent::DatasourceDisconnected { index, error: Some(error) } = event {
+//!         alerting.page(format!("datasource {} disconnected: {}", index, error)).await;
+//!     }
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Events are broadcast: each subscriber receives every event emitted after
+//!   it subscribed. A subscriber falling more than the capacity of the bus
+//!   behind misses the oldest events, and its next `recv` returns
+//!   `RecvError::Lagged`.
+//! - Emitting never blocks nor fails, events without subscribers are dropped.
+//! - Datasources reconnecting on their own, as most do, are not reported:
+//!   `DatasourceConnected` and `DatasourceDisconnected` follow the task
+//!   consuming each datasource, started again when it panics.
+//! - The checkpoint is the highest slot of the successfully processed
+//!   updates, from which a restarted pipeline can resume. It is held at the
+//!   slot of the first failed update, so that a pipeline restarted from it
+//!   replays the failed updates rather than skipping them. Updates received
+//!   out of order, below the checkpoint, do not hold it back.
+
+use {serde::Serialize, tokio::sync::broadcast};
+
+/// The default number of events a subscriber can fall behind.
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 1_024;
+
+/// Why a pipeline is shutting down.
+///
+/// - `Signal`: The process received a Ctrl-C.
+/// - `Requested`: `PipelineHandle::shutdown` was called.
+/// - `DatasourcesFinished`: Every datasource finished, or their channel
+///   closed.
+/// - `SlotRangeEnd`: The slot range was fully processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownReason {
+    Signal,
+    Requested,
+    DatasourcesFinished,
+    SlotRangeEnd,
+}
+
+/// A lifecycle event of a pipeline.
+///
+/// Datasources are identified by their index, in the order they were added.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum PipelineEvent {
+    /// The processors are set up and the datasources are starting.
+    Started,
+    /// The task consuming a datasource started, for the first time or after
+    /// `restarts` restarts.
+    DatasourceConnected { index: usize, restarts: u32 },
+    /// The task consuming a datasource stopped, with the error it returned or
+    /// the panic it died of, if any. A panicking datasource connects again
+    /// once restarted.
+    DatasourceDisconnected { index: usize, error: Option<String> },
+    /// The highest slot of the successfully processed updates advanced, up
+    /// to the slot of the first failed update. Emitted once the processors
+    /// flushed the updates up to it, so a sink buffering its writes has
+    /// written them durably.
+    CheckpointAdvanced { slot: u64 },
+    /// The circuit breaker of a pipe opened, skipping its updates.
+    CircuitOpened { pipe: String },
+    /// The circuit breaker of a pipe closed after a successful trial.
+    CircuitClosed { pipe: String },
+    /// The pipeline started shutting down.
+    ShutdownInitiated { reason: ShutdownReason },
+    /// The pipeline stopped.
+    Stopped,
+}
+
+/// The channel the lifecycle events of a pipeline are emitted on, cheap to
+/// clone and shared between the pipeline, its wrappers and the subscribers.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<PipelineEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+impl EventBus {
+    /// Creates a bus on which subscribers can fall `capacity` events behind.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            sender: broadcast::channel(capacity.max(1)).0,
+        }
+    }
+
+    /// Returns a receiver of the events emitted from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<PipelineEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Emits an event to the current subscribers, if any.
+    pub fn emit(&self, event: PipelineEvent) {
+        log::trace!("emit(event: {:?})", event);
+        let _ = self.sender.send(event);
+    }
+
+    /// Returns the number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// Follows the checkpoint of a pipeline, the slot a restarted pipeline can
+/// resume from without skipping failed updates.
+///
+/// Buffering processors only write their updates durably when flushed, so the
+/// checkpoint advanced by processed updates is held until the next successful
+/// flush, then committed.
+#[derive(Debug, Default)]
+pub(crate) struct CheckpointTracker {
+    checkpoint: u64,
+    /// The lowest slot of the failed updates.
+    lowest_failed: Option<u64>,
+    /// The checkpoint advanced since the last commit.
+    pending: Option<u64>,
+}
+
+impl CheckpointTracker {
+    /// Records an update processed at `slot`, returning the new checkpoint if
+    /// it advanced. It is held until the next `commit`.
+    pub(crate) fn record(&mut self, slot: u64, success: bool) -> Option<u64> {
+        if !success {
+            self.lowest_failed = Some(self.lowest_failed.map_or(slot, |failed| failed.min(slot)));
+            return None;
+        }
+
+        let slot = self.lowest_failed.map_or(slot, |failed| failed.min(slot));
+        if slot <= self.checkpoint {
+            return None;
+        }
+
+        self.checkpoint = slot;
+        self.pending = Some(slot);
+        Some(slot)
+    }
+
+    /// Takes the checkpoint advanced since the last commit, once the
+    /// processors flushed the updates up to it.
+    pub(crate) fn commit(&mut self) -> Option<u64> {
+        self.pending.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribers_receive_events_emitted_after_subscribing() {
+        // Arrange
+        let events = EventBus::new(2);
+        events.emit(PipelineEvent::Started);
+        let mut receiver = events.subscribe();
+
+        // Act
+        events.emit(PipelineEvent::CheckpointAdvanced { slot: 1 });
+        events.emit(PipelineEvent::CheckpointAdvanced { slot: 2 });
+        events.emit(PipelineEvent::Stopped);
+
+        // Assert
+        assert!(matches!(
+            receiver.try_recv(),
+            Err(broadcast::error::TryRecvError::Lagged(1))
+        ));
+        assert_eq!(
+            receiver.try_recv().unwrap(),
+            PipelineEvent::CheckpointAdvanced { slot: 2 }
+        );
+        assert_eq!(receiver.try_recv().unwrap(), PipelineEvent::Stopped);
+        assert_eq!(events.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn test_checkpoint_is_held_at_the_first_failed_slot() {
+        // Arrange
+        let mut checkpoint = CheckpointTracker::default();
+
+        // Act
+        let advanced = [
+            checkpoint.record(10, true),
+            checkpoint.record(11, false),
+            checkpoint.record(12, true),
+            checkpoint.record(13, true),
+            checkpoint.record(9, false),
+        ];
+
+        // Assert
+        assert_eq!(advanced, [Some(10), None, Some(11), None, None]);
+    }
+
+    #[test]
+    fn test_checkpoint_is_committed_once_flushed() {
+        // Arrange
+        let mut checkpoint = CheckpointTracker::default();
+
+        // Act
+        let before_processing = checkpoint.commit();
+        checkpoint.record(10, true);
+        checkpoint.record(12, true);
+        let after_flush = checkpoint.commit();
+        let after_second_flush = checkpoint.commit();
+
+        // Assert
+        assert_eq!(before_processing, None);
+        assert_eq!(after_flush, Some(12));
+        assert_eq!(after_second_flush, None);
+    }
+}
@@ -0,0 +1,189 @@
+//! Describes why a transaction failed, down to the program error.
+//!
+//! Failed transactions are decoded like successful ones. Their
+//! `TransactionMetadata` carries a `FailureReason`, extracted from the
+//! transaction status and logs, with the instruction and program that failed
+//! and the custom error code they returned.
+//!
+//! Custom error codes are mapped to the named errors of the failing program
+//! through the resolvers registered with `PipelineBuilder::program_errors`,
+//! typically backed by the error enum of the program's decoder.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .program_errors(PUMPFUN_PROGRAM_ID, |code| {
+//!         PumpfunError::from_code(code).map(Into::into)
+//!     })
+//!     .transaction(PUMPFUN_SCHEMA.clone(), FailedBuysProcessor)
+//!     .build()?;
+//! ```
+
+use {
+    solana_program::message::VersionedMessage,
+    solana_pubkey::Pubkey,
+    solana_sdk::{instruction::InstructionError, transaction::TransactionError},
+    solana_transaction_status::TransactionStatusMeta,
+    std::{collections::HashMap, str::FromStr, sync::Arc},
+};
+
+/// A named error of a program, such as one generated from its IDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramErrorDetails {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+/// Maps the custom error codes of a program to its named errors.
+pub type ProgramErrorResolver = Arc<dyn Fn(u32) -> Option<ProgramErrorDetails> + Send + Sync>;
+
+/// Why a transaction failed.
+///
+/// # Fields
+///
+/// - `error`: The error returned by the runtime.
+/// - `instruction_index`: The index of the failing top-level instruction, if
+///   the error comes from an instruction.
+/// - `program_id`: The program that returned the error. This is the innermost
+///   failing program when the logs are available, which may have been invoked
+///   by the top-level instruction, and the program of the top-level
+///   instruction otherwise.
+/// - `custom_code`: The custom error code returned by the program, if any.
+/// - `program_error`: The named error matching `custom_code`, when a resolver
+///   is registered for `program_id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureReason {
+    pub error: TransactionError,
+    pub instruction_index: Option<u8>,
+    pub program_id: Option<Pubkey>,
+    pub custom_code: Option<u32>,
+    pub program_error: Option<ProgramErrorDetails>,
+}
+
+impl FailureReason {
+    /// Extracts the failure reason of a transaction, or `None` if it
+    /// succeeded.
+    pub fn from_transaction(
+        meta: &TransactionStatusMeta,
+        message: &VersionedMessage,
+    ) -> Option<Self> {
+        let error = meta.status.clone().err()?;
+
+        let (instruction_index, custom_code) = match &error {
+            TransactionError::InstructionError(index, InstructionError::Custom(code)) => {
+                (Some(*index), Some(*code))
+            }
+            TransactionError::InstructionError(index, _) => (Some(*index), None),
+            _ => (None, None),
+        };
+
+        let program_id = meta
+            .log_messages
+            .as_deref()
+            .and_then(failed_program)
+            .or_else(|| {
+                let instruction = message.instructions().get(instruction_index? as usize)?;
+                message
+                    .static_account_keys()
+                    .get(instruction.program_id_index as usize)
+                    .copied()
+            });
+
+        Some(Self {
+            error,
+            instruction_index,
+            program_id,
+            custom_code,
+            program_error: None,
+        })
+    }
+
+    /// Sets `program_error` using the resolver registered for the failing
+    /// program.
+    pub fn resolve(&mut self, resolvers: &HashMap<Pubkey, ProgramErrorResolver>) {
+        let (Some(program_id), Some(code)) = (self.program_id, self.custom_code) else {
+            return;
+        };
+
+        self.program_error = resolvers
+            .get(&program_id)
+            .and_then(|resolver| resolver(code));
+    }
+}
+
+/// Returns the first program logging `Program <id> failed`, which is the
+/// innermost failing program.
+fn failed_program(log_messages: &[String]) -> Option<Pubkey> {
+    log_messages.iter().find_map(|log_message| {
+        let mut parts = log_message.strip_prefix("Program ")?.split_whitespace();
+        let program_id = parts.next()?;
+
+        parts
+            .next()?
+            .starts_with("failed")
+            .then(|| Pubkey::from_str(program_id).ok())
+            .flatten()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_sdk::message::Message};
+
+    #[test]
+    fn test_failure_reason_resolves_innermost_program() {
+        // Arrange
+        let program = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+        let meta = TransactionStatusMeta {
+            status: Err(TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(1),
+            )),
+            log_messages: Some(vec![
+                format!("Program {} invoke [1]", program),
+                format!("Program {} invoke [2]", token_program),
+                "Program log: Error: insufficient funds".to_string(),
+                format!(
+                    "Program {} failed: custom program error: 0x1",
+                    token_program
+                ),
+                format!("Program {} failed: custom program error: 0x1", program),
+            ]),
+            ..Default::default()
+        };
+        let message = VersionedMessage::Legacy(Message::default());
+        let resolver: ProgramErrorResolver = Arc::new(|code| {
+            (code == 1).then(|| ProgramErrorDetails {
+                code,
+                name: "InsufficientFunds".to_string(),
+                message: "Insufficient funds".to_string(),
+            })
+        });
+
+        // Act
+        let mut failure_reason =
+            FailureReason::from_transaction(&meta, &message).expect("failure reason");
+        failure_reason.resolve(&HashMap::from([(token_program, resolver)]));
+
+        // Assert
+        assert_eq!(failure_reason.instruction_index, Some(1));
+        assert_eq!(failure_reason.program_id, Some(token_program));
+        assert_eq!(failure_reason.custom_code, Some(1));
+        assert_eq!(
+            failure_reason.program_error.map(|error| error.name),
+            Some("InsufficientFunds".to_string())
+        );
+    }
+
+    #[test]
+    fn test_successful_transaction_has_no_failure_reason() {
+        let meta = TransactionStatusMeta::default();
+        let message = VersionedMessage::Legacy(Message::default());
+
+        assert!(FailureReason::from_transaction(&meta, &message).is_none());
+    }
+}
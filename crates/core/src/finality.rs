@@ -0,0 +1,187 @@
+//! Reconciles provisional updates with their finalized copies.
+//!
+//! A datasource subscribed at two commitments at once, such as the Yellowstone
+//! `YellowstoneGrpcDualCommitment`, delivers most updates twice: first a fast,
+//! provisional copy at `processed` commitment, then an authoritative copy at
+//! `finalized` commitment. Each copy is tagged with its commitment in
+//! `AccountMetadata::commitment` and `TransactionMetadata::commitment`.
+//!
+//! A `FinalityReconciler` remembers the provisional copies a sink wrote and
+//! tells it, for each copy received, whether to upsert a provisional row,
+//! confirm it, or correct it with the finalized value. Provisional copies on
+//! forks that were never finalized are handed back by `take_orphaned`, for
+//! the sink to delete them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut reconciler = FinalityReconciler::new();
+//!
+//! // In an account processor:
+//! let key = metadata.idempotency_key();
+//! match reconciler.observe(key.clone(), metadata.slot, account.lamports, metadata.commitment) {
+//!     FinalityAction::UpsertProvisional => upsert(&key, account.lamports, false).await?,
+//!     FinalityAction::Confirm => mark_final(&key).await?,
+//!     FinalityAction::Correct { .. } | FinalityAction::InsertFinalized => {
+//!         upsert(&key, account.lamports, true).await?
+//!     }
+//!     FinalityAction::Skip => {}
+//! }
+//! for (key, _) in reconciler.take_orphaned() {
+//!     delete(&key).await?;
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates without a commitment are treated as provisional, and updates at
+//!   `confirmed` commitment too, as they can still be rolled back.
+//! - Keys should identify a copy independently of its commitment, which the
+//!   `IdempotencyKey` of the metadata does.
+//! - A provisional copy received for a slot at or below the last finalized
+//!   slot is skipped: the slot is settled, and its finalized copy is either
+//!   already received or never coming.
+//! - The account watermark and the unchanged account suppression of the
+//!   pipeline would drop the finalized copies of account updates already seen
+//!   at `processed`, so leave them disabled in dual commitment mode.
+
+use {
+    solana_sdk::commitment_config::CommitmentLevel,
+    std::{collections::HashMap, hash::Hash},
+};
+
+/// What a sink should do with a copy passed to `FinalityReconciler::observe`.
+///
+/// - `UpsertProvisional`: Write the provisional value, marked as not final.
+/// - `Confirm`: The finalized value matches the provisional one: mark the
+///   provisional row as final.
+/// - `Correct`: The finalized value differs from the provisional one, which is
+///   returned: overwrite the row and mark it as final.
+/// - `InsertFinalized`: No provisional copy was received: write the finalized
+///   value, marked as final.
+/// - `Skip`: A provisional copy of a settled slot: ignore it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FinalityAction<V> {
+    UpsertProvisional,
+    Confirm,
+    Correct { provisional: V },
+    InsertFinalized,
+    Skip,
+}
+
+/// Tracks the provisional copies awaiting their finalized copy.
+#[derive(Debug, Clone)]
+pub struct FinalityReconciler<K, V> {
+    provisional: HashMap<K, (u64, V)>,
+    finalized_slot: Option<u64>,
+}
+
+impl<K, V> FinalityReconciler<K, V>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+{
+    pub fn new() -> Self {
+        Self {
+            provisional: HashMap::new(),
+            finalized_slot: None,
+        }
+    }
+
+    /// Takes the next copy of the value identified by `key`, returning what
+    /// the sink should do with it.
+    pub fn observe(
+        &mut self,
+        key: K,
+        slot: u64,
+        value: V,
+        commitment: Option<CommitmentLevel>,
+    ) -> FinalityAction<V> {
+        if commitment == Some(CommitmentLevel::Finalized) {
+            self.finalized_slot = Some(self.finalized_slot.unwrap_or_default().max(slot));
+
+            return match self.provisional.remove(&key) {
+                Some((_, provisional)) if provisional == value => FinalityAction::Confirm,
+                Some((_, provisional)) => FinalityAction::Correct { provisional },
+                None => FinalityAction::InsertFinalized,
+            };
+        }
+
+        if self
+            .finalized_slot
+            .is_some_and(|finalized_slot| slot <= finalized_slot)
+        {
+            return FinalityAction::Skip;
+        }
+
+        self.provisional.insert(key, (slot, value));
+        FinalityAction::UpsertProvisional
+    }
+
+    /// Removes and returns the provisional copies of the slots at or below
+    /// the last finalized slot that were not finalized, as they belong to
+    /// abandoned forks.
+    pub fn take_orphaned(&mut self) -> Vec<(K, V)> {
+        let Some(finalized_slot) = self.finalized_slot else {
+            return Vec::new();
+        };
+
+        let (orphaned, pending) = std::mem::take(&mut self.provisional)
+            .into_iter()
+            .partition(|(_, (slot, _))| *slot <= finalized_slot);
+        self.provisional = pending;
+
+        orphaned
+            .into_iter()
+            .map(|(key, (_, value))| (key, value))
+            .collect()
+    }
+
+    /// Returns the number of provisional copies awaiting their finalized copy.
+    pub fn pending(&self) -> usize {
+        self.provisional.len()
+    }
+}
+
+impl<K, V> Default for FinalityReconciler<K, V>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconciler_confirms_corrects_and_orphans() {
+        // Arrange
+        let mut reconciler = FinalityReconciler::new();
+        let processed = Some(CommitmentLevel::Processed);
+        let finalized = Some(CommitmentLevel::Finalized);
+
+        // Act
+        let upserted = reconciler.observe("a", 10, 1, processed);
+        reconciler.observe("b", 10, 2, processed);
+        reconciler.observe("forked", 11, 3, processed);
+        reconciler.observe("pending", 13, 4, processed);
+        let confirmed = reconciler.observe("a", 10, 1, finalized);
+        let corrected = reconciler.observe("b", 12, 5, finalized);
+        let inserted = reconciler.observe("c", 12, 6, finalized);
+        let skipped = reconciler.observe("late", 12, 7, processed);
+        let orphaned = reconciler.take_orphaned();
+
+        // Assert
+        assert_eq!(upserted, FinalityAction::UpsertProvisional);
+        assert_eq!(confirmed, FinalityAction::Confirm);
+        assert_eq!(corrected, FinalityAction::Correct { provisional: 2 });
+        assert_eq!(inserted, FinalityAction::InsertFinalized);
+        assert_eq!(skipped, FinalityAction::Skip);
+        assert_eq!(orphaned, vec![("forked", 3)]);
+        assert_eq!(reconciler.pending(), 1);
+    }
+}
@@ -0,0 +1,306 @@
+//! Skips pathologically large transactions before they are decoded.
+//!
+//! A single transaction with thousands of inner instructions or a long list of
+//! loaded accounts can stall decoding for every pipe. With
+//! `PipelineBuilder::transaction_limits`, transactions exceeding the
+//! `TransactionLimits` are skipped instead: no pipe processes them, the
+//! `transactions_skipped` counter and a counter for the exceeded limit, such
+//! as `transactions_skipped_too_many_instructions`, are incremented, and they
+//! are passed as a `SkippedTransaction` to the processor set with
+//! `PipelineBuilder::skipped_transactions`, if any, to be stored for offline
+//! inspection.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .transaction_limits(
+//!         TransactionLimits::new()
+//!             .max_instructions(1_000)
+//!             .max_accounts(512)
+//!             .max_instruction_data_len(64 * 1024),
+//!     )
+//!     .skipped_transactions(SkippedTransactionsSink::new(...))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Instructions are counted with their inner instructions, and accounts
+//!   with the accounts loaded from address lookup tables.
+//! - Skipping a transaction is not an error: the update is counted as
+//!   successful, unless the skipped transactions processor fails.
+//! - `max_instruction_data_len` does not skip transactions: instructions with
+//!   more data are not decoded by instruction pipes and are counted in the
+//!   `instructions_oversized` counter, but their transaction is still
+//!   processed, and transaction pipes receive them with their raw data in
+//!   the `NestedInstructions`.
+
+use {
+    crate::{datasource::TransactionUpdate, instruction::NestedInstruction},
+    serde::Serialize,
+    std::fmt,
+};
+
+/// The limits above which a transaction is skipped. No limit is set by
+/// default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TransactionLimits {
+    /// The maximum number of instructions, inner instructions included.
+    pub max_instructions: Option<usize>,
+    /// The maximum number of accounts, loaded accounts included.
+    pub max_accounts: Option<usize>,
+    /// The maximum length of the data of an instruction decoded by
+    /// instruction pipes, in bytes.
+    pub max_instruction_data_len: Option<usize>,
+}
+
+impl TransactionLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_instructions(mut self, max_instructions: usize) -> Self {
+        self.max_instructions = Some(max_instructions);
+        self
+    }
+
+    pub fn max_accounts(mut self, max_accounts: usize) -> Self {
+        self.max_accounts = Some(max_accounts);
+        self
+    }
+
+    pub fn max_instruction_data_len(mut self, max_instruction_data_len: usize) -> Self {
+        self.max_instruction_data_len = Some(max_instruction_data_len);
+        self
+    }
+
+    /// Returns the number of instructions of `nested_instructions`, inner
+    /// instructions included, with more data than
+    /// `max_instruction_data_len`.
+    pub fn oversized_instructions(&self, nested_instructions: &[NestedInstruction]) -> usize {
+        let Some(max) = self.max_instruction_data_len else {
+            return 0;
+        };
+
+        nested_instructions
+            .iter()
+            .map(|nested_instruction| {
+                usize::from(nested_instruction.instruction.data.len() > max)
+                    + self.oversized_instructions(&nested_instruction.inner_instructions)
+            })
+            .sum()
+    }
+
+    /// Returns the first limit `transaction_update` exceeds, if any.
+    pub fn check(&self, transaction_update: &TransactionUpdate) -> Option<LimitExceeded> {
+        if let Some(max) = self.max_instructions {
+            let count = instruction_count(transaction_update);
+            if count > max {
+                return Some(LimitExceeded::Instructions { count, max });
+            }
+        }
+
+        if let Some(max) = self.max_accounts {
+            let count = account_count(transaction_update);
+            if count > max {
+                return Some(LimitExceeded::Accounts { count, max });
+            }
+        }
+
+        None
+    }
+}
+
+/// The limit a skipped transaction exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitExceeded {
+    Instructions { count: usize, max: usize },
+    Accounts { count: usize, max: usize },
+}
+
+impl LimitExceeded {
+    /// The name of the counter incremented for transactions skipped for this
+    /// reason.
+    pub fn metric_name(&self) -> &'static str {
+        match self {
+            LimitExceeded::Instructions { .. } => "transactions_skipped_too_many_instructions",
+            LimitExceeded::Accounts { .. } => "transactions_skipped_too_many_accounts",
+        }
+    }
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitExceeded::Instructions { count, max } => {
+                write!(f, "{} instructions, more than the limit of {}", count, max)
+            }
+            LimitExceeded::Accounts { count, max } => {
+                write!(f, "{} accounts, more than the limit of {}", count, max)
+            }
+        }
+    }
+}
+
+/// A transaction skipped by the `TransactionLimits`, with the limit it
+/// exceeded.
+#[derive(Debug, Clone)]
+pub struct SkippedTransaction {
+    pub transaction_update: Box<TransactionUpdate>,
+    pub reason: LimitExceeded,
+}
+
+fn instruction_count(transaction_update: &TransactionUpdate) -> usize {
+    let inner_instructions = transaction_update
+        .meta
+        .inner_instructions
+        .as_ref()
+        .map(|inner_instructions| {
+            inner_instructions
+                .iter()
+                .map(|inner_instructions| inner_instructions.instructions.len())
+                .sum()
+        })
+        .unwrap_or(0);
+
+    transaction_update.transaction.message.instructions().len() + inner_instructions
+}
+
+fn account_count(transaction_update: &TransactionUpdate) -> usize {
+    let loaded_addresses = &transaction_update.meta.loaded_addresses;
+
+    transaction_update
+        .transaction
+        .message
+        .static_account_keys()
+        .len()
+        + loaded_addresses.writable.len()
+        + loaded_addresses.readonly.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            message::{v0::LoadedAddresses, Message, VersionedMessage},
+            pubkey::Pubkey,
+            signature::Signature,
+            transaction::VersionedTransaction,
+        },
+        solana_transaction_status::{InnerInstruction, InnerInstructions, TransactionStatusMeta},
+    };
+
+    fn transaction_update(instructions: usize, inner_instructions: usize) -> TransactionUpdate {
+        let compiled_instruction = CompiledInstruction::new_from_raw_parts(1, vec![], vec![0]);
+
+        TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(Message {
+                    account_keys: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                    instructions: vec![compiled_instruction.clone(); instructions],
+                    ..Message::default()
+                }),
+            },
+            meta: TransactionStatusMeta {
+                inner_instructions: Some(vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![
+                        InnerInstruction {
+                            instruction: compiled_instruction,
+                            stack_height: Some(2),
+                        };
+                        inner_instructions
+                    ],
+                }]),
+                loaded_addresses: LoadedAddresses {
+                    writable: vec![Pubkey::new_unique()],
+                    readonly: vec![],
+                },
+                ..TransactionStatusMeta::default()
+            },
+            is_vote: false,
+            slot: 1,
+            block_time: None,
+            commitment: None,
+        }
+    }
+
+    #[test]
+    fn test_check_counts_inner_instructions_and_loaded_accounts() {
+        // Arrange
+        let limits = TransactionLimits::new().max_instructions(4).max_accounts(3);
+
+        // Act
+        let within_limits = limits.check(&transaction_update(2, 2));
+        let too_many_instructions = limits.check(&transaction_update(2, 3));
+        let too_many_accounts = TransactionLimits::new()
+            .max_accounts(2)
+            .check(&transaction_update(1, 0));
+
+        // Assert
+        assert_eq!(within_limits, None);
+        assert_eq!(
+            too_many_instructions,
+            Some(LimitExceeded::Instructions { count: 5, max: 4 })
+        );
+        assert_eq!(
+            too_many_accounts,
+            Some(LimitExceeded::Accounts { count: 3, max: 2 })
+        );
+    }
+
+    fn nested_instruction(data_len: usize, inner: Vec<NestedInstruction>) -> NestedInstruction {
+        NestedInstruction {
+            metadata: crate::instruction::InstructionMetadata {
+                transaction_metadata: crate::transaction::TransactionMetadata::default(),
+                stack_height: 1,
+                index: 0,
+                absolute_path: vec![0],
+                compute_units_consumed: None,
+            },
+            instruction: solana_instruction::Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![],
+                data: vec![0; data_len],
+            },
+            inner_instructions: crate::instruction::NestedInstructions(inner),
+        }
+    }
+
+    #[test]
+    fn test_oversized_instructions_include_inner_instructions() {
+        // Arrange
+        let nested_instructions = vec![
+            nested_instruction(8, vec![nested_instruction(2_048, vec![])]),
+            nested_instruction(1_025, vec![nested_instruction(16, vec![])]),
+        ];
+
+        // Act
+        let oversized = TransactionLimits::new()
+            .max_instruction_data_len(1_024)
+            .oversized_instructions(&nested_instructions);
+        let unlimited = TransactionLimits::new().oversized_instructions(&nested_instructions);
+
+        // Assert
+        assert_eq!(oversized, 2);
+        assert_eq!(unlimited, 0);
+    }
+
+    #[test]
+    fn test_no_limits_skip_nothing() {
+        assert_eq!(
+            TransactionLimits::default().check(&transaction_update(100, 100)),
+            None
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Provides deterministic keys for the items produced by the pipeline.
+//!
+//! Processors that write to external storage may see the same update more than
+//! once, for example when a datasource reconnects or a backfill is restarted
+//! after a crash. An `IdempotencyKey` identifies a decoded item independently
+//! of when it was received, so sinks can use it as a unique key (for instance
+//! with an upsert, or as a message key for a deduplicating consumer) and make
+//! retries harmless.
+//!
+//! # Usage
+//!
+//! The keys are available from the metadata passed to processors:
+//!
+//! ```ignore
+//! async fn process(&mut self, (metadata, instruction, _): InstructionProcessorInputType<T>, _: Arc<MetricsCollection>) -> CarbonResult<()> {
+//!     let key = metadata.idempotency_key().to_string();
+//!     // INSERT ... ON CONFLICT (key) DO NOTHING
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Instruction keys combine the transaction signature with the instruction's
+//!   `absolute_path`, so every instruction of a transaction, including inner
+//!   instructions, has its own key.
+//! - Account keys combine the account's public key with the slot of the
+//!   update and, when the datasource provides it, its write version. Each
+//!   write to the account within a slot then has its own key. Without a write
+//!   version, the writes of a slot share a key, so an upsert keeps the latest
+//!   state of the account for that slot.
+
+use {solana_pubkey::Pubkey, solana_signature::Signature, std::fmt};
+
+/// A deterministic key identifying a decoded account or instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IdempotencyKey {
+    Instruction {
+        signature: Signature,
+        absolute_path: Vec<u8>,
+    },
+    Account {
+        pubkey: Pubkey,
+        slot: u64,
+        write_version: Option<u64>,
+    },
+}
+
+impl fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdempotencyKey::Instruction {
+                signature,
+                absolute_path,
+            } => {
+                write!(f, "{signature}")?;
+                for index in absolute_path {
+                    write!(f, ":{index}")?;
+                }
+                Ok(())
+            }
+            IdempotencyKey::Account {
+                pubkey,
+                slot,
+                write_version,
+            } => {
+                write!(f, "{pubkey}@{slot}")?;
+                if let Some(write_version) = write_version {
+                    write!(f, "/{write_version}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idempotency_key_display() {
+        // Arrange
+        let signature = Signature::default();
+        let pubkey = Pubkey::default();
+
+        // Act
+        let instruction_key = IdempotencyKey::Instruction {
+            signature,
+            absolute_path: vec![2, 0, 1],
+        };
+        let account_key = IdempotencyKey::Account {
+            pubkey,
+            slot: 42,
+            write_version: None,
+        };
+        let write_key = IdempotencyKey::Account {
+            pubkey,
+            slot: 42,
+            write_version: Some(7),
+        };
+
+        // Assert
+        assert_eq!(instruction_key.to_string(), format!("{signature}:2:0:1"));
+        assert_eq!(account_key.to_string(), format!("{pubkey}@42"));
+        assert_eq!(write_key.to_string(), format!("{pubkey}@42/7"));
+    }
+}
@@ -10,6 +10,8 @@
 //!   types.
 //! - **`InstructionPipe`**: A structure that processes instructions using a
 //!   decoder and a processor.
+//! - **`UnknownInstructionHook`**: A hook reporting instructions of a
+//!   decoder's program that the decoder did not recognize.
 //! - **`InstructionPipes`**: An async trait for processing instructions within
 //!   nested contexts.
 //! - **`NestedInstruction`**: Represents instructions with potential nested
@@ -21,11 +23,16 @@
 
 use {
     crate::{
-        error::CarbonResult, metrics::MetricsCollection, processor::Processor,
+        actions::ActionIdentifier,
+        error::CarbonResult,
+        idempotency::IdempotencyKey,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+        slot_clock::SlotClock,
         transaction::TransactionMetadata,
     },
     async_trait::async_trait,
-    serde::Deserialize,
+    serde::{Deserialize, Serialize},
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
     std::{
@@ -51,6 +58,13 @@ use {
 /// - `index`: The index of the instruction in the transaction. The index is
 ///   relative within stack height and is 1-based. Note that the inner instruction indexes are grouped into one vector,
 ///   so different inner instructions that have different stack heights may have continuous indexes.
+/// - `absolute_path`: The position of the instruction in the transaction's
+///   instruction tree, starting with the 0-based index of the top-level
+///   instruction followed by the 0-based index at each nesting level. Unlike
+///   `index`, it is unique within a transaction.
+/// - `compute_units_consumed`: The compute units consumed by the instruction,
+///   including the instructions it invoked, as reported in the transaction
+///   logs. `None` when the logs do not report it.
 ///
 
 #[derive(Debug, Clone)]
@@ -58,6 +72,31 @@ pub struct InstructionMetadata {
     pub transaction_metadata: TransactionMetadata,
     pub stack_height: u32,
     pub index: u32,
+    pub absolute_path: Vec<u8>,
+    pub compute_units_consumed: Option<u64>,
+}
+
+impl InstructionMetadata {
+    /// Returns the deterministic key identifying this instruction, made of the
+    /// transaction signature and the instruction's `absolute_path`.
+    pub fn idempotency_key(&self) -> IdempotencyKey {
+        IdempotencyKey::Instruction {
+            signature: self.transaction_metadata.signature,
+            absolute_path: self.absolute_path.clone(),
+        }
+    }
+
+    /// Returns the epoch and estimated time of the transaction's slot, if the
+    /// pipeline has a `ClusterSchedule`.
+    pub fn slot_clock(&self) -> Option<&SlotClock> {
+        self.transaction_metadata.slot_clock.as_ref()
+    }
+
+    /// Returns the Solana Actions identifiers of the transaction, if the
+    /// pipeline detects actions.
+    pub fn actions(&self) -> &[ActionIdentifier] {
+        &self.transaction_metadata.actions
+    }
 }
 
 pub type InstructionsWithMetadata = Vec<(InstructionMetadata, solana_instruction::Instruction)>;
@@ -78,12 +117,56 @@ pub type InstructionsWithMetadata = Vec<(InstructionMetadata, solana_instruction
 /// - `data`: The decoded data payload for the instruction, of type `T`.
 /// - `accounts`: A vector of `AccountMeta`, representing the accounts involved
 ///   in the instruction.
+/// - `audit`: The raw data and the matched discriminator of the instruction,
+///   captured when the `audit-trail` feature is enabled.
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct DecodedInstruction<T> {
     pub program_id: Pubkey,
     pub data: T,
     pub accounts: Vec<AccountMeta>,
+    #[serde(skip)]
+    pub audit: Option<InstructionAudit>,
+}
+
+/// The raw bytes an instruction was decoded from, for sinks keeping an audit
+/// trail of exactly what was decoded.
+///
+/// # Fields
+///
+/// - `discriminator`: The discriminator of the instruction type that matched,
+///   empty for types without one.
+/// - `data`: The raw data of the instruction, discriminator included.
+///
+/// # Notes
+///
+/// - Capturing copies the data of every decoded instruction, so it is only
+///   done with the `audit-trail` feature of `carbon-core`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InstructionAudit {
+    pub discriminator: &'static [u8],
+    pub data: Vec<u8>,
+}
+
+impl InstructionAudit {
+    /// Captures the data of an instruction decoded with `discriminator`, if
+    /// the `audit-trail` feature is enabled.
+    pub fn capture(discriminator: &'static [u8], data: &[u8]) -> Option<Self> {
+        if !cfg!(feature = "audit-trail") {
+            return None;
+        }
+
+        Some(Self {
+            discriminator,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns whether `instruction`, such as fetched again from the chain,
+    /// holds the captured data.
+    pub fn verify(&self, instruction: &solana_instruction::Instruction) -> bool {
+        instruction.data == self.data && self.data.starts_with(self.discriminator)
+    }
 }
 
 /// A trait for decoding Solana instructions into a structured type.
@@ -101,13 +184,35 @@ pub struct DecodedInstruction<T> {
 ///
 /// - `decode_instruction`: Decodes a raw Solana `Instruction` into a
 ///   `DecodedInstruction`.
+///
+/// # Provided Methods
+///
+/// - `decode_instruction_at_slot`: Decodes the instruction knowing the slot of
+///   its transaction. Defaults to `decode_instruction`; decoders supporting
+///   several layout versions, such as `VersionedInstructionDecoder`, override
+///   it.
+/// - `program_id`: The program whose instructions are decoded, used to derive
+///   the filters of a datasource. Defaults to `None`, for decoders of several
+///   programs or of a program without a fixed address.
 pub trait InstructionDecoder<'a> {
     type InstructionType;
 
+    fn program_id(&self) -> Option<Pubkey> {
+        None
+    }
+
     fn decode_instruction(
         &self,
         instruction: &'a solana_instruction::Instruction,
     ) -> Option<DecodedInstruction<Self::InstructionType>>;
+
+    fn decode_instruction_at_slot(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        _slot: u64,
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        self.decode_instruction(instruction)
+    }
 }
 
 /// The input type for the instruction processor.
@@ -133,11 +238,37 @@ pub type InstructionProcessorInputType<T> = (
 ///
 /// - `decoder`: The decoder used for parsing instructions.
 /// - `processor`: The processor that handles decoded instructions.
+/// - `on_unknown`: An optional `UnknownInstructionHook` called for
+///   instructions of the decoder's program that the decoder did not recognize.
+/// - `max_data_len`: The maximum length of the data of the instructions the
+///   decoder is given. Longer instructions are neither decoded nor reported
+///   as unknown.
 pub struct InstructionPipe<T: Send> {
     pub decoder:
         Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
     pub processor:
         Box<dyn Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static>,
+    pub on_unknown: Option<UnknownInstructionHook>,
+    pub max_data_len: Option<usize>,
+}
+
+/// A hook notified of instructions that belong to a decoder's program but
+/// could not be decoded, typically because the program added an instruction
+/// with a new discriminator in an upgrade.
+///
+/// Every unknown instruction also increments the `instructions_unknown`
+/// counter.
+///
+/// # Fields
+///
+/// - `program_id`: The program of the decoder. Instructions of other programs
+///   are never reported.
+/// - `hook`: Called with the metadata and the raw instruction, including its
+///   data and accounts.
+#[derive(Clone)]
+pub struct UnknownInstructionHook {
+    pub program_id: Pubkey,
+    pub hook: Arc<dyn Fn(&InstructionMetadata, &solana_instruction::Instruction) + Send + Sync>,
 }
 
 /// An async trait for processing instructions within nested contexts.
@@ -157,6 +288,33 @@ pub trait InstructionPipes<'a>: Send + Sync {
         nested_instruction: &NestedInstruction,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Decodes the instruction and its inner instructions without running the
+    /// processor, returning how many of them were recognized. Used by
+    /// `Pipeline::check`.
+    fn decode_only(&self, nested_instruction: &NestedInstruction) -> usize;
+
+    /// Sets the maximum length of the data of the instructions the pipe
+    /// decodes, from the `TransactionLimits` of the pipeline. Does nothing
+    /// by default.
+    fn set_max_data_len(&mut self, _max_data_len: Option<usize>) {}
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+impl<T: Send> InstructionPipe<T> {
+    fn is_oversized(&self, nested_instruction: &NestedInstruction) -> bool {
+        self.max_data_len
+            .is_some_and(|max| nested_instruction.instruction.data.len() > max)
+    }
 }
 
 #[async_trait]
@@ -171,10 +329,17 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
             nested_instruction,
         );
 
-        if let Some(decoded_instruction) = self
-            .decoder
-            .decode_instruction(&nested_instruction.instruction)
-        {
+        if self.is_oversized(nested_instruction) {
+            log::debug!(
+                "not decoding instruction {:?} of {}: {} bytes of data",
+                nested_instruction.metadata.absolute_path,
+                nested_instruction.metadata.transaction_metadata.signature,
+                nested_instruction.instruction.data.len()
+            );
+        } else if let Some(decoded_instruction) = self.decoder.decode_instruction_at_slot(
+            &nested_instruction.instruction,
+            nested_instruction.metadata.transaction_metadata.slot,
+        ) {
             self.processor
                 .process(
                     (
@@ -185,6 +350,15 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
                     metrics.clone(),
                 )
                 .await?;
+        } else if let Some(on_unknown) = &self.on_unknown {
+            if nested_instruction.instruction.program_id == on_unknown.program_id {
+                metrics.increment_counter("instructions_unknown", 1).await?;
+
+                (on_unknown.hook)(
+                    &nested_instruction.metadata,
+                    &nested_instruction.instruction,
+                );
+            }
         }
 
         for nested_inner_instruction in nested_instruction.inner_instructions.iter() {
@@ -193,6 +367,36 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
 
         Ok(())
     }
+
+    fn decode_only(&self, nested_instruction: &NestedInstruction) -> usize {
+        let decoded = (!self.is_oversized(nested_instruction)
+            && self
+                .decoder
+                .decode_instruction_at_slot(
+                    &nested_instruction.instruction,
+                    nested_instruction.metadata.transaction_metadata.slot,
+                )
+                .is_some()) as usize;
+
+        decoded
+            + nested_instruction
+                .inner_instructions
+                .iter()
+                .map(|inner_instruction| self.decode_only(inner_instruction))
+                .sum::<usize>()
+    }
+
+    fn set_max_data_len(&mut self, max_data_len: Option<usize>) {
+        self.max_data_len = max_data_len;
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
 }
 
 /// Represents a nested instruction with metadata, including potential inner
@@ -303,6 +507,8 @@ mod tests {
             transaction_metadata: TransactionMetadata::default(),
             stack_height,
             index,
+            absolute_path: vec![],
+            compute_units_consumed: None,
         };
         let instruction = Instruction {
             program_id: Pubkey::new_unique(),
@@ -394,4 +600,24 @@ mod tests {
         );
         assert!(nested_instructions.0[1].inner_instructions.is_empty());
     }
+
+    #[test]
+    fn test_instruction_audit_verify() {
+        let audit = InstructionAudit {
+            discriminator: &[1, 2],
+            data: vec![1, 2, 3],
+        };
+        let instruction = |data: Vec<u8>| Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data,
+        };
+
+        assert!(audit.verify(&instruction(vec![1, 2, 3])));
+        assert!(!audit.verify(&instruction(vec![1, 2, 4])));
+        assert_eq!(
+            InstructionAudit::capture(&[1, 2], &[1, 2, 3]).is_some(),
+            cfg!(feature = "audit-trail")
+        );
+    }
 }
@@ -0,0 +1,279 @@
+//! Counts decoded instructions per type, for insight into the traffic of a
+//! program without writing a processor.
+//!
+//! `PipelineBuilder::instruction_stats` adds an instruction pipe counting the
+//! instructions its decoder decodes, by variant, such as `Buy` or `Sell` for
+//! `PumpfunInstruction`. Each instruction increments the
+//! `instructions_decoded_<variant>` counter, the variant being in snake case,
+//! with a `program_id` pubkey label.
+//!
+//! With `PipelineBuilder::instruction_stats_summary`, the counts are also
+//! gathered per time bucket, and an `InstructionStats` summary is passed to a
+//! processor when each bucket ends, for example to log the traffic
+//! composition every minute.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction_stats_summary(
+//!         PumpfunDecoder,
+//!         Duration::from_secs(60),
+//!         LogStatsProcessor,
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Instructions are bucketed by the block time of their transaction, or by
+//!   the time they are processed when it is unknown, so backfills produce the
+//!   same summaries as live indexing.
+//! - A bucket ends when an instruction of a later bucket is processed, and
+//!   the last bucket when the pipeline shuts down. Instructions of an earlier
+//!   bucket, delivered out of order, are counted in the current one.
+//! - The variant is the tag the decoded type is serialized with, so the
+//!   decoded type must implement `Serialize`, as generated decoders do.
+
+use {
+    crate::{
+        clock::{system_clock, Clock},
+        error::CarbonResult,
+        instruction::InstructionProcessorInputType,
+        metrics::{MetricLabel, MetricsCollection},
+        processor::Processor,
+        row::{identifier, split_variant},
+    },
+    async_trait::async_trait,
+    serde::Serialize,
+    solana_pubkey::Pubkey,
+    std::{collections::BTreeMap, marker::PhantomData, sync::Arc, time::Duration},
+};
+
+/// The number of decoded instructions of each type within a time bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionStats {
+    /// The program of the counted instructions.
+    pub program_id: Pubkey,
+    /// The start of the bucket, in seconds since the Unix epoch.
+    pub bucket_start: i64,
+    pub bucket: Duration,
+    /// The number of instructions of each variant, such as `Buy`.
+    pub counts: BTreeMap<String, u64>,
+}
+
+impl InstructionStats {
+    /// Returns the number of instructions of all types.
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+}
+
+/// A processor counting decoded instructions per type, in metrics and,
+/// optionally, in an `InstructionStats` summary per time bucket.
+pub struct InstructionStatsProcessor<T> {
+    bucket: Duration,
+    summary_processor: Option<Box<dyn Processor<InputType = InstructionStats> + Send + Sync>>,
+    current: Option<InstructionStats>,
+    clock: Arc<dyn Clock>,
+    _instruction: PhantomData<fn(T)>,
+}
+
+impl<T> InstructionStatsProcessor<T> {
+    /// Counts instructions in metrics only.
+    pub fn new() -> Self {
+        Self {
+            bucket: Duration::from_secs(60),
+            summary_processor: None,
+            current: None,
+            clock: system_clock(),
+            _instruction: PhantomData,
+        }
+    }
+
+    /// Buckets instructions without a block time with `clock` rather than
+    /// the system time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Also passes an `InstructionStats` summary to `processor` at the end of
+    /// each bucket. Buckets shorter than a second are a second long.
+    pub fn with_summary(
+        mut self,
+        bucket: Duration,
+        processor: impl Processor<InputType = InstructionStats> + Send + Sync + 'static,
+    ) -> Self {
+        self.bucket = bucket.max(Duration::from_secs(1));
+        self.summary_processor = Some(Box::new(processor));
+        self
+    }
+
+    /// Counts an instruction at `timestamp`, returning the summary of the
+    /// bucket it ended, if any.
+    fn observe(
+        &mut self,
+        program_id: Pubkey,
+        timestamp: i64,
+        variant: String,
+    ) -> Option<InstructionStats> {
+        let bucket_secs = self.bucket.as_secs() as i64;
+        let bucket_start = timestamp - timestamp.rem_euclid(bucket_secs);
+
+        let ended = match &self.current {
+            Some(current) if current.bucket_start < bucket_start => self.current.take(),
+            _ => None,
+        };
+
+        let current = self.current.get_or_insert_with(|| InstructionStats {
+            program_id,
+            bucket_start,
+            bucket: self.bucket,
+            counts: BTreeMap::new(),
+        });
+        *current.counts.entry(variant).or_insert(0) += 1;
+
+        ended
+    }
+}
+
+impl<T> Default for InstructionStatsProcessor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Serialize + Send + Sync + 'static> Processor for InstructionStatsProcessor<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (metadata, instruction, _nested_instructions) = data;
+
+        let variant = variant_name(&instruction.data);
+
+        metrics
+            .increment_counter_with_labels(
+                &format!("instructions_decoded_{}", identifier(&variant)),
+                1,
+                &[MetricLabel::pubkey("program_id", &instruction.program_id)],
+            )
+            .await?;
+
+        if self.summary_processor.is_none() {
+            return Ok(());
+        }
+
+        let timestamp = metadata
+            .transaction_metadata
+            .block_time
+            .unwrap_or_else(|| self.clock.unix_timestamp());
+
+        match (
+            self.observe(instruction.program_id, timestamp, variant),
+            self.summary_processor.as_mut(),
+        ) {
+            (Some(stats), Some(processor)) => processor.process(stats, metrics).await,
+            _ => Ok(()),
+        }
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        match self.summary_processor.as_mut() {
+            Some(processor) => processor.setup(metrics).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        match self.summary_processor.as_mut() {
+            Some(processor) => processor.flush(metrics).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        let Some(processor) = self.summary_processor.as_mut() else {
+            return Ok(());
+        };
+
+        if let Some(stats) = self.current.take() {
+            processor.process(stats, metrics.clone()).await?;
+        }
+
+        processor.teardown(metrics).await
+    }
+}
+
+/// Returns the variant a decoded instruction is serialized with, such as
+/// `Buy` for `{"Buy": {...}}`, or `unknown` for other values.
+fn variant_name<T: Serialize>(data: &T) -> String {
+    serde_json::to_value(data)
+        .ok()
+        .and_then(|value| split_variant(value).ok())
+        .map(|(tag, _)| tag)
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    enum TestInstruction {
+        Buy { amount: u64 },
+        CollectFees,
+    }
+
+    #[test]
+    fn test_variant_name() {
+        assert_eq!(variant_name(&TestInstruction::Buy { amount: 1 }), "Buy");
+        assert_eq!(variant_name(&TestInstruction::CollectFees), "CollectFees");
+        assert_eq!(identifier("CollectFees"), "collect_fees");
+    }
+
+    #[test]
+    fn test_observe_ends_buckets() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let mut processor = InstructionStatsProcessor::<TestInstruction>::new();
+        processor.bucket = Duration::from_secs(60);
+
+        // Act
+        let ended: Vec<Option<InstructionStats>> =
+            [(120, "Buy"), (150, "Buy"), (130, "Sell"), (185, "Sell")]
+                .into_iter()
+                .map(|(timestamp, variant)| {
+                    processor.observe(program_id, timestamp, variant.to_string())
+                })
+                .collect();
+
+        // Assert
+        assert_eq!(
+            ended,
+            vec![
+                None,
+                None,
+                None,
+                Some(InstructionStats {
+                    program_id,
+                    bucket_start: 120,
+                    bucket: Duration::from_secs(60),
+                    counts: BTreeMap::from([("Buy".to_string(), 2), ("Sell".to_string(), 1)]),
+                }),
+            ]
+        );
+        assert_eq!(
+            processor.current.as_ref().map(InstructionStats::total),
+            Some(1)
+        );
+    }
+}
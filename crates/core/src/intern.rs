@@ -0,0 +1,208 @@
+//! Maps pubkeys to small integer ids, to shrink the storage of high
+//! throughput sinks.
+//!
+//! A pubkey takes 32 bytes, or 44 characters as base58 text, and sinks store
+//! several of them per row. A `PubkeyInterner` assigns each pubkey it sees a
+//! sequential `u64` id instead, so sinks can store 8-byte integers and join
+//! on them, and resolve them back to pubkeys through a mapping table.
+//!
+//! The mapping is persisted through a `PubkeyIdStore`, usually implemented by
+//! the sink itself so the mapping lives next to the data, and reloaded with
+//! `PubkeyInterner::with_store` when the pipeline restarts, keeping ids
+//! stable across runs.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = SqliteSink::open("indexer.db")?.intern_pubkeys().await?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account(TokenProgramDecoder, sink.accounts(carbon_sqlite_sink::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Interning is optional: sinks write pubkeys as text unless given an
+//!   interner.
+//! - A new id is stored before it is returned, so an id is never written
+//!   without its mapping. Ids are only assigned one at a time; known pubkeys
+//!   are resolved without waiting.
+
+use {
+    crate::error::{CarbonResult, Error},
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+    },
+    tokio::sync::Mutex,
+};
+
+/// Persists the ids assigned by a `PubkeyInterner`.
+#[async_trait]
+pub trait PubkeyIdStore: Send + Sync {
+    /// Returns every stored mapping.
+    async fn load(&self) -> CarbonResult<Vec<(Pubkey, u64)>>;
+
+    /// Stores the id assigned to `pubkey`.
+    async fn store(&self, pubkey: Pubkey, id: u64) -> CarbonResult<()>;
+}
+
+#[derive(Debug, Default)]
+struct Mapping {
+    ids: HashMap<Pubkey, u64>,
+    pubkeys: HashMap<u64, Pubkey>,
+    next_id: u64,
+}
+
+impl Mapping {
+    fn insert(&mut self, pubkey: Pubkey, id: u64) {
+        self.ids.insert(pubkey, id);
+        self.pubkeys.insert(id, pubkey);
+        self.next_id = self.next_id.max(id + 1);
+    }
+}
+
+/// Assigns sequential ids to pubkeys, shared by the processors of a pipeline.
+#[derive(Clone, Default)]
+pub struct PubkeyInterner {
+    mapping: Arc<RwLock<Mapping>>,
+    assigning: Arc<Mutex<()>>,
+    store: Option<Arc<dyn PubkeyIdStore>>,
+}
+
+impl PubkeyInterner {
+    /// Creates an interner keeping its mapping in memory only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an interner persisting its mapping to `store`, starting from
+    /// the mapping already stored.
+    pub async fn with_store(store: Arc<dyn PubkeyIdStore>) -> CarbonResult<Self> {
+        let mut mapping = Mapping::default();
+        for (pubkey, id) in store.load().await? {
+            mapping.insert(pubkey, id);
+        }
+
+        Ok(Self {
+            mapping: Arc::new(RwLock::new(mapping)),
+            assigning: Arc::new(Mutex::new(())),
+            store: Some(store),
+        })
+    }
+
+    /// Returns the id of `pubkey`, assigning and storing a new one if it has
+    /// none yet.
+    pub async fn intern(&self, pubkey: &Pubkey) -> CarbonResult<u64> {
+        if let Some(id) = self.get(pubkey)? {
+            return Ok(id);
+        }
+
+        let _assigning = self.assigning.lock().await;
+        if let Some(id) = self.get(pubkey)? {
+            return Ok(id);
+        }
+
+        let id = self.read(|mapping| mapping.next_id)?;
+        if let Some(store) = &self.store {
+            store.store(*pubkey, id).await?;
+        }
+        self.mapping
+            .write()
+            .map_err(|_| Error::Custom("Pubkey interner lock poisoned".to_string()))?
+            .insert(*pubkey, id);
+
+        Ok(id)
+    }
+
+    /// Returns the id of `pubkey`, if it has one.
+    pub fn get(&self, pubkey: &Pubkey) -> CarbonResult<Option<u64>> {
+        self.read(|mapping| mapping.ids.get(pubkey).copied())
+    }
+
+    /// Returns the pubkey with `id`, if any.
+    pub fn resolve(&self, id: u64) -> CarbonResult<Option<Pubkey>> {
+        self.read(|mapping| mapping.pubkeys.get(&id).copied())
+    }
+
+    /// Returns the number of interned pubkeys.
+    pub fn len(&self) -> CarbonResult<usize> {
+        self.read(|mapping| mapping.ids.len())
+    }
+
+    pub fn is_empty(&self) -> CarbonResult<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    fn read<T>(&self, read: impl FnOnce(&Mapping) -> T) -> CarbonResult<T> {
+        let mapping = self
+            .mapping
+            .read()
+            .map_err(|_| Error::Custom("Pubkey interner lock poisoned".to_string()))?;
+        Ok(read(&mapping))
+    }
+}
+
+impl std::fmt::Debug for PubkeyInterner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PubkeyInterner")
+            .field("len", &self.len().ok())
+            .field("persisted", &self.store.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        mappings: std::sync::Mutex<Vec<(Pubkey, u64)>>,
+    }
+
+    #[async_trait]
+    impl PubkeyIdStore for MemoryStore {
+        async fn load(&self) -> CarbonResult<Vec<(Pubkey, u64)>> {
+            Ok(self.mappings.lock().unwrap().clone())
+        }
+
+        async fn store(&self, pubkey: Pubkey, id: u64) -> CarbonResult<()> {
+            self.mappings.lock().unwrap().push((pubkey, id));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interned_ids_survive_a_restart() {
+        // Arrange
+        let store = Arc::new(MemoryStore::default());
+        let (first, second, third) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        // Act
+        let interner = PubkeyInterner::with_store(store.clone()).await.unwrap();
+        let first_id = interner.intern(&first).await.unwrap();
+        let second_id = interner.intern(&second).await.unwrap();
+        let first_id_again = interner.intern(&first).await.unwrap();
+
+        let restarted = PubkeyInterner::with_store(store.clone()).await.unwrap();
+        let second_id_after_restart = restarted.intern(&second).await.unwrap();
+        let third_id = restarted.intern(&third).await.unwrap();
+
+        // Assert
+        assert_eq!((first_id, second_id, first_id_again), (0, 1, 0));
+        assert_eq!((second_id_after_restart, third_id), (1, 2));
+        assert_eq!(restarted.resolve(0).unwrap(), Some(first));
+        assert_eq!(store.mappings.lock().unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,374 @@
+//! Shares the state of a pipeline across replicas through a key-value store.
+//!
+//! Pipelines keep some state beside their sinks: the slot to resume from, the
+//! keys of the updates already processed, the decimals of the mints seen so
+//! far. Kept in memory or in a local file, this state is lost on redeploy and
+//! not shared between the replicas of a cloud deployment. The
+//! `KeyValueStore` trait abstracts the store holding it, and is used by:
+//!
+//! - `KvCheckpoint`, loading and saving the slot a pipeline resumes from,
+//! - `Deduplicator`, telling whether an update was already processed by any
+//!   replica,
+//! - `MintDecimalsCache::load_from` and `MintDecimalsCache::save_to`, sharing
+//!   the decimals of mints.
+//!
+//! `MemoryKeyValueStore` keeps the entries in memory, for tests and single
+//! replica deployments. `carbon-redis-sink` provides a Redis store, and
+//! `carbon-dynamodb-store` a DynamoDB one.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let store: Arc<dyn KeyValueStore> = Arc::new(RedisKeyValueStore::new(connection, "indexer"));
+//!
+//! let checkpoint = KvCheckpoint::new(store.clone(), "checkpoint");
+//! let from_slot = checkpoint.load().await?;
+//!
+//! let pipeline = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         Deduplicated::new(PumpfunProcessor, Deduplicator::new(store, "seen")),
+//!     )
+//!     .event_bus(events.clone())
+//!     .build()?;
+//! checkpoint.track(&events);
+//! ```
+//!
+//! # Notes
+//!
+//! - Keys are strings and values bytes: each store maps them onto its own
+//!   types, and may prefix the keys to share a database between pipelines.
+//! - Entries expire after their TTL, when set. Stores without native expiry
+//!   check it on read.
+//! - `set_if_absent` must be atomic, as it is what replicas rely on to agree
+//!   on which of them processes an update.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        event_bus::{EventBus, PipelineEvent},
+        idempotency::IdempotencyKey,
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex, MutexGuard},
+        time::{Duration, Instant},
+    },
+    tokio::{sync::broadcast::error::RecvError, task::JoinHandle},
+};
+
+/// The default interval between the writes of a `KvCheckpoint`.
+pub const DEFAULT_CHECKPOINT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A store of byte values by string key, shared between replicas.
+#[async_trait]
+pub trait KeyValueStore: Send + Sync {
+    /// Returns the value of `key`, if set and not expired.
+    async fn get(&self, key: &str) -> CarbonResult<Option<Vec<u8>>>;
+
+    /// Sets the value of `key`, expiring after `ttl` if set.
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> CarbonResult<()>;
+
+    /// Sets the value of `key` unless it is already set, returning whether it
+    /// was set.
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> CarbonResult<bool>;
+
+    /// Removes `key`, if set.
+    async fn delete(&self, key: &str) -> CarbonResult<()>;
+}
+
+/// The values of a `MemoryKeyValueStore`, with their expiry.
+type MemoryEntries = HashMap<String, (Vec<u8>, Option<Instant>)>;
+
+/// A `KeyValueStore` keeping its entries in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryKeyValueStore {
+    entries: Arc<Mutex<MemoryEntries>>,
+}
+
+impl MemoryKeyValueStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> CarbonResult<MutexGuard<'_, MemoryEntries>> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::Custom("Memory key-value store poisoned".to_string()))
+    }
+}
+
+/// Returns whether an entry expiring at `expires_at` is still live at `now`.
+fn is_live(expires_at: Option<Instant>, now: Instant) -> bool {
+    expires_at.is_none_or(|expires_at| expires_at > now)
+}
+
+#[async_trait]
+impl KeyValueStore for MemoryKeyValueStore {
+    async fn get(&self, key: &str) -> CarbonResult<Option<Vec<u8>>> {
+        let entries = self.lock()?;
+
+        Ok(entries
+            .get(key)
+            .filter(|(_, expires_at)| is_live(*expires_at, Instant::now()))
+            .map(|(value, _)| value.clone()))
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> CarbonResult<()> {
+        let expires_at = ttl.map(|ttl| Instant::now() + ttl);
+        self.lock()?.insert(key.to_string(), (value, expires_at));
+
+        Ok(())
+    }
+
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> CarbonResult<bool> {
+        let now = Instant::now();
+        let mut entries = self.lock()?;
+
+        if entries
+            .get(key)
+            .is_some_and(|(_, expires_at)| is_live(*expires_at, now))
+        {
+            return Ok(false);
+        }
+        entries.insert(key.to_string(), (value, ttl.map(|ttl| now + ttl)));
+
+        Ok(true)
+    }
+
+    async fn delete(&self, key: &str) -> CarbonResult<()> {
+        self.lock()?.remove(key);
+
+        Ok(())
+    }
+}
+
+/// The slot a pipeline resumes from, saved in a `KeyValueStore`.
+#[derive(Clone)]
+pub struct KvCheckpoint {
+    store: Arc<dyn KeyValueStore>,
+    key: String,
+    interval: Duration,
+}
+
+impl KvCheckpoint {
+    pub fn new(store: Arc<dyn KeyValueStore>, key: impl Into<String>) -> Self {
+        Self {
+            store,
+            key: key.into(),
+            interval: DEFAULT_CHECKPOINT_INTERVAL,
+        }
+    }
+
+    /// Sets the interval between the writes of `track`. Defaults to one
+    /// second.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the saved slot, if any.
+    pub async fn load(&self) -> CarbonResult<Option<u64>> {
+        let Some(value) = self.store.get(&self.key).await? else {
+            return Ok(None);
+        };
+
+        String::from_utf8(value)
+            .ok()
+            .and_then(|slot| slot.trim().parse().ok())
+            .map(Some)
+            .ok_or_else(|| Error::Custom(format!("Invalid checkpoint {}", self.key)))
+    }
+
+    /// Saves `slot`.
+    pub async fn save(&self, slot: u64) -> CarbonResult<()> {
+        self.store
+            .set(&self.key, slot.to_string().into_bytes(), None)
+            .await
+    }
+
+    /// Spawns a task saving the `CheckpointAdvanced` events of `events`, at
+    /// most once per interval and when the pipeline stops.
+    ///
+    /// # Notes
+    ///
+    /// - This method must be called within a Tokio runtime, before the
+    ///   pipeline starts.
+    pub fn track(self, events: &EventBus) -> JoinHandle<()> {
+        let mut receiver = events.subscribe();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.interval);
+            let mut checkpoint = None;
+            let mut saved = None;
+
+            loop {
+                let stopped = tokio::select! {
+                    event = receiver.recv() => match event {
+                        Ok(PipelineEvent::CheckpointAdvanced { slot }) => {
+                            checkpoint = Some(slot);
+                            continue;
+                        }
+                        Ok(PipelineEvent::Stopped) | Err(RecvError::Closed) => true,
+                        Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                    },
+                    _ = interval.tick() => false,
+                };
+
+                if let Some(slot) = checkpoint.filter(|slot| saved != Some(*slot)) {
+                    match self.save(slot).await {
+                        Ok(()) => saved = Some(slot),
+                        Err(error) => log::error!("Failed to save checkpoint: {:?}", error),
+                    }
+                }
+                if stopped {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+/// Tells whether an update was already processed, by this replica or another
+/// one sharing its `KeyValueStore`.
+#[derive(Clone)]
+pub struct Deduplicator {
+    store: Arc<dyn KeyValueStore>,
+    key_prefix: String,
+    ttl: Option<Duration>,
+}
+
+impl Deduplicator {
+    /// Creates a deduplicator recording the keys seen as `{key_prefix}:{key}`.
+    pub fn new(store: Arc<dyn KeyValueStore>, key_prefix: impl Into<String>) -> Self {
+        Self {
+            store,
+            key_prefix: key_prefix.into(),
+            ttl: None,
+        }
+    }
+
+    /// Forgets the keys seen after `ttl`, bounding the size of the store to
+    /// the keys of the updates that can still be received again.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Records `key`, returning whether it was seen for the first time.
+    pub async fn first_seen(&self, key: &IdempotencyKey) -> CarbonResult<bool> {
+        self.store
+            .set_if_absent(
+                &format!("{}:{}", self.key_prefix, key),
+                Vec::new(),
+                self.ttl,
+            )
+            .await
+    }
+}
+
+/// An instruction processor wrapper skipping the instructions already
+/// processed, recorded by their `IdempotencyKey`.
+///
+/// Skipped instructions are counted by the `instructions_deduplicated`
+/// counter.
+pub struct Deduplicated<P> {
+    processor: P,
+    deduplicator: Deduplicator,
+}
+
+impl<P> Deduplicated<P> {
+    pub fn new(processor: P, deduplicator: Deduplicator) -> Self {
+        Self {
+            processor,
+            deduplicator,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for Deduplicated<P>
+where
+    T: Send + Sync + 'static,
+    P: Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync,
+{
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if !self
+            .deduplicator
+            .first_seen(&data.0.idempotency_key())
+            .await?
+        {
+            metrics
+                .increment_counter("instructions_deduplicated", 1)
+                .await?;
+            return Ok(());
+        }
+
+        self.processor.process(data, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_pubkey::Pubkey};
+
+    #[tokio::test]
+    async fn test_checkpoint_and_deduplicator_share_a_store() {
+        // Arrange
+        let store: Arc<dyn KeyValueStore> = Arc::new(MemoryKeyValueStore::new());
+        let checkpoint = KvCheckpoint::new(store.clone(), "checkpoint");
+        let replica_a = Deduplicator::new(store.clone(), "seen");
+        let replica_b = Deduplicator::new(store.clone(), "seen");
+        let key = IdempotencyKey::Account {
+            pubkey: Pubkey::new_unique(),
+            slot: 42,
+            write_version: None,
+        };
+
+        // Act
+        let missing = checkpoint.load().await.unwrap();
+        checkpoint.save(42).await.unwrap();
+        let saved = checkpoint.load().await.unwrap();
+        let first = replica_a.first_seen(&key).await.unwrap();
+        let second = replica_b.first_seen(&key).await.unwrap();
+
+        // Assert
+        assert_eq!(missing, None);
+        assert_eq!(saved, Some(42));
+        assert!(first);
+        assert!(!second);
+    }
+}
@@ -0,0 +1,159 @@
+//! Monitors how far the pipeline is behind the tip of the chain.
+//!
+//! The `LagMonitor` periodically queries an RPC node for the latest slot and
+//! compares it with the slot of the last update processed by the pipeline. The
+//! difference is exported as the `pipeline_lag_slots` gauge, alongside
+//! `chain_tip_slot` and `last_processed_slot`, so operators can tell when an
+//! indexer is falling behind.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .lag_monitor(
+//!         LagMonitor::new("https://api.mainnet-beta.solana.com".to_string())
+//!             .interval(Duration::from_secs(10))
+//!             .alert_threshold(150, |lag| {
+//!                 log::warn!("indexer is {} slots behind", lag.lag_slots);
+//!             }),
+//!     )
+//!     .build()?;
+//! ```
+//!
+//! # Notes
+//!
+//! - No lag is reported until the pipeline has processed its first update.
+//! - The monitor stops when the pipeline's datasource cancellation token is
+//!   cancelled or the pipeline shuts down.
+
+use {
+    crate::{metrics::MetricsCollection, status::PipelineStatus},
+    serde::Serialize,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::commitment_config::CommitmentConfig,
+    std::{
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        time::Duration,
+    },
+    tokio::task::JoinHandle,
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default interval between two chain tip queries.
+pub const DEFAULT_LAG_MONITOR_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The lag observed by the `LagMonitor` at a given point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PipelineLag {
+    pub chain_tip_slot: u64,
+    pub last_processed_slot: u64,
+    pub lag_slots: u64,
+}
+
+/// A hook called when the lag exceeds the alert threshold.
+pub type LagAlertHook = Arc<dyn Fn(PipelineLag) + Send + Sync>;
+
+/// Periodically measures the pipeline's lag against the chain tip.
+pub struct LagMonitor {
+    pub rpc_url: String,
+    pub commitment: CommitmentConfig,
+    pub interval: Duration,
+    pub alert_threshold: Option<(u64, LagAlertHook)>,
+}
+
+impl LagMonitor {
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            rpc_url,
+            commitment: CommitmentConfig::confirmed(),
+            interval: DEFAULT_LAG_MONITOR_INTERVAL,
+            alert_threshold: None,
+        }
+    }
+
+    /// Sets the commitment used to query the chain tip. Defaults to
+    /// `confirmed`.
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    /// Sets how often the chain tip is queried. Defaults to 10 seconds.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Calls `hook` every time the measured lag is greater than `lag_slots`.
+    pub fn alert_threshold(
+        mut self,
+        lag_slots: u64,
+        hook: impl Fn(PipelineLag) + Send + Sync + 'static,
+    ) -> Self {
+        self.alert_threshold = Some((lag_slots, Arc::new(hook)));
+        self
+    }
+
+    pub(crate) fn spawn(
+        &self,
+        last_processed_slot: Arc<AtomicU64>,
+        metrics: Arc<MetricsCollection>,
+        status: PipelineStatus,
+        cancellation_token: CancellationToken,
+    ) -> JoinHandle<()> {
+        let rpc_client = RpcClient::new_with_commitment(self.rpc_url.clone(), self.commitment);
+        let interval = self.interval;
+        let alert_threshold = self.alert_threshold.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+
+                let chain_tip_slot = match rpc_client.get_slot().await {
+                    Ok(slot) => slot,
+                    Err(error) => {
+                        log::error!("error fetching chain tip slot: {:?}", error);
+                        continue;
+                    }
+                };
+
+                let last_processed_slot = last_processed_slot.load(Ordering::Relaxed);
+                if last_processed_slot == 0 {
+                    continue;
+                }
+
+                let lag = PipelineLag {
+                    chain_tip_slot,
+                    last_processed_slot,
+                    lag_slots: chain_tip_slot.saturating_sub(last_processed_slot),
+                };
+                status.record_lag(lag);
+
+                for (name, value) in [
+                    ("chain_tip_slot", lag.chain_tip_slot),
+                    ("last_processed_slot", lag.last_processed_slot),
+                    ("pipeline_lag_slots", lag.lag_slots),
+                ] {
+                    if let Err(error) = metrics.update_gauge(name, value as f64).await {
+                        log::error!("error recording metric: {:?}", error);
+                    }
+                }
+
+                if let Some((threshold, hook)) = alert_threshold.as_ref() {
+                    if lag.lag_slots > *threshold {
+                        hook(lag);
+                    }
+                }
+            }
+        })
+    }
+}
@@ -19,24 +19,105 @@
 //! - **[`account_deletion`]**: Handles the deletion of accounts and processes
 //!   these events in the pipeline.
 //!
+//! - **[`account_lifecycle`]**: Detects the ownership changes and closures of
+//!   accounts, including the closures not reported by an account deletion.
+//!
+//! - **[`account_filter`]**: Builds the `getProgramAccounts` and
+//!   subscription filters of an account type from the discriminator and size
+//!   exposed by its decoder, and its rent-exempt minimum balance.
+//!
+//! - **[`actions`]**: Parses the Solana Actions identifiers of transactions,
+//!   such as the transactions built through a Blink.
+//!
+//! - **[`block_details`]**: Handles block metadata and rewards, delivered once
+//!   per block by block-based datasources.
+//!
+//! - **[`block_time`]**: Provides the `BlockTimeEnricher`, resolving the block
+//!   time of transactions delivered without one.
+//!
+//! - **[`bundle`]**: Detects Jito bundles in the transactions of the pipeline
+//!   and groups them into `BundleUpdate`s for bundle pipes.
+//!
+//! - **[`circuit_breaker`]**: Provides the `CircuitBreaker` processor
+//!   wrapper, skipping a failing processor until a trial call succeeds.
+//!
+//! - **[`clock`]**: Defines the `Clock` trait abstracting time, with the
+//!   `SystemClock` used by default and a `ManualClock` for deterministic
+//!   tests.
+//!
 //! - **[`collection`]**: Defines collections for instruction decoding, allowing
 //!   for customized instruction parsers that handle specific instruction sets.
 //!
+//! - **[`concurrency`]**: Processes the updates of a pipe concurrently, with
+//!   a `ConcurrencyLimit` on the updates in flight shared by several pipes.
+//!
+//! - **[`contention`]**: Reports the accounts write-locked by the most
+//!   transactions of each slot, for contention analytics.
+//!
 //! - **[`datasource`]**: Provides data ingestion capabilities, enabling the
 //!   integration of external data sources into the pipeline. Supports
 //!   Solana-specific data structures.
 //!
+//! - **[`debug_print`]**: Provides the `DebugPrintProcessor`, printing decoded
+//!   instructions as JSON while validating a decoder.
+//!
+//! - **[`derived`]**: Provides derived channels, feeding the updates emitted
+//!   by a pipe into other pipes of the same pipeline.
+//!
+//! - **[`description`]**: Provides the `PipelineDescription` returned by
+//!   `Pipeline::describe`, naming and summarizing the components of a
+//!   pipeline.
+//!
 //! - **[`deserialize`]**: Contains utilities for data deserialization,
 //!   including helper functions for parsing Solana transactions and other
 //!   binary data formats.
 //!
+//! - **[`epoch`]**: Detects the epoch boundaries in the slots of the updates
+//!   and passes `EpochChange`s, with their leader schedule, to epoch pipes.
+//!
 //! - **[`error`]**: Defines error types used throughout the crate, providing
 //!   consistent error handling for the framework.
 //!
+//! - **[`event`]**: Extracts the Anchor events of transactions, whether logged
+//!   with `emit!` or emitted through a self-CPI with `emit_cpi!`, and
+//!   processes them through a single pipe.
+//!
+//! - **[`event_bus`]**: Provides the `EventBus` on which a pipeline emits
+//!   its lifecycle events, such as a datasource disconnecting or a shutdown
+//!   starting, for custom supervision and alerting.
+//!
+//! - **[`failure`]**: Describes why a transaction failed, mapping custom
+//!   error codes to the named errors of the failing program.
+//!
+//! - **[`finality`]**: Reconciles the provisional updates of a dual commitment
+//!   datasource with their finalized copies.
+//!
+//! - **[`guardrails`]**: Provides the `TransactionLimits` skipping
+//!   pathologically large transactions before they are decoded.
+//!
+//! - **[`idempotency`]**: Defines the deterministic keys identifying decoded
+//!   accounts and instructions, so sinks can deduplicate retried writes.
+//!
 //! - **[`instruction`]**: Supports instruction parsing and processing within
 //!   transactions. This module includes structures and traits for decoding and
 //!   handling transaction instructions.
 //!
+//! - **[`instruction_stats`]**: Provides the `InstructionStatsProcessor`,
+//!   counting decoded instructions per type in metrics and in periodic
+//!   summaries.
+//!
+//! - **[`intern`]**: Provides the `PubkeyInterner`, mapping pubkeys to small
+//!   integer ids persisted by sinks to shrink their storage.
+//!
+//! - **[`kv_store`]**: Defines the `KeyValueStore` trait sharing checkpoints,
+//!   deduplication keys and mint decimals between the replicas of a pipeline.
+//!
+//! - **[`lag`]**: Provides the `LagMonitor`, which exports how far the
+//!   pipeline is behind the chain tip and raises alerts past a threshold.
+//!
+//! - **[`local_replay`]**: Replays indexed transactions and instructions
+//!   against a local test validator, for debugging.
+//!
 //! - **[`metrics`]**: Facilitates performance monitoring and metric recording
 //!   within the pipeline. Metrics can be customized and are recorded at each
 //!   processing stage for monitoring and debugging purposes.
@@ -46,24 +127,93 @@
 //!   integrates data sources, processing pipes, and metrics to provide a
 //!   complete data processing solution.
 //!
+//! - **[`mev`]**: Provides the `MevDetector`, flagging sandwiches and atomic
+//!   arbitrages in the Jito bundles of a pipeline.
+//!
+//! - **[`ordering`]**: Provides the `OrderedProcessor` keeping the decoded
+//!   updates of each account in order, so state stores never regress.
+//!
+//! - **[`preset`]**: Provides the `PipelinePreset` defaults for low-latency
+//!   indexing and bulk backfills, selectable from the environment.
+//!
 //! - **[`processor`]**: Contains traits and implementations for processing data
 //!   in the pipeline. This module allows for the creation of custom data
 //!   processors that can be integrated into various stages of the pipeline.
 //!
+//! - **[`rate_of_change`]**: Provides the `RateOfChangeProcessor`, alerting
+//!   when a numeric field of decoded accounts changes too fast.
+//!
+//! - **[`registry`]**: Maps known program ids to the decoder crates of this
+//!   repository, and detects which of them match a transaction.
+//!
+//! - **[`replay`]**: Provides the `ReplayEngine`, rebuilding the history of
+//!   account states by replaying decoded instructions into a `StateModel`.
+//!
+//! - **[`row`]**: Flattens decoded data into table rows, and creates the
+//!   tables and columns of SQL sinks to fit them.
+//!
 //! - **[`schema`]**: Defines transaction schemas, allowing for structured
 //!   parsing and validation of transaction data based on specified rules.
 //!   Supports complex nested instruction matching for comprehensive transaction
 //!   analysis.
 //!
+//! - **[`serializer`]**: Defines the `SinkSerializer` trait, the output
+//!   format of the events written by sinks, with JSON and CSV serializers.
+//!
+//! - **[`sharding`]**: Provides the `ShardedDatasource`, splitting updates
+//!   across several instances by pubkey, signature or slot.
+//!
+//! - **[`shared_datasource`]**: Shares one datasource connection between
+//!   several pipelines, broadcasting each update to all of them.
+//!
+//! - **[`slot_alignment`]**: Provides the `SlotAlignedDatasource`, merging
+//!   several datasources into one stream of updates reordered by slot within
+//!   a skew window.
+//!
+//! - **[`slot_clock`]**: Computes the `SlotClock` of a slot, its epoch and
+//!   estimated time, from the `ClusterSchedule` of the cluster.
+//!
+//! - **[`slot_range`]**: Provides the `SlotRangeWatermark`, telling when the
+//!   slot range of a pipeline has been fully processed from the chain of its
+//!   blocks.
+//!
+//! - **[`status`]**: Tracks the `PipelineStatus` of a running pipeline, with
+//!   its last processed slot, lag, pipe counters and recent errors.
+//!
+//! - **`status_server`**: Serves the status over HTTP, with `/healthz` and
+//!   `/readyz` probes, with the `status-server` feature.
+//!
+//! - **[`supervision`]**: Provides the `PipelineHandle` of a spawned
+//!   pipeline, restarts datasource tasks that panic, and turns panics of
+//!   processors into pipe errors.
+//!
+//! - **[`timestamp`]**: Attaches both the embedded timestamp and the block
+//!   time to decoded events, resolving the one sinks should use.
+//!
+//! - **[`trace`]**: Defines the `TraceId` assigned to each update when it
+//!   enters the pipeline, allowing an update to be followed end to end.
+//!
 //! - **[`transaction`]**: Manages transaction data, including metadata
 //!   extraction and parsing. This module supports transaction validation and
 //!   processing, enabling detailed transaction insights.
 //!
+//! - **[`transaction_status`]**: Handles transaction status updates, which
+//!   carry only the signature, slot and error of a transaction.
+//!
 //! - **[`transformers`]**: Provides utility functions for transforming and
 //!   restructuring data. This module includes functions for converting Solana
 //!   transaction data into formats suitable for processing within the
 //!   framework.
 //!
+//! - **[`ui_amount`]**: Adds decimal-adjusted `ui_` amounts to decoded
+//!   outputs, using the decimals of mints cached from token balances.
+//!
+//! - **[`verification`]**: Verifies the signatures of transactions from less
+//!   trusted datasources, flagging or dropping those that fail.
+//!
+//! - **[`versioned`]**: Provides decoders selecting between several layout
+//!   versions of an account or instruction, by slot range or runtime flag.
+//!
 //! ## Quick Start
 //!
 //! To create a new `carbon-core` pipeline, start by configuring data sources,
@@ -113,17 +263,65 @@
 
 pub mod account;
 pub mod account_deletion;
+pub mod account_filter;
+pub mod account_lifecycle;
+pub mod actions;
+pub mod block_details;
+pub mod block_time;
+pub mod bundle;
+pub mod circuit_breaker;
+pub mod clock;
 pub mod collection;
+pub mod concurrency;
+pub mod contention;
 pub mod datasource;
+pub mod debug_print;
+pub mod derived;
+pub mod description;
 pub mod deserialize;
+pub mod epoch;
 pub mod error;
+pub mod event;
+pub mod event_bus;
+pub mod failure;
+pub mod finality;
+pub mod guardrails;
+pub mod idempotency;
 pub mod instruction;
+pub mod instruction_stats;
+pub mod intern;
+pub mod kv_store;
+pub mod lag;
+pub mod local_replay;
 pub mod metrics;
+pub mod mev;
+pub mod ordering;
 pub mod pipeline;
+pub mod preset;
 pub mod processor;
+pub mod rate_of_change;
+pub mod registry;
+pub mod replay;
+pub mod row;
 pub mod schema;
+pub mod serializer;
+pub mod sharding;
+pub mod shared_datasource;
+pub mod slot_alignment;
+pub mod slot_clock;
+pub mod slot_range;
+pub mod status;
+#[cfg(feature = "status-server")]
+pub mod status_server;
+pub mod supervision;
+pub mod timestamp;
+pub mod trace;
 pub mod transaction;
+pub mod transaction_status;
 pub mod transformers;
+pub mod ui_amount;
+pub mod verification;
+pub mod versioned;
 pub use borsh;
 #[cfg(feature = "macros")]
 pub use carbon_macros::*;
@@ -0,0 +1,374 @@
+//! Replays indexed transactions against a local test validator, for
+//! debugging.
+//!
+//! A transaction seen by the pipeline can be re-executed locally once the
+//! accounts it used are cloned into a `solana-test-validator`. A
+//! `LocalReplay` rebuilds an executable transaction from the
+//! `TransactionMetadata` of an indexed transaction, or from the
+//! `InstructionMetadata` of one of its decoded instructions, and simulates it
+//! on the validator, returning its logs and outcome next to the original
+//! ones.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Start the validator with the accounts of the transaction:
+//! // solana-test-validator --url mainnet-beta <validator_args(&transaction_metadata)>
+//! println!("{}", validator_args(&transaction_metadata).join(" "));
+//!
+//! let replay = LocalReplay::new(DEFAULT_LOCAL_RPC_URL.to_string());
+//! let outcome = replay.replay_transaction(&transaction_metadata).await?;
+//! if !outcome.matches_original() {
+//!     for log in &outcome.logs {
+//!         println!("{}", log);
+//!     }
+//! }
+//!
+//! // Or only the top-level instruction of a decoded instruction:
+//! let outcome = replay.replay_instruction(&instruction_metadata).await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Instructions are rebuilt from the raw message of the transaction rather
+//!   than encoded back from their decoded form, so the instructions of every
+//!   decoder can be replayed.
+//! - Transactions are simulated without signature verification and with a
+//!   recent blockhash of the validator, as their signatures cannot be
+//!   reproduced. The cloned accounts are in their current state rather than
+//!   their state before the transaction, so a replay of an old transaction
+//!   may differ from the original.
+//! - Only top-level instructions can be replayed alone: the replay of an
+//!   inner instruction replays the top-level instruction invoking it.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        instruction::InstructionMetadata,
+        transaction::TransactionMetadata,
+    },
+    solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    solana_sdk::{
+        message::{Message, VersionedMessage},
+        signature::Signature,
+        transaction::{TransactionError, VersionedTransaction},
+    },
+};
+
+/// The RPC URL of a `solana-test-validator` started with its defaults.
+pub const DEFAULT_LOCAL_RPC_URL: &str = "http://127.0.0.1:8899";
+
+/// The programs a test validator starts with, which must not be cloned.
+pub const PRELOADED_PROGRAM_IDS: [Pubkey; 12] = [
+    Pubkey::from_str_const("11111111111111111111111111111111"),
+    Pubkey::from_str_const("ComputeBudget111111111111111111111111111111"),
+    Pubkey::from_str_const("Vote111111111111111111111111111111111111111"),
+    Pubkey::from_str_const("Stake11111111111111111111111111111111111111"),
+    Pubkey::from_str_const("Config1111111111111111111111111111111111111"),
+    Pubkey::from_str_const("AddressLookupTab1e1111111111111111111111111"),
+    Pubkey::from_str_const("BPFLoader2111111111111111111111111111111111"),
+    Pubkey::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111"),
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+    Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+];
+
+/// The outcome of a replayed transaction, next to the original one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayOutcome {
+    /// The signature of the original transaction.
+    pub signature: Signature,
+    pub err: Option<TransactionError>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+    pub original_err: Option<TransactionError>,
+    pub original_logs: Vec<String>,
+    pub original_units_consumed: Option<u64>,
+}
+
+impl ReplayOutcome {
+    /// Returns whether the replay succeeded or failed as the original did.
+    pub fn matches_original(&self) -> bool {
+        self.err == self.original_err
+    }
+}
+
+/// Returns the `solana-test-validator` arguments cloning the accounts and
+/// programs a transaction uses, preloaded programs excluded.
+///
+/// Invoked programs are cloned with `--clone-upgradeable-program`, other
+/// accounts and address lookup tables with `--maybe-clone`, which skips the
+/// accounts that do not exist.
+pub fn validator_args(transaction_metadata: &TransactionMetadata) -> Vec<String> {
+    let account_keys = transaction_metadata.account_keys();
+    let program_ids: Vec<Pubkey> = transaction_metadata
+        .message
+        .instructions()
+        .iter()
+        .filter_map(|instruction| {
+            account_keys
+                .get(instruction.program_id_index as usize)
+                .copied()
+        })
+        .collect();
+    let lookup_tables = transaction_metadata
+        .message
+        .address_table_lookups()
+        .unwrap_or_default()
+        .iter()
+        .map(|lookup| lookup.account_key);
+
+    let mut args = Vec::new();
+    let mut cloned = Vec::new();
+    for pubkey in account_keys.iter().copied().chain(lookup_tables) {
+        if PRELOADED_PROGRAM_IDS.contains(&pubkey) || cloned.contains(&pubkey) {
+            continue;
+        }
+        cloned.push(pubkey);
+
+        let flag = if program_ids.contains(&pubkey) {
+            "--clone-upgradeable-program"
+        } else {
+            "--maybe-clone"
+        };
+        args.push(flag.to_string());
+        args.push(pubkey.to_string());
+    }
+
+    args
+}
+
+/// Rebuilds a transaction with the message of an indexed transaction, and
+/// placeholder signatures.
+pub fn rebuild_transaction(transaction_metadata: &TransactionMetadata) -> VersionedTransaction {
+    let num_signatures = transaction_metadata.signers().len().max(1);
+    let mut signatures = vec![Signature::default(); num_signatures];
+    signatures[0] = transaction_metadata.signature;
+
+    VersionedTransaction {
+        signatures,
+        message: transaction_metadata.message.clone(),
+    }
+}
+
+/// Rebuilds a transaction made only of the top-level instruction at the start
+/// of the path of a decoded instruction, paid by the original fee payer.
+pub fn rebuild_instruction(
+    instruction_metadata: &InstructionMetadata,
+) -> CarbonResult<VersionedTransaction> {
+    let transaction_metadata = &instruction_metadata.transaction_metadata;
+    let index = instruction_metadata
+        .absolute_path
+        .first()
+        .copied()
+        .unwrap_or(instruction_metadata.index as u8) as usize;
+
+    let compiled_instruction = transaction_metadata
+        .message
+        .instructions()
+        .get(index)
+        .ok_or_else(|| {
+            Error::Custom(format!(
+                "Transaction {} has no top-level instruction {}",
+                transaction_metadata.signature, index
+            ))
+        })?;
+
+    let account_keys = transaction_metadata.account_keys();
+    let writable_accounts = transaction_metadata.writable_accounts();
+    let account_key = |account_index: u8| {
+        account_keys
+            .get(account_index as usize)
+            .copied()
+            .ok_or_else(|| {
+                Error::Custom(format!(
+                    "Transaction {} has no account {}",
+                    transaction_metadata.signature, account_index
+                ))
+            })
+    };
+
+    let accounts = compiled_instruction
+        .accounts
+        .iter()
+        .map(|account_index| {
+            let pubkey = account_key(*account_index)?;
+            Ok(AccountMeta {
+                pubkey,
+                is_signer: transaction_metadata.is_signer(&pubkey),
+                is_writable: writable_accounts.contains(&pubkey),
+            })
+        })
+        .collect::<CarbonResult<Vec<AccountMeta>>>()?;
+    let instruction = Instruction {
+        program_id: account_key(compiled_instruction.program_id_index)?,
+        accounts,
+        data: compiled_instruction.data.clone(),
+    };
+
+    let message = Message::new(&[instruction], Some(&transaction_metadata.fee_payer));
+    let num_signatures = message.header.num_required_signatures.max(1) as usize;
+
+    Ok(VersionedTransaction {
+        signatures: vec![Signature::default(); num_signatures],
+        message: VersionedMessage::Legacy(message),
+    })
+}
+
+/// Simulates indexed transactions on a local test validator.
+pub struct LocalReplay {
+    rpc_client: RpcClient,
+}
+
+impl LocalReplay {
+    /// Creates a replay against the validator at `rpc_url`, such as
+    /// `DEFAULT_LOCAL_RPC_URL`.
+    pub fn new(rpc_url: String) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_url),
+        }
+    }
+
+    /// Replays a whole indexed transaction.
+    pub async fn replay_transaction(
+        &self,
+        transaction_metadata: &TransactionMetadata,
+    ) -> CarbonResult<ReplayOutcome> {
+        log::trace!(
+            "replay_transaction(transaction_metadata: {:?})",
+            transaction_metadata.signature
+        );
+
+        self.simulate(
+            rebuild_transaction(transaction_metadata),
+            transaction_metadata,
+        )
+        .await
+    }
+
+    /// Replays the top-level instruction of a decoded instruction alone.
+    pub async fn replay_instruction(
+        &self,
+        instruction_metadata: &InstructionMetadata,
+    ) -> CarbonResult<ReplayOutcome> {
+        log::trace!(
+            "replay_instruction(instruction_metadata: {:?})",
+            instruction_metadata.absolute_path
+        );
+
+        self.simulate(
+            rebuild_instruction(instruction_metadata)?,
+            &instruction_metadata.transaction_metadata,
+        )
+        .await
+    }
+
+    async fn simulate(
+        &self,
+        transaction: VersionedTransaction,
+        transaction_metadata: &TransactionMetadata,
+    ) -> CarbonResult<ReplayOutcome> {
+        let result = self
+            .rpc_client
+            .simulate_transaction_with_config(
+                &transaction,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    ..RpcSimulateTransactionConfig::default()
+                },
+            )
+            .await
+            .map_err(|error| {
+                Error::Custom(format!(
+                    "Failed to replay transaction {}: {}",
+                    transaction_metadata.signature, error
+                ))
+            })?
+            .value;
+
+        Ok(ReplayOutcome {
+            signature: transaction_metadata.signature,
+            err: result.err,
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+            original_err: transaction_metadata.meta.status.clone().err(),
+            original_logs: transaction_metadata
+                .meta
+                .log_messages
+                .clone()
+                .unwrap_or_default(),
+            original_units_consumed: transaction_metadata.meta.compute_units_consumed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_program::message::{legacy, MessageHeader},
+        solana_sdk::instruction::CompiledInstruction,
+    };
+
+    #[test]
+    fn test_rebuild_top_level_instruction() {
+        // Arrange
+        let (fee_payer, pool, program_id, token_program) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            PRELOADED_PROGRAM_IDS[8],
+        );
+        let transaction_metadata = TransactionMetadata {
+            fee_payer,
+            message: VersionedMessage::Legacy(legacy::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 2,
+                },
+                account_keys: vec![fee_payer, pool, program_id, token_program],
+                instructions: vec![
+                    CompiledInstruction::new_from_raw_parts(3, vec![1], vec![0]),
+                    CompiledInstruction::new_from_raw_parts(2, vec![7, 8], vec![0, 1, 3]),
+                ],
+                ..legacy::Message::default()
+            }),
+            ..TransactionMetadata::default()
+        };
+        let instruction_metadata = InstructionMetadata {
+            transaction_metadata: transaction_metadata.clone(),
+            stack_height: 2,
+            index: 3,
+            absolute_path: vec![1, 0],
+            compute_units_consumed: None,
+        };
+
+        // Act
+        let transaction = rebuild_instruction(&instruction_metadata).unwrap();
+
+        // Assert
+        let VersionedMessage::Legacy(message) = transaction.message else {
+            panic!("expected a legacy message");
+        };
+        assert_eq!(message.account_keys[0], fee_payer);
+        assert_eq!(message.instructions.len(), 1);
+        assert_eq!(message.instructions[0].data, vec![7, 8]);
+        assert_eq!(transaction.signatures.len(), 1);
+        assert_eq!(
+            validator_args(&transaction_metadata),
+            vec![
+                "--maybe-clone".to_string(),
+                fee_payer.to_string(),
+                "--maybe-clone".to_string(),
+                pool.to_string(),
+                "--clone-upgradeable-program".to_string(),
+                program_id.to_string(),
+            ]
+        );
+    }
+}
@@ -23,8 +23,126 @@
 //! visualization and alerting. The trait requires `async` functions, allowing
 //! implementations to perform non-blocking I/O operations, such as network
 //! requests or database writes.
+//!
+//! ## Labels and Configuration
+//!
+//! Metrics can be recorded with `MetricLabel`s, such as the program id of an
+//! instruction, through the `*_with_labels` methods. Backends supporting
+//! labels, like Prometheus, record one series per set of label values; other
+//! backends ignore them by default.
+//!
+//! Labels holding a pubkey are high-cardinality: an account-heavy pipeline can
+//! create millions of series. `MetricsConfig`, set with
+//! `PipelineBuilder::metrics_config`, drops them unless
+//! `include_pubkey_labels` is set, can exclude other labels by name, and
+//! prefixes every metric name with a namespace.
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .metrics(Arc::new(PrometheusMetrics::new()))
+//!     .metrics_config(
+//!         MetricsConfig::new()
+//!             .namespace("indexer")
+//!             .include_pubkey_labels(true)
+//!             .exclude_label("account")
+//!             .flush_interval(10),
+//!     )
+//! ```
+
+use {
+    crate::error::CarbonResult,
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{borrow::Cow, collections::HashSet, sync::Arc},
+};
+
+/// A label of a metric.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetricLabel {
+    pub name: String,
+    pub value: String,
+    /// Whether the value is a pubkey, and the label high-cardinality.
+    pub is_pubkey: bool,
+}
+
+impl MetricLabel {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            is_pubkey: false,
+        }
+    }
+
+    /// A label whose value is `pubkey`, dropped unless
+    /// `MetricsConfig::include_pubkey_labels` is set.
+    pub fn pubkey(name: impl Into<String>, pubkey: &Pubkey) -> Self {
+        Self {
+            name: name.into(),
+            value: pubkey.to_string(),
+            is_pubkey: true,
+        }
+    }
+}
+
+/// The configuration of the metrics of a pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsConfig {
+    /// A prefix for every metric name, joined with `_`.
+    pub namespace: Option<String>,
+    /// Whether labels holding a pubkey are recorded. Off by default.
+    pub include_pubkey_labels: bool,
+    /// The names of the labels never recorded.
+    pub excluded_labels: HashSet<String>,
+    /// The interval, in seconds, at which metrics are flushed.
+    pub flush_interval: Option<u64>,
+}
 
-use {crate::error::CarbonResult, async_trait::async_trait, std::sync::Arc};
+impl MetricsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn include_pubkey_labels(mut self, include_pubkey_labels: bool) -> Self {
+        self.include_pubkey_labels = include_pubkey_labels;
+        self
+    }
+
+    pub fn exclude_label(mut self, name: impl Into<String>) -> Self {
+        self.excluded_labels.insert(name.into());
+        self
+    }
+
+    pub fn flush_interval(mut self, flush_interval: u64) -> Self {
+        self.flush_interval = Some(flush_interval);
+        self
+    }
+
+    /// Returns `name` prefixed with the namespace, if any.
+    pub fn metric_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match &self.namespace {
+            Some(namespace) => Cow::Owned(format!("{}_{}", namespace, name)),
+            None => Cow::Borrowed(name),
+        }
+    }
+
+    /// Returns the labels to record out of `labels`.
+    pub fn filter_labels(&self, labels: &[MetricLabel]) -> Vec<MetricLabel> {
+        labels
+            .iter()
+            .filter(|label| {
+                (self.include_pubkey_labels || !label.is_pubkey)
+                    && !self.excluded_labels.contains(&label.name)
+            })
+            .cloned()
+            .collect()
+    }
+}
 
 #[async_trait]
 pub trait Metrics: Send + Sync {
@@ -61,16 +179,56 @@ pub trait Metrics: Send + Sync {
     /// - `value`: The value to add to the histogram, typically representing
     ///   time or size.
     async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()>;
+
+    /// Updates a gauge metric with `labels`. Ignores the labels by default.
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        _labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        self.update_gauge(name, value).await
+    }
+
+    /// Increments a counter metric with `labels`. Ignores the labels by
+    /// default.
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        value: u64,
+        _labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        self.increment_counter(name, value).await
+    }
+
+    /// Records a value in a histogram metric with `labels`. Ignores the labels
+    /// by default.
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        _labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        self.record_histogram(name, value).await
+    }
 }
 
 #[derive(Default)]
 pub struct MetricsCollection {
     pub metrics: Vec<Arc<dyn Metrics>>,
+    pub config: MetricsConfig,
 }
 
 impl MetricsCollection {
     pub fn new(metrics: Vec<Arc<dyn Metrics>>) -> Self {
-        Self { metrics }
+        Self {
+            metrics,
+            config: MetricsConfig::default(),
+        }
+    }
+
+    pub fn with_config(metrics: Vec<Arc<dyn Metrics>>, config: MetricsConfig) -> Self {
+        Self { metrics, config }
     }
 
     pub async fn initialize_metrics(&self) -> CarbonResult<()> {
@@ -95,23 +253,109 @@ impl MetricsCollection {
     }
 
     pub async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
         for metric in &self.metrics {
-            metric.update_gauge(name, value).await?;
+            metric.update_gauge(&name, value).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
+        let labels = self.config.filter_labels(labels);
+        for metric in &self.metrics {
+            metric
+                .update_gauge_with_labels(&name, value, &labels)
+                .await?;
         }
         Ok(())
     }
 
     pub async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
+        for metric in &self.metrics {
+            metric.increment_counter(&name, value).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        value: u64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
+        let labels = self.config.filter_labels(labels);
         for metric in &self.metrics {
-            metric.increment_counter(name, value).await?;
+            metric
+                .increment_counter_with_labels(&name, value, &labels)
+                .await?;
         }
         Ok(())
     }
 
     pub async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
         for metric in &self.metrics {
-            metric.record_histogram(name, value).await?;
+            metric.record_histogram(&name, value).await?;
         }
         Ok(())
     }
+
+    pub async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        let name = self.config.metric_name(name);
+        let labels = self.config.filter_labels(labels);
+        for metric in &self.metrics {
+            metric
+                .record_histogram_with_labels(&name, value, &labels)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_prefixes_names_and_filters_labels() {
+        // Arrange
+        let labels = [
+            MetricLabel::pubkey("program_id", &Pubkey::new_unique()),
+            MetricLabel::new("variant", "swap"),
+            MetricLabel::new("datasource", "geyser"),
+        ];
+        let config = MetricsConfig::new()
+            .namespace("indexer")
+            .exclude_label("datasource");
+
+        // Act
+        let name = config.metric_name("updates_received");
+        let filtered = config.filter_labels(&labels);
+        let with_pubkeys = config
+            .clone()
+            .include_pubkey_labels(true)
+            .filter_labels(&labels);
+
+        // Assert
+        assert_eq!(name, "indexer_updates_received");
+        assert_eq!(filtered, vec![labels[1].clone()]);
+        assert_eq!(with_pubkeys, labels[..2].to_vec());
+        assert_eq!(
+            MetricsConfig::new().metric_name("updates_received"),
+            "updates_received"
+        );
+    }
 }
@@ -0,0 +1,523 @@
+//! Flags sandwiches and atomic arbitrages in the Jito bundles of a pipeline.
+//!
+//! `MevDetector` is a bundle processor: it inspects each `BundleUpdate`
+//! grouped by a bundle pipe and passes a `MevFinding` to its inner processor
+//! for every pattern it recognizes:
+//!
+//! - `Sandwich`: Two transactions of the bundle paid by the same signer, the
+//!   front run and the back run, around transactions of other signers, the
+//!   victims. All of them write to a common account, such as a pool, and the
+//!   back run reverses the balance changes of the front run.
+//! - `AtomicArbitrage`: A transaction invoking at least two programs, besides
+//!   the system, token and compute budget programs, after which its signer
+//!   only gained tokens or SOL, fees added back.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct FindingsProcessor;
+//!
+//! #[async_trait]
+//! impl Processor for FindingsProcessor {
+//!     type InputType = MevFinding;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         finding: MevFinding,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         if let MevFinding::Sandwich(sandwich) = finding {
+//!             log::info!("{} sandwiched {:?}", sandwich.attacker, sandwich.victims);
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_block_datasource)
+//!     .mev_findings(FindingsProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Detection works on raw balance changes and written accounts, without
+//!   decoding the swaps, so it applies to every venue but is a heuristic:
+//!   expect some false positives, and sandwiches landed outside of bundles are
+//!   not seen.
+//! - Balance changes are computed for the fee payer. Wrapped SOL is counted as
+//!   SOL, under the wrapped SOL mint.
+
+use {
+    crate::{
+        bundle::{BundleUpdate, JITO_TIP_ACCOUNTS},
+        datasource::TransactionUpdate,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::Processor,
+        transaction::TransactionMetadata,
+    },
+    async_trait::async_trait,
+    serde::Serialize,
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::{
+        collections::{BTreeMap, HashSet},
+        sync::Arc,
+    },
+};
+
+/// The mint under which SOL balance changes are counted.
+pub const WRAPPED_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Programs invoked by most transactions, not counted as trading venues.
+const INFRASTRUCTURE_PROGRAMS: [Pubkey; 5] = [
+    Pubkey::from_str_const("11111111111111111111111111111111"),
+    Pubkey::from_str_const("ComputeBudget111111111111111111111111111111"),
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+    Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+];
+
+/// A sandwich detected in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Sandwich {
+    pub slot: u64,
+    pub attacker: Pubkey,
+    pub front_run: Signature,
+    pub victims: Vec<Signature>,
+    pub back_run: Signature,
+    /// The accounts written by the front run, the victims and the back run.
+    pub pools: Vec<Pubkey>,
+    /// The balance changes of the attacker over both runs, by mint.
+    pub profits: BTreeMap<String, i128>,
+    pub tip_lamports: u64,
+}
+
+/// An atomic arbitrage detected in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AtomicArbitrage {
+    pub slot: u64,
+    pub signature: Signature,
+    pub searcher: Pubkey,
+    /// The programs invoked, besides the infrastructure programs.
+    pub programs: Vec<Pubkey>,
+    /// The balance changes of the searcher, by mint.
+    pub profits: BTreeMap<String, i128>,
+    pub tip_lamports: u64,
+}
+
+/// A pattern recognized by the `MevDetector`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MevFinding {
+    Sandwich(Sandwich),
+    AtomicArbitrage(AtomicArbitrage),
+}
+
+/// A bundle processor passing the sandwiches and atomic arbitrages of each
+/// bundle to `processor`.
+pub struct MevDetector<P> {
+    processor: P,
+}
+
+impl<P> MevDetector<P> {
+    pub fn new(processor: P) -> Self {
+        Self { processor }
+    }
+}
+
+#[async_trait]
+impl<P> Processor for MevDetector<P>
+where
+    P: Processor<InputType = MevFinding> + Send + Sync,
+{
+    type InputType = BundleUpdate;
+
+    async fn process(
+        &mut self,
+        bundle: BundleUpdate,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        for finding in detect(&bundle)? {
+            let name = match &finding {
+                MevFinding::Sandwich(_) => "mev_sandwiches_detected",
+                MevFinding::AtomicArbitrage(_) => "mev_atomic_arbitrages_detected",
+            };
+            metrics.increment_counter(name, 1).await?;
+
+            self.processor.process(finding, metrics.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+/// The facts about a transaction the detection relies on.
+struct Summary {
+    signature: Signature,
+    fee_payer: Pubkey,
+    writable_accounts: HashSet<Pubkey>,
+    programs: Vec<Pubkey>,
+    balance_changes: BTreeMap<String, i128>,
+}
+
+impl Summary {
+    fn new(transaction_update: &TransactionUpdate) -> CarbonResult<Self> {
+        let transaction_metadata = TransactionMetadata::try_from(transaction_update.clone())?;
+        let account_keys = transaction_metadata.account_keys();
+
+        let mut programs: Vec<Pubkey> = transaction_metadata
+            .message
+            .instructions()
+            .iter()
+            .map(|instruction| instruction.program_id_index)
+            .chain(
+                transaction_metadata
+                    .meta
+                    .inner_instructions
+                    .iter()
+                    .flatten()
+                    .flat_map(|inner_instructions| &inner_instructions.instructions)
+                    .map(|inner_instruction| inner_instruction.instruction.program_id_index),
+            )
+            .filter_map(|index| account_keys.get(index as usize).copied())
+            .filter(|program_id| !INFRASTRUCTURE_PROGRAMS.contains(program_id))
+            .collect();
+        programs.sort();
+        programs.dedup();
+
+        Ok(Self {
+            signature: transaction_metadata.signature,
+            fee_payer: transaction_metadata.fee_payer,
+            writable_accounts: transaction_metadata
+                .writable_accounts()
+                .into_iter()
+                .collect(),
+            programs,
+            balance_changes: balance_changes(&transaction_metadata),
+        })
+    }
+}
+
+/// Returns the balance changes of the fee payer of a transaction, by mint,
+/// with the fee added back.
+fn balance_changes(transaction_metadata: &TransactionMetadata) -> BTreeMap<String, i128> {
+    let meta = &transaction_metadata.meta;
+    let fee_payer = transaction_metadata.fee_payer.to_string();
+    let mut changes = BTreeMap::new();
+
+    if let (Some(pre_balance), Some(post_balance)) =
+        (meta.pre_balances.first(), meta.post_balances.first())
+    {
+        *changes.entry(WRAPPED_SOL_MINT.to_string()).or_insert(0) +=
+            *post_balance as i128 - *pre_balance as i128 + meta.fee as i128;
+    }
+
+    for (token_balances, sign) in [
+        (&meta.pre_token_balances, -1),
+        (&meta.post_token_balances, 1),
+    ] {
+        for token_balance in token_balances.iter().flatten() {
+            if token_balance.owner != fee_payer {
+                continue;
+            }
+            let amount = token_balance
+                .ui_token_amount
+                .amount
+                .parse::<i128>()
+                .unwrap_or(0);
+            *changes.entry(token_balance.mint.clone()).or_insert(0) += sign * amount;
+        }
+    }
+
+    changes.retain(|_, change| *change != 0);
+    changes
+}
+
+/// Returns the sandwiches and atomic arbitrages of `bundle`.
+pub fn detect(bundle: &BundleUpdate) -> CarbonResult<Vec<MevFinding>> {
+    let summaries = bundle
+        .transactions
+        .iter()
+        .map(Summary::new)
+        .collect::<CarbonResult<Vec<_>>>()?;
+    let mut findings = Vec::new();
+
+    let mut front = 0;
+    while front + 2 < summaries.len() {
+        match sandwich(bundle, &summaries, front) {
+            Some((finding, back)) => {
+                findings.push(MevFinding::Sandwich(finding));
+                front = back + 1;
+            }
+            None => front += 1,
+        }
+    }
+
+    for summary in &summaries {
+        let only_gains = summary.balance_changes.values().all(|change| *change >= 0);
+        if summary.programs.len() >= 2 && !summary.balance_changes.is_empty() && only_gains {
+            findings.push(MevFinding::AtomicArbitrage(AtomicArbitrage {
+                slot: bundle.slot,
+                signature: summary.signature,
+                searcher: summary.fee_payer,
+                programs: summary.programs.clone(),
+                profits: summary.balance_changes.clone(),
+                tip_lamports: bundle.tip_lamports,
+            }));
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Returns the sandwich front-run by the `front`th transaction, and the index
+/// of its back run.
+fn sandwich(
+    bundle: &BundleUpdate,
+    summaries: &[Summary],
+    front: usize,
+) -> Option<(Sandwich, usize)> {
+    let front_run = &summaries[front];
+    let back = summaries[front + 1..]
+        .iter()
+        .position(|summary| summary.fee_payer == front_run.fee_payer)
+        .map(|position| front + 1 + position)?;
+    if back == front + 1 {
+        return None;
+    }
+    let back_run = &summaries[back];
+    let victims = &summaries[front + 1..back];
+
+    let mut pools: Vec<Pubkey> = front_run
+        .writable_accounts
+        .iter()
+        .filter(|pubkey| {
+            **pubkey != front_run.fee_payer
+                && !JITO_TIP_ACCOUNTS.contains(pubkey)
+                && back_run.writable_accounts.contains(pubkey)
+                && victims
+                    .iter()
+                    .all(|victim| victim.writable_accounts.contains(pubkey))
+        })
+        .copied()
+        .collect();
+    if pools.is_empty() {
+        return None;
+    }
+    pools.sort();
+
+    let reverses = |front_sign: i128| {
+        front_run.balance_changes.iter().any(|(mint, change)| {
+            change.signum() == front_sign
+                && back_run
+                    .balance_changes
+                    .get(mint)
+                    .is_some_and(|back_change| back_change.signum() == -front_sign)
+        })
+    };
+    if !reverses(1) || !reverses(-1) {
+        return None;
+    }
+
+    let mut profits = front_run.balance_changes.clone();
+    for (mint, change) in &back_run.balance_changes {
+        *profits.entry(mint.clone()).or_insert(0) += change;
+    }
+    profits.retain(|_, change| *change != 0);
+
+    Some((
+        Sandwich {
+            slot: bundle.slot,
+            attacker: front_run.fee_payer,
+            front_run: front_run.signature,
+            victims: victims.iter().map(|victim| victim.signature).collect(),
+            back_run: back_run.signature,
+            pools,
+            profits,
+            tip_lamports: bundle.tip_lamports,
+        },
+        back,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            message::{Message, MessageHeader, VersionedMessage},
+            transaction::VersionedTransaction,
+        },
+        solana_transaction_status::{TransactionStatusMeta, TransactionTokenBalance},
+    };
+
+    fn token_balance(owner: &Pubkey, mint: &str, amount: u64) -> TransactionTokenBalance {
+        TransactionTokenBalance {
+            account_index: 1,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: None,
+                decimals: 0,
+                amount: amount.to_string(),
+                ui_amount_string: amount.to_string(),
+            },
+            owner: owner.to_string(),
+            program_id: String::new(),
+        }
+    }
+
+    /// A transaction of `signer` writing `pool`, trading `sold` of mint `a`
+    /// for `bought` of mint `b`, through `programs`.
+    fn swap(
+        signer: &Pubkey,
+        pool: &Pubkey,
+        (sold, bought): (u64, u64),
+        programs: &[Pubkey],
+    ) -> TransactionUpdate {
+        let mut account_keys = vec![*signer, *pool];
+        account_keys.extend(programs);
+
+        TransactionUpdate {
+            signature: Signature::new_unique(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(Message {
+                    header: MessageHeader {
+                        num_required_signatures: 1,
+                        num_readonly_signed_accounts: 0,
+                        num_readonly_unsigned_accounts: programs.len() as u8,
+                    },
+                    account_keys,
+                    instructions: (0..programs.len())
+                        .map(|index| {
+                            CompiledInstruction::new_from_raw_parts(
+                                2 + index as u8,
+                                vec![],
+                                vec![1],
+                            )
+                        })
+                        .collect(),
+                    ..Message::default()
+                }),
+            },
+            meta: TransactionStatusMeta {
+                fee: 5_000,
+                pre_balances: vec![1_000_000, 0],
+                post_balances: vec![995_000, 0],
+                pre_token_balances: Some(vec![
+                    token_balance(signer, "a", 1_000),
+                    token_balance(signer, "b", 1_000),
+                ]),
+                post_token_balances: Some(vec![
+                    token_balance(signer, "a", 1_000 - sold),
+                    token_balance(signer, "b", 1_000 + bought),
+                ]),
+                ..TransactionStatusMeta::default()
+            },
+            is_vote: false,
+            slot: 7,
+            block_time: None,
+            commitment: None,
+        }
+    }
+
+    fn bundle(transactions: Vec<TransactionUpdate>) -> BundleUpdate {
+        BundleUpdate {
+            slot: 7,
+            block_time: None,
+            transactions,
+            tip_account: JITO_TIP_ACCOUNTS[0],
+            tip_lamports: 10_000,
+        }
+    }
+
+    #[test]
+    fn test_detect_sandwich() {
+        // Arrange
+        let (attacker, victim, pool, venue) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let front_run = swap(&attacker, &pool, (100, 90), &[venue]);
+        let victim_swap = swap(&victim, &pool, (500, 400), &[venue]);
+        let mut back_run = swap(&attacker, &pool, (0, 0), &[venue]);
+        back_run.meta.post_token_balances = Some(vec![
+            token_balance(&attacker, "a", 1_020),
+            token_balance(&attacker, "b", 910),
+        ]);
+        let bundle = bundle(vec![
+            front_run.clone(),
+            victim_swap.clone(),
+            back_run.clone(),
+        ]);
+
+        // Act
+        let findings = detect(&bundle).unwrap();
+
+        // Assert
+        assert_eq!(
+            findings,
+            vec![MevFinding::Sandwich(Sandwich {
+                slot: 7,
+                attacker,
+                front_run: front_run.signature,
+                victims: vec![victim_swap.signature],
+                back_run: back_run.signature,
+                pools: vec![pool],
+                profits: BTreeMap::from([("a".to_string(), -80)]),
+                tip_lamports: 10_000,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_detect_atomic_arbitrage() {
+        // Arrange
+        let searcher = Pubkey::new_unique();
+        let mut arbitrage = swap(
+            &searcher,
+            &Pubkey::new_unique(),
+            (0, 25),
+            &[Pubkey::new_unique(), Pubkey::new_unique()],
+        );
+        let single_venue = swap(
+            &searcher,
+            &Pubkey::new_unique(),
+            (0, 25),
+            &[Pubkey::new_unique()],
+        );
+        arbitrage.meta.pre_balances[0] = 2_000_000;
+        arbitrage.meta.post_balances[0] = 1_995_000;
+
+        // Act
+        let findings = detect(&bundle(vec![arbitrage.clone(), single_venue])).unwrap();
+
+        // Assert
+        assert_eq!(findings.len(), 1);
+        let MevFinding::AtomicArbitrage(found) = &findings[0] else {
+            panic!("expected an atomic arbitrage, got {:?}", findings[0]);
+        };
+        assert_eq!(found.signature, arbitrage.signature);
+        assert_eq!(found.profits, BTreeMap::from([("b".to_string(), 25)]));
+    }
+}
@@ -0,0 +1,281 @@
+//! Keeps the decoded account updates of a pipe in order for each account.
+//!
+//! Updates of an account may arrive out of order: a datasource replays older
+//! states after reconnecting, and datasources merged into one pipeline lag
+//! behind each other. A processor writing the latest state of each account to
+//! a store would then overwrite a newer state with an older one.
+//! `PipelineBuilder::account_ordered` adds an account pipe whose processor is
+//! wrapped in an `OrderedProcessor`, which guards each account against such
+//! regressions, by either dropping the older updates or reordering them
+//! within a delay.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account_ordered(
+//!         PumpfunDecoder,
+//!         BondingCurveStore,
+//!         AccountOrdering::Reorder { delay_slots: 2 },
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates are ordered by slot, then by write version within a slot when
+//!   the datasource provides it. Updates of the same slot without write
+//!   versions are passed on, as their order is unknown.
+//! - Updates older than the last update passed on for their account are
+//!   dropped in both modes, and counted by the `account_updates_out_of_order`
+//!   counter.
+//! - Unlike `PipelineBuilder::skip_stale_accounts`, which drops stale updates
+//!   for every pipe of the pipeline, the guard only applies to its own pipe.
+//! - Updates still held by a reordering guard are passed on when the pipeline
+//!   stops.
+
+use {
+    crate::{
+        account::{AccountProcessorInputType, AccountWatermark},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    std::{collections::BTreeMap, sync::Arc},
+};
+
+/// How an `OrderedProcessor` keeps the updates of each account in order.
+///
+/// - `Drop`: Updates are passed on as they arrive, and those older than the
+///   last update passed on for their account are dropped.
+/// - `Reorder`: Updates are held until `delay_slots` newer slots were
+///   received, and passed on sorted, so updates arriving late by up to
+///   `delay_slots` slots are put back in order rather than dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountOrdering {
+    Drop,
+    Reorder { delay_slots: u64 },
+}
+
+/// A processor wrapper passing the decoded updates of each account to its
+/// processor in slot and write version order.
+pub struct OrderedProcessor<T, P> {
+    processor: P,
+    ordering: AccountOrdering,
+    watermark: AccountWatermark,
+    pending: BTreeMap<(u64, u64, u64), AccountProcessorInputType<T>>,
+    sequence: u64,
+    max_slot: u64,
+}
+
+impl<T, P> OrderedProcessor<T, P> {
+    pub fn new(processor: P, ordering: AccountOrdering) -> Self {
+        Self {
+            processor,
+            ordering,
+            watermark: AccountWatermark::new(),
+            pending: BTreeMap::new(),
+            sequence: 0,
+            max_slot: 0,
+        }
+    }
+
+    /// Returns the number of updates held for reordering.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Removes and returns the held updates older than the reordering delay,
+    /// or all of them if `all` is set, oldest first.
+    fn release(&mut self, all: bool) -> Vec<AccountProcessorInputType<T>> {
+        let delay_slots = match self.ordering {
+            AccountOrdering::Reorder { delay_slots } => delay_slots,
+            AccountOrdering::Drop => 0,
+        };
+        let ready = if all {
+            std::mem::take(&mut self.pending)
+        } else {
+            let held = self
+                .pending
+                .split_off(&(self.max_slot.saturating_sub(delay_slots), 0, 0));
+            std::mem::replace(&mut self.pending, held)
+        };
+
+        ready.into_values().collect()
+    }
+}
+
+impl<T, P> OrderedProcessor<T, P>
+where
+    T: Send + Sync + 'static,
+    P: Processor<InputType = AccountProcessorInputType<T>> + Send + Sync,
+{
+    async fn pass_on(
+        &mut self,
+        updates: Vec<AccountProcessorInputType<T>>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        for (metadata, account) in updates {
+            if self
+                .watermark
+                .is_stale(&metadata.pubkey, metadata.slot, metadata.write_version)
+            {
+                metrics
+                    .increment_counter("account_updates_out_of_order", 1)
+                    .await?;
+                continue;
+            }
+
+            self.processor
+                .process((metadata, account), metrics.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for OrderedProcessor<T, P>
+where
+    T: Send + Sync + 'static,
+    P: Processor<InputType = AccountProcessorInputType<T>> + Send + Sync,
+{
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if self.ordering == AccountOrdering::Drop {
+            return self.pass_on(vec![data], metrics).await;
+        }
+
+        let key = (
+            data.0.slot,
+            data.0.write_version.unwrap_or_default(),
+            self.sequence,
+        );
+        self.sequence += 1;
+        self.max_slot = self.max_slot.max(data.0.slot);
+        self.pending.insert(key, data);
+
+        let ready = self.release(false);
+        self.pass_on(ready, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        let ready = self.release(true);
+        self.pass_on(ready, metrics.clone()).await?;
+        self.processor.flush(metrics.clone()).await?;
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            account::{AccountMetadata, DecodedAccount},
+            trace::TraceId,
+        },
+        solana_pubkey::Pubkey,
+    };
+
+    /// Records the slot and write version of each update.
+    struct Store(Vec<(u64, Option<u64>)>);
+
+    #[async_trait]
+    impl Processor for Store {
+        type InputType = AccountProcessorInputType<()>;
+
+        async fn process(
+            &mut self,
+            (metadata, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.push((metadata.slot, metadata.write_version));
+            Ok(())
+        }
+    }
+
+    fn update(pubkey: Pubkey, slot: u64, write_version: u64) -> AccountProcessorInputType<()> {
+        (
+            AccountMetadata {
+                slot,
+                pubkey,
+                trace_id: TraceId::new(),
+                commitment: None,
+                write_version: Some(write_version),
+                lamports: 0,
+                owner: Pubkey::default(),
+                executable: false,
+                rent_epoch: 0,
+            },
+            DecodedAccount {
+                lamports: 0,
+                data: (),
+                owner: Pubkey::default(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+    }
+
+    async fn run(ordering: AccountOrdering) -> Vec<(u64, Option<u64>)> {
+        let pubkey = Pubkey::new_unique();
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let mut processor = OrderedProcessor::new(Store(Vec::new()), ordering);
+
+        for (slot, write_version) in [(10, 2), (10, 1), (12, 5), (11, 4), (14, 6), (9, 0)] {
+            processor
+                .process(update(pubkey, slot, write_version), metrics.clone())
+                .await
+                .unwrap();
+        }
+        processor.teardown(metrics).await.unwrap();
+
+        processor.processor.0
+    }
+
+    #[tokio::test]
+    async fn test_drop_skips_older_updates() {
+        // Act
+        let result = run(AccountOrdering::Drop).await;
+
+        // Assert
+        assert_eq!(result, vec![(10, Some(2)), (12, Some(5)), (14, Some(6))]);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_sorts_late_updates_within_the_delay() {
+        // Act
+        let result = run(AccountOrdering::Reorder { delay_slots: 2 }).await;
+
+        // Assert
+        assert_eq!(
+            result,
+            vec![
+                (10, Some(1)),
+                (10, Some(2)),
+                (11, Some(4)),
+                (12, Some(5)),
+                (14, Some(6)),
+            ]
+        );
+    }
+}
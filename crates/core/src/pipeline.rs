@@ -34,8 +34,14 @@
 //! - **account_pipes**: A collection of pipes for processing account updates.
 //! - **account_deletion_pipes**: Pipes responsible for handling account
 //!   deletion events.
+//! - **account_lifecycle_pipes**: For handling the ownership changes and
+//!   closures of accounts.
 //! - **instruction_pipes**: Used to process instructions within transactions.
 //! - **transaction_pipes**: For handling full transactions.
+//! - **transaction_status_pipes**: For handling transaction status updates.
+//! - **block_details_pipes**: For handling block metadata and rewards.
+//! - **bundle_pipes**: For handling the Jito bundles detected in transactions.
+//! - **epoch_pipes**: For handling the epoch changes of the updates' slots.
 //! - **metrics**: A vector of `Metrics` implementations that gather and report
 //!   on performance data.
 //! - **metrics_flush_interval**: Specifies how frequently metrics are flushed.
@@ -53,25 +59,85 @@
 use {
     crate::{
         account::{
-            AccountDecoder, AccountMetadata, AccountPipe, AccountPipes, AccountProcessorInputType,
+            AccountDataCache, AccountDecoder, AccountMetadata, AccountPipe, AccountPipes,
+            AccountProcessorInputType, AccountWatermark,
         },
         account_deletion::{AccountDeletionPipe, AccountDeletionPipes},
+        account_lifecycle::{
+            AccountLifecycleEvent, AccountLifecyclePipe, AccountLifecyclePipes,
+            AccountLifecycleTracker,
+        },
+        actions,
+        block_details::{BlockDetailsPipe, BlockDetailsPipes},
+        bundle::{BundleDetector, BundlePipe, BundlePipes, BundleUpdate},
+        clock::{system_clock, Clock},
         collection::InstructionDecoderCollection,
-        datasource::{AccountDeletion, Datasource, Update},
-        error::CarbonResult,
+        contention::{ContentionPipe, ContentionReport, ContentionTracker},
+        datasource::{
+            AccountDeletion, BlockDetails, Capabilities, Capability, Datasource,
+            TransactionStatusUpdate, Update,
+        },
+        debug_print::DebugPrintProcessor,
+        derived::{DerivedPipe, DerivedPipes, DerivedReceiver},
+        description::{
+            default_pipe_name, describe_capabilities, describe_shutdown_strategy,
+            short_type_name_of, DatasourceDescription, FilterDescription, PipeDescription,
+            PipeKind, PipelineDescription,
+        },
+        deserialize::{strict_decoding_scope, with_strict_decoding},
+        epoch::{EpochChange, EpochPipe, EpochPipes, EpochTracker},
+        error::{CarbonResult, Error},
+        event::{CorrelatedEvent, CorrelatedEventPipe, EventPipe, EventProcessorInputType},
+        event_bus::{CheckpointTracker, EventBus, PipelineEvent, ShutdownReason},
+        failure::{ProgramErrorDetails, ProgramErrorResolver},
+        guardrails::{SkippedTransaction, TransactionLimits},
         instruction::{
-            InstructionDecoder, InstructionPipe, InstructionPipes, InstructionProcessorInputType,
-            InstructionsWithMetadata, NestedInstructions,
+            InstructionDecoder, InstructionMetadata, InstructionPipe, InstructionPipes,
+            InstructionProcessorInputType, InstructionsWithMetadata, NestedInstruction,
+            NestedInstructions, UnknownInstructionHook,
+        },
+        instruction_stats::{InstructionStats, InstructionStatsProcessor},
+        lag::LagMonitor,
+        metrics::{Metrics, MetricsCollection, MetricsConfig},
+        mev::{MevDetector, MevFinding},
+        ordering::{AccountOrdering, OrderedProcessor},
+        preset::PipelinePreset,
+        processor::{run_lifecycle, Lifecycle, Processor, SampledProcessor, Sampling},
+        rate_of_change::{RateOfChangeAlert, RateOfChangeProcessor},
+        registry,
+        schema::{SequenceMatch, SequenceSchema, TransactionSchema},
+        slot_clock::ClusterSchedule,
+        slot_range::SlotRangeWatermark,
+        status::PipelineStatus,
+        supervision::{
+            isolate_panics, supervise_datasource, DatasourceHealth, PauseState, PipelineHandle,
+            RestartPolicy, TaskHealth,
         },
-        metrics::{Metrics, MetricsCollection},
-        processor::Processor,
-        schema::TransactionSchema,
-        transaction::{TransactionPipe, TransactionPipes, TransactionProcessorInputType},
+        timestamp::{TimestampNormalizer, TimestampPolicy, TimestampedEvent},
+        trace::TraceId,
+        transaction::{
+            SequencePipe, TransactionMetadata, TransactionPipe, TransactionPipes,
+            TransactionProcessorInputType,
+        },
+        transaction_status::{TransactionStatusPipe, TransactionStatusPipes},
         transformers,
+        ui_amount::{MintDecimalsCache, MintDecimalsPipe},
+        verification::{self, VerificationMode},
     },
     core::time,
-    serde::de::DeserializeOwned,
-    std::{convert::TryInto, sync::Arc, time::Instant},
+    serde::{de::DeserializeOwned, Serialize},
+    solana_pubkey::Pubkey,
+    std::{
+        collections::{HashMap, HashSet},
+        convert::TryInto,
+        ops::RangeInclusive,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, RwLock,
+        },
+        time::Instant,
+    },
+    tokio::sync::watch,
     tokio_util::sync::CancellationToken,
 };
 
@@ -106,6 +172,34 @@ pub enum ShutdownStrategy {
     ProcessPending,
 }
 
+/// A summary of the updates observed by `Pipeline::check`.
+///
+/// # Fields
+///
+/// - `updates_received`: The total number of updates received from the
+///   datasources.
+/// - `account_updates`, `transaction_updates`, `account_deletions`,
+///   `transaction_statuses`, `block_details`: The number of updates received
+///   for each update type.
+/// - `accounts_decoded`: The number of account updates recognized by at least
+///   one account pipe.
+/// - `instructions_decoded`: The number of instructions recognized across the
+///   instruction and transaction pipes.
+/// - `errors`: Errors raised by the datasources or while preparing updates for
+///   decoding.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CheckReport {
+    pub updates_received: usize,
+    pub account_updates: usize,
+    pub transaction_updates: usize,
+    pub account_deletions: usize,
+    pub transaction_statuses: usize,
+    pub block_details: usize,
+    pub accounts_decoded: usize,
+    pub instructions_decoded: usize,
+    pub errors: Vec<String>,
+}
+
 /// The default size of the channel buffer for the pipeline.
 ///
 /// This constant defines the default number of updates that can be queued in
@@ -141,6 +235,8 @@ pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 ///   - `AccountDeletionPipes` for account deletions.
 ///   - `InstructionPipes` for instruction data within transactions.
 ///   - `TransactionPipes` for entire transaction payloads.
+///   - `TransactionStatusPipes` for transaction statuses.
+///   - `BlockDetailsPipes` for block metadata and rewards.
 /// - **Metrics**: Collect performance data, enabling real-time insights and
 ///   efficient monitoring.
 ///
@@ -153,12 +249,24 @@ pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 ///   account updates.
 /// - `account_deletion_pipes`: A vector of `AccountDeletionPipes` to handle
 ///   deletion events.
+/// - `account_lifecycle_pipes`: A vector of `AccountLifecyclePipes` following
+///   the owner and lamports of the updated accounts.
 /// - `instruction_pipes`: A vector of `InstructionPipes` for processing
 ///   instructions within transactions. These pipes work with nested
 ///   instructions and are generically defined to support varied instruction
 ///   types.
 /// - `transaction_pipes`: A vector of `TransactionPipes` responsible for
 ///   processing complete transaction payloads.
+/// - `transaction_status_pipes`: A vector of `TransactionStatusPipes` handling
+///   transaction status updates.
+/// - `block_details_pipes`: A vector of `BlockDetailsPipes` handling block
+///   metadata and rewards.
+/// - `bundle_pipes`: A vector of `BundlePipes` grouping transactions into Jito
+///   bundles.
+/// - `epoch_pipes`: A vector of `EpochPipes` following the epoch of the
+///   updates' slots.
+/// - `derived_pipes`: A vector of `DerivedPipes` processing the updates
+///   derived by other pipes.
 /// - `metrics`: A vector of `Metrics` implementations to record and track
 ///   performance data. Each metrics instance is managed within an `Arc` to
 ///   ensure thread safety.
@@ -167,6 +275,39 @@ pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 ///   used.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
 ///   If not set, a default size of 10_000 will be used.
+/// - `account_data_cache`: An optional `AccountDataCache` used to skip account
+///   updates whose data did not change since the previous update.
+/// - `account_watermark`: An optional `AccountWatermark` used to skip account
+///   updates older than the last update processed for the same account.
+/// - `lag_monitor`: An optional `LagMonitor` exporting the lag of the pipeline
+///   against the chain tip.
+/// - `program_error_resolvers`: The resolvers mapping the custom error codes of
+///   failed transactions to program errors, by program ID.
+/// - `pause_state`: Whether the pipeline is paused, set through the
+///   `PipelineHandle` returned by `spawn`.
+/// - `slot_range`: An optional range of slots to process. The pipeline stops
+///   once the range has been fully processed, as told by a
+///   `SlotRangeWatermark`, or once every bounded datasource finished.
+/// - `transaction_limits`: Optional `TransactionLimits` above which
+///   transactions are skipped, and `skipped_transaction_processor` the
+///   processor receiving them.
+/// - `transaction_verification`: Whether transactions failing signature
+///   verification are flagged or dropped, if they are verified.
+/// - `cluster_schedule`: An optional `ClusterSchedule` computing the
+///   `SlotClock` of each transaction.
+/// - `detect_actions`: Whether the `ActionIdentifier`s of each transaction
+///   are parsed from its memos.
+/// - `strict_decoding`: Whether the pipes decode their updates in strict
+///   decoding mode.
+/// - `clock`: The `Clock` timing the metrics flushes and the datasource
+///   restarts.
+/// - `datasource_names` and `pipe_descriptions`: The type names of the
+///   datasources and the descriptions of the pipes, returned by `describe`.
+/// - `status`: The `PipelineStatus` updated while the pipeline runs.
+/// - `events`: The `EventBus` the lifecycle events of the pipeline are
+///   emitted on.
+/// - `status_server_addr`: The address of the `StatusServer` started with the
+///   pipeline, with the `status-server` feature.
 ///
 /// ## Example
 ///
@@ -205,13 +346,42 @@ pub struct Pipeline {
     pub datasources: Vec<Arc<dyn Datasource + Send + Sync>>,
     pub account_pipes: Vec<Box<dyn AccountPipes>>,
     pub account_deletion_pipes: Vec<Box<dyn AccountDeletionPipes>>,
+    pub account_lifecycle_pipes: Vec<Box<dyn AccountLifecyclePipes>>,
     pub instruction_pipes: Vec<Box<dyn for<'a> InstructionPipes<'a>>>,
     pub transaction_pipes: Vec<Box<dyn for<'a> TransactionPipes<'a>>>,
+    pub transaction_status_pipes: Vec<Box<dyn TransactionStatusPipes>>,
+    pub block_details_pipes: Vec<Box<dyn BlockDetailsPipes>>,
+    pub bundle_pipes: Vec<Box<dyn BundlePipes>>,
+    pub epoch_pipes: Vec<Box<dyn EpochPipes>>,
+    pub derived_pipes: Vec<Box<dyn DerivedPipes>>,
     pub metrics: Arc<MetricsCollection>,
     pub metrics_flush_interval: Option<u64>,
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
     pub channel_buffer_size: usize,
+    pub account_data_cache: Option<AccountDataCache>,
+    pub account_watermark: Option<AccountWatermark>,
+    pub lag_monitor: Option<LagMonitor>,
+    pub program_error_resolvers: HashMap<Pubkey, ProgramErrorResolver>,
+    pub restart_policy: RestartPolicy,
+    pub datasource_health: DatasourceHealth,
+    pub checked_programs: Option<HashSet<Pubkey>>,
+    pub pause_state: PauseState,
+    pub slot_range: Option<RangeInclusive<u64>>,
+    pub transaction_limits: Option<TransactionLimits>,
+    pub skipped_transaction_processor:
+        Option<Box<dyn Processor<InputType = SkippedTransaction> + Send + Sync>>,
+    pub transaction_verification: Option<VerificationMode>,
+    pub cluster_schedule: Option<ClusterSchedule>,
+    pub detect_actions: bool,
+    pub strict_decoding: bool,
+    pub clock: Arc<dyn Clock>,
+    pub datasource_names: Vec<String>,
+    pub pipe_descriptions: Vec<PipeDescription>,
+    pub status: PipelineStatus,
+    pub events: EventBus,
+    #[cfg(feature = "status-server")]
+    pub status_server_addr: Option<std::net::SocketAddr>,
 }
 
 impl Pipeline {
@@ -248,16 +418,81 @@ impl Pipeline {
             datasources: Vec::new(),
             account_pipes: Vec::new(),
             account_deletion_pipes: Vec::new(),
+            account_lifecycle_pipes: Vec::new(),
             instruction_pipes: Vec::new(),
             transaction_pipes: Vec::new(),
+            transaction_status_pipes: Vec::new(),
+            block_details_pipes: Vec::new(),
+            bundle_pipes: Vec::new(),
+            epoch_pipes: Vec::new(),
+            derived_pipes: Vec::new(),
             metrics: MetricsCollection::default(),
             metrics_flush_interval: None,
             datasource_cancellation_token: None,
             shutdown_strategy: ShutdownStrategy::default(),
             channel_buffer_size: DEFAULT_CHANNEL_BUFFER_SIZE,
+            account_data_cache: None,
+            account_watermark: None,
+            lag_monitor: None,
+            program_error_resolvers: HashMap::new(),
+            restart_policy: RestartPolicy::default(),
+            suggest_missing_decoders: false,
+            slot_range: None,
+            transaction_limits: None,
+            skipped_transaction_processor: None,
+            transaction_verification: None,
+            cluster_schedule: None,
+            detect_actions: false,
+            strict_decoding: false,
+            clock: None,
+            datasource_names: Vec::new(),
+            pipe_descriptions: Vec::new(),
+            event_bus: None,
+            #[cfg(feature = "status-server")]
+            status_server_addr: None,
         }
     }
 
+    /// Creates a `PipelineBuilder` configured for indexing live updates with
+    /// the least delay, with the `PipelinePreset::LowLatency` defaults.
+    ///
+    /// Any builder method called afterwards overrides the preset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// carbon_core::pipeline::Pipeline::low_latency()
+    ///     .datasource(RpcBlockSubscribe::new(
+    ///         rpc_ws_url,
+    ///         filters.commitment(PipelinePreset::LowLatency.commitment()),
+    ///     ))
+    ///     .instruction(TestProgramDecoder, TestProgramProcessor)
+    /// // ...
+    /// ```
+    pub fn low_latency() -> PipelineBuilder {
+        log::trace!("Pipeline::low_latency()");
+        Self::builder().preset(PipelinePreset::LowLatency)
+    }
+
+    /// Creates a `PipelineBuilder` configured for backfilling large ranges of
+    /// historical updates, with the `PipelinePreset::BulkBackfill` defaults.
+    ///
+    /// Any builder method called afterwards overrides the preset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// carbon_core::pipeline::Pipeline::bulk_backfill()
+    ///     .datasource(transaction_crawler)
+    ///     .instruction(TestProgramDecoder, TestProgramProcessor)
+    ///     .metrics_flush_interval(60)
+    /// // ...
+    /// ```
+    pub fn bulk_backfill() -> PipelineBuilder {
+        log::trace!("Pipeline::bulk_backfill()");
+        Self::builder().preset(PipelinePreset::BulkBackfill)
+    }
+
     /// Runs the `Pipeline`, processing updates from data sources and handling
     /// metrics.
     ///
@@ -278,13 +513,13 @@ impl Pipeline {
     ///   or AccountDeletion).
     /// - Records performance metrics such as update processing times, and
     ///   tracks success and failure counts.
+    /// - Runs the `setup` hook of every processor before consuming updates,
+    ///   `flush` on every metrics flush interval, and `flush` then `teardown`
+    ///   when shutting down.
     ///
     /// # Errors
     ///
     /// The method returns an `Err` variant if:
-    /// - Required update types (e.g., `AccountUpdate`, `AccountDeletion`,
-    ///   `Transaction`) are not provided by any data source, causing a mismatch
-    ///   in expected data processing capabilities.
     /// - A data source encounters an error while consuming updates.
     /// - An error occurs during metrics flushing or processing of updates.
     ///
@@ -315,19 +550,39 @@ impl Pipeline {
     ///   `metrics_flush_interval`.
     /// - The `run` method operates in an infinite loop, handling updates until
     ///   a termination condition occurs.
+    /// - Datasource tasks that panic are restarted according to the
+    ///   `restart_policy`. Use `spawn` to supervise the pipeline from another
+    ///   task.
+    /// - A processor panicking fails the update it was processing, as if it
+    ///   had returned an error, and the pipeline carries on.
     pub async fn run(&mut self) -> CarbonResult<()> {
-        log::info!("starting pipeline. num_datasources: {}, num_metrics: {}, num_account_pipes: {}, num_account_deletion_pipes: {}, num_instruction_pipes: {}, num_transaction_pipes: {}",
+        log::info!("starting pipeline. num_datasources: {}, num_metrics: {}, num_account_pipes: {}, num_account_deletion_pipes: {}, num_instruction_pipes: {}, num_transaction_pipes: {}, num_transaction_status_pipes: {}, num_block_details_pipes: {}",
             self.datasources.len(),
             self.metrics.metrics.len(),
             self.account_pipes.len(),
             self.account_deletion_pipes.len(),
             self.instruction_pipes.len(),
             self.transaction_pipes.len(),
+            self.transaction_status_pipes.len(),
+            self.block_details_pipes.len(),
         );
 
+        log::debug!("pipeline description: {:?}", self.describe());
         log::trace!("run(self)");
 
+        #[cfg(feature = "status-server")]
+        let _status_server = match self.status_server_addr {
+            Some(addr) => {
+                Some(crate::status_server::StatusServer::bind(addr, self.status.clone()).await?)
+            }
+            None => None,
+        };
+
         self.metrics.initialize_metrics().await?;
+        self.lifecycle(Lifecycle::Setup).await?;
+        self.status.start(&self.describe());
+        self.events.emit(PipelineEvent::Started);
+
         let (update_sender, mut update_receiver) =
             tokio::sync::mpsc::channel::<Update>(self.channel_buffer_size);
 
@@ -336,57 +591,167 @@ impl Pipeline {
             .clone()
             .unwrap_or_default();
 
-        for datasource in &self.datasources {
-            let datasource_cancellation_token_clone = datasource_cancellation_token.clone();
-            let sender_clone = update_sender.clone();
-            let datasource_clone = Arc::clone(datasource);
-            let metrics_collection = self.metrics.clone();
-
-            tokio::spawn(async move {
-                if let Err(e) = datasource_clone
-                    .consume(
-                        &sender_clone,
-                        datasource_cancellation_token_clone,
-                        metrics_collection,
-                    )
-                    .await
-                {
-                    log::error!("error consuming datasource: {:?}", e);
-                }
-            });
+        for (index, datasource) in self.datasources.iter().enumerate() {
+            supervise_datasource(
+                index,
+                Arc::clone(datasource),
+                update_sender.clone(),
+                datasource_cancellation_token.clone(),
+                self.metrics.clone(),
+                self.datasource_health.clone(),
+                self.restart_policy,
+                self.clock.clone(),
+                self.events.clone(),
+            );
         }
+        drop(update_sender);
 
-        let mut interval = tokio::time::interval(time::Duration::from_secs(
-            self.metrics_flush_interval.unwrap_or(5),
-        ));
+        let last_processed_slot = Arc::new(AtomicU64::new(0));
+        let mut checkpoint = CheckpointTracker::default();
+        let mut slot_range = self.slot_range.clone().map(SlotRangeWatermark::new);
+        let lag_monitor_cancellation_token = datasource_cancellation_token.child_token();
+        let _lag_monitor_guard = lag_monitor_cancellation_token.clone().drop_guard();
+        if let Some(lag_monitor) = &self.lag_monitor {
+            lag_monitor.spawn(
+                last_processed_slot.clone(),
+                self.metrics.clone(),
+                self.status.clone(),
+                lag_monitor_cancellation_token,
+            );
+        }
+
+        let clock = self.clock.clone();
+        let flush_interval = time::Duration::from_secs(self.metrics_flush_interval.unwrap_or(5));
+        let flush_timer = clock.sleep(flush_interval);
+        tokio::pin!(flush_timer);
+
+        let mut pause_receiver = self.pause_state.subscribe();
+        let mut paused = *pause_receiver.borrow_and_update();
+        let bounded_datasources = match self
+            .datasources
+            .iter()
+            .filter(|datasource| datasource.is_bounded())
+            .count()
+        {
+            0 => self.datasources.len(),
+            bounded_datasources => bounded_datasources,
+        };
+        let mut finished_datasources = 0;
+        let mut shutdown_initiated = false;
 
         loop {
             tokio::select! {
                 _ = tokio::signal::ctrl_c() => {
                     log::trace!("received SIGINT, shutting down.");
+                    if !shutdown_initiated {
+                        shutdown_initiated = true;
+                        self.events.emit(PipelineEvent::ShutdownInitiated { reason: ShutdownReason::Signal });
+                    }
                     datasource_cancellation_token.cancel();
 
                     if self.shutdown_strategy == ShutdownStrategy::Immediate {
                         log::info!("shutting down the pipeline immediately.");
+                        self.flush(&mut checkpoint).await?;
+                        self.lifecycle(Lifecycle::Teardown).await?;
                         self.metrics.flush_metrics().await?;
                         self.metrics.shutdown_metrics().await?;
                         break;
                     } else {
                         log::info!("shutting down the pipeline after processing pending updates.");
+                        self.pause_state.send_replace(false);
                     }
                 }
-                _ = interval.tick() => {
+                _ = datasource_cancellation_token.cancelled(), if !shutdown_initiated => {
+                    shutdown_initiated = true;
+                    self.events.emit(PipelineEvent::ShutdownInitiated { reason: ShutdownReason::Requested });
+                }
+                _ = &mut flush_timer => {
+                    flush_timer.set(clock.sleep(flush_interval));
+                    if let Err(error) = self.flush(&mut checkpoint).await {
+                        log::error!("error flushing processors: {:?}", error);
+                    }
                     self.metrics.flush_metrics().await?;
                 }
-                update = update_receiver.recv() => {
+                Ok(()) = pause_receiver.changed() => {
+                    paused = *pause_receiver.borrow_and_update();
+                    if paused {
+                        log::info!("pipeline paused, {} updates queued.", update_receiver.len());
+                    } else {
+                        log::info!("pipeline resumed.");
+                    }
+                }
+                update = update_receiver.recv(), if !paused => {
                     match update {
+                        Some(Update::DatasourceFinished) => {
+                            finished_datasources += 1;
+                            self.metrics.increment_counter("datasources_finished", 1).await?;
+
+                            if finished_datasources < bounded_datasources {
+                                log::info!(
+                                    "{} of {} bounded datasources finished.",
+                                    finished_datasources,
+                                    bounded_datasources
+                                );
+                                continue;
+                            }
+
+                            log::info!("all bounded datasources finished, shutting down.");
+                            if !shutdown_initiated {
+                                self.events.emit(PipelineEvent::ShutdownInitiated { reason: ShutdownReason::DatasourcesFinished });
+                            }
+                            datasource_cancellation_token.cancel();
+                            self.flush(&mut checkpoint).await?;
+                            self.lifecycle(Lifecycle::Teardown).await?;
+                            self.metrics.flush_metrics().await?;
+                            self.metrics.shutdown_metrics().await?;
+                            break;
+                        }
                         Some(update) => {
+                            if let Some(slot_range) = slot_range.as_mut() {
+                                let completed = match &update {
+                                    Update::BlockDetails(block_details) => slot_range.push(block_details),
+                                    _ => false,
+                                };
+
+                                if completed {
+                                    log::info!("slot range processed up to slot {}, shutting down.", slot_range.range().end());
+                                    if !shutdown_initiated {
+                                        self.events.emit(PipelineEvent::ShutdownInitiated { reason: ShutdownReason::SlotRangeEnd });
+                                    }
+                                    datasource_cancellation_token.cancel();
+                                    self.flush(&mut checkpoint).await?;
+                                    self.lifecycle(Lifecycle::Teardown).await?;
+                                    self.metrics.flush_metrics().await?;
+                                    self.metrics.shutdown_metrics().await?;
+                                    break;
+                                }
+
+                                if !update.slot().is_some_and(|slot| slot_range.range().contains(&slot)) {
+                                    continue;
+                                }
+                            }
+
                             self
                                 .metrics.increment_counter("updates_received", 1)
                                 .await?;
 
+                            let trace_id = TraceId::new();
                             let start = Instant::now();
-                            let process_result = self.process(update.clone()).await;
+                            let process_result = strict_decoding_scope(
+                                self.strict_decoding,
+                                self.process(update.clone(), trace_id),
+                            )
+                            .await;
+                            let derived_result = self.process_derived().await;
+                            let process_result = process_result.and(derived_result);
+                            if let Some(slot) = update.slot() {
+                                last_processed_slot.fetch_max(slot, Ordering::Relaxed);
+                                checkpoint.record(slot, process_result.is_ok());
+                            }
+                            self.status.record_update(
+                                update.slot(),
+                                process_result.as_ref().err().map(ToString::to_string),
+                            );
                             let time_taken_nanoseconds = start.elapsed().as_nanos();
                             let time_taken_milliseconds = time_taken_nanoseconds / 1_000_000;
 
@@ -406,10 +771,10 @@ impl Pipeline {
                                         .metrics.increment_counter("updates_successful", 1)
                                         .await?;
 
-                                    log::trace!("processed update")
+                                    log::trace!("processed update (trace_id: {})", trace_id)
                                 }
                                 Err(error) => {
-                                    log::error!("error processing update ({:?}): {:?}", update, error);
+                                    log::error!("error processing update (trace_id: {}, {:?}): {:?}", trace_id, update, error);
                                     self.metrics.increment_counter("updates_failed", 1).await?;
                                 }
                             };
@@ -424,6 +789,11 @@ impl Pipeline {
                         }
                         None => {
                             log::info!("update_receiver closed, shutting down.");
+                            if !shutdown_initiated {
+                                self.events.emit(PipelineEvent::ShutdownInitiated { reason: ShutdownReason::DatasourcesFinished });
+                            }
+                            self.flush(&mut checkpoint).await?;
+                            self.lifecycle(Lifecycle::Teardown).await?;
                             self.metrics.flush_metrics().await?;
                             self.metrics.shutdown_metrics().await?;
                             break;
@@ -433,11 +803,471 @@ impl Pipeline {
             }
         }
 
+        self.status.stop();
+        self.events.emit(PipelineEvent::Stopped);
         log::info!("pipeline shutdown complete.");
 
         Ok(())
     }
 
+    /// Runs the `Pipeline` on its own task and returns a `PipelineHandle` to
+    /// supervise it.
+    ///
+    /// The handle can wait for the pipeline to stop, shut it down, abort it,
+    /// and report the health of each datasource task. This is meant for
+    /// services embedding the pipeline alongside other tasks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let handle = Pipeline::builder()
+    ///     .datasource(MyDatasource::new())
+    ///     .instruction(MyDecoder, MyProcessor)
+    ///     .build()?
+    ///     .spawn();
+    ///
+    /// if handle
+    ///     .health()
+    ///     .iter()
+    ///     .any(|health| matches!(health, TaskHealth::Failed(_)))
+    /// {
+    ///     handle.abort();
+    /// }
+    ///
+    /// handle.join().await?;
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - This method must be called within a Tokio runtime.
+    /// - `PipelineHandle::shutdown` cancels the datasources through the
+    ///   pipeline's datasource cancellation token, creating one if none was
+    ///   configured.
+    /// - `PipelineHandle::pause` stops the pipeline from pulling updates until
+    ///   `PipelineHandle::resume` is called, without disconnecting the
+    ///   datasources.
+    pub fn spawn(mut self) -> PipelineHandle {
+        log::trace!("spawn(self)");
+
+        let cancellation_token = self
+            .datasource_cancellation_token
+            .get_or_insert_with(CancellationToken::new)
+            .clone();
+        let datasource_health = self.datasource_health.clone();
+        let pause_state = self.pause_state.clone();
+        let status = self.status.clone();
+        let events = self.events.clone();
+
+        let task = tokio::spawn(async move { self.run().await });
+
+        PipelineHandle {
+            task,
+            cancellation_token,
+            datasource_health,
+            pause_state,
+            status,
+            events,
+        }
+    }
+
+    /// Returns a handle to the status of the pipeline, updated while it runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let status = pipeline.status();
+    /// tokio::spawn(async move { pipeline.run().await });
+    ///
+    /// let report = status.report();
+    /// log::info!("last processed slot: {:?}", report.last_processed_slot);
+    /// ```
+    pub fn status(&self) -> PipelineStatus {
+        self.status.clone()
+    }
+
+    /// Returns the `EventBus` the lifecycle events of the pipeline are
+    /// emitted on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut events = pipeline.events().subscribe();
+    /// tokio::spawn(async move { pipeline.run().await });
+    ///
+    /// while let Ok(event) = events.recv().await {
+    ///     log::info!("pipeline event: {:?}", event);
+    /// }
+    /// ```
+    pub fn events(&self) -> EventBus {
+        self.events.clone()
+    }
+
+    /// Returns a summary of the datasources, pipes, filters and settings of
+    /// the pipeline.
+    ///
+    /// Each pipe is described by its name, its kind, and the type names of
+    /// its decoder and processor. The description serializes to JSON, for
+    /// status endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let description = pipeline.describe();
+    /// for pipe in &description.pipes {
+    ///     log::info!("pipe {}: {:?} -> {:?}", pipe.name, pipe.decoder, pipe.processor);
+    /// }
+    /// ```
+    pub fn describe(&self) -> PipelineDescription {
+        let datasources = self
+            .datasources
+            .iter()
+            .enumerate()
+            .map(|(index, datasource)| DatasourceDescription {
+                name: datasource_name(&self.datasource_names, index),
+                capabilities: describe_capabilities(datasource.capabilities().iter()),
+            })
+            .collect();
+
+        PipelineDescription {
+            datasources,
+            pipes: describe_pipes(&self.pipe_descriptions, self.pipe_counts()),
+            filters: FilterDescription {
+                skip_unchanged_accounts: self.account_data_cache.is_some(),
+                skip_stale_accounts: self.account_watermark.is_some(),
+                slot_range: self.slot_range.clone(),
+                transaction_limits: self.transaction_limits,
+                transaction_verification: self.transaction_verification,
+            },
+            metrics: self.metrics.metrics.len(),
+            metrics_flush_interval: self.metrics_flush_interval,
+            channel_buffer_size: self.channel_buffer_size,
+            shutdown_strategy: describe_shutdown_strategy(&self.shutdown_strategy),
+        }
+    }
+
+    /// Returns the number of pipes of each kind.
+    fn pipe_counts(&self) -> [(PipeKind, usize); 10] {
+        [
+            (PipeKind::Account, self.account_pipes.len()),
+            (PipeKind::AccountDeletion, self.account_deletion_pipes.len()),
+            (
+                PipeKind::AccountLifecycle,
+                self.account_lifecycle_pipes.len(),
+            ),
+            (PipeKind::Instruction, self.instruction_pipes.len()),
+            (PipeKind::Transaction, self.transaction_pipes.len()),
+            (
+                PipeKind::TransactionStatus,
+                self.transaction_status_pipes.len(),
+            ),
+            (PipeKind::BlockDetails, self.block_details_pipes.len()),
+            (PipeKind::Bundle, self.bundle_pipes.len()),
+            (PipeKind::Epoch, self.epoch_pipes.len()),
+            (PipeKind::Derived, self.derived_pipes.len()),
+        ]
+    }
+
+    /// Connects to the datasources and decodes a sample of live updates
+    /// without invoking any processor.
+    ///
+    /// This is a dry run meant to catch misconfiguration before a long
+    /// backfill starts. The pipeline is validated as in
+    /// [`PipelineBuilder::validate`], then updates are received until
+    /// `sample_size` updates have been seen, the datasources stop, or `timeout`
+    /// elapses. Each update is decoded by every pipe, but the processors never
+    /// run.
+    ///
+    /// # Parameters
+    ///
+    /// - `sample_size`: The number of updates to decode before stopping.
+    /// - `timeout`: The maximum time to wait for updates.
+    ///
+    /// # Returns
+    ///
+    /// A [`CheckReport`] summarizing the received and decoded updates. An error
+    /// is returned only if validation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let report = pipeline.check(100, Duration::from_secs(30)).await?;
+    /// if report.accounts_decoded == 0 {
+    ///     log::warn!("no account could be decoded, check the decoders");
+    /// }
+    /// ```
+    pub async fn check(
+        &self,
+        sample_size: usize,
+        timeout: time::Duration,
+    ) -> CarbonResult<CheckReport> {
+        log::trace!(
+            "check(self, sample_size: {:?}, timeout: {:?})",
+            sample_size,
+            timeout
+        );
+
+        validate_components(
+            &self.datasources,
+            &self.datasource_names,
+            &describe_pipes(&self.pipe_descriptions, self.pipe_counts()),
+            self.slot_range.as_ref(),
+            self.channel_buffer_size,
+        )?;
+
+        let (update_sender, mut update_receiver) =
+            tokio::sync::mpsc::channel::<Update>(self.channel_buffer_size);
+        let cancellation_token = CancellationToken::new();
+
+        let handles: Vec<_> = self
+            .datasources
+            .iter()
+            .map(|datasource| {
+                let datasource = Arc::clone(datasource);
+                let sender = update_sender.clone();
+                let cancellation_token = cancellation_token.clone();
+                let metrics = self.metrics.clone();
+
+                tokio::spawn(async move {
+                    datasource
+                        .consume(&sender, cancellation_token, metrics)
+                        .await
+                })
+            })
+            .collect();
+        drop(update_sender);
+
+        let mut report = CheckReport::default();
+        let deadline = tokio::time::sleep(timeout);
+        tokio::pin!(deadline);
+
+        while report.updates_received < sample_size {
+            tokio::select! {
+                _ = &mut deadline => break,
+                update = update_receiver.recv() => match update {
+                    Some(Update::DatasourceFinished) => continue,
+                    Some(update) => with_strict_decoding(self.strict_decoding, || {
+                        self.check_update(update, &mut report)
+                    }),
+                    None => break,
+                }
+            }
+        }
+
+        cancellation_token.cancel();
+
+        for handle in handles {
+            if !handle.is_finished() {
+                handle.abort();
+                continue;
+            }
+
+            match handle.await {
+                Ok(Err(error)) => report.errors.push(error.to_string()),
+                Err(error) => report.errors.push(error.to_string()),
+                Ok(Ok(())) => {}
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn check_update(&self, update: Update, report: &mut CheckReport) {
+        report.updates_received += 1;
+
+        match update {
+            Update::Account(account_update) => {
+                report.account_updates += 1;
+
+                if self
+                    .account_pipes
+                    .iter()
+                    .any(|pipe| pipe.decode_only(&account_update.account, account_update.slot))
+                {
+                    report.accounts_decoded += 1;
+                }
+            }
+            Update::Transaction(transaction_update) => {
+                report.transaction_updates += 1;
+
+                let nested_instructions = (*transaction_update)
+                    .clone()
+                    .try_into()
+                    .and_then(|transaction_metadata: TransactionMetadata| {
+                        transformers::extract_instructions_with_metadata(
+                            &transaction_metadata,
+                            &transaction_update,
+                        )
+                    })
+                    .map(NestedInstructions::from);
+
+                match nested_instructions {
+                    Ok(nested_instructions) => {
+                        for pipe in self.instruction_pipes.iter() {
+                            report.instructions_decoded += nested_instructions
+                                .iter()
+                                .map(|nested_instruction| pipe.decode_only(nested_instruction))
+                                .sum::<usize>();
+                        }
+
+                        for pipe in self.transaction_pipes.iter() {
+                            report.instructions_decoded += pipe.decode_only(&nested_instructions);
+                        }
+                    }
+                    Err(error) => report.errors.push(error.to_string()),
+                }
+            }
+            Update::AccountDeletion(_) => {
+                report.account_deletions += 1;
+            }
+            Update::TransactionStatus(_) => {
+                report.transaction_statuses += 1;
+            }
+            Update::BlockDetails(_) => {
+                report.block_details += 1;
+            }
+            Update::DatasourceFinished => {}
+        }
+    }
+
+    /// Flushes the processors, then commits the checkpoint advanced since the
+    /// last flush, as the updates up to it are now written durably.
+    async fn flush(&mut self, checkpoint: &mut CheckpointTracker) -> CarbonResult<()> {
+        self.lifecycle(Lifecycle::Flush).await?;
+        if let Some(slot) = checkpoint.commit() {
+            self.events.emit(PipelineEvent::CheckpointAdvanced { slot });
+        }
+
+        Ok(())
+    }
+
+    /// Runs a lifecycle hook on the processor of every pipe, stopping at the
+    /// first error.
+    async fn lifecycle(&mut self, lifecycle: Lifecycle) -> CarbonResult<()> {
+        log::trace!("lifecycle(self, lifecycle: {:?})", lifecycle);
+
+        for pipe in self.account_pipes.iter_mut() {
+            isolate_panics(
+                "account",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.account_deletion_pipes.iter_mut() {
+            isolate_panics(
+                "account deletion",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.account_lifecycle_pipes.iter_mut() {
+            isolate_panics(
+                "account lifecycle",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.instruction_pipes.iter_mut() {
+            isolate_panics(
+                "instruction",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.transaction_pipes.iter_mut() {
+            isolate_panics(
+                "transaction",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.transaction_status_pipes.iter_mut() {
+            isolate_panics(
+                "transaction status",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.block_details_pipes.iter_mut() {
+            isolate_panics(
+                "block details",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.bundle_pipes.iter_mut() {
+            isolate_panics(
+                "bundle",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        for pipe in self.epoch_pipes.iter_mut() {
+            isolate_panics(
+                "epoch",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        if lifecycle == Lifecycle::Flush {
+            // Processes the updates derived while flushing before flushing
+            // the pipes processing them.
+            self.process_derived().await?;
+        }
+        for pipe in self.derived_pipes.iter_mut() {
+            isolate_panics(
+                "derived",
+                pipe.lifecycle(lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+        if let Some(processor) = self.skipped_transaction_processor.as_mut() {
+            isolate_panics(
+                "skipped transaction",
+                run_lifecycle(processor.as_mut(), lifecycle, self.metrics.clone()),
+                &self.metrics,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Processes the updates derived by the pipes until none is left, as
+    /// derived pipes can derive updates in turn.
+    async fn process_derived(&mut self) -> CarbonResult<()> {
+        loop {
+            let mut processed = 0;
+            for (index, pipe) in self.derived_pipes.iter_mut().enumerate() {
+                let result = isolate_panics(
+                    "derived",
+                    async {
+                        processed += pipe.run(self.metrics.clone()).await?;
+                        Ok(())
+                    },
+                    &self.metrics,
+                )
+                .await;
+                self.status
+                    .record_pipe(PipeKind::Derived, index, result.is_err());
+                result?;
+            }
+
+            if processed == 0 {
+                return Ok(());
+            }
+        }
+    }
+
     /// Processes a single update and routes it through the appropriate pipeline
     /// stages.
     ///
@@ -457,6 +1287,17 @@ impl Pipeline {
     ///   `instruction_pipes` and `transaction_pipes`.
     /// - **Account Deletions**: Sends account deletion events through the
     ///   `account_deletion_pipes`.
+    /// - **Account Lifecycle**: Passes the metadata of every account update
+    ///   through the `account_lifecycle_pipes` before the `account_pipes`, and
+    ///   every account deletion before the `account_deletion_pipes`.
+    /// - **Transaction Statuses**: Sends transaction status updates through
+    ///   the `transaction_status_pipes`.
+    /// - **Block Details**: Sends block metadata and rewards through the
+    ///   `block_details_pipes`, and transactions through the `bundle_pipes`,
+    ///   which group them into Jito bundles.
+    /// - **Epoch Changes**: Passes the slot of every update through the
+    ///   `epoch_pipes` first, so epoch changes are processed before the first
+    ///   update of the new epoch.
     ///
     /// The method also updates metrics counters for each type of update,
     /// tracking how many updates have been processed in each category.
@@ -466,6 +1307,9 @@ impl Pipeline {
     /// - `update`: An `Update` variant representing the type of data received.
     ///   This can be an `Account`, `Transaction`, or `AccountDeletion`, each
     ///   triggering different processing logic within the pipeline.
+    /// - `trace_id`: The `TraceId` assigned to the update on ingestion. It is
+    ///   set on the `AccountMetadata` or `TransactionMetadata` passed to the
+    ///   pipes.
     ///
     /// # Returns
     ///
@@ -487,21 +1331,88 @@ impl Pipeline {
     /// Returns an error if any of the pipes fail during processing, or if an
     /// issue arises while incrementing counters or updating metrics. Handle
     /// errors gracefully to ensure continuous pipeline operation.
-    async fn process(&mut self, update: Update) -> CarbonResult<()> {
-        log::trace!("process(self, update: {:?})", update);
+    async fn process(&mut self, update: Update, trace_id: TraceId) -> CarbonResult<()> {
+        log::trace!(
+            "process(self, update: {:?}, trace_id: {})",
+            update,
+            trace_id
+        );
+        if let Some(slot) = update.slot() {
+            for (index, pipe) in self.epoch_pipes.iter_mut().enumerate() {
+                let result =
+                    isolate_panics("epoch", pipe.run(slot, self.metrics.clone()), &self.metrics)
+                        .await;
+                self.status
+                    .record_pipe(PipeKind::Epoch, index, result.is_err());
+                result?;
+            }
+        }
+
         match update {
             Update::Account(account_update) => {
+                if let Some(account_watermark) = self.account_watermark.as_mut() {
+                    if account_watermark.is_stale(
+                        &account_update.pubkey,
+                        account_update.slot,
+                        account_update.write_version,
+                    ) {
+                        self.metrics
+                            .increment_counter("account_updates_stale", 1)
+                            .await?;
+
+                        return Ok(());
+                    }
+                }
+
+                if let Some(account_data_cache) = self.account_data_cache.as_mut() {
+                    if account_data_cache
+                        .is_unchanged(&account_update.pubkey, &account_update.account)
+                    {
+                        self.metrics
+                            .increment_counter("account_updates_suppressed", 1)
+                            .await?;
+
+                        return Ok(());
+                    }
+                }
+
                 let account_metadata = AccountMetadata {
                     slot: account_update.slot,
                     pubkey: account_update.pubkey,
+                    trace_id,
+                    commitment: account_update.commitment,
+                    write_version: account_update.write_version,
+                    lamports: account_update.account.lamports,
+                    owner: account_update.account.owner,
+                    executable: account_update.account.executable,
+                    rent_epoch: account_update.account.rent_epoch,
                 };
 
-                for pipe in self.account_pipes.iter_mut() {
-                    pipe.run(
-                        (account_metadata.clone(), account_update.account.clone()),
-                        self.metrics.clone(),
+                for (index, pipe) in self.account_lifecycle_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "account lifecycle",
+                        pipe.run(&account_metadata, self.metrics.clone()),
+                        &self.metrics,
                     )
-                    .await?;
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::AccountLifecycle, index, result.is_err());
+                    result?;
+                }
+
+                for (index, pipe) in self.account_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "account",
+                        pipe.run(
+                            (account_metadata.clone(), account_update.account.clone()),
+                            self.metrics.clone(),
+                        ),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::Account, index, result.is_err());
+                    result?;
                 }
 
                 self.metrics
@@ -509,7 +1420,90 @@ impl Pipeline {
                     .await?;
             }
             Update::Transaction(transaction_update) => {
-                let transaction_metadata = &(*transaction_update).clone().try_into()?;
+                if let Some(reason) = self
+                    .transaction_limits
+                    .as_ref()
+                    .and_then(|limits| limits.check(&transaction_update))
+                {
+                    log::warn!(
+                        "skipping transaction {} (trace_id: {}): {}",
+                        transaction_update.signature,
+                        trace_id,
+                        reason
+                    );
+                    self.metrics
+                        .increment_counter("transactions_skipped", 1)
+                        .await?;
+                    self.metrics
+                        .increment_counter(reason.metric_name(), 1)
+                        .await?;
+
+                    if let Some(processor) = self.skipped_transaction_processor.as_mut() {
+                        isolate_panics(
+                            "skipped transaction",
+                            processor.process(
+                                SkippedTransaction {
+                                    transaction_update,
+                                    reason,
+                                },
+                                self.metrics.clone(),
+                            ),
+                            &self.metrics,
+                        )
+                        .await?;
+                    }
+
+                    return Ok(());
+                }
+
+                let verification_failure = match self.transaction_verification {
+                    Some(mode) => match verification::verify_transaction(&transaction_update) {
+                        Some(failure) => {
+                            log::warn!(
+                                "transaction {} failed verification (trace_id: {}): {}",
+                                transaction_update.signature,
+                                trace_id,
+                                failure
+                            );
+                            self.metrics
+                                .increment_counter("transactions_unverified", 1)
+                                .await?;
+                            self.metrics
+                                .increment_counter(failure.metric_name(), 1)
+                                .await?;
+
+                            if mode == VerificationMode::Drop {
+                                return Ok(());
+                            }
+                            Some(failure)
+                        }
+                        None => None,
+                    },
+                    None => None,
+                };
+
+                let mut transaction_metadata: TransactionMetadata =
+                    (*transaction_update).clone().try_into()?;
+                transaction_metadata.verification_failure = verification_failure;
+                transaction_metadata.trace_id = trace_id;
+                transaction_metadata.slot_clock = self
+                    .cluster_schedule
+                    .map(|cluster_schedule| cluster_schedule.slot_clock(transaction_metadata.slot));
+                if self.detect_actions {
+                    transaction_metadata.actions = actions::extract(&transaction_metadata);
+                    if !transaction_metadata.actions.is_empty() {
+                        self.metrics
+                            .increment_counter("action_transactions_processed", 1)
+                            .await?;
+                    }
+                }
+                if let Some(failure_reason) = &mut transaction_metadata.failure_reason {
+                    failure_reason.resolve(&self.program_error_resolvers);
+                    self.metrics
+                        .increment_counter("failed_transactions_processed", 1)
+                        .await?;
+                }
+                let transaction_metadata = &transaction_metadata;
 
                 let instructions_with_metadata: InstructionsWithMetadata =
                     transformers::extract_instructions_with_metadata(
@@ -519,19 +1513,87 @@ impl Pipeline {
 
                 let nested_instructions: NestedInstructions = instructions_with_metadata.into();
 
-                for pipe in self.instruction_pipes.iter_mut() {
+                if let Some(limits) = self.transaction_limits.as_ref() {
+                    let oversized_instructions =
+                        limits.oversized_instructions(&nested_instructions);
+                    if oversized_instructions > 0 {
+                        self.metrics
+                            .increment_counter(
+                                "instructions_oversized",
+                                oversized_instructions as u64,
+                            )
+                            .await?;
+                    }
+                }
+
+                for (index, pipe) in self.instruction_pipes.iter_mut().enumerate() {
                     for nested_instruction in nested_instructions.iter() {
-                        pipe.run(nested_instruction, self.metrics.clone()).await?;
+                        let result = isolate_panics(
+                            "instruction",
+                            pipe.run(nested_instruction, self.metrics.clone()),
+                            &self.metrics,
+                        )
+                        .await;
+                        self.status
+                            .record_pipe(PipeKind::Instruction, index, result.is_err());
+                        result?;
                     }
                 }
 
-                for pipe in self.transaction_pipes.iter_mut() {
-                    pipe.run(
-                        transaction_metadata.clone(),
+                if let Some(checked_programs) = self.checked_programs.as_mut() {
+                    suggest_missing_decoders(
+                        checked_programs,
+                        &self.instruction_pipes,
                         &nested_instructions,
-                        self.metrics.clone(),
+                    );
+                }
+
+                let raw_transaction = if self
+                    .transaction_pipes
+                    .iter()
+                    .any(|pipe| pipe.wants_raw_transaction())
+                {
+                    let raw_transaction = bincode::serialize(&transaction_update.transaction)
+                        .map_err(|err| {
+                            Error::Custom(format!(
+                                "Failed to serialize transaction {}: {}",
+                                transaction_update.signature, err
+                            ))
+                        })?;
+                    Some(Arc::new(raw_transaction))
+                } else {
+                    None
+                };
+
+                for (index, pipe) in self.transaction_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "transaction",
+                        pipe.run(
+                            TransactionMetadata {
+                                raw_transaction: raw_transaction.clone(),
+                                ..transaction_metadata.clone()
+                            },
+                            &nested_instructions,
+                            self.metrics.clone(),
+                        ),
+                        &self.metrics,
                     )
-                    .await?;
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::Transaction, index, result.is_err());
+                    result?;
+                }
+
+                for (index, pipe) in self.bundle_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "bundle",
+                        pipe.run(&transaction_update, self.metrics.clone()),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::Bundle, index, result.is_err());
+                    result?;
                 }
 
                 self.metrics
@@ -539,20 +1601,89 @@ impl Pipeline {
                     .await?;
             }
             Update::AccountDeletion(account_deletion) => {
-                for pipe in self.account_deletion_pipes.iter_mut() {
-                    pipe.run(account_deletion.clone(), self.metrics.clone())
-                        .await?;
+                if let Some(account_watermark) = self.account_watermark.as_mut() {
+                    if account_watermark.delete(&account_deletion.pubkey, account_deletion.slot) {
+                        self.metrics
+                            .increment_counter("account_deletions_stale", 1)
+                            .await?;
+
+                        return Ok(());
+                    }
+                }
+                if let Some(account_data_cache) = self.account_data_cache.as_mut() {
+                    account_data_cache.remove(&account_deletion.pubkey);
+                }
+
+                for (index, pipe) in self.account_lifecycle_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "account lifecycle",
+                        pipe.run_deletion(&account_deletion, self.metrics.clone()),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::AccountLifecycle, index, result.is_err());
+                    result?;
+                }
+
+                for (index, pipe) in self.account_deletion_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "account deletion",
+                        pipe.run(account_deletion.clone(), self.metrics.clone()),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::AccountDeletion, index, result.is_err());
+                    result?;
                 }
 
                 self.metrics
                     .increment_counter("account_deletions_processed", 1)
                     .await?;
             }
-        };
+            Update::TransactionStatus(transaction_status) => {
+                for (index, pipe) in self.transaction_status_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "transaction status",
+                        pipe.run(transaction_status.clone(), self.metrics.clone()),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::TransactionStatus, index, result.is_err());
+                    result?;
+                }
 
-        Ok(())
-    }
-}
+                self.metrics
+                    .increment_counter("transaction_statuses_processed", 1)
+                    .await?;
+            }
+            Update::BlockDetails(block_details) => {
+                for (index, pipe) in self.block_details_pipes.iter_mut().enumerate() {
+                    let result = isolate_panics(
+                        "block details",
+                        pipe.run(block_details.clone(), self.metrics.clone()),
+                        &self.metrics,
+                    )
+                    .await;
+                    self.status
+                        .record_pipe(PipeKind::BlockDetails, index, result.is_err());
+                    result?;
+                }
+
+                self.metrics
+                    .increment_counter("block_details_processed", 1)
+                    .await?;
+            }
+            // Handled by `run`, which shuts down once every bounded
+            // datasource has finished.
+            Update::DatasourceFinished => {}
+        };
+
+        Ok(())
+    }
+}
 
 /// A builder for constructing a `Pipeline` instance with customized data
 /// sources, processing pipes, and metrics.
@@ -573,6 +1704,10 @@ impl Pipeline {
 /// - **Instruction Pipes**: For handling instructions associated with
 ///   transactions.
 /// - **Transaction Pipes**: For handling full transaction data.
+/// - **Transaction Status Pipes**: For handling transaction status updates.
+/// - **Block Details Pipes**: For handling block metadata and rewards.
+/// - **Bundle Pipes**: For handling the Jito bundles detected in transactions.
+/// - **Epoch Pipes**: For handling the epoch changes of the updates' slots.
 /// - **Metrics**: Collects and reports performance data, such as update
 ///   processing times.
 /// - **Metrics Flush Interval**: Optional interval defining how often to flush
@@ -603,10 +1738,22 @@ impl Pipeline {
 /// - `account_pipes`: A collection of `AccountPipes` to handle account updates.
 /// - `account_deletion_pipes`: A collection of `AccountDeletionPipes` for
 ///   processing account deletions.
+/// - `account_lifecycle_pipes`: A collection of `AccountLifecyclePipes` to
+///   process the ownership changes and closures of accounts.
 /// - `instruction_pipes`: A collection of `InstructionPipes` to process
 ///   instructions in transactions.
 /// - `transaction_pipes`: A collection of `TransactionPipes` to process full
 ///   transaction data.
+/// - `transaction_status_pipes`: A collection of `TransactionStatusPipes` to
+///   process transaction status updates.
+/// - `block_details_pipes`: A collection of `BlockDetailsPipes` to process
+///   block metadata and rewards.
+/// - `bundle_pipes`: A collection of `BundlePipes` to process the Jito bundles
+///   detected in transactions.
+/// - `epoch_pipes`: A collection of `EpochPipes` to process the epoch changes
+///   of the updates' slots.
+/// - `derived_pipes`: A collection of `DerivedPipes` to process the updates
+///   derived by other pipes.
 /// - `metrics`: A vector of `Metrics` implementations for tracking pipeline
 ///   performance.
 /// - `metrics_flush_interval`: An optional interval (in seconds) for flushing
@@ -615,6 +1762,32 @@ impl Pipeline {
 ///   canceling datasource. If not set, a default `CancellationToken` will be used.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
 ///   If not set, a default size of 10_000 will be used.
+/// - `account_data_cache`: An optional `AccountDataCache` used to skip
+///   unchanged account updates. Disabled unless `skip_unchanged_accounts` is
+///   set.
+/// - `account_watermark`: An optional `AccountWatermark` used to skip stale
+///   account updates. Disabled unless `skip_stale_accounts` is set.
+/// - `lag_monitor`: An optional `LagMonitor` measuring the lag of the pipeline
+///   against the chain tip.
+/// - `program_error_resolvers`: The resolvers registered with
+///   `program_errors`, by program ID.
+/// - `slot_range`: An optional range of slots to process, set with
+///   `slot_range`.
+/// - `transaction_limits` and `skipped_transaction_processor`: The limits
+///   above which transactions are skipped, and the processor receiving them,
+///   set with `transaction_limits` and `skipped_transactions`.
+/// - `transaction_verification`: The `VerificationMode` of the transactions
+///   failing verification, set with `verify_transactions`.
+/// - `cluster_schedule`: An optional `ClusterSchedule` computing the
+///   `SlotClock` of each transaction, set with `cluster_schedule`.
+/// - `detect_actions`: Whether the `ActionIdentifier`s of each transaction
+///   are parsed from its memos, set with `detect_actions`.
+/// - `strict_decoding`: Whether the pipes decode in strict decoding mode, set
+///   with `strict_decoding`.
+/// - `clock`: An optional `Clock` timing the pipeline, set with `clock`. The
+///   system time is used if not set.
+/// - `event_bus`: An optional `EventBus` the lifecycle events are emitted on,
+///   set with `event_bus`. A new bus is used if not set.
 ///
 /// # Returns
 ///
@@ -633,13 +1806,39 @@ pub struct PipelineBuilder {
     pub datasources: Vec<Arc<dyn Datasource + Send + Sync>>,
     pub account_pipes: Vec<Box<dyn AccountPipes>>,
     pub account_deletion_pipes: Vec<Box<dyn AccountDeletionPipes>>,
+    pub account_lifecycle_pipes: Vec<Box<dyn AccountLifecyclePipes>>,
     pub instruction_pipes: Vec<Box<dyn for<'a> InstructionPipes<'a>>>,
     pub transaction_pipes: Vec<Box<dyn for<'a> TransactionPipes<'a>>>,
+    pub transaction_status_pipes: Vec<Box<dyn TransactionStatusPipes>>,
+    pub block_details_pipes: Vec<Box<dyn BlockDetailsPipes>>,
+    pub bundle_pipes: Vec<Box<dyn BundlePipes>>,
+    pub epoch_pipes: Vec<Box<dyn EpochPipes>>,
+    pub derived_pipes: Vec<Box<dyn DerivedPipes>>,
     pub metrics: MetricsCollection,
     pub metrics_flush_interval: Option<u64>,
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
     pub channel_buffer_size: usize,
+    pub account_data_cache: Option<AccountDataCache>,
+    pub account_watermark: Option<AccountWatermark>,
+    pub lag_monitor: Option<LagMonitor>,
+    pub program_error_resolvers: HashMap<Pubkey, ProgramErrorResolver>,
+    pub restart_policy: RestartPolicy,
+    pub suggest_missing_decoders: bool,
+    pub slot_range: Option<RangeInclusive<u64>>,
+    pub transaction_limits: Option<TransactionLimits>,
+    pub skipped_transaction_processor:
+        Option<Box<dyn Processor<InputType = SkippedTransaction> + Send + Sync>>,
+    pub transaction_verification: Option<VerificationMode>,
+    pub cluster_schedule: Option<ClusterSchedule>,
+    pub detect_actions: bool,
+    pub strict_decoding: bool,
+    pub clock: Option<Arc<dyn Clock>>,
+    pub datasource_names: Vec<String>,
+    pub pipe_descriptions: Vec<PipeDescription>,
+    pub event_bus: Option<EventBus>,
+    #[cfg(feature = "status-server")]
+    pub status_server_addr: Option<std::net::SocketAddr>,
 }
 
 impl PipelineBuilder {
@@ -659,7 +1858,7 @@ impl PipelineBuilder {
     /// ```
     pub fn new() -> Self {
         log::trace!("PipelineBuilder::new()");
-        Self::default()
+        Pipeline::builder()
     }
 
     /// Adds a datasource to the pipeline.
@@ -681,6 +1880,7 @@ impl PipelineBuilder {
     /// ```
     pub fn datasource(mut self, datasource: impl Datasource + 'static) -> Self {
         log::trace!("datasource(self, datasource: {:?})", stringify!(datasource));
+        self.datasource_names.push(short_type_name_of(&datasource));
         self.datasources.push(Arc::new(datasource));
         self
     }
@@ -744,6 +1944,11 @@ impl PipelineBuilder {
             stringify!(decoder),
             stringify!(processor)
         );
+        self.describe_pipe(
+            PipeKind::Account,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
         self.account_pipes.push(Box::new(AccountPipe {
             decoder: Box::new(decoder),
             processor: Box::new(processor),
@@ -751,6 +1956,116 @@ impl PipelineBuilder {
         self
     }
 
+    /// Adds an account pipe whose processor only receives a sample of the
+    /// decoded account updates.
+    ///
+    /// This behaves like [`PipelineBuilder::account`], but wraps the processor
+    /// in a `SampledProcessor`. Other pipes on the pipeline are not affected
+    /// and still see every account update.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `AccountDecoder` that decodes the account data.
+    /// - `processor`: A `Processor` that processes the decoded account data.
+    /// - `sampling`: The [`Sampling`] applied before the processor runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .account_sampled(MyAccountDecoder, MyAnalyticsProcessor, Sampling::MaxPerSecond(5));
+    /// ```
+    pub fn account_sampled<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = AccountProcessorInputType<T>> + Send + Sync + 'static,
+        sampling: Sampling,
+    ) -> Self {
+        log::trace!("account_sampled(self, sampling: {:?})", sampling);
+        self.account(decoder, SampledProcessor::new(processor, sampling))
+    }
+
+    /// Adds an account pipe whose processor receives the updates of each
+    /// account in order.
+    ///
+    /// This behaves like [`PipelineBuilder::account`], but wraps the processor
+    /// in an `OrderedProcessor`. Updates older than the last update passed to
+    /// the processor for the same account, as datasources deliver after
+    /// reconnecting or when merged, are dropped or, with
+    /// `AccountOrdering::Reorder`, put back in order within a delay. Dropped
+    /// updates are counted in the `account_updates_out_of_order` metric.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `AccountDecoder` that decodes the account data.
+    /// - `processor`: A `Processor` that processes the decoded account data.
+    /// - `ordering`: The [`AccountOrdering`] applied before the processor
+    ///   runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .account_ordered(MyAccountDecoder, MyStateStore, AccountOrdering::Drop);
+    /// ```
+    pub fn account_ordered<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = AccountProcessorInputType<T>> + Send + Sync + 'static,
+        ordering: AccountOrdering,
+    ) -> Self {
+        log::trace!("account_ordered(self, ordering: {:?})", ordering);
+        self.account(decoder, OrderedProcessor::new(processor, ordering))
+    }
+
+    /// Adds an account pipe alerting when a value of the decoded accounts
+    /// changes by more than `threshold` within `window`.
+    ///
+    /// The value of each account is extracted by `value` and tracked per
+    /// account. When it grows or drops by more than `threshold`, relative to a
+    /// value seen within the window, a `RateOfChangeAlert` is passed to the
+    /// alert processor and counted in the `rate_of_change_alerts` metric.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `AccountDecoder` that decodes the account data.
+    /// - `value`: Extracts the watched value of a decoded account, or `None`
+    ///   to ignore the account.
+    /// - `threshold`: The relative change firing an alert, `0.5` for 50%.
+    /// - `window`: How long values are compared against.
+    /// - `processor`: A `Processor` handling the alerts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .account_rate_of_change(
+    ///         MyPoolDecoder,
+    ///         |pool| Some(pool.reserves as f64),
+    ///         0.5,
+    ///         Duration::from_secs(60),
+    ///         MyAlertProcessor,
+    ///     );
+    /// ```
+    pub fn account_rate_of_change<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static,
+        value: impl Fn(&T) -> Option<f64> + Send + Sync + 'static,
+        threshold: f64,
+        window: time::Duration,
+        processor: impl Processor<InputType = RateOfChangeAlert> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "account_rate_of_change(self, threshold: {:?}, window: {:?})",
+            threshold,
+            window
+        );
+        self.account(
+            decoder,
+            RateOfChangeProcessor::new(value, threshold, window, processor),
+        )
+    }
+
     /// Adds an account deletion pipe to handle account deletion events.
     ///
     /// Account deletion pipes process deletions of accounts, with a `Processor`
@@ -774,6 +2089,11 @@ impl PipelineBuilder {
             "account_deletions(self, processor: {:?})",
             stringify!(processor)
         );
+        self.describe_pipe(
+            PipeKind::AccountDeletion,
+            None,
+            short_type_name_of(&processor),
+        );
         self.account_deletion_pipes
             .push(Box::new(AccountDeletionPipe {
                 processor: Box::new(processor),
@@ -781,214 +2101,1763 @@ impl PipelineBuilder {
         self
     }
 
-    /// Adds an instruction pipe to process instructions within transactions.
+    /// Adds an account lifecycle pipe following the owner and lamports of
+    /// the updated accounts.
     ///
-    /// Instruction pipes decode and process individual instructions,
-    /// enabling specialized handling of various instruction types.
+    /// When an account is assigned to another program, its lamports drop to
+    /// zero or it is deleted, an `AccountLifecycleEvent` is passed to the
+    /// processor before the update reaches the account or account deletion
+    /// pipes. Sinks keeping the latest state of accounts provide processors
+    /// deleting it on these events.
     ///
     /// # Parameters
     ///
-    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
-    ///   transaction data.
-    /// - `processor`: A `Processor` that processes decoded instruction data.
+    /// - `processor`: A `Processor` that processes account lifecycle events.
     ///
     /// # Example
     ///
     /// ```rust
     /// let builder = PipelineBuilder::new()
-    ///     .instruction(MyDecoder, MyInstructionProcessor);
+    ///     .account_lifecycle(MyAccountLifecycleProcessor);
     /// ```
-    pub fn instruction<T: Send + Sync + 'static>(
+    pub fn account_lifecycle(
         mut self,
-        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
-        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+        processor: impl Processor<InputType = AccountLifecycleEvent> + Send + Sync + 'static,
     ) -> Self {
         log::trace!(
-            "instruction(self, decoder: {:?}, processor: {:?})",
-            stringify!(decoder),
+            "account_lifecycle(self, processor: {:?})",
             stringify!(processor)
         );
-        self.instruction_pipes.push(Box::new(InstructionPipe {
-            decoder: Box::new(decoder),
-            processor: Box::new(processor),
-        }));
+        self.describe_pipe(
+            PipeKind::AccountLifecycle,
+            None,
+            short_type_name_of(&processor),
+        );
+        self.account_lifecycle_pipes
+            .push(Box::new(AccountLifecyclePipe {
+                tracker: AccountLifecycleTracker::new(),
+                processor: Box::new(processor),
+            }));
         self
     }
 
-    /// Adds a transaction pipe for processing full transaction data.
+    /// Adds a transaction status pipe to handle transaction status updates.
     ///
-    /// This method requires a transaction schema for decoding and a `Processor`
-    /// to handle the processed transaction data.
+    /// Transaction status pipes receive the signature, slot and error of each
+    /// transaction without its instructions, which is enough to track the
+    /// confirmation of submitted transactions. The datasource must provide
+    /// the `Capability::TransactionStatuses` capability.
     ///
     /// # Parameters
     ///
-    /// - `schema`: A `TransactionSchema` used to match and interpret
-    ///   transaction data.
-    /// - `processor`: A `Processor` that processes the decoded transaction
-    ///   data.
+    /// - `processor`: A `Processor` that processes transaction status updates.
     ///
     /// # Example
     ///
     /// ```rust
     /// let builder = PipelineBuilder::new()
-    ///     .transaction(MY_SCHEMA.clone(), MyTransactionProcessor);
+    ///     .transaction_statuses(MyTransactionStatusProcessor);
     /// ```
-    pub fn transaction<T, U>(
+    pub fn transaction_statuses(
         mut self,
-        processor: impl Processor<InputType = TransactionProcessorInputType<T, U>>
-            + Send
-            + Sync
-            + 'static,
-        schema: Option<TransactionSchema<T>>,
-    ) -> Self
-    where
-        T: InstructionDecoderCollection + 'static,
-        U: DeserializeOwned + Send + Sync + 'static,
-    {
+        processor: impl Processor<InputType = TransactionStatusUpdate> + Send + Sync + 'static,
+    ) -> Self {
         log::trace!(
-            "transaction(self, schema: {:?}, processor: {:?})",
-            stringify!(schema),
+            "transaction_statuses(self, processor: {:?})",
             stringify!(processor)
         );
-        self.transaction_pipes
-            .push(Box::new(TransactionPipe::<T, U>::new(schema, processor)));
+        self.describe_pipe(
+            PipeKind::TransactionStatus,
+            None,
+            short_type_name_of(&processor),
+        );
+        self.transaction_status_pipes
+            .push(Box::new(TransactionStatusPipe {
+                processor: Box::new(processor),
+            }));
         self
     }
 
-    /// Adds a metrics component to the pipeline for performance tracking.
+    /// Adds a block details pipe to handle block metadata and rewards.
     ///
-    /// This component collects and reports on pipeline metrics, providing
-    /// insights into performance and operational statistics.
+    /// Block details pipes receive one `BlockDetails` per block, including its
+    /// parent slot, height, time and rewards. The datasource must provide
+    /// the `Capability::BlockDetails` capability.
     ///
     /// # Parameters
     ///
-    /// - `metrics`: An instance of a `Metrics` implementation, used to gather
-    ///   and report metrics.
+    /// - `processor`: A `Processor` that processes block details.
     ///
     /// # Example
     ///
     /// ```rust
     /// let builder = PipelineBuilder::new()
-    ///     .metrics(Arc::new(LogMetrics::new()));
+    ///     .block_details(MyBlockProcessor);
     /// ```
-    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
-        log::trace!("metrics(self, metrics: {:?})", stringify!(metrics));
-        self.metrics.metrics.push(metrics);
+    pub fn block_details(
+        mut self,
+        processor: impl Processor<InputType = BlockDetails> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "block_details(self, processor: {:?})",
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::BlockDetails, None, short_type_name_of(&processor));
+        self.block_details_pipes.push(Box::new(BlockDetailsPipe {
+            processor: Box::new(processor),
+        }));
         self
     }
 
-    /// Sets the interval for flushing metrics data.
+    /// Adds a bundle pipe grouping transactions into Jito bundles.
     ///
-    /// This value defines the frequency, in seconds, at which metrics data is
-    /// flushed from memory. If not set, a default interval is used.
+    /// A successful transaction paying a Jito tip closes a bundle made of
+    /// itself and up to four transactions received right before it in the
+    /// same slot. Each bundle is passed to the processor as a `BundleUpdate`.
+    /// The datasource must provide transactions in block order.
     ///
     /// # Parameters
     ///
-    /// - `interval`: The flush interval for metrics, in seconds.
+    /// - `processor`: A `Processor` that processes bundles.
     ///
     /// # Example
     ///
     /// ```rust
     /// let builder = PipelineBuilder::new()
-    ///     .metrics_flush_interval(60);
+    ///     .bundles(MyBundleProcessor);
     /// ```
-    pub fn metrics_flush_interval(mut self, interval: u64) -> Self {
-        log::trace!("metrics_flush_interval(self, interval: {:?})", interval);
-        self.metrics_flush_interval = Some(interval);
+    pub fn bundles(
+        mut self,
+        processor: impl Processor<InputType = BundleUpdate> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!("bundles(self, processor: {:?})", stringify!(processor));
+        self.describe_pipe(PipeKind::Bundle, None, short_type_name_of(&processor));
+        self.bundle_pipes.push(Box::new(BundlePipe {
+            detector: BundleDetector::new(),
+            processor: Box::new(processor),
+        }));
         self
     }
 
-    /// Sets the cancellation token for cancelling datasource on demand.
+    /// Adds an epoch pipe following the epoch of the updates' slots.
     ///
-    /// This value is used to cancel datasource on demand.
-    /// If not set, a default `CancellationToken` is used.
+    /// When the slot of an update moves into a new epoch, an `EpochChange` is
+    /// passed to the processor before the update reaches the other pipes,
+    /// with the leader schedule of the epoch if the tracker fetches it.
     ///
     /// # Parameters
     ///
-    /// - `cancellation_token`: An instance of `CancellationToken`.
+    /// - `tracker`: An `EpochTracker` with the epoch schedule of the cluster.
+    /// - `processor`: A `Processor` that processes epoch changes.
     ///
     /// # Example
     ///
     /// ```rust
     /// let builder = PipelineBuilder::new()
-    ///     .datasource_cancellation_token(CancellationToken::new());
+    ///     .epochs(EpochTracker::mainnet(), MyEpochProcessor);
     /// ```
-    pub fn datasource_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+    pub fn epochs(
+        mut self,
+        tracker: EpochTracker,
+        processor: impl Processor<InputType = EpochChange> + Send + Sync + 'static,
+    ) -> Self {
         log::trace!(
-            "datasource_cancellation_token(self, cancellation_token: {:?})",
-            cancellation_token
+            "epochs(self, tracker: {:?}, processor: {:?})",
+            tracker,
+            stringify!(processor)
         );
-        self.datasource_cancellation_token = Some(cancellation_token);
+        self.describe_pipe(PipeKind::Epoch, None, short_type_name_of(&processor));
+        self.epoch_pipes
+            .push(Box::new(EpochPipe::new(tracker, Box::new(processor))));
         self
     }
 
-    /// Sets the size of the channel buffer for the pipeline.
+    /// Adds a derived pipe processing the updates emitted on a derived
+    /// channel by other pipes.
     ///
-    /// This value defines the maximum number of updates that can be queued in
-    /// the pipeline's channel buffer. If not set, a default size of 10_000
-    /// will be used.
+    /// The updates derived while processing an update are processed right
+    /// after it, before the next update.
     ///
     /// # Parameters
     ///
-    /// - `size`: The size of the channel buffer for the pipeline.
+    /// - `receiver`: The `DerivedReceiver` of a channel created with
+    ///   `derived_channel`.
+    /// - `processor`: A `Processor` that processes the derived updates.
     ///
     /// # Example
     ///
     /// ```rust
+    /// let (swaps, swaps_receiver) = derived_channel::<NormalizedSwap>();
+    ///
     /// let builder = PipelineBuilder::new()
-    ///     .channel_buffer_size(1000);
+    ///     .instruction(RaydiumAmmV4Decoder, RaydiumSwapNormalizer::new(swaps))
+    ///     .derived(swaps_receiver, CandleAggregator::new(Duration::from_secs(60)));
     /// ```
-    pub fn channel_buffer_size(mut self, size: usize) -> Self {
-        log::trace!("channel_buffer_size(self, size: {:?})", size);
-        self.channel_buffer_size = size;
+    pub fn derived<T: Send + Sync + 'static>(
+        mut self,
+        receiver: DerivedReceiver<T>,
+        processor: impl Processor<InputType = T> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "derived(self, receiver: {:?}, processor: {:?})",
+            stringify!(receiver),
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::Derived, None, short_type_name_of(&processor));
+        self.derived_pipes.push(Box::new(DerivedPipe {
+            receiver,
+            processor: Box::new(processor),
+        }));
         self
     }
 
-    /// Builds and returns a `Pipeline` configured with the specified
-    /// components.
+    /// Adds a bundle pipe flagging the sandwiches and atomic arbitrages of
+    /// each Jito bundle.
     ///
-    /// After configuring the `PipelineBuilder` with data sources, pipes, and
-    /// metrics, call this method to create the final `Pipeline` instance
-    /// ready for operation.
+    /// This is a shorthand for `bundles(MevDetector::new(processor))`.
     ///
-    /// # Returns
+    /// # Parameters
     ///
-    /// Returns a `CarbonResult<Pipeline>` containing the configured `Pipeline`,
-    /// or an error if any part of the configuration is invalid.
+    /// - `processor`: A `Processor` that processes `MevFinding`s.
     ///
     /// # Example
     ///
     /// ```rust
-    /// carbon_core::pipeline::Pipeline::builder()
-    /// .datasource(transaction_crawler)
-    /// .metrics(Arc::new(LogMetrics::new()))
-    /// .metrics(Arc::new(PrometheusMetrics::new()))
-    /// .instruction(
-    ///    TestProgramDecoder,
-    ///    TestProgramProcessor
-    /// )
-    /// .account(
-    ///     TestProgramDecoder,
-    ///     TestProgramAccountProcessor
-    /// )
-    /// .transaction(TEST_SCHEMA.clone(), TestProgramTransactionProcessor)
-    /// .account_deletions(TestProgramAccountDeletionProcessor)
-    /// .channel_buffer_size(1000)
-    /// .build()?
+    /// let builder = PipelineBuilder::new()
+    ///     .mev_findings(MyFindingsProcessor);
     /// ```
-    pub fn build(self) -> CarbonResult<Pipeline> {
-        log::trace!("build(self)");
-        Ok(Pipeline {
-            datasources: self.datasources,
-            account_pipes: self.account_pipes,
-            account_deletion_pipes: self.account_deletion_pipes,
-            instruction_pipes: self.instruction_pipes,
-            transaction_pipes: self.transaction_pipes,
-            shutdown_strategy: self.shutdown_strategy,
-            metrics: Arc::new(self.metrics),
-            metrics_flush_interval: self.metrics_flush_interval,
-            datasource_cancellation_token: self.datasource_cancellation_token,
-            channel_buffer_size: self.channel_buffer_size,
-        })
+    pub fn mev_findings(
+        self,
+        processor: impl Processor<InputType = MevFinding> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!("mev_findings(self, processor: {:?})", stringify!(processor));
+        self.bundles(MevDetector::new(processor))
+    }
+
+    /// Adds an instruction pipe to process instructions within transactions.
+    ///
+    /// Instruction pipes decode and process individual instructions,
+    /// enabling specialized handling of various instruction types.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    /// - `processor`: A `Processor` that processes decoded instruction data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(MyDecoder, MyInstructionProcessor);
+    /// ```
+    pub fn instruction<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "instruction(self, decoder: {:?}, processor: {:?})",
+            stringify!(decoder),
+            stringify!(processor)
+        );
+        self.describe_pipe(
+            PipeKind::Instruction,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
+        self.instruction_pipes.push(Box::new(InstructionPipe {
+            decoder: Box::new(decoder),
+            processor: Box::new(processor),
+            on_unknown: None,
+            max_data_len: None,
+        }));
+        self
+    }
+
+    /// Adds an instruction pipe printing every Nth instruction decoded by
+    /// `decoder` as pretty JSON to stdout.
+    ///
+    /// This is meant for validating a new decoder without writing a processor
+    /// first. The instructions are printed by a `DebugPrintProcessor`, sampled
+    /// with `Sampling::EveryNth`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` whose instruction type implements
+    ///   `Serialize`.
+    /// - `every_nth`: Prints one out of every `every_nth` decoded instructions,
+    ///   starting with the first one. `0` or `1` prints every instruction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource(MyDatasource::new())
+    ///     .debug_print(MyDecoder, 10);
+    /// ```
+    pub fn debug_print<T: Serialize + Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        every_nth: u64,
+    ) -> Self {
+        log::trace!(
+            "debug_print(self, decoder: {:?}, every_nth: {:?})",
+            stringify!(decoder),
+            every_nth
+        );
+        let processor =
+            SampledProcessor::new(DebugPrintProcessor::new(), Sampling::EveryNth(every_nth));
+        self.describe_pipe(
+            PipeKind::Instruction,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
+        self.instruction_pipes.push(Box::new(InstructionPipe {
+            decoder: Box::new(decoder),
+            processor: Box::new(processor),
+            on_unknown: None,
+            max_data_len: None,
+        }));
+        self
+    }
+
+    /// Adds an instruction pipe counting the decoded instructions per type.
+    ///
+    /// Each instruction decoded by `decoder` increments the
+    /// `instructions_decoded_<variant>` counter, such as
+    /// `instructions_decoded_buy`, without writing a processor.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction_stats(MyDecoder);
+    /// ```
+    pub fn instruction_stats<T: Serialize + Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "instruction_stats(self, decoder: {:?})",
+            stringify!(decoder)
+        );
+        self.instruction(decoder, InstructionStatsProcessor::new())
+    }
+
+    /// Adds an instruction pipe counting the decoded instructions per type,
+    /// and passing an `InstructionStats` summary of the counts to `processor`
+    /// at the end of each time bucket.
+    ///
+    /// The counts are also exported as metrics, as with
+    /// [`PipelineBuilder::instruction_stats`].
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    /// - `bucket`: The length of the time buckets, at least a second.
+    /// - `processor`: A `Processor` handling the summary of each bucket.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction_stats_summary(MyDecoder, Duration::from_secs(60), MyStatsProcessor);
+    /// ```
+    pub fn instruction_stats_summary<T: Serialize + Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        bucket: time::Duration,
+        processor: impl Processor<InputType = InstructionStats> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "instruction_stats_summary(self, decoder: {:?}, bucket: {:?})",
+            stringify!(decoder),
+            bucket
+        );
+        self.instruction(
+            decoder,
+            InstructionStatsProcessor::new().with_summary(bucket, processor),
+        )
+    }
+
+    /// Adds an instruction pipe that reports the instructions its decoder did
+    /// not recognize.
+    ///
+    /// This behaves like [`PipelineBuilder::instruction`], but instructions
+    /// of `program_id` that the decoder cannot decode, such as instructions
+    /// added by a program upgrade, are passed to `on_unknown` with their raw
+    /// data and accounts, and counted in the `instructions_unknown` metric.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    /// - `processor`: A `Processor` that processes decoded instruction data.
+    /// - `program_id`: The program handled by the decoder.
+    /// - `on_unknown`: Called for each instruction of `program_id` that the
+    ///   decoder did not recognize.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new().instruction_with_unknown_hook(
+    ///     MyDecoder,
+    ///     MyInstructionProcessor,
+    ///     MY_PROGRAM_ID,
+    ///     |metadata, instruction| {
+    ///         log::warn!(
+    ///             "unknown instruction in {}: {:?}",
+    ///             metadata.transaction_metadata.signature,
+    ///             instruction.data
+    ///         );
+    ///     },
+    /// );
+    /// ```
+    pub fn instruction_with_unknown_hook<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+        program_id: Pubkey,
+        on_unknown: impl Fn(&InstructionMetadata, &solana_instruction::Instruction)
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        log::trace!(
+            "instruction_with_unknown_hook(self, decoder: {:?}, processor: {:?}, program_id: {:?})",
+            stringify!(decoder),
+            stringify!(processor),
+            program_id
+        );
+        self.describe_pipe(
+            PipeKind::Instruction,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
+        self.instruction_pipes.push(Box::new(InstructionPipe {
+            decoder: Box::new(decoder),
+            processor: Box::new(processor),
+            on_unknown: Some(UnknownInstructionHook {
+                program_id,
+                hook: Arc::new(on_unknown),
+            }),
+            max_data_len: None,
+        }));
+        self
+    }
+
+    /// Adds an instruction pipe whose processor only receives a sample of the
+    /// decoded instructions.
+    ///
+    /// This behaves like [`PipelineBuilder::instruction`], but wraps the
+    /// processor in a `SampledProcessor`. Other pipes on the pipeline are not
+    /// affected and still see every instruction.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    /// - `processor`: A `Processor` that processes decoded instruction data.
+    /// - `sampling`: The [`Sampling`] applied before the processor runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction_sampled(MyDecoder, MyAnalyticsProcessor, Sampling::EveryNth(10));
+    /// ```
+    pub fn instruction_sampled<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+        sampling: Sampling,
+    ) -> Self {
+        log::trace!("instruction_sampled(self, sampling: {:?})", sampling);
+        self.instruction(decoder, SampledProcessor::new(processor, sampling))
+    }
+
+    /// Adds an event pipe processing the Anchor events decoded by `decoder`.
+    ///
+    /// Events are extracted from both the `Program data:` logs written by
+    /// `emit!` and the self-CPI instructions of `emit_cpi!`, and processed
+    /// once when found through both, so the processor does not depend on how
+    /// each version of the program emits them.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` decoding the self-CPI instructions
+    ///   of the program's events.
+    /// - `processor`: A `Processor` that processes the decoded events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .event(MyDecoder, MyEventProcessor);
+    /// ```
+    pub fn event<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = EventProcessorInputType<T>> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "event(self, decoder: {:?}, processor: {:?})",
+            stringify!(decoder),
+            stringify!(processor)
+        );
+        self.describe_pipe(
+            PipeKind::Transaction,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
+        self.transaction_pipes.push(Box::new(EventPipe {
+            decoder: Box::new(decoder),
+            processor: Box::new(processor),
+        }));
+        self
+    }
+
+    /// Adds an event pipe whose processor receives each event with both its
+    /// embedded timestamp and the block time of its transaction.
+    ///
+    /// This behaves like [`PipelineBuilder::event`], but wraps the processor
+    /// in a `TimestampNormalizer`, passing each event as a
+    /// `TimestampedEvent` whose `timestamps.timestamp` is resolved by
+    /// `policy`, so time series stay consistent whichever timestamp is
+    /// missing or skewed.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` decoding the self-CPI instructions
+    ///   of the program's events.
+    /// - `processor`: A `Processor` that processes the timestamped events.
+    /// - `extractor`: Returns the timestamp embedded in an event, in Unix
+    ///   seconds, if it has one.
+    /// - `policy`: The `TimestampPolicy` resolving the timestamp of each
+    ///   event.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new().event_timestamped(
+    ///     PumpfunDecoder,
+    ///     TradeSeriesSink,
+    ///     |event: &PumpfunInstruction| match event {
+    ///         PumpfunInstruction::TradeEvent(trade) => Some(trade.timestamp),
+    ///         _ => None,
+    ///     },
+    ///     TimestampPolicy::new(TimestampPreference::Event).max_skew(Duration::from_secs(30)),
+    /// );
+    /// ```
+    pub fn event_timestamped<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = TimestampedEvent<T>> + Send + Sync + 'static,
+        extractor: impl Fn(&T) -> Option<i64> + Send + Sync + 'static,
+        policy: TimestampPolicy,
+    ) -> Self {
+        log::trace!("event_timestamped(self, policy: {:?})", policy);
+        self.event(
+            decoder,
+            TimestampNormalizer::new(processor, extractor, policy),
+        )
+    }
+
+    /// Adds a pipe pairing the Anchor events decoded by `decoder` with the
+    /// instructions that emitted them, such as a `Buy` instruction with its
+    /// `BuyEvent`.
+    ///
+    /// Each event is paired with the instruction invoking its self-CPI, or
+    /// with the invocation executing when it was logged, and both are decoded
+    /// by `decoder`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` decoding both the instructions and
+    ///   the self-CPI instructions of the program's events.
+    /// - `processor`: A `Processor` that processes each `CorrelatedEvent`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .correlated_events(PumpfunDecoder, TradeProcessor);
+    /// ```
+    pub fn correlated_events<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = CorrelatedEvent<T>> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "correlated_events(self, decoder: {:?}, processor: {:?})",
+            stringify!(decoder),
+            stringify!(processor)
+        );
+        self.describe_pipe(
+            PipeKind::Transaction,
+            Some(short_type_name_of(&decoder)),
+            short_type_name_of(&processor),
+        );
+        self.transaction_pipes.push(Box::new(CorrelatedEventPipe {
+            decoder: Box::new(decoder),
+            processor: Box::new(processor),
+        }));
+        self
+    }
+
+    /// Records the decimals of the mints in the token balances of each
+    /// transaction into `cache`, for `UiAmounts` encoders to scale raw token
+    /// amounts with.
+    ///
+    /// # Parameters
+    ///
+    /// - `cache`: The `MintDecimalsCache` shared with the encoders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let decimals = MintDecimalsCache::new();
+    ///
+    /// let builder = PipelineBuilder::new()
+    ///     .mint_decimals(decimals.clone())
+    ///     .instruction(
+    ///         PumpfunDecoder,
+    ///         sink.instructions(UiAmounts::new(decimals).amount("amount", "mint").encoder()),
+    ///     );
+    /// ```
+    pub fn mint_decimals(mut self, cache: MintDecimalsCache) -> Self {
+        log::trace!("mint_decimals(self, cache: {:?})", cache);
+        self.describe_pipe(PipeKind::Transaction, None, "MintDecimalsPipe".to_string());
+        self.transaction_pipes
+            .push(Box::new(MintDecimalsPipe { cache }));
+        self
+    }
+
+    /// Adds a transaction pipe reporting the accounts write-locked by the most
+    /// transactions of each slot.
+    ///
+    /// When a slot ends, a `ContentionReport` of its `max_accounts` most
+    /// write-locked accounts is passed to the processor. The datasource must
+    /// deliver the transactions of a slot together.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_accounts`: The number of accounts in each report.
+    /// - `processor`: A `Processor` that processes contention reports.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .write_lock_contention(10, MyContentionProcessor);
+    /// ```
+    pub fn write_lock_contention(
+        mut self,
+        max_accounts: usize,
+        processor: impl Processor<InputType = ContentionReport> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "write_lock_contention(self, max_accounts: {:?}, processor: {:?})",
+            max_accounts,
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::Transaction, None, short_type_name_of(&processor));
+        self.transaction_pipes.push(Box::new(ContentionPipe {
+            tracker: ContentionTracker::new(max_accounts),
+            processor: Box::new(processor),
+        }));
+        self
+    }
+
+    /// Adds a transaction pipe for processing full transaction data.
+    ///
+    /// This method requires a transaction schema for decoding and a `Processor`
+    /// to handle the processed transaction data.
+    ///
+    /// # Parameters
+    ///
+    /// - `schema`: A `TransactionSchema` used to match and interpret
+    ///   transaction data.
+    /// - `processor`: A `Processor` that processes the decoded transaction
+    ///   data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction(MY_SCHEMA.clone(), MyTransactionProcessor);
+    /// ```
+    pub fn transaction<T, U>(
+        mut self,
+        processor: impl Processor<InputType = TransactionProcessorInputType<T, U>>
+            + Send
+            + Sync
+            + 'static,
+        schema: Option<TransactionSchema<T>>,
+    ) -> Self
+    where
+        T: InstructionDecoderCollection + 'static,
+        U: DeserializeOwned + Send + Sync + 'static,
+    {
+        log::trace!(
+            "transaction(self, schema: {:?}, processor: {:?})",
+            stringify!(schema),
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::Transaction, None, short_type_name_of(&processor));
+        self.transaction_pipes
+            .push(Box::new(TransactionPipe::<T, U>::new(schema, processor)));
+        self
+    }
+
+    /// Adds a transaction pipe whose processor also receives the transaction
+    /// serialized in its wire format.
+    ///
+    /// This behaves like [`PipelineBuilder::transaction`], and sets
+    /// `TransactionMetadata::raw_transaction` to the bincode serialization of
+    /// the signed transaction, for processors archiving raw data or verifying
+    /// the signatures again. The status meta is already in
+    /// `TransactionMetadata::meta`. The transaction is only serialized once,
+    /// for all the pipes asking for it.
+    ///
+    /// # Parameters
+    ///
+    /// - `processor`: A `Processor` that processes the decoded transaction
+    ///   data.
+    /// - `schema`: A `TransactionSchema` used to match and interpret
+    ///   transaction data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_with_raw(MyArchiveProcessor, None);
+    /// ```
+    pub fn transaction_with_raw<T, U>(
+        mut self,
+        processor: impl Processor<InputType = TransactionProcessorInputType<T, U>>
+            + Send
+            + Sync
+            + 'static,
+        schema: Option<TransactionSchema<T>>,
+    ) -> Self
+    where
+        T: InstructionDecoderCollection + 'static,
+        U: DeserializeOwned + Send + Sync + 'static,
+    {
+        log::trace!(
+            "transaction_with_raw(self, schema: {:?}, processor: {:?})",
+            stringify!(schema),
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::Transaction, None, short_type_name_of(&processor));
+        self.transaction_pipes.push(Box::new(
+            TransactionPipe::<T, U>::new(schema, processor).with_raw_transaction(),
+        ));
+        self
+    }
+
+    /// Adds a transaction pipe whose processor only receives a sample of the
+    /// matched transactions.
+    ///
+    /// This behaves like [`PipelineBuilder::transaction`], but wraps the
+    /// processor in a `SampledProcessor`. Other pipes on the pipeline are not
+    /// affected and still see every transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `processor`: A `Processor` that processes the decoded transaction
+    ///   data.
+    /// - `schema`: A `TransactionSchema` used to match and interpret
+    ///   transaction data.
+    /// - `sampling`: The [`Sampling`] applied before the processor runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_sampled(MyTransactionProcessor, None, Sampling::EveryNth(50));
+    /// ```
+    pub fn transaction_sampled<T, U>(
+        self,
+        processor: impl Processor<InputType = TransactionProcessorInputType<T, U>>
+            + Send
+            + Sync
+            + 'static,
+        schema: Option<TransactionSchema<T>>,
+        sampling: Sampling,
+    ) -> Self
+    where
+        T: InstructionDecoderCollection + 'static,
+        U: DeserializeOwned + Send + Sync + 'static,
+    {
+        log::trace!("transaction_sampled(self, sampling: {:?})", sampling);
+        self.transaction(SampledProcessor::new(processor, sampling), schema)
+    }
+
+    /// Adds a pipe matching a sequence of transactions sent by the same fee
+    /// payer within a time window.
+    ///
+    /// Each step of the `SequenceSchema` is a `TransactionSchema` matched
+    /// against a whole transaction. Once a fee payer sends transactions
+    /// matching every step, in order and within the window, the processor
+    /// receives a single `SequenceMatch` combining the matched instructions.
+    ///
+    /// # Parameters
+    ///
+    /// - `schema`: The `SequenceSchema` describing the steps and the window.
+    /// - `processor`: A `Processor` that processes the completed sequences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new().transaction_sequence(
+    ///     SequenceSchema {
+    ///         steps: vec![CREATE_SCHEMA.clone(), ADD_LIQUIDITY_SCHEMA.clone(), REMOVE_LIQUIDITY_SCHEMA.clone()],
+    ///         window: Duration::from_secs(600),
+    ///     },
+    ///     RugPullAlertProcessor,
+    /// );
+    /// ```
+    pub fn transaction_sequence<T, U>(
+        mut self,
+        schema: SequenceSchema<T>,
+        processor: impl Processor<InputType = SequenceMatch<U>> + Send + Sync + 'static,
+    ) -> Self
+    where
+        T: InstructionDecoderCollection + 'static,
+        U: DeserializeOwned + Send + Sync + 'static,
+    {
+        log::trace!(
+            "transaction_sequence(self, schema: {:?}, processor: {:?})",
+            stringify!(schema),
+            stringify!(processor)
+        );
+        self.describe_pipe(PipeKind::Transaction, None, short_type_name_of(&processor));
+        self.transaction_pipes
+            .push(Box::new(SequencePipe::<T, U>::new(schema, processor)));
+        self
+    }
+
+    /// Names the pipe added last, replacing its default name, such as
+    /// `instruction_0`.
+    ///
+    /// The names appear in the `PipelineDescription` returned by
+    /// `Pipeline::describe`, and must be unique within the pipeline.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the pipe.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(PumpfunDecoder, TradeSink)
+    ///     .named("pumpfun_trades");
+    /// ```
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        log::trace!("named(self, name: {:?})", name);
+
+        match self.pipe_descriptions.last_mut() {
+            Some(pipe) => pipe.name = name,
+            None => log::warn!("no pipe to name {:?}, add a pipe before naming it", name),
+        }
+        self
+    }
+
+    /// Serves the status of the pipeline over HTTP on `addr` while it runs,
+    /// with `/healthz` and `/readyz` probes and a JSON `/status` endpoint.
+    ///
+    /// The server is bound when the pipeline starts running, which fails if
+    /// the address is not available. Requires the `status-server` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `addr`: The address to listen on, such as `0.0.0.0:8080`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .status_server("0.0.0.0:8080".parse()?);
+    /// ```
+    #[cfg(feature = "status-server")]
+    pub fn status_server(mut self, addr: std::net::SocketAddr) -> Self {
+        log::trace!("status_server(self, addr: {:?})", addr);
+        self.status_server_addr = Some(addr);
+        self
+    }
+
+    /// Adds a metrics component to the pipeline for performance tracking.
+    ///
+    /// This component collects and reports on pipeline metrics, providing
+    /// insights into performance and operational statistics.
+    ///
+    /// # Parameters
+    ///
+    /// - `metrics`: An instance of a `Metrics` implementation, used to gather
+    ///   and report metrics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .metrics(Arc::new(LogMetrics::new()));
+    /// ```
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        log::trace!("metrics(self, metrics: {:?})", stringify!(metrics));
+        self.metrics.metrics.push(metrics);
+        self
+    }
+
+    /// Sets the interval for flushing metrics data.
+    ///
+    /// This value defines the frequency, in seconds, at which metrics data is
+    /// flushed from memory. If not set, a default interval is used.
+    ///
+    /// # Parameters
+    ///
+    /// - `interval`: The flush interval for metrics, in seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .metrics_flush_interval(60);
+    /// ```
+    pub fn metrics_flush_interval(mut self, interval: u64) -> Self {
+        log::trace!("metrics_flush_interval(self, interval: {:?})", interval);
+        self.metrics_flush_interval = Some(interval);
+        self
+    }
+
+    /// Configures the metrics namespace, the labels recorded and the flush
+    /// interval.
+    ///
+    /// Pubkey labels are dropped unless the config includes them. The flush
+    /// interval of the config, if set, replaces the one set with
+    /// `metrics_flush_interval`.
+    ///
+    /// # Parameters
+    ///
+    /// - `config`: The `MetricsConfig` of the pipeline's metrics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .metrics_config(MetricsConfig::new().namespace("indexer").flush_interval(10));
+    /// ```
+    pub fn metrics_config(mut self, config: MetricsConfig) -> Self {
+        log::trace!("metrics_config(self, config: {:?})", config);
+        if let Some(flush_interval) = config.flush_interval {
+            self.metrics_flush_interval = Some(flush_interval);
+        }
+        self.metrics.config = config;
+        self
+    }
+
+    /// Sets the `Clock` timing the metrics flushes and the datasource
+    /// restarts, such as a `ManualClock` in tests. The system time is used by
+    /// default.
+    ///
+    /// # Parameters
+    ///
+    /// - `clock`: The `Clock` to read the time from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let clock = ManualClock::new(1_700_000_000);
+    ///
+    /// let builder = PipelineBuilder::new()
+    ///     .clock(Arc::new(clock.clone()));
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    /// ```
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        log::trace!("clock(self, clock: {:?})", stringify!(clock));
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Sets the cancellation token for cancelling datasource on demand.
+    ///
+    /// This value is used to cancel datasource on demand.
+    /// If not set, a default `CancellationToken` is used.
+    ///
+    /// # Parameters
+    ///
+    /// - `cancellation_token`: An instance of `CancellationToken`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource_cancellation_token(CancellationToken::new());
+    /// ```
+    pub fn datasource_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        log::trace!(
+            "datasource_cancellation_token(self, cancellation_token: {:?})",
+            cancellation_token
+        );
+        self.datasource_cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Sets the size of the channel buffer for the pipeline.
+    ///
+    /// This value defines the maximum number of updates that can be queued in
+    /// the pipeline's channel buffer. If not set, a default size of 10_000
+    /// will be used.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The size of the channel buffer for the pipeline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .channel_buffer_size(1000);
+    /// ```
+    pub fn channel_buffer_size(mut self, size: usize) -> Self {
+        log::trace!("channel_buffer_size(self, size: {:?})", size);
+        self.channel_buffer_size = size;
+        self
+    }
+
+    /// Skips account updates whose data is identical to the previous update of
+    /// the same account.
+    ///
+    /// When enabled, the pipeline keeps a hash of each account's last state in
+    /// an `AccountDataCache`. Updates that rewrite an account without changing
+    /// it are dropped before decoding and processing, and counted in the
+    /// `account_updates_suppressed` metric. Disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `enabled`: Whether unchanged account updates should be skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .skip_unchanged_accounts(true);
+    /// ```
+    pub fn skip_unchanged_accounts(mut self, enabled: bool) -> Self {
+        log::trace!("skip_unchanged_accounts(self, enabled: {:?})", enabled);
+        self.account_data_cache = enabled.then(AccountDataCache::new);
+        self
+    }
+
+    /// Skips account updates older than the last update processed for the
+    /// same account.
+    ///
+    /// When enabled, the pipeline keeps the slot and write version of each
+    /// account's last update in an `AccountWatermark`. Updates at or below
+    /// it, such as those of a backfill running behind a live datasource, are
+    /// dropped before decoding and processing, and counted in the
+    /// `account_updates_stale` metric. Deleted accounts keep their watermark
+    /// at the slot of the deletion, so older updates do not bring them back,
+    /// and deletions older than the watermark are counted in the
+    /// `account_deletions_stale` metric and dropped. Disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `enabled`: Whether stale account updates should be skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .skip_stale_accounts(true);
+    /// ```
+    pub fn skip_stale_accounts(mut self, enabled: bool) -> Self {
+        log::trace!("skip_stale_accounts(self, enabled: {:?})", enabled);
+        self.account_watermark = enabled.then(AccountWatermark::new);
+        self
+    }
+
+    /// Monitors the lag of the pipeline against the chain tip.
+    ///
+    /// The `LagMonitor` periodically fetches the latest slot from its RPC
+    /// endpoint and exports the difference with the slot of the last processed
+    /// update as the `pipeline_lag_slots` gauge. An alert hook can be set on
+    /// the monitor to be notified when the lag exceeds a threshold.
+    ///
+    /// # Parameters
+    ///
+    /// - `lag_monitor`: The [`LagMonitor`] to run alongside the pipeline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .lag_monitor(
+    ///         LagMonitor::new(rpc_url).alert_threshold(150, |lag| {
+    ///             log::warn!("pipeline is {} slots behind", lag.lag_slots);
+    ///         }),
+    ///     );
+    /// ```
+    pub fn lag_monitor(mut self, lag_monitor: LagMonitor) -> Self {
+        log::trace!("lag_monitor(self, rpc_url: {:?})", lag_monitor.rpc_url);
+        self.lag_monitor = Some(lag_monitor);
+        self
+    }
+
+    /// Warns about programs that have a decoder in this repository but are
+    /// not decoded by any instruction pipe.
+    ///
+    /// Each program invoked by the processed transactions is checked once
+    /// against the `registry`. When none of the instruction pipes decodes its
+    /// instructions, a warning names the decoder crate to add.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(PumpfunDecoder, PumpfunProcessor)
+    ///     .suggest_missing_decoders();
+    /// ```
+    pub fn suggest_missing_decoders(mut self) -> Self {
+        log::trace!("suggest_missing_decoders(self)");
+        self.suggest_missing_decoders = true;
+        self
+    }
+
+    /// Parses the Solana Actions identifiers of each transaction from its
+    /// memo instructions, such as the identifiers of transactions built
+    /// through a Blink.
+    ///
+    /// Processors read them through `InstructionMetadata::actions`, and
+    /// transactions with at least one are counted in the
+    /// `action_transactions_processed` counter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(JupiterSwapDecoder, SwapAttributionProcessor)
+    ///     .detect_actions();
+    /// ```
+    pub fn detect_actions(mut self) -> Self {
+        log::trace!("detect_actions(self)");
+        self.detect_actions = true;
+        self
+    }
+
+    /// Enables strict decoding, rejecting the decodes that leave bytes of the
+    /// data unread, even for types declaring a partial layout.
+    ///
+    /// Strict decoding only applies to the decodes of the pipes of this
+    /// pipeline, other pipelines of the process keep their own mode. See
+    /// `carbon_core::deserialize::with_strict_decoding`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(PumpfunDecoder, PumpfunProcessor)
+    ///     .strict_decoding();
+    /// ```
+    pub fn strict_decoding(mut self) -> Self {
+        log::trace!("strict_decoding(self)");
+        self.strict_decoding = true;
+        self
+    }
+
+    /// Sets how datasource tasks that panic are restarted.
+    ///
+    /// By default, a panicking datasource task is restarted up to 10 times,
+    /// waiting 1 second before the first restart and doubling the delay after
+    /// each restart, up to 60 seconds.
+    ///
+    /// # Parameters
+    ///
+    /// - `restart_policy`: The `RestartPolicy` of the datasource tasks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .restart_policy(
+    ///         RestartPolicy::default()
+    ///             .max_restarts(3)
+    ///             .backoff(Duration::from_millis(500), Duration::from_secs(10)),
+    ///     );
+    /// ```
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        log::trace!("restart_policy(self, restart_policy: {:?})", restart_policy);
+        self.restart_policy = restart_policy;
+        self
+    }
+
+    /// Emits the lifecycle events of the pipeline on `event_bus`, shared with
+    /// the circuit breakers of its pipes and the subscribers created before
+    /// the pipeline is built.
+    ///
+    /// Without it, the pipeline emits on its own bus, returned by
+    /// `Pipeline::events` and `PipelineHandle::events`.
+    ///
+    /// # Parameters
+    ///
+    /// - `event_bus`: The `EventBus` to emit the events on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let events = EventBus::default();
+    /// let mut receiver = events.subscribe();
+    ///
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource(MyDatasource::new())
+    ///     .event_bus(events);
+    /// ```
+    pub fn event_bus(mut self, event_bus: EventBus) -> Self {
+        log::trace!("event_bus(self, event_bus: {:?})", event_bus);
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Applies the defaults of a `PipelinePreset`: the channel buffer size,
+    /// the metrics and processors flush interval, the account filters and the
+    /// restart policy.
+    ///
+    /// Settings made before the preset are overwritten, and settings made
+    /// after it override the preset. Datasources are configured separately,
+    /// so pass them the commitment recommended by `PipelinePreset::commitment`.
+    ///
+    /// # Parameters
+    ///
+    /// - `preset`: The `PipelinePreset` to apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .preset(PipelinePreset::from_env().unwrap_or(PipelinePreset::LowLatency))
+    ///     .channel_buffer_size(500);
+    /// ```
+    pub fn preset(self, preset: PipelinePreset) -> Self {
+        log::trace!("preset(self, preset: {:?})", preset);
+        preset.apply(self)
+    }
+
+    /// Registers the named errors of a program, used to describe why
+    /// transactions failed.
+    ///
+    /// When a transaction fails with a custom error code returned by
+    /// `program_id`, the resolver maps the code to the program's error, set as
+    /// the `program_error` of the transaction's `failure_reason`.
+    ///
+    /// # Parameters
+    ///
+    /// - `program_id`: The program returning the errors.
+    /// - `resolver`: A function mapping a custom error code to the program's
+    ///   error, typically backed by the error enum of its decoder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .program_errors(PUMPFUN_PROGRAM_ID, |code| {
+    ///         PumpfunError::from_code(code).map(Into::into)
+    ///     });
+    /// ```
+    pub fn program_errors(
+        mut self,
+        program_id: Pubkey,
+        resolver: impl Fn(u32) -> Option<ProgramErrorDetails> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!("program_errors(self, program_id: {:?})", program_id);
+        self.program_error_resolvers
+            .insert(program_id, Arc::new(resolver));
+        self
+    }
+
+    /// Processes only the updates of the slots from `start` to `end`,
+    /// inclusive, then stops the pipeline.
+    ///
+    /// Updates outside of the range are skipped. Once the end slot has been
+    /// fully processed, the datasources are cancelled, the processors are
+    /// flushed and torn down, and `run` returns. This makes backfills bounded
+    /// jobs that can be scheduled and repeated.
+    ///
+    /// The range is fully processed once every bounded datasource finished,
+    /// as a block crawler given the same end slot does, or once a
+    /// `SlotRangeWatermark` following the `BlockDetails` of the datasources
+    /// has received every block of the range. Updates may arrive out of
+    /// order, such as from a block crawler fetching several blocks at once.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: The first slot to process.
+    /// - `end`: The last slot to process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource(RpcBlockCrawler::new(rpc_url, 320_000_000, None, ...))
+    ///     .slot_range(320_000_000, 320_100_000);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - With datasources that neither finish nor provide `BlockDetails`, the
+    ///   pipeline keeps running past the range, skipping its updates.
+    /// - The pipeline also stops if every bounded datasource finishes before
+    ///   the end slot.
+    pub fn slot_range(mut self, start: u64, end: u64) -> Self {
+        log::trace!("slot_range(self, start: {:?}, end: {:?})", start, end);
+        self.slot_range = Some(start..=end);
+        self
+    }
+
+    /// Skips transactions exceeding `limits`, such as transactions with more
+    /// instructions or accounts than any legitimate transaction of the indexed
+    /// programs, so they cannot stall decoding.
+    ///
+    /// Skipped transactions are counted in the `transactions_skipped`
+    /// counter, and passed to the processor set with `skipped_transactions`,
+    /// if any. Instructions with more data than `max_instruction_data_len`
+    /// are not decoded, without skipping their transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `limits`: The `TransactionLimits` above which transactions are
+    ///   skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_limits(TransactionLimits::new().max_instructions(1_000).max_accounts(512));
+    /// ```
+    pub fn transaction_limits(mut self, limits: TransactionLimits) -> Self {
+        log::trace!("transaction_limits(self, limits: {:?})", limits);
+        self.transaction_limits = Some(limits);
+        self
+    }
+
+    /// Sets the processor receiving the transactions skipped by the
+    /// `transaction_limits`, for example to store them for offline
+    /// inspection.
+    ///
+    /// # Parameters
+    ///
+    /// - `processor`: A `Processor` of `SkippedTransaction`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_limits(TransactionLimits::new().max_instructions(1_000))
+    ///     .skipped_transactions(SkippedTransactionsSink::new());
+    /// ```
+    pub fn skipped_transactions(
+        mut self,
+        processor: impl Processor<InputType = SkippedTransaction> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "skipped_transactions(self, processor: {:?})",
+            stringify!(processor)
+        );
+        self.skipped_transaction_processor = Some(Box::new(processor));
+        self
+    }
+
+    /// Verifies the signatures of each transaction before it is decoded, for
+    /// pipelines consuming less trusted datasources, such as third-party
+    /// streams or files.
+    ///
+    /// Transactions that are malformed, whose signature differs from their
+    /// first signature, or with a signature not verifying against their
+    /// message, are counted by the `transactions_unverified` counter, and
+    /// either passed to the pipes with the failure in
+    /// `TransactionMetadata::verification_failure`, or dropped, depending on
+    /// `mode`.
+    ///
+    /// # Parameters
+    ///
+    /// - `mode`: The `VerificationMode` of the transactions failing
+    ///   verification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .verify_transactions(VerificationMode::Drop);
+    /// ```
+    pub fn verify_transactions(mut self, mode: VerificationMode) -> Self {
+        log::trace!("verify_transactions(self, mode: {:?})", mode);
+        self.transaction_verification = Some(mode);
+        self
+    }
+
+    /// Sets the `ClusterSchedule` of the indexed cluster, so the metadata of
+    /// each transaction and instruction has the `SlotClock` of its slot: its
+    /// epoch, its index within the epoch and its estimated time.
+    ///
+    /// # Parameters
+    ///
+    /// - `cluster_schedule`: The epoch schedule, genesis time and slot
+    ///   duration of the cluster.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .cluster_schedule(ClusterSchedule::mainnet().anchor(320_000_000, 1_738_000_000));
+    /// ```
+    pub fn cluster_schedule(mut self, cluster_schedule: ClusterSchedule) -> Self {
+        log::trace!(
+            "cluster_schedule(self, cluster_schedule: {:?})",
+            cluster_schedule
+        );
+        self.cluster_schedule = Some(cluster_schedule);
+        self
+    }
+
+    /// Validates the configured components without building the pipeline.
+    ///
+    /// The validation fails if:
+    /// - No datasource has been added, or the channel buffer size is zero.
+    /// - A pipe needs a `Capability` that none of the datasources provide, for
+    ///   example account pipes without a datasource streaming account updates.
+    /// - A datasource rejects its own configuration, such as invalid filters,
+    ///   through [`Datasource::validate`].
+    /// - The `slot_range` starts after its end, or no datasource provides
+    ///   `HistoricalRange` or `BlockDetails` to complete it.
+    /// - Several pipes share the same name.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the configuration is consistent, or the first error
+    /// found otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .datasource(MyDatasource::new())
+    ///     .account(MyAccountDecoder, MyAccountProcessor);
+    ///
+    /// builder.validate()?;
+    /// let pipeline = builder.build()?;
+    /// ```
+    pub fn validate(&self) -> CarbonResult<()> {
+        log::trace!("validate(self)");
+
+        if let Some(slot_range) = &self.slot_range {
+            if slot_range.is_empty() {
+                return Err(Error::Custom(format!(
+                    "Slot range starts at {}, after its end at {}",
+                    slot_range.start(),
+                    slot_range.end()
+                )));
+            }
+        }
+
+        let mut names = HashSet::new();
+        if let Some(pipe) = self
+            .pipe_descriptions
+            .iter()
+            .find(|pipe| !names.insert(pipe.name.as_str()))
+        {
+            return Err(Error::Custom(format!(
+                "Pipe name {} is used by several pipes",
+                pipe.name
+            )));
+        }
+
+        validate_components(
+            &self.datasources,
+            &self.datasource_names,
+            &describe_pipes(&self.pipe_descriptions, self.pipe_counts()),
+            self.slot_range.as_ref(),
+            self.channel_buffer_size,
+        )
+    }
+
+    /// Builds and returns a `Pipeline` configured with the specified
+    /// components.
+    ///
+    /// After configuring the `PipelineBuilder` with data sources, pipes, and
+    /// metrics, call this method to create the final `Pipeline` instance
+    /// ready for operation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CarbonResult<Pipeline>` containing the configured `Pipeline`,
+    /// or an error if any part of the configuration is invalid, as checked by
+    /// `validate`. A pipe needing updates that none of the datasources provide
+    /// is reported as `Error::IncompatiblePipe`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// carbon_core::pipeline::Pipeline::builder()
+    /// .datasource(transaction_crawler)
+    /// .metrics(Arc::new(LogMetrics::new()))
+    /// .metrics(Arc::new(PrometheusMetrics::new()))
+    /// .instruction(
+    ///    TestProgramDecoder,
+    ///    TestProgramProcessor
+    /// )
+    /// .account(
+    ///     TestProgramDecoder,
+    ///     TestProgramAccountProcessor
+    /// )
+    /// .transaction(TEST_SCHEMA.clone(), TestProgramTransactionProcessor)
+    /// .account_deletions(TestProgramAccountDeletionProcessor)
+    /// .channel_buffer_size(1000)
+    /// .build()?
+    /// ```
+    pub fn build(mut self) -> CarbonResult<Pipeline> {
+        log::trace!("build(self)");
+        self.validate()?;
+
+        let max_instruction_data_len = self
+            .transaction_limits
+            .and_then(|limits| limits.max_instruction_data_len);
+        for pipe in self.instruction_pipes.iter_mut() {
+            pipe.set_max_data_len(max_instruction_data_len);
+        }
+
+        let datasource_health: DatasourceHealth = Arc::new(RwLock::new(vec![
+            TaskHealth::Pending;
+            self.datasources.len()
+        ]));
+        let pause_state: PauseState = Arc::new(watch::channel(false).0);
+        let clock = self.clock.unwrap_or_else(system_clock);
+        let status = PipelineStatus::new(
+            datasource_health.clone(),
+            pause_state.clone(),
+            clock.clone(),
+        );
+
+        Ok(Pipeline {
+            datasources: self.datasources,
+            account_pipes: self.account_pipes,
+            account_deletion_pipes: self.account_deletion_pipes,
+            account_lifecycle_pipes: self.account_lifecycle_pipes,
+            instruction_pipes: self.instruction_pipes,
+            transaction_pipes: self.transaction_pipes,
+            transaction_status_pipes: self.transaction_status_pipes,
+            block_details_pipes: self.block_details_pipes,
+            bundle_pipes: self.bundle_pipes,
+            epoch_pipes: self.epoch_pipes,
+            derived_pipes: self.derived_pipes,
+            shutdown_strategy: self.shutdown_strategy,
+            metrics: Arc::new(self.metrics),
+            metrics_flush_interval: self.metrics_flush_interval,
+            datasource_cancellation_token: self.datasource_cancellation_token,
+            channel_buffer_size: self.channel_buffer_size,
+            account_data_cache: self.account_data_cache,
+            account_watermark: self.account_watermark,
+            lag_monitor: self.lag_monitor,
+            program_error_resolvers: self.program_error_resolvers,
+            restart_policy: self.restart_policy,
+            datasource_health,
+            checked_programs: self.suggest_missing_decoders.then(HashSet::new),
+            pause_state,
+            slot_range: self.slot_range,
+            transaction_limits: self.transaction_limits,
+            skipped_transaction_processor: self.skipped_transaction_processor,
+            transaction_verification: self.transaction_verification,
+            cluster_schedule: self.cluster_schedule,
+            detect_actions: self.detect_actions,
+            strict_decoding: self.strict_decoding,
+            clock,
+            datasource_names: self.datasource_names,
+            pipe_descriptions: self.pipe_descriptions,
+            status,
+            events: self.event_bus.unwrap_or_default(),
+            #[cfg(feature = "status-server")]
+            status_server_addr: self.status_server_addr,
+        })
+    }
+
+    /// Records a pipe added to the builder, named after its kind and its
+    /// position among the pipes of that kind.
+    fn describe_pipe(&mut self, kind: PipeKind, decoder: Option<String>, processor: String) {
+        let index = self
+            .pipe_descriptions
+            .iter()
+            .filter(|pipe| pipe.kind == kind)
+            .count();
+
+        self.pipe_descriptions.push(PipeDescription {
+            name: default_pipe_name(kind, index),
+            kind,
+            decoder,
+            processor: Some(processor),
+        });
+    }
+
+    /// Returns the number of pipes of each kind.
+    fn pipe_counts(&self) -> [(PipeKind, usize); 10] {
+        [
+            (PipeKind::Account, self.account_pipes.len()),
+            (PipeKind::AccountDeletion, self.account_deletion_pipes.len()),
+            (
+                PipeKind::AccountLifecycle,
+                self.account_lifecycle_pipes.len(),
+            ),
+            (PipeKind::Instruction, self.instruction_pipes.len()),
+            (PipeKind::Transaction, self.transaction_pipes.len()),
+            (
+                PipeKind::TransactionStatus,
+                self.transaction_status_pipes.len(),
+            ),
+            (PipeKind::BlockDetails, self.block_details_pipes.len()),
+            (PipeKind::Bundle, self.bundle_pipes.len()),
+            (PipeKind::Epoch, self.epoch_pipes.len()),
+            (PipeKind::Derived, self.derived_pipes.len()),
+        ]
+    }
+}
+
+/// Checks the datasources and the channel, and that every pipe receives the
+/// updates it processes from at least one datasource.
+///
+/// A `slot_range` also needs a datasource fetching past slots, or one
+/// providing `BlockDetails` to tell when the range is complete.
+fn validate_components(
+    datasources: &[Arc<dyn Datasource + Send + Sync>],
+    datasource_names: &[String],
+    pipes: &[PipeDescription],
+    slot_range: Option<&RangeInclusive<u64>>,
+    channel_buffer_size: usize,
+) -> CarbonResult<()> {
+    if datasources.is_empty() {
+        return Err(Error::Custom("No datasource configured".to_string()));
+    }
+
+    if channel_buffer_size == 0 {
+        return Err(Error::Custom(
+            "Channel buffer size must be greater than zero".to_string(),
+        ));
+    }
+
+    let provided: Vec<(String, Capabilities)> = datasources
+        .iter()
+        .enumerate()
+        .map(|(index, datasource)| {
+            (
+                datasource_name(datasource_names, index),
+                datasource.capabilities(),
+            )
+        })
+        .collect();
+    let capabilities = provided
+        .iter()
+        .fold(Capabilities::new(), |capabilities, (_, provided)| {
+            capabilities.union(*provided)
+        });
+
+    for pipe in pipes {
+        if let Some(capability) = pipe.kind.capability() {
+            if !capabilities.contains(capability) {
+                return Err(Error::IncompatiblePipe {
+                    pipe: pipe.name.clone(),
+                    capability,
+                    datasources: provided,
+                });
+            }
+        }
+    }
+
+    if let Some(slot_range) = slot_range {
+        if !capabilities.contains(Capability::HistoricalRange)
+            && !capabilities.contains(Capability::BlockDetails)
+        {
+            return Err(Error::Custom(format!(
+                "Slot range {}..={} needs a datasource providing HistoricalRange or BlockDetails, \
+                 as none of the datasources could tell when the range is complete",
+                slot_range.start(),
+                slot_range.end()
+            )));
+        }
+    }
+
+    for datasource in datasources {
+        datasource.validate()?;
+    }
+
+    Ok(())
+}
+
+/// Returns the name of the datasource at `index`, defaulting to its position.
+fn datasource_name(datasource_names: &[String], index: usize) -> String {
+    datasource_names
+        .get(index)
+        .cloned()
+        .unwrap_or_else(|| format!("datasource_{}", index))
+}
+
+/// Describes every pipe, naming the pipes added without a description after
+/// their kind and their position among the pipes of that kind.
+fn describe_pipes(
+    pipe_descriptions: &[PipeDescription],
+    pipe_counts: [(PipeKind, usize); 10],
+) -> Vec<PipeDescription> {
+    let mut pipes = pipe_descriptions.to_vec();
+    for (kind, count) in pipe_counts {
+        let described = pipes.iter().filter(|pipe| pipe.kind == kind).count();
+        pipes.extend((described..count).map(|index| PipeDescription::new(kind, index)));
+    }
+
+    pipes
+}
+
+/// Warns once per program about the programs of the registry whose
+/// instructions no instruction pipe decodes.
+fn suggest_missing_decoders(
+    checked_programs: &mut HashSet<Pubkey>,
+    instruction_pipes: &[Box<dyn for<'a> InstructionPipes<'a>>],
+    nested_instructions: &[NestedInstruction],
+) {
+    for nested_instruction in nested_instructions {
+        let program_id = nested_instruction.instruction.program_id;
+
+        if checked_programs.insert(program_id) {
+            if let Some(decoder) = registry::find(&program_id) {
+                let instruction = NestedInstruction {
+                    metadata: nested_instruction.metadata.clone(),
+                    instruction: nested_instruction.instruction.clone(),
+                    inner_instructions: NestedInstructions::default(),
+                };
+
+                if !instruction_pipes
+                    .iter()
+                    .any(|pipe| pipe.decode_only(&instruction) > 0)
+                {
+                    log::warn!(
+                        "no instruction pipe decodes {} ({}), add `{}` to decode it",
+                        decoder.program_name,
+                        program_id,
+                        decoder.crate_name
+                    );
+                }
+            }
+        }
+
+        suggest_missing_decoders(
+            checked_programs,
+            instruction_pipes,
+            &nested_instruction.inner_instructions,
+        );
     }
 }
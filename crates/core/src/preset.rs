@@ -0,0 +1,165 @@
+//! Presets configuring a pipeline for common use cases.
+//!
+//! Each `PipelinePreset` sets the channel buffer, the flush interval, the
+//! account filters and the restart policy of a `PipelineBuilder` to values
+//! suited to its use case, and recommends a commitment for the datasources:
+//!
+//! - `LowLatency`: Small channel buffer and a one second flush interval, so
+//!   updates spend little time queued and batching processors write often.
+//!   Stale account updates are skipped, and datasources restart quickly.
+//!   Datasources should use the `processed` commitment.
+//! - `BulkBackfill`: Large channel buffer and a 30 seconds flush interval, so
+//!   batching processors write large batches. Unchanged account updates are
+//!   skipped, and datasources are restarted more patiently. Datasources should
+//!   use the `finalized` commitment.
+//!
+//! Presets only set defaults: any builder method called after the preset
+//! overrides it.
+//!
+//! The preset can also be picked per environment, from the
+//! `CARBON_PIPELINE_PRESET` environment variable.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let preset = PipelinePreset::from_env().unwrap_or(PipelinePreset::LowLatency);
+//!
+//! Pipeline::builder()
+//!     .preset(preset)
+//!     .datasource(RpcBlockSubscribe::new(rpc_ws_url, filters.commitment(preset.commitment())))
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .channel_buffer_size(500)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        pipeline::PipelineBuilder,
+        supervision::RestartPolicy,
+    },
+    solana_sdk::commitment_config::CommitmentConfig,
+    std::{str::FromStr, time::Duration},
+};
+
+/// The environment variable read by `PipelinePreset::from_env`.
+pub const PIPELINE_PRESET_ENV: &str = "CARBON_PIPELINE_PRESET";
+
+/// A set of defaults for a `PipelineBuilder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelinePreset {
+    /// Processes live updates as soon as possible.
+    LowLatency,
+    /// Processes a large range of historical updates with high throughput.
+    BulkBackfill,
+}
+
+impl PipelinePreset {
+    /// Returns the preset named in the `CARBON_PIPELINE_PRESET` environment
+    /// variable, such as `low_latency`, if it is set and valid.
+    pub fn from_env() -> Option<Self> {
+        let name = std::env::var(PIPELINE_PRESET_ENV).ok()?;
+
+        match name.parse() {
+            Ok(preset) => Some(preset),
+            Err(error) => {
+                log::warn!("ignoring {}: {}", PIPELINE_PRESET_ENV, error);
+                None
+            }
+        }
+    }
+
+    /// The commitment the datasources of the pipeline should use.
+    pub fn commitment(self) -> CommitmentConfig {
+        match self {
+            PipelinePreset::LowLatency => CommitmentConfig::processed(),
+            PipelinePreset::BulkBackfill => CommitmentConfig::finalized(),
+        }
+    }
+
+    pub fn channel_buffer_size(self) -> usize {
+        match self {
+            PipelinePreset::LowLatency => 256,
+            PipelinePreset::BulkBackfill => 100_000,
+        }
+    }
+
+    /// The interval, in seconds, at which metrics and processors are flushed.
+    pub fn flush_interval(self) -> u64 {
+        match self {
+            PipelinePreset::LowLatency => 1,
+            PipelinePreset::BulkBackfill => 30,
+        }
+    }
+
+    pub fn restart_policy(self) -> RestartPolicy {
+        match self {
+            PipelinePreset::LowLatency => {
+                RestartPolicy::default().backoff(Duration::from_millis(100), Duration::from_secs(5))
+            }
+            PipelinePreset::BulkBackfill => RestartPolicy::default()
+                .max_restarts(50)
+                .backoff(Duration::from_secs(5), Duration::from_secs(300)),
+        }
+    }
+
+    /// Sets the defaults of the preset on `builder`.
+    pub fn apply(self, builder: PipelineBuilder) -> PipelineBuilder {
+        let builder = builder
+            .channel_buffer_size(self.channel_buffer_size())
+            .metrics_flush_interval(self.flush_interval())
+            .restart_policy(self.restart_policy());
+
+        match self {
+            PipelinePreset::LowLatency => builder.skip_stale_accounts(true),
+            PipelinePreset::BulkBackfill => builder.skip_unchanged_accounts(true),
+        }
+    }
+}
+
+impl FromStr for PipelinePreset {
+    type Err = Error;
+
+    fn from_str(name: &str) -> CarbonResult<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "low_latency" => Ok(PipelinePreset::LowLatency),
+            "bulk_backfill" => Ok(PipelinePreset::BulkBackfill),
+            _ => Err(Error::Custom(format!(
+                "Unknown pipeline preset {:?}, expected low_latency or bulk_backfill",
+                name
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::pipeline::Pipeline};
+
+    #[test]
+    fn test_preset_is_overridable() {
+        // Act
+        let builder = Pipeline::bulk_backfill().channel_buffer_size(10);
+
+        // Assert
+        assert_eq!(builder.channel_buffer_size, 10);
+        assert_eq!(builder.metrics_flush_interval, Some(30));
+        assert!(builder.account_data_cache.is_some());
+        assert!(builder.account_watermark.is_none());
+    }
+
+    #[test]
+    fn test_parse_preset() {
+        assert_eq!(
+            "low-latency".parse::<PipelinePreset>().ok(),
+            Some(PipelinePreset::LowLatency)
+        );
+        assert_eq!(
+            "BULK_BACKFILL".parse::<PipelinePreset>().ok(),
+            Some(PipelinePreset::BulkBackfill)
+        );
+        assert!("fast".parse::<PipelinePreset>().is_err());
+    }
+}
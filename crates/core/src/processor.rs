@@ -30,6 +30,14 @@
 //!   asynchronous and should be implemented to define how data should be
 //!   processed in your specific use case.
 //!
+//! ### Provided Methods
+//!
+//! - `setup`: Called once when the pipeline starts, before any update is
+//!   processed, to open connections or allocate buffers.
+//! - `flush`: Called on every metrics flush interval and at shutdown, to write
+//!   out buffered data.
+//! - `teardown`: Called once when the pipeline stops, after the final `flush`.
+//!
 //! ## Parameters
 //!
 //! - `data`: An instance of the type specified by `InputType`. This represents
@@ -52,9 +60,16 @@
 //!   enable monitoring and alerting on key performance indicators.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection},
+    crate::{
+        clock::{system_clock, Clock},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+    },
     async_trait::async_trait,
-    std::sync::Arc,
+    std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    },
 };
 
 /// A trait for defining asynchronous data processing within the pipeline.
@@ -76,6 +91,12 @@ use {
 /// - `process`: Processes the specified `InputType` data asynchronously,
 ///   optionally updating associated metrics.
 ///
+/// # Provided Methods
+///
+/// - `setup`, `flush` and `teardown`: Lifecycle hooks called by the pipeline
+///   when it starts, on every metrics flush interval and at shutdown, and when
+///   it stops. They do nothing by default.
+///
 /// # Example
 ///
 /// ```rust
@@ -110,4 +131,206 @@ pub trait Processor {
         data: Self::InputType,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Called once when the pipeline starts, before any update is processed.
+    /// An error stops the pipeline.
+    async fn setup(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Called on every metrics flush interval and when the pipeline stops, to
+    /// write out buffered data.
+    async fn flush(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Called once when the pipeline stops, after the final `flush`.
+    async fn teardown(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+/// The stages of the lifecycle of a processor, run by the pipeline on each of
+/// its pipes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lifecycle {
+    Setup,
+    Flush,
+    Teardown,
+}
+
+/// Runs the lifecycle hook of `processor` matching `lifecycle`.
+pub async fn run_lifecycle<P>(
+    processor: &mut P,
+    lifecycle: Lifecycle,
+    metrics: Arc<MetricsCollection>,
+) -> CarbonResult<()>
+where
+    P: Processor + Send + ?Sized,
+{
+    match lifecycle {
+        Lifecycle::Setup => processor.setup(metrics).await,
+        Lifecycle::Flush => processor.flush(metrics).await,
+        Lifecycle::Teardown => processor.teardown(metrics).await,
+    }
+}
+
+/// Determines which updates a sampled pipe forwards to its processor.
+///
+/// # Variants
+///
+/// - `EveryNth(n)`: Processes only one out of every `n` updates, starting with
+///   the first one. A value of `0` or `1` processes every update.
+/// - `MaxPerSecond(limit)`: Processes at most `limit` updates per one-second
+///   window and drops the rest until the next window starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sampling {
+    EveryNth(u64),
+    MaxPerSecond(u32),
+}
+
+/// A `Processor` wrapper that forwards only a sample of its input to the inner
+/// processor.
+///
+/// `SampledProcessor` is meant for expensive pipes, such as analytics, that do
+/// not need to see every update. Sampling is applied per pipe, so other pipes
+/// on the same pipeline still receive every update. Skipped updates are
+/// counted in the `updates_sampled_out` metric.
+///
+/// # Example
+///
+/// ```rust
+/// let processor = SampledProcessor::new(MyAnalyticsProcessor, Sampling::EveryNth(100));
+/// ```
+pub struct SampledProcessor<P> {
+    processor: P,
+    sampling: Sampling,
+    seen: u64,
+    clock: Arc<dyn Clock>,
+    window_start: Instant,
+    window_count: u32,
+}
+
+impl<P> SampledProcessor<P> {
+    pub fn new(processor: P, sampling: Sampling) -> Self {
+        Self {
+            processor,
+            sampling,
+            seen: 0,
+            clock: system_clock(),
+            window_start: Instant::now(),
+            window_count: 0,
+        }
+    }
+
+    /// Measures the `MaxPerSecond` windows with `clock` rather than the
+    /// system time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.window_start = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    fn should_process(&mut self) -> bool {
+        match self.sampling {
+            Sampling::EveryNth(n) => {
+                let process = n <= 1 || self.seen % n == 0;
+                self.seen = self.seen.wrapping_add(1);
+                process
+            }
+            Sampling::MaxPerSecond(limit) => {
+                let now = self.clock.now();
+                if now.saturating_duration_since(self.window_start) >= Duration::from_secs(1) {
+                    self.window_start = now;
+                    self.window_count = 0;
+                }
+
+                if self.window_count < limit {
+                    self.window_count += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<P> Processor for SampledProcessor<P>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if !self.should_process() {
+            metrics.increment_counter("updates_sampled_out", 1).await?;
+            return Ok(());
+        }
+
+        self.processor.process(data, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::clock::ManualClock};
+
+    struct NoopProcessor;
+
+    #[async_trait]
+    impl Processor for NoopProcessor {
+        type InputType = ();
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_every_nth_sampling() {
+        let mut processor = SampledProcessor::new(NoopProcessor, Sampling::EveryNth(3));
+
+        let processed: Vec<bool> = (0..7).map(|_| processor.should_process()).collect();
+
+        assert_eq!(
+            processed,
+            vec![true, false, false, true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_max_per_second_sampling() {
+        let clock = ManualClock::new(0);
+        let mut processor = SampledProcessor::new(NoopProcessor, Sampling::MaxPerSecond(2))
+            .with_clock(Arc::new(clock.clone()));
+
+        let mut processed: Vec<bool> = (0..3).map(|_| processor.should_process()).collect();
+        clock.advance(Duration::from_secs(1));
+        processed.extend((0..3).map(|_| processor.should_process()));
+
+        assert_eq!(processed, vec![true, true, false, true, true, false]);
+    }
 }
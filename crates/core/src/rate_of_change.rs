@@ -0,0 +1,270 @@
+//! Alerts on fast changes of a numeric field of decoded accounts.
+//!
+//! `PipelineBuilder::account_rate_of_change` adds an account pipe watching a
+//! value extracted from each decoded account, such as the reserves of a pool.
+//! When the value of an account changes by more than a threshold within a
+//! time window, a `RateOfChangeAlert` is passed to the alert processor.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account_rate_of_change(
+//!         RaydiumAmmV4Decoder,
+//!         |account| match account {
+//!             RaydiumAmmV4Account::AmmInfo(amm) => Some(amm.lp_amount as f64),
+//!             _ => None,
+//!         },
+//!         0.5,
+//!         Duration::from_secs(60),
+//!         ReservesAlertProcessor,
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The change is relative to the values seen within the window, so `0.5`
+//!   fires when the value grows or drops by more than 50%.
+//! - Once an alert fires, the window of the account restarts from the current
+//!   value, so a single drop fires a single alert.
+
+use {
+    crate::{
+        account::AccountProcessorInputType,
+        clock::{system_clock, Clock},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    std::{
+        collections::{HashMap, VecDeque},
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+};
+
+/// A change of the watched value of an account beyond the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateOfChangeAlert {
+    pub pubkey: Pubkey,
+    /// The slot of the account update that fired the alert.
+    pub slot: u64,
+    /// The value within the window the change is measured from.
+    pub previous_value: f64,
+    pub current_value: f64,
+    /// The relative change, negative when the value dropped.
+    pub change: f64,
+    /// The time between the previous and the current value.
+    pub elapsed: Duration,
+}
+
+/// A processor watching a value of decoded accounts, passing a
+/// `RateOfChangeAlert` to the alert processor when it changes too fast.
+///
+/// Values are tracked per account. Accounts for which `value` returns `None`
+/// are ignored.
+pub struct RateOfChangeProcessor<T, P> {
+    value: Box<dyn Fn(&T) -> Option<f64> + Send + Sync>,
+    threshold: f64,
+    window: Duration,
+    processor: P,
+    clock: Arc<dyn Clock>,
+    samples: HashMap<Pubkey, VecDeque<(Instant, f64)>>,
+}
+
+impl<T, P> RateOfChangeProcessor<T, P> {
+    pub fn new(
+        value: impl Fn(&T) -> Option<f64> + Send + Sync + 'static,
+        threshold: f64,
+        window: Duration,
+        processor: P,
+    ) -> Self {
+        Self {
+            value: Box::new(value),
+            threshold,
+            window,
+            processor,
+            clock: system_clock(),
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Measures the windows with `clock` rather than the system time.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    fn observe(
+        &mut self,
+        pubkey: Pubkey,
+        slot: u64,
+        now: Instant,
+        current_value: f64,
+    ) -> Option<RateOfChangeAlert> {
+        let samples = self.samples.entry(pubkey).or_default();
+
+        while let Some((at, _)) = samples.front() {
+            if now.saturating_duration_since(*at) <= self.window {
+                break;
+            }
+            samples.pop_front();
+        }
+
+        let largest_change = samples
+            .iter()
+            .filter(|(_, previous_value)| *previous_value != 0.0)
+            .map(|(at, previous_value)| {
+                (
+                    *at,
+                    *previous_value,
+                    (current_value - previous_value) / previous_value.abs(),
+                )
+            })
+            .max_by(|a, b| a.2.abs().total_cmp(&b.2.abs()));
+
+        let alert = match largest_change {
+            Some((at, previous_value, change)) if change.abs() > self.threshold => {
+                samples.clear();
+                Some(RateOfChangeAlert {
+                    pubkey,
+                    slot,
+                    previous_value,
+                    current_value,
+                    change,
+                    elapsed: now.saturating_duration_since(at),
+                })
+            }
+            _ => None,
+        };
+
+        samples.push_back((now, current_value));
+
+        alert
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for RateOfChangeProcessor<T, P>
+where
+    T: Send + Sync + 'static,
+    P: Processor<InputType = RateOfChangeAlert> + Send + Sync,
+{
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (metadata, account) = data;
+
+        let Some(value) = (self.value)(&account.data) else {
+            return Ok(());
+        };
+
+        let Some(alert) = self.observe(metadata.pubkey, metadata.slot, self.clock.now(), value)
+        else {
+            return Ok(());
+        };
+
+        metrics
+            .increment_counter("rate_of_change_alerts", 1)
+            .await?;
+
+        self.processor.process(alert, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopProcessor;
+
+    #[async_trait]
+    impl Processor for NoopProcessor {
+        type InputType = RateOfChangeAlert;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_alert_on_change_within_window() {
+        // Arrange
+        let mut processor: RateOfChangeProcessor<u64, _> = RateOfChangeProcessor::new(
+            |value| Some(*value as f64),
+            0.5,
+            Duration::from_secs(60),
+            NoopProcessor,
+        );
+        let pubkey = Pubkey::new_unique();
+        let start = Instant::now();
+
+        // Act
+        let first = processor.observe(pubkey, 1, start, 100.0);
+        let second = processor.observe(pubkey, 2, start + Duration::from_secs(10), 90.0);
+        let third = processor.observe(pubkey, 3, start + Duration::from_secs(20), 40.0);
+        let fourth = processor.observe(pubkey, 4, start + Duration::from_secs(30), 35.0);
+
+        // Assert
+        assert_eq!(first, None);
+        assert_eq!(second, None);
+        assert_eq!(
+            third,
+            Some(RateOfChangeAlert {
+                pubkey,
+                slot: 3,
+                previous_value: 100.0,
+                current_value: 40.0,
+                change: -0.6,
+                elapsed: Duration::from_secs(20),
+            })
+        );
+        assert_eq!(fourth, None);
+    }
+
+    #[test]
+    fn test_no_alert_on_change_outside_window() {
+        // Arrange
+        let mut processor: RateOfChangeProcessor<u64, _> = RateOfChangeProcessor::new(
+            |value| Some(*value as f64),
+            0.5,
+            Duration::from_secs(60),
+            NoopProcessor,
+        );
+        let pubkey = Pubkey::new_unique();
+        let start = Instant::now();
+
+        // Act
+        processor.observe(pubkey, 1, start, 100.0);
+        let alert = processor.observe(pubkey, 2, start + Duration::from_secs(61), 40.0);
+
+        // Assert
+        assert_eq!(alert, None);
+    }
+}
@@ -0,0 +1,414 @@
+//! Maps known program ids to the Carbon decoder crates decoding them.
+//!
+//! The registry lists every decoder published in this repository with the
+//! program it decodes. `detect` uses it to tell which decoders would match a
+//! transaction, which is how the CLI `detect` command suggests decoders for a
+//! transaction and how the pipeline warns about programs it could decode, when
+//! enabled with `PipelineBuilder::suggest_missing_decoders`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! for decoder in carbon_core::registry::detect(&transaction_update) {
+//!     println!("{} ({}): {}", decoder.program_name, decoder.program_id, decoder.crate_name);
+//! }
+//! ```
+
+use {crate::datasource::TransactionUpdate, solana_pubkey::Pubkey, std::collections::HashSet};
+
+/// A decoder crate and the program it decodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisteredDecoder {
+    pub program_id: Pubkey,
+    pub crate_name: &'static str,
+    pub program_name: &'static str,
+}
+
+const fn decoder(
+    program_id: &str,
+    crate_name: &'static str,
+    program_name: &'static str,
+) -> RegisteredDecoder {
+    RegisteredDecoder {
+        program_id: Pubkey::from_str_const(program_id),
+        crate_name,
+        program_name,
+    }
+}
+
+/// The decoders published in this repository, sorted by crate name.
+pub const DECODERS: &[RegisteredDecoder] = &[
+    decoder(
+        "ALTNSZ46uaAUU7XUV6awvdorLGqAsPwa9shm7h4uP2FK",
+        "carbon-alldomains-decoder",
+        "AllDomains Name Service",
+    ),
+    decoder(
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        "carbon-associated-token-account-decoder",
+        "Associated Token Account",
+    ),
+    decoder(
+        "CLMM9tUoggJu2wagPkkqs9eFG4BWhVBZWkP1qv3Sp7tR",
+        "carbon-crema-clmm-decoder",
+        "Crema CLMM",
+    ),
+    decoder(
+        "dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH",
+        "carbon-drift-v2-decoder",
+        "Drift V2",
+    ),
+    decoder(
+        "FLUXubRmkEi2q6K3Y9kBPg9248ggaZVsoSFhtJHSrm1X",
+        "carbon-fluxbeam-decoder",
+        "Fluxbeam",
+    ),
+    decoder(
+        "GAMMA7meSFWaBXF25oSUgmGRwaW6sCMFLmBNiMSdbHVT",
+        "carbon-goosefx-gamma-decoder",
+        "GooseFX GAMMA",
+    ),
+    decoder(
+        "HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt",
+        "carbon-invariant-decoder",
+        "Invariant",
+    ),
+    decoder(
+        "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
+        "carbon-jupiter-dca-decoder",
+        "Jupiter DCA",
+    ),
+    decoder(
+        "j1o2qRpjcyUwEvwtcfhEQefh773ZgjxcVRry7LDqg5X",
+        "carbon-jupiter-limit-order-2-decoder",
+        "Jupiter Limit Order 2",
+    ),
+    decoder(
+        "jupoNjAxXgZ4rjzxzPMP4oxduvQsQtZzyknqvzYNrNu",
+        "carbon-jupiter-limit-order-decoder",
+        "Jupiter Limit Order",
+    ),
+    decoder(
+        "PERPHjGBqRHArX4DySjwM6UJHiR3sWAatqfdBS2qQJu",
+        "carbon-jupiter-perpetuals-decoder",
+        "Jupiter Perpetuals",
+    ),
+    decoder(
+        "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+        "carbon-jupiter-swap-decoder",
+        "Jupiter Swap",
+    ),
+    decoder(
+        "FarmsPZpWu9i7Kky8tPN37rs2TpmMrAZrC7S7vJa91Hr",
+        "carbon-kamino-farms-decoder",
+        "Kamino Farms",
+    ),
+    decoder(
+        "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+        "carbon-kamino-lending-decoder",
+        "Kamino Lend",
+    ),
+    decoder(
+        "Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g",
+        "carbon-mpl-candy-guard-decoder",
+        "MPL Candy Guard",
+    ),
+    decoder(
+        "CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR",
+        "carbon-mpl-candy-machine-core-decoder",
+        "MPL Candy Machine Core",
+    ),
+    decoder(
+        "FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH",
+        "carbon-pyth-oracle-decoder",
+        "Pyth Oracle",
+    ),
+    decoder(
+        "rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ",
+        "carbon-pyth-receiver-decoder",
+        "Pyth Receiver",
+    ),
+    decoder(
+        "SW1TCH7qEPTdLsDHRgPuMQjbQxKdH2aBStViMFnt64f",
+        "carbon-switchboard-v2-decoder",
+        "Switchboard V2",
+    ),
+    decoder(
+        "LiMoM9rMhrdYrfzUCxQppvxCSG1FcrUK9G8uLq4A1GF",
+        "kamino-limit-order-decoder",
+        "Kamino Limit Order",
+    ),
+    decoder(
+        "kvauTFR8qm1dhniz6pYuBZkuene3Hfrs1VQhVRgCNrr",
+        "carbon-kamino-vault-decoder",
+        "Kamino Vault",
+    ),
+    decoder(
+        "2wT8Yq49kHgDzXuPxZSaeLaH1qbmGXtEyPy64bL7aD3c",
+        "carbon-lifinity-amm-v2-decoder",
+        "Lifinity AMM V2",
+    ),
+    decoder(
+        "MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FZnsebVacA",
+        "carbon-marginfi-v2-decoder",
+        "Marginfi V2",
+    ),
+    decoder(
+        "MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD",
+        "carbon-marinade-finance-decoder",
+        "Marinade Finance",
+    ),
+    decoder(
+        "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo",
+        "carbon-memo-program-decoder",
+        "SPL Memo",
+    ),
+    decoder(
+        "MERLuDFBMmsHnsBPZw2sDQZHvXFMwp8EdjudcU2HKky",
+        "carbon-mercurial-stable-swap-decoder",
+        "Mercurial Stable Swap",
+    ),
+    decoder(
+        "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo",
+        "carbon-meteora-dlmm-decoder",
+        "Meteora DLMM",
+    ),
+    decoder(
+        "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB",
+        "carbon-meteora-pools-decoder",
+        "Meteora Pools",
+    ),
+    decoder(
+        "MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG",
+        "carbon-moonshot-decoder",
+        "Moonshot",
+    ),
+    decoder(
+        "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d",
+        "carbon-mpl-core-decoder",
+        "MPL Core",
+    ),
+    decoder(
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+        "carbon-mpl-token-metadata-decoder",
+        "MPL Token Metadata",
+    ),
+    decoder(
+        "namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX",
+        "carbon-name-service-decoder",
+        "SPL Name Service",
+    ),
+    decoder(
+        "6m2CDdhRgxpH4WjvdzxAYbGxwdGUz5MziiL5jek2kBma",
+        "carbon-okx-dex-decoder",
+        "OKX DEX",
+    ),
+    decoder(
+        "opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb",
+        "carbon-openbook-v2-decoder",
+        "Openbook V2",
+    ),
+    decoder(
+        "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",
+        "carbon-orca-whirlpool-decoder",
+        "Orca Whirlpool",
+    ),
+    decoder(
+        "PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY",
+        "carbon-phoenix-v1-decoder",
+        "Phoenix V1",
+    ),
+    decoder(
+        "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA",
+        "carbon-pump-swap-decoder",
+        "PumpSwap",
+    ),
+    decoder(
+        "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+        "carbon-pumpfun-decoder",
+        "Pumpfun",
+    ),
+    decoder(
+        "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+        "carbon-raydium-amm-v4-decoder",
+        "Raydium AMM V4",
+    ),
+    decoder(
+        "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK",
+        "carbon-raydium-clmm-decoder",
+        "Raydium CLMM",
+    ),
+    decoder(
+        "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C",
+        "carbon-raydium-cpmm-decoder",
+        "Raydium CPMM",
+    ),
+    decoder(
+        "LockrWmn6K5twhz3y9w1dQERbmgSaRkfnTeTKbpofwE",
+        "carbon-raydium-liquidity-locking-decoder",
+        "Raydium Liquidity Locking",
+    ),
+    decoder(
+        "SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ",
+        "carbon-saber-stable-swap-decoder",
+        "Saber Stable Swap",
+    ),
+    decoder(
+        "SHARKobtfF1bHhxD2eqftjHBdVSCbKo9JtgK71FhELP",
+        "carbon-sharky-decoder",
+        "SharkyFi",
+    ),
+    decoder(
+        "sSo1iU21jBrU9VaJ8PJib1MtorefUV4fzC9GURa2KNn",
+        "carbon-solayer-restaking-program-decoder",
+        "Solayer Pool Restaking",
+    ),
+    decoder(
+        "swapNyd8XiQwJ6ianp9snpu4brUqFxadzvHebnAXjJZ",
+        "carbon-stabble-stable-swap-decoder",
+        "Stabble Stable Swap",
+    ),
+    decoder(
+        "swapFpHZwjELNnjvThjajtiVmkz3yPQEHjLtka2fwHW",
+        "carbon-stabble-weighted-swap-decoder",
+        "Stabble Weighted Swap",
+    ),
+    decoder(
+        "Stake11111111111111111111111111111111111111",
+        "carbon-stake-program-decoder",
+        "Stake Program",
+    ),
+    decoder(
+        "11111111111111111111111111111111",
+        "carbon-system-program-decoder",
+        "System Program",
+    ),
+    decoder(
+        "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+        "carbon-token-2022-decoder",
+        "Token 2022",
+    ),
+    decoder(
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        "carbon-token-program-decoder",
+        "Token Program",
+    ),
+    decoder(
+        "5U3EU2ubXtK84QcRjWVmYt9RaDyA8gKxdUrPFXmZyaki",
+        "carbon-virtuals-decoder",
+        "Virtuals",
+    ),
+    decoder(
+        "Vote111111111111111111111111111111111111111",
+        "carbon-vote-program-decoder",
+        "Vote Program",
+    ),
+    decoder(
+        "ZETAxsqBRek56DhiGXrn75yj2NHU3aYUnxvHXpkf3aD",
+        "carbon-zeta-decoder",
+        "Zeta",
+    ),
+];
+
+/// Returns the registered decoder of `program_id`, if any.
+pub fn find(program_id: &Pubkey) -> Option<&'static RegisteredDecoder> {
+    DECODERS
+        .iter()
+        .find(|decoder| decoder.program_id == *program_id)
+}
+
+/// Returns the registered decoders matching the programs invoked by the
+/// transaction, including through inner instructions, in registry order.
+pub fn detect(transaction_update: &TransactionUpdate) -> Vec<&'static RegisteredDecoder> {
+    let message = &transaction_update.transaction.message;
+    let meta = &transaction_update.meta;
+
+    let account_keys: Vec<Pubkey> = message
+        .static_account_keys()
+        .iter()
+        .chain(meta.loaded_addresses.writable.iter())
+        .chain(meta.loaded_addresses.readonly.iter())
+        .copied()
+        .collect();
+
+    let inner_program_id_indexes = meta
+        .inner_instructions
+        .iter()
+        .flatten()
+        .flat_map(|inner_instructions| inner_instructions.instructions.iter())
+        .map(|inner_instruction| inner_instruction.instruction.program_id_index);
+
+    let program_ids: HashSet<Pubkey> = message
+        .instructions()
+        .iter()
+        .map(|instruction| instruction.program_id_index)
+        .chain(inner_program_id_indexes)
+        .filter_map(|index| account_keys.get(index as usize).copied())
+        .collect();
+
+    DECODERS
+        .iter()
+        .filter(|decoder| program_ids.contains(&decoder.program_id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            message::{Message, VersionedMessage},
+            signature::Signature,
+            transaction::VersionedTransaction,
+        },
+        solana_transaction_status::{InnerInstruction, InnerInstructions, TransactionStatusMeta},
+    };
+
+    #[test]
+    fn test_detect_includes_inner_instructions() {
+        // Arrange
+        let fee_payer = Pubkey::new_unique();
+        let pumpfun = find(&Pubkey::from_str_const(
+            "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+        ))
+        .expect("pumpfun decoder");
+        let token_program = find(&Pubkey::from_str_const(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        ))
+        .expect("token program decoder");
+
+        let message = Message {
+            account_keys: vec![fee_payer, pumpfun.program_id, token_program.program_id],
+            instructions: vec![CompiledInstruction::new_from_raw_parts(1, vec![], vec![0])],
+            ..Message::default()
+        };
+        let transaction_update = TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![],
+                message: VersionedMessage::Legacy(message),
+            },
+            meta: TransactionStatusMeta {
+                inner_instructions: Some(vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![InnerInstruction {
+                        instruction: CompiledInstruction::new_from_raw_parts(2, vec![], vec![0]),
+                        stack_height: Some(2),
+                    }],
+                }]),
+                ..Default::default()
+            },
+            is_vote: false,
+            slot: 0,
+            block_time: None,
+            commitment: None,
+        };
+
+        // Act
+        let result = detect(&transaction_update);
+
+        // Assert
+        assert_eq!(result, vec![pumpfun, token_program]);
+    }
+}
@@ -0,0 +1,258 @@
+//! Reconstructs the history of account states from decoded instructions.
+//!
+//! Some programs change their accounts in a way fully described by their
+//! instructions and events, such as bonding curves whose trade events carry
+//! the reserves after each trade. For these programs, the state of an account
+//! at any slot can be rebuilt from transaction data alone, without account
+//! snapshots, which historical datasources seldom provide.
+//!
+//! A `StateModel` describes how the instructions of a program change its
+//! accounts. A `ReplayEngine` is an instruction processor applying each
+//! decoded instruction to a model, in order, and passing every account state
+//! it changes to its inner processor as a `ReconstructedAccount`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! struct CurveHistory;
+//!
+//! #[async_trait]
+//! impl Processor for CurveHistory {
+//!     type InputType = ReconstructedAccount<BondingCurveState>;
+//!
+//!     async fn process(
+//!         &mut self,
+//!         account: ReconstructedAccount<BondingCurveState>,
+//!         _metrics: Arc<MetricsCollection>,
+//!     ) -> CarbonResult<()> {
+//!         log::info!("{} at slot {}: {:?}", account.pubkey, account.slot, account.state);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! Pipeline::builder()
+//!     .datasource(my_transaction_crawler)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         ReplayEngine::new(BondingCurveReplay::new(), CurveHistory),
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Instructions must be received in execution order, as transaction
+//!   crawlers replaying a range of slots deliver them. An instruction received
+//!   out of order produces a state that never existed on chain.
+//! - Models start from an empty state unless seeded, so accounts created
+//!   before the first replayed instruction are only known from the first
+//!   instruction fully describing them.
+//! - Failed transactions are not replayed, as they change no account.
+
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::{DecodedInstruction, InstructionMetadata, InstructionProcessorInputType},
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::sync::Arc,
+};
+
+/// Describes how the instructions of a program change its accounts.
+///
+/// # Required Methods
+///
+/// - `apply`: Applies a decoded instruction to the model, returning the
+///   accounts it changed with their new state.
+pub trait StateModel: Send + Sync {
+    type Instruction: Send + Sync + 'static;
+    type State: Clone + Send + Sync + 'static;
+
+    fn apply(
+        &mut self,
+        metadata: &InstructionMetadata,
+        instruction: &DecodedInstruction<Self::Instruction>,
+    ) -> CarbonResult<Vec<(Pubkey, Self::State)>>;
+}
+
+/// The state of an account after an instruction, as rebuilt by a
+/// `StateModel`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconstructedAccount<S> {
+    pub pubkey: Pubkey,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// The transaction whose instruction produced the state.
+    pub signature: Signature,
+    pub state: S,
+}
+
+/// An instruction processor replaying instructions into a `StateModel`.
+pub struct ReplayEngine<M, P> {
+    model: M,
+    processor: P,
+}
+
+impl<M, P> ReplayEngine<M, P>
+where
+    M: StateModel,
+    P: Processor<InputType = ReconstructedAccount<M::State>>,
+{
+    pub fn new(model: M, processor: P) -> Self {
+        Self { model, processor }
+    }
+
+    /// Returns the model, with the state replayed so far.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+}
+
+#[async_trait]
+impl<M, P> Processor for ReplayEngine<M, P>
+where
+    M: StateModel,
+    P: Processor<InputType = ReconstructedAccount<M::State>> + Send + Sync,
+{
+    type InputType = InstructionProcessorInputType<M::Instruction>;
+
+    async fn process(
+        &mut self,
+        (metadata, instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if metadata.transaction_metadata.meta.status.is_err() {
+            return Ok(());
+        }
+
+        for (pubkey, state) in self.model.apply(&metadata, &instruction)? {
+            metrics
+                .increment_counter("replay_accounts_reconstructed", 1)
+                .await?;
+            self.processor
+                .process(
+                    ReconstructedAccount {
+                        pubkey,
+                        slot: metadata.transaction_metadata.slot,
+                        block_time: metadata.transaction_metadata.block_time,
+                        signature: metadata.transaction_metadata.signature,
+                        state,
+                    },
+                    metrics.clone(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{instruction::NestedInstructions, transaction::TransactionMetadata},
+        solana_sdk::transaction::TransactionError,
+        std::{collections::HashMap, sync::Mutex},
+    };
+
+    /// A counter program: each instruction adds its data to the counter of
+    /// its first account.
+    #[derive(Default)]
+    struct Counters(HashMap<Pubkey, u64>);
+
+    impl StateModel for Counters {
+        type Instruction = u64;
+        type State = u64;
+
+        fn apply(
+            &mut self,
+            _metadata: &InstructionMetadata,
+            instruction: &DecodedInstruction<u64>,
+        ) -> CarbonResult<Vec<(Pubkey, u64)>> {
+            let pubkey = instruction.accounts[0].pubkey;
+            let counter = self.0.entry(pubkey).or_default();
+            *counter += instruction.data;
+            Ok(vec![(pubkey, *counter)])
+        }
+    }
+
+    struct History(Arc<Mutex<Vec<(u64, u64)>>>);
+
+    #[async_trait]
+    impl Processor for History {
+        type InputType = ReconstructedAccount<u64>;
+
+        async fn process(
+            &mut self,
+            account: ReconstructedAccount<u64>,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push((account.slot, account.state));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_skips_failed_transactions() {
+        // Arrange
+        let history = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = ReplayEngine::new(Counters::default(), History(history.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let counter = Pubkey::new_unique();
+
+        // Act
+        for (slot, amount, failed) in [(1, 5, false), (2, 7, true), (3, 2, false)] {
+            let mut transaction_metadata = TransactionMetadata {
+                slot,
+                ..TransactionMetadata::default()
+            };
+            if failed {
+                transaction_metadata.meta.status = Err(TransactionError::AccountInUse);
+            }
+            let metadata = InstructionMetadata {
+                transaction_metadata,
+                stack_height: 1,
+                index: 0,
+                absolute_path: vec![0],
+                compute_units_consumed: None,
+            };
+            let instruction = DecodedInstruction {
+                program_id: Pubkey::new_unique(),
+                data: amount,
+                accounts: vec![solana_instruction::AccountMeta::new(counter, false)],
+                audit: None,
+            };
+
+            engine
+                .process(
+                    (metadata, instruction, NestedInstructions::default()),
+                    metrics.clone(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        assert_eq!(*history.lock().unwrap(), vec![(1, 5), (3, 7)]);
+        assert_eq!(engine.model().0.get(&counter), Some(&7));
+    }
+}
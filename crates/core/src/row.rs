@@ -0,0 +1,357 @@
+//! Flattens decoded data into table rows, for the sinks writing to tables or
+//! files.
+//!
+//! Decoded types are externally tagged enums once serialized, such as
+//! `{"Buy": {"amount": 1}}`. A `Row` takes the variant as its table, `buy`,
+//! and its fields as columns, after the metadata columns of the sink such as
+//! the signature or the slot. A `SchemaWriter` writes rows to a SQL database,
+//! creating each table the first time one of its rows is written and adding
+//! the columns of new fields, for example after a program upgrade.
+//!
+//! The SQL dialect is left to the sinks, which implement `SqlDatabase` with
+//! their column types and their way of listing the columns of a table.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let row = Row::new(
+//!     "instructions",
+//!     vec![("signature", json!(signature.to_string())), ("slot", json!(slot))],
+//!     serde_json::to_value(&instruction)?,
+//! );
+//!
+//! schema_writer.write(&Sqlite(&connection), row, &["signature"], false)?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Table and column names are converted to snake case SQL identifiers.
+//!   Fields colliding with a metadata column are prefixed with `data_`.
+//! - Rows are inserted with `INSERT OR REPLACE` or `INSERT OR IGNORE`
+//!   statements and `?` placeholders, bound in order to the column values.
+//!   Dialects without them override `SqlDatabase::insert`.
+
+use {
+    heck::ToSnakeCase,
+    serde_json::Value,
+    std::collections::{HashMap, HashSet},
+};
+
+/// A row to write, with the table derived from the decoded data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub table: String,
+    pub columns: Vec<(String, Value)>,
+}
+
+impl Row {
+    /// Builds a row from metadata columns and the encoded data.
+    ///
+    /// Externally tagged enums, such as `{"Buy": {"amount": 1}}`, are written
+    /// to a table named after the variant (`buy`), with one column per field.
+    /// Other values are written to `default_table`. Fields colliding with a
+    /// metadata column are prefixed with `data_`.
+    pub fn new(default_table: &str, metadata: Vec<(&str, Value)>, data: Value) -> Self {
+        let (table, fields) = match split_variant(data) {
+            Ok((tag, Value::Object(fields))) => (tag, fields.into_iter().collect()),
+            Ok((tag, Value::Null)) => (tag, Vec::new()),
+            Ok((tag, value)) => (tag, vec![("value".to_string(), value)]),
+            Err(Value::Object(fields)) => (default_table.to_string(), fields.into_iter().collect()),
+            Err(value) => (
+                default_table.to_string(),
+                vec![("value".to_string(), value)],
+            ),
+        };
+
+        let mut columns: Vec<(String, Value)> = metadata
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        for (name, value) in fields {
+            let mut name = identifier(&name);
+            if columns.iter().any(|(column, _)| *column == name) {
+                name = format!("data_{}", name);
+            }
+            columns.push((name, value));
+        }
+
+        Self {
+            table: identifier(&table),
+            columns,
+        }
+    }
+}
+
+/// Splits an externally tagged enum, such as `{"Buy": {"amount": 1}}`, into
+/// its variant and its content. A unit variant, serialized as its name, has a
+/// `null` content.
+///
+/// Returns the value back if it is not a tagged enum.
+pub fn split_variant(value: Value) -> Result<(String, Value), Value> {
+    match value {
+        Value::Object(map) if map.len() == 1 => {
+            Ok(map.into_iter().next().expect("map has one entry"))
+        }
+        Value::String(tag) => Ok((tag, Value::Null)),
+        value => Err(value),
+    }
+}
+
+/// Converts a name into a snake case SQL identifier.
+pub fn identifier(name: &str) -> String {
+    let identifier: String = name
+        .to_snake_case()
+        .chars()
+        .filter(|char| char.is_ascii_alphanumeric() || *char == '_')
+        .collect();
+
+    if identifier.is_empty() {
+        "value".to_string()
+    } else {
+        identifier
+    }
+}
+
+/// The dialect of a SQL database written by a `SchemaWriter`.
+pub trait SqlDatabase {
+    type Error;
+
+    /// Returns the type of a new column first written with `value`.
+    fn column_type(value: &Value) -> &'static str;
+
+    /// Returns the statement inserting a row of `columns` into `table`, and
+    /// the parameters to bind to it. With `replace`, a row with the same
+    /// primary key is overwritten; otherwise the new row is ignored.
+    fn insert(
+        table: &str,
+        columns: Vec<(String, Value)>,
+        _primary_key: &[&str],
+        replace: bool,
+    ) -> (String, Vec<Value>) {
+        let (names, values): (Vec<String>, Vec<Value>) = columns
+            .into_iter()
+            .map(|(name, value)| (format!("\"{}\"", name), value))
+            .unzip();
+        let placeholders = vec!["?"; names.len()];
+
+        let sql = format!(
+            "INSERT OR {} INTO \"{}\" ({}) VALUES ({})",
+            if replace { "REPLACE" } else { "IGNORE" },
+            table,
+            names.join(", "),
+            placeholders.join(", ")
+        );
+
+        (sql, values)
+    }
+
+    /// Returns the names of the columns of `table`, after it was created.
+    fn table_columns(&self, table: &str) -> Result<HashSet<String>, Self::Error>;
+
+    /// Executes `sql`, binding `params` to its placeholders in order.
+    /// Returns the number of rows changed.
+    fn execute(&self, sql: &str, params: Vec<Value>) -> Result<usize, Self::Error>;
+}
+
+/// Creates tables and columns on demand to fit the rows written.
+#[derive(Debug, Default)]
+pub struct SchemaWriter {
+    columns: HashMap<String, HashSet<String>>,
+}
+
+impl SchemaWriter {
+    /// Inserts the row, creating its table and any missing column first.
+    ///
+    /// With `replace`, a row with the same primary key is overwritten;
+    /// otherwise it is kept and the new row ignored.
+    pub fn write<D: SqlDatabase>(
+        &mut self,
+        database: &D,
+        row: Row,
+        primary_key: &[&str],
+        replace: bool,
+    ) -> Result<(), D::Error> {
+        self.ensure_table(database, &row, primary_key)?;
+
+        let (sql, params) = D::insert(&row.table, row.columns, primary_key, replace);
+        database.execute(&sql, params)?;
+
+        Ok(())
+    }
+
+    /// Returns the tables written so far.
+    pub fn tables(&self) -> impl Iterator<Item = &str> {
+        self.columns.keys().map(String::as_str)
+    }
+
+    fn ensure_table<D: SqlDatabase>(
+        &mut self,
+        database: &D,
+        row: &Row,
+        primary_key: &[&str],
+    ) -> Result<(), D::Error> {
+        if !self.columns.contains_key(&row.table) {
+            let definitions: Vec<String> = row
+                .columns
+                .iter()
+                .map(|(name, value)| format!("\"{}\" {}", name, D::column_type(value)))
+                .collect();
+            let primary_key: Vec<String> = primary_key
+                .iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect();
+
+            database.execute(
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS \"{}\" ({}, PRIMARY KEY ({}))",
+                    row.table,
+                    definitions.join(", "),
+                    primary_key.join(", ")
+                ),
+                Vec::new(),
+            )?;
+
+            let existing = database.table_columns(&row.table)?;
+            self.columns.insert(row.table.clone(), existing);
+        }
+
+        let known = self
+            .columns
+            .get_mut(&row.table)
+            .expect("table columns were loaded");
+
+        for (name, value) in &row.columns {
+            if known.contains(name) {
+                continue;
+            }
+
+            database.execute(
+                &format!(
+                    "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {}",
+                    row.table,
+                    name,
+                    D::column_type(value)
+                ),
+                Vec::new(),
+            )?;
+            known.insert(name.clone());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json, std::cell::RefCell};
+
+    #[test]
+    fn test_row_from_tagged_enum() {
+        let row = Row::new(
+            "instructions",
+            vec![("signature", json!("sig")), ("slot", json!(10))],
+            json!({ "SwapBaseIn": { "amountIn": 5, "slot": 3 } }),
+        );
+
+        assert_eq!(row.table, "swap_base_in");
+        assert_eq!(
+            row.columns,
+            vec![
+                ("signature".to_string(), json!("sig")),
+                ("slot".to_string(), json!(10)),
+                ("amount_in".to_string(), json!(5)),
+                ("data_slot".to_string(), json!(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_from_other_values() {
+        assert_eq!(
+            Row::new("instructions", vec![], json!("CollectFees")),
+            Row {
+                table: "collect_fees".to_string(),
+                columns: vec![],
+            }
+        );
+        assert_eq!(
+            Row::new("accounts", vec![], json!({ "fee": 1, "paused": true })),
+            Row {
+                table: "accounts".to_string(),
+                columns: vec![
+                    ("fee".to_string(), json!(1)),
+                    ("paused".to_string(), json!(true)),
+                ],
+            }
+        );
+        assert_eq!(
+            Row::new("accounts", vec![], json!(7)),
+            Row {
+                table: "accounts".to_string(),
+                columns: vec![("value".to_string(), json!(7))],
+            }
+        );
+    }
+
+    /// Records the statements executed. Tables are listed with the columns
+    /// of the first row of the test.
+    #[derive(Default)]
+    struct Statements(RefCell<Vec<String>>);
+
+    impl SqlDatabase for Statements {
+        type Error = ();
+
+        fn column_type(_value: &Value) -> &'static str {
+            "TEXT"
+        }
+
+        fn table_columns(&self, _table: &str) -> Result<HashSet<String>, ()> {
+            Ok(["pubkey", "fee"].into_iter().map(String::from).collect())
+        }
+
+        fn execute(&self, sql: &str, _params: Vec<Value>) -> Result<usize, ()> {
+            self.0.borrow_mut().push(sql.to_string());
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_write_creates_the_table_and_adds_new_columns_once() {
+        // Arrange
+        let database = Statements::default();
+        let mut writer = SchemaWriter::default();
+        let pool = |fields: Value| {
+            Row::new(
+                "accounts",
+                vec![("pubkey", json!("a"))],
+                json!({ "Pool": fields }),
+            )
+        };
+
+        // Act
+        writer
+            .write(&database, pool(json!({ "fee": 1 })), &["pubkey"], true)
+            .unwrap();
+        writer
+            .write(
+                &database,
+                pool(json!({ "fee": 2, "paused": true })),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            database.0.into_inner(),
+            vec![
+                "CREATE TABLE IF NOT EXISTS \"pool\" (\"pubkey\" TEXT, \"fee\" TEXT, PRIMARY KEY (\"pubkey\"))",
+                "INSERT OR REPLACE INTO \"pool\" (\"pubkey\", \"fee\") VALUES (?, ?)",
+                "ALTER TABLE \"pool\" ADD COLUMN \"paused\" TEXT",
+                "INSERT OR REPLACE INTO \"pool\" (\"pubkey\", \"fee\", \"paused\") VALUES (?, ?, ?)",
+            ]
+        );
+        assert_eq!(writer.tables().collect::<Vec<_>>(), vec!["pool"]);
+    }
+}
@@ -15,7 +15,12 @@
 //!   node or an `Any` node, allowing flexibility in matching instructions at
 //!   that level.
 //! - **InstructionSchemaNode**: Represents an instruction with its type, name,
-//!   and any nested inner instructions.
+//!   account constraints, and any nested inner instructions.
+//! - **AccountConstraint**: A condition on the accounts of an instruction, such
+//!   as its mint being a given pubkey or its pool being in a set, checked while
+//!   matching.
+//! - **SequenceSchema**: Represents a sequence of transaction schemas matched
+//!   across several transactions from the same signer within a time window.
 //!
 //! ## Usage
 //!
@@ -31,19 +36,35 @@
 //!   providing flexibility in handling unknown instructions within
 //!   transactions. Each `InstructionSchemaNode` defines specific instructions
 //!   to be matched, allowing for strict validation where needed.
+//! - **Account Constraints**: An instruction of the right type whose accounts
+//!   do not satisfy the constraints of its node is treated as a different
+//!   instruction, so transaction pipes only receive the transactions they
+//!   care about instead of filtering them after matching.
 //! - **Nested Instructions**: Instruction schemas can contain nested
 //!   instructions, enabling validation of complex transactions with inner
 //!   instructions.
 //! - **Data Conversion**: The `match_schema` method returns data as a
 //!   deserialized type using `serde_json`. Ensure that your expected output
 //!   type implements `DeserializeOwned`.
+//! - **Sequences**: A `SequenceMatcher` keeps the progress of each signer
+//!   through a `SequenceSchema` in memory, so a sequence spanning a pipeline
+//!   restart is not detected.
 
 use {
-    crate::{collection::InstructionDecoderCollection, instruction::DecodedInstruction},
+    crate::{
+        collection::InstructionDecoderCollection, deserialize::ArrangeAccounts,
+        instruction::DecodedInstruction,
+    },
     serde::de::DeserializeOwned,
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
-    std::collections::HashMap,
+    solana_signature::Signature,
+    std::{
+        collections::{HashMap, HashSet},
+        fmt,
+        sync::Arc,
+        time::Duration,
+    },
 };
 
 /// Represents a node within a transaction schema, which can be either an
@@ -64,10 +85,113 @@ pub struct InstructionSchemaNode<T: InstructionDecoderCollection> {
     pub ix_type: T::InstructionType,
     /// A unique name identifier for the instruction node within the schema.
     pub name: String,
+    /// Conditions the accounts of the instruction must all satisfy.
+    pub account_constraints: Vec<AccountConstraint>,
     /// A vector of nested schema nodes for matching nested instructions.
     pub inner_instructions: Vec<SchemaNode<T>>,
 }
 
+impl<T: InstructionDecoderCollection> InstructionSchemaNode<T> {
+    /// Returns whether an instruction has the type of this node and satisfies
+    /// its account constraints.
+    pub fn matches(&self, instruction: &ParsedInstruction<T>) -> bool {
+        instruction.instruction.data.get_type() == self.ix_type
+            && self
+                .account_constraints
+                .iter()
+                .all(|constraint| constraint.matches(&instruction.instruction.accounts))
+    }
+}
+
+/// A condition on the accounts of an instruction, checked when matching an
+/// `InstructionSchemaNode`.
+///
+/// # Example
+///
+/// ```ignore
+/// let schema = schema![
+///     any
+///     [
+///         AllInstructionTypes::Pumpfun(PumpfunInstructionType::Buy),
+///         "buy",
+///         where [AccountConstraint::arranged::<buy::Buy>(move |accounts| accounts.mint == mint)]
+///     ]
+///     any
+/// ];
+/// ```
+#[derive(Clone)]
+pub struct AccountConstraint {
+    description: String,
+    predicate: Arc<dyn Fn(&[AccountMeta]) -> bool + Send + Sync>,
+}
+
+impl AccountConstraint {
+    /// Creates a constraint from a predicate on the accounts of the
+    /// instruction, in order.
+    pub fn new(
+        description: impl Into<String>,
+        predicate: impl Fn(&[AccountMeta]) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            description: description.into(),
+            predicate: Arc::new(predicate),
+        }
+    }
+
+    /// Requires the account at `index` to be `pubkey`.
+    pub fn equals(index: usize, pubkey: Pubkey) -> Self {
+        Self::new(
+            format!("accounts[{}] == {}", index, pubkey),
+            move |accounts| {
+                accounts
+                    .get(index)
+                    .is_some_and(|account| account.pubkey == pubkey)
+            },
+        )
+    }
+
+    /// Requires the account at `index` to be one of `pubkeys`.
+    pub fn one_of(index: usize, pubkeys: impl IntoIterator<Item = Pubkey>) -> Self {
+        let pubkeys: HashSet<Pubkey> = pubkeys.into_iter().collect();
+
+        Self::new(
+            format!("accounts[{}] in {} pubkeys", index, pubkeys.len()),
+            move |accounts| {
+                accounts
+                    .get(index)
+                    .is_some_and(|account| pubkeys.contains(&account.pubkey))
+            },
+        )
+    }
+
+    /// Requires the accounts, arranged as by the `ArrangeAccounts`
+    /// implementation of `I`, to satisfy `predicate`. Instructions whose
+    /// accounts cannot be arranged do not match.
+    pub fn arranged<I: ArrangeAccounts>(
+        predicate: impl Fn(&I::ArrangedAccounts) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(
+            format!("arranged {}", std::any::type_name::<I>()),
+            move |accounts| {
+                I::arrange_accounts(accounts).is_some_and(|arranged| predicate(&arranged))
+            },
+        )
+    }
+
+    /// Returns whether the accounts of an instruction satisfy the constraint.
+    pub fn matches(&self, accounts: &[AccountMeta]) -> bool {
+        (self.predicate)(accounts)
+    }
+}
+
+impl fmt::Debug for AccountConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AccountConstraint")
+            .field(&self.description)
+            .finish()
+    }
+}
+
 /// Represents a parsed instruction, containing its program ID, decoded
 /// instruction data, and any nested instructions within the transaction.
 #[derive(Debug)]
@@ -189,21 +313,15 @@ impl<T: InstructionDecoderCollection> TransactionSchema<T> {
                     return None;
                 };
 
-                if current_instruction.instruction.data.get_type() != instruction_node.ix_type
-                    && !any
-                {
+                if !instruction_node.matches(current_instruction) && !any {
                     log::trace!(
-                        "Schema::match_nodes: instruction type mismatch, returning (any = false)"
+                        "Schema::match_nodes: instruction mismatch, returning (any = false)"
                     );
                     return None;
                 }
 
-                if current_instruction.instruction.data.get_type() != instruction_node.ix_type
-                    && any
-                {
-                    log::trace!(
-                        "Schema::match_nodes: instruction type mismatch, skipping (any = true)"
-                    );
+                if !instruction_node.matches(current_instruction) && any {
+                    log::trace!("Schema::match_nodes: instruction mismatch, skipping (any = true)");
                     instruction_index += 1;
                     continue;
                 }
@@ -248,6 +366,138 @@ impl<T: InstructionDecoderCollection> TransactionSchema<T> {
     }
 }
 
+/// Represents a sequence of steps matched across several transactions sent by
+/// the same signer, such as creating a token, adding liquidity and removing it.
+///
+/// Each step is a `TransactionSchema` matched against a whole transaction, so
+/// `Any` nodes can be used to ignore unrelated instructions around the
+/// expected ones. A sequence must complete within `window`, measured from the
+/// block time of the transaction matching the first step.
+///
+/// The instruction names of all steps are merged into a single output, so they
+/// should be unique across steps.
+#[derive(Debug, Clone)]
+pub struct SequenceSchema<T: InstructionDecoderCollection> {
+    pub steps: Vec<TransactionSchema<T>>,
+    pub window: Duration,
+}
+
+/// A sequence completed by a signer.
+///
+/// # Fields
+///
+/// - `signer`: The signer that sent every transaction of the sequence.
+/// - `signatures`: The signatures of the transactions matching each step, in
+///   order.
+/// - `data`: The matched instructions of all steps, deserialized like the
+///   output of `TransactionSchema::match_schema`.
+#[derive(Debug, Clone)]
+pub struct SequenceMatch<U> {
+    pub signer: Pubkey,
+    pub signatures: Vec<Signature>,
+    pub data: U,
+}
+
+#[derive(Debug)]
+struct SequenceProgress<T> {
+    next_step: usize,
+    started_at: i64,
+    signatures: Vec<Signature>,
+    output: HashMap<String, (T, Vec<AccountMeta>)>,
+}
+
+/// Tracks the progress of each signer through a `SequenceSchema`.
+///
+/// Transactions are fed to the matcher in the order they are received. A
+/// transaction advances the signer's sequence if it matches the next step, or
+/// starts a new sequence if it matches the first one. Sequences that did not
+/// complete within the window are dropped.
+#[derive(Debug)]
+pub struct SequenceMatcher<T: InstructionDecoderCollection> {
+    schema: SequenceSchema<T>,
+    in_progress: HashMap<Pubkey, SequenceProgress<T>>,
+}
+
+impl<T: InstructionDecoderCollection> SequenceMatcher<T> {
+    pub fn new(schema: SequenceSchema<T>) -> Self {
+        Self {
+            schema,
+            in_progress: HashMap::new(),
+        }
+    }
+
+    /// Feeds a transaction to the matcher and returns the completed sequence
+    /// if this transaction matched its last step.
+    ///
+    /// # Parameters
+    ///
+    /// - `signer`: The signer of the transaction.
+    /// - `signature`: The signature of the transaction.
+    /// - `timestamp`: The Unix timestamp of the transaction, in seconds.
+    /// - `instructions`: The parsed instructions of the transaction.
+    pub fn observe<U>(
+        &mut self,
+        signer: Pubkey,
+        signature: Signature,
+        timestamp: i64,
+        instructions: &[ParsedInstruction<T>],
+    ) -> Option<SequenceMatch<U>>
+    where
+        U: DeserializeOwned,
+    {
+        log::trace!(
+            "SequenceMatcher::observe(signer: {:?}, signature: {:?}, timestamp: {:?})",
+            signer,
+            signature,
+            timestamp
+        );
+        let window = self.schema.window.as_secs() as i64;
+        self.in_progress
+            .retain(|_, progress| timestamp - progress.started_at <= window);
+
+        let advanced = self.in_progress.get(&signer).and_then(|progress| {
+            self.schema.steps[progress.next_step]
+                .match_nodes(instructions)
+                .map(|output| (progress.next_step, output))
+        });
+
+        let (step, output) = match advanced {
+            Some(advanced) => advanced,
+            None => {
+                let output = self.schema.steps.first()?.match_nodes(instructions)?;
+                self.in_progress.insert(
+                    signer,
+                    SequenceProgress {
+                        next_step: 0,
+                        started_at: timestamp,
+                        signatures: Vec::new(),
+                        output: HashMap::new(),
+                    },
+                );
+                (0, output)
+            }
+        };
+
+        let progress = self.in_progress.get_mut(&signer)?;
+        progress.next_step = step + 1;
+        progress.signatures.push(signature);
+        progress.output.extend(output);
+
+        if progress.next_step < self.schema.steps.len() {
+            return None;
+        }
+
+        let progress = self.in_progress.remove(&signer)?;
+        let value = serde_json::to_value(progress.output).ok()?;
+
+        Some(SequenceMatch {
+            signer,
+            signatures: progress.signatures,
+            data: serde_json::from_value::<U>(value).ok()?,
+        })
+    }
+}
+
 /// Merges two hash maps containing instruction data and account information.
 ///
 /// # Parameters
@@ -273,3 +523,207 @@ where
     }
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+    };
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    enum TestInstruction {
+        Create,
+        AddLiquidity,
+        RemoveLiquidity,
+    }
+
+    impl InstructionDecoderCollection for TestInstruction {
+        type InstructionType = TestInstruction;
+
+        fn parse_instruction(
+            _instruction: &solana_instruction::Instruction,
+        ) -> Option<DecodedInstruction<Self>> {
+            None
+        }
+
+        fn get_type(&self) -> Self::InstructionType {
+            self.clone()
+        }
+    }
+
+    fn step(ix_type: TestInstruction, name: &str) -> TransactionSchema<TestInstruction> {
+        TransactionSchema {
+            root: vec![
+                SchemaNode::Any,
+                SchemaNode::Instruction(InstructionSchemaNode {
+                    ix_type,
+                    name: name.to_string(),
+                    account_constraints: vec![],
+                    inner_instructions: vec![],
+                }),
+                SchemaNode::Any,
+            ],
+        }
+    }
+
+    fn transaction(data: TestInstruction) -> Vec<ParsedInstruction<TestInstruction>> {
+        vec![ParsedInstruction {
+            program_id: Pubkey::new_unique(),
+            instruction: DecodedInstruction {
+                program_id: Pubkey::new_unique(),
+                data,
+                accounts: vec![],
+                audit: None,
+            },
+            inner_instructions: vec![],
+        }]
+    }
+
+    fn add_liquidity(pool: Pubkey) -> ParsedInstruction<TestInstruction> {
+        ParsedInstruction {
+            program_id: Pubkey::new_unique(),
+            instruction: DecodedInstruction {
+                program_id: Pubkey::new_unique(),
+                data: TestInstruction::AddLiquidity,
+                accounts: vec![AccountMeta::new(pool, false)],
+                audit: None,
+            },
+            inner_instructions: vec![],
+        }
+    }
+
+    struct AddLiquidityAccounts {
+        pool: Pubkey,
+    }
+
+    impl ArrangeAccounts for AddLiquidityAccounts {
+        type ArrangedAccounts = AddLiquidityAccounts;
+
+        fn arrange_accounts(accounts: &[AccountMeta]) -> Option<Self::ArrangedAccounts> {
+            let [pool, ..] = accounts else {
+                return None;
+            };
+
+            Some(AddLiquidityAccounts { pool: pool.pubkey })
+        }
+    }
+
+    fn constrained(constraint: AccountConstraint) -> TransactionSchema<TestInstruction> {
+        let mut schema = step(TestInstruction::AddLiquidity, "add");
+        if let SchemaNode::Instruction(node) = &mut schema.root[1] {
+            node.account_constraints.push(constraint);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_match_nodes_with_account_constraints() {
+        // Arrange
+        let pool = Pubkey::new_unique();
+        let other_pool = Pubkey::new_unique();
+        let instructions = vec![add_liquidity(other_pool), add_liquidity(pool)];
+
+        // Act
+        let one_of = constrained(AccountConstraint::one_of(0, [pool])).match_nodes(&instructions);
+        let arranged = constrained(AccountConstraint::arranged::<AddLiquidityAccounts>(
+            move |accounts| accounts.pool == pool,
+        ))
+        .match_nodes(&instructions);
+        let missing = constrained(AccountConstraint::equals(0, Pubkey::new_unique()))
+            .match_nodes(&instructions);
+
+        // Assert
+        for output in [one_of, arranged] {
+            let output = output.expect("schema should match");
+            assert_eq!(output["add"].1[0].pubkey, pool);
+        }
+        assert!(missing.is_none());
+    }
+
+    fn matcher() -> SequenceMatcher<TestInstruction> {
+        SequenceMatcher::new(SequenceSchema {
+            steps: vec![
+                step(TestInstruction::Create, "create"),
+                step(TestInstruction::AddLiquidity, "add"),
+                step(TestInstruction::RemoveLiquidity, "remove"),
+            ],
+            window: Duration::from_secs(60),
+        })
+    }
+
+    type Output = HashMap<String, (TestInstruction, Vec<AccountMeta>)>;
+
+    #[test]
+    fn test_sequence_matcher_completes_sequence() {
+        // Arrange
+        let mut matcher = matcher();
+        let signer = Pubkey::new_unique();
+        let other_signer = Pubkey::new_unique();
+
+        // Act
+        let create = matcher.observe::<Output>(
+            signer,
+            Signature::new_unique(),
+            0,
+            &transaction(TestInstruction::Create),
+        );
+        let other = matcher.observe::<Output>(
+            other_signer,
+            Signature::new_unique(),
+            10,
+            &transaction(TestInstruction::AddLiquidity),
+        );
+        let add = matcher.observe::<Output>(
+            signer,
+            Signature::new_unique(),
+            20,
+            &transaction(TestInstruction::AddLiquidity),
+        );
+        let remove = matcher.observe::<Output>(
+            signer,
+            Signature::new_unique(),
+            30,
+            &transaction(TestInstruction::RemoveLiquidity),
+        );
+
+        // Assert
+        assert!(create.is_none());
+        assert!(other.is_none());
+        assert!(add.is_none());
+
+        let sequence = remove.expect("sequence should complete");
+        assert_eq!(sequence.signer, signer);
+        assert_eq!(sequence.signatures.len(), 3);
+        assert_eq!(sequence.data.len(), 3);
+    }
+
+    #[test]
+    fn test_sequence_matcher_expires_after_window() {
+        // Arrange
+        let mut matcher = matcher();
+        let signer = Pubkey::new_unique();
+
+        // Act
+        for (timestamp, instruction) in [
+            (0, TestInstruction::Create),
+            (30, TestInstruction::AddLiquidity),
+        ] {
+            matcher.observe::<Output>(
+                signer,
+                Signature::new_unique(),
+                timestamp,
+                &transaction(instruction),
+            );
+        }
+        let remove = matcher.observe::<Output>(
+            signer,
+            Signature::new_unique(),
+            61,
+            &transaction(TestInstruction::RemoveLiquidity),
+        );
+
+        // Assert
+        assert!(remove.is_none());
+    }
+}
@@ -0,0 +1,286 @@
+//! Defines the output formats of the events written by sinks.
+//!
+//! Sinks build each event as a JSON value, such as the decoded instruction
+//! with its signature and slot, and serialize it with a `SinkSerializer`
+//! before writing it. Sinks accept any serializer through their builder, so
+//! the output format can be changed without changing the sink.
+//!
+//! - `JsonSerializer`: One JSON object per event. This is the default.
+//! - `CsvSerializer`: One CSV record per event.
+//!
+//! Other formats, such as Avro or protobuf, are added by implementing
+//! `SinkSerializer`.
+//!
+//! # Byte encodings
+//!
+//! Decoded types derive `CarbonSerialize`, which serializes them as
+//! `serde::Serialize` would, except for fields marked with
+//! `#[carbon(serialize_as = "base58")]` or `#[carbon(serialize_as = "hex")]`.
+//! Those are written as text with the given `ByteEncoding` instead of arrays
+//! of numbers, so every sink writes pubkeys and byte arrays the same way. The
+//! decoders generated by the CLI mark pubkeys as `base58` and byte arrays as
+//! `hex`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let publisher = NatsPublisher::new(jetstream, "carbon.{program}", carbon_nats_sink::json())
+//!     .with_serializer(CsvSerializer);
+//! ```
+
+use {
+    crate::error::{CarbonResult, Error},
+    serde::{Serialize, Serializer},
+    serde_json::Value,
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::fmt::Write,
+};
+
+/// Serializes the events written by sinks.
+pub trait SinkSerializer: Send + Sync {
+    /// Returns the MIME type of the serialized events, such as
+    /// `application/json`.
+    fn content_type(&self) -> &'static str;
+
+    fn serialize(&self, event: &Value) -> CarbonResult<Vec<u8>>;
+}
+
+/// Serializes each event as a JSON object.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonSerializer;
+
+impl SinkSerializer for JsonSerializer {
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn serialize(&self, event: &Value) -> CarbonResult<Vec<u8>> {
+        serde_json::to_vec(event)
+            .map_err(|error| Error::Custom(format!("Failed to serialize event: {}", error)))
+    }
+}
+
+/// Serializes each event as a CSV record, without a header.
+///
+/// The columns are the top-level fields of the event, sorted by name. Nested
+/// values are written as JSON text and `null` as an empty field.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvSerializer;
+
+impl SinkSerializer for CsvSerializer {
+    fn content_type(&self) -> &'static str {
+        "text/csv"
+    }
+
+    fn serialize(&self, event: &Value) -> CarbonResult<Vec<u8>> {
+        let fields: Vec<String> = match event {
+            Value::Object(map) => {
+                let mut fields: Vec<(&String, &Value)> = map.iter().collect();
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                fields
+                    .into_iter()
+                    .map(|(_, value)| csv_field(value))
+                    .collect()
+            }
+            value => vec![csv_field(value)],
+        };
+
+        let mut record = fields.join(",");
+        record.push('\n');
+
+        Ok(record.into_bytes())
+    }
+}
+
+/// Formats a value as a CSV field: strings as they are, nested values as JSON
+/// text and `null` as an empty field, quoted when needed.
+pub fn csv_field(value: &Value) -> String {
+    let field = match value {
+        Value::Null => return String::new(),
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    };
+
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// The text encodings of the fields marked with
+/// `#[carbon(serialize_as = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteEncoding {
+    /// Base58, as pubkeys and signatures are usually displayed.
+    Base58,
+    /// Lowercase hexadecimal, without a `0x` prefix.
+    Hex,
+}
+
+impl ByteEncoding {
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            ByteEncoding::Base58 => bs58::encode(bytes).into_string(),
+            ByteEncoding::Hex => {
+                bytes
+                    .iter()
+                    .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+                        let _ = write!(hex, "{:02x}", byte);
+                        hex
+                    })
+            }
+        }
+    }
+}
+
+/// Values that `CarbonSerialize` can write with a `ByteEncoding`: pubkeys,
+/// signatures and byte arrays, and options or collections of pubkeys.
+pub trait EncodeBytes {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+impl EncodeBytes for Pubkey {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encoding.encode(self.as_ref()))
+    }
+}
+
+impl EncodeBytes for Signature {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encoding.encode(self.as_ref()))
+    }
+}
+
+impl<const N: usize> EncodeBytes for [u8; N] {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encoding.encode(self))
+    }
+}
+
+impl EncodeBytes for Vec<u8> {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encoding.encode(self))
+    }
+}
+
+impl<const N: usize> EncodeBytes for [Pubkey; N] {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|pubkey| Encoded(pubkey, encoding)))
+    }
+}
+
+impl EncodeBytes for Vec<Pubkey> {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|pubkey| Encoded(pubkey, encoding)))
+    }
+}
+
+impl<T: EncodeBytes> EncodeBytes for Option<T> {
+    fn serialize_encoded<S: Serializer>(
+        &self,
+        encoding: ByteEncoding,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&Encoded(value, encoding)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Serializes a value with a `ByteEncoding`. Used by the code generated by
+/// `CarbonSerialize`.
+pub struct Encoded<'a, T: ?Sized>(pub &'a T, pub ByteEncoding);
+
+impl<T: EncodeBytes + ?Sized> Serialize for Encoded<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_encoded(self.1, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_csv_serializer_sorts_and_escapes_fields() {
+        let event = json!({
+            "slot": 10,
+            "data": { "Buy": { "amount": 1 } },
+            "memo": null,
+            "signature": "abc",
+        });
+
+        let record = CsvSerializer.serialize(&event).unwrap();
+
+        assert_eq!(
+            String::from_utf8(record).unwrap(),
+            "\"{\"\"Buy\"\":{\"\"amount\"\":1}}\",,abc,10\n"
+        );
+    }
+
+    #[test]
+    fn test_json_serializer() {
+        let event = json!({ "slot": 10 });
+
+        assert_eq!(JsonSerializer.serialize(&event).unwrap(), b"{\"slot\":10}");
+    }
+
+    #[test]
+    fn test_encoded_bytes() {
+        let pubkey = Pubkey::new_from_array([1; 32]);
+
+        assert_eq!(
+            serde_json::to_value(Encoded(&pubkey, ByteEncoding::Base58)).unwrap(),
+            json!(pubkey.to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(Encoded(&[0x0a_u8, 0xff], ByteEncoding::Hex)).unwrap(),
+            json!("0aff")
+        );
+        assert_eq!(
+            serde_json::to_value(Encoded(&None::<Pubkey>, ByteEncoding::Base58)).unwrap(),
+            json!(null)
+        );
+        assert_eq!(
+            serde_json::to_value(Encoded(&vec![pubkey], ByteEncoding::Base58)).unwrap(),
+            json!([pubkey.to_string()])
+        );
+
+        let signature = Signature::from([2; 64]);
+        assert_eq!(
+            serde_json::to_value(Encoded(&signature, ByteEncoding::Base58)).unwrap(),
+            json!(signature.to_string())
+        );
+    }
+}
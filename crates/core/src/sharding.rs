@@ -0,0 +1,198 @@
+//! Splits the updates of a datasource across several Carbon instances.
+//!
+//! Indexing a very high-volume program on a single machine can exceed what one
+//! pipeline processes. A `ShardedDatasource` wraps a datasource and only
+//! forwards the updates belonging to its shard, so the same configuration can
+//! run on several machines, each with its own shard index, without any update
+//! being processed twice.
+//!
+//! Updates are assigned to shards deterministically:
+//!
+//! - Account updates and deletions by account pubkey, so every update of an
+//!   account is processed by the same instance.
+//! - Transactions and transaction statuses by signature.
+//! - Block details by slot.
+//!
+//! Pubkeys and signatures are uniformly distributed, so their leading bytes are
+//! used directly, which keeps the assignment stable across Rust versions and
+//! machines.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // On the second of four machines:
+//! let datasource = ShardedDatasource::new(yellowstone_datasource, 1, 4);
+//!
+//! Pipeline::builder()
+//!     .datasource(datasource)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Every instance still receives the full stream from its datasource and
+//!   discards the updates of other shards, reported by the
+//!   `sharding_updates_skipped` counter.
+//! - Changing the shard count reassigns most accounts to other shards.
+
+use {
+    crate::{
+        datasource::{Capabilities, Datasource, Update},
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+    tokio::sync::mpsc,
+    tokio_util::sync::CancellationToken,
+};
+
+/// A datasource forwarding only the updates of one shard.
+pub struct ShardedDatasource<D> {
+    datasource: D,
+    shard_index: u64,
+    shard_count: u64,
+}
+
+impl<D: Datasource> ShardedDatasource<D> {
+    /// Wraps `datasource` to forward the updates of shard `shard_index`, out
+    /// of `shard_count` shards numbered from 0.
+    pub fn new(datasource: D, shard_index: u64, shard_count: u64) -> Self {
+        Self {
+            datasource,
+            shard_index,
+            shard_count,
+        }
+    }
+
+    /// Returns whether the update belongs to this shard. `DatasourceFinished`
+    /// belongs to every shard, so each instance shuts down.
+    pub fn contains(&self, update: &Update) -> bool {
+        matches!(update, Update::DatasourceFinished)
+            || shard_of(update, self.shard_count) == self.shard_index
+    }
+}
+
+/// Returns the shard of `update`, out of `shard_count` shards.
+pub fn shard_of(update: &Update, shard_count: u64) -> u64 {
+    let key = match update {
+        Update::Account(account_update) => bytes_key(account_update.pubkey.as_ref()),
+        Update::AccountDeletion(account_deletion) => bytes_key(account_deletion.pubkey.as_ref()),
+        Update::Transaction(transaction_update) => bytes_key(transaction_update.signature.as_ref()),
+        Update::TransactionStatus(transaction_status) => {
+            bytes_key(transaction_status.signature.as_ref())
+        }
+        // `DatasourceFinished` has no slot, and is forwarded by every shard.
+        Update::BlockDetails(_) | Update::DatasourceFinished => update.slot().unwrap_or_default(),
+    };
+
+    key % shard_count.max(1)
+}
+
+fn bytes_key(bytes: &[u8]) -> u64 {
+    let mut key = [0u8; 8];
+    key.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(key)
+}
+
+#[async_trait]
+impl<D: Datasource> Datasource for ShardedDatasource<D> {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (shard_sender, mut shard_receiver) = mpsc::channel::<Update>(sender.max_capacity());
+
+        let forward = async {
+            while let Some(update) = shard_receiver.recv().await {
+                if !self.contains(&update) {
+                    metrics
+                        .increment_counter("sharding_updates_skipped", 1)
+                        .await?;
+                    continue;
+                }
+
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok::<(), Error>(())
+        };
+
+        let consume = async {
+            let result = self
+                .datasource
+                .consume(&shard_sender, cancellation_token, metrics.clone())
+                .await;
+            drop(shard_sender);
+            result
+        };
+
+        let (consume_result, forward_result) = tokio::join!(consume, forward);
+        consume_result?;
+        forward_result
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.datasource.capabilities()
+    }
+
+    fn validate(&self) -> CarbonResult<()> {
+        if self.shard_count == 0 || self.shard_index >= self.shard_count {
+            return Err(Error::Custom(format!(
+                "Invalid shard {} out of {} shards",
+                self.shard_index, self.shard_count
+            )));
+        }
+
+        self.datasource.validate()
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.datasource.is_bounded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::datasource::{AccountDeletion, BlockDetails},
+        solana_pubkey::Pubkey,
+    };
+
+    fn deletion(pubkey: Pubkey, slot: u64) -> Update {
+        Update::AccountDeletion(AccountDeletion { pubkey, slot })
+    }
+
+    #[test]
+    fn test_account_shard_does_not_depend_on_slot() {
+        let pubkey = Pubkey::new_unique();
+
+        assert_eq!(
+            shard_of(&deletion(pubkey, 1), 8),
+            shard_of(&deletion(pubkey, 2), 8)
+        );
+    }
+
+    #[test]
+    fn test_block_details_sharded_by_slot() {
+        let block_details = Update::BlockDetails(BlockDetails {
+            slot: 10,
+            parent_slot: 9,
+            block_hash: String::new(),
+            previous_block_hash: String::new(),
+            block_height: None,
+            block_time: None,
+            rewards: Vec::new(),
+        });
+
+        assert_eq!(shard_of(&block_details, 4), 2);
+    }
+}
@@ -0,0 +1,292 @@
+//! Shares one datasource between several pipelines.
+//!
+//! Running several indexers on one machine usually means one datasource
+//! connection per pipeline, for example one Yellowstone subscription each.
+//! A `SharedDatasource` wraps a single datasource and hands out
+//! `SharedDatasourceHandle`s, which implement `Datasource` and can be given to
+//! as many pipelines as needed. The wrapped datasource is consumed once, when
+//! the first handle starts, and each update is broadcast to every handle.
+//!
+//! Each pipeline keeps its own decoders, processors and error handling: a
+//! pipeline failing or shutting down does not affect the others. The wrapped
+//! datasource is cancelled once every handle has stopped, and the handles stop
+//! once the wrapped datasource is done.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let shared = SharedDatasource::new(yellowstone_datasource);
+//!
+//! let mut pumpfun = Pipeline::builder()
+//!     .datasource(shared.handle())
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .build()?;
+//! let mut raydium = Pipeline::builder()
+//!     .datasource(shared.handle())
+//!     .instruction(RaydiumAmmV4Decoder, RaydiumProcessor)
+//!     .build()?;
+//!
+//! let (pumpfun_result, raydium_result) = tokio::join!(pumpfun.run(), raydium.run());
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates are only delivered to handles that have started, so the pipelines
+//!   should be run together.
+//! - A pipeline falling more than the broadcast capacity behind skips the
+//!   updates it missed, reported by the `shared_datasource_updates_skipped`
+//!   counter, instead of slowing the other pipelines down.
+//! - The wrapped datasource reports its metrics to the pipeline whose handle
+//!   started it.
+
+use {
+    crate::{
+        datasource::{Capabilities, Datasource, Update},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    std::sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    tokio::sync::{
+        broadcast::{self, error::RecvError},
+        mpsc,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default number of updates buffered for each handle.
+pub const DEFAULT_SHARED_DATASOURCE_CAPACITY: usize = 10_000;
+
+/// A datasource consumed once and shared by several pipelines.
+pub struct SharedDatasource {
+    inner: Arc<SharedDatasourceInner>,
+}
+
+struct SharedDatasourceInner {
+    datasource: Arc<dyn Datasource>,
+    sender: broadcast::Sender<Update>,
+    capacity: usize,
+    started: AtomicBool,
+    active_handles: AtomicUsize,
+    cancellation_token: CancellationToken,
+}
+
+impl SharedDatasource {
+    pub fn new(datasource: impl Datasource + 'static) -> Self {
+        Self::with_capacity(datasource, DEFAULT_SHARED_DATASOURCE_CAPACITY)
+    }
+
+    /// Creates a shared datasource buffering up to `capacity` updates for each
+    /// handle.
+    pub fn with_capacity(datasource: impl Datasource + 'static, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self {
+            inner: Arc::new(SharedDatasourceInner {
+                datasource: Arc::new(datasource),
+                sender,
+                capacity,
+                started: AtomicBool::new(false),
+                active_handles: AtomicUsize::new(0),
+                cancellation_token: CancellationToken::new(),
+            }),
+        }
+    }
+
+    /// Returns a new handle to pass to a pipeline as its datasource.
+    pub fn handle(&self) -> SharedDatasourceHandle {
+        SharedDatasourceHandle {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl SharedDatasourceInner {
+    /// Consumes the wrapped datasource and broadcasts its updates, unless this
+    /// was already done by another handle.
+    fn start(&self, metrics: Arc<MetricsCollection>) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let (sender, mut receiver) = mpsc::channel::<Update>(self.capacity);
+
+        let datasource = self.datasource.clone();
+        let cancellation_token = self.cancellation_token.clone();
+        tokio::spawn(async move {
+            if let Err(error) = datasource
+                .consume(&sender, cancellation_token, metrics)
+                .await
+            {
+                log::error!("Shared datasource error: {:?}", error);
+            }
+        });
+
+        let broadcast_sender = self.sender.clone();
+        let cancellation_token = self.cancellation_token.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    update = receiver.recv() => match update {
+                        // Sending only fails when no handle is listening.
+                        Some(update) => {
+                            let _ = broadcast_sender.send(update);
+                        }
+                        None => break,
+                    },
+                }
+            }
+
+            // Stops the handles once the wrapped datasource is done.
+            cancellation_token.cancel();
+        });
+    }
+}
+
+/// A handle consuming the updates of a `SharedDatasource`.
+pub struct SharedDatasourceHandle {
+    inner: Arc<SharedDatasourceInner>,
+}
+
+impl SharedDatasourceHandle {
+    /// Forwards broadcast updates to the pipeline until either side stops.
+    async fn forward(
+        &self,
+        mut receiver: broadcast::Receiver<Update>,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        loop {
+            // Updates already broadcast are received before noticing that the
+            // wrapped datasource is done, so the handle forwards all of them.
+            tokio::select! {
+                biased;
+                _ = cancellation_token.cancelled() => return Ok(()),
+                update = receiver.recv() => match update {
+                    Ok(update) => {
+                        if sender.send(update).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!("Shared datasource handle skipped {} updates", skipped);
+                        metrics
+                            .increment_counter("shared_datasource_updates_skipped", skipped)
+                            .await?;
+                    }
+                    Err(RecvError::Closed) => return Ok(()),
+                },
+                _ = self.inner.cancellation_token.cancelled() => return Ok(()),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Datasource for SharedDatasourceHandle {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let receiver = self.inner.sender.subscribe();
+        self.inner.active_handles.fetch_add(1, Ordering::SeqCst);
+        self.inner.start(metrics.clone());
+
+        let result = self
+            .forward(receiver, sender, cancellation_token, metrics)
+            .await;
+
+        if self.inner.active_handles.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.cancellation_token.cancel();
+        }
+
+        result
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.datasource.capabilities()
+    }
+
+    fn validate(&self) -> CarbonResult<()> {
+        self.inner.datasource.validate()
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.inner.datasource.is_bounded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::datasource::AccountDeletion, solana_pubkey::Pubkey};
+
+    /// Sends a number of account deletions, then finishes.
+    struct Bounded(u64);
+
+    #[async_trait]
+    impl Datasource for Bounded {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in 0..self.0 {
+                let update = Update::AccountDeletion(AccountDeletion {
+                    pubkey: Pubkey::new_unique(),
+                    slot,
+                });
+                let _ = sender.send(update).await;
+            }
+            let _ = sender.send(Update::DatasourceFinished).await;
+
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handles_forward_the_tail_of_a_finished_datasource() {
+        for _ in 0..20 {
+            // Arrange
+            let shared = SharedDatasource::new(Bounded(100));
+            let handles = [shared.handle(), shared.handle()];
+            let metrics = Arc::new(MetricsCollection::new(vec![]));
+            let (sender, mut receiver) = mpsc::channel(1_000);
+
+            // Act
+            let results =
+                futures::future::join_all(handles.iter().map(|handle| {
+                    handle.consume(&sender, CancellationToken::new(), metrics.clone())
+                }))
+                .await;
+            drop(sender);
+            let mut updates = Vec::new();
+            while let Some(update) = receiver.recv().await {
+                updates.push(update);
+            }
+
+            // Assert
+            assert!(results.iter().all(Result::is_ok));
+            assert_eq!(updates.len(), 2 * 101);
+            assert_eq!(
+                updates
+                    .iter()
+                    .filter(|update| matches!(update, Update::DatasourceFinished))
+                    .count(),
+                2
+            );
+        }
+    }
+}
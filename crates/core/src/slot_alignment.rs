@@ -0,0 +1,377 @@
+//! Merges several datasources into one stream of updates ordered by slot.
+//!
+//! Datasources merged into a pipeline deliver their updates independently:
+//! two gRPC regions lag behind each other, and a block datasource delivers a
+//! slot after the transactions of a transaction datasource. The pipeline then
+//! sees slots going back and forth, which breaks processors relying on slot
+//! monotonicity.
+//!
+//! A `SlotAlignedDatasource` consumes several datasources and passes their
+//! updates on through a `SlotAlignmentBuffer`, which holds each update until
+//! updates `skew_slots` slots newer were received, and releases the held
+//! updates sorted by slot. Datasources lagging behind each other by up to the
+//! skew window are thus put back in order.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let datasource = SlotAlignedDatasource::new(4)
+//!     .datasource(yellowstone_frankfurt)
+//!     .datasource(yellowstone_new_york);
+//!
+//! Pipeline::builder()
+//!     .datasource(datasource)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Updates of the same slot are released in the order they were received.
+//! - Updates of a slot older than the last released slot arrived too late to
+//!   be aligned: they are passed on immediately, and counted by the
+//!   `slot_alignment_late_updates` counter.
+//! - The buffer is bounded: once it holds `capacity` updates, its oldest
+//!   slots are released before the skew window has passed. Slots held longer
+//!   than `max_hold` are released too, so a stalled stream does not hold
+//!   updates back forever.
+//! - Updates are not deduplicated: merge copies of the same stream with a
+//!   deduplicating datasource, such as `YellowstoneGrpcRace`, instead.
+//! - The merged datasource finishes once every datasource has sent
+//!   `Update::DatasourceFinished`, after releasing the held updates.
+
+use {
+    crate::{
+        datasource::{Capabilities, Datasource, Update},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    std::{
+        collections::BTreeMap,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    tokio::sync::mpsc,
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default number of updates a `SlotAlignmentBuffer` holds at most.
+pub const DEFAULT_SLOT_ALIGNMENT_CAPACITY: usize = 10_000;
+
+/// The default time a slot is held at most.
+pub const DEFAULT_SLOT_ALIGNMENT_MAX_HOLD: Duration = Duration::from_secs(2);
+
+/// A bounded buffer reordering updates by slot within a skew window.
+pub struct SlotAlignmentBuffer {
+    skew_slots: u64,
+    capacity: usize,
+    max_hold: Duration,
+    pending: BTreeMap<u64, (Instant, Vec<Update>)>,
+    len: usize,
+    highest_slot: Option<u64>,
+    released_slot: Option<u64>,
+}
+
+impl SlotAlignmentBuffer {
+    /// Creates a buffer holding each update until updates `skew_slots` slots
+    /// newer were received.
+    pub fn new(skew_slots: u64) -> Self {
+        Self {
+            skew_slots,
+            capacity: DEFAULT_SLOT_ALIGNMENT_CAPACITY,
+            max_hold: DEFAULT_SLOT_ALIGNMENT_MAX_HOLD,
+            pending: BTreeMap::new(),
+            len: 0,
+            highest_slot: None,
+            released_slot: None,
+        }
+    }
+
+    /// Sets the number of updates held at most. Defaults to 10,000.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets the time a slot is held at most. Defaults to 2 seconds.
+    pub fn max_hold(mut self, max_hold: Duration) -> Self {
+        self.max_hold = max_hold;
+        self
+    }
+
+    /// Returns the number of updates held.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether an update of `slot` arrives too late to be aligned,
+    /// its slot being older than the last released slot.
+    pub fn is_late(&self, slot: u64) -> bool {
+        self.released_slot.is_some_and(|released| slot < released)
+    }
+
+    /// Holds `update` received at `now`, returning the updates released in
+    /// order. Late updates and updates without a slot are returned at once.
+    pub fn push(&mut self, update: Update, now: Instant) -> Vec<Update> {
+        let Some(slot) = update.slot().filter(|slot| !self.is_late(*slot)) else {
+            return vec![update];
+        };
+
+        self.pending
+            .entry(slot)
+            .or_insert_with(|| (now, Vec::new()))
+            .1
+            .push(update);
+        self.len += 1;
+        self.highest_slot = self.highest_slot.max(Some(slot));
+
+        let mut released = match self.highest_slot {
+            Some(highest_slot) => self.release_until(highest_slot.saturating_sub(self.skew_slots)),
+            None => Vec::new(),
+        };
+        while self.len > self.capacity {
+            let Some((slot, (_, updates))) = self.pending.pop_first() else {
+                break;
+            };
+            self.len -= updates.len();
+            self.released_slot = Some(slot);
+            released.extend(updates);
+        }
+
+        released
+    }
+
+    /// Releases in order the slots held since longer than `max_hold` at
+    /// `now`, along with the older slots.
+    pub fn release_expired(&mut self, now: Instant) -> Vec<Update> {
+        let expired = self
+            .pending
+            .iter()
+            .rev()
+            .find(|(_, (held_since, _))| {
+                now.saturating_duration_since(*held_since) >= self.max_hold
+            })
+            .map(|(slot, _)| *slot);
+
+        match expired {
+            Some(slot) => self.release_until(slot),
+            None => Vec::new(),
+        }
+    }
+
+    /// Releases every held update in order.
+    pub fn drain(&mut self) -> Vec<Update> {
+        self.release_until(u64::MAX)
+    }
+
+    /// Releases in order the updates of the slots up to `slot`.
+    fn release_until(&mut self, slot: u64) -> Vec<Update> {
+        let mut released = Vec::new();
+
+        while let Some(entry) = self.pending.first_entry() {
+            if *entry.key() > slot {
+                break;
+            }
+            let (slot, (_, updates)) = entry.remove_entry();
+            self.len -= updates.len();
+            self.released_slot = Some(slot);
+            released.extend(updates);
+        }
+
+        released
+    }
+}
+
+/// A datasource merging the updates of several datasources in slot order.
+pub struct SlotAlignedDatasource {
+    datasources: Vec<Arc<dyn Datasource>>,
+    skew_slots: u64,
+    capacity: usize,
+    max_hold: Duration,
+}
+
+impl SlotAlignedDatasource {
+    /// Creates a datasource aligning the updates of its datasources within a
+    /// window of `skew_slots` slots.
+    pub fn new(skew_slots: u64) -> Self {
+        Self {
+            datasources: Vec::new(),
+            skew_slots,
+            capacity: DEFAULT_SLOT_ALIGNMENT_CAPACITY,
+            max_hold: DEFAULT_SLOT_ALIGNMENT_MAX_HOLD,
+        }
+    }
+
+    /// Adds a datasource to merge.
+    pub fn datasource(mut self, datasource: impl Datasource + 'static) -> Self {
+        self.datasources.push(Arc::new(datasource));
+        self
+    }
+
+    /// Sets the number of updates held at most. Defaults to 10,000.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets the time a slot is held at most. Defaults to 2 seconds.
+    pub fn max_hold(mut self, max_hold: Duration) -> Self {
+        self.max_hold = max_hold;
+        self
+    }
+}
+
+/// Sends the released updates to the pipeline, returning whether it is still
+/// receiving.
+async fn send_all(sender: &mpsc::Sender<Update>, updates: Vec<Update>) -> bool {
+    for update in updates {
+        if sender.send(update).await.is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[async_trait]
+impl Datasource for SlotAlignedDatasource {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (inner_sender, mut receiver) = mpsc::channel::<Update>(self.capacity);
+
+        for (index, datasource) in self.datasources.iter().enumerate() {
+            let datasource = datasource.clone();
+            let inner_sender = inner_sender.clone();
+            let cancellation_token = cancellation_token.clone();
+            let metrics = metrics.clone();
+            tokio::spawn(async move {
+                if let Err(error) = datasource
+                    .consume(&inner_sender, cancellation_token, metrics)
+                    .await
+                {
+                    log::error!("Aligned datasource {} error: {:?}", index, error);
+                }
+            });
+        }
+        drop(inner_sender);
+
+        let mut buffer = SlotAlignmentBuffer::new(self.skew_slots)
+            .capacity(self.capacity)
+            .max_hold(self.max_hold);
+        let mut expiry = tokio::time::interval(self.max_hold.max(Duration::from_millis(10)));
+        let mut finished_datasources = 0;
+
+        loop {
+            let released = tokio::select! {
+                _ = cancellation_token.cancelled() => return Ok(()),
+                _ = expiry.tick() => buffer.release_expired(Instant::now()),
+                update = receiver.recv() => match update {
+                    Some(Update::DatasourceFinished) => {
+                        finished_datasources += 1;
+                        if finished_datasources < self.datasources.len() {
+                            continue;
+                        }
+
+                        if send_all(sender, buffer.drain()).await {
+                            let _ = sender.send(Update::DatasourceFinished).await;
+                        }
+                        return Ok(());
+                    }
+                    Some(update) => {
+                        if update.slot().is_some_and(|slot| buffer.is_late(slot)) {
+                            metrics
+                                .increment_counter("slot_alignment_late_updates", 1)
+                                .await?;
+                        }
+                        buffer.push(update, Instant::now())
+                    }
+                    None => {
+                        send_all(sender, buffer.drain()).await;
+                        return Ok(());
+                    }
+                },
+            };
+
+            if !send_all(sender, released).await {
+                return Ok(());
+            }
+            metrics
+                .update_gauge("slot_alignment_buffered", buffer.len() as f64)
+                .await?;
+        }
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.datasources
+            .iter()
+            .fold(Capabilities::new(), |capabilities, datasource| {
+                capabilities.union(datasource.capabilities())
+            })
+    }
+
+    fn validate(&self) -> CarbonResult<()> {
+        self.datasources
+            .iter()
+            .try_for_each(|datasource| datasource.validate())
+    }
+
+    /// Finishes once every wrapped datasource finished.
+    fn is_bounded(&self) -> bool {
+        self.datasources
+            .iter()
+            .all(|datasource| datasource.is_bounded())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::datasource::TransactionStatusUpdate, solana_signature::Signature};
+
+    fn update(slot: u64) -> Update {
+        Update::TransactionStatus(TransactionStatusUpdate {
+            signature: Signature::default(),
+            slot,
+            is_vote: false,
+            err: None,
+        })
+    }
+
+    fn slots(updates: Vec<Update>) -> Vec<u64> {
+        updates.iter().filter_map(Update::slot).collect()
+    }
+
+    #[test]
+    fn test_buffer_aligns_updates_within_skew_window() {
+        // Arrange
+        let mut buffer = SlotAlignmentBuffer::new(2).capacity(4);
+        let now = Instant::now();
+
+        // Act
+        let first = buffer.push(update(10), now);
+        let second = buffer.push(update(12), now);
+        let third = buffer.push(update(11), now);
+        let fourth = buffer.push(update(14), now);
+        let late = buffer.push(update(9), now);
+        let expired = buffer.release_expired(now + DEFAULT_SLOT_ALIGNMENT_MAX_HOLD);
+
+        // Assert
+        assert!(first.is_empty());
+        assert_eq!(slots(second), vec![10]);
+        assert!(third.is_empty());
+        assert_eq!(slots(fourth), vec![11, 12]);
+        assert_eq!(slots(late), vec![9]);
+        assert_eq!(slots(expired), vec![14]);
+        assert!(buffer.is_empty());
+    }
+}
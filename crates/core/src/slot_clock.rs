@@ -0,0 +1,151 @@
+//! Computes the epoch and the estimated time of a slot from the parameters of
+//! the cluster, without RPC calls.
+//!
+//! With `PipelineBuilder::cluster_schedule`, every `TransactionMetadata` gets
+//! a `SlotClock` for its slot: the epoch, the index of the slot within the
+//! epoch, and an estimate of its wall-clock time. Instruction processors read
+//! it through `InstructionMetadata::slot_clock`, for example to bucket events
+//! by epoch.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(StakeProgramDecoder, EpochRewardsProcessor)
+//!     .cluster_schedule(ClusterSchedule::mainnet())
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! // In the processor:
+//! let epoch = metadata.slot_clock().map(|clock| clock.epoch);
+//! ```
+//!
+//! # Notes
+//!
+//! - The time of a slot is estimated from the target slot duration, counted
+//!   from the genesis or from a known `(slot, unix_timestamp)` anchor. Slots
+//!   are often slower than the target, so the estimate drifts the further the
+//!   slot is from the anchor. Anchor the schedule near the indexed slots, or
+//!   prefer the `block_time` of the transaction when it is known.
+
+use {solana_sdk::epoch_schedule::EpochSchedule, std::time::Duration};
+
+/// The creation time of the mainnet-beta genesis block, in seconds since the
+/// Unix epoch.
+pub const MAINNET_GENESIS_UNIX_TIMESTAMP: i64 = 1_584_368_940;
+
+/// The target duration of a slot.
+pub const DEFAULT_SLOT_DURATION: Duration = Duration::from_millis(400);
+
+/// The epoch and estimated time of a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotClock {
+    pub slot: u64,
+    pub epoch: u64,
+    /// The index of the slot within its epoch.
+    pub slot_index: u64,
+    pub slots_in_epoch: u64,
+    /// The estimated time of the slot, in seconds since the Unix epoch.
+    pub estimated_unix_timestamp: i64,
+}
+
+/// The parameters of a cluster needed to compute the `SlotClock` of a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClusterSchedule {
+    pub epoch_schedule: EpochSchedule,
+    pub slot_duration: Duration,
+    /// A slot of known time, and its time in seconds since the Unix epoch.
+    pub anchor: (u64, i64),
+}
+
+impl ClusterSchedule {
+    /// Creates a schedule for a cluster created at `genesis_unix_timestamp`.
+    pub fn new(epoch_schedule: EpochSchedule, genesis_unix_timestamp: i64) -> Self {
+        Self {
+            epoch_schedule,
+            slot_duration: DEFAULT_SLOT_DURATION,
+            anchor: (0, genesis_unix_timestamp),
+        }
+    }
+
+    /// The schedule of mainnet-beta: 432,000 slots per epoch, without warmup.
+    pub fn mainnet() -> Self {
+        Self::new(
+            EpochSchedule::without_warmup(),
+            MAINNET_GENESIS_UNIX_TIMESTAMP,
+        )
+    }
+
+    pub fn slot_duration(mut self, slot_duration: Duration) -> Self {
+        self.slot_duration = slot_duration;
+        self
+    }
+
+    /// Estimates slot times from `slot`, known to have happened at
+    /// `unix_timestamp`, rather than from the genesis.
+    pub fn anchor(mut self, slot: u64, unix_timestamp: i64) -> Self {
+        self.anchor = (slot, unix_timestamp);
+        self
+    }
+
+    /// Returns the `SlotClock` of `slot`.
+    pub fn slot_clock(&self, slot: u64) -> SlotClock {
+        let (epoch, slot_index) = self.epoch_schedule.get_epoch_and_slot_index(slot);
+
+        let (anchor_slot, anchor_unix_timestamp) = self.anchor;
+        let slot_duration_millis = self.slot_duration.as_millis() as i128;
+        let offset_millis = (slot as i128 - anchor_slot as i128) * slot_duration_millis;
+        let estimated_unix_timestamp =
+            anchor_unix_timestamp + offset_millis.div_euclid(1_000) as i64;
+
+        SlotClock {
+            slot,
+            epoch,
+            slot_index,
+            slots_in_epoch: self.epoch_schedule.get_slots_in_epoch(epoch),
+            estimated_unix_timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mainnet_slot_clock() {
+        // Arrange
+        let schedule = ClusterSchedule::mainnet();
+
+        // Act
+        let slot_clock = schedule.slot_clock(432_000 * 700 + 1_234);
+
+        // Assert
+        assert_eq!(
+            slot_clock,
+            SlotClock {
+                slot: 302_401_234,
+                epoch: 700,
+                slot_index: 1_234,
+                slots_in_epoch: 432_000,
+                estimated_unix_timestamp: MAINNET_GENESIS_UNIX_TIMESTAMP + 120_960_493,
+            }
+        );
+    }
+
+    #[test]
+    fn test_anchored_slot_clock() {
+        let schedule = ClusterSchedule::mainnet().anchor(300_000_000, 1_730_000_000);
+
+        assert_eq!(
+            schedule.slot_clock(300_000_010).estimated_unix_timestamp,
+            1_730_000_004
+        );
+        assert_eq!(
+            schedule.slot_clock(299_999_999).estimated_unix_timestamp,
+            1_729_999_999
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Detects when a slot range has been fully processed.
+//!
+//! `PipelineBuilder::slot_range` stops the pipeline once the updates of the
+//! last slot of its range have been processed. A bounded datasource, such as a
+//! block crawler given an end slot, reports it by finishing. Other
+//! datasources keep sending updates past the range, and may send them out of
+//! order, as the block crawler fetches several blocks at once: an update past
+//! the end of the range does not mean the range is complete.
+//!
+//! A `SlotRangeWatermark` follows the chain of the blocks of the range from
+//! their `BlockDetails`, each block linking to its parent. The watermark is the
+//! last block reached by following the chain from the start of the range, so
+//! skipped slots are accounted for, and blocks received early are held until
+//! the chain reaches them. Once the chain goes past the end of the range, every
+//! block of the range has been received.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(RpcBlockCrawler::new(rpc_url, 320_000_000, None, ...))
+//!     .slot_range(320_000_000, 320_100_000)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Datasources are expected to send the updates of a block right after its
+//!   `BlockDetails`, as the block crawler does. The range is complete when a
+//!   block is received after the chain went past the end, or when the block
+//!   taking the chain past the end is itself past the end.
+//! - Without `BlockDetails`, only the datasources finishing stop the
+//!   pipeline.
+
+use {
+    crate::datasource::BlockDetails,
+    std::{collections::HashMap, ops::RangeInclusive},
+};
+
+/// Follows the chain of the blocks of a slot range, to tell when the range has
+/// been fully received.
+#[derive(Debug, Clone)]
+pub struct SlotRangeWatermark {
+    range: RangeInclusive<u64>,
+    /// The last block reached from the start of the range.
+    watermark: Option<u64>,
+    /// The blocks received before their parent, by parent slot.
+    pending: HashMap<u64, u64>,
+}
+
+impl SlotRangeWatermark {
+    pub fn new(range: RangeInclusive<u64>) -> Self {
+        Self {
+            range,
+            watermark: None,
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn range(&self) -> &RangeInclusive<u64> {
+        &self.range
+    }
+
+    /// Returns the last block reached from the start of the range, if any.
+    pub fn watermark(&self) -> Option<u64> {
+        self.watermark
+    }
+
+    /// Returns `true` if the chain of blocks went past the end of the range.
+    pub fn is_past_end(&self) -> bool {
+        self.watermark
+            .is_some_and(|watermark| watermark > *self.range.end())
+    }
+
+    /// Takes the details of the next block received, returning `true` if the
+    /// range is complete: every block of the range, and the updates sent
+    /// after their details, have been received.
+    pub fn push(&mut self, block_details: &BlockDetails) -> bool {
+        // The updates of the block that took the chain past the end were
+        // received before this block.
+        if self.is_past_end() {
+            return true;
+        }
+
+        let (slot, parent_slot) = (block_details.slot, block_details.parent_slot);
+        if slot < *self.range.start() {
+            return false;
+        }
+
+        let connects = match self.watermark {
+            Some(watermark) => parent_slot == watermark,
+            None => parent_slot < *self.range.start(),
+        };
+        if !connects {
+            self.pending.insert(parent_slot, slot);
+            return false;
+        }
+
+        let mut watermark = slot;
+        while let Some(child) = self.pending.remove(&watermark) {
+            watermark = child;
+        }
+        self.watermark = Some(watermark);
+
+        // A block past the end has no updates of the range to wait for.
+        self.is_past_end() && slot > *self.range.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            account::{AccountDecoder, AccountProcessorInputType, DecodedAccount},
+            datasource::{AccountUpdate, Capabilities, Capability, Datasource, Update},
+            error::CarbonResult,
+            metrics::MetricsCollection,
+            pipeline::Pipeline,
+            processor::Processor,
+        },
+        async_trait::async_trait,
+        solana_account::Account,
+        solana_pubkey::Pubkey,
+        std::sync::{Arc, Mutex},
+        tokio::sync::mpsc,
+        tokio_util::sync::CancellationToken,
+    };
+
+    fn block(slot: u64, parent_slot: u64) -> BlockDetails {
+        BlockDetails {
+            slot,
+            parent_slot,
+            block_hash: String::new(),
+            previous_block_hash: String::new(),
+            block_height: None,
+            block_time: None,
+            rewards: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_range_completes_once_every_block_is_received() {
+        // Arrange
+        let mut watermark = SlotRangeWatermark::new(10..=13);
+
+        // Act
+        // Slot 12 is skipped, and blocks 13 and 14 arrive before block 11.
+        let completed = [
+            watermark.push(&block(10, 9)),
+            watermark.push(&block(14, 13)),
+            watermark.push(&block(13, 11)),
+            watermark.push(&block(11, 10)),
+            watermark.push(&block(15, 14)),
+        ];
+
+        // Assert
+        assert_eq!(completed, [false, false, false, false, true]);
+        assert_eq!(watermark.watermark(), Some(14));
+    }
+
+    #[test]
+    fn test_range_completes_on_the_block_past_its_end() {
+        // Arrange
+        let mut watermark = SlotRangeWatermark::new(10..=11);
+
+        // Act
+        let completed = [
+            watermark.push(&block(10, 8)),
+            watermark.push(&block(11, 10)),
+            watermark.push(&block(12, 11)),
+        ];
+
+        // Assert
+        assert_eq!(completed, [false, false, true]);
+    }
+
+    /// Sends its updates, then waits to be cancelled, as a live datasource.
+    struct Live(Vec<Update>);
+
+    #[async_trait]
+    impl Datasource for Live {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for update in &self.0 {
+                let _ = sender.send(update.clone()).await;
+            }
+            cancellation_token.cancelled().await;
+
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new()
+                .with(Capability::AccountUpdates)
+                .with(Capability::BlockDetails)
+        }
+    }
+
+    struct AnyAccount;
+
+    impl AccountDecoder<'_> for AnyAccount {
+        type AccountType = ();
+
+        fn decode_account(&self, account: &Account) -> Option<DecodedAccount<()>> {
+            Some(DecodedAccount {
+                lamports: account.lamports,
+                data: (),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+        }
+    }
+
+    struct Slots(Arc<Mutex<Vec<u64>>>);
+
+    #[async_trait]
+    impl Processor for Slots {
+        type InputType = AccountProcessorInputType<()>;
+
+        async fn process(
+            &mut self,
+            (metadata, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(metadata.slot);
+            Ok(())
+        }
+    }
+
+    fn account(slot: u64) -> Update {
+        Update::Account(AccountUpdate {
+            pubkey: Pubkey::new_unique(),
+            account: Account::default(),
+            slot,
+            write_version: None,
+            commitment: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_stops_once_its_slot_range_is_processed() {
+        // Arrange
+        let slots = Arc::new(Mutex::new(Vec::new()));
+        let datasource = Live(vec![
+            Update::BlockDetails(block(10, 9)),
+            account(10),
+            Update::BlockDetails(block(12, 11)),
+            account(12),
+            Update::BlockDetails(block(11, 10)),
+            account(11),
+            Update::BlockDetails(block(13, 12)),
+            account(13),
+        ]);
+        let mut pipeline = Pipeline::builder()
+            .datasource(datasource)
+            .account(AnyAccount, Slots(slots.clone()))
+            .slot_range(10, 12)
+            .build()
+            .unwrap();
+
+        // Act
+        pipeline.run().await.unwrap();
+
+        // Assert
+        assert_eq!(*slots.lock().unwrap(), vec![10, 12, 11]);
+    }
+}
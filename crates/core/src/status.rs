@@ -0,0 +1,403 @@
+//! Tracks the status of a running pipeline, for health checks and status
+//! endpoints.
+//!
+//! Every pipeline keeps a `PipelineStatus`, updated as it processes updates:
+//! the last processed slot, the lag measured by the `LagMonitor`, the number
+//! of updates each pipe processed and failed, and a summary of the most
+//! recent errors. `Pipeline::status` and `PipelineHandle::status` return a
+//! handle to it, and `PipelineStatus::report` a `StatusReport` snapshot,
+//! serializable to JSON.
+//!
+//! With the `status-server` feature, `PipelineBuilder::status_server` serves
+//! the report over HTTP, with `/healthz` and `/readyz` probes.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let handle = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .named("pumpfun")
+//!     .build()?
+//!     .spawn();
+//!
+//! let report = handle.status().report();
+//! log::info!(
+//!     "slot {:?}, pumpfun processed {}",
+//!     report.last_processed_slot,
+//!     report.pipes["pumpfun"].processed
+//! );
+//! ```
+//!
+//! # Notes
+//!
+//! - Pipes are counted by the name given in `PipelineDescription`, once per
+//!   update they run on. Instruction pipes run once per instruction.
+//! - Errors are summarized by message. Only the `MAX_ERROR_SUMMARIES` most
+//!   recently seen messages are kept.
+
+use {
+    crate::{
+        clock::Clock,
+        description::{PipeKind, PipelineDescription},
+        lag::PipelineLag,
+        supervision::{DatasourceHealth, PauseState, TaskHealth},
+    },
+    serde::Serialize,
+    std::{
+        collections::{BTreeMap, HashMap},
+        sync::{Arc, RwLock},
+    },
+};
+
+/// The number of distinct error messages kept in a `StatusReport`.
+pub const MAX_ERROR_SUMMARIES: usize = 20;
+
+/// The stage of the pipeline's lifecycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineState {
+    /// The pipeline was built but its processors are not set up yet.
+    #[default]
+    Starting,
+    Running,
+    Stopped,
+}
+
+/// The number of updates a pipe processed, and how many of them failed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PipeCounters {
+    pub processed: u64,
+    pub failed: u64,
+}
+
+/// The occurrences of an error message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorSummary {
+    pub message: String,
+    pub count: u64,
+    /// When the error was first and last seen, in seconds since the Unix
+    /// epoch.
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+/// The health of a datasource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DatasourceStatus {
+    pub name: String,
+    pub health: TaskHealth,
+}
+
+/// A snapshot of the status of a pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusReport {
+    pub state: PipelineState,
+    pub paused: bool,
+    pub last_processed_slot: Option<u64>,
+    /// The last lag measured by the `LagMonitor`, if configured.
+    pub lag: Option<PipelineLag>,
+    pub updates_processed: u64,
+    pub updates_failed: u64,
+    pub datasources: Vec<DatasourceStatus>,
+    /// The counters of each pipe, by name.
+    pub pipes: BTreeMap<String, PipeCounters>,
+    /// The most recent errors, the last seen first.
+    pub errors: Vec<ErrorSummary>,
+}
+
+impl StatusReport {
+    /// Returns whether the pipeline is alive: it has not stopped, and not
+    /// every datasource failed.
+    pub fn is_live(&self) -> bool {
+        self.state != PipelineState::Stopped
+            && (self.datasources.is_empty()
+                || !self
+                    .datasources
+                    .iter()
+                    .all(|datasource| matches!(datasource.health, TaskHealth::Failed(_))))
+    }
+
+    /// Returns whether the pipeline is processing updates: it is running, not
+    /// paused, and every datasource is running or finished.
+    pub fn is_ready(&self) -> bool {
+        self.state == PipelineState::Running
+            && !self.paused
+            && self.datasources.iter().all(|datasource| {
+                matches!(
+                    datasource.health,
+                    TaskHealth::Running { .. } | TaskHealth::Finished
+                )
+            })
+    }
+}
+
+#[derive(Default)]
+struct StatusState {
+    state: PipelineState,
+    datasource_names: Vec<String>,
+    pipe_names: HashMap<PipeKind, Vec<String>>,
+    pipes: BTreeMap<String, PipeCounters>,
+    last_processed_slot: Option<u64>,
+    lag: Option<PipelineLag>,
+    updates_processed: u64,
+    updates_failed: u64,
+    errors: Vec<ErrorSummary>,
+}
+
+/// The status of a pipeline, shared between the pipeline updating it and the
+/// handles reading it.
+#[derive(Clone)]
+pub struct PipelineStatus {
+    state: Arc<RwLock<StatusState>>,
+    datasource_health: DatasourceHealth,
+    pause_state: PauseState,
+    clock: Arc<dyn Clock>,
+}
+
+impl PipelineStatus {
+    pub(crate) fn new(
+        datasource_health: DatasourceHealth,
+        pause_state: PauseState,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(StatusState::default())),
+            datasource_health,
+            pause_state,
+            clock,
+        }
+    }
+
+    /// Returns a snapshot of the status.
+    pub fn report(&self) -> StatusReport {
+        let health = self
+            .datasource_health
+            .read()
+            .map(|health| health.clone())
+            .unwrap_or_default();
+        let paused = *self.pause_state.borrow();
+
+        let state = self
+            .state
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let datasources = health
+            .into_iter()
+            .enumerate()
+            .map(|(index, health)| DatasourceStatus {
+                name: state
+                    .datasource_names
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| format!("datasource_{}", index)),
+                health,
+            })
+            .collect();
+
+        let mut errors = state.errors.clone();
+        errors.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+
+        StatusReport {
+            state: state.state,
+            paused,
+            last_processed_slot: state.last_processed_slot,
+            lag: state.lag,
+            updates_processed: state.updates_processed,
+            updates_failed: state.updates_failed,
+            datasources,
+            pipes: state.pipes.clone(),
+            errors,
+        }
+    }
+
+    /// Marks the pipeline as running, with the names of its components.
+    pub(crate) fn start(&self, description: &PipelineDescription) {
+        self.update(|state| {
+            state.state = PipelineState::Running;
+            state.datasource_names = description
+                .datasources
+                .iter()
+                .map(|datasource| datasource.name.clone())
+                .collect();
+            state.pipe_names.clear();
+            for pipe in &description.pipes {
+                state
+                    .pipe_names
+                    .entry(pipe.kind)
+                    .or_default()
+                    .push(pipe.name.clone());
+                state.pipes.entry(pipe.name.clone()).or_default();
+            }
+        });
+    }
+
+    pub(crate) fn stop(&self) {
+        self.update(|state| state.state = PipelineState::Stopped);
+    }
+
+    /// Counts a processed update of `slot`, if it has one, and its error if it
+    /// failed.
+    pub(crate) fn record_update(&self, slot: Option<u64>, error: Option<String>) {
+        let now = self.clock.unix_timestamp();
+
+        self.update(|state| {
+            state.updates_processed += 1;
+            state.last_processed_slot = state.last_processed_slot.max(slot);
+
+            let Some(message) = error else {
+                return;
+            };
+            state.updates_failed += 1;
+
+            match state
+                .errors
+                .iter_mut()
+                .find(|summary| summary.message == message)
+            {
+                Some(summary) => {
+                    summary.count += 1;
+                    summary.last_seen = now;
+                }
+                None => {
+                    if state.errors.len() >= MAX_ERROR_SUMMARIES {
+                        if let Some(oldest) = state
+                            .errors
+                            .iter()
+                            .enumerate()
+                            .min_by_key(|(_, summary)| summary.last_seen)
+                            .map(|(index, _)| index)
+                        {
+                            state.errors.swap_remove(oldest);
+                        }
+                    }
+                    state.errors.push(ErrorSummary {
+                        message,
+                        count: 1,
+                        first_seen: now,
+                        last_seen: now,
+                    });
+                }
+            }
+        });
+    }
+
+    /// Counts a run of the `index`th pipe of `kind`.
+    pub(crate) fn record_pipe(&self, kind: PipeKind, index: usize, failed: bool) {
+        self.update(|state| {
+            let Some(name) = state
+                .pipe_names
+                .get(&kind)
+                .and_then(|names| names.get(index))
+                .cloned()
+            else {
+                return;
+            };
+
+            let counters = state.pipes.entry(name).or_default();
+            counters.processed += 1;
+            if failed {
+                counters.failed += 1;
+            }
+        });
+    }
+
+    pub(crate) fn record_lag(&self, lag: PipelineLag) {
+        self.update(|state| state.lag = Some(lag));
+    }
+
+    fn update(&self, update: impl FnOnce(&mut StatusState)) {
+        let mut state = self
+            .state
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        update(&mut state);
+    }
+}
+
+impl std::fmt::Debug for PipelineStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PipelineStatus")
+            .field("report", &self.report())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::ManualClock,
+            description::{PipeDescription, PipelineDescription},
+        },
+        std::time::Duration,
+        tokio::sync::watch,
+    };
+
+    fn status(clock: &ManualClock) -> PipelineStatus {
+        PipelineStatus::new(
+            Arc::new(RwLock::new(vec![TaskHealth::Running { restarts: 0 }])),
+            Arc::new(watch::channel(false).0),
+            Arc::new(clock.clone()),
+        )
+    }
+
+    #[test]
+    fn test_report_counts_pipes_and_errors() {
+        // Arrange
+        let clock = ManualClock::new(1_000);
+        let status = status(&clock);
+        status.start(&PipelineDescription {
+            datasources: Vec::new(),
+            pipes: vec![PipeDescription::new(PipeKind::Instruction, 0)],
+            filters: Default::default(),
+            metrics: 0,
+            metrics_flush_interval: None,
+            channel_buffer_size: 1,
+            shutdown_strategy: "ProcessPending".to_string(),
+        });
+
+        // Act
+        status.record_pipe(PipeKind::Instruction, 0, false);
+        status.record_pipe(PipeKind::Instruction, 0, true);
+        status.record_update(Some(12), Some("decode failed".to_string()));
+        clock.advance(Duration::from_secs(5));
+        status.record_update(Some(10), Some("decode failed".to_string()));
+        let report = status.report();
+
+        // Assert
+        assert!(report.is_live());
+        assert!(report.is_ready());
+        assert_eq!(report.last_processed_slot, Some(12));
+        assert_eq!((report.updates_processed, report.updates_failed), (2, 2));
+        assert_eq!(
+            report.pipes.get("instruction_0"),
+            Some(&PipeCounters {
+                processed: 2,
+                failed: 1,
+            })
+        );
+        assert_eq!(
+            report.errors,
+            vec![ErrorSummary {
+                message: "decode failed".to_string(),
+                count: 2,
+                first_seen: 1_000,
+                last_seen: 1_005,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stopped_pipeline_is_not_live() {
+        let status = status(&ManualClock::new(0));
+
+        assert!(!status.report().is_ready());
+
+        status.stop();
+
+        assert!(!status.report().is_live());
+    }
+}
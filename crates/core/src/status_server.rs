@@ -0,0 +1,248 @@
+//! Serves the status of a pipeline over HTTP, so deployments can probe it.
+//!
+//! `StatusServer` answers three `GET` endpoints:
+//!
+//! - `/healthz`: `200` while the pipeline is alive, `503` once it stopped or
+//!   every datasource failed. Meant for liveness probes.
+//! - `/readyz`: `200` while the pipeline is running, not paused, and every
+//!   datasource is running or finished, `503` otherwise. Meant for readiness
+//!   probes.
+//! - `/status`: The `StatusReport` of the pipeline as JSON, with the last
+//!   processed slot, the lag, the counters of each pipe and the most recent
+//!   errors.
+//!
+//! Requires the `status-server` feature.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .status_server("0.0.0.0:8080".parse()?)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! ```yaml
+//! livenessProbe:
+//!   httpGet:
+//!     path: /healthz
+//!     port: 8080
+//! readinessProbe:
+//!   httpGet:
+//!     path: /readyz
+//!     port: 8080
+//! ```
+//!
+//! # Notes
+//!
+//! - The server only answers `GET` requests with a single response per
+//!   connection, which is what probes and `curl` send.
+//! - The server started by `PipelineBuilder::status_server` stops when the
+//!   pipeline's run returns, including on an error, so probes fail once the
+//!   pipeline is gone.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        status::PipelineStatus,
+    },
+    std::net::SocketAddr,
+    tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream, ToSocketAddrs},
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// The maximum size of a request, headers included.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// An HTTP server answering health probes and status requests for a
+/// pipeline.
+pub struct StatusServer {
+    local_addr: SocketAddr,
+    cancellation_token: CancellationToken,
+}
+
+impl StatusServer {
+    /// Binds the server to `addr` and starts answering requests in the
+    /// background, until `shutdown` is called or the server is dropped.
+    pub async fn bind(addr: impl ToSocketAddrs, status: PipelineStatus) -> CarbonResult<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|error| Error::Custom(format!("Failed to bind status server: {}", error)))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|error| Error::Custom(format!("Failed to bind status server: {}", error)))?;
+        let cancellation_token = CancellationToken::new();
+
+        let accept_cancellation_token = cancellation_token.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = accept_cancellation_token.cancelled() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => {
+                            tokio::spawn(serve_request(stream, status.clone()));
+                        }
+                        Err(error) => log::error!("Failed to accept status request: {}", error),
+                    },
+                }
+            }
+        });
+
+        log::info!("status server listening on {}", local_addr);
+
+        Ok(Self {
+            local_addr,
+            cancellation_token,
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops accepting requests.
+    pub fn shutdown(&self) {
+        self.cancellation_token.cancel();
+    }
+}
+
+impl Drop for StatusServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+async fn serve_request(mut stream: TcpStream, status: PipelineStatus) {
+    let (code, content_type, body) = match read_request_line(&mut stream).await {
+        Some(request_line) => respond(&request_line, &status),
+        None => (400, "text/plain", "bad request".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        code,
+        reason(code),
+        content_type,
+        body.len(),
+        body
+    );
+
+    if let Err(error) = stream.write_all(response.as_bytes()).await {
+        log::debug!("Failed to answer status request: {}", error);
+    }
+    let _ = stream.shutdown().await;
+}
+
+/// Reads the request until the end of its headers, returning its first line.
+async fn read_request_line(stream: &mut TcpStream) -> Option<String> {
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 1024];
+
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await.ok()?;
+        if read == 0 || request.len() + read > MAX_REQUEST_SIZE {
+            break;
+        }
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    request.lines().next().map(str::to_string)
+}
+
+/// Returns the status code, content type and body answering `request_line`,
+/// such as `GET /readyz HTTP/1.1`.
+fn respond(request_line: &str, status: &PipelineStatus) -> (u16, &'static str, String) {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return (400, "text/plain", "bad request".to_string());
+    };
+    let path = target.split('?').next().unwrap_or(target);
+
+    if method != "GET" {
+        return (405, "text/plain", "method not allowed".to_string());
+    }
+
+    let report = status.report();
+    let probe = |ok: bool| {
+        if ok {
+            (200, "text/plain", "ok".to_string())
+        } else {
+            (503, "text/plain", "unavailable".to_string())
+        }
+    };
+
+    match path {
+        "/healthz" => probe(report.is_live()),
+        "/readyz" => probe(report.is_ready()),
+        "/status" => match serde_json::to_string(&report) {
+            Ok(body) => (200, "application/json", body),
+            Err(error) => (500, "text/plain", error.to_string()),
+        },
+        _ => (404, "text/plain", "not found".to_string()),
+    }
+}
+
+fn reason(code: u16) -> &'static str {
+    match code {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{clock::SystemClock, supervision::TaskHealth},
+        std::sync::{Arc, RwLock},
+        tokio::sync::watch,
+    };
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes())
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_status_server_answers_probes() {
+        // Arrange
+        let status = PipelineStatus::new(
+            Arc::new(RwLock::new(vec![TaskHealth::Pending])),
+            Arc::new(watch::channel(false).0),
+            Arc::new(SystemClock),
+        );
+        let server = StatusServer::bind("127.0.0.1:0", status).await.unwrap();
+
+        // Act
+        let healthz = get(server.local_addr(), "/healthz").await;
+        let readyz = get(server.local_addr(), "/readyz").await;
+        let report = get(server.local_addr(), "/status").await;
+        let unknown = get(server.local_addr(), "/unknown").await;
+
+        // Assert
+        assert!(healthz.starts_with("HTTP/1.1 200 OK"));
+        assert!(readyz.starts_with("HTTP/1.1 503 Service Unavailable"));
+        assert!(report.starts_with("HTTP/1.1 200 OK"));
+        assert!(report.contains("\"state\":\"starting\""));
+        assert!(unknown.starts_with("HTTP/1.1 404 Not Found"));
+    }
+}
@@ -0,0 +1,535 @@
+//! Supervises the tasks of a running pipeline.
+//!
+//! `Pipeline::spawn` runs the pipeline on its own task and returns a
+//! `PipelineHandle`, so a larger service embedding Carbon can wait for the
+//! pipeline, stop it, or report its health, without owning the loop.
+//!
+//! Each datasource is consumed on a supervised task. A datasource task that
+//! panics is restarted after a backoff, doubling after each restart, as
+//! configured by the `RestartPolicy`. A datasource returning an error is not
+//! restarted, since it already handles its own reconnections. Datasources
+//! running their work on tasks of their own await them with
+//! `datasource::join_tasks`, which resumes their panics, so the task is
+//! supervised until the datasource is done.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let handle = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .restart_policy(RestartPolicy::default().max_restarts(3))
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .build()?
+//!     .spawn();
+//!
+//! for (index, health) in handle.health().iter().enumerate() {
+//!     log::info!("datasource {}: {:?}", index, health);
+//! }
+//!
+//! handle.shutdown();
+//! handle.join().await?;
+//! ```
+//!
+//! The handle can also pause the pipeline. A paused pipeline stops pulling
+//! updates from its channel, so once the channel is full the datasources wait
+//! on it, while their subscriptions stay open. Processors are still flushed
+//! on each metrics tick, so sinks can be drained before a maintenance window.
+//!
+//! Pipes are isolated in the same spirit. A processor panicking while
+//! processing an update, or in a lifecycle hook, does not take the pipeline
+//! down: the panic is caught and turned into an error of the pipe, so the
+//! update is counted as failed like any other processing error, and the
+//! pipeline moves on to the next update.
+//!
+//! # Notes
+//!
+//! - Restarts are reported by the `datasource_restarts` counter, and caught
+//!   pipe panics by the `pipe_panics` counter.
+//! - A processor that panicked keeps its state as it was when it panicked,
+//!   and receives the next updates.
+//! - A restarted datasource consumes from the start again, as when the
+//!   pipeline starts.
+//! - A bounded datasource that fails for good is counted as finished, so a
+//!   pipeline waiting for its bounded datasources still shuts down. Its
+//!   failure is kept in its `TaskHealth`.
+
+use {
+    crate::{
+        clock::Clock,
+        datasource::{Datasource, Update},
+        error::{CarbonResult, Error},
+        event_bus::{EventBus, PipelineEvent},
+        metrics::MetricsCollection,
+        status::PipelineStatus,
+    },
+    futures::FutureExt,
+    serde::Serialize,
+    std::{
+        any::Any,
+        future::Future,
+        panic::AssertUnwindSafe,
+        sync::{Arc, RwLock},
+        time::Duration,
+    },
+    tokio::{
+        sync::{mpsc, watch},
+        task::JoinHandle,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// The default number of times a panicking datasource task is restarted.
+pub const DEFAULT_MAX_RESTARTS: u32 = 10;
+
+/// The default delay before the first restart of a datasource task.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The default maximum delay between two restarts of a datasource task.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// How panicking datasource tasks are restarted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    pub max_restarts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Never restarts a panicking datasource task.
+    pub fn never() -> Self {
+        Self::default().max_restarts(0)
+    }
+
+    /// Sets how many times a datasource task is restarted. Defaults to 10.
+    pub fn max_restarts(mut self, max_restarts: u32) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Sets the delays before the first restart and between two restarts.
+    /// Defaults to 1 and 60 seconds.
+    pub fn backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff.max(initial_backoff);
+        self
+    }
+
+    /// Returns the delay before restart number `restart`, counted from 1.
+    pub fn delay(&self, restart: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(restart.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+/// The state of a supervised task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum TaskHealth {
+    /// The task has not started yet.
+    Pending,
+    Running {
+        restarts: u32,
+    },
+    /// The task panicked and is waiting to be restarted.
+    Restarting {
+        restarts: u32,
+    },
+    /// The task completed without error.
+    Finished,
+    /// The task returned an error, or panicked more often than allowed.
+    Failed(String),
+}
+
+/// The health of each datasource task of a pipeline, in the order the
+/// datasources were added.
+pub type DatasourceHealth = Arc<RwLock<Vec<TaskHealth>>>;
+
+fn set_health(health: &DatasourceHealth, index: usize, task_health: TaskHealth) {
+    if let Ok(mut health) = health.write() {
+        if let Some(entry) = health.get_mut(index) {
+            *entry = task_health;
+        }
+    }
+}
+
+/// Whether a pipeline is paused, shared between the pipeline and its handle.
+pub type PauseState = Arc<watch::Sender<bool>>;
+
+/// Spawns a task consuming `datasource`, restarting it after a backoff when it
+/// panics.
+///
+/// A bounded datasource failing for good, with an error or after its last
+/// restart, is reported as finished.
+pub(crate) fn supervise_datasource(
+    index: usize,
+    datasource: Arc<dyn Datasource + Send + Sync>,
+    sender: mpsc::Sender<Update>,
+    cancellation_token: CancellationToken,
+    metrics: Arc<MetricsCollection>,
+    health: DatasourceHealth,
+    restart_policy: RestartPolicy,
+    clock: Arc<dyn Clock>,
+    events: EventBus,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut restarts = 0;
+
+        loop {
+            set_health(&health, index, TaskHealth::Running { restarts });
+            events.emit(PipelineEvent::DatasourceConnected { index, restarts });
+
+            let task = {
+                let datasource = datasource.clone();
+                let sender = sender.clone();
+                let cancellation_token = cancellation_token.clone();
+                let metrics = metrics.clone();
+                tokio::spawn(async move {
+                    datasource
+                        .consume(&sender, cancellation_token, metrics)
+                        .await
+                })
+            };
+
+            let error = match task.await {
+                Ok(Ok(())) => {
+                    set_health(&health, index, TaskHealth::Finished);
+                    events.emit(PipelineEvent::DatasourceDisconnected { index, error: None });
+                    return;
+                }
+                Ok(Err(error)) => {
+                    log::error!("error consuming datasource: {:?}", error);
+                    set_health(&health, index, TaskHealth::Failed(error.to_string()));
+                    events.emit(PipelineEvent::DatasourceDisconnected {
+                        index,
+                        error: Some(error.to_string()),
+                    });
+                    finish_failed(datasource.as_ref(), &sender).await;
+                    return;
+                }
+                Err(join_error) => join_error,
+            };
+            events.emit(PipelineEvent::DatasourceDisconnected {
+                index,
+                error: error
+                    .is_panic()
+                    .then(|| format!("datasource panicked: {}", error)),
+            });
+
+            if !error.is_panic() || cancellation_token.is_cancelled() {
+                set_health(&health, index, TaskHealth::Finished);
+                return;
+            }
+
+            if restarts >= restart_policy.max_restarts {
+                log::error!("datasource {} panicked, giving up: {:?}", index, error);
+                set_health(
+                    &health,
+                    index,
+                    TaskHealth::Failed(format!("datasource panicked: {}", error)),
+                );
+                finish_failed(datasource.as_ref(), &sender).await;
+                return;
+            }
+
+            restarts += 1;
+            let delay = restart_policy.delay(restarts);
+            log::error!(
+                "datasource {} panicked, restarting in {:?}: {:?}",
+                index,
+                delay,
+                error
+            );
+            set_health(&health, index, TaskHealth::Restarting { restarts });
+            if let Err(error) = metrics.increment_counter("datasource_restarts", 1).await {
+                log::error!("error recording metric: {:?}", error);
+            }
+
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    set_health(&health, index, TaskHealth::Finished);
+                    return;
+                }
+                _ = clock.sleep(delay) => {}
+            }
+        }
+    })
+}
+
+/// Sends `DatasourceFinished` for a bounded datasource that failed, which
+/// will not send it itself, so the pipeline does not wait for it forever.
+async fn finish_failed(datasource: &(dyn Datasource + Send + Sync), sender: &mpsc::Sender<Update>) {
+    if datasource.is_bounded() {
+        let _ = sender.send(Update::DatasourceFinished).await;
+    }
+}
+
+/// Runs a pipe, turning a panic of its processor into an error.
+///
+/// `pipe` names the kind of pipe, such as `instruction`, in the error and the
+/// log. Panics are counted by the `pipe_panics` counter.
+pub(crate) async fn isolate_panics(
+    pipe: &str,
+    future: impl Future<Output = CarbonResult<()>>,
+    metrics: &MetricsCollection,
+) -> CarbonResult<()> {
+    let payload = match AssertUnwindSafe(future).catch_unwind().await {
+        Ok(result) => return result,
+        Err(payload) => payload,
+    };
+
+    let message = panic_message(payload.as_ref());
+    log::error!("{} pipe panicked: {}", pipe, message);
+    if let Err(error) = metrics.increment_counter("pipe_panics", 1).await {
+        log::error!("error recording metric: {:?}", error);
+    }
+
+    Err(Error::Custom(format!(
+        "{} pipe panicked: {}",
+        pipe, message
+    )))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// A handle to a pipeline running on its own task, returned by
+/// `Pipeline::spawn`.
+pub struct PipelineHandle {
+    pub(crate) task: JoinHandle<CarbonResult<()>>,
+    pub(crate) cancellation_token: CancellationToken,
+    pub(crate) datasource_health: DatasourceHealth,
+    pub(crate) pause_state: PauseState,
+    pub(crate) status: PipelineStatus,
+    pub(crate) events: EventBus,
+}
+
+impl PipelineHandle {
+    /// Waits for the pipeline to stop and returns its result. A pipeline that
+    /// panicked or was aborted returns an error.
+    pub async fn join(self) -> CarbonResult<()> {
+        self.task
+            .await
+            .map_err(|error| Error::Custom(format!("Pipeline task failed: {}", error)))?
+    }
+
+    /// Stops the datasources. The pipeline stops once it has processed the
+    /// pending updates, and is resumed if it was paused.
+    pub fn shutdown(&self) {
+        self.cancellation_token.cancel();
+        self.resume();
+    }
+
+    /// Stops processing updates until `resume` is called. The datasources stay
+    /// connected and wait once the update channel is full.
+    pub fn pause(&self) {
+        self.pause_state.send_replace(true);
+    }
+
+    pub fn resume(&self) {
+        self.pause_state.send_replace(false);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.pause_state.borrow()
+    }
+
+    /// Stops the datasources and the pipeline immediately, dropping the
+    /// pending updates.
+    pub fn abort(&self) {
+        self.cancellation_token.cancel();
+        self.task.abort();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Returns a handle to the status of the pipeline, with its last
+    /// processed slot, pipe counters and recent errors.
+    pub fn status(&self) -> PipelineStatus {
+        self.status.clone()
+    }
+
+    /// Returns the health of each datasource task, in the order the
+    /// datasources were added.
+    pub fn health(&self) -> Vec<TaskHealth> {
+        self.datasource_health
+            .read()
+            .map(|health| health.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the `EventBus` the lifecycle events of the pipeline are
+    /// emitted on. Subscribe to it before the events of interest happen.
+    pub fn events(&self) -> EventBus {
+        self.events.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            clock::SystemClock,
+            datasource::{join_tasks, Capabilities},
+        },
+        async_trait::async_trait,
+        std::sync::atomic::{AtomicU32, Ordering},
+    };
+
+    #[test]
+    fn test_restart_delay_doubles_up_to_max_backoff() {
+        let restart_policy =
+            RestartPolicy::default().backoff(Duration::from_secs(1), Duration::from_secs(5));
+
+        assert_eq!(restart_policy.delay(1), Duration::from_secs(1));
+        assert_eq!(restart_policy.delay(2), Duration::from_secs(2));
+        assert_eq!(restart_policy.delay(3), Duration::from_secs(4));
+        assert_eq!(restart_policy.delay(4), Duration::from_secs(5));
+        assert_eq!(restart_policy.delay(40), Duration::from_secs(5));
+    }
+
+    async fn panicking_processor() -> CarbonResult<()> {
+        panic!("processor bug")
+    }
+
+    #[test]
+    fn test_isolate_panics_returns_error() {
+        let metrics = MetricsCollection::default();
+
+        let result = futures::executor::block_on(isolate_panics(
+            "instruction",
+            panicking_processor(),
+            &metrics,
+        ));
+        let ok = futures::executor::block_on(isolate_panics("account", async { Ok(()) }, &metrics));
+
+        assert!(matches!(
+            result,
+            Err(Error::Custom(message)) if message == "instruction pipe panicked: processor bug"
+        ));
+        assert!(ok.is_ok());
+    }
+
+    /// A datasource whose spawned task panics on its first `panics` runs.
+    struct PanickingDatasource {
+        runs: Arc<AtomicU32>,
+        panics: u32,
+    }
+
+    #[async_trait]
+    impl Datasource for PanickingDatasource {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst);
+            let panics = self.panics;
+            let sender = sender.clone();
+
+            let task = tokio::spawn(async move {
+                if run < panics {
+                    panic!("datasource bug");
+                }
+                let _ = sender.send(Update::DatasourceFinished).await;
+            });
+            join_tasks(vec![task]).await;
+
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new()
+        }
+    }
+
+    fn supervise(
+        panics: u32,
+        restart_policy: RestartPolicy,
+    ) -> (
+        JoinHandle<()>,
+        Arc<AtomicU32>,
+        DatasourceHealth,
+        mpsc::Receiver<Update>,
+    ) {
+        let runs = Arc::new(AtomicU32::new(0));
+        let health = Arc::new(RwLock::new(vec![TaskHealth::Pending]));
+        let (sender, receiver) = mpsc::channel(10);
+
+        let task = supervise_datasource(
+            0,
+            Arc::new(PanickingDatasource {
+                runs: runs.clone(),
+                panics,
+            }),
+            sender,
+            CancellationToken::new(),
+            Arc::new(MetricsCollection::default()),
+            health.clone(),
+            restart_policy,
+            Arc::new(SystemClock),
+            EventBus::new(16),
+        );
+
+        (task, runs, health, receiver)
+    }
+
+    #[tokio::test]
+    async fn test_datasource_task_panic_is_restarted() {
+        // Arrange
+        let restart_policy =
+            RestartPolicy::default().backoff(Duration::from_millis(1), Duration::from_millis(1));
+
+        // Act
+        let (task, runs, health, mut receiver) = supervise(2, restart_policy);
+        task.await.unwrap();
+
+        // Assert
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert!(matches!(
+            receiver.recv().await,
+            Some(Update::DatasourceFinished)
+        ));
+        assert_eq!(health.read().unwrap()[0], TaskHealth::Finished);
+    }
+
+    #[tokio::test]
+    async fn test_datasource_task_panic_fails_after_max_restarts() {
+        // Arrange
+        let restart_policy = RestartPolicy::default()
+            .max_restarts(1)
+            .backoff(Duration::from_millis(1), Duration::from_millis(1));
+
+        // Act
+        let (task, runs, health, _receiver) = supervise(5, restart_policy);
+        task.await.unwrap();
+
+        // Assert
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert!(matches!(
+            &health.read().unwrap()[0],
+            TaskHealth::Failed(message) if message.starts_with("datasource panicked")
+        ));
+    }
+}
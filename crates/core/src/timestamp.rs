@@ -0,0 +1,245 @@
+//! Normalizes the timestamps of decoded events against their block time.
+//!
+//! Many events embed an on-chain timestamp, read from the `Clock` sysvar by
+//! the program, such as the `timestamp` of a Pumpfun `TradeEvent`. It usually
+//! matches the block time of the transaction, but can drift from it by a few
+//! seconds, and is sometimes missing or wrong, while the block time itself is
+//! not delivered by every datasource. Time series mixing both end up
+//! inconsistent.
+//!
+//! `PipelineBuilder::event_timestamped` adds an event pipe whose processor
+//! receives each event as a `TimestampedEvent`, carrying both the embedded
+//! timestamp and the block time, and the `timestamp` a sink should use,
+//! resolved by a `TimestampPolicy`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .event_timestamped(
+//!         PumpfunDecoder,
+//!         TradeSeriesSink::new(pool),
+//!         |event: &PumpfunInstruction| match event {
+//!             PumpfunInstruction::TradeEvent(trade) => Some(trade.timestamp),
+//!             _ => None,
+//!         },
+//!         TimestampPolicy::new(TimestampPreference::Event).max_skew(Duration::from_secs(30)),
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Timestamps are Unix timestamps in seconds, as block times are: convert
+//!   embedded timestamps in milliseconds in the extractor.
+//! - Embedded timestamps differing from the block time by more than the
+//!   maximum skew are counted by the `event_timestamps_skewed` counter, and
+//!   the block time is used instead, whatever the preference.
+//! - Each timestamp falls back on the other when missing, so `timestamp` is
+//!   only `None` when both are.
+
+use {
+    crate::{
+        error::CarbonResult,
+        event::{EventMetadata, EventProcessorInputType},
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    std::{sync::Arc, time::Duration},
+};
+
+/// The timestamp a `TimestampPolicy` resolves to when both are known.
+///
+/// - `Event`: The timestamp embedded in the event, unless it is skewed.
+/// - `BlockTime`: The block time of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampPreference {
+    Event,
+    BlockTime,
+}
+
+/// Resolves the timestamp of an event from its embedded timestamp and its
+/// block time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampPolicy {
+    pub preference: TimestampPreference,
+    /// The maximum difference between the embedded timestamp and the block
+    /// time, above which the embedded timestamp is considered wrong.
+    pub max_skew: Option<Duration>,
+}
+
+impl TimestampPolicy {
+    pub fn new(preference: TimestampPreference) -> Self {
+        Self {
+            preference,
+            max_skew: None,
+        }
+    }
+
+    /// Sets the maximum difference between the embedded timestamp and the
+    /// block time. No maximum is set by default.
+    pub fn max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = Some(max_skew);
+        self
+    }
+
+    /// Returns the timestamps of an event with the given embedded timestamp
+    /// and block time, both in Unix seconds.
+    pub fn resolve(&self, event_time: Option<i64>, block_time: Option<i64>) -> EventTimestamps {
+        let skewed = match (event_time, block_time, self.max_skew) {
+            (Some(event_time), Some(block_time), Some(max_skew)) => {
+                event_time.abs_diff(block_time) > max_skew.as_secs()
+            }
+            _ => false,
+        };
+
+        let timestamp = match self.preference {
+            TimestampPreference::Event if !skewed => event_time.or(block_time),
+            _ => block_time.or(event_time),
+        };
+
+        EventTimestamps {
+            event_time,
+            block_time,
+            timestamp,
+            skewed,
+        }
+    }
+}
+
+/// The timestamps of an event, in Unix seconds.
+///
+/// # Fields
+///
+/// - `event_time`: The timestamp embedded in the event, if any.
+/// - `block_time`: The block time of the transaction, if known.
+/// - `timestamp`: The timestamp resolved by the `TimestampPolicy`.
+/// - `skewed`: Whether the embedded timestamp differs from the block time by
+///   more than the maximum skew.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventTimestamps {
+    pub event_time: Option<i64>,
+    pub block_time: Option<i64>,
+    pub timestamp: Option<i64>,
+    pub skewed: bool,
+}
+
+impl EventTimestamps {
+    /// Returns how many seconds the embedded timestamp is ahead of the block
+    /// time, if both are known.
+    pub fn skew(&self) -> Option<i64> {
+        Some(self.event_time? - self.block_time?)
+    }
+}
+
+/// A decoded event with its timestamps.
+#[derive(Debug, Clone)]
+pub struct TimestampedEvent<T> {
+    pub metadata: EventMetadata,
+    pub event: T,
+    pub timestamps: EventTimestamps,
+}
+
+/// An event processor wrapper attaching the timestamps of each event before
+/// passing it to its processor of `TimestampedEvent`s.
+pub struct TimestampNormalizer<T, P> {
+    processor: P,
+    extractor: Box<dyn Fn(&T) -> Option<i64> + Send + Sync>,
+    policy: TimestampPolicy,
+}
+
+impl<T, P> TimestampNormalizer<T, P> {
+    /// Creates a wrapper reading the embedded timestamp of each event with
+    /// `extractor`, and resolving its timestamps with `policy`.
+    pub fn new(
+        processor: P,
+        extractor: impl Fn(&T) -> Option<i64> + Send + Sync + 'static,
+        policy: TimestampPolicy,
+    ) -> Self {
+        Self {
+            processor,
+            extractor: Box::new(extractor),
+            policy,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for TimestampNormalizer<T, P>
+where
+    T: Send + Sync + 'static,
+    P: Processor<InputType = TimestampedEvent<T>> + Send + Sync,
+{
+    type InputType = EventProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, event): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let timestamps = self.policy.resolve(
+            (self.extractor)(&event),
+            metadata.transaction_metadata.block_time,
+        );
+        if timestamps.skewed {
+            metrics
+                .increment_counter("event_timestamps_skewed", 1)
+                .await?;
+        }
+
+        self.processor
+            .process(
+                TimestampedEvent {
+                    metadata,
+                    event,
+                    timestamps,
+                },
+                metrics,
+            )
+            .await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_resolves_timestamps() {
+        // Arrange
+        let prefer_event =
+            TimestampPolicy::new(TimestampPreference::Event).max_skew(Duration::from_secs(30));
+        let prefer_block_time = TimestampPolicy::new(TimestampPreference::BlockTime);
+
+        // Act
+        let close = prefer_event.resolve(Some(1_010), Some(1_000));
+        let skewed = prefer_event.resolve(Some(2_000), Some(1_000));
+        let without_block_time = prefer_block_time.resolve(Some(1_010), None);
+        let block_time = prefer_block_time.resolve(Some(1_010), Some(1_000));
+
+        // Assert
+        assert_eq!(close.timestamp, Some(1_010));
+        assert_eq!(close.skew(), Some(10));
+        assert!(!close.skewed);
+        assert_eq!(skewed.timestamp, Some(1_000));
+        assert!(skewed.skewed);
+        assert_eq!(without_block_time.timestamp, Some(1_010));
+        assert_eq!(block_time.timestamp, Some(1_000));
+    }
+}
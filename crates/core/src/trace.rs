@@ -0,0 +1,74 @@
+//! Provides the `TraceId` assigned to every update entering the pipeline.
+//!
+//! Each update received from a datasource is stamped with a `TraceId` before
+//! it is processed. The id is carried in the metadata passed to processors
+//! (`AccountMetadata::trace_id` and `TransactionMetadata::trace_id`, which is
+//! also reachable from every `InstructionMetadata`), so a single update can be
+//! followed from ingestion through processors, database writes and downstream
+//! consumers.
+//!
+//! # Notes
+//!
+//! - Ids are unique within a process and seeded from the start time, so ids
+//!   from different runs are very unlikely to collide.
+//! - Account deletions are passed to processors as-is and do not carry a
+//!   trace id.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+static TRACE_ID_SEED: OnceLock<u64> = OnceLock::new();
+static TRACE_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A unique identifier assigned to an update when it enters the pipeline.
+///
+/// The id is displayed as a 16 character hexadecimal string, which is the
+/// format expected when it is written to logs or external systems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
+pub struct TraceId(pub u64);
+
+impl TraceId {
+    /// Generates a new, process-unique `TraceId`.
+    pub fn new() -> Self {
+        let seed = *TRACE_ID_SEED.get_or_init(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+
+        Self(seed.wrapping_add(TRACE_ID_COUNTER.fetch_add(1, Ordering::Relaxed)))
+    }
+}
+
+impl Default for TraceId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_ids_are_unique() {
+        let first = TraceId::new();
+        let second = TraceId::new();
+
+        assert_ne!(first, second);
+        assert_eq!(first.to_string().len(), 16);
+    }
+}
@@ -12,6 +12,8 @@
 //!   slot, signature, and fee payer information.
 //! - **ParsedTransaction**: Represents a transaction with its metadata and
 //!   parsed instructions.
+//! - **SequencePipe**: Matches a `SequenceSchema` across the transactions of
+//!   each fee payer and processes the completed sequences.
 //!
 //! ## Usage
 //!
@@ -31,20 +33,32 @@
 
 use {
     crate::{
+        actions::ActionIdentifier,
         collection::InstructionDecoderCollection,
         error::CarbonResult,
+        failure::FailureReason,
         instruction::{DecodedInstruction, InstructionMetadata, NestedInstruction},
         metrics::MetricsCollection,
-        processor::Processor,
-        schema::{ParsedInstruction, TransactionSchema},
+        processor::{run_lifecycle, Lifecycle, Processor},
+        schema::{
+            ParsedInstruction, SequenceMatch, SequenceMatcher, SequenceSchema, TransactionSchema,
+        },
+        slot_clock::SlotClock,
+        trace::TraceId,
         transformers,
+        verification::VerificationFailure,
     },
     async_trait::async_trait,
     core::convert::TryFrom,
     serde::de::DeserializeOwned,
+    solana_program::message::{v0::LoadedMessage, VersionedMessage},
     solana_pubkey::Pubkey,
+    solana_sdk::{commitment_config::CommitmentLevel, reserved_account_keys::ReservedAccountKeys},
     solana_signature::Signature,
-    std::sync::Arc,
+    std::{
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    },
 };
 /// Contains metadata about a transaction, including its slot, signature, fee
 /// payer, transaction status metadata, the version transaction message and its
@@ -57,6 +71,13 @@ use {
 /// - `meta`: Transaction status metadata containing execution status, fees, balances, and other metadata
 /// - `message`: The versioned message containing the transaction instructions and account keys
 /// - `block_time`: The Unix timestamp of when the transaction was processed.
+/// - `trace_id`: The `TraceId` assigned to the update when it entered the pipeline
+/// - `failure_reason`: Why the transaction failed, or `None` if it succeeded
+/// - `slot_clock`: The epoch and estimated time of the slot, set when the pipeline has a `ClusterSchedule`
+/// - `actions`: The Solana Actions identifiers of the transaction's memos, set when the pipeline detects actions
+/// - `commitment`: The commitment the transaction was received at, when tagged by the datasource
+/// - `raw_transaction`: The transaction serialized in its wire format, set for the transaction pipes added with `PipelineBuilder::transaction_with_raw`
+/// - `verification_failure`: Why the transaction failed verification, set when the pipeline flags unverified transactions
 ///
 /// Note: The `block_time` field may not be returned in all scenarios.
 #[derive(Debug, Clone)]
@@ -67,6 +88,71 @@ pub struct TransactionMetadata {
     pub meta: solana_transaction_status::TransactionStatusMeta,
     pub message: solana_program::message::VersionedMessage,
     pub block_time: Option<i64>,
+    pub trace_id: TraceId,
+    pub failure_reason: Option<FailureReason>,
+    pub slot_clock: Option<SlotClock>,
+    pub actions: Vec<ActionIdentifier>,
+    pub commitment: Option<CommitmentLevel>,
+    pub raw_transaction: Option<Arc<Vec<u8>>>,
+    pub verification_failure: Option<VerificationFailure>,
+}
+
+impl TransactionMetadata {
+    /// Returns the accounts that signed the transaction, the fee payer first.
+    pub fn signers(&self) -> &[Pubkey] {
+        let account_keys = self.message.static_account_keys();
+        let num_signers =
+            (self.message.header().num_required_signatures as usize).min(account_keys.len());
+
+        &account_keys[..num_signers]
+    }
+
+    /// Returns whether `pubkey` signed the transaction.
+    pub fn is_signer(&self, pubkey: &Pubkey) -> bool {
+        self.signers().contains(pubkey)
+    }
+
+    /// Returns the accounts of the transaction in the order instructions
+    /// index them: the static account keys, then the writable and the
+    /// readonly accounts loaded from address lookup tables.
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        self.message
+            .static_account_keys()
+            .iter()
+            .chain(&self.meta.loaded_addresses.writable)
+            .chain(&self.meta.loaded_addresses.readonly)
+            .copied()
+            .collect()
+    }
+
+    /// Returns the accounts the transaction locked as writable, including
+    /// the writable accounts loaded from address lookup tables.
+    pub fn writable_accounts(&self) -> Vec<Pubkey> {
+        match &self.message {
+            VersionedMessage::Legacy(legacy) => legacy
+                .account_keys
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| legacy.is_maybe_writable(*index, None))
+                .map(|(_, pubkey)| *pubkey)
+                .collect(),
+            VersionedMessage::V0(v0) => {
+                let loaded_message = LoadedMessage::new(
+                    v0.clone(),
+                    self.meta.loaded_addresses.clone(),
+                    &ReservedAccountKeys::empty_key_set(),
+                );
+
+                loaded_message
+                    .account_keys()
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| loaded_message.is_writable(*index))
+                    .map(|(_, pubkey)| *pubkey)
+                    .collect()
+            }
+        }
+    }
 }
 
 impl Default for TransactionMetadata {
@@ -80,6 +166,13 @@ impl Default for TransactionMetadata {
                 solana_sdk::message::Message::default(),
             ),
             block_time: None,
+            trace_id: TraceId::new(),
+            failure_reason: None,
+            slot_clock: None,
+            actions: Vec::new(),
+            commitment: None,
+            raw_transaction: None,
+            verification_failure: None,
         }
     }
 }
@@ -119,6 +212,16 @@ impl TryFrom<crate::datasource::TransactionUpdate> for TransactionMetadata {
             meta: value.meta.clone(),
             message: value.transaction.message.clone(),
             block_time: value.block_time,
+            trace_id: TraceId::new(),
+            failure_reason: FailureReason::from_transaction(
+                &value.meta,
+                &value.transaction.message,
+            ),
+            slot_clock: None,
+            actions: Vec::new(),
+            commitment: value.commitment,
+            raw_transaction: None,
+            verification_failure: None,
         })
     }
 }
@@ -149,6 +252,7 @@ pub type TransactionProcessorInputType<T, U = ()> = (
 pub struct TransactionPipe<T: InstructionDecoderCollection, U> {
     schema: Option<TransactionSchema<T>>,
     processor: Box<dyn Processor<InputType = TransactionProcessorInputType<T, U>> + Send + Sync>,
+    raw_transaction: bool,
 }
 
 /// Represents a parsed transaction, including its metadata and parsed
@@ -185,9 +289,17 @@ impl<T: InstructionDecoderCollection, U> TransactionPipe<T, U> {
         Self {
             schema,
             processor: Box::new(processor),
+            raw_transaction: false,
         }
     }
 
+    /// Passes the transaction serialized in its wire format to the processor,
+    /// in `TransactionMetadata::raw_transaction`.
+    pub fn with_raw_transaction(mut self) -> Self {
+        self.raw_transaction = true;
+        self
+    }
+
     /// Matches parsed instructions against the schema and returns the data as
     /// type `U`.
     ///
@@ -273,6 +385,28 @@ pub trait TransactionPipes<'a>: Send + Sync {
         instructions: &[NestedInstruction],
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Parses the instructions without running the processor, returning how
+    /// many top-level instructions were recognized by the decoder collection.
+    /// Used by `Pipeline::check`.
+    fn decode_only(&self, instructions: &[NestedInstruction]) -> usize;
+
+    /// Returns whether the processor of the pipe receives the serialized
+    /// transaction, which the pipeline then sets in the metadata. Defaults to
+    /// `false`.
+    fn wants_raw_transaction(&self) -> bool {
+        false
+    }
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -283,7 +417,7 @@ where
 {
     async fn run(
         &mut self,
-        transaction_metadata: TransactionMetadata,
+        mut transaction_metadata: TransactionMetadata,
         instructions: &[NestedInstruction],
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()> {
@@ -292,6 +426,10 @@ where
             instructions,
         );
 
+        if !self.raw_transaction {
+            transaction_metadata.raw_transaction = None;
+        }
+
         let parsed_instructions = parse_instructions(instructions);
 
         let matched_data = self.matches_schema(&parsed_instructions);
@@ -311,4 +449,176 @@ where
 
         Ok(())
     }
+
+    fn decode_only(&self, instructions: &[NestedInstruction]) -> usize {
+        parse_instructions::<T>(instructions).len()
+    }
+
+    fn wants_raw_transaction(&self) -> bool {
+        self.raw_transaction
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+/// A pipe matching a `SequenceSchema` across transactions and processing the
+/// completed sequences.
+///
+/// Transactions are grouped by fee payer. The block time of each transaction
+/// is used to enforce the window of the schema, falling back to the current
+/// time when the datasource does not provide it.
+///
+/// ## Generics
+///
+/// - `T`: The instruction type, implementing `InstructionDecoderCollection`.
+/// - `U`: The output type for the matched data, implementing
+///   `DeserializeOwned`.
+pub struct SequencePipe<T: InstructionDecoderCollection, U> {
+    matcher: SequenceMatcher<T>,
+    processor: Box<dyn Processor<InputType = SequenceMatch<U>> + Send + Sync>,
+}
+
+impl<T: InstructionDecoderCollection, U> SequencePipe<T, U> {
+    /// Creates a new `SequencePipe` with the specified sequence schema and
+    /// processor.
+    pub fn new(
+        schema: SequenceSchema<T>,
+        processor: impl Processor<InputType = SequenceMatch<U>> + Send + Sync + 'static,
+    ) -> Self {
+        log::trace!(
+            "SequencePipe::new(schema: {:?}, processor: {:?})",
+            schema,
+            stringify!(processor)
+        );
+        Self {
+            matcher: SequenceMatcher::new(schema),
+            processor: Box::new(processor),
+        }
+    }
+}
+
+#[async_trait]
+impl<T, U> TransactionPipes<'_> for SequencePipe<T, U>
+where
+    T: InstructionDecoderCollection + Sync + 'static,
+    U: DeserializeOwned + Send + Sync + 'static,
+{
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "SequencePipe::run(instructions: {:?}, metrics)",
+            instructions,
+        );
+
+        let parsed_instructions = parse_instructions(instructions);
+
+        let timestamp = transaction_metadata.block_time.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs() as i64)
+                .unwrap_or_default()
+        });
+
+        if let Some(sequence) = self.matcher.observe(
+            transaction_metadata.fee_payer,
+            transaction_metadata.signature,
+            timestamp,
+            &parsed_instructions,
+        ) {
+            metrics.increment_counter("sequences_matched", 1).await?;
+            self.processor.process(sequence, metrics).await?;
+        }
+
+        Ok(())
+    }
+
+    fn decode_only(&self, instructions: &[NestedInstruction]) -> usize {
+        parse_instructions::<T>(instructions).len()
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_program::message::{legacy, v0, MessageHeader},
+        solana_sdk::message::v0::LoadedAddresses,
+    };
+
+    #[test]
+    fn test_legacy_signers_and_writable_accounts() {
+        // Arrange
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let transaction_metadata = TransactionMetadata {
+            message: VersionedMessage::Legacy(legacy::Message {
+                header: MessageHeader {
+                    num_required_signatures: 2,
+                    num_readonly_signed_accounts: 1,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: keys.clone(),
+                ..legacy::Message::default()
+            }),
+            ..TransactionMetadata::default()
+        };
+
+        // Act
+        let signers = transaction_metadata.signers();
+        let writable_accounts = transaction_metadata.writable_accounts();
+
+        // Assert
+        assert_eq!(signers, &keys[..2]);
+        assert!(transaction_metadata.is_signer(&keys[1]));
+        assert!(!transaction_metadata.is_signer(&keys[2]));
+        assert_eq!(writable_accounts, vec![keys[0], keys[2]]);
+    }
+
+    #[test]
+    fn test_v0_accounts_include_loaded_addresses() {
+        // Arrange
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut transaction_metadata = TransactionMetadata {
+            message: VersionedMessage::V0(v0::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: keys[..2].to_vec(),
+                ..v0::Message::default()
+            }),
+            ..TransactionMetadata::default()
+        };
+        transaction_metadata.meta.loaded_addresses = LoadedAddresses {
+            writable: vec![keys[2]],
+            readonly: vec![keys[3]],
+        };
+
+        // Act
+        let account_keys = transaction_metadata.account_keys();
+        let writable_accounts = transaction_metadata.writable_accounts();
+
+        // Assert
+        assert_eq!(transaction_metadata.signers(), &keys[..1]);
+        assert_eq!(account_keys, keys);
+        assert_eq!(writable_accounts, vec![keys[0], keys[2]]);
+    }
 }
@@ -0,0 +1,104 @@
+//! Provides structures and traits for handling transaction status updates
+//! within the pipeline.
+//!
+//! This module defines the `TransactionStatusPipe` struct and
+//! `TransactionStatusPipes` trait, which process `TransactionStatusUpdate`
+//! events. Status updates only carry the signature, slot and error of a
+//! transaction, which makes them a cheap way to track confirmations of
+//! submitted transactions alongside decoding pipes.
+
+use {
+    crate::{
+        datasource::TransactionStatusUpdate,
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::{run_lifecycle, Lifecycle, Processor},
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+};
+
+/// A processing pipe for handling transaction status updates.
+///
+/// The `TransactionStatusPipe` passes each `TransactionStatusUpdate` to a
+/// user-defined `Processor`.
+///
+/// # Example
+///
+/// ```rust
+/// struct MyTransactionStatusProcessor;
+///
+/// #[async_trait]
+/// impl Processor for MyTransactionStatusProcessor {
+///     type InputType = TransactionStatusUpdate;
+///
+///     async fn process(
+///         &mut self,
+///         status: TransactionStatusUpdate,
+///         metrics: Arc<MetricsCollection>,
+///     ) -> CarbonResult<()> {
+///         if status.err.is_none() {
+///             // Mark the transaction as landed
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// ## Fields
+///
+/// - `processor`: A boxed `Processor` that handles transaction status updates.
+pub struct TransactionStatusPipe {
+    pub processor: Box<dyn Processor<InputType = TransactionStatusUpdate> + Send + Sync>,
+}
+
+/// A trait for handling transaction status updates in the pipeline.
+///
+/// # Required Methods
+///
+/// - `run`: Processes a `TransactionStatusUpdate`, tracking the operation with
+///   metrics.
+#[async_trait]
+pub trait TransactionStatusPipes: Send + Sync {
+    async fn run(
+        &mut self,
+        transaction_status: TransactionStatusUpdate,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Runs the lifecycle hook of the pipe's processor. Does nothing by
+    /// default.
+    async fn lifecycle(
+        &mut self,
+        _lifecycle: Lifecycle,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransactionStatusPipes for TransactionStatusPipe {
+    async fn run(
+        &mut self,
+        transaction_status: TransactionStatusUpdate,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "TransactionStatusPipe::run(transaction_status: {:?}, metrics)",
+            transaction_status,
+        );
+
+        self.processor.process(transaction_status, metrics).await?;
+
+        Ok(())
+    }
+
+    async fn lifecycle(
+        &mut self,
+        lifecycle: Lifecycle,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        run_lifecycle(self.processor.as_mut(), lifecycle, metrics).await
+    }
+}
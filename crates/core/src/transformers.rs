@@ -14,6 +14,10 @@
 //!   from transactions.
 //! - **Account Metadata**: Converts account data into a standardized format for
 //!   transactions.
+//! - **Transaction Updates**: Converts the transactions returned by the RPC
+//!   methods into `TransactionUpdate`s, for custom datasources and tools.
+//! - **Account Updates**: Converts the accounts returned by `getAccountInfo`
+//!   and `getProgramAccounts` into `AccountUpdate`s.
 //!
 //! ## Notes
 //!
@@ -23,31 +27,42 @@
 use {
     crate::{
         collection::InstructionDecoderCollection,
-        datasource::TransactionUpdate,
+        datasource::{AccountUpdate, BlockDetails, TransactionUpdate},
         error::{CarbonResult, Error},
         instruction::{DecodedInstruction, InstructionMetadata},
         schema::ParsedInstruction,
         transaction::TransactionMetadata,
     },
+    solana_account::Account,
+    solana_account_decoder_client_types::UiAccount,
+    solana_client::rpc_response::RpcKeyedAccount,
     solana_instruction::AccountMeta,
     solana_program::{
         instruction::CompiledInstruction,
         message::{
-            v0::{LoadedAddresses, LoadedMessage},
+            legacy,
+            v0::{self, LoadedAddresses, LoadedMessage, MessageAddressTableLookup},
             VersionedMessage,
         },
     },
     solana_pubkey::Pubkey,
     solana_sdk::{
+        hash::Hash,
         reserved_account_keys::ReservedAccountKeys,
+        transaction::VersionedTransaction,
         transaction_context::TransactionReturnData, // TODO: replace with solana_transaction_context after release of 2.2.0
     },
+    solana_signature::Signature,
     solana_transaction_status::{
-        option_serializer::OptionSerializer, InnerInstruction, InnerInstructions, Reward,
-        TransactionStatusMeta, TransactionTokenBalance, UiInstruction, UiLoadedAddresses,
-        UiTransactionStatusMeta,
+        option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+        EncodedTransaction, EncodedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
+        Reward, TransactionStatusMeta, TransactionTokenBalance, UiConfirmedBlock, UiInstruction,
+        UiLoadedAddresses, UiMessage, UiRawMessage, UiTransactionStatusMeta,
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        str::FromStr,
     },
-    std::{collections::HashSet, str::FromStr},
 };
 
 /// Extracts instructions with metadata from a transaction update.
@@ -114,6 +129,8 @@ pub fn extract_instructions_with_metadata(
                         transaction_metadata: transaction_metadata.clone(),
                         stack_height: 1,
                         index: i as u32 + 1,
+                        absolute_path: vec![i as u8],
+                        compute_units_consumed: None,
                     },
                     solana_instruction::Instruction {
                         program_id,
@@ -125,6 +142,7 @@ pub fn extract_instructions_with_metadata(
                 if let Some(inner_instructions) = &meta.inner_instructions {
                     for inner_instructions_per_tx in inner_instructions {
                         if inner_instructions_per_tx.index == i as u8 {
+                            let mut path = vec![i as u8];
                             for inner_instruction in inner_instructions_per_tx.instructions.iter() {
                                 let program_id = *legacy
                                     .account_keys
@@ -153,6 +171,11 @@ pub fn extract_instructions_with_metadata(
                                         transaction_metadata: transaction_metadata.clone(),
                                         stack_height: inner_instruction.stack_height.unwrap_or(1),
                                         index: inner_instructions_per_tx.index as u32,
+                                        absolute_path: next_inner_instruction_path(
+                                            &mut path,
+                                            inner_instruction.stack_height,
+                                        ),
+                                        compute_units_consumed: None,
                                     },
                                     solana_instruction::Instruction {
                                         program_id,
@@ -204,6 +227,8 @@ pub fn extract_instructions_with_metadata(
                         transaction_metadata: transaction_metadata.clone(),
                         stack_height: 1,
                         index: i as u32 + 1,
+                        absolute_path: vec![i as u8],
+                        compute_units_consumed: None,
                     },
                     solana_instruction::Instruction {
                         program_id,
@@ -215,6 +240,7 @@ pub fn extract_instructions_with_metadata(
                 if let Some(inner_instructions) = &meta.inner_instructions {
                     for inner_instructions_per_tx in inner_instructions {
                         if inner_instructions_per_tx.index == i as u8 {
+                            let mut path = vec![i as u8];
                             for inner_instruction in inner_instructions_per_tx.instructions.iter() {
                                 let program_id = *loaded_message
                                     .account_keys()
@@ -247,6 +273,11 @@ pub fn extract_instructions_with_metadata(
                                         transaction_metadata: transaction_metadata.clone(),
                                         stack_height: inner_instruction.stack_height.unwrap_or(1),
                                         index: inner_instructions_per_tx.index as u32,
+                                        absolute_path: next_inner_instruction_path(
+                                            &mut path,
+                                            inner_instruction.stack_height,
+                                        ),
+                                        compute_units_consumed: None,
                                     },
                                     solana_instruction::Instruction {
                                         program_id,
@@ -262,9 +293,163 @@ pub fn extract_instructions_with_metadata(
         }
     }
 
+    if let Some(log_messages) = &meta.log_messages {
+        attach_compute_units(&mut instructions_with_metadata, log_messages);
+    }
+
     Ok(instructions_with_metadata)
 }
 
+/// A program invocation recorded in the logs of a transaction.
+///
+/// # Fields
+///
+/// - `program_id`: The invoked program.
+/// - `stack_height`: The depth of the invocation, where 1 is a top-level
+///   instruction.
+/// - `compute_units_consumed`: The compute units consumed by the invocation,
+///   including the programs it invoked. `None` for programs not logging their
+///   consumption, such as most native programs.
+/// - `own_compute_units`: The compute units consumed by the program itself,
+///   excluding the programs it invoked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramInvocation {
+    pub program_id: Pubkey,
+    pub stack_height: u32,
+    pub compute_units_consumed: Option<u64>,
+    pub own_compute_units: Option<u64>,
+}
+
+/// Extracts the program invocations of a transaction from its log messages.
+///
+/// The `Program <id> invoke [<depth>]`, `Program <id> consumed <x> of <y>
+/// compute units` and `Program <id> success` (or `failed`) lines are followed
+/// to attribute compute units to each invocation.
+///
+/// # Parameters
+///
+/// - `log_messages`: The log messages of the transaction.
+///
+/// # Returns
+///
+/// The invocations in execution order, which is the order of the instructions
+/// returned by `extract_instructions_with_metadata`.
+///
+/// # Notes
+///
+/// Logs truncated by the runtime only contain the first invocations.
+pub fn extract_program_invocations(log_messages: &[String]) -> Vec<ProgramInvocation> {
+    log::trace!(
+        "extract_program_invocations(log_messages: {:?})",
+        log_messages
+    );
+
+    let mut invocations = Vec::<ProgramInvocation>::new();
+    // The invocations still executing, with the compute units consumed by the
+    // programs they invoked.
+    let mut stack = Vec::<(usize, u64)>::new();
+
+    for log_message in log_messages {
+        let Some(log_message) = log_message.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut parts = log_message.split_whitespace();
+        let (Some(program_id), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let Ok(program_id) = Pubkey::from_str(program_id) else {
+            continue;
+        };
+
+        match action {
+            "invoke" => {
+                let stack_height = parts
+                    .next()
+                    .and_then(|depth| depth.trim_matches(['[', ']']).parse().ok())
+                    .unwrap_or(stack.len() as u32 + 1);
+
+                stack.push((invocations.len(), 0));
+                invocations.push(ProgramInvocation {
+                    program_id,
+                    stack_height,
+                    compute_units_consumed: None,
+                    own_compute_units: None,
+                });
+            }
+            "consumed" => {
+                let Some(units) = parts.next().and_then(|units| units.parse::<u64>().ok()) else {
+                    continue;
+                };
+
+                if let Some((index, invoked_units)) = stack.last() {
+                    let invocation = &mut invocations[*index];
+                    if invocation.program_id == program_id {
+                        invocation.compute_units_consumed = Some(units);
+                        invocation.own_compute_units = Some(units.saturating_sub(*invoked_units));
+                    }
+                }
+            }
+            "success" | "failed" | "failed:" => {
+                if let Some((index, _)) = stack.pop() {
+                    if let (Some(units), Some((_, invoked_units))) =
+                        (invocations[index].compute_units_consumed, stack.last_mut())
+                    {
+                        *invoked_units += units;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    invocations
+}
+
+/// Sums the compute units consumed by each program, excluding the programs it
+/// invoked.
+///
+/// # Parameters
+///
+/// - `invocations`: The invocations returned by
+///   `extract_program_invocations`.
+///
+/// # Returns
+///
+/// The compute units consumed by each program that logged its consumption.
+pub fn compute_units_by_program(invocations: &[ProgramInvocation]) -> HashMap<Pubkey, u64> {
+    let mut compute_units = HashMap::new();
+
+    for invocation in invocations {
+        if let Some(units) = invocation.own_compute_units {
+            *compute_units.entry(invocation.program_id).or_insert(0) += units;
+        }
+    }
+
+    compute_units
+}
+
+/// Sets the `compute_units_consumed` of each instruction from the logs.
+///
+/// Instructions and invocations are matched in execution order, and matching
+/// stops at the first program mismatch, for example when the logs were
+/// truncated, leaving the remaining instructions without compute units.
+fn attach_compute_units(
+    instructions_with_metadata: &mut [(InstructionMetadata, solana_instruction::Instruction)],
+    log_messages: &[String],
+) {
+    let invocations = extract_program_invocations(log_messages);
+
+    for ((metadata, instruction), invocation) in
+        instructions_with_metadata.iter_mut().zip(invocations)
+    {
+        if instruction.program_id != invocation.program_id {
+            break;
+        }
+
+        metadata.compute_units_consumed = invocation.compute_units_consumed;
+    }
+}
+
 /// Extracts account metadata from a compiled instruction and transaction
 /// message.
 ///
@@ -320,6 +505,27 @@ pub fn extract_account_metas(
     Ok(accounts)
 }
 
+/// Advances `path` to the position of the next inner instruction and returns
+/// it.
+///
+/// `path` starts as the index of the top-level instruction. Inner instructions
+/// are listed in execution order, so an instruction one level deeper than the
+/// previous one is its first child, and an instruction at the same or a higher
+/// level is the next sibling at that level. Inner instructions without a stack
+/// height are treated as direct children of the top-level instruction.
+fn next_inner_instruction_path(path: &mut Vec<u8>, stack_height: Option<u32>) -> Vec<u8> {
+    let depth = stack_height.unwrap_or(2).max(2) as usize;
+
+    if path.len() >= depth {
+        path.truncate(depth);
+        path[depth - 1] = path[depth - 1].wrapping_add(1);
+    } else {
+        path.resize(depth, 0);
+    }
+
+    path.clone()
+}
+
 /// Unnests parsed instructions, producing an array of `(InstructionMetadata,
 /// DecodedInstruction<T>)` tuple
 ///
@@ -339,6 +545,12 @@ pub fn extract_account_metas(
 ///
 /// A vector of `(InstructionMetadata, DecodedInstruction<T>)` tuples
 /// representing the unnested instructions.
+///
+/// # Notes
+///
+/// - The `absolute_path` of each instruction is built from its position among
+///   the parsed instructions, which only include the instructions recognized
+///   by the decoder collection.
 pub fn unnest_parsed_instructions<T: InstructionDecoderCollection>(
     transaction_metadata: TransactionMetadata,
     instructions: Vec<ParsedInstruction<T>>,
@@ -349,27 +561,67 @@ pub fn unnest_parsed_instructions<T: InstructionDecoderCollection>(
         instructions
     );
 
+    unnest_parsed_instructions_at(transaction_metadata, instructions, stack_height, &[])
+}
+
+fn unnest_parsed_instructions_at<T: InstructionDecoderCollection>(
+    transaction_metadata: TransactionMetadata,
+    instructions: Vec<ParsedInstruction<T>>,
+    stack_height: u32,
+    parent_path: &[u8],
+) -> Vec<(InstructionMetadata, DecodedInstruction<T>)> {
     let mut result = Vec::new();
 
     for (ix_idx, parsed_instruction) in instructions.into_iter().enumerate() {
+        let mut absolute_path = parent_path.to_vec();
+        absolute_path.push(ix_idx as u8);
+
         result.push((
             InstructionMetadata {
                 transaction_metadata: transaction_metadata.clone(),
                 stack_height,
                 index: ix_idx as u32 + 1,
+                absolute_path: absolute_path.clone(),
+                compute_units_consumed: None,
             },
             parsed_instruction.instruction,
         ));
-        result.extend(unnest_parsed_instructions(
+        result.extend(unnest_parsed_instructions_at(
             transaction_metadata.clone(),
             parsed_instruction.inner_instructions,
             stack_height + 1,
+            &absolute_path,
         ));
     }
 
     result
 }
 
+/// Extracts the `BlockDetails` of a block returned by the RPC.
+///
+/// # Parameters
+///
+/// - `slot`: The slot of the block.
+/// - `block`: The block, as returned by `getBlock` or `blockSubscribe`.
+///
+/// # Returns
+///
+/// The `BlockDetails` of the block. The rewards are empty if they were not
+/// requested.
+pub fn block_details_from_ui_block(slot: u64, block: &UiConfirmedBlock) -> BlockDetails {
+    log::trace!("block_details_from_ui_block(slot: {:?}, block)", slot);
+
+    BlockDetails {
+        slot,
+        parent_slot: block.parent_slot,
+        block_hash: block.blockhash.clone(),
+        previous_block_hash: block.previous_blockhash.clone(),
+        block_height: block.block_height,
+        block_time: block.block_time,
+        rewards: block.rewards.clone().unwrap_or_default(),
+    }
+}
+
 /// Converts UI transaction metadata into `TransactionStatusMeta`.
 ///
 /// This function transforms the user interface format of transaction metadata
@@ -541,12 +793,280 @@ pub fn transaction_metadata_from_original_meta(
     })
 }
 
+/// Converts a transaction fetched with `getTransaction` into a
+/// `TransactionUpdate`.
+///
+/// # Parameters
+///
+/// - `signature`: The signature of the transaction.
+/// - `confirmed_transaction`: The transaction, as returned by `getTransaction`.
+///
+/// # Returns
+///
+/// The `TransactionUpdate` of the transaction, with its slot and block time.
+///
+/// # Errors
+///
+/// See `transaction_update_from_encoded_transaction`.
+pub fn transaction_update_from_confirmed_transaction(
+    signature: Signature,
+    confirmed_transaction: EncodedConfirmedTransactionWithStatusMeta,
+) -> CarbonResult<TransactionUpdate> {
+    log::trace!(
+        "transaction_update_from_confirmed_transaction(signature: {:?}, confirmed_transaction)",
+        signature
+    );
+
+    transaction_update_from_encoded_transaction(
+        signature,
+        confirmed_transaction.slot,
+        confirmed_transaction.block_time,
+        confirmed_transaction.transaction,
+    )
+}
+
+/// Converts an encoded transaction and its UI metadata into a
+/// `TransactionUpdate`.
+///
+/// This is the format of the transactions returned by `getTransaction`,
+/// `getBlock` and `blockSubscribe`. The binary encodings (`base58`, `base64`)
+/// and the `json` encoding are supported, for both legacy and v0
+/// transactions.
+///
+/// # Parameters
+///
+/// - `signature`: The signature of the transaction.
+/// - `slot`: The slot of the transaction.
+/// - `block_time`: The block time of the transaction, if known.
+/// - `encoded_transaction`: The encoded transaction with its metadata.
+///
+/// # Errors
+///
+/// Returns an error if the metadata is missing, if the transaction can't be
+/// decoded, or if it was fetched with the `jsonParsed` encoding, which loses
+/// the instruction data of parsed instructions.
+///
+/// # Notes
+///
+/// RPC responses don't say whether a transaction is a vote, so `is_vote` is
+/// always `false`.
+pub fn transaction_update_from_encoded_transaction(
+    signature: Signature,
+    slot: u64,
+    block_time: Option<i64>,
+    encoded_transaction: EncodedTransactionWithStatusMeta,
+) -> CarbonResult<TransactionUpdate> {
+    log::trace!(
+        "transaction_update_from_encoded_transaction(signature: {:?}, slot: {:?}, block_time: {:?}, encoded_transaction)",
+        signature,
+        slot,
+        block_time
+    );
+
+    let meta = encoded_transaction
+        .meta
+        .ok_or_else(|| Error::Custom(format!("Transaction {} has no status meta", signature)))?;
+
+    let transaction = match encoded_transaction.transaction {
+        EncodedTransaction::Json(ui_transaction) => {
+            let UiMessage::Raw(message) = ui_transaction.message else {
+                return Err(Error::Custom(format!(
+                    "Transaction {} was fetched with the jsonParsed encoding, which can't be converted",
+                    signature
+                )));
+            };
+
+            VersionedTransaction {
+                signatures: ui_transaction
+                    .signatures
+                    .iter()
+                    .map(|signature| {
+                        Signature::from_str(signature).map_err(|error| {
+                            Error::Custom(format!("Invalid signature {}: {}", signature, error))
+                        })
+                    })
+                    .collect::<CarbonResult<Vec<Signature>>>()?,
+                message: versioned_message_from_ui_raw_message(message)?,
+            }
+        }
+        encoded => encoded
+            .decode()
+            .ok_or_else(|| Error::Custom(format!("Failed to decode transaction {}", signature)))?,
+    };
+
+    Ok(TransactionUpdate {
+        signature,
+        transaction,
+        meta: transaction_metadata_from_original_meta(meta)?,
+        is_vote: false,
+        slot,
+        block_time,
+        commitment: None,
+    })
+}
+
+fn versioned_message_from_ui_raw_message(message: UiRawMessage) -> CarbonResult<VersionedMessage> {
+    let account_keys = message
+        .account_keys
+        .iter()
+        .map(|account_key| parse_pubkey(account_key))
+        .collect::<CarbonResult<Vec<Pubkey>>>()?;
+
+    let recent_blockhash = Hash::from_str(&message.recent_blockhash).map_err(|error| {
+        Error::Custom(format!(
+            "Invalid recent blockhash {}: {}",
+            message.recent_blockhash, error
+        ))
+    })?;
+
+    let instructions = message
+        .instructions
+        .into_iter()
+        .map(|instruction| {
+            Ok(CompiledInstruction {
+                program_id_index: instruction.program_id_index,
+                accounts: instruction.accounts,
+                data: bs58::decode(&instruction.data)
+                    .into_vec()
+                    .map_err(|error| {
+                        Error::Custom(format!("Invalid instruction data: {}", error))
+                    })?,
+            })
+        })
+        .collect::<CarbonResult<Vec<CompiledInstruction>>>()?;
+
+    // Only v0 messages carry address table lookups, even when empty.
+    Ok(match message.address_table_lookups {
+        Some(address_table_lookups) => VersionedMessage::V0(v0::Message {
+            header: message.header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups: address_table_lookups
+                .iter()
+                .map(|lookup| {
+                    Ok(MessageAddressTableLookup {
+                        account_key: parse_pubkey(&lookup.account_key)?,
+                        writable_indexes: lookup.writable_indexes.clone(),
+                        readonly_indexes: lookup.readonly_indexes.clone(),
+                    })
+                })
+                .collect::<CarbonResult<Vec<MessageAddressTableLookup>>>()?,
+        }),
+        None => VersionedMessage::Legacy(legacy::Message {
+            header: message.header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+        }),
+    })
+}
+
+/// Converts an account returned by `getAccountInfo` or
+/// `getMultipleAccounts` into an `AccountUpdate`.
+///
+/// # Parameters
+///
+/// - `pubkey`: The address of the account.
+/// - `account`: The account, fetched with a binary encoding (`base58`,
+///   `base64` or `base64+zstd`).
+/// - `slot`: The slot of the response context.
+///
+/// # Errors
+///
+/// Returns an error if the account data can't be decoded, such as when it was
+/// fetched with the `jsonParsed` encoding.
+///
+/// # Example
+///
+/// ```ignore
+/// let response = rpc_client
+///     .get_account_with_config(&pubkey, RpcAccountInfoConfig {
+///         encoding: Some(UiAccountEncoding::Base64),
+///         ..Default::default()
+///     })
+///     .await?;
+/// ```
+pub fn account_update_from_ui_account(
+    pubkey: Pubkey,
+    account: &UiAccount,
+    slot: u64,
+) -> CarbonResult<AccountUpdate> {
+    log::trace!(
+        "account_update_from_ui_account(pubkey: {:?}, account, slot: {:?})",
+        pubkey,
+        slot
+    );
+
+    let account = account.decode::<Account>().ok_or_else(|| {
+        Error::Custom(format!(
+            "Failed to decode account {}, fetch it with a binary encoding",
+            pubkey
+        ))
+    })?;
+
+    Ok(AccountUpdate {
+        pubkey,
+        account,
+        slot,
+        write_version: None,
+        commitment: None,
+    })
+}
+
+/// Converts an account returned by `getProgramAccounts` or `programSubscribe`
+/// into an `AccountUpdate`.
+///
+/// # Errors
+///
+/// Returns an error if the pubkey is invalid or the account data can't be
+/// decoded.
+pub fn account_update_from_keyed_account(
+    keyed_account: &RpcKeyedAccount,
+    slot: u64,
+) -> CarbonResult<AccountUpdate> {
+    account_update_from_ui_account(
+        parse_pubkey(&keyed_account.pubkey)?,
+        &keyed_account.account,
+        slot,
+    )
+}
+
+/// Converts the accounts returned by `getProgramAccounts` into
+/// `AccountUpdate`s, all at the given `slot`.
+///
+/// # Errors
+///
+/// Returns the first error of `account_update_from_keyed_account`.
+pub fn account_updates_from_program_accounts(
+    keyed_accounts: &[RpcKeyedAccount],
+    slot: u64,
+) -> CarbonResult<Vec<AccountUpdate>> {
+    log::trace!(
+        "account_updates_from_program_accounts(keyed_accounts: {} accounts, slot: {:?})",
+        keyed_accounts.len(),
+        slot
+    );
+
+    keyed_accounts
+        .iter()
+        .map(|keyed_account| account_update_from_keyed_account(keyed_account, slot))
+        .collect()
+}
+
+fn parse_pubkey(pubkey: &str) -> CarbonResult<Pubkey> {
+    Pubkey::from_str(pubkey)
+        .map_err(|error| Error::Custom(format!("Invalid pubkey {}: {}", pubkey, error)))
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
 
     use carbon_test_utils::base58_deserialize;
-    use solana_account_decoder_client_types::token::UiTokenAmount;
+    use solana_account_decoder_client_types::{
+        token::UiTokenAmount, UiAccountData, UiAccountEncoding,
+    };
     use solana_sdk::{
         hash::Hash,
         message::{
@@ -557,11 +1077,36 @@ mod tests {
         transaction::VersionedTransaction,
     };
     use solana_signature::Signature;
+    use solana_transaction_status::{EncodableWithMeta, TransactionVersion, UiTransactionEncoding};
 
     use crate::instruction::{InstructionsWithMetadata, NestedInstructions};
 
     use super::*;
 
+    #[test]
+    fn test_next_inner_instruction_path() {
+        // Arrange
+        let stack_heights = [Some(2), Some(3), Some(3), Some(4), Some(2), None];
+        let expected_paths: Vec<Vec<u8>> = vec![
+            vec![1, 0],
+            vec![1, 0, 0],
+            vec![1, 0, 1],
+            vec![1, 0, 1, 0],
+            vec![1, 1],
+            vec![1, 2],
+        ];
+
+        // Act
+        let mut path = vec![1];
+        let paths: Vec<Vec<u8>> = stack_heights
+            .iter()
+            .map(|stack_height| next_inner_instruction_path(&mut path, *stack_height))
+            .collect();
+
+        // Assert
+        assert_eq!(paths, expected_paths);
+    }
+
     #[test]
     fn test_transaction_metadata_from_original_meta_simple() {
         // Arrange
@@ -790,6 +1335,7 @@ mod tests {
             is_vote: false,
             slot: 123,
             block_time: Some(123),
+            commitment: None,
         };
         let transaction_metadata = transaction_update
             .clone()
@@ -1214,6 +1760,7 @@ mod tests {
             is_vote: false,
             slot: 123,
             block_time: Some(123),
+            commitment: None,
         };
         let transaction_metadata = transaction_update
             .clone()
@@ -1232,4 +1779,164 @@ mod tests {
         assert_eq!(nested_instructions[2].inner_instructions.len(), 0);
         assert_eq!(nested_instructions[3].inner_instructions.len(), 5);
     }
+
+    #[test]
+    fn test_extract_program_invocations() {
+        // Arrange
+        let program = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+        let compute_budget = Pubkey::new_unique();
+        let log_messages: Vec<String> = vec![
+            format!("Program {} invoke [1]", compute_budget),
+            format!("Program {} success", compute_budget),
+            format!("Program {} invoke [1]", program),
+            "Program log: Instruction: Buy".to_string(),
+            format!("Program {} invoke [2]", token_program),
+            format!(
+                "Program {} consumed 4645 of 190000 compute units",
+                token_program
+            ),
+            format!("Program {} success", token_program),
+            format!("Program {} consumed 30000 of 199850 compute units", program),
+            format!("Program {} success", program),
+        ];
+
+        // Act
+        let invocations = extract_program_invocations(&log_messages);
+        let by_program = compute_units_by_program(&invocations);
+
+        // Assert
+        assert_eq!(
+            invocations,
+            vec![
+                ProgramInvocation {
+                    program_id: compute_budget,
+                    stack_height: 1,
+                    compute_units_consumed: None,
+                    own_compute_units: None,
+                },
+                ProgramInvocation {
+                    program_id: program,
+                    stack_height: 1,
+                    compute_units_consumed: Some(30000),
+                    own_compute_units: Some(25355),
+                },
+                ProgramInvocation {
+                    program_id: token_program,
+                    stack_height: 2,
+                    compute_units_consumed: Some(4645),
+                    own_compute_units: Some(4645),
+                },
+            ]
+        );
+        assert_eq!(by_program.get(&program), Some(&25355));
+        assert_eq!(by_program.get(&token_program), Some(&4645));
+        assert_eq!(by_program.get(&compute_budget), None);
+    }
+
+    #[test]
+    fn test_transaction_update_from_encoded_transaction() {
+        // Arrange
+        let signature = Signature::from([1; 64]);
+        let transaction = VersionedTransaction {
+            signatures: vec![signature],
+            message: VersionedMessage::V0(v0::Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                recent_blockhash: Hash::new_unique(),
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 1,
+                    accounts: vec![0, 2],
+                    data: vec![1, 2, 3],
+                }],
+                address_table_lookups: vec![MessageAddressTableLookup {
+                    account_key: Pubkey::new_unique(),
+                    writable_indexes: vec![4],
+                    readonly_indexes: vec![],
+                }],
+            }),
+        };
+        let meta = TransactionStatusMeta {
+            loaded_addresses: LoadedAddresses {
+                writable: vec![Pubkey::new_unique()],
+                readonly: vec![],
+            },
+            ..Default::default()
+        };
+
+        for encoding in [UiTransactionEncoding::Json, UiTransactionEncoding::Base64] {
+            let encoded_transaction = EncodedTransactionWithStatusMeta {
+                transaction: transaction.encode_with_meta(encoding, &meta),
+                meta: Some(UiTransactionStatusMeta::from(meta.clone())),
+                version: Some(TransactionVersion::Number(0)),
+            };
+
+            // Act
+            let transaction_update = transaction_update_from_encoded_transaction(
+                signature,
+                42,
+                Some(1_700_000_000),
+                encoded_transaction,
+            )
+            .expect("transaction update from encoded transaction");
+
+            // Assert
+            assert_eq!(transaction_update.signature, signature);
+            assert_eq!(transaction_update.transaction, transaction);
+            assert_eq!(
+                transaction_update.meta.loaded_addresses,
+                meta.loaded_addresses
+            );
+            assert_eq!(transaction_update.slot, 42);
+            assert_eq!(transaction_update.block_time, Some(1_700_000_000));
+        }
+    }
+
+    #[test]
+    fn test_account_updates_from_program_accounts() {
+        // Arrange
+        let pubkey = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let data = vec![1, 2, 3, 4];
+        let keyed_accounts = [
+            UiAccountData::Binary(bs58::encode(&data).into_string(), UiAccountEncoding::Base58),
+            UiAccountData::LegacyBinary(bs58::encode(&data).into_string()),
+        ]
+        .map(|data| RpcKeyedAccount {
+            pubkey: pubkey.to_string(),
+            account: UiAccount {
+                lamports: 10,
+                data,
+                owner: owner.to_string(),
+                executable: false,
+                rent_epoch: 5,
+                space: Some(4),
+            },
+        });
+
+        // Act
+        let account_updates =
+            account_updates_from_program_accounts(&keyed_accounts, 42).expect("account updates");
+
+        // Assert
+        assert_eq!(account_updates.len(), 2);
+        for account_update in account_updates {
+            assert_eq!(account_update.pubkey, pubkey);
+            assert_eq!(
+                account_update.account,
+                Account {
+                    lamports: 10,
+                    data: data.clone(),
+                    owner,
+                    executable: false,
+                    rent_epoch: 5,
+                }
+            );
+            assert_eq!(account_update.slot, 42);
+        }
+    }
 }
@@ -0,0 +1,374 @@
+//! Adds decimal-adjusted amounts to decoded outputs, so sink consumers do not
+//! have to scale raw token amounts themselves.
+//!
+//! Decoded events and instructions hold raw `u64` amounts, in the smallest
+//! unit of their mint. `UiAmounts` rewrites the JSON value of decoded data,
+//! adding a `ui_<field>` value next to each configured amount field, equal to
+//! the raw amount divided by `10^decimals`.
+//!
+//! The decimals of a mint are read from a `MintDecimalsCache`, filled with
+//! `MintDecimalsCache::insert` or, with `PipelineBuilder::mint_decimals`, from
+//! the token balances of the transactions processed by the pipeline. Replicas
+//! can share it through a `KeyValueStore` with `MintDecimalsCache::load_from`
+//! and `MintDecimalsCache::save_to`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let decimals = MintDecimalsCache::new();
+//! decimals.insert(WSOL_MINT, 9);
+//!
+//! let encoder = UiAmounts::new(decimals.clone())
+//!     .amount("token_amount", "mint")
+//!     .fixed("sol_amount", 9)
+//!     .encoder();
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .mint_decimals(decimals)
+//!     .instruction(PumpfunDecoder, sink.instructions(encoder))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The mint field must hold the mint as a base58 string, as written by
+//!   `CarbonSerialize`.
+//! - Amounts whose decimals are unknown are left without a `ui_` value, rather
+//!   than guessed.
+//! - `ui_` values are `f64`, exact for amounts up to `2^53` raw units.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        instruction::NestedInstruction,
+        kv_store::KeyValueStore,
+        metrics::MetricsCollection,
+        transaction::{TransactionMetadata, TransactionPipes},
+    },
+    async_trait::async_trait,
+    serde::Serialize,
+    serde_json::{Map, Value},
+    solana_pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        str::FromStr,
+        sync::{Arc, RwLock},
+    },
+};
+
+/// The decimals of the mints seen so far, shared between the pipeline feeding
+/// it and the encoders reading it.
+#[derive(Debug, Clone, Default)]
+pub struct MintDecimalsCache {
+    decimals: Arc<RwLock<HashMap<Pubkey, u8>>>,
+}
+
+impl MintDecimalsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the decimals of a mint, replacing any previous value.
+    pub fn insert(&self, mint: Pubkey, decimals: u8) {
+        if let Ok(mut cache) = self.decimals.write() {
+            cache.insert(mint, decimals);
+        }
+    }
+
+    /// Returns the decimals of a mint, if known.
+    pub fn get(&self, mint: &Pubkey) -> Option<u8> {
+        self.decimals.read().ok()?.get(mint).copied()
+    }
+
+    /// Returns the number of mints with known decimals.
+    pub fn len(&self) -> usize {
+        self.decimals.read().map(|cache| cache.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Merges the decimals saved under `key` in `store`, such as by another
+    /// replica, returning how many mints were not known yet.
+    pub async fn load_from(&self, store: &dyn KeyValueStore, key: &str) -> CarbonResult<usize> {
+        let Some(value) = store.get(key).await? else {
+            return Ok(0);
+        };
+        let saved: HashMap<String, u8> = serde_json::from_slice(&value)
+            .map_err(|err| Error::Custom(format!("Invalid mint decimals {}: {}", key, err)))?;
+
+        let Ok(mut cache) = self.decimals.write() else {
+            return Ok(0);
+        };
+
+        let mut recorded = 0;
+        for (mint, decimals) in saved {
+            let Ok(mint) = Pubkey::from_str(&mint) else {
+                continue;
+            };
+
+            if cache.insert(mint, decimals).is_none() {
+                recorded += 1;
+            }
+        }
+
+        Ok(recorded)
+    }
+
+    /// Saves the known decimals under `key` in `store`, as a JSON object of
+    /// decimals by mint.
+    pub async fn save_to(&self, store: &dyn KeyValueStore, key: &str) -> CarbonResult<()> {
+        let saved: HashMap<String, u8> = match self.decimals.read() {
+            Ok(cache) => cache
+                .iter()
+                .map(|(mint, decimals)| (mint.to_string(), *decimals))
+                .collect(),
+            Err(_) => return Ok(()),
+        };
+        let value = serde_json::to_vec(&saved)
+            .map_err(|err| Error::Custom(format!("Failed to serialize mint decimals: {}", err)))?;
+
+        store.set(key, value, None).await
+    }
+
+    /// Records the decimals of the mints in the pre and post token balances
+    /// of a transaction, returning how many were not known yet.
+    pub fn record_token_balances(&self, transaction_metadata: &TransactionMetadata) -> usize {
+        let meta = &transaction_metadata.meta;
+        let balances = meta
+            .pre_token_balances
+            .iter()
+            .chain(meta.post_token_balances.iter())
+            .flatten();
+
+        let Ok(mut cache) = self.decimals.write() else {
+            return 0;
+        };
+
+        let mut recorded = 0;
+        for balance in balances {
+            let Ok(mint) = Pubkey::from_str(&balance.mint) else {
+                continue;
+            };
+
+            if cache
+                .insert(mint, balance.ui_token_amount.decimals)
+                .is_none()
+            {
+                recorded += 1;
+            }
+        }
+
+        recorded
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Decimals {
+    /// The decimals of the mint in this field of the same object.
+    Mint(String),
+    Fixed(u8),
+}
+
+/// Adds `ui_<field>` values to the JSON value of decoded data for the
+/// configured amount fields.
+#[derive(Debug, Clone)]
+pub struct UiAmounts {
+    cache: MintDecimalsCache,
+    fields: Vec<(String, Decimals)>,
+}
+
+impl UiAmounts {
+    pub fn new(cache: MintDecimalsCache) -> Self {
+        Self {
+            cache,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Scales `amount_field` by the decimals of the mint in `mint_field` of
+    /// the same object.
+    pub fn amount(mut self, amount_field: &str, mint_field: &str) -> Self {
+        self.fields.push((
+            amount_field.to_string(),
+            Decimals::Mint(mint_field.to_string()),
+        ));
+        self
+    }
+
+    /// Scales `amount_field` by fixed decimals, such as 9 for lamports.
+    pub fn fixed(mut self, amount_field: &str, decimals: u8) -> Self {
+        self.fields
+            .push((amount_field.to_string(), Decimals::Fixed(decimals)));
+        self
+    }
+
+    /// Adds the `ui_` values to every object of `value` holding a configured
+    /// amount field, at any depth, so tagged enums such as
+    /// `{"TradeEvent": {...}}` are covered.
+    pub fn apply(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for nested in map.values_mut() {
+                    self.apply(nested);
+                }
+                self.apply_fields(map);
+            }
+            Value::Array(values) => {
+                for nested in values {
+                    self.apply(nested);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns an encoder serializing decoded data to JSON and adding the
+    /// `ui_` values, usable wherever a sink takes an `Encoder<T>`.
+    pub fn encoder<T: Serialize>(self) -> Arc<dyn Fn(&T) -> Option<Value> + Send + Sync> {
+        Arc::new(move |data: &T| {
+            let mut value = serde_json::to_value(data).ok()?;
+            self.apply(&mut value);
+            Some(value)
+        })
+    }
+
+    fn apply_fields(&self, map: &mut Map<String, Value>) {
+        for (field, decimals) in &self.fields {
+            let Some(amount) = map.get(field).and_then(Value::as_u64) else {
+                continue;
+            };
+
+            let decimals = match decimals {
+                Decimals::Fixed(decimals) => Some(*decimals),
+                Decimals::Mint(mint_field) => map
+                    .get(mint_field)
+                    .and_then(Value::as_str)
+                    .and_then(|mint| Pubkey::from_str(mint).ok())
+                    .and_then(|mint| self.cache.get(&mint)),
+            };
+
+            if let Some(decimals) = decimals {
+                map.insert(format!("ui_{}", field), ui_amount(amount, decimals));
+            }
+        }
+    }
+}
+
+/// Returns `amount / 10^decimals` as a JSON number.
+fn ui_amount(amount: u64, decimals: u8) -> Value {
+    let ui_amount = amount as f64 / 10f64.powi(decimals as i32);
+    serde_json::Number::from_f64(ui_amount)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+/// A transaction pipe recording the decimals of the mints in the token
+/// balances of each transaction.
+pub struct MintDecimalsPipe {
+    pub cache: MintDecimalsCache,
+}
+
+#[async_trait]
+impl TransactionPipes<'_> for MintDecimalsPipe {
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        _instructions: &[NestedInstruction],
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let recorded = self.cache.record_token_balances(&transaction_metadata);
+
+        if recorded > 0 {
+            metrics
+                .increment_counter("mint_decimals_recorded", recorded as u64)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn decode_only(&self, _instructions: &[NestedInstruction]) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, serde_json::json, solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_transaction_status::TransactionTokenBalance,
+    };
+
+    #[test]
+    fn test_record_token_balances() {
+        // Arrange
+        let mint = Pubkey::new_unique();
+        let mut transaction_metadata = TransactionMetadata::default();
+        transaction_metadata.meta.post_token_balances = Some(vec![TransactionTokenBalance {
+            account_index: 1,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: Some(1.5),
+                decimals: 6,
+                amount: "1500000".to_string(),
+                ui_amount_string: "1.5".to_string(),
+            },
+            owner: Pubkey::new_unique().to_string(),
+            program_id: Pubkey::new_unique().to_string(),
+        }]);
+        let cache = MintDecimalsCache::new();
+
+        // Act
+        let recorded = cache.record_token_balances(&transaction_metadata);
+
+        // Assert
+        assert_eq!(recorded, 1);
+        assert_eq!(cache.get(&mint), Some(6));
+        assert_eq!(cache.record_token_balances(&transaction_metadata), 0);
+    }
+
+    #[test]
+    fn test_apply_adds_ui_amounts() {
+        // Arrange
+        let known_mint = Pubkey::new_unique();
+        let unknown_mint = Pubkey::new_unique();
+        let cache = MintDecimalsCache::new();
+        cache.insert(known_mint, 6);
+
+        let ui_amounts = UiAmounts::new(cache)
+            .amount("token_amount", "mint")
+            .fixed("sol_amount", 9);
+
+        let mut value = json!({
+            "TradeEvent": {
+                "mint": known_mint.to_string(),
+                "token_amount": 2_500_000,
+                "sol_amount": 1_000_000_000u64,
+                "trades": [{ "mint": unknown_mint.to_string(), "token_amount": 7 }],
+            }
+        });
+
+        // Act
+        ui_amounts.apply(&mut value);
+
+        // Assert
+        assert_eq!(
+            value,
+            json!({
+                "TradeEvent": {
+                    "mint": known_mint.to_string(),
+                    "token_amount": 2_500_000,
+                    "ui_token_amount": 2.5,
+                    "sol_amount": 1_000_000_000u64,
+                    "ui_sol_amount": 1.0,
+                    "trades": [{ "mint": unknown_mint.to_string(), "token_amount": 7 }],
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Verifies the signatures of transactions from less trusted datasources.
+//!
+//! Updates from a validator plugin can be trusted, but updates from a
+//! third-party stream or a file may be corrupted or forged. With
+//! `PipelineBuilder::verify_transactions`, each transaction is sanity checked
+//! before it is decoded: its structure must be valid, its signature must be
+//! the first signature of the transaction, and every signature must verify
+//! against the serialized message and the signer it belongs to.
+//!
+//! Transactions failing verification are counted by the
+//! `transactions_unverified` counter and a counter for the failure, such as
+//! `transactions_unverified_invalid_signature`, then either dropped or passed
+//! to the pipes with the failure in `TransactionMetadata::verification_failure`,
+//! depending on the `VerificationMode`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(third_party_stream)
+//!     .instruction(PumpfunDecoder, PumpfunProcessor)
+//!     .verify_transactions(VerificationMode::Drop)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Verifying a signature costs tens of microseconds, so enable verification
+//!   only on pipelines consuming less trusted datasources, and run trusted
+//!   datasources in their own pipeline.
+//! - Only the transaction itself is verified: its status meta, such as its
+//!   logs and balances, is not signed and is taken as is.
+//! - Account updates are not signed and are never verified.
+
+use {crate::datasource::TransactionUpdate, serde::Serialize, std::fmt};
+
+/// What happens to the transactions failing verification.
+///
+/// - `Flag`: They are passed to the pipes, with the failure in
+///   `TransactionMetadata::verification_failure`.
+/// - `Drop`: They are skipped: no pipe processes them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationMode {
+    Flag,
+    Drop,
+}
+
+/// Why a transaction failed verification.
+///
+/// - `Malformed`: The transaction is inconsistent, such as a signature count
+///   differing from the number of required signers, or an instruction
+///   referencing a missing account.
+/// - `SignatureMismatch`: The signature of the update is not the first
+///   signature of the transaction.
+/// - `InvalidSignature`: A signature does not verify against the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationFailure {
+    Malformed,
+    SignatureMismatch,
+    InvalidSignature,
+}
+
+impl VerificationFailure {
+    /// The name of the counter incremented for transactions failing
+    /// verification for this reason.
+    pub fn metric_name(&self) -> &'static str {
+        match self {
+            VerificationFailure::Malformed => "transactions_unverified_malformed",
+            VerificationFailure::SignatureMismatch => "transactions_unverified_signature_mismatch",
+            VerificationFailure::InvalidSignature => "transactions_unverified_invalid_signature",
+        }
+    }
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationFailure::Malformed => write!(f, "malformed transaction"),
+            VerificationFailure::SignatureMismatch => {
+                write!(f, "signature differs from the transaction's")
+            }
+            VerificationFailure::InvalidSignature => write!(f, "invalid signature"),
+        }
+    }
+}
+
+/// Checks the structure and the signatures of a transaction update,
+/// returning why it failed, if it did.
+pub fn verify_transaction(transaction_update: &TransactionUpdate) -> Option<VerificationFailure> {
+    let transaction = &transaction_update.transaction;
+
+    if transaction.sanitize().is_err() {
+        return Some(VerificationFailure::Malformed);
+    }
+
+    if transaction.signatures.first() != Some(&transaction_update.signature) {
+        return Some(VerificationFailure::SignatureMismatch);
+    }
+
+    // Serializes the message again and verifies each signature against it.
+    if transaction.verify_and_hash_message().is_err() {
+        return Some(VerificationFailure::InvalidSignature);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_sdk::{
+            hash::Hash,
+            message::{Message, VersionedMessage},
+            signature::{Keypair, Signature},
+            signer::Signer,
+            transaction::VersionedTransaction,
+        },
+        solana_transaction_status::TransactionStatusMeta,
+    };
+
+    fn transaction_update(transaction: VersionedTransaction) -> TransactionUpdate {
+        TransactionUpdate {
+            signature: transaction.signatures[0],
+            transaction,
+            meta: TransactionStatusMeta::default(),
+            is_vote: false,
+            slot: 1,
+            block_time: None,
+            commitment: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_transaction() {
+        // Arrange
+        let payer = Keypair::new();
+        let message = Message::new_with_blockhash(&[], Some(&payer.pubkey()), &Hash::new_unique());
+        let signed =
+            VersionedTransaction::try_new(VersionedMessage::Legacy(message), &[&payer]).unwrap();
+
+        let mut forged = signed.clone();
+        forged.signatures[0] = Signature::new_unique();
+
+        let mut mismatched = transaction_update(signed.clone());
+        mismatched.signature = Signature::new_unique();
+
+        let mut malformed = signed.clone();
+        malformed.signatures.push(Signature::new_unique());
+
+        // Act
+        let valid = verify_transaction(&transaction_update(signed));
+        let forged = verify_transaction(&transaction_update(forged));
+        let mismatched = verify_transaction(&mismatched);
+        let malformed = verify_transaction(&transaction_update(malformed));
+
+        // Assert
+        assert_eq!(valid, None);
+        assert_eq!(forged, Some(VerificationFailure::InvalidSignature));
+        assert_eq!(mismatched, Some(VerificationFailure::SignatureMismatch));
+        assert_eq!(malformed, Some(VerificationFailure::Malformed));
+    }
+}
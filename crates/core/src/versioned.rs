@@ -0,0 +1,340 @@
+//! Provides decoders that select between several layout versions of the same
+//! account or instruction type.
+//!
+//! Programs change their layouts across upgrades. A `VersionedAccountDecoder`
+//! or `VersionedInstructionDecoder` wraps one decoder per layout version, each
+//! paired with a `VersionSelector` describing when that version applies. This
+//! lets a single decoder crate decode both pre- and post-upgrade data.
+//!
+//! When the pipeline decodes an update, it passes the slot of the update to
+//! the decoder through `AccountDecoder::decode_account_at_slot` and
+//! `InstructionDecoder::decode_instruction_at_slot`. The versioned decoders
+//! use the first version whose selector matches that slot. Without a slot,
+//! they fall back to the first version able to decode the data.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let decoder = VersionedAccountDecoder::new()
+//!     .version(VersionSelector::until_slot(250_000_000), DlmmV1AccountDecoder)
+//!     .version(VersionSelector::from_slot(250_000_000), DlmmV2AccountDecoder);
+//!
+//! let pipeline = Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account(decoder, MyAccountProcessor)
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        account::{AccountDecoder, DecodedAccount},
+        instruction::{DecodedInstruction, InstructionDecoder},
+    },
+    std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Decides whether a layout version applies to an update.
+///
+/// # Variants
+///
+/// - `Slots`: Applies to updates with a slot in `start..end`. An `end` of
+///   `None` leaves the range open.
+/// - `Flag`: Applies while the flag is set, regardless of the slot. The flag
+///   can be toggled at runtime, for example when a program upgrade is
+///   detected.
+#[derive(Debug, Clone)]
+pub enum VersionSelector {
+    Slots { start: u64, end: Option<u64> },
+    Flag(Arc<AtomicBool>),
+}
+
+impl VersionSelector {
+    /// Selects updates from `slot` onwards.
+    pub fn from_slot(slot: u64) -> Self {
+        VersionSelector::Slots {
+            start: slot,
+            end: None,
+        }
+    }
+
+    /// Selects updates before `slot`.
+    pub fn until_slot(slot: u64) -> Self {
+        VersionSelector::Slots {
+            start: 0,
+            end: Some(slot),
+        }
+    }
+
+    /// Selects updates with a slot in `start..end`.
+    pub fn slots(start: u64, end: u64) -> Self {
+        VersionSelector::Slots {
+            start,
+            end: Some(end),
+        }
+    }
+
+    pub fn matches(&self, slot: u64) -> bool {
+        match self {
+            VersionSelector::Slots { start, end } => {
+                slot >= *start && end.is_none_or(|end| slot < end)
+            }
+            VersionSelector::Flag(flag) => flag.load(Ordering::Relaxed),
+        }
+    }
+}
+
+type BoxedAccountDecoder<T> =
+    Box<dyn for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static>;
+
+/// An account decoder dispatching to one of several layout versions.
+///
+/// Versions are tried in the order they were added.
+pub struct VersionedAccountDecoder<T> {
+    pub versions: Vec<(VersionSelector, BoxedAccountDecoder<T>)>,
+}
+
+impl<T> Default for VersionedAccountDecoder<T> {
+    fn default() -> Self {
+        Self {
+            versions: Vec::new(),
+        }
+    }
+}
+
+impl<T> VersionedAccountDecoder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layout version, used for updates matching `selector`.
+    pub fn version(
+        mut self,
+        selector: VersionSelector,
+        decoder: impl for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static,
+    ) -> Self {
+        self.versions.push((selector, Box::new(decoder)));
+        self
+    }
+}
+
+impl<'a, T> AccountDecoder<'a> for VersionedAccountDecoder<T> {
+    type AccountType = T;
+
+    fn decode_account(
+        &self,
+        account: &'a solana_account::Account,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        self.versions
+            .iter()
+            .find_map(|(_, decoder)| decoder.decode_account(account))
+    }
+
+    fn decode_account_at_slot(
+        &self,
+        account: &'a solana_account::Account,
+        slot: u64,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        self.versions
+            .iter()
+            .find(|(selector, _)| selector.matches(slot))
+            .and_then(|(_, decoder)| decoder.decode_account_at_slot(account, slot))
+    }
+}
+
+type BoxedInstructionDecoder<T> =
+    Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>;
+
+/// An instruction decoder dispatching to one of several layout versions.
+///
+/// Versions are tried in the order they were added.
+pub struct VersionedInstructionDecoder<T> {
+    pub versions: Vec<(VersionSelector, BoxedInstructionDecoder<T>)>,
+}
+
+impl<T> Default for VersionedInstructionDecoder<T> {
+    fn default() -> Self {
+        Self {
+            versions: Vec::new(),
+        }
+    }
+}
+
+impl<T> VersionedInstructionDecoder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layout version, used for instructions matching `selector`.
+    pub fn version(
+        mut self,
+        selector: VersionSelector,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+    ) -> Self {
+        self.versions.push((selector, Box::new(decoder)));
+        self
+    }
+}
+
+impl<'a, T> InstructionDecoder<'a> for VersionedInstructionDecoder<T> {
+    type InstructionType = T;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        self.versions
+            .iter()
+            .find_map(|(_, decoder)| decoder.program_id())
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        self.versions
+            .iter()
+            .find_map(|(_, decoder)| decoder.decode_instruction(instruction))
+    }
+
+    fn decode_instruction_at_slot(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        slot: u64,
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        self.versions
+            .iter()
+            .find(|(selector, _)| selector.matches(slot))
+            .and_then(|(_, decoder)| decoder.decode_instruction_at_slot(instruction, slot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            account::{AccountPipe, AccountPipes, AccountProcessorInputType},
+            error::CarbonResult,
+            metrics::MetricsCollection,
+            processor::Processor,
+        },
+        async_trait::async_trait,
+        solana_pubkey::Pubkey,
+    };
+
+    struct LayoutDecoder(u8);
+
+    impl<'a> AccountDecoder<'a> for LayoutDecoder {
+        type AccountType = u8;
+
+        fn decode_account(
+            &self,
+            account: &'a solana_account::Account,
+        ) -> Option<DecodedAccount<Self::AccountType>> {
+            if account.data.first() != Some(&self.0) {
+                return None;
+            }
+
+            Some(DecodedAccount {
+                lamports: account.lamports,
+                data: self.0,
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+        }
+    }
+
+    fn account(version: u8) -> solana_account::Account {
+        solana_account::Account {
+            lamports: 1,
+            data: vec![version],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_version_selector_slots() {
+        let selector = VersionSelector::slots(10, 20);
+
+        assert!(!selector.matches(9));
+        assert!(selector.matches(10));
+        assert!(selector.matches(19));
+        assert!(!selector.matches(20));
+        assert!(VersionSelector::from_slot(20).matches(u64::MAX));
+    }
+
+    #[test]
+    fn test_versioned_account_decoder_selects_by_slot() {
+        // Arrange
+        let decoder = VersionedAccountDecoder::new()
+            .version(VersionSelector::until_slot(100), LayoutDecoder(1))
+            .version(VersionSelector::from_slot(100), LayoutDecoder(2));
+
+        // Act
+        let before_upgrade = decoder.decode_account_at_slot(&account(1), 99);
+        let after_upgrade = decoder.decode_account_at_slot(&account(2), 100);
+        let mismatched = decoder.decode_account_at_slot(&account(1), 100);
+
+        // Assert
+        assert_eq!(before_upgrade.map(|decoded| decoded.data), Some(1));
+        assert_eq!(after_upgrade.map(|decoded| decoded.data), Some(2));
+        assert!(mismatched.is_none());
+    }
+
+    #[test]
+    fn test_versioned_account_decoder_selects_by_flag() {
+        // Arrange
+        let upgraded = Arc::new(AtomicBool::new(false));
+        let decoder = VersionedAccountDecoder::new()
+            .version(VersionSelector::Flag(upgraded.clone()), LayoutDecoder(2))
+            .version(VersionSelector::from_slot(0), LayoutDecoder(1));
+
+        // Act
+        let before_upgrade = decoder.decode_account_at_slot(&account(1), 5);
+        upgraded.store(true, Ordering::Relaxed);
+        let after_upgrade = decoder.decode_account_at_slot(&account(2), 5);
+
+        // Assert
+        assert_eq!(before_upgrade.map(|decoded| decoded.data), Some(1));
+        assert_eq!(after_upgrade.map(|decoded| decoded.data), Some(2));
+    }
+
+    struct Ignore;
+
+    #[async_trait]
+    impl Processor for Ignore {
+        type InputType = AccountProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_account_pipe_decodes_with_the_layout_of_the_slot() {
+        // Arrange
+        let pipe = AccountPipe {
+            decoder: Box::new(
+                VersionedAccountDecoder::new()
+                    .version(VersionSelector::until_slot(100), LayoutDecoder(1))
+                    .version(VersionSelector::from_slot(100), LayoutDecoder(2)),
+            ),
+            processor: Box::new(Ignore),
+        };
+
+        // Act
+        let before_upgrade = pipe.decode_only(&account(1), 99);
+        let mismatched = pipe.decode_only(&account(1), 100);
+
+        // Assert
+        assert!(before_upgrade);
+        assert!(!mismatched);
+    }
+}
@@ -0,0 +1,187 @@
+//! Shares the state of Carbon pipelines through a DynamoDB table.
+//!
+//! [`DynamoDbKeyValueStore`] implements `KeyValueStore` on a table with a
+//! string partition key named `key`, so the replicas of a pipeline deployed on
+//! AWS can share their checkpoint, deduplication keys and mint decimals.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let config = aws_config::load_from_env().await;
+//! let store: Arc<dyn KeyValueStore> = Arc::new(DynamoDbKeyValueStore::new(
+//!     aws_sdk_dynamodb::Client::new(&config),
+//!     "carbon-state",
+//! ));
+//!
+//! let checkpoint = KvCheckpoint::new(store.clone(), "pumpfun:checkpoint");
+//! ```
+//!
+//! # Notes
+//!
+//! - Values are stored in the binary attribute `value`, and the expiry of
+//!   entries with a TTL in the number attribute `expires_at`, in Unix seconds.
+//!   DynamoDB deletes expired items late, so reads check the expiry
+//!   themselves: enable TTL on `expires_at` only to reclaim their storage.
+//! - Reads are strongly consistent, so a replica sees the checkpoint another
+//!   replica just saved.
+//! - TTLs are rounded up to the second.
+
+use {
+    async_trait::async_trait,
+    aws_sdk_dynamodb::{
+        error::DisplayErrorContext, primitives::Blob, types::AttributeValue, Client,
+    },
+    carbon_core::{
+        error::{CarbonResult, Error},
+        kv_store::KeyValueStore,
+    },
+    std::time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const KEY_ATTRIBUTE: &str = "key";
+const VALUE_ATTRIBUTE: &str = "value";
+const EXPIRES_AT_ATTRIBUTE: &str = "expires_at";
+
+/// A `KeyValueStore` keeping its entries in a DynamoDB table.
+#[derive(Debug, Clone)]
+pub struct DynamoDbKeyValueStore {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbKeyValueStore {
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+
+    /// Returns the item attributes of an entry.
+    fn item(
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> impl Iterator<Item = (String, AttributeValue)> {
+        let expires_at = ttl.map(|ttl| {
+            let ttl = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+            (
+                EXPIRES_AT_ATTRIBUTE.to_string(),
+                AttributeValue::N((unix_now() + ttl).to_string()),
+            )
+        });
+
+        [
+            (
+                KEY_ATTRIBUTE.to_string(),
+                AttributeValue::S(key.to_string()),
+            ),
+            (
+                VALUE_ATTRIBUTE.to_string(),
+                AttributeValue::B(Blob::new(value)),
+            ),
+        ]
+        .into_iter()
+        .chain(expires_at)
+    }
+}
+
+/// Returns the current Unix timestamp in seconds.
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or_default()
+}
+
+fn dynamodb_error(error: impl std::error::Error) -> Error {
+    Error::Custom(format!("DynamoDB error: {}", DisplayErrorContext(error)))
+}
+
+#[async_trait]
+impl KeyValueStore for DynamoDbKeyValueStore {
+    async fn get(&self, key: &str) -> CarbonResult<Option<Vec<u8>>> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(dynamodb_error)?;
+
+        let Some(item) = output.item() else {
+            return Ok(None);
+        };
+        let expired = item
+            .get(EXPIRES_AT_ATTRIBUTE)
+            .and_then(|expires_at| expires_at.as_n().ok())
+            .and_then(|expires_at| expires_at.parse::<u64>().ok())
+            .is_some_and(|expires_at| expires_at <= unix_now());
+        if expired {
+            return Ok(None);
+        }
+
+        Ok(item
+            .get(VALUE_ATTRIBUTE)
+            .and_then(|value| value.as_b().ok())
+            .map(|value| value.as_ref().to_vec()))
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> CarbonResult<()> {
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(Self::item(key, value, ttl).collect()))
+            .send()
+            .await
+            .map_err(dynamodb_error)?;
+
+        Ok(())
+    }
+
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> CarbonResult<bool> {
+        let result = self
+            .client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(Self::item(key, value, ttl).collect()))
+            // Expired entries may not be deleted yet, and count as absent.
+            .condition_expression("attribute_not_exists(#key) OR #expires_at <= :now")
+            .expression_attribute_names("#key", KEY_ATTRIBUTE)
+            .expression_attribute_names("#expires_at", EXPIRES_AT_ATTRIBUTE)
+            .expression_attribute_values(":now", AttributeValue::N(unix_now().to_string()))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_conditional_check_failed_exception()) =>
+            {
+                Ok(false)
+            }
+            Err(error) => Err(dynamodb_error(error)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> CarbonResult<()> {
+        self.client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .send()
+            .await
+            .map_err(dynamodb_error)?;
+
+        Ok(())
+    }
+}
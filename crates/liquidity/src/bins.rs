@@ -0,0 +1,163 @@
+//! The depth of Meteora DLMM pairs, made of discrete price bins.
+
+use {
+    crate::{DepthLevel, Quote},
+    carbon_meteora_dlmm_decoder::types::Bin,
+    std::collections::BTreeMap,
+};
+
+/// The number of bins in a DLMM bin array.
+pub const BINS_PER_ARRAY: i64 = 70;
+
+/// Returns the price of the bin `bin_id` of a pair with `bin_step`.
+pub fn bin_price(bin_id: i32, bin_step: u16) -> f64 {
+    (1.0 + bin_step as f64 / 10_000.0).powi(bin_id)
+}
+
+/// The reserves of a bin, at the bin's price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinLiquidity {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub price: f64,
+}
+
+/// The bins of a DLMM pair, by bin id.
+#[derive(Debug, Clone, Default)]
+pub struct BinDepth {
+    pub active_id: Option<i32>,
+    pub bin_step: u16,
+    pub bins: BTreeMap<i32, BinLiquidity>,
+}
+
+impl BinDepth {
+    /// Sets the active bin and bin step, from the pair account.
+    pub fn update_pair(&mut self, active_id: i32, bin_step: u16) {
+        self.active_id = Some(active_id);
+        self.bin_step = bin_step;
+    }
+
+    /// Replaces the bins of the bin array at `index`.
+    pub fn update_bin_array(&mut self, index: i64, bins: &[Bin]) {
+        let lower_bin_id = index * BINS_PER_ARRAY;
+        for (offset, bin) in bins.iter().enumerate() {
+            let Ok(bin_id) = i32::try_from(lower_bin_id + offset as i64) else {
+                continue;
+            };
+
+            if bin.amount_x == 0 && bin.amount_y == 0 {
+                self.bins.remove(&bin_id);
+            } else {
+                self.bins.insert(
+                    bin_id,
+                    BinLiquidity {
+                        amount_x: bin.amount_x,
+                        amount_y: bin.amount_y,
+                        price: bin.price as f64 / 2f64.powi(64),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns the price of the active bin, if known.
+    pub fn spot_price(&self) -> Option<f64> {
+        Some(bin_price(self.active_id?, self.bin_step))
+    }
+
+    /// Returns the output of swapping `amount_in` of X for Y if `x_to_y`, or
+    /// of Y for X otherwise, walking the bins away from the active one.
+    pub fn quote(&self, amount_in: u64, x_to_y: bool) -> Option<Quote> {
+        let active_id = self.active_id?;
+        let mut remaining = amount_in as f64;
+        let mut amount_out = 0.0;
+
+        let bins: Box<dyn Iterator<Item = &BinLiquidity>> = match x_to_y {
+            true => Box::new(self.bins.range(..=active_id).rev().map(|(_, bin)| bin)),
+            false => Box::new(self.bins.range(active_id..).map(|(_, bin)| bin)),
+        };
+        for bin in bins.filter(|bin| bin.price > 0.0) {
+            let (reserve_out, max_in) = match x_to_y {
+                true => (bin.amount_y as f64, bin.amount_y as f64 / bin.price),
+                false => (bin.amount_x as f64, bin.amount_x as f64 * bin.price),
+            };
+
+            if remaining <= max_in {
+                amount_out += match x_to_y {
+                    true => remaining * bin.price,
+                    false => remaining / bin.price,
+                };
+                remaining = 0.0;
+                break;
+            }
+
+            amount_out += reserve_out;
+            remaining -= max_in;
+        }
+
+        Some(Quote::new(
+            amount_in as f64 - remaining,
+            amount_out,
+            self.spot_price()?,
+            x_to_y,
+        ))
+    }
+
+    /// Returns the known bins, by increasing price.
+    pub fn levels(&self) -> Vec<DepthLevel> {
+        self.bins
+            .values()
+            .map(|bin| DepthLevel {
+                lower_price: bin.price,
+                upper_price: bin.price,
+                amount_a: bin.amount_x,
+                amount_b: bin.amount_y,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bin(amount_x: u64, amount_y: u64, price: f64) -> Bin {
+        Bin {
+            amount_x,
+            amount_y,
+            price: (price * 2f64.powi(64)) as u128,
+            liquidity_supply: 0,
+            reward_per_token_stored: [0; 2],
+            fee_amount_x_per_token_stored: 0,
+            fee_amount_y_per_token_stored: 0,
+            amount_x_in: 0,
+            amount_y_in: 0,
+        }
+    }
+
+    #[test]
+    fn test_quote_walks_bins_below_the_active_one() {
+        // Arrange
+        let empty = bin(0, 0, 0.0);
+        let mut lower_bins = vec![empty.clone(); BINS_PER_ARRAY as usize];
+        lower_bins[69] = bin(0, 100, 0.5);
+        let mut upper_bins = vec![empty; BINS_PER_ARRAY as usize];
+        upper_bins[0] = bin(100, 100, 1.0);
+
+        let mut depth = BinDepth::default();
+        depth.update_pair(0, 25);
+        depth.update_bin_array(-1, &lower_bins);
+        depth.update_bin_array(0, &upper_bins);
+
+        // Act
+        let quote = depth.quote(300, true).unwrap();
+        let exhausted = depth.quote(1_000, true).unwrap();
+
+        // Assert
+        assert_eq!((quote.amount_in, quote.amount_out), (300, 200));
+        assert_eq!(quote.spot_price, 1.0);
+        assert!((quote.price_impact - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!((exhausted.amount_in, exhausted.amount_out), (300, 200));
+        assert_eq!(depth.levels().len(), 2);
+    }
+}
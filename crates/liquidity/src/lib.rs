@@ -0,0 +1,271 @@
+//! Reconstructs the liquidity depth of concentrated liquidity pools from
+//! their account updates.
+//!
+//! Meteora DLMM pools keep their liquidity in bin arrays, and Orca Whirlpool
+//! and Raydium CLMM pools in tick arrays, next to a pool account holding the
+//! active bin or the current price. A [`LiquidityBook`] follows these accounts
+//! through account pipes and maintains a depth curve per pool, which
+//! processors query to price a swap before it happens, such as the output and
+//! the price impact of selling a given input.
+//!
+//! A [`PriceFeed`] derives the mid price of the same pools from their pool
+//! accounts alone, and publishes it as a normalized `PriceUpdate` stream to
+//! a processor, whatever the venue.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let book = LiquidityBook::new();
+//!
+//! Pipeline::builder()
+//!     .datasource(my_account_datasource)
+//!     .account(MeteoraDlmmDecoder, book.meteora_dlmm())
+//!     .account(OrcaWhirlpoolDecoder, book.orca_whirlpool())
+//!     .account(RaydiumClmmDecoder, book.raydium_clmm())
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_account_datasource)
+//!     .account(OrcaWhirlpoolDecoder, PriceFeed::new(MyPriceProcessor))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! // From any processor holding a clone of the book:
+//! if let Some(quote) = book.quote(&pool, 1_000_000_000, true)? {
+//!     log::info!("price impact: {:.4}%", quote.price_impact * 100.0);
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Amounts and prices are in raw token units, without decimals. Prices are
+//!   in token B (Y for DLMM pools) per token A (X).
+//! - Quotes ignore swap fees, and treat the liquidity beyond the bin or tick
+//!   arrays received so far as empty: they are estimates for analytics, not
+//!   for building swaps.
+//! - Updates are applied in the order they are received.
+
+pub mod bins;
+pub mod prices;
+pub mod processors;
+pub mod ticks;
+
+pub use {
+    bins::BinDepth,
+    prices::{PoolPrice, PriceFeed, PriceUpdate},
+    processors::*,
+    ticks::TickDepth,
+};
+
+use {
+    carbon_core::error::{CarbonResult, Error},
+    solana_pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        sync::{Arc, RwLock},
+    },
+};
+
+/// The output of a swap through the depth of a pool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quote {
+    /// The input consumed, lower than requested if the known depth ran out.
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// The price before the swap.
+    pub spot_price: f64,
+    /// The relative difference between the execution and the spot price,
+    /// from 0 to 1.
+    pub price_impact: f64,
+}
+
+impl Quote {
+    fn new(amount_in: f64, amount_out: f64, spot_price: f64, a_to_b: bool) -> Self {
+        let execution_price = match a_to_b {
+            true => amount_out / amount_in,
+            false => amount_in / amount_out,
+        };
+        let price_impact = if amount_in <= 0.0 || spot_price <= 0.0 {
+            0.0
+        } else if amount_out <= 0.0 {
+            1.0
+        } else if a_to_b {
+            1.0 - execution_price / spot_price
+        } else {
+            1.0 - spot_price / execution_price
+        };
+
+        Self {
+            amount_in: amount_in.ceil() as u64,
+            amount_out: amount_out.floor() as u64,
+            spot_price,
+            price_impact: price_impact.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// The liquidity of a pool between two prices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthLevel {
+    pub lower_price: f64,
+    pub upper_price: f64,
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
+
+/// The depth of a pool, by liquidity model.
+#[derive(Debug, Clone)]
+pub enum PoolDepth {
+    Bins(BinDepth),
+    Ticks(TickDepth),
+}
+
+impl PoolDepth {
+    /// Returns the current price of the pool, if known.
+    pub fn spot_price(&self) -> Option<f64> {
+        match self {
+            PoolDepth::Bins(bins) => bins.spot_price(),
+            PoolDepth::Ticks(ticks) => ticks.spot_price(),
+        }
+    }
+
+    /// Returns the output of swapping `amount_in` of token A for token B if
+    /// `a_to_b`, or of token B for token A otherwise.
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<Quote> {
+        match self {
+            PoolDepth::Bins(bins) => bins.quote(amount_in, a_to_b),
+            PoolDepth::Ticks(ticks) => ticks.quote(amount_in, a_to_b),
+        }
+    }
+
+    /// Returns the known liquidity of the pool, by increasing price.
+    pub fn levels(&self) -> Vec<DepthLevel> {
+        match self {
+            PoolDepth::Bins(bins) => bins.levels(),
+            PoolDepth::Ticks(ticks) => ticks.levels(),
+        }
+    }
+}
+
+/// The depth of the pools followed by the processors of a pipeline, shared
+/// with the processors querying it.
+#[derive(Debug, Clone, Default)]
+pub struct LiquidityBook {
+    pools: Arc<RwLock<HashMap<Pubkey, PoolDepth>>>,
+}
+
+impl LiquidityBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the processor following Meteora DLMM pairs and bin arrays.
+    pub fn meteora_dlmm(&self) -> MeteoraDlmmDepthProcessor {
+        MeteoraDlmmDepthProcessor::new(self.clone())
+    }
+
+    /// Returns the processor following Orca whirlpools and tick arrays.
+    pub fn orca_whirlpool(&self) -> OrcaWhirlpoolDepthProcessor {
+        OrcaWhirlpoolDepthProcessor::new(self.clone())
+    }
+
+    /// Returns the processor following Raydium CLMM pools and tick arrays.
+    pub fn raydium_clmm(&self) -> RaydiumClmmDepthProcessor {
+        RaydiumClmmDepthProcessor::new(self.clone())
+    }
+
+    /// Returns a copy of the depth of `pool`, if any.
+    pub fn pool(&self, pool: &Pubkey) -> CarbonResult<Option<PoolDepth>> {
+        self.read(|pools| pools.get(pool).cloned())
+    }
+
+    /// Returns the pools with a known depth.
+    pub fn pools(&self) -> CarbonResult<Vec<Pubkey>> {
+        self.read(|pools| pools.keys().copied().collect())
+    }
+
+    /// Returns the output of swapping `amount_in` through `pool`, if its
+    /// depth is known. See [`PoolDepth::quote`].
+    pub fn quote(
+        &self,
+        pool: &Pubkey,
+        amount_in: u64,
+        a_to_b: bool,
+    ) -> CarbonResult<Option<Quote>> {
+        self.read(|pools| {
+            pools
+                .get(pool)
+                .and_then(|depth| depth.quote(amount_in, a_to_b))
+        })
+    }
+
+    /// Returns the known liquidity of `pool`, by increasing price.
+    pub fn levels(&self, pool: &Pubkey) -> CarbonResult<Vec<DepthLevel>> {
+        self.read(|pools| pools.get(pool).map(PoolDepth::levels).unwrap_or_default())
+    }
+
+    /// Updates the bin depth of `pool`, creating it if needed.
+    pub fn update_bins(
+        &self,
+        pool: Pubkey,
+        update: impl FnOnce(&mut BinDepth),
+    ) -> CarbonResult<()> {
+        self.write(|pools| {
+            let depth = pools
+                .entry(pool)
+                .or_insert_with(|| PoolDepth::Bins(BinDepth::default()));
+            if !matches!(depth, PoolDepth::Bins(_)) {
+                *depth = PoolDepth::Bins(BinDepth::default());
+            }
+            if let PoolDepth::Bins(bins) = depth {
+                update(bins);
+            }
+        })
+    }
+
+    /// Updates the tick depth of `pool`, creating it if needed.
+    pub fn update_ticks(
+        &self,
+        pool: Pubkey,
+        update: impl FnOnce(&mut TickDepth),
+    ) -> CarbonResult<()> {
+        self.write(|pools| {
+            let depth = pools
+                .entry(pool)
+                .or_insert_with(|| PoolDepth::Ticks(TickDepth::default()));
+            if !matches!(depth, PoolDepth::Ticks(_)) {
+                *depth = PoolDepth::Ticks(TickDepth::default());
+            }
+            if let PoolDepth::Ticks(ticks) = depth {
+                update(ticks);
+            }
+        })
+    }
+
+    /// Stops following `pool`.
+    pub fn remove(&self, pool: &Pubkey) -> CarbonResult<Option<PoolDepth>> {
+        self.write(|pools| pools.remove(pool))
+    }
+
+    fn read<T>(&self, read: impl FnOnce(&HashMap<Pubkey, PoolDepth>) -> T) -> CarbonResult<T> {
+        let pools = self
+            .pools
+            .read()
+            .map_err(|_| Error::Custom("Liquidity book lock poisoned".to_string()))?;
+        Ok(read(&pools))
+    }
+
+    fn write<T>(
+        &self,
+        write: impl FnOnce(&mut HashMap<Pubkey, PoolDepth>) -> T,
+    ) -> CarbonResult<T> {
+        let mut pools = self
+            .pools
+            .write()
+            .map_err(|_| Error::Custom("Liquidity book lock poisoned".to_string()))?;
+        Ok(write(&mut pools))
+    }
+}
@@ -0,0 +1,230 @@
+//! Derives the mid price of pools from their account updates.
+//!
+//! Each supported venue stores its price differently: an active bin for
+//! Meteora DLMM pairs, a square root price for Orca whirlpools and Raydium
+//! CLMM pools. A `PriceFeed` wraps a processor of `PriceUpdate`s and turns
+//! the pool accounts of a venue into a single, normalized price stream.
+
+use {
+    crate::{bins::bin_price, ticks::sqrt_price_x64_to_price},
+    async_trait::async_trait,
+    carbon_core::{
+        account::{AccountMetadata, AccountProcessorInputType},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    carbon_meteora_dlmm_decoder::accounts::MeteoraDlmmAccount,
+    carbon_orca_whirlpool_decoder::accounts::OrcaWhirlpoolAccount,
+    carbon_raydium_clmm_decoder::accounts::RaydiumClmmAccount,
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, marker::PhantomData, sync::Arc},
+};
+
+/// The mid price of a pool, in raw units of token B per raw unit of token A.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceUpdate {
+    pub slot: u64,
+    pub pool: Pubkey,
+    pub venue: &'static str,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub price: f64,
+}
+
+/// A decoded account type the mid price of a pool can be derived from.
+pub trait PoolPrice {
+    /// Returns the price of the pool the account describes, if it describes
+    /// one.
+    fn price_update(&self, metadata: &AccountMetadata) -> Option<PriceUpdate>;
+}
+
+impl PoolPrice for MeteoraDlmmAccount {
+    fn price_update(&self, metadata: &AccountMetadata) -> Option<PriceUpdate> {
+        let MeteoraDlmmAccount::LbPair(lb_pair) = self else {
+            return None;
+        };
+
+        Some(PriceUpdate {
+            slot: metadata.slot,
+            pool: metadata.pubkey,
+            venue: "meteora-dlmm",
+            mint_a: lb_pair.token_x_mint,
+            mint_b: lb_pair.token_y_mint,
+            price: bin_price(lb_pair.active_id, lb_pair.bin_step),
+        })
+    }
+}
+
+impl PoolPrice for OrcaWhirlpoolAccount {
+    fn price_update(&self, metadata: &AccountMetadata) -> Option<PriceUpdate> {
+        let OrcaWhirlpoolAccount::Whirlpool(whirlpool) = self else {
+            return None;
+        };
+
+        Some(PriceUpdate {
+            slot: metadata.slot,
+            pool: metadata.pubkey,
+            venue: "orca-whirlpool",
+            mint_a: whirlpool.token_mint_a,
+            mint_b: whirlpool.token_mint_b,
+            price: sqrt_price_x64_to_price(whirlpool.sqrt_price),
+        })
+    }
+}
+
+impl PoolPrice for RaydiumClmmAccount {
+    fn price_update(&self, metadata: &AccountMetadata) -> Option<PriceUpdate> {
+        let RaydiumClmmAccount::PoolState(pool_state) = self else {
+            return None;
+        };
+
+        Some(PriceUpdate {
+            slot: metadata.slot,
+            pool: metadata.pubkey,
+            venue: "raydium-clmm",
+            mint_a: pool_state.token_mint0,
+            mint_b: pool_state.token_mint1,
+            price: sqrt_price_x64_to_price(pool_state.sqrt_price_x64),
+        })
+    }
+}
+
+/// An account processor publishing the price of the pools of a venue to a
+/// processor of `PriceUpdate`s, each time it changes.
+pub struct PriceFeed<T, P> {
+    processor: P,
+    prices: HashMap<Pubkey, f64>,
+    _account: PhantomData<fn() -> T>,
+}
+
+impl<T, P> PriceFeed<T, P>
+where
+    T: PoolPrice,
+    P: Processor<InputType = PriceUpdate>,
+{
+    pub fn new(processor: P) -> Self {
+        Self {
+            processor,
+            prices: HashMap::new(),
+            _account: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for PriceFeed<T, P>
+where
+    T: PoolPrice + Send + Sync + 'static,
+    P: Processor<InputType = PriceUpdate> + Send + Sync,
+{
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(price_update) = decoded_account.data.price_update(&metadata) else {
+            return Ok(());
+        };
+        if !price_update.price.is_finite() || price_update.price <= 0.0 {
+            return Ok(());
+        }
+        if self.prices.insert(price_update.pool, price_update.price) == Some(price_update.price) {
+            return Ok(());
+        }
+
+        metrics.increment_counter("price_updates", 1).await?;
+        self.processor.process(price_update, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::{account::DecodedAccount, trace::TraceId},
+        std::sync::Mutex,
+    };
+
+    struct Pool(f64);
+
+    impl PoolPrice for Pool {
+        fn price_update(&self, metadata: &AccountMetadata) -> Option<PriceUpdate> {
+            Some(PriceUpdate {
+                slot: metadata.slot,
+                pool: metadata.pubkey,
+                venue: "test",
+                mint_a: Pubkey::default(),
+                mint_b: Pubkey::default(),
+                price: self.0,
+            })
+        }
+    }
+
+    struct Prices(Arc<Mutex<Vec<f64>>>);
+
+    #[async_trait]
+    impl Processor for Prices {
+        type InputType = PriceUpdate;
+
+        async fn process(
+            &mut self,
+            price_update: PriceUpdate,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(price_update.price);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feed_publishes_price_changes_only() {
+        // Arrange
+        let prices = Arc::new(Mutex::new(Vec::new()));
+        let mut feed = PriceFeed::<Pool, _>::new(Prices(prices.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let pool = Pubkey::new_unique();
+
+        // Act
+        for (slot, price) in [(1, 1.5), (2, 1.5), (3, f64::NAN), (4, 1.75)] {
+            let metadata = AccountMetadata {
+                slot,
+                pubkey: pool,
+                trace_id: TraceId::new(),
+                commitment: None,
+                write_version: None,
+                lamports: 0,
+                owner: Pubkey::default(),
+                executable: false,
+                rent_epoch: 0,
+            };
+            let decoded_account = DecodedAccount {
+                lamports: 0,
+                data: Pool(price),
+                owner: Pubkey::default(),
+                executable: false,
+                rent_epoch: 0,
+            };
+            feed.process((metadata, decoded_account), metrics.clone())
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        assert_eq!(*prices.lock().unwrap(), vec![1.5, 1.75]);
+    }
+}
@@ -0,0 +1,145 @@
+//! The account processors updating a `LiquidityBook`.
+
+use {
+    crate::LiquidityBook,
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType, error::CarbonResult, metrics::MetricsCollection,
+        processor::Processor,
+    },
+    carbon_meteora_dlmm_decoder::accounts::MeteoraDlmmAccount,
+    carbon_orca_whirlpool_decoder::accounts::OrcaWhirlpoolAccount,
+    carbon_raydium_clmm_decoder::accounts::RaydiumClmmAccount,
+    std::sync::Arc,
+};
+
+/// Follows the `LbPair` and `BinArray` accounts of Meteora DLMM pairs.
+pub struct MeteoraDlmmDepthProcessor {
+    book: LiquidityBook,
+}
+
+impl MeteoraDlmmDepthProcessor {
+    pub fn new(book: LiquidityBook) -> Self {
+        Self { book }
+    }
+}
+
+#[async_trait]
+impl Processor for MeteoraDlmmDepthProcessor {
+    type InputType = AccountProcessorInputType<MeteoraDlmmAccount>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        match decoded_account.data {
+            MeteoraDlmmAccount::LbPair(lb_pair) => {
+                self.book.update_bins(metadata.pubkey, |depth| {
+                    depth.update_pair(lb_pair.active_id, lb_pair.bin_step)
+                })
+            }
+            MeteoraDlmmAccount::BinArray(bin_array) => {
+                self.book.update_bins(bin_array.lb_pair, |depth| {
+                    depth.update_bin_array(bin_array.index, &bin_array.bins)
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Follows the `Whirlpool` and `TickArray` accounts of Orca whirlpools.
+pub struct OrcaWhirlpoolDepthProcessor {
+    book: LiquidityBook,
+}
+
+impl OrcaWhirlpoolDepthProcessor {
+    pub fn new(book: LiquidityBook) -> Self {
+        Self { book }
+    }
+}
+
+#[async_trait]
+impl Processor for OrcaWhirlpoolDepthProcessor {
+    type InputType = AccountProcessorInputType<OrcaWhirlpoolAccount>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        match decoded_account.data {
+            OrcaWhirlpoolAccount::Whirlpool(whirlpool) => {
+                self.book.update_ticks(metadata.pubkey, |depth| {
+                    depth.update_pool(
+                        whirlpool.tick_spacing,
+                        whirlpool.liquidity,
+                        whirlpool.sqrt_price,
+                        whirlpool.tick_current_index,
+                    )
+                })
+            }
+            OrcaWhirlpoolAccount::TickArray(tick_array) => {
+                self.book.update_ticks(tick_array.whirlpool, |depth| {
+                    depth.update_tick_array(
+                        tick_array.start_tick_index,
+                        tick_array.ticks.iter().map(|tick| {
+                            if tick.initialized {
+                                tick.liquidity_net
+                            } else {
+                                0
+                            }
+                        }),
+                    )
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Follows the `PoolState` and `TickArrayState` accounts of Raydium CLMM
+/// pools.
+pub struct RaydiumClmmDepthProcessor {
+    book: LiquidityBook,
+}
+
+impl RaydiumClmmDepthProcessor {
+    pub fn new(book: LiquidityBook) -> Self {
+        Self { book }
+    }
+}
+
+#[async_trait]
+impl Processor for RaydiumClmmDepthProcessor {
+    type InputType = AccountProcessorInputType<RaydiumClmmAccount>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        match decoded_account.data {
+            RaydiumClmmAccount::PoolState(pool_state) => {
+                self.book.update_ticks(metadata.pubkey, |depth| {
+                    depth.update_pool(
+                        pool_state.tick_spacing,
+                        pool_state.liquidity,
+                        pool_state.sqrt_price_x64,
+                        pool_state.tick_current,
+                    )
+                })
+            }
+            RaydiumClmmAccount::TickArrayState(tick_array) => {
+                self.book.update_ticks(tick_array.pool_id, |depth| {
+                    depth.update_tick_array(
+                        tick_array.start_tick_index,
+                        tick_array.ticks.iter().map(|tick| tick.liquidity_net),
+                    )
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
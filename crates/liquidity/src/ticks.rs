@@ -0,0 +1,236 @@
+//! The depth of concentrated liquidity pools made of ticks, such as Orca
+//! whirlpools and Raydium CLMM pools.
+
+use {
+    crate::{DepthLevel, Quote},
+    std::collections::BTreeMap,
+};
+
+/// Returns the square root of the price at `tick`.
+pub fn tick_sqrt_price(tick: i32) -> f64 {
+    1.0001f64.powf(tick as f64 / 2.0)
+}
+
+/// Returns the price encoded by a Q64.64 square root price.
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128) -> f64 {
+    (sqrt_price_x64 as f64 / 2f64.powi(64)).powi(2)
+}
+
+/// A price range of constant liquidity, from the price a swap enters it at
+/// to the price it leaves it at.
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    from_sqrt_price: f64,
+    to_sqrt_price: f64,
+    liquidity: f64,
+}
+
+/// The ticks of a pool, by tick array.
+#[derive(Debug, Clone, Default)]
+pub struct TickDepth {
+    pub tick_spacing: u16,
+    pub liquidity: u128,
+    pub sqrt_price_x64: u128,
+    pub tick_current: i32,
+    /// The net liquidity of each tick of the arrays, by start tick index.
+    pub tick_arrays: BTreeMap<i32, Vec<i128>>,
+}
+
+impl TickDepth {
+    /// Sets the current state of the pool, from the pool account.
+    pub fn update_pool(
+        &mut self,
+        tick_spacing: u16,
+        liquidity: u128,
+        sqrt_price_x64: u128,
+        tick_current: i32,
+    ) {
+        self.tick_spacing = tick_spacing;
+        self.liquidity = liquidity;
+        self.sqrt_price_x64 = sqrt_price_x64;
+        self.tick_current = tick_current;
+    }
+
+    /// Replaces the net liquidity of the ticks of the tick array starting at
+    /// `start_tick_index`, one per tick spacing.
+    pub fn update_tick_array(
+        &mut self,
+        start_tick_index: i32,
+        liquidity_net: impl IntoIterator<Item = i128>,
+    ) {
+        self.tick_arrays
+            .insert(start_tick_index, liquidity_net.into_iter().collect());
+    }
+
+    /// Returns the current price of the pool, if known.
+    pub fn spot_price(&self) -> Option<f64> {
+        (self.sqrt_price_x64 != 0).then(|| sqrt_price_x64_to_price(self.sqrt_price_x64))
+    }
+
+    /// Returns the output of swapping `amount_in` of token A for token B if
+    /// `a_to_b`, or of token B for token A otherwise, crossing the known
+    /// ticks.
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<Quote> {
+        let spot_price = self.spot_price()?;
+        let mut remaining = amount_in as f64;
+        let mut amount_out = 0.0;
+
+        for range in self.ranges(a_to_b) {
+            let (from, to, liquidity) =
+                (range.from_sqrt_price, range.to_sqrt_price, range.liquidity);
+            let max_in = match a_to_b {
+                true => liquidity * (1.0 / to - 1.0 / from),
+                false => liquidity * (to - from),
+            };
+
+            if remaining <= max_in {
+                amount_out += match a_to_b {
+                    true => liquidity * (from - 1.0 / (1.0 / from + remaining / liquidity)),
+                    false => liquidity * (1.0 / from - 1.0 / (from + remaining / liquidity)),
+                };
+                remaining = 0.0;
+                break;
+            }
+
+            amount_out += match a_to_b {
+                true => liquidity * (from - to),
+                false => liquidity * (1.0 / from - 1.0 / to),
+            };
+            remaining -= max_in;
+        }
+
+        Some(Quote::new(
+            amount_in as f64 - remaining,
+            amount_out,
+            spot_price,
+            a_to_b,
+        ))
+    }
+
+    /// Returns the known liquidity, by increasing price: token B below the
+    /// current price, token A above it.
+    pub fn levels(&self) -> Vec<DepthLevel> {
+        if self.sqrt_price_x64 == 0 {
+            return Vec::new();
+        }
+
+        let below = self.ranges(true).into_iter().rev().map(|range| DepthLevel {
+            lower_price: range.to_sqrt_price.powi(2),
+            upper_price: range.from_sqrt_price.powi(2),
+            amount_a: 0,
+            amount_b: (range.liquidity * (range.from_sqrt_price - range.to_sqrt_price)) as u64,
+        });
+        let above = self.ranges(false).into_iter().map(|range| DepthLevel {
+            lower_price: range.from_sqrt_price.powi(2),
+            upper_price: range.to_sqrt_price.powi(2),
+            amount_a: (range.liquidity * (1.0 / range.from_sqrt_price - 1.0 / range.to_sqrt_price))
+                as u64,
+            amount_b: 0,
+        });
+
+        below
+            .chain(above)
+            .filter(|level| level.amount_a > 0 || level.amount_b > 0)
+            .collect()
+    }
+
+    fn sqrt_price(&self) -> f64 {
+        self.sqrt_price_x64 as f64 / 2f64.powi(64)
+    }
+
+    /// Returns the initialized ticks of the known tick arrays, with their net
+    /// liquidity, by increasing index.
+    fn ticks(&self) -> BTreeMap<i32, i128> {
+        self.tick_arrays
+            .iter()
+            .flat_map(|(start_tick_index, liquidity_net)| {
+                liquidity_net
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, liquidity_net)| **liquidity_net != 0)
+                    .map(move |(offset, liquidity_net)| {
+                        (
+                            start_tick_index + offset as i32 * self.tick_spacing as i32,
+                            *liquidity_net,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the ranges a swap crosses from the current price, down to the
+    /// lowest known tick if `a_to_b`, up to the highest one otherwise.
+    fn ranges(&self, a_to_b: bool) -> Vec<Range> {
+        let ticks = self.ticks();
+        let mut sqrt_price = self.sqrt_price();
+        let mut liquidity = self.liquidity as f64;
+        let mut ranges = Vec::new();
+
+        let crossed: Box<dyn Iterator<Item = (&i32, &i128)>> = match a_to_b {
+            true => Box::new(ticks.range(..=self.tick_current).rev()),
+            false => Box::new(ticks.range(self.tick_current + 1..)),
+        };
+        for (tick, liquidity_net) in crossed {
+            let tick_sqrt_price = tick_sqrt_price(*tick);
+            ranges.push(Range {
+                from_sqrt_price: sqrt_price,
+                to_sqrt_price: tick_sqrt_price,
+                liquidity,
+            });
+
+            sqrt_price = tick_sqrt_price;
+            liquidity = match a_to_b {
+                true => liquidity - *liquidity_net as f64,
+                false => liquidity + *liquidity_net as f64,
+            }
+            .max(0.0);
+        }
+
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn depth() -> TickDepth {
+        let mut liquidity_net = vec![0; 11];
+        liquidity_net[0] = 1_000_000_000;
+        liquidity_net[10] = -1_000_000_000;
+
+        let mut depth = TickDepth::default();
+        depth.update_pool(1, 1_000_000_000, 1 << 64, 0);
+        depth.update_tick_array(-5, liquidity_net);
+        depth
+    }
+
+    #[test]
+    fn test_quote_within_the_current_range() {
+        // Arrange
+        let depth = depth();
+
+        // Act
+        let quote = depth.quote(1_000, true).unwrap();
+
+        // Assert
+        assert_eq!(quote.spot_price, 1.0);
+        assert_eq!((quote.amount_in, quote.amount_out), (1_000, 999));
+        assert!(quote.price_impact < 1e-5);
+    }
+
+    #[test]
+    fn test_quote_stops_at_the_known_depth() {
+        // Arrange
+        let depth = depth();
+
+        // Act
+        let quote = depth.quote(1_000_000, false).unwrap();
+
+        // Assert
+        assert!((249_900..250_100).contains(&quote.amount_in));
+        assert!((249_800..quote.amount_in).contains(&quote.amount_out));
+        assert!(quote.price_impact > 0.0);
+        assert_eq!(depth.levels().len(), 2);
+    }
+}
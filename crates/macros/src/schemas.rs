@@ -66,6 +66,9 @@
 //!    nested instructions.
 //! 3. **`[$ix_type:expr, $name:expr, [$($inner:tt)*]]`**: Adds an `Instruction`
 //!    node with nested inner instructions.
+//! 4. **`[$ix_type:expr, $name:expr, where [$($constraint:expr),*] ...]`**: Adds
+//!    an `Instruction` node whose accounts must satisfy the given
+//!    `AccountConstraint`s, optionally followed by nested inner instructions.
 //!
 //! ## Notes
 //!
@@ -160,6 +163,10 @@ macro_rules! schema {
 /// 3. `[$ix_type:expr, $name:expr, [$($inner:tt)*]]`: Adds an `Instruction`
 ///    node with the specified instruction type and name, including inner
 ///    instructions which are parsed recursively.
+/// 4. `[$ix_type:expr, $name:expr, where [$($constraint:expr),*]]`: Adds an
+///    `Instruction` node matching only instructions whose accounts satisfy
+///    every `AccountConstraint`. Inner instructions can follow the
+///    constraints, as in `[$ix_type, $name, where [...], [...]]`.
 ///
 /// # Parameters
 ///
@@ -199,17 +206,41 @@ macro_rules! schema_inner {
         $nodes.push(carbon_core::schema::SchemaNode::Instruction(carbon_core::schema::InstructionSchemaNode {
             ix_type: $ix_type,
             name: $name.to_string(),
+            account_constraints: Vec::new(),
             inner_instructions: Vec::new(),
         }));
         schema_inner!($nodes, $($rest)*);
     };
 
+    ($nodes:expr, [$ix_type:expr, $name:expr, where [$($constraint:expr),* $(,)?]] $($rest:tt)*) => {
+        $nodes.push(carbon_core::schema::SchemaNode::Instruction(carbon_core::schema::InstructionSchemaNode {
+            ix_type: $ix_type,
+            name: $name.to_string(),
+            account_constraints: vec![$($constraint),*],
+            inner_instructions: Vec::new(),
+        }));
+        schema_inner!($nodes, $($rest)*);
+    };
+
+    ($nodes:expr, [$ix_type:expr, $name:expr, where [$($constraint:expr),* $(,)?], [$($inner:tt)*]] $($rest:tt)*) => {{
+        let mut inner_nodes = Vec::new();
+        schema_inner!(&mut inner_nodes, $($inner)*);
+        $nodes.push(carbon_core::schema::SchemaNode::Instruction(carbon_core::schema::InstructionSchemaNode {
+            ix_type: $ix_type,
+            name: $name.to_string(),
+            account_constraints: vec![$($constraint),*],
+            inner_instructions: inner_nodes,
+        }));
+        schema_inner!($nodes, $($rest)*);
+    }};
+
     ($nodes:expr, [$ix_type:expr, $name:expr, [$($inner:tt)*]] $($rest:tt)*) => {{
         let mut inner_nodes = Vec::new();
         schema_inner!(&mut inner_nodes, $($inner)*);
         $nodes.push(carbon_core::schema::SchemaNode::Instruction(carbon_core::schema::InstructionSchemaNode {
             ix_type: $ix_type,
             name: $name.to_string(),
+            account_constraints: Vec::new(),
             inner_instructions: inner_nodes,
             }));
         schema_inner!($nodes, $($rest)*);
@@ -69,23 +69,33 @@
 ///   should handle byte slices.
 /// - The macro iterates over each variant type sequentially, returning the
 ///   first successful match. If no types match, `None` is returned.
+/// - With the `audit-trail` feature of `carbon-core`, the raw data and the
+///   discriminator of `$ty` are captured in `DecodedInstruction::audit`.
+/// - Each pair may be preceded by attributes, such as
+///   `#[cfg(feature = "events")]`, so variants behind a feature are only
+///   decoded when it is enabled.
 /// - This macro is especially useful for processing complex transactions where
 ///   multiple instruction types are possible, improving flexibility and
 ///   reducing boilerplate code.
 #[macro_export]
 macro_rules! try_decode_instructions {
-    ($instruction:expr, $($variant:path => $ty:ty),* $(,)?) => {{
+    ($instruction:expr, $($(#[$meta:meta])* $variant:path => $ty:ty),* $(,)?) => {{
         use carbon_core::deserialize::CarbonDeserialize;
-        $(
-            if let Some(decoded_instruction) = <$ty>::deserialize($instruction.data.as_slice()) {
-                Some(carbon_core::instruction::DecodedInstruction {
-                    program_id: $instruction.program_id,
-                    accounts: $instruction.accounts.clone(),
-                    data: $variant(decoded_instruction),
-                })
-            } else
-        )*
-        {
+        'decode: {
+            $(
+                $(#[$meta])*
+                if let Some(decoded_instruction) = <$ty>::deserialize($instruction.data.as_slice()) {
+                    break 'decode Some(carbon_core::instruction::DecodedInstruction {
+                        program_id: $instruction.program_id,
+                        accounts: $instruction.accounts.clone(),
+                        data: $variant(decoded_instruction),
+                        audit: carbon_core::instruction::InstructionAudit::capture(
+                            <$ty as carbon_core::deserialize::CarbonDeserialize>::DISCRIMINATOR,
+                            &$instruction.data,
+                        ),
+                    });
+                }
+            )*
             None
         }
     }};
@@ -0,0 +1,197 @@
+//! Runs SQL over the Parquet exports of a Carbon pipeline, from Rust.
+//!
+//! The DuckDB sink exports every table to `<dir>/<table>.parquet`.
+//! [`ParquetHistory`] registers each of these files as a table of a DataFusion
+//! session, so indexed history can be queried with SQL without a database
+//! server or any other infrastructure. [`ParquetHistory::as_of`] queries the
+//! history as it was at a slot, ignoring the rows written after it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let history = ParquetHistory::open("exports").await?;
+//!
+//! let volume = history
+//!     .sql_json("SELECT sum(sol_amount) AS volume FROM buy")
+//!     .await?;
+//! let volume_at_launch = history
+//!     .as_of(310_000_000)
+//!     .await?
+//!     .sql_json("SELECT sum(sol_amount) AS volume FROM buy")
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Tables are named after their file, without the `.parquet` extension.
+//! - `as_of` filters the tables with a `slot` column. Account tables only
+//!   hold the latest state of each account, so the accounts updated after the
+//!   slot are missing from them rather than shown in their earlier state.
+//! - `context` gives access to the DataFusion session, for example to
+//!   register other tables or object stores.
+
+use {
+    carbon_core::error::{CarbonResult, Error},
+    datafusion::{
+        arrow::{json::ArrayWriter, record_batch::RecordBatch},
+        error::DataFusionError,
+        prelude::{col, lit, ParquetReadOptions, SessionContext},
+    },
+    serde_json::{Map, Value},
+    std::path::{Path, PathBuf},
+};
+
+/// The Parquet files of a directory, queryable with SQL.
+pub struct ParquetHistory {
+    context: SessionContext,
+    tables: Vec<(String, PathBuf)>,
+}
+
+impl ParquetHistory {
+    /// Registers every `<table>.parquet` file of `dir` as a table.
+    pub async fn open(dir: impl AsRef<Path>) -> CarbonResult<Self> {
+        let dir = dir.as_ref();
+        let entries = std::fs::read_dir(dir).map_err(|error| {
+            Error::Custom(format!(
+                "Failed to read Parquet directory {}: {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        let mut tables: Vec<(String, PathBuf)> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "parquet")
+            })
+            .filter_map(|path| {
+                let table = path.file_stem()?.to_str()?.to_string();
+                Some((table, path))
+            })
+            .collect();
+        tables.sort();
+
+        Self::register(tables, None).await
+    }
+
+    /// Returns the history as it was at `slot`: the rows of a later slot are
+    /// filtered out of the tables with a `slot` column.
+    pub async fn as_of(&self, slot: u64) -> CarbonResult<Self> {
+        Self::register(self.tables.clone(), Some(slot)).await
+    }
+
+    async fn register(tables: Vec<(String, PathBuf)>, slot: Option<u64>) -> CarbonResult<Self> {
+        let context = SessionContext::new();
+
+        for (table, path) in &tables {
+            let mut frame = context
+                .read_parquet(
+                    path.to_string_lossy().to_string(),
+                    ParquetReadOptions::default(),
+                )
+                .await
+                .map_err(datafusion_error)?;
+            if let Some(slot) = slot {
+                if frame.schema().has_column_with_unqualified_name("slot") {
+                    frame = frame
+                        .filter(col("slot").lt_eq(lit(slot)))
+                        .map_err(datafusion_error)?;
+                }
+            }
+
+            context
+                .register_table(table.as_str(), frame.into_view())
+                .map_err(datafusion_error)?;
+        }
+
+        Ok(Self { context, tables })
+    }
+
+    /// Returns the names of the tables, in alphabetical order.
+    pub fn tables(&self) -> impl Iterator<Item = &str> {
+        self.tables.iter().map(|(table, _)| table.as_str())
+    }
+
+    /// Returns the DataFusion session the tables are registered in.
+    pub fn context(&self) -> &SessionContext {
+        &self.context
+    }
+
+    /// Runs `sql`, returning the result as Arrow record batches.
+    pub async fn sql(&self, sql: &str) -> CarbonResult<Vec<RecordBatch>> {
+        self.context
+            .sql(sql)
+            .await
+            .map_err(datafusion_error)?
+            .collect()
+            .await
+            .map_err(datafusion_error)
+    }
+
+    /// Runs `sql`, returning the result as one JSON object per row.
+    pub async fn sql_json(&self, sql: &str) -> CarbonResult<Vec<Map<String, Value>>> {
+        let batches = self.sql(sql).await?;
+
+        let mut writer = ArrayWriter::new(Vec::new());
+        writer
+            .write_batches(&batches.iter().collect::<Vec<_>>())
+            .and_then(|()| writer.finish())
+            .map_err(|error| datafusion_error(error.into()))?;
+        let json = writer.into_inner();
+        if json.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_slice(&json)
+            .map_err(|error| Error::Custom(format!("Failed to read query result: {}", error)))
+    }
+}
+
+fn datafusion_error(error: DataFusionError) -> Error {
+    Error::Custom(format!("DataFusion error: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, datafusion::dataframe::DataFrameWriteOptions, serde_json::json};
+
+    #[tokio::test]
+    async fn test_as_of_ignores_later_slots() {
+        // Arrange
+        let dir = std::env::temp_dir().join(format!("carbon_parquet_query_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        SessionContext::new()
+            .sql("SELECT * FROM (VALUES (1, 10), (2, 20), (3, 30)) AS buy(slot, amount)")
+            .await
+            .unwrap()
+            .write_parquet(
+                &dir.join("buy.parquet").to_string_lossy(),
+                DataFrameWriteOptions::new().with_single_file_output(true),
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let history = ParquetHistory::open(&dir).await.unwrap();
+        let total = history
+            .sql_json("SELECT sum(amount) AS total FROM buy")
+            .await
+            .unwrap();
+        let total_at_slot_2 = history
+            .as_of(2)
+            .await
+            .unwrap()
+            .sql_json("SELECT sum(amount) AS total FROM buy")
+            .await
+            .unwrap();
+        let tables: Vec<String> = history.tables().map(str::to_string).collect();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // Assert
+        assert_eq!(tables, vec!["buy"]);
+        assert_eq!(total[0]["total"], json!(60));
+        assert_eq!(total_at_slot_2[0]["total"], json!(30));
+    }
+}
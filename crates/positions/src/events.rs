@@ -0,0 +1,300 @@
+//! Derives position events from the decoded instructions of lending and
+//! liquidity protocols.
+//!
+//! Each protocol names the accounts and amounts of its instructions
+//! differently. A `PositionInstruction` implementation maps the instructions
+//! of a protocol affecting positions to normalized `PositionEvent`s, keyed by
+//! the account holding the position and the market it is in.
+
+use {
+    crate::{PositionAction, PositionEvent, PositionKind},
+    carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionMetadata},
+    carbon_kamino_lending_decoder::instructions::{
+        borrow_obligation_liquidity::BorrowObligationLiquidity,
+        deposit_reserve_liquidity_and_obligation_collateral::DepositReserveLiquidityAndObligationCollateral,
+        liquidate_obligation_and_redeem_reserve_collateral::LiquidateObligationAndRedeemReserveCollateral,
+        repay_obligation_liquidity::RepayObligationLiquidity,
+        withdraw_obligation_collateral_and_redeem_reserve_collateral::WithdrawObligationCollateralAndRedeemReserveCollateral,
+        KaminoLendingInstruction,
+    },
+    carbon_marginfi_v2_decoder::instructions::{
+        lending_account_borrow::LendingAccountBorrow,
+        lending_account_deposit::LendingAccountDeposit,
+        lending_account_liquidate::LendingAccountLiquidate,
+        lending_account_repay::LendingAccountRepay,
+        lending_account_withdraw::LendingAccountWithdraw, MarginfiV2Instruction,
+    },
+    carbon_raydium_cpmm_decoder::instructions::{
+        deposit::Deposit, withdraw::Withdraw, RaydiumCpmmInstruction,
+    },
+    solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+};
+
+/// A decoded instruction type position events can be derived from.
+pub trait PositionInstruction {
+    /// Returns the events of the instruction on positions, if it affects
+    /// any.
+    fn position_events(
+        &self,
+        accounts: &[AccountMeta],
+        metadata: &InstructionMetadata,
+    ) -> Vec<PositionEvent>;
+}
+
+/// Returns a closure building the events of `protocol` for the instruction
+/// described by `metadata`.
+fn event_builder(
+    protocol: &'static str,
+    metadata: &InstructionMetadata,
+) -> impl Fn(Option<Pubkey>, Pubkey, Pubkey, PositionKind, PositionAction, u64) -> PositionEvent {
+    let slot = metadata.transaction_metadata.slot;
+    let signature = metadata.transaction_metadata.signature;
+
+    move |wallet, account, market, kind, action, amount| PositionEvent {
+        protocol,
+        wallet,
+        account,
+        market,
+        kind,
+        action,
+        amount,
+        close: false,
+        slot,
+        signature,
+    }
+}
+
+impl PositionInstruction for KaminoLendingInstruction {
+    fn position_events(
+        &self,
+        accounts: &[AccountMeta],
+        metadata: &InstructionMetadata,
+    ) -> Vec<PositionEvent> {
+        let event = event_builder("kamino-lending", metadata);
+
+        match self {
+            KaminoLendingInstruction::DepositReserveLiquidityAndObligationCollateral(deposit) => {
+                let Some(accounts) =
+                    DepositReserveLiquidityAndObligationCollateral::arrange_accounts(accounts)
+                else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.obligation,
+                    accounts.reserve,
+                    PositionKind::Supply,
+                    PositionAction::Deposit,
+                    deposit.liquidity_amount,
+                )]
+            }
+            KaminoLendingInstruction::WithdrawObligationCollateralAndRedeemReserveCollateral(
+                withdraw,
+            ) => {
+                let Some(accounts) =
+                    WithdrawObligationCollateralAndRedeemReserveCollateral::arrange_accounts(
+                        accounts,
+                    )
+                else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.obligation,
+                    accounts.withdraw_reserve,
+                    PositionKind::Supply,
+                    PositionAction::Withdraw,
+                    withdraw.collateral_amount,
+                )]
+            }
+            KaminoLendingInstruction::BorrowObligationLiquidity(borrow) => {
+                let Some(accounts) = BorrowObligationLiquidity::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.obligation,
+                    accounts.borrow_reserve,
+                    PositionKind::Borrow,
+                    PositionAction::Borrow,
+                    borrow.liquidity_amount,
+                )]
+            }
+            KaminoLendingInstruction::RepayObligationLiquidity(repay) => {
+                let Some(accounts) = RepayObligationLiquidity::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.obligation,
+                    accounts.repay_reserve,
+                    PositionKind::Borrow,
+                    PositionAction::Repay,
+                    repay.liquidity_amount,
+                )]
+            }
+            KaminoLendingInstruction::LiquidateObligationAndRedeemReserveCollateral(liquidate) => {
+                let Some(accounts) =
+                    LiquidateObligationAndRedeemReserveCollateral::arrange_accounts(accounts)
+                else {
+                    return Vec::new();
+                };
+                vec![
+                    event(
+                        None,
+                        accounts.obligation,
+                        accounts.repay_reserve,
+                        PositionKind::Borrow,
+                        PositionAction::Liquidate,
+                        liquidate.liquidity_amount,
+                    ),
+                    event(
+                        None,
+                        accounts.obligation,
+                        accounts.withdraw_reserve,
+                        PositionKind::Supply,
+                        PositionAction::Liquidate,
+                        0,
+                    ),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl PositionInstruction for MarginfiV2Instruction {
+    fn position_events(
+        &self,
+        accounts: &[AccountMeta],
+        metadata: &InstructionMetadata,
+    ) -> Vec<PositionEvent> {
+        let event = event_builder("marginfi-v2", metadata);
+
+        match self {
+            MarginfiV2Instruction::LendingAccountDeposit(deposit) => {
+                let Some(accounts) = LendingAccountDeposit::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.signer),
+                    accounts.marginfi_account,
+                    accounts.bank,
+                    PositionKind::Supply,
+                    PositionAction::Deposit,
+                    deposit.amount,
+                )]
+            }
+            MarginfiV2Instruction::LendingAccountWithdraw(withdraw) => {
+                let Some(accounts) = LendingAccountWithdraw::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![PositionEvent {
+                    close: withdraw.withdraw_all == Some(true),
+                    ..event(
+                        Some(accounts.signer),
+                        accounts.marginfi_account,
+                        accounts.bank,
+                        PositionKind::Supply,
+                        PositionAction::Withdraw,
+                        withdraw.amount,
+                    )
+                }]
+            }
+            MarginfiV2Instruction::LendingAccountBorrow(borrow) => {
+                let Some(accounts) = LendingAccountBorrow::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.signer),
+                    accounts.marginfi_account,
+                    accounts.bank,
+                    PositionKind::Borrow,
+                    PositionAction::Borrow,
+                    borrow.amount,
+                )]
+            }
+            MarginfiV2Instruction::LendingAccountRepay(repay) => {
+                let Some(accounts) = LendingAccountRepay::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![PositionEvent {
+                    close: repay.repay_all == Some(true),
+                    ..event(
+                        Some(accounts.signer),
+                        accounts.marginfi_account,
+                        accounts.bank,
+                        PositionKind::Borrow,
+                        PositionAction::Repay,
+                        repay.amount,
+                    )
+                }]
+            }
+            MarginfiV2Instruction::LendingAccountLiquidate(liquidate) => {
+                let Some(accounts) = LendingAccountLiquidate::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![
+                    event(
+                        None,
+                        accounts.liquidatee_marginfi_account,
+                        accounts.asset_bank,
+                        PositionKind::Supply,
+                        PositionAction::Liquidate,
+                        liquidate.asset_amount,
+                    ),
+                    event(
+                        None,
+                        accounts.liquidatee_marginfi_account,
+                        accounts.liab_bank,
+                        PositionKind::Borrow,
+                        PositionAction::Liquidate,
+                        0,
+                    ),
+                ]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl PositionInstruction for RaydiumCpmmInstruction {
+    fn position_events(
+        &self,
+        accounts: &[AccountMeta],
+        metadata: &InstructionMetadata,
+    ) -> Vec<PositionEvent> {
+        let event = event_builder("raydium-cpmm", metadata);
+
+        match self {
+            RaydiumCpmmInstruction::Deposit(deposit) => {
+                let Some(accounts) = Deposit::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.owner,
+                    accounts.pool_state,
+                    PositionKind::Liquidity,
+                    PositionAction::Deposit,
+                    deposit.lp_token_amount,
+                )]
+            }
+            RaydiumCpmmInstruction::Withdraw(withdraw) => {
+                let Some(accounts) = Withdraw::arrange_accounts(accounts) else {
+                    return Vec::new();
+                };
+                vec![event(
+                    Some(accounts.owner),
+                    accounts.owner,
+                    accounts.pool_state,
+                    PositionKind::Liquidity,
+                    PositionAction::Withdraw,
+                    withdraw.lp_token_amount,
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Tracks the DeFi positions of wallets from their lending and liquidity
+//! instructions.
+//!
+//! Lending protocols such as Kamino Lending and marginfi v2, and liquidity
+//! pools such as Raydium CPMM, each have their own instructions to deposit,
+//! withdraw, borrow, repay and liquidate. A [`PositionTracker`] turns the
+//! decoded instructions of a protocol into normalized [`PositionEvent`]s,
+//! applies them to a shared [`PositionBook`] holding the open positions of
+//! each wallet, and passes each resulting [`PositionChange`] (a position
+//! opened, modified, closed or liquidated) to a processor, such as a sink
+//! feeding a risk dashboard.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let book = PositionBook::new();
+//!
+//! Pipeline::builder()
+//!     .datasource(my_transaction_datasource)
+//!     .instruction(KaminoLendingDecoder, book.tracker(RiskDashboardSink::new()))
+//!     .instruction(MarginfiV2Decoder, book.tracker(RiskDashboardSink::new()))
+//!     .instruction(RaydiumCpmmDecoder, book.tracker(RiskDashboardSink::new()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! // From any processor holding a clone of the book:
+//! for position in book.wallet_positions(&wallet)? {
+//!     log::info!("{} {:?} on {}: {}", position.protocol, position.kind, position.market, position.amount);
+//! }
+//! ```
+//!
+//! # Notes
+//!
+//! - Amounts are the raw amounts of the instructions, without decimals or
+//!   interest: they approximate the size of a position for monitoring, read
+//!   the obligation or margin accounts for exact balances. Kamino deposits
+//!   are in liquidity tokens while its withdrawals are in collateral tokens.
+//! - Positions opened before tracking started are unknown, so withdrawals,
+//!   repayments and liquidations of such positions are ignored and counted by
+//!   the `position_events_untracked` counter.
+//! - Liquidations only carry the amount of one side of the liquidated
+//!   account: the other side is reported as liquidated without an amount
+//!   change.
+//! - Instructions of failed transactions are ignored.
+
+pub mod events;
+pub mod tracker;
+
+pub use {events::PositionInstruction, tracker::PositionTracker};
+
+use {
+    carbon_core::{
+        error::{CarbonResult, Error},
+        processor::Processor,
+    },
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::{
+        collections::{hash_map::Entry, HashMap},
+        sync::{Arc, RwLock},
+    },
+};
+
+/// The side of a position.
+///
+/// - `Supply`: Assets lent to a lending protocol, usually as collateral.
+/// - `Borrow`: Assets borrowed from a lending protocol.
+/// - `Liquidity`: LP tokens of a liquidity pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionKind {
+    Supply,
+    Borrow,
+    Liquidity,
+}
+
+/// The action of an instruction on a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionAction {
+    Deposit,
+    Withdraw,
+    Borrow,
+    Repay,
+    Liquidate,
+}
+
+/// What happened to a position.
+///
+/// - `Opened`: A first deposit or borrow created the position.
+/// - `Modified`: The amount of the position changed.
+/// - `Closed`: The position was fully withdrawn or repaid.
+/// - `Liquidated`: The position was partly or fully liquidated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionLifecycle {
+    Opened,
+    Modified,
+    Closed,
+    Liquidated,
+}
+
+/// Identifies a position: the side of an account in a market of a protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PositionKey {
+    pub protocol: &'static str,
+    /// The account holding the position, such as a Kamino obligation, a
+    /// marginfi account, or the owner of LP tokens.
+    pub account: Pubkey,
+    /// The reserve, bank or pool of the position.
+    pub market: Pubkey,
+    pub kind: PositionKind,
+}
+
+/// A normalized deposit, withdrawal, borrow, repayment or liquidation,
+/// derived from a decoded instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionEvent {
+    pub protocol: &'static str,
+    /// The wallet owning the position, unless the instruction does not name
+    /// it, as for liquidations.
+    pub wallet: Option<Pubkey>,
+    pub account: Pubkey,
+    pub market: Pubkey,
+    pub kind: PositionKind,
+    pub action: PositionAction,
+    pub amount: u64,
+    /// Whether the instruction empties the position whatever its amount,
+    /// such as a withdraw all or a repay all.
+    pub close: bool,
+    pub slot: u64,
+    pub signature: Signature,
+}
+
+impl PositionEvent {
+    pub fn key(&self) -> PositionKey {
+        PositionKey {
+            protocol: self.protocol,
+            account: self.account,
+            market: self.market,
+            kind: self.kind,
+        }
+    }
+}
+
+/// An open position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    pub protocol: &'static str,
+    pub wallet: Option<Pubkey>,
+    pub account: Pubkey,
+    pub market: Pubkey,
+    pub kind: PositionKind,
+    pub amount: u64,
+    pub opened_slot: u64,
+    pub updated_slot: u64,
+}
+
+impl Position {
+    fn open(event: &PositionEvent) -> Self {
+        Self {
+            protocol: event.protocol,
+            wallet: event.wallet,
+            account: event.account,
+            market: event.market,
+            kind: event.kind,
+            amount: 0,
+            opened_slot: event.slot,
+            updated_slot: event.slot,
+        }
+    }
+
+    pub fn key(&self) -> PositionKey {
+        PositionKey {
+            protocol: self.protocol,
+            account: self.account,
+            market: self.market,
+            kind: self.kind,
+        }
+    }
+}
+
+/// A change of a position, passed to the processor of a `PositionTracker`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionChange {
+    pub lifecycle: PositionLifecycle,
+    /// The position after the change, with an amount of 0 once closed.
+    pub position: Position,
+    pub event: PositionEvent,
+}
+
+/// The open positions followed by the trackers of a pipeline, shared with
+/// the processors querying them.
+#[derive(Debug, Clone, Default)]
+pub struct PositionBook {
+    positions: Arc<RwLock<HashMap<PositionKey, Position>>>,
+}
+
+impl PositionBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a tracker applying the instructions of a protocol to the book
+    /// and passing the changes to `processor`.
+    pub fn tracker<T, P>(&self, processor: P) -> PositionTracker<T, P>
+    where
+        T: PositionInstruction,
+        P: Processor<InputType = PositionChange>,
+    {
+        PositionTracker::new(self.clone(), processor)
+    }
+
+    /// Applies an event to its position, returning the change, or `None` if
+    /// it reduces a position that is not tracked.
+    pub fn apply(&self, event: PositionEvent) -> CarbonResult<Option<PositionChange>> {
+        self.write(|positions| {
+            let key = event.key();
+
+            if matches!(
+                event.action,
+                PositionAction::Deposit | PositionAction::Borrow
+            ) {
+                let (lifecycle, position) = match positions.entry(key) {
+                    Entry::Occupied(entry) => (PositionLifecycle::Modified, entry.into_mut()),
+                    Entry::Vacant(entry) => (
+                        PositionLifecycle::Opened,
+                        entry.insert(Position::open(&event)),
+                    ),
+                };
+                position.amount = position.amount.saturating_add(event.amount);
+                position.updated_slot = event.slot;
+                position.wallet = event.wallet.or(position.wallet);
+
+                return Some(PositionChange {
+                    lifecycle,
+                    position: position.clone(),
+                    event,
+                });
+            }
+
+            let mut position = positions.get(&key)?.clone();
+            position.amount = position.amount.saturating_sub(event.amount);
+            position.updated_slot = event.slot;
+            position.wallet = event.wallet.or(position.wallet);
+            if event.close {
+                position.amount = 0;
+            }
+
+            let lifecycle = match event.action {
+                PositionAction::Liquidate => PositionLifecycle::Liquidated,
+                _ if position.amount == 0 => PositionLifecycle::Closed,
+                _ => PositionLifecycle::Modified,
+            };
+            if position.amount == 0 {
+                positions.remove(&key);
+            } else {
+                positions.insert(key, position.clone());
+            }
+
+            Some(PositionChange {
+                lifecycle,
+                position,
+                event,
+            })
+        })
+    }
+
+    /// Returns a copy of the position identified by `key`, if open.
+    pub fn position(&self, key: &PositionKey) -> CarbonResult<Option<Position>> {
+        self.read(|positions| positions.get(key).cloned())
+    }
+
+    /// Returns the open positions of `wallet`.
+    pub fn wallet_positions(&self, wallet: &Pubkey) -> CarbonResult<Vec<Position>> {
+        self.read(|positions| {
+            positions
+                .values()
+                .filter(|position| position.wallet.as_ref() == Some(wallet))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns the open positions.
+    pub fn positions(&self) -> CarbonResult<Vec<Position>> {
+        self.read(|positions| positions.values().cloned().collect())
+    }
+
+    fn read<T>(&self, read: impl FnOnce(&HashMap<PositionKey, Position>) -> T) -> CarbonResult<T> {
+        let positions = self
+            .positions
+            .read()
+            .map_err(|_| Error::Custom("Position book lock poisoned".to_string()))?;
+        Ok(read(&positions))
+    }
+
+    fn write<T>(
+        &self,
+        write: impl FnOnce(&mut HashMap<PositionKey, Position>) -> T,
+    ) -> CarbonResult<T> {
+        let mut positions = self
+            .positions
+            .write()
+            .map_err(|_| Error::Custom("Position book lock poisoned".to_string()))?;
+        Ok(write(&mut positions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(
+        account: Pubkey,
+        kind: PositionKind,
+        action: PositionAction,
+        amount: u64,
+    ) -> PositionEvent {
+        PositionEvent {
+            protocol: "test",
+            wallet: (action != PositionAction::Liquidate).then_some(account),
+            account,
+            market: Pubkey::default(),
+            kind,
+            action,
+            amount,
+            close: false,
+            slot: amount,
+            signature: Signature::default(),
+        }
+    }
+
+    #[test]
+    fn test_book_follows_position_lifecycle() {
+        // Arrange
+        let book = PositionBook::new();
+        let wallet = Pubkey::new_unique();
+        let supply = PositionKind::Supply;
+        let borrow = PositionKind::Borrow;
+
+        // Act
+        let lifecycles = [
+            event(wallet, supply, PositionAction::Deposit, 100),
+            event(wallet, supply, PositionAction::Deposit, 50),
+            event(wallet, borrow, PositionAction::Borrow, 80),
+            event(wallet, borrow, PositionAction::Repay, 80),
+            event(wallet, supply, PositionAction::Liquidate, 30),
+            event(wallet, borrow, PositionAction::Repay, 10),
+        ]
+        .into_iter()
+        .map(|event| {
+            book.apply(event)
+                .unwrap()
+                .map(|change| (change.lifecycle, change.position.amount))
+        })
+        .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            lifecycles,
+            vec![
+                Some((PositionLifecycle::Opened, 100)),
+                Some((PositionLifecycle::Modified, 150)),
+                Some((PositionLifecycle::Opened, 80)),
+                Some((PositionLifecycle::Closed, 0)),
+                Some((PositionLifecycle::Liquidated, 120)),
+                None,
+            ]
+        );
+        let positions = book.wallet_positions(&wallet).unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].kind, PositionKind::Supply);
+        assert_eq!(positions[0].wallet, Some(wallet));
+    }
+}
@@ -0,0 +1,180 @@
+//! Applies the position events of decoded instructions to a `PositionBook`.
+
+use {
+    crate::{PositionBook, PositionChange, PositionInstruction},
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult, instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection, processor::Processor,
+    },
+    std::{marker::PhantomData, sync::Arc},
+};
+
+/// An instruction processor applying the position events of a protocol to a
+/// `PositionBook`, and passing each position change to a processor of
+/// `PositionChange`s.
+pub struct PositionTracker<T, P> {
+    book: PositionBook,
+    processor: P,
+    _instruction: PhantomData<fn() -> T>,
+}
+
+impl<T, P> PositionTracker<T, P>
+where
+    T: PositionInstruction,
+    P: Processor<InputType = PositionChange>,
+{
+    pub fn new(book: PositionBook, processor: P) -> Self {
+        Self {
+            book,
+            processor,
+            _instruction: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for PositionTracker<T, P>
+where
+    T: PositionInstruction + Send + Sync + 'static,
+    P: Processor<InputType = PositionChange> + Send + Sync,
+{
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if metadata.transaction_metadata.meta.status.is_err() {
+            return Ok(());
+        }
+
+        for event in instruction
+            .data
+            .position_events(&instruction.accounts, &metadata)
+        {
+            let Some(change) = self.book.apply(event)? else {
+                metrics
+                    .increment_counter("position_events_untracked", 1)
+                    .await?;
+                continue;
+            };
+
+            metrics.increment_counter("position_changes", 1).await?;
+            self.processor.process(change, metrics.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{PositionAction, PositionEvent, PositionKind, PositionLifecycle},
+        carbon_core::{
+            instruction::{DecodedInstruction, InstructionMetadata},
+            transaction::TransactionMetadata,
+        },
+        solana_instruction::AccountMeta,
+        solana_pubkey::Pubkey,
+        std::sync::Mutex,
+    };
+
+    /// Deposits or withdraws its amount on the position of its first
+    /// account.
+    struct Transfer(PositionAction, u64);
+
+    impl PositionInstruction for Transfer {
+        fn position_events(
+            &self,
+            accounts: &[AccountMeta],
+            metadata: &InstructionMetadata,
+        ) -> Vec<PositionEvent> {
+            vec![PositionEvent {
+                protocol: "test",
+                wallet: Some(accounts[0].pubkey),
+                account: accounts[0].pubkey,
+                market: Pubkey::default(),
+                kind: PositionKind::Liquidity,
+                action: self.0,
+                amount: self.1,
+                close: false,
+                slot: metadata.transaction_metadata.slot,
+                signature: metadata.transaction_metadata.signature,
+            }]
+        }
+    }
+
+    struct Changes(Arc<Mutex<Vec<PositionLifecycle>>>);
+
+    #[async_trait]
+    impl Processor for Changes {
+        type InputType = PositionChange;
+
+        async fn process(
+            &mut self,
+            change: PositionChange,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(change.lifecycle);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tracker_passes_position_changes() {
+        // Arrange
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let book = PositionBook::new();
+        let mut tracker = book.tracker::<Transfer, _>(Changes(changes.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let wallet = Pubkey::new_unique();
+
+        // Act
+        for (action, amount) in [
+            (PositionAction::Withdraw, 5),
+            (PositionAction::Deposit, 10),
+            (PositionAction::Withdraw, 10),
+        ] {
+            let metadata = InstructionMetadata {
+                transaction_metadata: TransactionMetadata::default(),
+                stack_height: 1,
+                index: 0,
+                absolute_path: vec![0],
+                compute_units_consumed: None,
+            };
+            let instruction = DecodedInstruction {
+                program_id: Pubkey::default(),
+                data: Transfer(action, amount),
+                accounts: vec![AccountMeta::new(wallet, true)],
+                audit: None,
+            };
+            tracker
+                .process((metadata, instruction, Default::default()), metrics.clone())
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![PositionLifecycle::Opened, PositionLifecycle::Closed]
+        );
+        assert!(book.positions().unwrap().is_empty());
+    }
+}
@@ -24,6 +24,12 @@
 //! - **`InstructionType` Derivation**: Derive `InstructionType` enums that
 //!   mirror existing enum structures, providing a simplified, data-free version
 //!   of each variant.
+//! - **`CarbonSerialize`**: Implement `serde::Serialize` like serde's derive,
+//!   writing fields marked `#[carbon(serialize_as = "base58" | "hex")]` as
+//!   text.
+//! - **`ArrangeAccounts`**: Implement the `ArrangeAccounts` trait of an
+//!   instruction from its accounts struct, taking the accounts in the order of
+//!   the struct's fields.
 //!
 //! ## Usage
 //!
@@ -112,14 +118,49 @@ use {
 ///   provided, the deserialization proceeds without a discriminator check.
 /// - Ensure the discriminator matches the data's format exactly, as the
 ///   deserialization will return `None` if there is a mismatch.
+/// - The implementation also sets the `DISCRIMINATOR` and `LEN` constants of
+///   the trait, `LEN` being `None` when the type has a variable length, such
+///   as with a `String`, an `Option` or a `Vec` field, or a `deserialize_with`
+///   function.
 /// - The macro will panic if the discriminator is invalid or not provided
 ///   correctly as a hex string when expected.
 ///
+/// # Custom Deserialization
+///
+/// Types that don't decode cleanly with plain Borsh can plug in their own
+/// parsing with the `deserialize_with` attribute, while keeping the derive.
+///
+/// - On the type, `#[carbon(deserialize_with = "path")]` replaces the strict
+///   `try_from_slice` call. `path` is a `fn(&[u8]) -> Option<Self>` that
+///   receives the data following the discriminator, which makes it possible
+///   to tolerate padding or trailing bytes.
+/// - On a struct field, `#[carbon(deserialize_with = "path")]` replaces the
+///   Borsh deserialization of that field. `path` has the signature of
+///   `BorshDeserialize::deserialize_reader` and returns the field's type.
+/// - On the last field of a struct, of type `Vec<u8>`,
+///   `#[carbon(remaining_data)]` collects whatever bytes are left after the
+///   other fields instead of failing the decode, for accounts that carry extra
+///   bytes beyond their declared layout. With strict decoding enabled, see
+///   `carbon_core::deserialize::with_strict_decoding`, the decode fails if any
+///   byte is left instead.
+///
+/// ```ignore
+/// #[derive(CarbonDeserialize)]
+/// #[carbon(discriminator = "0x04", deserialize_with = "deserialize_padded")]
+/// struct Record {
+///     #[carbon(deserialize_with = "read_u32_tagged_enum")]
+///     kind: Kind,
+///     amount: u64,
+/// }
+/// ```
+///
 /// # Errors
 ///
 /// - The macro will return `None` during deserialization if the data is shorter
 ///   than the discriminator or if there is a mismatch between the provided and
 ///   expected discriminators.
+/// - Field level `deserialize_with` is only supported on structs, using it on
+///   an enum is a compile error.
 #[proc_macro_derive(CarbonDeserialize, attributes(carbon))]
 pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream {
     let derive_input = input_token_stream.clone();
@@ -127,13 +168,28 @@ pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream
     let name = &input.ident;
 
     let discriminator = get_discriminator(&input.attrs).unwrap_or(quote! { &[] });
-    let deser = gen_borsh_deserialize(input_token_stream);
+    let deser = if has_custom_field_deserialize(&input) {
+        gen_borsh_deserialize_with_fields(&input)
+    } else {
+        gen_borsh_deserialize(input_token_stream)
+    };
+    let (deserialize_body, len) = match get_deserialize_with(&input.attrs) {
+        Some(deserialize_with) => (quote! { #deserialize_with(rest) }, quote! { None }),
+        None => (
+            quote! { carbon_core::borsh::BorshDeserialize::try_from_slice(rest).ok() },
+            gen_len(&input),
+        ),
+    };
 
     let expanded = quote! {
         #deser
 
         #[automatically_derived]
         impl carbon_core::deserialize::CarbonDeserialize for #name {
+            const DISCRIMINATOR: &'static [u8] = #discriminator;
+
+            const LEN: Option<usize> = #len;
+
             fn deserialize(data: &[u8]) -> Option<Self> {
                 let discriminator: &[u8] = #discriminator;
                 if data.len() < discriminator.len() {
@@ -146,7 +202,7 @@ pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream
                     return None;
                 }
 
-                 carbon_core::borsh::BorshDeserialize::try_from_slice(rest).ok()
+                #deserialize_body
             }
         }
     };
@@ -154,6 +210,77 @@ pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream
     TokenStream::from(expanded)
 }
 
+/// Generates the `LEN` of a derived `CarbonDeserialize` implementation: the
+/// Borsh length of the type, if the same for every value.
+///
+/// Integers, booleans, floats and public keys have a fixed length, as do
+/// arrays and tuples of them. Other types without generic arguments are
+/// expected to implement `CarbonDeserialize` and read their own `LEN`, while
+/// strings, generic types such as `Option` and `Vec`, and fields with a
+/// custom deserializer have a variable length. An enum has a fixed length
+/// when all its variants have the same.
+fn gen_len(input: &DeriveInput) -> TokenStream2 {
+    let fields_len = |fields: &syn::Fields| {
+        let lens = fields.iter().map(|field| {
+            if get_deserialize_with(&field.attrs).is_some() || has_remaining_data(&field.attrs) {
+                quote! { None }
+            } else {
+                type_len(&field.ty)
+            }
+        });
+        quote! { carbon_core::deserialize::fields_len(&[#(#lens),*]) }
+    };
+
+    match &input.data {
+        syn::Data::Struct(data) => fields_len(&data.fields),
+        syn::Data::Enum(data) => {
+            let lens = data
+                .variants
+                .iter()
+                .map(|variant| fields_len(&variant.fields));
+            quote! { carbon_core::deserialize::variants_len(&[#(#lens),*]) }
+        }
+        syn::Data::Union(_) => quote! { None },
+    }
+}
+
+/// Returns the expression of the Borsh length of a field type, see `gen_len`.
+fn type_len(ty: &syn::Type) -> TokenStream2 {
+    match ty {
+        syn::Type::Array(array) => {
+            let elem_len = type_len(&array.elem);
+            let count = &array.len;
+            quote! { carbon_core::deserialize::array_len(#elem_len, #count) }
+        }
+        syn::Type::Tuple(tuple) => {
+            let lens = tuple.elems.iter().map(type_len);
+            quote! { carbon_core::deserialize::fields_len(&[#(#lens),*]) }
+        }
+        syn::Type::Group(group) => type_len(&group.elem),
+        syn::Type::Paren(paren) => type_len(&paren.elem),
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let Some(segment) = path.path.segments.last() else {
+                return quote! { None };
+            };
+            if !segment.arguments.is_empty() {
+                return quote! { None };
+            }
+
+            match segment.ident.to_string().as_str() {
+                "u8" | "i8" | "bool" => quote! { Some(1) },
+                "u16" | "i16" => quote! { Some(2) },
+                "u32" | "i32" | "f32" => quote! { Some(4) },
+                "u64" | "i64" | "f64" => quote! { Some(8) },
+                "u128" | "i128" => quote! { Some(16) },
+                "Pubkey" => quote! { Some(32) },
+                "String" | "PrefixString" | "U64PrefixString" => quote! { None },
+                _ => quote! { <#path as carbon_core::deserialize::CarbonDeserialize>::LEN },
+            }
+        }
+        _ => quote! { None },
+    }
+}
+
 /// Generates an implementation of the `CarbonDeserialize` trait for a given
 /// type.
 ///
@@ -229,6 +356,152 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream2 {
     }
 }
 
+/// Generates a `BorshDeserialize` implementation for a struct with fields
+/// marked `#[carbon(deserialize_with = "path")]` or `#[carbon(remaining_data)]`.
+///
+/// `borsh-derive-internal` has no support for custom field deserializers, so
+/// the implementation is generated here instead: `deserialize_with` fields are
+/// read with `path(reader)`, `remaining_data` fields collect every byte left in
+/// the reader, and every other field uses its own `BorshDeserialize`
+/// implementation, in declaration order.
+fn gen_borsh_deserialize_with_fields(input: &DeriveInput) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let syn::Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(
+            name,
+            "`deserialize_with` is only supported on struct fields",
+        )
+        .to_compile_error();
+    };
+
+    let field_de = |field: &syn::Field| {
+        if has_remaining_data(&field.attrs) {
+            return quote! {
+                {
+                    let mut remaining_data = borsh::maybestd::vec::Vec::new();
+                    reader.read_to_end(&mut remaining_data)?;
+                    if carbon_core::deserialize::is_strict_decoding() && !remaining_data.is_empty() {
+                        return Err(borsh::maybestd::io::Error::new(
+                            borsh::maybestd::io::ErrorKind::InvalidData,
+                            "trailing bytes rejected in strict decoding mode",
+                        ));
+                    }
+                    remaining_data
+                }
+            };
+        }
+
+        match get_deserialize_with(&field.attrs) {
+            Some(deserialize_with) => quote! { #deserialize_with(reader)? },
+            None => quote! { borsh::BorshDeserialize::deserialize_reader(reader)? },
+        }
+    };
+
+    let body = match &data.fields {
+        syn::Fields::Named(fields) => {
+            let inits = fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                let de = field_de(field);
+                quote! { #ident: #de }
+            });
+            quote! { Self { #(#inits,)* } }
+        }
+        syn::Fields::Unnamed(fields) => {
+            let inits = fields.unnamed.iter().map(field_de);
+            quote! { Self( #(#inits,)* ) }
+        }
+        syn::Fields::Unit => quote! { Self },
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics borsh::BorshDeserialize for #name #ty_generics #where_clause {
+            fn deserialize_reader<R: borsh::maybestd::io::Read>(
+                reader: &mut R,
+            ) -> ::core::result::Result<Self, borsh::maybestd::io::Error> {
+                Ok(#body)
+            }
+        }
+    }
+}
+
+/// Returns `true` if any field of the type, including enum variant fields,
+/// carries a `#[carbon(deserialize_with = "...")]` or `#[carbon(remaining_data)]`
+/// attribute.
+fn has_custom_field_deserialize(input: &DeriveInput) -> bool {
+    let has_attr = |fields: &syn::Fields| {
+        fields.iter().any(|field| {
+            get_deserialize_with(&field.attrs).is_some() || has_remaining_data(&field.attrs)
+        })
+    };
+
+    match &input.data {
+        syn::Data::Struct(data) => has_attr(&data.fields),
+        syn::Data::Enum(data) => data
+            .variants
+            .iter()
+            .any(|variant| has_attr(&variant.fields)),
+        syn::Data::Union(_) => false,
+    }
+}
+
+/// Returns `true` if the attributes contain `#[carbon(remaining_data)]`.
+///
+/// The attribute marks a trailing `Vec<u8>` field that receives any bytes left
+/// after the other fields, so accounts that were reallocated past their
+/// declared layout still decode instead of failing on the extra bytes.
+fn has_remaining_data(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path.is_ident("carbon") {
+            return false;
+        }
+
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.is_ident("remaining_data"),
+                _ => false,
+            }),
+            _ => false,
+        }
+    })
+}
+
+/// Extracts the path of a custom deserializer from a set of attributes.
+///
+/// Looks for a `carbon(deserialize_with = "path")` attribute and parses its
+/// value as an expression path, returning `None` if the attribute is absent.
+///
+/// # Errors
+///
+/// Panics with "Invalid deserialize_with path" if the value is not a valid
+/// path.
+fn get_deserialize_with(attrs: &[syn::Attribute]) -> Option<syn::ExprPath> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("carbon") {
+            return None;
+        }
+
+        match attr.parse_meta().ok()? {
+            Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("deserialize_with") => {
+                    match &nv.lit {
+                        Lit::Str(lit_str) => Some(
+                            lit_str
+                                .parse::<syn::ExprPath>()
+                                .expect("Invalid deserialize_with path"),
+                        ),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
+
 /// Extracts the discriminator value from a set of attributes.
 ///
 /// This function searches through a list of attributes for a `carbon` attribute
@@ -645,6 +918,7 @@ pub fn instruction_decoder_collection(input: TokenStream) -> TokenStream {
                     program_id: instruction.program_id,
                     accounts: instruction.accounts.clone(),
                     data: #instructions_enum_name::#program_variant(decoded_instruction.data),
+                    audit: decoded_instruction.audit,
                 });
             }
         });
@@ -823,3 +1097,456 @@ pub fn instruction_type_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Generates an implementation of `serde::Serialize`, writing pubkeys and byte
+/// arrays as text.
+///
+/// The output is the same as with `#[derive(serde::Serialize)]`, except for
+/// the fields marked with `#[carbon(serialize_as = "...")]`, which are written
+/// as a string with the given encoding:
+///
+/// - `base58`, as pubkeys are usually displayed.
+/// - `hex`, for byte arrays such as hashes or names.
+///
+/// Marked fields must implement `carbon_core::serializer::EncodeBytes`, as
+/// pubkeys, byte arrays and options of them do. Unmarked fields use their own
+/// `Serialize` implementation, or the `serialize` function of the module given
+/// with `#[serde(with = "...")]`. Other serde attributes are not supported.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(CarbonDeserialize, CarbonSerialize, serde::Deserialize)]
+/// pub struct Pool {
+///     #[carbon(serialize_as = "base58")]
+///     pub mint: solana_pubkey::Pubkey,
+///     #[carbon(serialize_as = "hex")]
+///     pub name: [u8; 32],
+///     pub fee: u64,
+/// }
+/// ```
+///
+/// # Notes
+///
+/// - Only serialization is affected. A `serde::Deserialize` implementation
+///   derived alongside still expects the default representation of the
+///   fields.
+#[proc_macro_derive(CarbonSerialize, attributes(carbon, serde))]
+pub fn carbon_serialize_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let name_str = syn::LitStr::new(&name.to_string(), name.span());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => {
+                let len = fields.named.len();
+                let serialize_fields = fields.named.iter().map(|field| {
+                    let ident = field.ident.as_ref().expect("named field");
+                    let field_name = field_name(ident);
+                    let value = serialize_value(field, quote! { &self.#ident });
+                    quote! {
+                        serde::ser::SerializeStruct::serialize_field(&mut __state, #field_name, #value)?;
+                    }
+                });
+                quote! {
+                    let mut __state = serde::Serializer::serialize_struct(__serializer, #name_str, #len)?;
+                    #(#serialize_fields)*
+                    serde::ser::SerializeStruct::end(__state)
+                }
+            }
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let value = serialize_value(&fields.unnamed[0], quote! { &self.0 });
+                quote! {
+                    serde::Serializer::serialize_newtype_struct(__serializer, #name_str, #value)
+                }
+            }
+            syn::Fields::Unnamed(fields) => {
+                let len = fields.unnamed.len();
+                let serialize_fields = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                    let index = syn::Index::from(index);
+                    let value = serialize_value(field, quote! { &self.#index });
+                    quote! {
+                        serde::ser::SerializeTupleStruct::serialize_field(&mut __state, #value)?;
+                    }
+                });
+                quote! {
+                    let mut __state = serde::Serializer::serialize_tuple_struct(__serializer, #name_str, #len)?;
+                    #(#serialize_fields)*
+                    serde::ser::SerializeTupleStruct::end(__state)
+                }
+            }
+            syn::Fields::Unit => quote! {
+                serde::Serializer::serialize_unit_struct(__serializer, #name_str)
+            },
+        },
+        syn::Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let index = index as u32;
+                let variant_ident = &variant.ident;
+                let variant_str = syn::LitStr::new(&variant_ident.to_string(), variant_ident.span());
+
+                match &variant.fields {
+                    syn::Fields::Named(fields) => {
+                        let len = fields.named.len();
+                        let idents: Vec<&Ident> = fields
+                            .named
+                            .iter()
+                            .map(|field| field.ident.as_ref().expect("named field"))
+                            .collect();
+                        let bindings: Vec<Ident> = (0..len)
+                            .map(|index| format_ident!("__field{}", index))
+                            .collect();
+                        let serialize_fields = fields.named.iter().zip(&bindings).map(|(field, binding)| {
+                            let ident = field.ident.as_ref().expect("named field");
+                            let field_name = field_name(ident);
+                            let value = serialize_value(field, quote! { #binding });
+                            quote! {
+                                serde::ser::SerializeStructVariant::serialize_field(&mut __state, #field_name, #value)?;
+                            }
+                        });
+                        quote! {
+                            Self::#variant_ident { #(#idents: #bindings),* } => {
+                                let mut __state = serde::Serializer::serialize_struct_variant(
+                                    __serializer, #name_str, #index, #variant_str, #len,
+                                )?;
+                                #(#serialize_fields)*
+                                serde::ser::SerializeStructVariant::end(__state)
+                            }
+                        }
+                    }
+                    syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        let value = serialize_value(&fields.unnamed[0], quote! { __field0 });
+                        quote! {
+                            Self::#variant_ident(__field0) => serde::Serializer::serialize_newtype_variant(
+                                __serializer, #name_str, #index, #variant_str, #value,
+                            ),
+                        }
+                    }
+                    syn::Fields::Unnamed(fields) => {
+                        let len = fields.unnamed.len();
+                        let bindings: Vec<Ident> = (0..len)
+                            .map(|index| format_ident!("__field{}", index))
+                            .collect();
+                        let serialize_fields =
+                            fields.unnamed.iter().zip(&bindings).map(|(field, binding)| {
+                                let value = serialize_value(field, quote! { #binding });
+                                quote! {
+                                    serde::ser::SerializeTupleVariant::serialize_field(&mut __state, #value)?;
+                                }
+                            });
+                        quote! {
+                            Self::#variant_ident(#(#bindings),*) => {
+                                let mut __state = serde::Serializer::serialize_tuple_variant(
+                                    __serializer, #name_str, #index, #variant_str, #len,
+                                )?;
+                                #(#serialize_fields)*
+                                serde::ser::SerializeTupleVariant::end(__state)
+                            }
+                        }
+                    }
+                    syn::Fields::Unit => quote! {
+                        Self::#variant_ident => serde::Serializer::serialize_unit_variant(
+                            __serializer, #name_str, #index, #variant_str,
+                        ),
+                    },
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(name, "`CarbonSerialize` does not support unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<__S: serde::Serializer>(
+                &self,
+                __serializer: __S,
+            ) -> ::core::result::Result<__S::Ok, __S::Error> {
+                #body
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Returns the serialized name of a field, without the `r#` prefix of raw
+/// identifiers.
+fn field_name(ident: &Ident) -> syn::LitStr {
+    let name = ident.to_string();
+    syn::LitStr::new(name.trim_start_matches("r#"), ident.span())
+}
+
+/// Returns the expression serialized for a field, given a reference to its
+/// value: the value itself, or a wrapper applying `serialize_as` or
+/// `#[serde(with = "...")]`.
+fn serialize_value(field: &syn::Field, value: TokenStream2) -> TokenStream2 {
+    if let Some(encoding) = get_serialize_as(&field.attrs) {
+        return quote! {
+            &carbon_core::serializer::Encoded(#value, carbon_core::serializer::ByteEncoding::#encoding)
+        };
+    }
+
+    if let Some(with) = get_serde_with(&field.attrs) {
+        let ty = &field.ty;
+        return quote! {
+            &{
+                struct SerializeWith<'a>(&'a #ty);
+
+                impl serde::Serialize for SerializeWith<'_> {
+                    fn serialize<__S: serde::Serializer>(
+                        &self,
+                        __serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error> {
+                        #with::serialize(self.0, __serializer)
+                    }
+                }
+
+                SerializeWith(#value)
+            }
+        };
+    }
+
+    value
+}
+
+/// Extracts the encoding of a `#[carbon(serialize_as = "...")]` attribute,
+/// as the name of a `ByteEncoding` variant.
+///
+/// # Errors
+///
+/// Panics with "Invalid serialize_as encoding" if the value is neither
+/// `base58` nor `hex`.
+fn get_serialize_as(attrs: &[syn::Attribute]) -> Option<Ident> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("carbon") {
+            return None;
+        }
+
+        match attr.parse_meta().ok()? {
+            Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("serialize_as") => {
+                    match &nv.lit {
+                        Lit::Str(lit_str) => match lit_str.value().as_str() {
+                            "base58" => Some(Ident::new("Base58", lit_str.span())),
+                            "hex" => Some(Ident::new("Hex", lit_str.span())),
+                            _ => panic!(
+                                "Invalid serialize_as encoding, expected \"base58\" or \"hex\""
+                            ),
+                        },
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
+
+/// Extracts the module path of a `#[serde(with = "...")]` attribute.
+fn get_serde_with(attrs: &[syn::Attribute]) -> Option<syn::ExprPath> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("serde") {
+            return None;
+        }
+
+        match attr.parse_meta().ok()? {
+            Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("with") => {
+                    match &nv.lit {
+                        Lit::Str(lit_str) => Some(
+                            lit_str
+                                .parse::<syn::ExprPath>()
+                                .expect("Invalid serde with path"),
+                        ),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
+
+/// Generates the `ArrangeAccounts` implementation of an instruction from the
+/// struct of its accounts.
+///
+/// The struct is annotated with `#[carbon(instruction = "...")]`, naming the
+/// instruction type, and its fields list the accounts of the instruction in
+/// order. Each field is filled from the account at its position, depending
+/// on its type:
+///
+/// - `Pubkey`: The pubkey of a required account. Arranging fails if the
+///   account is missing.
+/// - `AccountMeta`: A required account, with its signer and writable flags.
+/// - `Option<Pubkey>`: The pubkey of an optional account, `None` if the
+///   instruction has fewer accounts.
+/// - `Vec<AccountMeta>`: The accounts after the listed ones. This must be the
+///   last field.
+///
+/// `MIN_ACCOUNTS` is set to the position of the last required account, so
+/// `try_arrange_accounts` rejects truncated account lists with a
+/// `NotEnoughAccounts` error.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+/// #[carbon(instruction = "SwapBaseInput")]
+/// pub struct SwapBaseInputInstructionAccounts {
+///     pub payer: solana_pubkey::Pubkey,
+///     pub authority: solana_pubkey::Pubkey,
+///     pub amm_config: solana_pubkey::Pubkey,
+///     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Panics if the `instruction` attribute is missing, if the struct does not
+/// have named fields, or if a field has another type or follows the
+/// `Vec<AccountMeta>` field.
+#[proc_macro_derive(ArrangeAccounts, attributes(carbon))]
+pub fn arrange_accounts_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let instruction = get_instruction(&input.attrs)
+        .expect("ArrangeAccounts requires a #[carbon(instruction = \"...\")] attribute");
+
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => panic!("ArrangeAccounts can only be derived for structs with named fields"),
+    };
+
+    let mut index = 0usize;
+    let mut min_accounts = 0usize;
+    let arranged_fields = fields.iter().enumerate().map(|(position, field)| {
+        let ident = field.ident.as_ref().expect("named field");
+
+        let value = match account_field_kind(&field.ty) {
+            AccountFieldKind::Pubkey => {
+                min_accounts = index + 1;
+                quote! { accounts.get(#index)?.pubkey }
+            }
+            AccountFieldKind::AccountMeta => {
+                min_accounts = index + 1;
+                quote! { accounts.get(#index)?.clone() }
+            }
+            AccountFieldKind::OptionalPubkey => {
+                quote! { accounts.get(#index).map(|account| account.pubkey) }
+            }
+            AccountFieldKind::Remaining => {
+                if position + 1 != fields.len() {
+                    panic!("The Vec<AccountMeta> field of ArrangeAccounts must be the last field");
+                }
+                return quote! {
+                    #ident: accounts.get(#index..).unwrap_or_default().to_vec(),
+                };
+            }
+        };
+        index += 1;
+
+        quote! { #ident: #value, }
+    });
+    let arranged_fields: Vec<TokenStream2> = arranged_fields.collect();
+
+    let expanded = quote! {
+        impl carbon_core::deserialize::ArrangeAccounts for #instruction {
+            type ArrangedAccounts = #name;
+
+            const MIN_ACCOUNTS: usize = #min_accounts;
+
+            fn arrange_accounts(
+                accounts: &[solana_instruction::AccountMeta],
+            ) -> Option<Self::ArrangedAccounts> {
+                Some(#name {
+                    #(#arranged_fields)*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// How an `ArrangeAccounts` field is filled from the accounts.
+enum AccountFieldKind {
+    Pubkey,
+    AccountMeta,
+    OptionalPubkey,
+    Remaining,
+}
+
+/// Returns how a field of type `ty` is filled, by the last segment of its
+/// type path.
+///
+/// # Errors
+///
+/// Panics with "Unsupported ArrangeAccounts field type" for other types.
+fn account_field_kind(ty: &syn::Type) -> AccountFieldKind {
+    fn unsupported() -> ! {
+        panic!(
+            "Unsupported ArrangeAccounts field type, expected Pubkey, AccountMeta, \
+             Option<Pubkey> or Vec<AccountMeta>"
+        )
+    }
+
+    let syn::Type::Path(TypePath { path, .. }) = ty else {
+        unsupported();
+    };
+    let Some(segment) = path.segments.last() else {
+        unsupported();
+    };
+
+    match segment.ident.to_string().as_str() {
+        "Pubkey" => AccountFieldKind::Pubkey,
+        "AccountMeta" => AccountFieldKind::AccountMeta,
+        "Option" => AccountFieldKind::OptionalPubkey,
+        "Vec" => AccountFieldKind::Remaining,
+        _ => unsupported(),
+    }
+}
+
+/// Extracts the instruction type of a `#[carbon(instruction = "...")]`
+/// attribute.
+fn get_instruction(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path.is_ident("carbon") {
+            return None;
+        }
+
+        match attr.parse_meta().ok()? {
+            Meta::List(list) => list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("instruction") => {
+                    match &nv.lit {
+                        Lit::Str(lit_str) => Some(
+                            lit_str
+                                .parse::<syn::Path>()
+                                .expect("Invalid instruction type"),
+                        ),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+            _ => None,
+        }
+    })
+}
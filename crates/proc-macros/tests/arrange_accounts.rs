@@ -0,0 +1,87 @@
+use {
+    carbon_core::deserialize::ArrangeAccounts, solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+};
+
+struct Swap;
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Swap")]
+struct SwapInstructionAccounts {
+    payer: Pubkey,
+    pool: AccountMeta,
+    referrer: Option<Pubkey>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+struct Deposit;
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Deposit")]
+struct DepositInstructionAccounts {
+    owner: Pubkey,
+    delegate: Option<Pubkey>,
+    vault: Pubkey,
+}
+
+fn accounts(count: usize) -> Vec<AccountMeta> {
+    (0..count)
+        .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+        .collect()
+}
+
+#[test]
+fn test_arrange_accounts_fills_fields_in_order() {
+    // Arrange
+    let accounts = accounts(5);
+
+    // Act
+    let arranged = Swap::arrange_accounts(&accounts).unwrap();
+
+    // Assert
+    assert_eq!(arranged.payer, accounts[0].pubkey);
+    assert_eq!(arranged.pool, accounts[1]);
+    assert_eq!(arranged.referrer, Some(accounts[2].pubkey));
+    assert_eq!(arranged.remaining_accounts, accounts[3..].to_vec());
+}
+
+#[test]
+fn test_arrange_accounts_without_optional_or_remaining_accounts() {
+    // Arrange
+    let accounts = accounts(2);
+
+    // Act
+    let arranged = Swap::arrange_accounts(&accounts).unwrap();
+
+    // Assert
+    assert_eq!(arranged.payer, accounts[0].pubkey);
+    assert_eq!(arranged.pool, accounts[1]);
+    assert_eq!(arranged.referrer, None);
+    assert!(arranged.remaining_accounts.is_empty());
+}
+
+#[test]
+fn test_min_accounts_is_the_position_of_the_last_required_account() {
+    // Arrange
+    let accounts = accounts(3);
+
+    // Act
+    let arranged = Deposit::try_arrange_accounts(&accounts).unwrap();
+    let truncated = Deposit::try_arrange_accounts(&accounts[..2]);
+
+    // Assert
+    assert_eq!(Swap::MIN_ACCOUNTS, 2);
+    assert_eq!(Deposit::MIN_ACCOUNTS, 3);
+    assert_eq!(
+        (arranged.owner, arranged.delegate, arranged.vault),
+        (
+            accounts[0].pubkey,
+            Some(accounts[1].pubkey),
+            accounts[2].pubkey
+        )
+    );
+    assert_eq!(
+        truncated.err().map(|error| error.to_string()),
+        Some("Deposit needs at least 3 accounts, but 2 were passed".to_string())
+    );
+}
@@ -0,0 +1,119 @@
+use carbon_core::{
+    borsh::{self, maybestd::io},
+    deserialize::{with_strict_decoding, CarbonDeserialize},
+    CarbonDeserialize,
+};
+
+/// Decoded from the data after the discriminator, ignoring the padding that
+/// follows the amount.
+#[derive(CarbonDeserialize)]
+#[carbon(discriminator = "0x01", deserialize_with = "deserialize_padded")]
+struct Padded {
+    amount: u64,
+}
+
+fn deserialize_padded(data: &[u8]) -> Option<Padded> {
+    let mut reader = data;
+    let amount: u64 = borsh::BorshDeserialize::deserialize(&mut reader).ok()?;
+
+    Some(Padded { amount })
+}
+
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Deposit,
+    Withdraw,
+}
+
+/// Reads a `Kind` from a `u32` tag rather than Borsh's `u8` one.
+fn read_u32_kind<R: io::Read>(reader: &mut R) -> io::Result<Kind> {
+    match <u32 as borsh::BorshDeserialize>::deserialize_reader(reader)? {
+        0 => Ok(Kind::Deposit),
+        1 => Ok(Kind::Withdraw),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown kind")),
+    }
+}
+
+#[derive(CarbonDeserialize)]
+#[carbon(discriminator = "0x02")]
+struct Record {
+    #[carbon(deserialize_with = "read_u32_kind")]
+    kind: Kind,
+    amount: u64,
+}
+
+#[derive(CarbonDeserialize)]
+#[carbon(discriminator = "0x03")]
+struct Extended {
+    amount: u64,
+    #[carbon(remaining_data)]
+    extension: Vec<u8>,
+}
+
+#[test]
+fn test_type_deserialize_with_receives_the_data_after_the_discriminator() {
+    // Arrange
+    let data: Vec<u8> = [1]
+        .into_iter()
+        .chain(7u64.to_le_bytes())
+        .chain([0; 16])
+        .collect();
+
+    // Act
+    let padded = Padded::deserialize(&data);
+    let other = Padded::deserialize(&[[2].as_slice(), &data[1..]].concat());
+
+    // Assert
+    assert_eq!(padded.map(|padded| padded.amount), Some(7));
+    assert!(other.is_none());
+    assert_eq!(Padded::LEN, None);
+}
+
+#[test]
+fn test_field_deserialize_with_replaces_the_borsh_decoding_of_the_field() {
+    // Arrange
+    let data: Vec<u8> = [2]
+        .into_iter()
+        .chain(1u32.to_le_bytes())
+        .chain(5u64.to_le_bytes())
+        .collect();
+
+    // Act
+    let record = Record::deserialize(&data);
+    let unknown = Record::deserialize(&[[2].as_slice(), &[9, 0, 0, 0], &data[5..]].concat());
+
+    // Assert
+    assert_eq!(
+        record.map(|record| (record.kind, record.amount)),
+        Some((Kind::Withdraw, 5))
+    );
+    assert!(unknown.is_none());
+    assert_eq!(Record::LEN, None);
+}
+
+#[test]
+fn test_remaining_data_captures_the_trailing_bytes() {
+    // Arrange
+    let data: Vec<u8> = [3]
+        .into_iter()
+        .chain(9u64.to_le_bytes())
+        .chain([0xaa, 0xbb])
+        .collect();
+
+    // Act
+    let extended = Extended::deserialize(&data);
+    let exact = Extended::deserialize(&data[..9]);
+    let strict = with_strict_decoding(true, || Extended::deserialize(&data));
+
+    // Assert
+    assert_eq!(
+        extended.map(|extended| (extended.amount, extended.extension)),
+        Some((9, vec![0xaa, 0xbb]))
+    );
+    assert_eq!(
+        exact.map(|exact| (exact.amount, exact.extension)),
+        Some((9, Vec::new()))
+    );
+    assert!(strict.is_none());
+    assert_eq!(Extended::LEN, None);
+}
@@ -0,0 +1,433 @@
+//! Derives swaps from the decoded instructions of DEX programs.
+//!
+//! Swap instructions name the pool, the trader and the token accounts the
+//! trader pays from and receives to, but usually only carry the amount
+//! requested, such as an exact input and a minimum output. A
+//! `SwapInstruction` implementation picks these accounts from the
+//! instructions of a venue, and the amounts and mints swapped are read from
+//! the token transfers out of and into the token accounts of the trader.
+
+use {
+    crate::{
+        transfers::{token_transfers, TokenTransfer},
+        Swap,
+    },
+    carbon_core::{
+        deserialize::ArrangeAccounts,
+        instruction::{InstructionMetadata, NestedInstructions},
+    },
+    carbon_jupiter_swap_decoder::instructions::JupiterSwapInstruction,
+    carbon_meteora_dlmm_decoder::instructions::{
+        swap::Swap as MeteoraDlmmSwap, swap_exact_out::SwapExactOut,
+        swap_with_price_impact::SwapWithPriceImpact, MeteoraDlmmInstruction,
+    },
+    carbon_orca_whirlpool_decoder::instructions::{
+        swap::Swap as OrcaWhirlpoolSwap, swap_v2::SwapV2 as OrcaWhirlpoolSwapV2,
+        OrcaWhirlpoolInstruction,
+    },
+    carbon_raydium_amm_v4_decoder::instructions::{
+        swap_base_in::SwapBaseIn, swap_base_out::SwapBaseOut, RaydiumAmmV4Instruction,
+    },
+    carbon_raydium_clmm_decoder::instructions::{
+        swap::Swap as RaydiumClmmSwap, swap_v2::SwapV2 as RaydiumClmmSwapV2, RaydiumClmmInstruction,
+    },
+    carbon_raydium_cpmm_decoder::instructions::{
+        swap_base_input::SwapBaseInput, swap_base_output::SwapBaseOutput, RaydiumCpmmInstruction,
+    },
+    solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+};
+
+/// A decoded instruction type swaps can be derived from.
+pub trait SwapInstruction {
+    /// Returns the swap made by the instruction, if it is a swap.
+    /// `nested_instructions` are its inner instructions, holding the token
+    /// transfers of the swap.
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap>;
+}
+
+/// The accounts of a swap instruction.
+struct SwapAccounts {
+    pool: Pubkey,
+    trader: Pubkey,
+    /// The token account the trader pays from.
+    source: Pubkey,
+    /// The token account the trader receives to.
+    destination: Pubkey,
+}
+
+impl SwapAccounts {
+    /// Returns the swap of `venue`, from the transfers out of the source and
+    /// into the destination among `nested_instructions`.
+    fn swap(
+        self,
+        venue: &'static str,
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let transaction_metadata = &metadata.transaction_metadata;
+        let transfers = token_transfers(nested_instructions);
+        let paid: Vec<&TokenTransfer> = transfers
+            .iter()
+            .filter(|transfer| transfer.source == self.source)
+            .collect();
+        let received: Vec<&TokenTransfer> = transfers
+            .iter()
+            .filter(|transfer| transfer.destination == self.destination)
+            .collect();
+
+        Some(Swap {
+            venue,
+            pool: self.pool,
+            trader: self.trader,
+            mint_in: paid.first()?.mint(transaction_metadata)?,
+            mint_out: received.first()?.mint(transaction_metadata)?,
+            amount_in: paid.iter().map(|transfer| transfer.amount).sum(),
+            amount_out: received.iter().map(|transfer| transfer.amount).sum(),
+            slot: transaction_metadata.slot,
+            block_time: transaction_metadata.block_time,
+            signature: transaction_metadata.signature,
+            path: metadata.absolute_path.clone(),
+        })
+    }
+}
+
+impl SwapInstruction for JupiterSwapInstruction {
+    fn swap(
+        &self,
+        _accounts: &[AccountMeta],
+        _nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let JupiterSwapInstruction::SwapEvent(event) = self else {
+            return None;
+        };
+        let transaction_metadata = &metadata.transaction_metadata;
+
+        Some(Swap {
+            venue: "jupiter",
+            pool: event.amm,
+            trader: transaction_metadata.fee_payer,
+            mint_in: event.input_mint,
+            mint_out: event.output_mint,
+            amount_in: event.input_amount,
+            amount_out: event.output_amount,
+            slot: transaction_metadata.slot,
+            block_time: transaction_metadata.block_time,
+            signature: transaction_metadata.signature,
+            path: metadata.absolute_path.clone(),
+        })
+    }
+}
+
+impl SwapInstruction for MeteoraDlmmInstruction {
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let swap_accounts = match self {
+            MeteoraDlmmInstruction::Swap(_) => {
+                let accounts = MeteoraDlmmSwap::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.lb_pair,
+                    trader: accounts.user,
+                    source: accounts.user_token_in,
+                    destination: accounts.user_token_out,
+                }
+            }
+            MeteoraDlmmInstruction::SwapExactOut(_) => {
+                let accounts = SwapExactOut::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.lb_pair,
+                    trader: accounts.user,
+                    source: accounts.user_token_in,
+                    destination: accounts.user_token_out,
+                }
+            }
+            MeteoraDlmmInstruction::SwapWithPriceImpact(_) => {
+                let accounts = SwapWithPriceImpact::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.lb_pair,
+                    trader: accounts.user,
+                    source: accounts.user_token_in,
+                    destination: accounts.user_token_out,
+                }
+            }
+            _ => return None,
+        };
+
+        swap_accounts.swap("meteora-dlmm", nested_instructions, metadata)
+    }
+}
+
+impl SwapInstruction for OrcaWhirlpoolInstruction {
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let (a_to_b, token_authority, whirlpool, account_a, account_b) = match self {
+            OrcaWhirlpoolInstruction::Swap(swap) => {
+                let accounts = OrcaWhirlpoolSwap::arrange_accounts(accounts)?;
+                (
+                    swap.a_to_b,
+                    accounts.token_authority,
+                    accounts.whirlpool,
+                    accounts.token_owner_account_a,
+                    accounts.token_owner_account_b,
+                )
+            }
+            OrcaWhirlpoolInstruction::SwapV2(swap) => {
+                let accounts = OrcaWhirlpoolSwapV2::arrange_accounts(accounts)?;
+                (
+                    swap.a_to_b,
+                    accounts.token_authority,
+                    accounts.whirlpool,
+                    accounts.token_owner_account_a,
+                    accounts.token_owner_account_b,
+                )
+            }
+            _ => return None,
+        };
+        let (source, destination) = if a_to_b {
+            (account_a, account_b)
+        } else {
+            (account_b, account_a)
+        };
+
+        SwapAccounts {
+            pool: whirlpool,
+            trader: token_authority,
+            source,
+            destination,
+        }
+        .swap("orca-whirlpool", nested_instructions, metadata)
+    }
+}
+
+impl SwapInstruction for RaydiumAmmV4Instruction {
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let swap_accounts = match self {
+            RaydiumAmmV4Instruction::SwapBaseIn(_) => {
+                let accounts = SwapBaseIn::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.amm,
+                    trader: accounts.user_source_owner,
+                    source: accounts.uer_source_token_account,
+                    destination: accounts.uer_destination_token_account,
+                }
+            }
+            RaydiumAmmV4Instruction::SwapBaseOut(_) => {
+                let accounts = SwapBaseOut::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.amm,
+                    trader: accounts.user_source_owner,
+                    source: accounts.uer_source_token_account,
+                    destination: accounts.uer_destination_token_account,
+                }
+            }
+            _ => return None,
+        };
+
+        swap_accounts.swap("raydium-amm-v4", nested_instructions, metadata)
+    }
+}
+
+impl SwapInstruction for RaydiumClmmInstruction {
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let swap_accounts = match self {
+            RaydiumClmmInstruction::Swap(_) => {
+                let accounts = RaydiumClmmSwap::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.pool_state,
+                    trader: accounts.payer,
+                    source: accounts.input_token_account,
+                    destination: accounts.output_token_account,
+                }
+            }
+            RaydiumClmmInstruction::SwapV2(_) => {
+                let accounts = RaydiumClmmSwapV2::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.pool_state,
+                    trader: accounts.payer,
+                    source: accounts.input_token_account,
+                    destination: accounts.output_token_account,
+                }
+            }
+            _ => return None,
+        };
+
+        swap_accounts.swap("raydium-clmm", nested_instructions, metadata)
+    }
+}
+
+impl SwapInstruction for RaydiumCpmmInstruction {
+    fn swap(
+        &self,
+        accounts: &[AccountMeta],
+        nested_instructions: &NestedInstructions,
+        metadata: &InstructionMetadata,
+    ) -> Option<Swap> {
+        let swap_accounts = match self {
+            RaydiumCpmmInstruction::SwapBaseInput(_) => {
+                let accounts = SwapBaseInput::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.pool_state,
+                    trader: accounts.payer,
+                    source: accounts.input_token_account,
+                    destination: accounts.output_token_account,
+                }
+            }
+            RaydiumCpmmInstruction::SwapBaseOutput(_) => {
+                let accounts = SwapBaseOutput::arrange_accounts(accounts)?;
+                SwapAccounts {
+                    pool: accounts.pool_state,
+                    trader: accounts.payer,
+                    source: accounts.input_token_account,
+                    destination: accounts.output_token_account,
+                }
+            }
+            _ => return None,
+        };
+
+        swap_accounts.swap("raydium-cpmm", nested_instructions, metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::{instruction::NestedInstruction, transaction::TransactionMetadata},
+        solana_instruction::Instruction,
+    };
+
+    const TOKEN_PROGRAM: Pubkey =
+        Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+    fn metadata(absolute_path: Vec<u8>) -> InstructionMetadata {
+        InstructionMetadata {
+            transaction_metadata: TransactionMetadata::default(),
+            stack_height: absolute_path.len() as u32,
+            index: 0,
+            absolute_path,
+            compute_units_consumed: None,
+        }
+    }
+
+    /// A `TransferChecked` of `amount` of `mint` from `source` to
+    /// `destination`.
+    fn transfer_checked(
+        source: Pubkey,
+        mint: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    ) -> NestedInstruction {
+        let mut data = vec![12];
+        data.extend(amount.to_le_bytes());
+        data.push(6);
+
+        NestedInstruction {
+            metadata: metadata(vec![0, 0]),
+            instruction: Instruction {
+                program_id: TOKEN_PROGRAM,
+                accounts: [source, mint, destination, Pubkey::new_unique()]
+                    .into_iter()
+                    .map(|account| AccountMeta::new(account, false))
+                    .collect(),
+                data,
+            },
+            inner_instructions: NestedInstructions::default(),
+        }
+    }
+
+    #[test]
+    fn test_whirlpool_swap_reads_the_amounts_of_its_transfers() {
+        // Arrange
+        let [token_authority, whirlpool, account_a, vault_a, account_b, vault_b, mint_a, mint_b] =
+            [(); 8].map(|_| Pubkey::new_unique());
+        let accounts: Vec<AccountMeta> = [
+            TOKEN_PROGRAM,
+            token_authority,
+            whirlpool,
+            account_a,
+            vault_a,
+            account_b,
+            vault_b,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ]
+        .into_iter()
+        .map(|account| AccountMeta::new(account, false))
+        .collect();
+        let instruction = OrcaWhirlpoolInstruction::Swap(OrcaWhirlpoolSwap {
+            amount: 100,
+            other_amount_threshold: 40,
+            sqrt_price_limit: 0,
+            amount_specified_is_input: true,
+            a_to_b: false,
+        });
+
+        let mut nested_instructions = NestedInstructions::default();
+        nested_instructions.push(transfer_checked(account_b, mint_b, vault_b, 100));
+        nested_instructions.push(transfer_checked(vault_a, mint_a, account_a, 45));
+
+        // Act
+        let swap = instruction
+            .swap(&accounts, &nested_instructions, &metadata(vec![1]))
+            .unwrap();
+
+        // Assert
+        assert_eq!(swap.venue, "orca-whirlpool");
+        assert_eq!(swap.pool, whirlpool);
+        assert_eq!(swap.trader, token_authority);
+        assert_eq!((swap.mint_in, swap.amount_in), (mint_b, 100));
+        assert_eq!((swap.mint_out, swap.amount_out), (mint_a, 45));
+        assert_eq!(swap.path, vec![1]);
+    }
+
+    #[test]
+    fn test_swap_without_transfers_is_skipped() {
+        // Arrange
+        let accounts: Vec<AccountMeta> = (0..11)
+            .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+            .collect();
+        let instruction = OrcaWhirlpoolInstruction::Swap(OrcaWhirlpoolSwap {
+            amount: 100,
+            other_amount_threshold: 40,
+            sqrt_price_limit: 0,
+            amount_specified_is_input: true,
+            a_to_b: true,
+        });
+
+        // Act
+        let swap = instruction.swap(
+            &accounts,
+            &NestedInstructions::default(),
+            &metadata(vec![0]),
+        );
+
+        // Assert
+        assert_eq!(swap, None);
+    }
+}
@@ -0,0 +1,130 @@
+//! Normalizes the swaps of DEX programs into a single `Swap` type.
+//!
+//! Each venue names the accounts and amounts of its swap instructions
+//! differently, and most instructions only carry the amount requested, not
+//! the amount swapped. A [`SwapNormalizer`] turns the decoded swap
+//! instructions of a venue into [`Swap`]s, reading the amounts swapped from
+//! the token transfers the instruction made, and passes them to a processor,
+//! such as a sink writing the trades of every venue to a single table.
+//!
+//! The supported venues and instructions are:
+//!
+//! - `jupiter`: the `SwapEvent` of each hop of a route.
+//! - `meteora-dlmm`: `Swap`, `SwapExactOut` and `SwapWithPriceImpact`.
+//! - `orca-whirlpool`: `Swap` and `SwapV2`.
+//! - `raydium-amm-v4`: `SwapBaseIn` and `SwapBaseOut`.
+//! - `raydium-clmm`: `Swap` and `SwapV2`.
+//! - `raydium-cpmm`: `SwapBaseInput` and `SwapBaseOutput`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! Pipeline::builder()
+//!     .datasource(my_transaction_datasource)
+//!     .instruction(OrcaWhirlpoolDecoder, SwapNormalizer::new(TradesSink::new()))
+//!     .instruction(RaydiumAmmV4Decoder, SwapNormalizer::new(TradesSink::new()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Amounts are raw token amounts, without decimals. `amount_in` includes
+//!   the fees the trader paid within the swap, such as host fees.
+//! - Jupiter routes its swaps through the other venues. A routed swap is
+//!   reported by Jupiter, with the program of the venue as its `pool`, and
+//!   again by the venue if its instructions are normalized too: filter on
+//!   `venue` to count the volume once.
+//! - Orca two-hop swaps, Raydium CLMM router swaps and Token-2022 transfers
+//!   with a transfer fee are not normalized.
+//! - Instructions of failed transactions are ignored.
+
+pub mod instructions;
+pub mod normalizer;
+mod transfers;
+
+pub use {instructions::SwapInstruction, normalizer::SwapNormalizer};
+
+use {carbon_core::CarbonSerialize, solana_pubkey::Pubkey, solana_signature::Signature};
+
+/// A swap of one token for another against a pool, whatever the venue.
+#[derive(Debug, Clone, PartialEq, Eq, CarbonSerialize)]
+pub struct Swap {
+    pub venue: &'static str,
+    /// The pool swapped against, or for Jupiter, whose events do not name
+    /// it, the program of the venue the route went through.
+    #[carbon(serialize_as = "base58")]
+    pub pool: Pubkey,
+    /// The signer of the swap, or the fee payer of the transaction for
+    /// Jupiter.
+    #[carbon(serialize_as = "base58")]
+    pub trader: Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub mint_in: Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub mint_out: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    #[carbon(serialize_as = "base58")]
+    pub signature: Signature,
+    /// The position of the instruction in its transaction, serialized as the
+    /// `path` column of the SQL sinks, such as `0:1` for the second inner
+    /// instruction of the first instruction.
+    #[serde(with = "path")]
+    pub path: Vec<u8>,
+}
+
+mod path {
+    pub fn serialize<S: serde::Serializer>(path: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let path = path.iter().map(u8::to_string).collect::<Vec<_>>().join(":");
+
+        serializer.serialize_str(&path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_swap_serializes_pubkeys_and_path_as_text() {
+        // Arrange
+        let swap = Swap {
+            venue: "orca-whirlpool",
+            pool: Pubkey::new_unique(),
+            trader: Pubkey::new_unique(),
+            mint_in: Pubkey::new_unique(),
+            mint_out: Pubkey::new_unique(),
+            amount_in: 10,
+            amount_out: 20,
+            slot: 5,
+            block_time: None,
+            signature: Signature::from([1; 64]),
+            path: vec![0, 1],
+        };
+
+        // Act
+        let value = serde_json::to_value(&swap).unwrap();
+
+        // Assert
+        assert_eq!(
+            value,
+            json!({
+                "venue": "orca-whirlpool",
+                "pool": swap.pool.to_string(),
+                "trader": swap.trader.to_string(),
+                "mint_in": swap.mint_in.to_string(),
+                "mint_out": swap.mint_out.to_string(),
+                "amount_in": 10,
+                "amount_out": 20,
+                "slot": 5,
+                "block_time": null,
+                "signature": swap.signature.to_string(),
+                "path": "0:1",
+            })
+        );
+    }
+}
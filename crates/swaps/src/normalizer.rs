@@ -0,0 +1,166 @@
+//! Passes the swaps of decoded instructions to a processor of `Swap`s.
+
+use {
+    crate::{Swap, SwapInstruction},
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult, instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection, processor::Processor,
+    },
+    std::{marker::PhantomData, sync::Arc},
+};
+
+/// An instruction processor passing the swap of each swap instruction of a
+/// venue to a processor of `Swap`s.
+pub struct SwapNormalizer<T, P> {
+    processor: P,
+    _instruction: PhantomData<fn() -> T>,
+}
+
+impl<T, P> SwapNormalizer<T, P>
+where
+    T: SwapInstruction,
+    P: Processor<InputType = Swap>,
+{
+    pub fn new(processor: P) -> Self {
+        Self {
+            processor,
+            _instruction: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<T, P> Processor for SwapNormalizer<T, P>
+where
+    T: SwapInstruction + Send + Sync + 'static,
+    P: Processor<InputType = Swap> + Send + Sync,
+{
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, instruction, nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if metadata.transaction_metadata.meta.status.is_err() {
+            return Ok(());
+        }
+
+        let Some(swap) =
+            instruction
+                .data
+                .swap(&instruction.accounts, &nested_instructions, &metadata)
+        else {
+            return Ok(());
+        };
+
+        metrics.increment_counter("swaps_normalized", 1).await?;
+        self.processor.process(swap, metrics).await
+    }
+
+    async fn setup(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.setup(metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.flush(metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.processor.teardown(metrics).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::{
+            instruction::{DecodedInstruction, InstructionMetadata, NestedInstructions},
+            transaction::TransactionMetadata,
+        },
+        solana_instruction::AccountMeta,
+        solana_pubkey::Pubkey,
+        solana_sdk::transaction::TransactionError,
+        std::sync::Mutex,
+    };
+
+    /// Swaps its amount of the default mint for nothing.
+    struct Sell(u64);
+
+    impl SwapInstruction for Sell {
+        fn swap(
+            &self,
+            accounts: &[AccountMeta],
+            _nested_instructions: &NestedInstructions,
+            metadata: &InstructionMetadata,
+        ) -> Option<Swap> {
+            Some(Swap {
+                venue: "test",
+                pool: Pubkey::default(),
+                trader: accounts[0].pubkey,
+                mint_in: Pubkey::default(),
+                mint_out: Pubkey::default(),
+                amount_in: self.0,
+                amount_out: 0,
+                slot: metadata.transaction_metadata.slot,
+                block_time: None,
+                signature: metadata.transaction_metadata.signature,
+                path: metadata.absolute_path.clone(),
+            })
+        }
+    }
+
+    struct Swaps(Arc<Mutex<Vec<u64>>>);
+
+    #[async_trait]
+    impl Processor for Swaps {
+        type InputType = Swap;
+
+        async fn process(
+            &mut self,
+            swap: Swap,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(swap.amount_in);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_normalizer_skips_failed_transactions() {
+        // Arrange
+        let swaps = Arc::new(Mutex::new(Vec::new()));
+        let mut normalizer = SwapNormalizer::<Sell, _>::new(Swaps(swaps.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        for (amount, failed) in [(5, false), (10, true), (15, false)] {
+            let mut transaction_metadata = TransactionMetadata::default();
+            if failed {
+                transaction_metadata.meta.status = Err(TransactionError::AccountInUse);
+            }
+            let metadata = InstructionMetadata {
+                transaction_metadata,
+                stack_height: 1,
+                index: 0,
+                absolute_path: vec![0],
+                compute_units_consumed: None,
+            };
+            let instruction = DecodedInstruction {
+                program_id: Pubkey::default(),
+                data: Sell(amount),
+                accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+                audit: None,
+            };
+            normalizer
+                .process((metadata, instruction, Default::default()), metrics.clone())
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        assert_eq!(*swaps.lock().unwrap(), vec![5, 15]);
+    }
+}
@@ -0,0 +1,170 @@
+//! Reads the token transfers of an instruction, and the mints of the token
+//! accounts involved.
+
+use {
+    carbon_core::{
+        deserialize::{ArrangeAccounts, CarbonDeserialize},
+        instruction::NestedInstructions,
+        transaction::TransactionMetadata,
+    },
+    carbon_token_program_decoder::instructions::{
+        transfer::Transfer, transfer_checked::TransferChecked,
+    },
+    solana_pubkey::Pubkey,
+};
+
+/// The token programs whose transfers are read. Token-2022 shares the layout
+/// of the `Transfer` and `TransferChecked` instructions of the token program.
+const TOKEN_PROGRAMS: [Pubkey; 2] = [
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+];
+
+/// A transfer of tokens between two token accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TokenTransfer {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    /// The mint, named by `TransferChecked` only.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+impl TokenTransfer {
+    /// Returns the mint transferred, read from the token balances of the
+    /// transaction when the instruction does not name it.
+    pub(crate) fn mint(&self, transaction_metadata: &TransactionMetadata) -> Option<Pubkey> {
+        self.mint
+            .or_else(|| token_account_mint(transaction_metadata, &self.source))
+            .or_else(|| token_account_mint(transaction_metadata, &self.destination))
+    }
+}
+
+/// Returns the token transfers among the inner instructions of an
+/// instruction, in order.
+pub(crate) fn token_transfers(nested_instructions: &NestedInstructions) -> Vec<TokenTransfer> {
+    nested_instructions
+        .iter()
+        .filter(|nested_instruction| {
+            TOKEN_PROGRAMS.contains(&nested_instruction.instruction.program_id)
+        })
+        .filter_map(|nested_instruction| {
+            let instruction = &nested_instruction.instruction;
+
+            if let Some(transfer) = Transfer::deserialize(&instruction.data) {
+                let accounts = Transfer::arrange_accounts(&instruction.accounts)?;
+                return Some(TokenTransfer {
+                    source: accounts.source,
+                    destination: accounts.destination,
+                    mint: None,
+                    amount: transfer.amount,
+                });
+            }
+
+            let transfer = TransferChecked::deserialize(&instruction.data)?;
+            let accounts = TransferChecked::arrange_accounts(&instruction.accounts)?;
+            Some(TokenTransfer {
+                source: accounts.source,
+                destination: accounts.destination,
+                mint: Some(accounts.mint),
+                amount: transfer.amount,
+            })
+        })
+        .collect()
+}
+
+/// Returns the mint of a token account, from the token balances of the
+/// transaction.
+fn token_account_mint(
+    transaction_metadata: &TransactionMetadata,
+    account: &Pubkey,
+) -> Option<Pubkey> {
+    let index = transaction_metadata
+        .account_keys()
+        .iter()
+        .position(|key| key == account)?;
+    let meta = &transaction_metadata.meta;
+
+    meta.pre_token_balances
+        .iter()
+        .chain(meta.post_token_balances.iter())
+        .flatten()
+        .find(|balance| balance.account_index as usize == index)
+        .and_then(|balance| balance.mint.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::instruction::{InstructionMetadata, NestedInstruction},
+        solana_instruction::{AccountMeta, Instruction},
+    };
+
+    fn token_instruction(accounts: &[Pubkey], data: Vec<u8>) -> NestedInstruction {
+        NestedInstruction {
+            metadata: InstructionMetadata {
+                transaction_metadata: TransactionMetadata::default(),
+                stack_height: 2,
+                index: 0,
+                absolute_path: vec![0, 0],
+                compute_units_consumed: None,
+            },
+            instruction: Instruction {
+                program_id: TOKEN_PROGRAMS[0],
+                accounts: accounts
+                    .iter()
+                    .map(|account| AccountMeta::new(*account, false))
+                    .collect(),
+                data,
+            },
+            inner_instructions: NestedInstructions::default(),
+        }
+    }
+
+    #[test]
+    fn test_token_transfers_reads_transfers_and_checked_transfers() {
+        // Arrange
+        let [source, destination, mint, authority] = [(); 4].map(|_| Pubkey::new_unique());
+        let mut transfer = vec![3];
+        transfer.extend(5u64.to_le_bytes());
+        let mut transfer_checked = vec![12];
+        transfer_checked.extend(7u64.to_le_bytes());
+        transfer_checked.push(6);
+        let mut other_program = token_instruction(&[source, destination, authority], vec![9]);
+        other_program.instruction.program_id = Pubkey::new_unique();
+
+        let mut nested_instructions = NestedInstructions::default();
+        nested_instructions.push(token_instruction(
+            &[source, destination, authority],
+            transfer,
+        ));
+        nested_instructions.push(other_program);
+        nested_instructions.push(token_instruction(
+            &[destination, mint, source, authority],
+            transfer_checked,
+        ));
+
+        // Act
+        let transfers = token_transfers(&nested_instructions);
+
+        // Assert
+        assert_eq!(
+            transfers,
+            vec![
+                TokenTransfer {
+                    source,
+                    destination,
+                    mint: None,
+                    amount: 5,
+                },
+                TokenTransfer {
+                    source: destination,
+                    destination: source,
+                    mint: Some(mint),
+                    amount: 7,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Assertions matching decoded instructions and accounts against patterns.
+//!
+//! The macros decode with the given decoder and check the result with a
+//! pattern, so a test only spells out the fields it cares about:
+//!
+//! ```ignore
+//! let instruction = carbon_test_utils::read_instruction("tests/fixtures/buy_ix.json")?;
+//!
+//! carbon_test_utils::assert_decodes!(
+//!     PumpfunDecoder,
+//!     instruction,
+//!     PumpfunInstruction::Buy(buy::Buy { amount: 1_000.., .. })
+//! );
+//! ```
+//!
+//! Patterns accept ranges, wildcards and `if` guards. The macros call the
+//! `carbon_core` decoder traits, so the crate using them must depend on
+//! `carbon-core`.
+
+/// Decodes an instruction and asserts that the decoded data matches a
+/// pattern, returning the `DecodedInstruction`.
+///
+/// # Panics
+///
+/// Panics if the decoder does not decode the instruction, or if the decoded
+/// data does not match the pattern.
+#[macro_export]
+macro_rules! assert_decodes {
+    ($decoder:expr, $instruction:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+        let instruction = &$instruction;
+        let decoded =
+            carbon_core::instruction::InstructionDecoder::decode_instruction(&$decoder, instruction)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "instruction was not decoded, expected `{}`",
+                        stringify!($pattern)
+                    )
+                });
+
+        assert!(
+            matches!(&decoded.data, $pattern $(if $guard)?),
+            "decoded instruction {:?} does not match `{}`",
+            decoded.data,
+            stringify!($pattern)
+        );
+
+        decoded
+    }};
+}
+
+/// Decodes an account and asserts that the decoded data matches a pattern,
+/// returning the `DecodedAccount`.
+///
+/// # Panics
+///
+/// Panics if the decoder does not decode the account, or if the decoded data
+/// does not match the pattern.
+#[macro_export]
+macro_rules! assert_decodes_account {
+    ($decoder:expr, $account:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+        let account = &$account;
+        let decoded = carbon_core::account::AccountDecoder::decode_account(&$decoder, account)
+            .unwrap_or_else(|| {
+                panic!(
+                    "account was not decoded, expected `{}`",
+                    stringify!($pattern)
+                )
+            });
+
+        assert!(
+            matches!(&decoded.data, $pattern $(if $guard)?),
+            "decoded account {:?} does not match `{}`",
+            decoded.data,
+            stringify!($pattern)
+        );
+
+        decoded
+    }};
+}
+
+/// Asserts that the decoder does not decode an instruction.
+///
+/// # Panics
+///
+/// Panics with the decoded data if the instruction is decoded.
+#[macro_export]
+macro_rules! assert_not_decodes {
+    ($decoder:expr, $instruction:expr $(,)?) => {{
+        let instruction = &$instruction;
+
+        if let Some(decoded) =
+            carbon_core::instruction::InstructionDecoder::decode_instruction(&$decoder, instruction)
+        {
+            panic!("instruction was unexpectedly decoded as {:?}", decoded.data);
+        }
+    }};
+}
@@ -0,0 +1,205 @@
+//! A mock Yellowstone gRPC server replaying recorded geyser frames.
+//!
+//! Frames are `SubscribeUpdate` messages, stored back to back in a file, each
+//! prefixed with its length as protobuf does for delimited messages. Frames
+//! received from a live endpoint can be recorded with `write_frames` and
+//! replayed with `MockGeyserServer`, so the Yellowstone datasource is tested
+//! end to end without a real endpoint.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let server = MockGeyserServer::from_file("tests/fixtures/frames.bin")?
+//!     .start()
+//!     .await?;
+//!
+//! let datasource = YellowstoneGrpcGeyserClient::new(
+//!     GeyserEndpoint::new(server.endpoint(), None)?,
+//!     ...
+//! );
+//! ```
+
+use {
+    futures::{stream, Stream, StreamExt},
+    std::{fs, path::Path, pin::Pin, sync::Arc},
+    tokio::{net::TcpListener, task::JoinHandle},
+    tokio_stream::wrappers::TcpListenerStream,
+    yellowstone_grpc_proto::{
+        geyser::{
+            geyser_server::{Geyser, GeyserServer},
+            GetBlockHeightRequest, GetBlockHeightResponse, GetLatestBlockhashRequest,
+            GetLatestBlockhashResponse, GetSlotRequest, GetSlotResponse, GetVersionRequest,
+            GetVersionResponse, IsBlockhashValidRequest, IsBlockhashValidResponse, PingRequest,
+            PongResponse, SubscribeReplayInfoRequest, SubscribeReplayInfoResponse,
+            SubscribeRequest, SubscribeUpdate,
+        },
+        prost::Message,
+        tonic::{self, transport::Server, Request, Response, Status, Streaming},
+    },
+};
+
+/// Decodes length-delimited `SubscribeUpdate` frames.
+pub fn decode_frames(mut bytes: &[u8]) -> anyhow::Result<Vec<SubscribeUpdate>> {
+    let mut frames = Vec::new();
+
+    while !bytes.is_empty() {
+        let frame = SubscribeUpdate::decode_length_delimited(&mut bytes)
+            .map_err(|e| anyhow::anyhow!("Couldn't decode frame: {e}"))?;
+        frames.push(frame);
+    }
+
+    Ok(frames)
+}
+
+/// Encodes `SubscribeUpdate` frames, each prefixed with its length.
+pub fn encode_frames(frames: &[SubscribeUpdate]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    for frame in frames {
+        bytes.extend(frame.encode_length_delimited_to_vec());
+    }
+
+    bytes
+}
+
+pub fn read_frames<P: AsRef<Path>>(frames_path: P) -> anyhow::Result<Vec<SubscribeUpdate>> {
+    let data = fs::read(frames_path).map_err(|e| anyhow::anyhow!("Couldn't read fixture: {e}"))?;
+
+    decode_frames(&data)
+}
+
+pub fn write_frames<P: AsRef<Path>>(
+    frames_path: P,
+    frames: &[SubscribeUpdate],
+) -> anyhow::Result<()> {
+    fs::write(frames_path, encode_frames(frames))
+        .map_err(|e| anyhow::anyhow!("Couldn't write fixture: {e}"))
+}
+
+/// A Geyser server sending the same frames to each subscriber.
+///
+/// The subscription stays open after the last frame, as a live endpoint
+/// waiting for the next update would. Only `subscribe` is implemented.
+#[derive(Debug, Clone)]
+pub struct MockGeyserServer {
+    frames: Arc<Vec<SubscribeUpdate>>,
+}
+
+impl MockGeyserServer {
+    pub fn new(frames: Vec<SubscribeUpdate>) -> Self {
+        Self {
+            frames: Arc::new(frames),
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(frames_path: P) -> anyhow::Result<Self> {
+        Ok(Self::new(read_frames(frames_path)?))
+    }
+
+    /// Starts serving on a local port picked by the OS.
+    pub async fn start(self) -> anyhow::Result<RunningMockGeyserServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| anyhow::anyhow!("Couldn't bind mock server: {e}"))?;
+        let address = listener
+            .local_addr()
+            .map_err(|e| anyhow::anyhow!("Couldn't bind mock server: {e}"))?;
+
+        let task = tokio::spawn(async move {
+            if let Err(e) = Server::builder()
+                .add_service(GeyserServer::new(self))
+                .serve_with_incoming(TcpListenerStream::new(listener))
+                .await
+            {
+                eprintln!("Mock geyser server failed: {e}");
+            }
+        });
+
+        Ok(RunningMockGeyserServer {
+            endpoint: format!("http://{}", address),
+            task,
+        })
+    }
+}
+
+/// A started `MockGeyserServer`, stopped when dropped.
+#[derive(Debug)]
+pub struct RunningMockGeyserServer {
+    endpoint: String,
+    task: JoinHandle<()>,
+}
+
+impl RunningMockGeyserServer {
+    pub fn endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+}
+
+impl Drop for RunningMockGeyserServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[tonic::async_trait]
+impl Geyser for MockGeyserServer {
+    type SubscribeStream = Pin<Box<dyn Stream<Item = Result<SubscribeUpdate, Status>> + Send>>;
+
+    async fn subscribe(
+        &self,
+        _request: Request<Streaming<SubscribeRequest>>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let frames: Vec<Result<SubscribeUpdate, Status>> =
+            self.frames.iter().cloned().map(Ok).collect();
+
+        Ok(Response::new(Box::pin(
+            stream::iter(frames).chain(stream::pending()),
+        )))
+    }
+
+    async fn subscribe_replay_info(
+        &self,
+        _request: Request<SubscribeReplayInfoRequest>,
+    ) -> Result<Response<SubscribeReplayInfoResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn ping(&self, _request: Request<PingRequest>) -> Result<Response<PongResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn get_latest_blockhash(
+        &self,
+        _request: Request<GetLatestBlockhashRequest>,
+    ) -> Result<Response<GetLatestBlockhashResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn get_block_height(
+        &self,
+        _request: Request<GetBlockHeightRequest>,
+    ) -> Result<Response<GetBlockHeightResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn get_slot(
+        &self,
+        _request: Request<GetSlotRequest>,
+    ) -> Result<Response<GetSlotResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn is_blockhash_valid(
+        &self,
+        _request: Request<IsBlockhashValidRequest>,
+    ) -> Result<Response<IsBlockhashValidResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+
+    async fn get_version(
+        &self,
+        _request: Request<GetVersionRequest>,
+    ) -> Result<Response<GetVersionResponse>, Status> {
+        Err(Status::unimplemented("not supported by the mock server"))
+    }
+}
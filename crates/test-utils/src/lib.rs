@@ -6,9 +6,12 @@ use solana_pubkey::Pubkey;
 use solana_transaction_status::UiTransactionStatusMeta;
 use std::{fs, path::Path};
 
+mod assertions;
 pub mod base58_deserialize;
 mod base64_deserialize;
 mod field_as_string;
+#[cfg(feature = "geyser")]
+pub mod geyser;
 mod hex_deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -99,3 +102,57 @@ pub fn read_account<P: AsRef<Path>>(acc_path: P) -> anyhow::Result<Account> {
 
     Ok(acc.into())
 }
+
+/// Builds an instruction of `program_id` with raw `data` and no accounts, for
+/// decoders that only look at the data.
+pub fn instruction_from_data(program_id: Pubkey, data: impl Into<Vec<u8>>) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: Vec::new(),
+        data: data.into(),
+    }
+}
+
+/// Reads every instruction fixture of a directory, named `<name>_ix.json`,
+/// sorted by name.
+pub fn read_instructions<P: AsRef<Path>>(
+    fixtures_dir: P,
+) -> anyhow::Result<Vec<(String, Instruction)>> {
+    read_fixtures(fixtures_dir, "_ix.json", read_instruction)
+}
+
+/// Reads every account fixture of a directory, named `<name>_account.json`,
+/// sorted by name.
+pub fn read_accounts<P: AsRef<Path>>(fixtures_dir: P) -> anyhow::Result<Vec<(String, Account)>> {
+    read_fixtures(fixtures_dir, "_account.json", read_account)
+}
+
+fn read_fixtures<T>(
+    fixtures_dir: impl AsRef<Path>,
+    suffix: &str,
+    read: impl Fn(&Path) -> anyhow::Result<T>,
+) -> anyhow::Result<Vec<(String, T)>> {
+    let mut fixtures = Vec::new();
+
+    for entry in fs::read_dir(fixtures_dir)
+        .map_err(|e| anyhow::anyhow!("Couldn't read fixtures directory: {e}"))?
+    {
+        let path = entry
+            .map_err(|e| anyhow::anyhow!("Couldn't read fixtures directory: {e}"))?
+            .path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.strip_suffix(suffix))
+        else {
+            continue;
+        };
+
+        let fixture = read(&path).map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+        fixtures.push((name.to_string(), fixture));
+    }
+
+    fixtures.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(fixtures)
+}
@@ -2,7 +2,8 @@ use {
     async_trait::async_trait,
     carbon_core::{
         datasource::{
-            AccountDeletion, AccountUpdate, Datasource, TransactionUpdate, Update, UpdateType,
+            AccountDeletion, AccountUpdate, Capabilities, Capability, Datasource,
+            TransactionUpdate, Update,
         },
         error::CarbonResult,
         metrics::MetricsCollection,
@@ -331,6 +332,8 @@ impl Datasource for HeliusWebsocket {
                                                         pubkey: account,
                                                         account: decoded_account,
                                                         slot: acc_event.context.slot,
+                                                        write_version: None,
+                                                        commitment: None,
                                                     });
 
                                                     metrics.record_histogram("helius_atlas_ws_account_process_time_nanoseconds", start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
@@ -583,6 +586,7 @@ impl Datasource for HeliusWebsocket {
                                                 is_vote: config.filter.vote.is_some_and(|is_vote| is_vote),
                                                 slot: tx_event.slot,
                                                 block_time: None,
+                                                commitment: None,
                                             }));
 
                                             metrics
@@ -616,6 +620,9 @@ impl Datasource for HeliusWebsocket {
 
                 for handle in handles {
                     if let Err(e) = handle.await {
+                        if e.is_panic() {
+                            std::panic::resume_unwind(e.into_panic());
+                        }
                         log::error!("Helius WS Task failed: {:?}", e);
                     }
                 }
@@ -633,6 +640,12 @@ impl Datasource for HeliusWebsocket {
                 }
                 result = handle => {
                     if let Err(e) = result {
+                        // A panic is resumed, for the pipeline to restart the
+                        // datasource.
+                        if e.is_panic() {
+                            iteration_cancellation_clone.cancel();
+                            std::panic::resume_unwind(e.into_panic());
+                        }
                         log::error!("Main task failed: {:?}", e);
                     }
                 }
@@ -645,11 +658,10 @@ impl Datasource for HeliusWebsocket {
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![
-            UpdateType::Transaction,
-            UpdateType::AccountUpdate,
-            UpdateType::AccountDeletion,
-        ]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new()
+            .with(Capability::Transactions)
+            .with(Capability::AccountUpdates)
+            .with(Capability::AccountDeletions)
     }
 }
@@ -1,11 +1,10 @@
-pub use solana_client::rpc_config::RpcBlockConfig;
 use {
     async_trait::async_trait,
     carbon_core::{
-        datasource::{Datasource, TransactionUpdate, Update, UpdateType},
+        datasource::{join_tasks, Capabilities, Capability, Datasource, TransactionUpdate, Update},
         error::CarbonResult,
         metrics::MetricsCollection,
-        transformers::transaction_metadata_from_original_meta,
+        transformers::{block_details_from_ui_block, transaction_metadata_from_original_meta},
     },
     futures::StreamExt,
     solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction},
@@ -21,10 +20,14 @@ use {
     },
     tokio_util::sync::CancellationToken,
 };
+pub use {progress::*, solana_client::rpc_config::RpcBlockConfig};
+
+mod progress;
 
 const CHANNEL_BUFFER_SIZE: usize = 1000;
 const MAX_CONCURRENT_REQUESTS: usize = 10;
 const BLOCK_INTERVAL: Duration = Duration::from_millis(100);
+const PROGRESS_REPORT_INTERVAL: Duration = Duration::from_secs(10);
 
 /// RpcBlockCrawler is a datasource that crawls the Solana blockchain for blocks and sends them to the sender.
 /// It uses a channel to send blocks to the task processor.
@@ -36,6 +39,8 @@ pub struct RpcBlockCrawler {
     pub block_config: RpcBlockConfig,
     pub max_concurrent_requests: usize,
     pub channel_buffer_size: usize,
+    pub progress_callback: Option<ProgressCallback>,
+    pub progress_report_interval: Duration,
 }
 
 impl RpcBlockCrawler {
@@ -56,8 +61,29 @@ impl RpcBlockCrawler {
             block_interval: block_interval.unwrap_or(BLOCK_INTERVAL),
             max_concurrent_requests: max_concurrent_requests.unwrap_or(MAX_CONCURRENT_REQUESTS),
             channel_buffer_size: channel_buffer_size.unwrap_or(CHANNEL_BUFFER_SIZE),
+            progress_callback: None,
+            progress_report_interval: PROGRESS_REPORT_INTERVAL,
         }
     }
+
+    /// Calls `callback` with the crawl progress at every report interval and
+    /// once the `end_slot` is reached.
+    ///
+    /// Progress is also exported through the `block_crawler_backfill_*`
+    /// gauges, whether or not a callback is set.
+    pub fn with_progress_callback(
+        mut self,
+        callback: impl Fn(&BackfillProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sets how often progress is reported. Defaults to 10 seconds.
+    pub fn with_progress_report_interval(mut self, interval: Duration) -> Self {
+        self.progress_report_interval = interval;
+        self
+    }
 }
 
 #[async_trait]
@@ -85,6 +111,12 @@ impl Datasource for RpcBlockCrawler {
             self.block_config,
             block_sender,
             self.max_concurrent_requests,
+            ProgressTracker::new(
+                self.start_slot,
+                self.end_slot,
+                self.progress_report_interval,
+            ),
+            self.progress_callback.clone(),
             cancellation_token.clone(),
             metrics.clone(),
         );
@@ -96,18 +128,20 @@ impl Datasource for RpcBlockCrawler {
             metrics.clone(),
         );
 
-        tokio::spawn(async move {
-            tokio::select! {
-                _ = block_fetcher => {},
-                _ = task_processor => {},
-            }
-        });
+        join_tasks(vec![block_fetcher, task_processor]).await;
 
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![UpdateType::Transaction]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new()
+            .with(Capability::Transactions)
+            .with(Capability::BlockDetails)
+            .with(Capability::HistoricalRange)
+    }
+
+    fn is_bounded(&self) -> bool {
+        self.end_slot.is_some()
     }
 }
 
@@ -120,6 +154,8 @@ fn block_fetcher(
     block_config: RpcBlockConfig,
     block_sender: Sender<(u64, UiConfirmedBlock)>,
     max_concurrent_requests: usize,
+    mut progress_tracker: ProgressTracker,
+    progress_callback: Option<ProgressCallback>,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
 ) -> JoinHandle<()> {
@@ -170,7 +206,7 @@ fn block_fetcher(
                 }
             };
 
-            fetch_stream
+            let fetch_results = fetch_stream
                 .map(|slot| {
                     let rpc_client = Arc::clone(&rpc_client);
                     let metrics = metrics.clone();
@@ -197,7 +233,7 @@ fn block_fetcher(
                                         log::error!("Error recording metric: {}", value)
                                     });
 
-                                Some((slot, block))
+                                (slot, Some(block))
                             }
                             Err(e) => {
                                 // https://support.quicknode.com/hc/en-us/articles/16459608696721-Solana-RPC-Error-Code-Reference
@@ -218,20 +254,25 @@ fn block_fetcher(
                                 } else {
                                     log::error!("Error fetching block at slot {}: {:?}", slot, e);
                                 }
-                                None
+                                (slot, None)
                             }
                         }
                     }
                 })
-                .buffer_unordered(max_concurrent_requests)
-                .for_each(|result| async {
-                    if let Some((slot, block)) = result {
-                        if let Err(e) = block_sender.send((slot, block)).await {
-                            log::error!("Failed to send block: {:?}", e);
-                        }
+                .buffer_unordered(max_concurrent_requests);
+            futures::pin_mut!(fetch_results);
+
+            while let Some((slot, block)) = fetch_results.next().await {
+                if let Some(progress) = progress_tracker.record(slot) {
+                    report_progress(progress, progress_callback.as_ref(), &metrics).await;
+                }
+
+                if let Some(block) = block {
+                    if let Err(e) = block_sender.send((slot, block)).await {
+                        log::error!("Failed to send block: {:?}", e);
                     }
-                })
-                .await;
+                }
+            }
         };
 
         tokio::select! {
@@ -243,6 +284,48 @@ fn block_fetcher(
     })
 }
 
+async fn report_progress(
+    progress: &BackfillProgress,
+    progress_callback: Option<&ProgressCallback>,
+    metrics: &MetricsCollection,
+) {
+    log::info!(
+        "Block crawler progress: {} slots processed, highest slot {}, {:.2}% complete, ETA {:?}",
+        progress.slots_processed,
+        progress.highest_slot,
+        progress.percentage().unwrap_or_default(),
+        progress.eta(),
+    );
+
+    let mut gauges = vec![
+        (
+            "block_crawler_backfill_slots_processed",
+            progress.slots_processed as f64,
+        ),
+        (
+            "block_crawler_backfill_highest_slot",
+            progress.highest_slot as f64,
+        ),
+    ];
+    if let Some(percentage) = progress.percentage() {
+        gauges.push(("block_crawler_backfill_progress_percentage", percentage));
+    }
+    if let Some(eta) = progress.eta() {
+        gauges.push(("block_crawler_backfill_eta_seconds", eta.as_secs_f64()));
+    }
+
+    for (name, value) in gauges {
+        metrics
+            .update_gauge(name, value)
+            .await
+            .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+    }
+
+    if let Some(callback) = progress_callback {
+        callback(progress);
+    }
+}
+
 /// Process the block and send the transactions to the sender
 fn task_processor(
     block_receiver: Receiver<(u64, UiConfirmedBlock)>,
@@ -260,7 +343,21 @@ fn task_processor(
                     log::info!("Cancelling RPC Crawler task processor...");
                     break;
                 }
-                Some((slot, block)) = block_receiver.recv() => {
+                block = block_receiver.recv() => {
+                    let Some((slot, block)) = block else {
+                        // Unless cancelled, the fetcher only stops once the
+                        // `end_slot` is reached.
+                        if cancellation_token.is_cancelled() {
+                            break;
+                        }
+
+                        log::info!("RPC Crawler reached the end slot.");
+                        if let Err(err) = sender.send(Update::DatasourceFinished).await {
+                            log::error!("Error sending datasource finished: {:?}", err);
+                        }
+                        break;
+                    };
+
                     metrics
                         .increment_counter("block_crawler_blocks_received", 1)
                         .await
@@ -268,6 +365,12 @@ fn task_processor(
                             log::error!("Error recording metric: {}", value)
                         });
                     let block_start_time = Instant::now();
+
+                    let block_details = block_details_from_ui_block(slot, &block);
+                    if let Err(err) = sender.send(Update::BlockDetails(block_details)).await {
+                        log::error!("Error sending block details: {:?}", err);
+                    }
+
                     if let Some(transactions) = block.transactions {
                         for encoded_transaction_with_status_meta in transactions {
                             let start_time = std::time::Instant::now();
@@ -299,6 +402,7 @@ fn task_processor(
                                 is_vote: false,
                                 slot,
                                 block_time: block.block_time,
+                                commitment: None,
                             }));
 
                             metrics
@@ -364,6 +468,8 @@ mod tests {
             block_config,
             block_sender,
             1,
+            ProgressTracker::new(328837890, Some(328837901), PROGRESS_REPORT_INTERVAL),
+            None,
             cancellation_token.clone(),
             Arc::new(MetricsCollection::new(vec![])),
         );
@@ -445,6 +551,8 @@ mod tests {
             block_config,
             block_sender,
             2,
+            ProgressTracker::new(latest_slot, None, PROGRESS_REPORT_INTERVAL),
+            None,
             cancellation_token.clone(),
             Arc::new(MetricsCollection::new(vec![])),
         );
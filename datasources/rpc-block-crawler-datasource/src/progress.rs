@@ -0,0 +1,148 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Callback invoked with the progress of a backfill.
+pub type ProgressCallback = Arc<dyn Fn(&BackfillProgress) + Send + Sync>;
+
+/// A snapshot of the progress of a crawl over a slot range.
+///
+/// The total, percentage and estimated time remaining are only known when the
+/// crawler has an `end_slot`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackfillProgress {
+    pub start_slot: u64,
+    pub end_slot: Option<u64>,
+    /// The highest slot fetched so far. Slots are fetched concurrently, so
+    /// lower slots may still be in flight.
+    pub highest_slot: u64,
+    /// The number of slots fetched so far, including skipped slots.
+    pub slots_processed: u64,
+    pub elapsed: Duration,
+}
+
+impl BackfillProgress {
+    pub fn total_slots(&self) -> Option<u64> {
+        self.end_slot
+            .map(|end_slot| end_slot.saturating_sub(self.start_slot) + 1)
+    }
+
+    /// The completed share of the slot range, between `0.0` and `100.0`.
+    pub fn percentage(&self) -> Option<f64> {
+        self.total_slots().map(|total_slots| {
+            (self.slots_processed as f64 / total_slots as f64 * 100.0).min(100.0)
+        })
+    }
+
+    /// The estimated time remaining, based on the average rate so far.
+    pub fn eta(&self) -> Option<Duration> {
+        let total_slots = self.total_slots()?;
+        if self.slots_processed == 0 {
+            return None;
+        }
+
+        let remaining_slots = total_slots.saturating_sub(self.slots_processed);
+        let seconds_per_slot = self.elapsed.as_secs_f64() / self.slots_processed as f64;
+
+        Some(Duration::from_secs_f64(
+            seconds_per_slot * remaining_slots as f64,
+        ))
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.total_slots()
+            .is_some_and(|total_slots| self.slots_processed >= total_slots)
+    }
+}
+
+/// Accumulates fetched slots and decides when progress should be reported.
+pub(crate) struct ProgressTracker {
+    progress: BackfillProgress,
+    started_at: Instant,
+    last_report: Option<Instant>,
+    report_interval: Duration,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(start_slot: u64, end_slot: Option<u64>, report_interval: Duration) -> Self {
+        Self {
+            progress: BackfillProgress {
+                start_slot,
+                end_slot,
+                highest_slot: start_slot,
+                slots_processed: 0,
+                elapsed: Duration::ZERO,
+            },
+            started_at: Instant::now(),
+            last_report: None,
+            report_interval,
+        }
+    }
+
+    /// Records a fetched slot and returns the progress if a report is due,
+    /// either because the report interval elapsed or the range is complete.
+    pub(crate) fn record(&mut self, slot: u64) -> Option<&BackfillProgress> {
+        self.progress.slots_processed += 1;
+        self.progress.highest_slot = self.progress.highest_slot.max(slot);
+        self.progress.elapsed = self.started_at.elapsed();
+
+        let due = self
+            .last_report
+            .is_none_or(|last_report| last_report.elapsed() >= self.report_interval);
+
+        if due || self.progress.is_complete() {
+            self.last_report = Some(Instant::now());
+            Some(&self.progress)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_percentage_and_eta() {
+        let progress = BackfillProgress {
+            start_slot: 100,
+            end_slot: Some(199),
+            highest_slot: 124,
+            slots_processed: 25,
+            elapsed: Duration::from_secs(10),
+        };
+
+        assert_eq!(progress.total_slots(), Some(100));
+        assert_eq!(progress.percentage(), Some(25.0));
+        assert_eq!(progress.eta(), Some(Duration::from_secs(30)));
+        assert!(!progress.is_complete());
+    }
+
+    #[test]
+    fn test_progress_without_end_slot() {
+        let progress = BackfillProgress {
+            start_slot: 100,
+            end_slot: None,
+            highest_slot: 124,
+            slots_processed: 25,
+            elapsed: Duration::from_secs(10),
+        };
+
+        assert_eq!(progress.percentage(), None);
+        assert_eq!(progress.eta(), None);
+    }
+
+    #[test]
+    fn test_tracker_reports_on_completion() {
+        let mut tracker = ProgressTracker::new(10, Some(12), Duration::from_secs(3600));
+
+        assert!(tracker.record(10).is_some());
+        assert!(tracker.record(12).is_none());
+
+        let progress = tracker.record(11).expect("range is complete");
+        assert_eq!(progress.slots_processed, 3);
+        assert_eq!(progress.highest_slot, 12);
+    }
+}
@@ -1,10 +1,10 @@
 use {
     async_trait::async_trait,
     carbon_core::{
-        datasource::{Datasource, TransactionUpdate, Update, UpdateType},
+        datasource::{Capabilities, Capability, Datasource, TransactionUpdate, Update},
         error::CarbonResult,
         metrics::MetricsCollection,
-        transformers::transaction_metadata_from_original_meta,
+        transformers::{block_details_from_ui_block, transaction_metadata_from_original_meta},
     },
     core::time::Duration,
     futures::StreamExt,
@@ -122,6 +122,12 @@ impl Datasource for RpcBlockSubscribe {
 
                                 if let Some(block) = tx_event.value.block {
                                     let block_start_time = std::time::Instant::now();
+
+                                    let block_details = block_details_from_ui_block(slot, &block);
+                                    if let Err(err) = sender_clone.send(Update::BlockDetails(block_details)).await {
+                                        log::error!("Error sending block details: {:?}", err);
+                                    }
+
                                     if let Some(transactions) = block.transactions {
                                         for encoded_transaction_with_status_meta in transactions {
                                             let start_time = std::time::Instant::now();
@@ -153,6 +159,7 @@ impl Datasource for RpcBlockSubscribe {
                                                 is_vote: false,
                                                 slot,
                                                 block_time: block.block_time,
+                                                commitment: None,
                                             }));
 
                                             metrics
@@ -202,7 +209,9 @@ impl Datasource for RpcBlockSubscribe {
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![UpdateType::Transaction]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new()
+            .with(Capability::Transactions)
+            .with(Capability::BlockDetails)
     }
 }
@@ -1,17 +1,17 @@
 use {
     async_trait::async_trait,
     carbon_core::{
-        datasource::{AccountUpdate, Datasource, Update, UpdateType},
+        datasource::{Capabilities, Capability, Datasource, Update},
         error::CarbonResult,
         metrics::MetricsCollection,
+        transformers::account_update_from_keyed_account,
     },
     futures::StreamExt,
-    solana_account::Account,
     solana_client::{
         nonblocking::pubsub_client::PubsubClient, rpc_config::RpcProgramAccountsConfig,
     },
     solana_pubkey::Pubkey,
-    std::{str::FromStr, sync::Arc, time::Duration},
+    std::{sync::Arc, time::Duration},
     tokio::sync::mpsc::Sender,
     tokio_util::sync::CancellationToken,
 };
@@ -26,7 +26,10 @@ pub struct Filters {
 }
 
 impl Filters {
-    pub const fn new(pubkey: Pubkey, program_subscribe_config: Option<RpcProgramAccountsConfig>) -> Self {
+    pub const fn new(
+        pubkey: Pubkey,
+        program_subscribe_config: Option<RpcProgramAccountsConfig>,
+    ) -> Self {
         Filters {
             pubkey,
             program_subscribe_config,
@@ -114,24 +117,15 @@ impl Datasource for RpcProgramSubscribe {
                         match event_result {
                             Some(acc_event) => {
                                 let start_time = std::time::Instant::now();
-                                let decoded_account: Account = match acc_event.value.account.decode() {
-                                    Some(account_data) => account_data,
-                                    None => {
-                                        log::error!("Error decoding account event");
+                                let account_update = match account_update_from_keyed_account(&acc_event.value, acc_event.context.slot) {
+                                    Ok(account_update) => account_update,
+                                    Err(error) => {
+                                        log::error!("Error converting account event: {:?}", error);
                                         continue;
                                     }
                                 };
 
-                                let Ok(account_pubkey) = Pubkey::from_str(&acc_event.value.pubkey) else {
-                                    log::error!("Error parsing account pubkey. Value: {}", &acc_event.value.pubkey);
-                                    continue;
-                                };
-
-                                let update = Update::Account(AccountUpdate {
-                                    pubkey: account_pubkey,
-                                    account: decoded_account,
-                                    slot: acc_event.context.slot,
-                                });
+                                let update = Update::Account(account_update);
 
                                 metrics
                                     .record_histogram(
@@ -165,7 +159,7 @@ impl Datasource for RpcProgramSubscribe {
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![UpdateType::AccountUpdate]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new().with(Capability::AccountUpdates)
     }
 }
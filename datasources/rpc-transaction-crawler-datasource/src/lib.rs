@@ -1,10 +1,10 @@
 use {
     async_trait::async_trait,
     carbon_core::{
-        datasource::{Datasource, TransactionUpdate, Update, UpdateType},
+        datasource::{join_tasks, Capabilities, Capability, Datasource, Update},
         error::CarbonResult,
         metrics::MetricsCollection,
-        transformers::transaction_metadata_from_original_meta,
+        transformers::transaction_update_from_confirmed_transaction,
     },
     futures::StreamExt,
     solana_client::{
@@ -14,9 +14,7 @@ use {
     solana_pubkey::Pubkey,
     solana_sdk::commitment_config::CommitmentConfig,
     solana_signature::Signature,
-    solana_transaction_status::{
-        EncodedConfirmedTransactionWithStatusMeta, UiLoadedAddresses, UiTransactionEncoding,
-    },
+    solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding},
     std::{collections::HashSet, str::FromStr, sync::Arc, time::Duration},
     tokio::{
         sync::mpsc::{self, Receiver, Sender},
@@ -132,19 +130,15 @@ impl Datasource for RpcTransactionCrawler {
             metrics.clone(),
         );
 
-        tokio::spawn(async move {
-            tokio::select! {
-                _ = signature_fetcher => {},
-                _ = transaction_fetcher => {},
-                _ = task_processor => {},
-            }
-        });
+        join_tasks(vec![signature_fetcher, transaction_fetcher, task_processor]).await;
 
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![UpdateType::Transaction]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new()
+            .with(Capability::Transactions)
+            .with(Capability::HistoricalRange)
     }
 }
 
@@ -337,53 +331,35 @@ fn task_processor(
                 }
                 Some((signature, fetched_transaction)) = transaction_receiver.recv() => {
                     let start = Instant::now();
-                    let transaction = fetched_transaction.transaction;
-
-                    let meta_original = if let Some(meta) = transaction.clone().meta {
-                        meta
-                    } else {
-                        log::warn!("Meta is malformed for transaction: {:?}", signature);
-                        continue;
-                    };
-
-                    if meta_original.status.is_err() {
+                    let failed = fetched_transaction
+                        .transaction
+                        .meta
+                        .as_ref()
+                        .is_some_and(|meta| meta.status.is_err());
+                    if failed {
                         continue;
                     }
 
-                    let Some(decoded_transaction) = transaction.transaction.decode() else {
-                        log::error!("Failed to decode transaction: {:?}", transaction);
-                        continue;
-                    };
+                    let transaction_update =
+                        match transaction_update_from_confirmed_transaction(signature, fetched_transaction) {
+                            Ok(transaction_update) => transaction_update,
+                            Err(error) => {
+                                log::error!("Failed to convert transaction {:?}: {:?}", signature, error);
+                                continue;
+                            }
+                        };
 
                     if let Some(accounts) = &filters.accounts {
                         let account_set: HashSet<Pubkey> = accounts.iter().cloned().collect();
 
-                        let static_accounts = decoded_transaction.message.static_account_keys();
-
-                        let loaded_addresses =
-                            meta_original
-                                .loaded_addresses
-                                .clone()
-                                .unwrap_or_else(|| UiLoadedAddresses {
-                                    writable: vec![],
-                                    readonly: vec![],
-                                });
+                        let static_accounts = transaction_update.transaction.message.static_account_keys();
+                        let loaded_addresses = &transaction_update.meta.loaded_addresses;
 
                         let all_accounts: HashSet<Pubkey> = static_accounts
                             .iter()
+                            .chain(loaded_addresses.writable.iter())
+                            .chain(loaded_addresses.readonly.iter())
                             .cloned()
-                            .chain(
-                                loaded_addresses
-                                    .writable
-                                    .iter()
-                                    .filter_map(|s| Pubkey::from_str(s).ok()),
-                            )
-                            .chain(
-                                loaded_addresses
-                                    .readonly
-                                    .iter()
-                                    .filter_map(|s| Pubkey::from_str(s).ok()),
-                            )
                             .collect();
 
                         if !all_accounts
@@ -394,19 +370,7 @@ fn task_processor(
                         }
                     }
 
-                    let Ok(meta_needed) = transaction_metadata_from_original_meta(meta_original) else {
-                        log::error!("Error getting metadata from transaction original meta.");
-                        continue;
-                    };
-
-                    let update = Update::Transaction(Box::new(TransactionUpdate {
-                        signature,
-                        transaction: decoded_transaction.clone(),
-                        meta: meta_needed,
-                        is_vote: false,
-                        slot: fetched_transaction.slot,
-                        block_time: fetched_transaction.block_time,
-                    }));
+                    let update = Update::Transaction(Box::new(transaction_update));
 
 
                     metrics
@@ -0,0 +1,106 @@
+use {
+    crate::YellowstoneGrpcGeyserClient,
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::{Capabilities, Datasource, Update},
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    std::sync::Arc,
+    tokio::sync::mpsc::Sender,
+    tokio_util::sync::CancellationToken,
+    yellowstone_grpc_proto::geyser::CommitmentLevel,
+};
+
+/// Subscribes to the same filters at `processed` and `finalized` commitment at
+/// once, delivering a fast provisional copy of each update, then its
+/// authoritative finalized copy.
+///
+/// Each update is tagged with the commitment it was received at, in the
+/// `commitment` of the account and transaction metadata passed to processors.
+/// Sinks can reconcile both copies with a `FinalityReconciler` from
+/// `carbon_core::finality`, upserting provisional rows and confirming or
+/// correcting them when their finalized copy arrives.
+///
+/// The datasource fails only if neither subscription can be connected to.
+pub struct YellowstoneGrpcDualCommitment {
+    pub processed: YellowstoneGrpcGeyserClient,
+    pub finalized: YellowstoneGrpcGeyserClient,
+}
+
+impl YellowstoneGrpcDualCommitment {
+    /// Subscribes with the endpoint and filters of `client`, ignoring its
+    /// commitment.
+    pub fn new(client: YellowstoneGrpcGeyserClient) -> Self {
+        let finalized = YellowstoneGrpcGeyserClient {
+            endpoint: client.endpoint.clone(),
+            commitment: Some(CommitmentLevel::Finalized),
+            account_filters: client.account_filters.clone(),
+            transaction_filters: client.transaction_filters.clone(),
+            transaction_status_filters: client.transaction_status_filters.clone(),
+            account_deletions_tracked: client.account_deletions_tracked.clone(),
+            from_slot: client.from_slot,
+        };
+        let processed = YellowstoneGrpcGeyserClient {
+            commitment: Some(CommitmentLevel::Processed),
+            ..client
+        };
+
+        Self {
+            processed,
+            finalized,
+        }
+    }
+}
+
+#[async_trait]
+impl Datasource for YellowstoneGrpcDualCommitment {
+    async fn consume(
+        &self,
+        sender: &Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (processed, finalized) = tokio::join!(
+            consume(
+                &self.processed,
+                sender,
+                cancellation_token.clone(),
+                metrics.clone()
+            ),
+            consume(&self.finalized, sender, cancellation_token, metrics),
+        );
+
+        if processed.is_err() && finalized.is_err() {
+            return Err(Error::FailedToConsumeDatasource(
+                "No Yellowstone commitment could be subscribed to".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.processed.capabilities()
+    }
+}
+
+/// Consumes one of the subscriptions, logging its error.
+async fn consume(
+    client: &YellowstoneGrpcGeyserClient,
+    sender: &Sender<Update>,
+    cancellation_token: CancellationToken,
+    metrics: Arc<MetricsCollection>,
+) -> CarbonResult<()> {
+    let result = client.consume(sender, cancellation_token, metrics).await;
+    if let Err(err) = &result {
+        log::error!(
+            "Failed to subscribe to Yellowstone endpoint {} at {:?} commitment: {:?}",
+            client.endpoint,
+            client.commitment,
+            err
+        );
+    }
+
+    result
+}
@@ -0,0 +1,60 @@
+//! Converts the account type filters of the decoders into Geyser account
+//! filters.
+
+use {
+    carbon_core::{account_filter::AccountTypeFilter, deserialize::CarbonDeserialize},
+    solana_pubkey::Pubkey,
+    yellowstone_grpc_proto::geyser::{
+        subscribe_request_filter_accounts_filter::Filter,
+        subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccounts,
+        SubscribeRequestFilterAccountsFilter, SubscribeRequestFilterAccountsFilterMemcmp,
+    },
+};
+
+/// Returns the Geyser filters matching the discriminator and data size of
+/// `filter`.
+pub fn geyser_filters(filter: &AccountTypeFilter) -> Vec<SubscribeRequestFilterAccountsFilter> {
+    let mut filters = Vec::new();
+
+    if !filter.discriminator.is_empty() {
+        filters.push(SubscribeRequestFilterAccountsFilter {
+            filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                offset: 0,
+                data: Some(Data::Bytes(filter.discriminator.clone())),
+            })),
+        });
+    }
+    if let Some(data_size) = filter.data_size {
+        filters.push(SubscribeRequestFilterAccountsFilter {
+            filter: Some(Filter::Datasize(data_size)),
+        });
+    }
+
+    filters
+}
+
+/// Returns the account filter subscribing to the accounts of type `T` owned
+/// by `program_id`, to be added to the `account_filters` of the client.
+///
+/// Only the discriminator of `T` is matched: build the filters with
+/// `geyser_filters` and `AccountTypeFilter::with_data_size` to also match
+/// its size.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut account_filters = HashMap::new();
+/// account_filters.insert(
+///     "bonding_curves".to_string(),
+///     program_accounts_filter::<BondingCurve>(&PUMPFUN_PROGRAM_ID),
+/// );
+/// ```
+pub fn program_accounts_filter<T: CarbonDeserialize>(
+    program_id: &Pubkey,
+) -> SubscribeRequestFilterAccounts {
+    SubscribeRequestFilterAccounts {
+        owner: vec![program_id.to_string()],
+        filters: geyser_filters(&AccountTypeFilter::of::<T>()),
+        ..Default::default()
+    }
+}
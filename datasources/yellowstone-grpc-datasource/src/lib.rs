@@ -2,7 +2,8 @@ use {
     async_trait::async_trait,
     carbon_core::{
         datasource::{
-            AccountDeletion, AccountUpdate, Datasource, TransactionUpdate, Update, UpdateType,
+            AccountDeletion, AccountUpdate, Capabilities, Capability, Datasource,
+            TransactionStatusUpdate, TransactionUpdate, Update,
         },
         error::CarbonResult,
         metrics::MetricsCollection,
@@ -22,7 +23,7 @@ use {
     tokio_util::sync::CancellationToken,
     yellowstone_grpc_client::GeyserGrpcClient,
     yellowstone_grpc_proto::{
-        convert_from::{create_tx_meta, create_tx_versioned},
+        convert_from::{create_tx_error, create_tx_meta, create_tx_versioned},
         geyser::{
             subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
             SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions,
@@ -32,20 +33,34 @@ use {
     },
 };
 
+pub mod config;
+pub mod dual;
+pub mod filters;
+pub mod quickstart;
+pub mod race;
+
+pub use {
+    config::GeyserEndpoint,
+    dual::YellowstoneGrpcDualCommitment,
+    filters::{geyser_filters, program_accounts_filter},
+    quickstart::{Quickstart, YellowstonePipeline},
+    race::YellowstoneGrpcRace,
+};
+
 #[derive(Debug)]
 pub struct YellowstoneGrpcGeyserClient {
-    pub endpoint: String,
-    pub x_token: Option<String>,
+    pub endpoint: GeyserEndpoint,
     pub commitment: Option<CommitmentLevel>,
     pub account_filters: HashMap<String, SubscribeRequestFilterAccounts>,
     pub transaction_filters: HashMap<String, SubscribeRequestFilterTransactions>,
+    pub transaction_status_filters: HashMap<String, SubscribeRequestFilterTransactions>,
     pub account_deletions_tracked: Arc<RwLock<HashSet<Pubkey>>>,
+    pub from_slot: Option<u64>,
 }
 
 impl YellowstoneGrpcGeyserClient {
-    pub const fn new(
-        endpoint: String,
-        x_token: Option<String>,
+    pub fn new(
+        endpoint: GeyserEndpoint,
         commitment: Option<CommitmentLevel>,
         account_filters: HashMap<String, SubscribeRequestFilterAccounts>,
         transaction_filters: HashMap<String, SubscribeRequestFilterTransactions>,
@@ -53,13 +68,47 @@ impl YellowstoneGrpcGeyserClient {
     ) -> Self {
         YellowstoneGrpcGeyserClient {
             endpoint,
-            x_token,
             commitment,
             account_filters,
             transaction_filters,
+            transaction_status_filters: HashMap::new(),
             account_deletions_tracked,
+            from_slot: None,
         }
     }
+
+    /// Subscribes to transaction statuses matching the given filters.
+    ///
+    /// Status updates only carry the signature, slot and error of each
+    /// transaction and are delivered as `Update::TransactionStatus`. Pass
+    /// empty `transaction_filters` to `new` to receive statuses without the
+    /// full transaction payloads.
+    pub fn with_transaction_status_filters(
+        mut self,
+        transaction_status_filters: HashMap<String, SubscribeRequestFilterTransactions>,
+    ) -> Self {
+        self.transaction_status_filters = transaction_status_filters;
+        self
+    }
+
+    /// Replays the updates from `slot` before streaming live ones, such as
+    /// the last checkpoint of a restarted pipeline.
+    ///
+    /// Endpoints only retain a limited number of recent slots, and reject
+    /// subscriptions starting before them.
+    pub fn with_from_slot(mut self, slot: u64) -> Self {
+        self.from_slot = Some(slot);
+        self
+    }
+}
+
+/// Converts a Yellowstone commitment level to the one updates are tagged with.
+fn commitment_level(commitment: CommitmentLevel) -> solana_sdk::commitment_config::CommitmentLevel {
+    match commitment {
+        CommitmentLevel::Processed => solana_sdk::commitment_config::CommitmentLevel::Processed,
+        CommitmentLevel::Confirmed => solana_sdk::commitment_config::CommitmentLevel::Confirmed,
+        CommitmentLevel::Finalized => solana_sdk::commitment_config::CommitmentLevel::Finalized,
+    }
 }
 
 #[async_trait]
@@ -71,12 +120,15 @@ impl Datasource for YellowstoneGrpcGeyserClient {
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()> {
         let sender = sender.clone();
-        let endpoint = self.endpoint.clone();
-        let x_token = self.x_token.clone();
+        let endpoint = self.endpoint.url().to_string();
+        let x_token = self.endpoint.x_token().map(str::to_string);
         let commitment = self.commitment;
+        let update_commitment = commitment.map(commitment_level);
         let account_filters = self.account_filters.clone();
         let transaction_filters = self.transaction_filters.clone();
+        let transaction_status_filters = self.transaction_status_filters.clone();
         let account_deletions_tracked = self.account_deletions_tracked.clone();
+        let from_slot = self.from_slot;
 
         let mut geyser_client = GeyserGrpcClient::build_from_shared(endpoint)
             .map_err(|err| carbon_core::error::Error::FailedToConsumeDatasource(err.to_string()))?
@@ -90,23 +142,24 @@ impl Datasource for YellowstoneGrpcGeyserClient {
             .await
             .map_err(|err| carbon_core::error::Error::FailedToConsumeDatasource(err.to_string()))?;
 
-        tokio::spawn(async move {
+        async move {
             let subscribe_request = SubscribeRequest {
                 slots: HashMap::new(),
                 accounts: account_filters,
                 transactions: transaction_filters,
-                transactions_status: HashMap::new(),
+                transactions_status: transaction_status_filters,
                 entry: HashMap::new(),
                 blocks: HashMap::new(),
                 blocks_meta: HashMap::new(),
                 commitment: commitment.map(|x| x as i32),
                 accounts_data_slice: vec![],
                 ping: None,
-                from_slot: None,
+                from_slot,
             };
 
             loop {
                 tokio::select! {
+                    biased;
                     _ = cancellation_token.cancelled() => {
                         log::info!("Cancelling Yellowstone gRPC subscription.");
                         break;
@@ -118,11 +171,11 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                 // 将ping机制移至单独的异步任务中
                                 let ping_tx = subscribe_tx.clone();
                                 let ping_cancellation = cancellation_token.clone();
-                                
+
                                 tokio::spawn(async move {
                                     let mut timer = interval(AsyncDuration::from_mins(5));
                                     let mut id = 0;
-                                    
+
                                     loop {
                                         tokio::select! {
                                             // _ = ping_cancellation.cancelled() => {
@@ -145,7 +198,15 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                     }
                                 });
 
-                                while let Some(message) = stream.next().await {
+                                loop {
+                                    let message = tokio::select! {
+                                        _ = cancellation_token.cancelled() => break,
+                                        message = stream.next() => message,
+                                    };
+                                    let Some(message) = message else {
+                                        break;
+                                    };
+
                                     match message {
                                         Ok(msg) => match msg.update_oneof {
                                             Some(UpdateOneof::Account(account_update)) => {
@@ -198,6 +259,8 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                             pubkey: account_pubkey,
                                                             account,
                                                             slot: account_update.slot,
+                                                            write_version: Some(account_info.write_version),
+                                                            commitment: update_commitment,
                                                         });
 
                                                         if let Err(e) = sender.try_send(update) {
@@ -264,6 +327,7 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                         is_vote: transaction_info.is_vote,
                                                         slot: transaction_update.slot,
                                                         block_time: None,
+                                                        commitment: update_commitment,
                                                     }));
                                                     if let Err(e) = sender.try_send(update) {
                                                         log::error!("Failed to send transaction update with signature {:?} at slot {}: {:?}", signature, transaction_update.slot, e);
@@ -285,6 +349,36 @@ impl Datasource for YellowstoneGrpcGeyserClient {
 
                                             }
 
+                                            Some(UpdateOneof::TransactionStatus(transaction_status)) => {
+                                                let Ok(signature) =
+                                                    Signature::try_from(transaction_status.signature)
+                                                else {
+                                                    continue;
+                                                };
+                                                let err = match create_tx_error(transaction_status.err.as_ref()) {
+                                                    Ok(err) => err,
+                                                    Err(err) => {
+                                                        log::error!(
+                                                            "Failed to create transaction error: {:?}",
+                                                            err
+                                                        );
+                                                        continue;
+                                                    }
+                                                };
+                                                let update = Update::TransactionStatus(TransactionStatusUpdate {
+                                                    signature,
+                                                    slot: transaction_status.slot,
+                                                    is_vote: transaction_status.is_vote,
+                                                    err,
+                                                });
+                                                if let Err(e) = sender.try_send(update) {
+                                                    log::error!("Failed to send transaction status update with signature {:?} at slot {}: {:?}", signature, transaction_status.slot, e);
+                                                    continue;
+                                                }
+
+                                                metrics.increment_counter("yellowstone_grpc_transaction_status_updates_received", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                                            }
+
                                             Some(UpdateOneof::Ping(_)) => {
                                                 _ = subscribe_tx
                                                     .send(SubscribeRequest {
@@ -310,16 +404,186 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                     }
                 }
             }
-        });
+        }
+        .await;
 
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<UpdateType> {
-        vec![
-            UpdateType::AccountUpdate,
-            UpdateType::Transaction,
-            UpdateType::AccountDeletion,
-        ]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new()
+            .with(Capability::AccountUpdates)
+            .with(Capability::Transactions)
+            .with(Capability::AccountDeletions)
+            .with(Capability::TransactionStatuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_test_utils::geyser::{decode_frames, encode_frames, MockGeyserServer},
+        tokio::sync::mpsc,
+        yellowstone_grpc_proto::prelude::{
+            Message, MessageHeader, SubscribeUpdate, SubscribeUpdateAccount,
+            SubscribeUpdateAccountInfo, SubscribeUpdateBlockMeta, SubscribeUpdateSlot,
+            SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo,
+            SubscribeUpdateTransactionStatus, Transaction, TransactionStatusMeta,
+        },
+    };
+
+    fn frame(update_oneof: UpdateOneof) -> SubscribeUpdate {
+        SubscribeUpdate {
+            update_oneof: Some(update_oneof),
+            ..Default::default()
+        }
+    }
+
+    async fn recv(receiver: &mut mpsc::Receiver<Update>) -> Update {
+        tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+            .await
+            .expect("update should be received")
+            .expect("channel should be open")
+    }
+
+    #[tokio::test]
+    async fn test_consume_replayed_frames() {
+        // Arrange
+        let account_pubkey = Pubkey::new_unique();
+        let account_owner = Pubkey::new_unique();
+        let deleted_pubkey = Pubkey::new_unique();
+        let signature = Signature::from([1; 64]);
+        let status_signature = Signature::from([2; 64]);
+
+        let frames = vec![
+            frame(UpdateOneof::Slot(SubscribeUpdateSlot {
+                slot: 100,
+                ..Default::default()
+            })),
+            frame(UpdateOneof::Account(SubscribeUpdateAccount {
+                account: Some(SubscribeUpdateAccountInfo {
+                    pubkey: account_pubkey.to_bytes().to_vec(),
+                    lamports: 10,
+                    owner: account_owner.to_bytes().to_vec(),
+                    data: vec![1, 2, 3],
+                    ..Default::default()
+                }),
+                slot: 100,
+                ..Default::default()
+            })),
+            frame(UpdateOneof::Account(SubscribeUpdateAccount {
+                account: Some(SubscribeUpdateAccountInfo {
+                    pubkey: deleted_pubkey.to_bytes().to_vec(),
+                    lamports: 0,
+                    owner: solana_program::system_program::ID.to_bytes().to_vec(),
+                    data: vec![],
+                    ..Default::default()
+                }),
+                slot: 101,
+                ..Default::default()
+            })),
+            frame(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+                transaction: Some(SubscribeUpdateTransactionInfo {
+                    signature: signature.as_ref().to_vec(),
+                    is_vote: false,
+                    transaction: Some(Transaction {
+                        signatures: vec![signature.as_ref().to_vec()],
+                        message: Some(Message {
+                            header: Some(MessageHeader {
+                                num_required_signatures: 1,
+                                num_readonly_signed_accounts: 0,
+                                num_readonly_unsigned_accounts: 0,
+                            }),
+                            account_keys: vec![account_pubkey.to_bytes().to_vec()],
+                            recent_blockhash: vec![0; 32],
+                            ..Default::default()
+                        }),
+                    }),
+                    meta: Some(TransactionStatusMeta::default()),
+                    ..Default::default()
+                }),
+                slot: 102,
+            })),
+            frame(UpdateOneof::TransactionStatus(
+                SubscribeUpdateTransactionStatus {
+                    slot: 102,
+                    signature: status_signature.as_ref().to_vec(),
+                    ..Default::default()
+                },
+            )),
+            frame(UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+                slot: 102,
+                ..Default::default()
+            })),
+        ];
+        let frames = decode_frames(&encode_frames(&frames)).unwrap();
+
+        let server = MockGeyserServer::new(frames).start().await.unwrap();
+        let datasource = YellowstoneGrpcGeyserClient::new(
+            GeyserEndpoint::new(server.endpoint(), None).unwrap(),
+            None,
+            HashMap::new(),
+            HashMap::new(),
+            Arc::new(RwLock::new(HashSet::from([deleted_pubkey]))),
+        );
+        let (sender, mut receiver) = mpsc::channel(10);
+        let cancellation_token = CancellationToken::new();
+
+        // Act
+        let consume = tokio::spawn({
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                datasource
+                    .consume(
+                        &sender,
+                        cancellation_token,
+                        Arc::new(MetricsCollection::new(vec![])),
+                    )
+                    .await
+            }
+        });
+
+        // Assert
+        let Update::Account(account_update) = recv(&mut receiver).await else {
+            panic!("expected an account update");
+        };
+        assert_eq!(account_update.pubkey, account_pubkey);
+        assert_eq!(account_update.account.owner, account_owner);
+        assert_eq!(account_update.account.lamports, 10);
+        assert_eq!(account_update.account.data, vec![1, 2, 3]);
+        assert_eq!(account_update.slot, 100);
+
+        let Update::AccountDeletion(account_deletion) = recv(&mut receiver).await else {
+            panic!("expected an account deletion");
+        };
+        assert_eq!(account_deletion.pubkey, deleted_pubkey);
+        assert_eq!(account_deletion.slot, 101);
+
+        let Update::Transaction(transaction_update) = recv(&mut receiver).await else {
+            panic!("expected a transaction update");
+        };
+        assert_eq!(transaction_update.signature, signature);
+        assert_eq!(
+            transaction_update.transaction.message.static_account_keys(),
+            &[account_pubkey]
+        );
+        assert_eq!(transaction_update.slot, 102);
+
+        let Update::TransactionStatus(transaction_status) = recv(&mut receiver).await else {
+            panic!("expected a transaction status update");
+        };
+        assert_eq!(transaction_status.signature, status_signature);
+        assert_eq!(transaction_status.slot, 102);
+        assert!(transaction_status.err.is_none());
+
+        // Slot and block meta frames are not forwarded.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(receiver.try_recv().is_err());
+
+        // The datasource runs until it is cancelled.
+        assert!(!consume.is_finished());
+        cancellation_token.cancel();
+        consume.await.unwrap().unwrap();
     }
 }
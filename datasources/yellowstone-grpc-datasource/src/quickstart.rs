@@ -0,0 +1,398 @@
+//! A zero-config way to start indexing programs from a Yellowstone gRPC
+//! endpoint.
+//!
+//! `Pipeline::from_yellowstone` returns a `Quickstart`, which only needs the
+//! decoders and processors of the programs to index. It derives the
+//! subscription filters from the program ID of each decoder, logs the
+//! pipeline metrics with `LogMetrics`, and checkpoints the pipeline's
+//! `CheckpointAdvanced` slot to a file, from which a restarted pipeline
+//! resumes. `start` spawns the pipeline and returns its handle.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use carbon_yellowstone_grpc_datasource::quickstart::YellowstonePipeline;
+//!
+//! let handle = Pipeline::from_yellowstone("https://grpc.example.com:443", Some(token))?
+//!     .with_decoder(PumpfunDecoder, PumpfunProcessor)
+//!     .start()?;
+//!
+//! handle.join().await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - Only decoders returning an `InstructionDecoder::program_id` can derive
+//!   filters: add the others with `configure`, along with a datasource of
+//!   their own.
+//! - `configure` gives access to the `PipelineBuilder` for anything the
+//!   quickstart does not cover, such as account pipes or a restart policy.
+//! - The checkpoint is written at most once per second and when the pipeline
+//!   stops. The checkpointed slot is replayed on restart, so processors may
+//!   see some updates twice.
+//! - The checkpoint only advances over successfully processed updates, and is
+//!   held at the slot of the first failed update, so the failed updates are
+//!   replayed on restart rather than skipped.
+//! - The checkpoint only advances once the processors flushed, so the updates
+//!   buffered by sinks such as the CSV, DuckDB or SQLite ones are written
+//!   before their slot is checkpointed.
+//! - Endpoints only retain a limited number of recent slots: delete the
+//!   checkpoint file to start from the live slot after a long downtime.
+
+use {
+    crate::{config::GeyserEndpoint, YellowstoneGrpcGeyserClient},
+    carbon_core::{
+        error::{CarbonResult, Error},
+        event_bus::{EventBus, PipelineEvent},
+        instruction::{InstructionDecoder, InstructionProcessorInputType},
+        pipeline::{Pipeline, PipelineBuilder},
+        processor::Processor,
+        supervision::PipelineHandle,
+    },
+    carbon_log_metrics::LogMetrics,
+    solana_pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        sync::Arc,
+        time::Duration,
+    },
+    tokio::sync::broadcast::{self, error::RecvError},
+    yellowstone_grpc_proto::geyser::{CommitmentLevel, SubscribeRequestFilterTransactions},
+};
+
+/// The file the quickstart checkpoints the pipeline to.
+pub const DEFAULT_CHECKPOINT_PATH: &str = "carbon.checkpoint";
+
+/// How often the checkpoint is written at most.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Adds `Pipeline::from_yellowstone`.
+pub trait YellowstonePipeline {
+    /// Starts a `Quickstart` indexing from the Yellowstone gRPC `endpoint`,
+    /// authenticated with `x_token` if set.
+    fn from_yellowstone(
+        endpoint: impl Into<String>,
+        x_token: Option<String>,
+    ) -> CarbonResult<Quickstart>;
+}
+
+impl YellowstonePipeline for Pipeline {
+    fn from_yellowstone(
+        endpoint: impl Into<String>,
+        x_token: Option<String>,
+    ) -> CarbonResult<Quickstart> {
+        Ok(Quickstart::new(GeyserEndpoint::new(endpoint, x_token)?))
+    }
+}
+
+/// A pipeline indexing the transactions of the programs of its decoders from
+/// a Yellowstone gRPC endpoint, with metrics and checkpointing set up.
+pub struct Quickstart {
+    endpoint: GeyserEndpoint,
+    commitment: CommitmentLevel,
+    program_ids: Vec<Pubkey>,
+    checkpoint_path: Option<PathBuf>,
+    builder: PipelineBuilder,
+}
+
+impl Quickstart {
+    /// Creates a quickstart subscribing to `endpoint` at `confirmed`
+    /// commitment, logging its metrics and checkpointing to
+    /// `carbon.checkpoint`.
+    pub fn new(endpoint: GeyserEndpoint) -> Self {
+        Self {
+            endpoint,
+            commitment: CommitmentLevel::Confirmed,
+            program_ids: Vec::new(),
+            checkpoint_path: Some(PathBuf::from(DEFAULT_CHECKPOINT_PATH)),
+            builder: Pipeline::builder().metrics(Arc::new(LogMetrics::new())),
+        }
+    }
+
+    /// Adds an instruction pipe, and subscribes to the transactions of the
+    /// program of `decoder`.
+    pub fn with_decoder<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+    ) -> Self {
+        match decoder.program_id() {
+            Some(program_id) if !self.program_ids.contains(&program_id) => {
+                self.program_ids.push(program_id)
+            }
+            Some(_) => {}
+            None => log::warn!(
+                "{} has no program ID, its transactions are only received when another decoder's \
+                 program is invoked",
+                std::any::type_name_of_val(&decoder)
+            ),
+        }
+        self.builder = self.builder.instruction(decoder, processor);
+        self
+    }
+
+    /// Sets the commitment of the subscription. Defaults to `confirmed`.
+    pub fn commitment(mut self, commitment: CommitmentLevel) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    /// Sets the file the checkpoint is read from and written to.
+    pub fn checkpoint_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint_path = Some(path.into());
+        self
+    }
+
+    /// Disables checkpointing: the pipeline always starts from the live slot.
+    pub fn without_checkpoint(mut self) -> Self {
+        self.checkpoint_path = None;
+        self
+    }
+
+    /// Configures the underlying `PipelineBuilder`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// quickstart.configure(|builder| builder.restart_policy(RestartPolicy::never()))
+    /// ```
+    pub fn configure(mut self, configure: impl FnOnce(PipelineBuilder) -> PipelineBuilder) -> Self {
+        self.builder = configure(self.builder);
+        self
+    }
+
+    /// Builds the pipeline, returning it with the bus its checkpoints are
+    /// emitted on.
+    fn build(self) -> CarbonResult<(Pipeline, EventBus, Option<PathBuf>)> {
+        if self.program_ids.is_empty() {
+            return Err(Error::Custom(
+                "No decoder with a program ID to subscribe to".to_string(),
+            ));
+        }
+
+        let transaction_filter = SubscribeRequestFilterTransactions {
+            vote: Some(false),
+            failed: Some(false),
+            account_include: self.program_ids.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+        let mut datasource = YellowstoneGrpcGeyserClient::new(
+            self.endpoint,
+            Some(self.commitment),
+            HashMap::new(),
+            HashMap::from([("carbon_quickstart".to_string(), transaction_filter)]),
+            Default::default(),
+        );
+        if let Some(slot) = self.checkpoint_path.as_deref().and_then(read_checkpoint) {
+            log::info!("Resuming from checkpointed slot {}", slot);
+            datasource = datasource.with_from_slot(slot);
+        }
+
+        let events = EventBus::default();
+        let pipeline = self
+            .builder
+            .datasource(datasource)
+            .event_bus(events.clone())
+            .build()?;
+
+        Ok((pipeline, events, self.checkpoint_path))
+    }
+
+    /// Spawns the pipeline, and the task writing its checkpoints.
+    ///
+    /// # Notes
+    ///
+    /// - This method must be called within a Tokio runtime.
+    pub fn start(self) -> CarbonResult<PipelineHandle> {
+        let (pipeline, events, checkpoint_path) = self.build()?;
+
+        if let Some(path) = checkpoint_path {
+            tokio::spawn(write_checkpoints(events.subscribe(), path));
+        }
+
+        Ok(pipeline.spawn())
+    }
+}
+
+/// Reads the checkpointed slot of `path`, if any.
+fn read_checkpoint(path: &Path) -> Option<u64> {
+    let content = std::fs::read_to_string(path).ok()?;
+
+    match content.trim().parse() {
+        Ok(slot) => Some(slot),
+        Err(err) => {
+            log::warn!("Ignoring invalid checkpoint {}: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+/// Writes the checkpoints received on `receiver` to `path` until the pipeline
+/// stops.
+async fn write_checkpoints(mut receiver: broadcast::Receiver<PipelineEvent>, path: PathBuf) {
+    let mut interval = tokio::time::interval(CHECKPOINT_INTERVAL);
+    let mut checkpoint = None;
+    let mut written = None;
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(PipelineEvent::CheckpointAdvanced { slot }) => checkpoint = Some(slot),
+                Ok(PipelineEvent::Stopped) | Err(RecvError::Closed) => {
+                    write_checkpoint(&path, checkpoint, &mut written).await;
+                    break;
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+            },
+            _ = interval.tick() => write_checkpoint(&path, checkpoint, &mut written).await,
+        }
+    }
+}
+
+/// Writes `checkpoint` to `path` unless it was already written.
+async fn write_checkpoint(path: &Path, checkpoint: Option<u64>, written: &mut Option<u64>) {
+    let Some(slot) = checkpoint.filter(|slot| *written != Some(*slot)) else {
+        return;
+    };
+
+    match tokio::fs::write(path, slot.to_string()).await {
+        Ok(()) => *written = Some(slot),
+        Err(err) => log::error!("Failed to write checkpoint {}: {}", path.display(), err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        async_trait::async_trait,
+        carbon_core::{
+            account::{AccountDecoder, AccountProcessorInputType, DecodedAccount},
+            datasource::{AccountUpdate, Capabilities, Capability, Datasource, Update},
+            metrics::MetricsCollection,
+        },
+        solana_account::Account,
+        tokio::sync::mpsc::Sender,
+        tokio_util::sync::CancellationToken,
+    };
+
+    /// Sends an account update at each of its slots, then finishes.
+    struct AccountSlots(Vec<u64>);
+
+    #[async_trait]
+    impl Datasource for AccountSlots {
+        async fn consume(
+            &self,
+            sender: &Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in &self.0 {
+                let update = Update::Account(AccountUpdate {
+                    pubkey: Pubkey::new_unique(),
+                    account: Account::default(),
+                    slot: *slot,
+                    write_version: None,
+                    commitment: None,
+                });
+                let _ = sender.send(update).await;
+            }
+            let _ = sender.send(Update::DatasourceFinished).await;
+
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Capabilities {
+            Capabilities::new().with(Capability::AccountUpdates)
+        }
+
+        fn is_bounded(&self) -> bool {
+            true
+        }
+    }
+
+    struct AnyAccount;
+
+    impl AccountDecoder<'_> for AnyAccount {
+        type AccountType = ();
+
+        fn decode_account(&self, account: &Account) -> Option<DecodedAccount<()>> {
+            Some(DecodedAccount {
+                lamports: account.lamports,
+                data: (),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+        }
+    }
+
+    /// Fails to process the updates of one slot.
+    struct FailingAt(u64);
+
+    #[async_trait]
+    impl Processor for FailingAt {
+        type InputType = AccountProcessorInputType<()>;
+
+        async fn process(
+            &mut self,
+            (metadata, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            if metadata.slot == self.0 {
+                return Err(Error::Custom("sink unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_round_trips_through_its_file() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!(
+            "carbon_quickstart_{}.checkpoint",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let mut written = None;
+
+        // Act
+        let missing = read_checkpoint(&path);
+        write_checkpoint(&path, Some(42), &mut written).await;
+        let checkpoint = read_checkpoint(&path);
+        let _ = std::fs::remove_file(&path);
+
+        // Assert
+        assert_eq!(missing, None);
+        assert_eq!(written, Some(42));
+        assert_eq!(checkpoint, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_is_not_written_past_a_failed_update() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!(
+            "carbon_quickstart_failed_{}.checkpoint",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let events = EventBus::default();
+        let mut pipeline = Pipeline::builder()
+            .datasource(AccountSlots(vec![1, 2, 3, 4]))
+            .account(AnyAccount, FailingAt(2))
+            .event_bus(events.clone())
+            .build()
+            .unwrap();
+        let writer = tokio::spawn(write_checkpoints(events.subscribe(), path.clone()));
+
+        // Act
+        pipeline.run().await.unwrap();
+        writer.await.unwrap();
+        let checkpoint = read_checkpoint(&path);
+        let _ = std::fs::remove_file(&path);
+
+        // Assert
+        assert_eq!(checkpoint, Some(2));
+    }
+}
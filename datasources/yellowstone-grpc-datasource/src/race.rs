@@ -0,0 +1,245 @@
+use {
+    crate::YellowstoneGrpcGeyserClient,
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::{Capabilities, Datasource, Update},
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    futures::future::join_all,
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::{
+        collections::{hash_map::DefaultHasher, HashSet, VecDeque},
+        hash::{Hash, Hasher},
+        sync::Arc,
+    },
+    tokio::sync::mpsc::{self, Sender},
+    tokio_util::sync::CancellationToken,
+};
+
+const DEDUP_CAPACITY: usize = 100_000;
+const CHANNEL_BUFFER_SIZE: usize = 10_000;
+
+/// Subscribes to several Yellowstone gRPC endpoints at once and forwards
+/// whichever copy of each update arrives first.
+///
+/// Every endpoint receives the same subscription. Updates already forwarded
+/// from another endpoint are dropped, based on the transaction signature for
+/// transactions and on the public key, slot and state for accounts. This
+/// lowers latency and keeps the stream alive if one of the endpoints stalls or
+/// disconnects.
+///
+/// The datasource fails only if none of the endpoints can be connected to.
+pub struct YellowstoneGrpcRace {
+    pub clients: Vec<YellowstoneGrpcGeyserClient>,
+    pub dedup_capacity: usize,
+}
+
+impl YellowstoneGrpcRace {
+    pub fn new(clients: Vec<YellowstoneGrpcGeyserClient>) -> Self {
+        Self {
+            clients,
+            dedup_capacity: DEDUP_CAPACITY,
+        }
+    }
+
+    /// Sets how many recent updates are remembered for deduplication.
+    pub fn with_dedup_capacity(mut self, dedup_capacity: usize) -> Self {
+        self.dedup_capacity = dedup_capacity;
+        self
+    }
+}
+
+#[async_trait]
+impl Datasource for YellowstoneGrpcRace {
+    async fn consume(
+        &self,
+        sender: &Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (race_sender, mut race_receiver) = mpsc::channel::<Update>(CHANNEL_BUFFER_SIZE);
+
+        let clients = join_all(self.clients.iter().map(|client| {
+            let race_sender = race_sender.clone();
+            let cancellation_token = cancellation_token.clone();
+            let metrics = metrics.clone();
+            async move {
+                let result = client
+                    .consume(&race_sender, cancellation_token, metrics)
+                    .await;
+                if let Err(err) = &result {
+                    log::error!(
+                        "Failed to connect to Yellowstone endpoint {}: {:?}",
+                        client.endpoint,
+                        err
+                    );
+                }
+                result
+            }
+        }));
+        drop(race_sender);
+
+        let mut recent_updates = RecentUpdates::new(self.dedup_capacity);
+        let forward = async {
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => {
+                        log::info!("Cancelling Yellowstone gRPC race.");
+                        break;
+                    }
+                    update = race_receiver.recv() => {
+                        let Some(update) = update else {
+                            break;
+                        };
+
+                        // Endpoints are forwarded as one datasource, so one of
+                        // them finishing does not finish the race.
+                        if matches!(update, Update::DatasourceFinished) {
+                            continue;
+                        }
+
+                        if !recent_updates.insert(UpdateKey::from(&update)) {
+                            metrics
+                                .increment_counter("yellowstone_race_duplicates_dropped", 1)
+                                .await
+                                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                            continue;
+                        }
+
+                        metrics
+                            .increment_counter("yellowstone_race_updates_forwarded", 1)
+                            .await
+                            .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+
+                        if let Err(err) = sender.send(update).await {
+                            log::error!("Failed to forward update: {:?}", err);
+                            break;
+                        }
+                    }
+                }
+            }
+        };
+
+        let (results, ()) = tokio::join!(clients, forward);
+        if results.iter().all(Result::is_err) {
+            return Err(Error::FailedToConsumeDatasource(
+                "No Yellowstone endpoint could be connected to".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.clients
+            .first()
+            .map(|client| client.capabilities())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum UpdateKey {
+    Account {
+        pubkey: Pubkey,
+        slot: u64,
+        state_hash: u64,
+    },
+    Transaction(Signature),
+    AccountDeletion {
+        pubkey: Pubkey,
+        slot: u64,
+    },
+    TransactionStatus(Signature),
+    BlockDetails(u64),
+    DatasourceFinished,
+}
+
+impl From<&Update> for UpdateKey {
+    fn from(update: &Update) -> Self {
+        match update {
+            Update::Account(account_update) => {
+                let mut hasher = DefaultHasher::new();
+                account_update.account.data.hash(&mut hasher);
+                account_update.account.lamports.hash(&mut hasher);
+                account_update.account.owner.hash(&mut hasher);
+
+                UpdateKey::Account {
+                    pubkey: account_update.pubkey,
+                    slot: account_update.slot,
+                    state_hash: hasher.finish(),
+                }
+            }
+            Update::Transaction(transaction_update) => {
+                UpdateKey::Transaction(transaction_update.signature)
+            }
+            Update::AccountDeletion(account_deletion) => UpdateKey::AccountDeletion {
+                pubkey: account_deletion.pubkey,
+                slot: account_deletion.slot,
+            },
+            Update::TransactionStatus(transaction_status) => {
+                UpdateKey::TransactionStatus(transaction_status.signature)
+            }
+            Update::BlockDetails(block_details) => UpdateKey::BlockDetails(block_details.slot),
+            Update::DatasourceFinished => UpdateKey::DatasourceFinished,
+        }
+    }
+}
+
+/// A bounded set of the most recently forwarded updates.
+struct RecentUpdates {
+    keys: HashSet<UpdateKey>,
+    order: VecDeque<UpdateKey>,
+    capacity: usize,
+}
+
+impl RecentUpdates {
+    fn new(capacity: usize) -> Self {
+        Self {
+            keys: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns `false` if the key was already seen.
+    fn insert(&mut self, key: UpdateKey) -> bool {
+        if self.keys.contains(&key) {
+            return false;
+        }
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+
+        self.keys.insert(key.clone());
+        self.order.push_back(key);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_updates_drops_duplicates() {
+        let mut recent_updates = RecentUpdates::new(2);
+        let first = UpdateKey::Transaction(Signature::new_unique());
+        let second = UpdateKey::Transaction(Signature::new_unique());
+        let third = UpdateKey::Transaction(Signature::new_unique());
+
+        assert!(recent_updates.insert(first.clone()));
+        assert!(!recent_updates.insert(first.clone()));
+        assert!(recent_updates.insert(second));
+        assert!(recent_updates.insert(third));
+
+        // The oldest key was evicted once the capacity was reached.
+        assert!(recent_updates.insert(first));
+        assert_eq!(recent_updates.keys.len(), 2);
+    }
+}
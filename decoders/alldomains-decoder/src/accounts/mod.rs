@@ -0,0 +1,87 @@
+use {
+    super::AllDomainsDecoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod name_record_header;
+
+pub enum AllDomainsAccount {
+    NameRecordHeader(name_record_header::NameRecordHeader),
+}
+
+impl AccountDecoder<'_> for AllDomainsDecoder {
+    type AccountType = AllDomainsAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            name_record_header::NameRecordHeader::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: AllDomainsAccount::NameRecordHeader(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_name_record_account() {
+        // Arrange
+        let expected_account = name_record_header::NameRecordHeader {
+            parent_name: solana_pubkey::Pubkey::from_str_const(
+                "3pSeaEVTcKLkXPCoZHDpHQN1pWMk4SPhy7Tvp8BCThBx",
+            ),
+            owner: solana_pubkey::Pubkey::from_str_const(
+                "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA",
+            ),
+            nclass: solana_pubkey::Pubkey::default(),
+            expires_at: 1761955200,
+            created_at: 1730419200,
+            non_transferable: false,
+            data: b"https://alldomains.id".to_vec(),
+        };
+
+        // Act
+        let decoder = AllDomainsDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/name_record_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            AllDomainsAccount::NameRecordHeader(name_record) => {
+                assert_eq!(name_record, expected_account);
+            }
+        }
+    }
+
+    #[test]
+    fn test_name_record_with_another_discriminator_is_not_decoded() {
+        // Arrange
+        let mut account =
+            carbon_test_utils::read_account("tests/fixtures/name_record_account.json")
+                .expect("read fixture");
+        account.data[..8].copy_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // Act
+        let decoded_account = AllDomainsDecoder.decode_account(&account);
+
+        // Assert
+        assert!(decoded_account.is_none());
+    }
+}
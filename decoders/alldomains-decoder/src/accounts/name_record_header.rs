@@ -0,0 +1,53 @@
+use carbon_core::{
+    borsh::{self, BorshDeserialize},
+    CarbonDeserialize,
+};
+
+/// Size of the header that prefixes every AllDomains name record, including
+/// its discriminator and padding. The record data follows it.
+pub const NAME_RECORD_HEADER_LEN: usize = 200;
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0x4448582c0fa767f3",
+    deserialize_with = "deserialize_name_record_header"
+)]
+pub struct NameRecordHeader {
+    pub parent_name: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub nclass: solana_pubkey::Pubkey,
+    pub expires_at: u64,
+    pub created_at: u64,
+    pub non_transferable: bool,
+    pub data: Vec<u8>,
+}
+
+// The header is padded to `NAME_RECORD_HEADER_LEN` bytes, and followed by the
+// record data, which spans the rest of the account. `data` follows the 8 byte
+// discriminator.
+fn deserialize_name_record_header(data: &[u8]) -> Option<NameRecordHeader> {
+    if data.len() < NAME_RECORD_HEADER_LEN - 8 {
+        return None;
+    }
+
+    let mut reader = data;
+    let parent_name = solana_pubkey::Pubkey::deserialize(&mut reader).ok()?;
+    let owner = solana_pubkey::Pubkey::deserialize(&mut reader).ok()?;
+    let nclass = solana_pubkey::Pubkey::deserialize(&mut reader).ok()?;
+    let expires_at = u64::deserialize(&mut reader).ok()?;
+    let created_at = u64::deserialize(&mut reader).ok()?;
+    let non_transferable = bool::deserialize(&mut reader).ok()?;
+    let _padding = <[u8; 79]>::deserialize(&mut reader).ok()?;
+
+    Some(NameRecordHeader {
+        parent_name,
+        owner,
+        nclass,
+        expires_at,
+        created_at,
+        non_transferable,
+        data: reader.to_vec(),
+    })
+}
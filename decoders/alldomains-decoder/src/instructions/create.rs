@@ -0,0 +1,28 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Registers a domain, creating its name record.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x181ec828051c0777")]
+pub struct Create {
+    pub hashed_name: Vec<u8>,
+    pub lamports: u64,
+    pub space: u32,
+    /// The expiry of the domain as a unix timestamp, `None` for domains that
+    /// never expire.
+    pub expires_at: Option<u64>,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Create")]
+pub struct CreateInstructionAccounts {
+    pub system_program: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub name_record: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub nclass: Option<solana_pubkey::Pubkey>,
+    pub parent_name_record: Option<solana_pubkey::Pubkey>,
+    pub parent_name_owner: Option<solana_pubkey::Pubkey>,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,119 @@
+use crate::PROGRAM_ID;
+
+use super::AllDomainsDecoder;
+pub mod create;
+pub mod transfer;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum AllDomainsInstruction {
+    Create(create::Create),
+    Transfer(transfer::Transfer),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for AllDomainsDecoder {
+    type InstructionType = AllDomainsInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            AllDomainsInstruction::Create => create::Create,
+            AllDomainsInstruction::Transfer => transfer::Transfer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder},
+        solana_instruction::{AccountMeta, Instruction},
+    };
+
+    #[test]
+    fn test_decode_transfer() {
+        // Arrange
+        let name_record = solana_pubkey::Pubkey::new_unique();
+        let owner = solana_pubkey::Pubkey::new_unique();
+        let new_owner = solana_pubkey::Pubkey::new_unique();
+        let mut data = vec![0xa3, 0x34, 0xc8, 0xe7, 0x8c, 0x03, 0x45, 0xba];
+        data.extend_from_slice(new_owner.as_ref());
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(name_record, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+            data,
+        };
+
+        // Act
+        let decoded = AllDomainsDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            transfer::Transfer::arrange_accounts(&decoded.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            AllDomainsInstruction::Transfer(transfer::Transfer { new_owner })
+        );
+        assert_eq!(arranged_accounts.name_record, name_record);
+        assert_eq!(arranged_accounts.owner, owner);
+        assert_eq!(arranged_accounts.nclass, None);
+        assert_eq!(arranged_accounts.parent_name_record, None);
+    }
+
+    #[test]
+    fn test_decode_create_with_expiry() {
+        // Arrange
+        let mut data = vec![0x18, 0x1e, 0xc8, 0x28, 0x05, 0x1c, 0x07, 0x77];
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&[1, 2, 3, 4]);
+        data.extend_from_slice(&2_338_560u64.to_le_bytes());
+        data.extend_from_slice(&21u32.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&1_761_955_200u64.to_le_bytes());
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data,
+        };
+
+        // Act
+        let decoded = AllDomainsDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            AllDomainsInstruction::Create(create::Create {
+                hashed_name: vec![1, 2, 3, 4],
+                lamports: 2_338_560,
+                space: 21,
+                expires_at: Some(1_761_955_200),
+            })
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Transfers a domain to a new owner.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xa334c8e78c0345ba")]
+pub struct Transfer {
+    #[carbon(serialize_as = "base58")]
+    pub new_owner: solana_pubkey::Pubkey,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Transfer")]
+pub struct TransferInstructionAccounts {
+    pub name_record: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub nclass: Option<solana_pubkey::Pubkey>,
+    pub parent_name_record: Option<solana_pubkey::Pubkey>,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,10 @@
+use solana_pubkey::Pubkey;
+
+pub struct AllDomainsDecoder;
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+
+pub const PROGRAM_ID: Pubkey =
+    solana_pubkey::Pubkey::from_str_const("ALTNSZ46uaAUU7XUV6awvdorLGqAsPwa9shm7h4uP2FK");
@@ -1,11 +1,24 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use alloc::vec::Vec;
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x00")]
+#[carbon(deserialize_with = "deserialize_create")]
 pub struct Create {}
 
+/// Accepts both the `0x00` discriminator and the empty data of `Create`
+/// instructions built before the discriminator was introduced, which are
+/// still sent by older clients.
+fn deserialize_create(data: &[u8]) -> Option<Create> {
+    match data {
+        [] | [0] => Some(Create {}),
+        _ => None,
+    }
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Create")]
 pub struct CreateInstructionAccounts {
     pub funding_address: solana_pubkey::Pubkey,
     pub associated_account_address: solana_pubkey::Pubkey,
@@ -13,27 +26,5 @@ pub struct CreateInstructionAccounts {
     pub token_mint_address: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Create {
-    type ArrangedAccounts = CreateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [funding_address, associated_account_address, wallet_address, token_mint_address, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateInstructionAccounts {
-            funding_address: funding_address.pubkey,
-            associated_account_address: associated_account_address.pubkey,
-            wallet_address: wallet_address.pubkey,
-            token_mint_address: token_mint_address.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use alloc::vec::Vec;
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01")]
 pub struct CreateIdempotent {}
 
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "CreateIdempotent")]
 pub struct CreateIdempotentInstructionAccounts {
     pub funding_address: solana_pubkey::Pubkey,
     pub associated_account_address: solana_pubkey::Pubkey,
@@ -13,27 +16,5 @@ pub struct CreateIdempotentInstructionAccounts {
     pub token_mint_address: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateIdempotent {
-    type ArrangedAccounts = CreateIdempotentInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [funding_address, associated_account_address, wallet_address, token_mint_address, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateIdempotentInstructionAccounts {
-            funding_address: funding_address.pubkey,
-            associated_account_address: associated_account_address.pubkey,
-            wallet_address: wallet_address.pubkey,
-            token_mint_address: token_mint_address.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
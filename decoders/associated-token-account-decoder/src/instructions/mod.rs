@@ -1,4 +1,5 @@
 use crate::PROGRAM_ID;
+use carbon_core::deserialize::ArrangeAccounts;
 
 use super::SplAssociatedTokenAccountDecoder;
 pub mod create;
@@ -21,9 +22,58 @@ pub enum SplAssociatedTokenAccountInstruction {
     RecoverNested(recover_nested::RecoverNested),
 }
 
+/// A token account created by a `Create` or `CreateIdempotent` instruction,
+/// with the wallet owning it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreatedTokenAccount {
+    pub account: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub mint: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+}
+
+impl SplAssociatedTokenAccountInstruction {
+    /// Returns the token account created by this instruction, given its
+    /// accounts, to attribute it to its owner.
+    ///
+    /// `CreateIdempotent` succeeds without creating anything when the account
+    /// already exists, so its result names the account and owner but does not
+    /// prove the account is new.
+    pub fn created_token_account(
+        &self,
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<CreatedTokenAccount> {
+        match self {
+            SplAssociatedTokenAccountInstruction::Create(_) => {
+                let accounts = create::Create::arrange_accounts(accounts)?;
+                Some(CreatedTokenAccount {
+                    account: accounts.associated_account_address,
+                    owner: accounts.wallet_address,
+                    mint: accounts.token_mint_address,
+                    token_program: accounts.token_program,
+                })
+            }
+            SplAssociatedTokenAccountInstruction::CreateIdempotent(_) => {
+                let accounts = create_idempotent::CreateIdempotent::arrange_accounts(accounts)?;
+                Some(CreatedTokenAccount {
+                    account: accounts.associated_account_address,
+                    owner: accounts.wallet_address,
+                    mint: accounts.token_mint_address,
+                    token_program: accounts.token_program,
+                })
+            }
+            SplAssociatedTokenAccountInstruction::RecoverNested(_) => None,
+        }
+    }
+}
+
 impl carbon_core::instruction::InstructionDecoder<'_> for SplAssociatedTokenAccountDecoder {
     type InstructionType = SplAssociatedTokenAccountInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -39,3 +89,96 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SplAssociatedTokenAcco
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::instruction::InstructionDecoder;
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    const FUNDING_ADDRESS: solana_pubkey::Pubkey =
+        solana_pubkey::Pubkey::from_str_const("9JofT9XwTe2G77x25b1B2HGkLuxM51jqceFSFhkxat4y");
+    const ASSOCIATED_ACCOUNT_ADDRESS: solana_pubkey::Pubkey =
+        solana_pubkey::Pubkey::from_str_const("7m51tJmposRwZPCKdPHFr67ZmexbgyLmfb6GNyiBH67G");
+    const WALLET_ADDRESS: solana_pubkey::Pubkey =
+        solana_pubkey::Pubkey::from_str_const("53uw71LUBRZ3ndH9MT8C9RJZSgotGjF1aii5TyWjddVN");
+    const TOKEN_MINT_ADDRESS: solana_pubkey::Pubkey =
+        solana_pubkey::Pubkey::from_str_const("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+    const TOKEN_PROGRAM: solana_pubkey::Pubkey =
+        solana_pubkey::Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+    #[test]
+    fn test_decode_create_idempotent() {
+        // Arrange
+        let expected_ix = SplAssociatedTokenAccountInstruction::CreateIdempotent(
+            create_idempotent::CreateIdempotent {},
+        );
+        let expected_accounts = vec![
+            AccountMeta::new(FUNDING_ADDRESS, true),
+            AccountMeta::new(ASSOCIATED_ACCOUNT_ADDRESS, false),
+            AccountMeta::new_readonly(WALLET_ADDRESS, false),
+            AccountMeta::new_readonly(TOKEN_MINT_ADDRESS, false),
+            AccountMeta::new_readonly(solana_pubkey::Pubkey::default(), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM, false),
+        ];
+        let expected_arranged_accounts = create_idempotent::CreateIdempotentInstructionAccounts {
+            funding_address: FUNDING_ADDRESS,
+            associated_account_address: ASSOCIATED_ACCOUNT_ADDRESS,
+            wallet_address: WALLET_ADDRESS,
+            token_mint_address: TOKEN_MINT_ADDRESS,
+            system_program: solana_pubkey::Pubkey::default(),
+            token_program: TOKEN_PROGRAM,
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = SplAssociatedTokenAccountDecoder;
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/create_idempotent_ix.json")
+                .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            create_idempotent::CreateIdempotent::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+
+    #[test]
+    fn test_decode_legacy_create() {
+        // Arrange
+        let expected_created_account = CreatedTokenAccount {
+            account: ASSOCIATED_ACCOUNT_ADDRESS,
+            owner: WALLET_ADDRESS,
+            mint: TOKEN_MINT_ADDRESS,
+            token_program: TOKEN_PROGRAM,
+        };
+
+        // Act
+        let decoder = SplAssociatedTokenAccountDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/create_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        assert!(instruction.data.is_empty());
+        assert_eq!(
+            decoded.data,
+            SplAssociatedTokenAccountInstruction::Create(create::Create {})
+        );
+        assert_eq!(
+            decoded.data.created_token_account(&decoded.accounts),
+            Some(expected_created_account)
+        );
+    }
+}
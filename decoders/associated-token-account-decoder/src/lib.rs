@@ -1,9 +1,13 @@
 #![no_std]
 
+extern crate alloc;
+
 use solana_pubkey::Pubkey;
 
 pub struct SplAssociatedTokenAccountDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
@@ -0,0 +1,41 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0xd9ebe33f981b82a6",
+    deserialize_with = "deserialize_clmmpool"
+)]
+pub struct ClmmPool {
+    #[carbon(serialize_as = "base58")]
+    pub clmm_config: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_a: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_b: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_a_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_b_vault: solana_pubkey::Pubkey,
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+    /// The swap fee, in millionths of the amount.
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    /// The square root of the price, as a Q64.64 number.
+    pub current_sqrt_price: u128,
+    pub current_tick_index: i32,
+    pub fee_growth_global_a: u128,
+    pub fee_growth_global_b: u128,
+    pub fee_protocol_token_a: u64,
+    pub fee_protocol_token_b: u64,
+    pub bump: u8,
+}
+
+/// ClmmPool accounts hold more fields after the decoded ones, such as
+/// the rewarders, which are ignored instead of rejected.
+fn deserialize_clmmpool(data: &[u8]) -> Option<ClmmPool> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
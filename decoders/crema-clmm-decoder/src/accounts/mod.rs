@@ -0,0 +1,64 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::CremaClmmDecoder;
+pub mod clmmpool;
+
+pub enum CremaClmmAccount {
+    ClmmPool(clmmpool::ClmmPool),
+}
+
+impl AccountDecoder<'_> for CremaClmmDecoder {
+    type AccountType = CremaClmmAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = clmmpool::ClmmPool::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: CremaClmmAccount::ClmmPool(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_clmmpool_account() {
+        // Act
+        let decoder = CremaClmmDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/clmmpool_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            CremaClmmAccount::ClmmPool(clmmpool) => {
+                assert_eq!(
+                    clmmpool.token_b,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+                    )
+                );
+                assert_eq!(clmmpool.tick_spacing, 60);
+                assert_eq!(clmmpool.fee_rate, 2_500);
+                assert_eq!(clmmpool.current_tick_index, 12);
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x3cadf767045d8230")]
+pub struct CollectFee {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "CollectFee")]
+pub struct CollectFeeInstructionAccounts {
+    pub clmmpool: solana_pubkey::Pubkey,
+    pub position: solana_pubkey::Pubkey,
+    pub position_nft_mint: solana_pubkey::Pubkey,
+    pub position_ata: solana_pubkey::Pubkey,
+    pub token_a: solana_pubkey::Pubkey,
+    pub token_b: solana_pubkey::Pubkey,
+    pub account_a: solana_pubkey::Pubkey,
+    pub account_b: solana_pubkey::Pubkey,
+    pub token_a_vault: solana_pubkey::Pubkey,
+    pub token_b_vault: solana_pubkey::Pubkey,
+    pub tick_array_lower: solana_pubkey::Pubkey,
+    pub tick_array_upper: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,32 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xa026d06f685b2c01")]
+pub struct DecreaseLiquidity {
+    pub delta_liquidity: u128,
+    pub token_a_min: u64,
+    pub token_b_min: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "DecreaseLiquidity")]
+pub struct DecreaseLiquidityInstructionAccounts {
+    pub clmmpool: solana_pubkey::Pubkey,
+    pub position: solana_pubkey::Pubkey,
+    pub position_nft_mint: solana_pubkey::Pubkey,
+    pub position_ata: solana_pubkey::Pubkey,
+    pub token_a: solana_pubkey::Pubkey,
+    pub token_b: solana_pubkey::Pubkey,
+    pub account_a: solana_pubkey::Pubkey,
+    pub account_b: solana_pubkey::Pubkey,
+    pub token_a_vault: solana_pubkey::Pubkey,
+    pub token_b_vault: solana_pubkey::Pubkey,
+    pub tick_array_lower: solana_pubkey::Pubkey,
+    pub tick_array_upper: solana_pubkey::Pubkey,
+    pub tick_array_map: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,216 @@
+use crate::PROGRAM_ID;
+
+use super::CremaClmmDecoder;
+pub mod collect_fee;
+pub mod decrease_liquidity;
+pub mod increase_liquidity;
+pub mod swap;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum CremaClmmInstruction {
+    Swap(swap::Swap),
+    IncreaseLiquidity(increase_liquidity::IncreaseLiquidity),
+    DecreaseLiquidity(decrease_liquidity::DecreaseLiquidity),
+    CollectFee(collect_fee::CollectFee),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for CremaClmmDecoder {
+    type InstructionType = CremaClmmInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            CremaClmmInstruction::Swap => swap::Swap,
+            CremaClmmInstruction::IncreaseLiquidity => increase_liquidity::IncreaseLiquidity,
+            CremaClmmInstruction::DecreaseLiquidity => decrease_liquidity::DecreaseLiquidity,
+            CremaClmmInstruction::CollectFee => collect_fee::CollectFee,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_swap() {
+        // Arrange
+        let expected_ix = CremaClmmInstruction::Swap(swap::Swap {
+            a_to_b: false,
+            by_amount_in: true,
+            amount: 25_000_000,
+            amount_limit: 24_900_000,
+            sqrt_price_limit: 79_226_673_515_401_279_992_447_579_055,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "f9nBSdh2wsasxkKKBiqjpkfSKNQcqTbyEk4Cty4zuAe",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3G1abQaemV5h8cFKZw62MYRXgCCsXcrqKsiUs9bpGbeC",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "So11111111111111111111111111111111111111112",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "6DKFTtr6uVJYQmBixaqcWaYKSV9j19wwkdU4qZjaXhb3",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7MvyKzdBXeTuSwrt5VSePn9vVXCso1y7UjVQPnJubwYA",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "Df8FXnnykRQbPj3bHauMWCo8F242hzdHunrHqPbCmqyW",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "tXLdpfxBhoFdjUgjwEigrJbQRLY4JhWcKKvxLREJBJA",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "Aqdf8D1r76F7yA4fbBgm1HafCdwm8Fx3bRwk1EX9dZJb",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "DVwA2EJWRizhXNQZJBfLdYBQUovZHhz2TFMUn1GUAa2H",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9ep78dxChbi8tNzQWXgSyszyhsyp7YB1qHGiX2wMTxQq",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "FnUo13XLx3tWqc5u24okxJ7GKT2nf4AUFBWTPqA4dzju",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "DawV25f7nbjMDQ8yKRAj4TWwur52hcULzbRw2YBFRgdp",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = swap::SwapInstructionAccounts {
+            clmm_config: solana_pubkey::Pubkey::from_str_const(
+                "f9nBSdh2wsasxkKKBiqjpkfSKNQcqTbyEk4Cty4zuAe",
+            ),
+            clmmpool: solana_pubkey::Pubkey::from_str_const(
+                "3G1abQaemV5h8cFKZw62MYRXgCCsXcrqKsiUs9bpGbeC",
+            ),
+            token_a: solana_pubkey::Pubkey::from_str_const(
+                "So11111111111111111111111111111111111111112",
+            ),
+            token_b: solana_pubkey::Pubkey::from_str_const(
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            ),
+            account_a: solana_pubkey::Pubkey::from_str_const(
+                "6DKFTtr6uVJYQmBixaqcWaYKSV9j19wwkdU4qZjaXhb3",
+            ),
+            account_b: solana_pubkey::Pubkey::from_str_const(
+                "7MvyKzdBXeTuSwrt5VSePn9vVXCso1y7UjVQPnJubwYA",
+            ),
+            token_a_vault: solana_pubkey::Pubkey::from_str_const(
+                "Df8FXnnykRQbPj3bHauMWCo8F242hzdHunrHqPbCmqyW",
+            ),
+            token_b_vault: solana_pubkey::Pubkey::from_str_const(
+                "tXLdpfxBhoFdjUgjwEigrJbQRLY4JhWcKKvxLREJBJA",
+            ),
+            tick_array_map: solana_pubkey::Pubkey::from_str_const(
+                "Aqdf8D1r76F7yA4fbBgm1HafCdwm8Fx3bRwk1EX9dZJb",
+            ),
+            owner: solana_pubkey::Pubkey::from_str_const(
+                "DVwA2EJWRizhXNQZJBfLdYBQUovZHhz2TFMUn1GUAa2H",
+            ),
+            partner: solana_pubkey::Pubkey::from_str_const(
+                "9ep78dxChbi8tNzQWXgSyszyhsyp7YB1qHGiX2wMTxQq",
+            ),
+            partner_ata_a: solana_pubkey::Pubkey::from_str_const(
+                "FnUo13XLx3tWqc5u24okxJ7GKT2nf4AUFBWTPqA4dzju",
+            ),
+            partner_ata_b: solana_pubkey::Pubkey::from_str_const(
+                "DawV25f7nbjMDQ8yKRAj4TWwur52hcULzbRw2YBFRgdp",
+            ),
+            token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = CremaClmmDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/swap_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            swap::Swap::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,33 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xf8c69e91e17587c8")]
+pub struct Swap {
+    pub a_to_b: bool,
+    pub by_amount_in: bool,
+    pub amount: u64,
+    pub amount_limit: u64,
+    pub sqrt_price_limit: u128,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Swap")]
+pub struct SwapInstructionAccounts {
+    pub clmm_config: solana_pubkey::Pubkey,
+    pub clmmpool: solana_pubkey::Pubkey,
+    pub token_a: solana_pubkey::Pubkey,
+    pub token_b: solana_pubkey::Pubkey,
+    pub account_a: solana_pubkey::Pubkey,
+    pub account_b: solana_pubkey::Pubkey,
+    pub token_a_vault: solana_pubkey::Pubkey,
+    pub token_b_vault: solana_pubkey::Pubkey,
+    pub tick_array_map: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub partner: solana_pubkey::Pubkey,
+    pub partner_ata_a: solana_pubkey::Pubkey,
+    pub partner_ata_b: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x38d138c577febc75")]
 pub struct AddPerpLpShares {
@@ -9,26 +9,11 @@ pub struct AddPerpLpShares {
     pub market_index: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddPerpLpShares")]
 pub struct AddPerpLpSharesInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddPerpLpShares {
-    type ArrangedAccounts = AddPerpLpSharesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AddPerpLpSharesInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x11a4522db756bfc7")]
 pub struct AdminDisableUpdatePerpBidAskTwap {
     pub disable: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AdminDisableUpdatePerpBidAskTwap")]
 pub struct AdminDisableUpdatePerpBidAskTwapInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AdminDisableUpdatePerpBidAskTwap {
-    type ArrangedAccounts = AdminDisableUpdatePerpBidAskTwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, user_stats, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AdminDisableUpdatePerpBidAskTwapInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            user_stats: user_stats.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6bd3fa8512253964")]
 pub struct CancelOrderByUserId {
     pub user_order_id: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CancelOrderByUserId")]
 pub struct CancelOrderByUserIdInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CancelOrderByUserId {
-    type ArrangedAccounts = CancelOrderByUserIdInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CancelOrderByUserIdInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xeee15f9ee36708c2")]
 pub struct CancelOrders {
@@ -12,26 +12,11 @@ pub struct CancelOrders {
     pub direction: Option<PositionDirection>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CancelOrders")]
 pub struct CancelOrdersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CancelOrders {
-    type ArrangedAccounts = CancelOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CancelOrdersInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5b9a18576a3bbe42")]
 pub struct DeleteInitializedPerpMarket {
     pub market_index: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeleteInitializedPerpMarket")]
 pub struct DeleteInitializedPerpMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedPerpMarket {
-    type ArrangedAccounts = DeleteInitializedPerpMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(DeleteInitializedPerpMarketInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
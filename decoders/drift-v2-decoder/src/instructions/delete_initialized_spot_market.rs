@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1f8c43bfbd1465dd")]
 pub struct DeleteInitializedSpotMarket {
     pub market_index: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeleteInitializedSpotMarket")]
 pub struct DeleteInitializedSpotMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
@@ -16,28 +18,5 @@ pub struct DeleteInitializedSpotMarketInstructionAccounts {
     pub insurance_fund_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedSpotMarket {
-    type ArrangedAccounts = DeleteInitializedSpotMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, spot_market_vault, insurance_fund_vault, drift_signer, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DeleteInitializedSpotMarketInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            insurance_fund_vault: insurance_fund_vault.pubkey,
-            drift_signer: drift_signer.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xddf780fdd4fe2e99")]
 pub struct DeleteSignedMsgUserOrders {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeleteSignedMsgUserOrders")]
 pub struct DeleteSignedMsgUserOrdersInstructionAccounts {
     pub signed_msg_user_orders: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeleteSignedMsgUserOrders {
-    type ArrangedAccounts = DeleteSignedMsgUserOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [signed_msg_user_orders, state, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(DeleteSignedMsgUserOrdersInstructionAccounts {
-            signed_msg_user_orders: signed_msg_user_orders.pubkey,
-            state: state.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
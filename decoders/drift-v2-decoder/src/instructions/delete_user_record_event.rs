@@ -1,13 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d476fbe76070384de")]
 pub struct DeleteUserRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub user_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub sub_account_id: u16,
+    #[carbon(serialize_as = "base58")]
     pub keeper: Option<solana_pubkey::Pubkey>,
 }
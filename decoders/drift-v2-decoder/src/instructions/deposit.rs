@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf223c68952e1f2b6")]
 pub struct Deposit {
@@ -10,6 +10,8 @@ pub struct Deposit {
     pub reduce_only: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Deposit")]
 pub struct DepositInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -18,28 +20,5 @@ pub struct DepositInstructionAccounts {
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Deposit {
-    type ArrangedAccounts = DepositInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, spot_market_vault, user_token_account, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            authority: authority.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            user_token_account: user_token_account.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
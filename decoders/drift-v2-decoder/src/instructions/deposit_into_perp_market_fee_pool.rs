@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x223a39446150f406")]
 pub struct DepositIntoPerpMarketFeePool {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositIntoPerpMarketFeePool")]
 pub struct DepositIntoPerpMarketFeePoolInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
@@ -17,29 +19,5 @@ pub struct DepositIntoPerpMarketFeePoolInstructionAccounts {
     pub quote_spot_market: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositIntoPerpMarketFeePool {
-    type ArrangedAccounts = DepositIntoPerpMarketFeePoolInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, admin, source_vault, drift_signer, quote_spot_market, spot_market_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositIntoPerpMarketFeePoolInstructionAccounts {
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-            admin: admin.pubkey,
-            source_vault: source_vault.pubkey,
-            drift_signer: drift_signer.pubkey,
-            quote_spot_market: quote_spot_market.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5c28972a7afe8bf6")]
 pub struct DepositIntoSpotMarketRevenuePool {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositIntoSpotMarketRevenuePool")]
 pub struct DepositIntoSpotMarketRevenuePoolInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
@@ -15,27 +17,5 @@ pub struct DepositIntoSpotMarketRevenuePoolInstructionAccounts {
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketRevenuePool {
-    type ArrangedAccounts = DepositIntoSpotMarketRevenuePoolInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, authority, spot_market_vault, user_token_account, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositIntoSpotMarketRevenuePoolInstructionAccounts {
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-            authority: authority.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            user_token_account: user_token_account.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x30fc7749ffcdaef7")]
 pub struct DepositIntoSpotMarketVault {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositIntoSpotMarketVault")]
 pub struct DepositIntoSpotMarketVaultInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
@@ -15,27 +17,5 @@ pub struct DepositIntoSpotMarketVaultInstructionAccounts {
     pub source_vault: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketVault {
-    type ArrangedAccounts = DepositIntoSpotMarketVaultInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, admin, source_vault, spot_market_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositIntoSpotMarketVaultInstructionAccounts {
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-            admin: admin.pubkey,
-            source_vault: source_vault.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,14 +1,16 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1db4f1dacf66872c86")]
 pub struct DepositRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub user_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub direction: DepositDirection,
     pub deposit_record_id: u64,
@@ -22,5 +24,6 @@ pub struct DepositRecordEvent {
     pub total_deposits_after: u64,
     pub total_withdraws_after: u64,
     pub explanation: DepositExplanation,
+    #[carbon(serialize_as = "base58")]
     pub transfer_user: Option<solana_pubkey::Pubkey>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb1b81bc1220dd291")]
 pub struct EndSwap {
@@ -13,6 +13,8 @@ pub struct EndSwap {
     pub reduce_only: Option<SwapReduceOnly>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EndSwap")]
 pub struct EndSwapInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -25,32 +27,5 @@ pub struct EndSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub instructions: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EndSwap {
-    type ArrangedAccounts = EndSwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(EndSwapInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            authority: authority.pubkey,
-            out_spot_market_vault: out_spot_market_vault.pubkey,
-            in_spot_market_vault: in_spot_market_vault.pubkey,
-            out_token_account: out_token_account.pubkey,
-            in_token_account: in_token_account.pubkey,
-            token_program: token_program.pubkey,
-            drift_signer: drift_signer.pubkey,
-            instructions: instructions.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x40b5c43fde4840e8")]
 pub struct ForceCancelOrders {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ForceCancelOrders")]
 pub struct ForceCancelOrdersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub filler: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
-    type ArrangedAccounts = ForceCancelOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, user, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ForceCancelOrdersInstructionAccounts {
-            state: state.pubkey,
-            authority: authority.pubkey,
-            filler: filler.pubkey,
-            user: user.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
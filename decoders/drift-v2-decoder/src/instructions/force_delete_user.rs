@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x02f1c3ace318fe9e")]
 pub struct ForceDeleteUser {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ForceDeleteUser")]
 pub struct ForceDeleteUserInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
@@ -13,26 +15,5 @@ pub struct ForceDeleteUserInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub keeper: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ForceDeleteUser {
-    type ArrangedAccounts = ForceDeleteUserInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, keeper, drift_signer, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(ForceDeleteUserInstructionAccounts {
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            state: state.pubkey,
-            authority: authority.pubkey,
-            keeper: keeper.pubkey,
-            drift_signer: drift_signer.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d13897721e0f90657")]
 pub struct FuelSeasonRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
     pub fuel_insurance: u128,
     pub fuel_deposits: u128,
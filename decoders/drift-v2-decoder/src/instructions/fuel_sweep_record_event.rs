@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d295425f684f08308")]
 pub struct FuelSweepRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
     pub user_stats_fuel_insurance: u32,
     pub user_stats_fuel_deposits: u32,
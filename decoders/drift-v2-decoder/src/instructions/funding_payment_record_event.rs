@@ -1,12 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d083b601489c9385f")]
 pub struct FundingPaymentRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub user_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub market_index: u16,
     pub funding_payment: i64,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x84bfe48dc98a3c30")]
 pub struct InitUserFuel {
@@ -12,28 +12,12 @@ pub struct InitUserFuel {
     pub fuel_boost_insurance: Option<u32>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitUserFuel")]
 pub struct InitUserFuelInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitUserFuel {
-    type ArrangedAccounts = InitUserFuelInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, user, user_stats, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitUserFuelInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xafaf6d1f0d989bed")]
 pub struct Initialize {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Initialize")]
 pub struct InitializeInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
@@ -14,28 +16,5 @@ pub struct InitializeInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Initialize {
-    type ArrangedAccounts = InitializeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, quote_asset_mint, drift_signer, rent, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            quote_asset_mint: quote_asset_mint.pubkey,
-            drift_signer: drift_signer.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
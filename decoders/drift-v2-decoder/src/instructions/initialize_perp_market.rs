@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8409e5767576753e")]
 pub struct InitializePerpMarket {
@@ -31,9 +31,12 @@ pub struct InitializePerpMarket {
     pub concentration_coef_scale: u128,
     pub curve_update_intensity: u8,
     pub amm_jit_intensity: u8,
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializePerpMarket")]
 pub struct InitializePerpMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
@@ -41,26 +44,5 @@ pub struct InitializePerpMarketInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializePerpMarket {
-    type ArrangedAccounts = InitializePerpMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, oracle, rent, system_program, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(InitializePerpMarketInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-            oracle: oracle.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf846c6e0e0697dc3")]
 pub struct InitializePredictionMarket {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializePredictionMarket")]
 pub struct InitializePredictionMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializePredictionMarket {
-    type ArrangedAccounts = InitializePredictionMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializePredictionMarketInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
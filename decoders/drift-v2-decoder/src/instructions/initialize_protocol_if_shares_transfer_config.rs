@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5983efc8b28d6ac2")]
 pub struct InitializeProtocolIfSharesTransferConfig {}
@@ -12,6 +12,7 @@ pub struct InitializeProtocolIfSharesTransferConfigInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTransferConfig {
@@ -20,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTra
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protocol_if_shares_transfer_config, state, rent, system_program, _remaining @ ..] =
+        let [admin, protocol_if_shares_transfer_config, state, rent, system_program, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -33,6 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTra
                 state: state.pubkey,
                 rent: rent.pubkey,
                 system_program: system_program.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
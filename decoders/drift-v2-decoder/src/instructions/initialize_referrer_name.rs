@@ -1,13 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xeb7ee70a2aa41a3d")]
 pub struct InitializeReferrerName {
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeReferrerName")]
 pub struct InitializeReferrerNameInstructionAccounts {
     pub referrer_name: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -16,28 +19,5 @@ pub struct InitializeReferrerNameInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerName {
-    type ArrangedAccounts = InitializeReferrerNameInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [referrer_name, user, user_stats, authority, payer, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeReferrerNameInstructionAccounts {
-            referrer_name: referrer_name.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
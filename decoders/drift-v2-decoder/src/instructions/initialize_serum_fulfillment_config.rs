@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xc1d384ac46ab075e")]
 pub struct InitializeSerumFulfillmentConfig {
     pub market_index: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeSerumFulfillmentConfig")]
 pub struct InitializeSerumFulfillmentConfigInstructionAccounts {
     pub base_spot_market: solana_pubkey::Pubkey,
     pub quote_spot_market: solana_pubkey::Pubkey,
@@ -20,32 +22,5 @@ pub struct InitializeSerumFulfillmentConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeSerumFulfillmentConfig {
-    type ArrangedAccounts = InitializeSerumFulfillmentConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [base_spot_market, quote_spot_market, state, serum_program, serum_market, serum_open_orders, drift_signer, serum_fulfillment_config, admin, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeSerumFulfillmentConfigInstructionAccounts {
-            base_spot_market: base_spot_market.pubkey,
-            quote_spot_market: quote_spot_market.pubkey,
-            state: state.pubkey,
-            serum_program: serum_program.pubkey,
-            serum_market: serum_market.pubkey,
-            serum_open_orders: serum_open_orders.pubkey,
-            drift_signer: drift_signer.pubkey,
-            serum_fulfillment_config: serum_fulfillment_config.pubkey,
-            admin: admin.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
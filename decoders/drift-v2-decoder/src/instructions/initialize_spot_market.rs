@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xeac4802c5e0f30c9")]
 pub struct InitializeSpotMarket {
@@ -25,9 +25,12 @@ pub struct InitializeSpotMarket {
     pub order_tick_size: u64,
     pub order_step_size: u64,
     pub if_total_factor: u32,
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeSpotMarket")]
 pub struct InitializeSpotMarketInstructionAccounts {
     pub spot_market: solana_pubkey::Pubkey,
     pub spot_market_mint: solana_pubkey::Pubkey,
@@ -40,32 +43,5 @@ pub struct InitializeSpotMarketInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeSpotMarket {
-    type ArrangedAccounts = InitializeSpotMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, spot_market_mint, spot_market_vault, insurance_fund_vault, drift_signer, state, oracle, admin, rent, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeSpotMarketInstructionAccounts {
-            spot_market: spot_market.pubkey,
-            spot_market_mint: spot_market_mint.pubkey,
-            spot_market_vault: spot_market_vault.pubkey,
-            insurance_fund_vault: insurance_fund_vault.pubkey,
-            drift_signer: drift_signer.pubkey,
-            state: state.pubkey,
-            oracle: oracle.pubkey,
-            admin: admin.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,14 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6f11b9fa3c7a26fe")]
 pub struct InitializeUser {
     pub sub_account_id: u16,
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeUser")]
 pub struct InitializeUserInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
@@ -17,28 +20,5 @@ pub struct InitializeUserInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
-    type ArrangedAccounts = InitializeUserInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, payer, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeUserInstructionAccounts {
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            state: state.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x4b2377f7bf128b02")]
 pub struct LiquidatePerp {
@@ -10,6 +10,8 @@ pub struct LiquidatePerp {
     pub limit_price: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LiquidatePerp")]
 pub struct LiquidatePerpInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -17,27 +19,5 @@ pub struct LiquidatePerpInstructionAccounts {
     pub liquidator_stats: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LiquidatePerp {
-    type ArrangedAccounts = LiquidatePerpInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LiquidatePerpInstructionAccounts {
-            state: state.pubkey,
-            authority: authority.pubkey,
-            liquidator: liquidator.pubkey,
-            liquidator_stats: liquidator_stats.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6b00802923e5fb12")]
 pub struct LiquidateSpot {
@@ -11,6 +11,8 @@ pub struct LiquidateSpot {
     pub limit_price: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LiquidateSpot")]
 pub struct LiquidateSpotInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -18,27 +20,5 @@ pub struct LiquidateSpotInstructionAccounts {
     pub liquidator_stats: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpot {
-    type ArrangedAccounts = LiquidateSpotInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LiquidateSpotInstructionAccounts {
-            state: state.pubkey,
-            authority: authority.pubkey,
-            liquidator: liquidator.pubkey,
-            liquidator_stats: liquidator_stats.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
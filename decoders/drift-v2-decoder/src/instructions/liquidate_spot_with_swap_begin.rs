@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0c2bb0539cfb750d")]
 pub struct LiquidateSpotWithSwapBegin {
@@ -10,6 +10,8 @@ pub struct LiquidateSpotWithSwapBegin {
     pub swap_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LiquidateSpotWithSwapBegin")]
 pub struct LiquidateSpotWithSwapBeginInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -24,34 +26,5 @@ pub struct LiquidateSpotWithSwapBeginInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub instructions: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpotWithSwapBegin {
-    type ArrangedAccounts = LiquidateSpotWithSwapBeginInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, liability_spot_market_vault, asset_spot_market_vault, liability_token_account, asset_token_account, token_program, drift_signer, instructions, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LiquidateSpotWithSwapBeginInstructionAccounts {
-            state: state.pubkey,
-            authority: authority.pubkey,
-            liquidator: liquidator.pubkey,
-            liquidator_stats: liquidator_stats.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            liability_spot_market_vault: liability_spot_market_vault.pubkey,
-            asset_spot_market_vault: asset_spot_market_vault.pubkey,
-            liability_token_account: liability_token_account.pubkey,
-            asset_token_account: asset_token_account.pubkey,
-            token_program: token_program.pubkey,
-            drift_signer: drift_signer.pubkey,
-            instructions: instructions.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
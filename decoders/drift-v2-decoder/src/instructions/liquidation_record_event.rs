@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d7f11006cb60de735")]
 pub struct LiquidationRecordEvent {
     pub ts: i64,
     pub liquidation_type: LiquidationType,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub liquidator: solana_pubkey::Pubkey,
     pub margin_requirement: u128,
     pub total_collateral: i128,
@@ -1,13 +1,14 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d65163626b20d8e6f")]
 pub struct LpRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub action: LPAction,
     pub n_shares: u64,
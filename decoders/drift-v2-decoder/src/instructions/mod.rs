@@ -10,17 +10,20 @@ pub mod cancel_order_by_user_id;
 pub mod cancel_orders;
 pub mod cancel_orders_by_ids;
 pub mod cancel_request_remove_insurance_fund_stake;
+#[cfg(feature = "events")]
 pub mod curve_record_event;
 pub mod delete_initialized_perp_market;
 pub mod delete_initialized_spot_market;
 pub mod delete_prelaunch_oracle;
 pub mod delete_signed_msg_user_orders;
 pub mod delete_user;
+#[cfg(feature = "events")]
 pub mod delete_user_record_event;
 pub mod deposit;
 pub mod deposit_into_perp_market_fee_pool;
 pub mod deposit_into_spot_market_revenue_pool;
 pub mod deposit_into_spot_market_vault;
+#[cfg(feature = "events")]
 pub mod deposit_record_event;
 pub mod disable_user_high_leverage_mode;
 pub mod enable_user_high_leverage_mode;
@@ -29,9 +32,13 @@ pub mod fill_perp_order;
 pub mod fill_spot_order;
 pub mod force_cancel_orders;
 pub mod force_delete_user;
+#[cfg(feature = "events")]
 pub mod fuel_season_record_event;
+#[cfg(feature = "events")]
 pub mod fuel_sweep_record_event;
+#[cfg(feature = "events")]
 pub mod funding_payment_record_event;
+#[cfg(feature = "events")]
 pub mod funding_rate_record_event;
 pub mod init_user_fuel;
 pub mod initialize;
@@ -53,7 +60,9 @@ pub mod initialize_signed_msg_user_orders;
 pub mod initialize_spot_market;
 pub mod initialize_user;
 pub mod initialize_user_stats;
+#[cfg(feature = "events")]
 pub mod insurance_fund_record_event;
+#[cfg(feature = "events")]
 pub mod insurance_fund_stake_record_event;
 pub mod liquidate_borrow_for_perp_pnl;
 pub mod liquidate_perp;
@@ -62,15 +71,20 @@ pub mod liquidate_perp_with_fill;
 pub mod liquidate_spot;
 pub mod liquidate_spot_with_swap_begin;
 pub mod liquidate_spot_with_swap_end;
+#[cfg(feature = "events")]
 pub mod liquidation_record_event;
 pub mod log_user_balances;
+#[cfg(feature = "events")]
 pub mod lp_record_event;
 pub mod modify_order;
 pub mod modify_order_by_user_id;
 pub mod move_amm_price;
+#[cfg(feature = "events")]
 pub mod new_user_record_event;
 pub mod openbook_v2_fulfillment_config_status;
+#[cfg(feature = "events")]
 pub mod order_action_record_event;
+#[cfg(feature = "events")]
 pub mod order_record_event;
 pub mod pause_spot_market_deposit_withdraw;
 pub mod phoenix_fulfillment_config_status;
@@ -107,11 +121,16 @@ pub mod settle_funding_payment;
 pub mod settle_lp;
 pub mod settle_multiple_pnls;
 pub mod settle_pnl;
+#[cfg(feature = "events")]
 pub mod settle_pnl_record_event;
 pub mod settle_revenue_to_insurance_fund;
+#[cfg(feature = "events")]
 pub mod signed_msg_order_record_event;
+#[cfg(feature = "events")]
 pub mod spot_interest_record_event;
+#[cfg(feature = "events")]
 pub mod spot_market_vault_deposit_record_event;
+#[cfg(feature = "events")]
 pub mod swap_record_event;
 pub mod sweep_fuel;
 pub mod transfer_deposit;
@@ -425,30 +444,53 @@ pub enum DriftInstruction {
     UpdateHighLeverageModeConfig(update_high_leverage_mode_config::UpdateHighLeverageModeConfig),
     InitializeProtectedMakerModeConfig(initialize_protected_maker_mode_config::InitializeProtectedMakerModeConfig),
     UpdateProtectedMakerModeConfig(update_protected_maker_mode_config::UpdateProtectedMakerModeConfig),
+    #[cfg(feature = "events")]
     NewUserRecordEvent(new_user_record_event::NewUserRecordEvent),
+    #[cfg(feature = "events")]
     DepositRecordEvent(deposit_record_event::DepositRecordEvent),
+    #[cfg(feature = "events")]
     SpotInterestRecordEvent(spot_interest_record_event::SpotInterestRecordEvent),
+    #[cfg(feature = "events")]
     FundingPaymentRecordEvent(funding_payment_record_event::FundingPaymentRecordEvent),
+    #[cfg(feature = "events")]
     FundingRateRecordEvent(funding_rate_record_event::FundingRateRecordEvent),
+    #[cfg(feature = "events")]
     CurveRecordEvent(curve_record_event::CurveRecordEvent),
+    #[cfg(feature = "events")]
     SignedMsgOrderRecordEvent(signed_msg_order_record_event::SignedMsgOrderRecordEvent),
+    #[cfg(feature = "events")]
     OrderRecordEvent(order_record_event::OrderRecordEvent),
+    #[cfg(feature = "events")]
     OrderActionRecordEvent(order_action_record_event::OrderActionRecordEvent),
+    #[cfg(feature = "events")]
     LpRecordEvent(lp_record_event::LpRecordEvent),
+    #[cfg(feature = "events")]
     LiquidationRecordEvent(liquidation_record_event::LiquidationRecordEvent),
+    #[cfg(feature = "events")]
     SettlePnlRecordEvent(settle_pnl_record_event::SettlePnlRecordEvent),
+    #[cfg(feature = "events")]
     InsuranceFundRecordEvent(insurance_fund_record_event::InsuranceFundRecordEvent),
+    #[cfg(feature = "events")]
     InsuranceFundStakeRecordEvent(insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent),
+    #[cfg(feature = "events")]
     SwapRecordEvent(swap_record_event::SwapRecordEvent),
+    #[cfg(feature = "events")]
     SpotMarketVaultDepositRecordEvent(spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent),
+    #[cfg(feature = "events")]
     DeleteUserRecordEvent(delete_user_record_event::DeleteUserRecordEvent),
+    #[cfg(feature = "events")]
     FuelSweepRecordEvent(fuel_sweep_record_event::FuelSweepRecordEvent),
+    #[cfg(feature = "events")]
     FuelSeasonRecordEvent(fuel_season_record_event::FuelSeasonRecordEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for DriftDecoder {
     type InstructionType = DriftInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -654,24 +696,43 @@ impl carbon_core::instruction::InstructionDecoder<'_> for DriftDecoder {
             DriftInstruction::UpdateHighLeverageModeConfig => update_high_leverage_mode_config::UpdateHighLeverageModeConfig,
             DriftInstruction::InitializeProtectedMakerModeConfig => initialize_protected_maker_mode_config::InitializeProtectedMakerModeConfig,
             DriftInstruction::UpdateProtectedMakerModeConfig => update_protected_maker_mode_config::UpdateProtectedMakerModeConfig,
+            #[cfg(feature = "events")]
             DriftInstruction::NewUserRecordEvent => new_user_record_event::NewUserRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::DepositRecordEvent => deposit_record_event::DepositRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::SpotInterestRecordEvent => spot_interest_record_event::SpotInterestRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::FundingPaymentRecordEvent => funding_payment_record_event::FundingPaymentRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::FundingRateRecordEvent => funding_rate_record_event::FundingRateRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::CurveRecordEvent => curve_record_event::CurveRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::SignedMsgOrderRecordEvent => signed_msg_order_record_event::SignedMsgOrderRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::OrderRecordEvent => order_record_event::OrderRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::OrderActionRecordEvent => order_action_record_event::OrderActionRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::LpRecordEvent => lp_record_event::LpRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::LiquidationRecordEvent => liquidation_record_event::LiquidationRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::SettlePnlRecordEvent => settle_pnl_record_event::SettlePnlRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::InsuranceFundRecordEvent => insurance_fund_record_event::InsuranceFundRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::InsuranceFundStakeRecordEvent => insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::SwapRecordEvent => swap_record_event::SwapRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::SpotMarketVaultDepositRecordEvent => spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::DeleteUserRecordEvent => delete_user_record_event::DeleteUserRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::FuelSweepRecordEvent => fuel_sweep_record_event::FuelSweepRecordEvent,
+            #[cfg(feature = "events")]
             DriftInstruction::FuelSeasonRecordEvent => fuel_season_record_event::FuelSeasonRecordEvent,
         )
     }
@@ -1,14 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1decba71db2a3395f9")]
 pub struct NewUserRecordEvent {
     pub ts: i64,
+    #[carbon(serialize_as = "base58")]
     pub user_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub sub_account_id: u16,
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
+    #[carbon(serialize_as = "base58")]
     pub referrer: solana_pubkey::Pubkey,
 }
@@ -1,35 +1,20 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x601f71200ccb079a")]
 pub struct PhoenixFulfillmentConfigStatus {
     pub status: SpotFulfillmentConfigStatus,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PhoenixFulfillmentConfigStatus")]
 pub struct PhoenixFulfillmentConfigStatusInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub phoenix_fulfillment_config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PhoenixFulfillmentConfigStatus {
-    type ArrangedAccounts = PhoenixFulfillmentConfigStatusInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, phoenix_fulfillment_config, admin, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(PhoenixFulfillmentConfigStatusInstructionAccounts {
-            state: state.pubkey,
-            phoenix_fulfillment_config: phoenix_fulfillment_config.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x95750bed2f5f59ed")]
 pub struct PlaceAndMakePerpOrder {
@@ -11,6 +11,8 @@ pub struct PlaceAndMakePerpOrder {
     pub taker_order_id: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlaceAndMakePerpOrder")]
 pub struct PlaceAndMakePerpOrderInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -18,26 +20,5 @@ pub struct PlaceAndMakePerpOrderInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub taker_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakePerpOrder {
-    type ArrangedAccounts = PlaceAndMakePerpOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, taker, taker_stats, authority, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(PlaceAndMakePerpOrderInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            taker: taker.pubkey,
-            taker_stats: taker_stats.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x959e5542ef09f362")]
 pub struct PlaceAndMakeSpotOrder {
@@ -12,6 +12,8 @@ pub struct PlaceAndMakeSpotOrder {
     pub fulfillment_type: Option<SpotFulfillmentType>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlaceAndMakeSpotOrder")]
 pub struct PlaceAndMakeSpotOrderInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -19,26 +21,5 @@ pub struct PlaceAndMakeSpotOrderInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub taker_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakeSpotOrder {
-    type ArrangedAccounts = PlaceAndMakeSpotOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, taker, taker_stats, authority, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(PlaceAndMakeSpotOrderInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            user_stats: user_stats.pubkey,
-            taker: taker.pubkey,
-            taker_stats: taker_stats.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
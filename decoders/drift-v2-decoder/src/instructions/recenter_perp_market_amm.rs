@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x18570a73a5be508b")]
 pub struct RecenterPerpMarketAmm {
@@ -9,26 +9,11 @@ pub struct RecenterPerpMarketAmm {
     pub sqrt_k: u128,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RecenterPerpMarketAmm")]
 pub struct RecenterPerpMarketAmmInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RecenterPerpMarketAmm {
-    type ArrangedAccounts = RecenterPerpMarketAmmInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RecenterPerpMarketAmmInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x53fefd893b7a449c")]
 pub struct RemovePerpLpSharesInExpiringMarket {
@@ -9,24 +9,10 @@ pub struct RemovePerpLpSharesInExpiringMarket {
     pub market_index: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RemovePerpLpSharesInExpiringMarket")]
 pub struct RemovePerpLpSharesInExpiringMarketInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RemovePerpLpSharesInExpiringMarket {
-    type ArrangedAccounts = RemovePerpLpSharesInExpiringMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RemovePerpLpSharesInExpiringMarketInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
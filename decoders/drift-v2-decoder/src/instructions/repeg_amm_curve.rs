@@ -1,35 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x03246659b48078d5")]
 pub struct RepegAmmCurve {
     pub new_peg_candidate: u128,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RepegAmmCurve")]
 pub struct RepegAmmCurveInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RepegAmmCurve {
-    type ArrangedAccounts = RepegAmmCurveInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, oracle, admin, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RepegAmmCurveInstructionAccounts {
-            state: state.pubkey,
-            perp_market: perp_market.pubkey,
-            oracle: oracle.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8e46cc5c496ab434")]
 pub struct RequestRemoveInsuranceFundStake {
@@ -9,32 +9,13 @@ pub struct RequestRemoveInsuranceFundStake {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RequestRemoveInsuranceFundStake")]
 pub struct RequestRemoveInsuranceFundStakeInstructionAccounts {
     pub spot_market: solana_pubkey::Pubkey,
     pub insurance_fund_stake: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RequestRemoveInsuranceFundStake {
-    type ArrangedAccounts = RequestRemoveInsuranceFundStakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RequestRemoveInsuranceFundStakeInstructionAccounts {
-            spot_market: spot_market.pubkey,
-            insurance_fund_stake: insurance_fund_stake.pubkey,
-            user_stats: user_stats.pubkey,
-            authority: authority.pubkey,
-            insurance_fund_vault: insurance_fund_vault.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
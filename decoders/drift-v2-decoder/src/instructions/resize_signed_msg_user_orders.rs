@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x890a579612734fa8")]
 pub struct ResizeSignedMsgUserOrders {
     pub num_orders: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ResizeSignedMsgUserOrders")]
 pub struct ResizeSignedMsgUserOrdersInstructionAccounts {
     pub signed_msg_user_orders: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ResizeSignedMsgUserOrders {
-    type ArrangedAccounts = ResizeSignedMsgUserOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [signed_msg_user_orders, authority, system_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ResizeSignedMsgUserOrdersInstructionAccounts {
-            signed_msg_user_orders: signed_msg_user_orders.pubkey,
-            authority: authority.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xeceeb045ef0ab5c1")]
 pub struct RevertFill {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RevertFill")]
 pub struct RevertFillInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub filler: solana_pubkey::Pubkey,
     pub filler_stats: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RevertFill {
-    type ArrangedAccounts = RevertFillInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, filler_stats, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RevertFillInstructionAccounts {
-            state: state.pubkey,
-            authority: authority.pubkey,
-            filler: filler.pubkey,
-            filler_stats: filler_stats.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,17 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dd3c519128e56711b")]
 pub struct SignedMsgOrderRecordEvent {
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub hash: String,
     pub matching_order_params: OrderParams,
     pub user_order_id: u32,
     pub signed_msg_order_max_slot: u64,
+    #[carbon(serialize_as = "hex")]
     pub signed_msg_order_uuid: [u8; 8],
     pub ts: i64,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xc5679a196b5a3c5e")]
 pub struct TransferPools {
@@ -13,6 +13,8 @@ pub struct TransferPools {
     pub borrow_amount: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TransferPools")]
 pub struct TransferPoolsInstructionAccounts {
     pub from_user: solana_pubkey::Pubkey,
     pub to_user: solana_pubkey::Pubkey,
@@ -24,31 +26,5 @@ pub struct TransferPoolsInstructionAccounts {
     pub borrow_from_spot_market_vault: solana_pubkey::Pubkey,
     pub borrow_to_spot_market_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for TransferPools {
-    type ArrangedAccounts = TransferPoolsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [from_user, to_user, user_stats, authority, state, deposit_from_spot_market_vault, deposit_to_spot_market_vault, borrow_from_spot_market_vault, borrow_to_spot_market_vault, drift_signer, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(TransferPoolsInstructionAccounts {
-            from_user: from_user.pubkey,
-            to_user: to_user.pubkey,
-            user_stats: user_stats.pubkey,
-            authority: authority.pubkey,
-            state: state.pubkey,
-            deposit_from_spot_market_vault: deposit_from_spot_market_vault.pubkey,
-            deposit_to_spot_market_vault: deposit_to_spot_market_vault.pubkey,
-            borrow_from_spot_market_vault: borrow_from_spot_market_vault.pubkey,
-            borrow_to_spot_market_vault: borrow_to_spot_market_vault.pubkey,
-            drift_signer: drift_signer.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
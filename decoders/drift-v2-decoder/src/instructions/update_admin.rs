@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa1b028d53cb8b3e4")]
 pub struct UpdateAdmin {
+    #[carbon(serialize_as = "base58")]
     pub admin: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateAdmin")]
 pub struct UpdateAdminInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
-    type ArrangedAccounts = UpdateAdminInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateAdminInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x20fc7ad3421f2ff1")]
 pub struct UpdateDiscountMint {
+    #[carbon(serialize_as = "base58")]
     pub discount_mint: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateDiscountMint")]
 pub struct UpdateDiscountMintInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateDiscountMint {
-    type ArrangedAccounts = UpdateDiscountMintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateDiscountMintInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x53a0fcfa817431df")]
 pub struct UpdateExchangeStatus {
     pub exchange_status: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateExchangeStatus")]
 pub struct UpdateExchangeStatusInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateExchangeStatus {
-    type ArrangedAccounts = UpdateExchangeStatusInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateExchangeStatusInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2c452be2ccdfca34")]
 pub struct UpdateInsuranceFundUnstakingPeriod {
     pub insurance_fund_unstaking_period: i64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateInsuranceFundUnstakingPeriod")]
 pub struct UpdateInsuranceFundUnstakingPeriodInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateInsuranceFundUnstakingPeriod {
-    type ArrangedAccounts = UpdateInsuranceFundUnstakingPeriodInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateInsuranceFundUnstakingPeriodInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x3e5744731d9696a5")]
 pub struct UpdatePerpMarketTargetBaseAssetAmountPerLp {
@@ -12,6 +12,7 @@ pub struct UpdatePerpMarketTargetBaseAssetAmountPerLpInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketTargetBaseAssetAmountPerLp {
@@ -20,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketTargetBaseAss
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
+        let [admin, state, perp_market, remaining_accounts @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketTargetBaseAss
                 admin: admin.pubkey,
                 state: state.pubkey,
                 perp_market: perp_market.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
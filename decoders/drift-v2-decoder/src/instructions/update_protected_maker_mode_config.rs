@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x56a6ebfd43cadf11")]
 pub struct UpdateProtectedMakerModeConfig {
@@ -9,26 +9,11 @@ pub struct UpdateProtectedMakerModeConfig {
     pub reduce_only: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateProtectedMakerModeConfig")]
 pub struct UpdateProtectedMakerModeConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub protected_maker_mode_config: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateProtectedMakerModeConfig {
-    type ArrangedAccounts = UpdateProtectedMakerModeConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protected_maker_mode_config, state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateProtectedMakerModeConfigInstructionAccounts {
-            admin: admin.pubkey,
-            protected_maker_mode_config: protected_maker_mode_config.pubkey,
-            state: state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
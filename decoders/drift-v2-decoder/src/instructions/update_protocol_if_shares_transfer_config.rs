@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x22872f5bdc18d435")]
 pub struct UpdateProtocolIfSharesTransferConfig {
@@ -9,26 +9,11 @@ pub struct UpdateProtocolIfSharesTransferConfig {
     pub max_transfer_per_epoch: Option<u128>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateProtocolIfSharesTransferConfig")]
 pub struct UpdateProtocolIfSharesTransferConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub protocol_if_shares_transfer_config: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateProtocolIfSharesTransferConfig {
-    type ArrangedAccounts = UpdateProtocolIfSharesTransferConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protocol_if_shares_transfer_config, state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateProtocolIfSharesTransferConfigInstructionAccounts {
-            admin: admin.pubkey,
-            protocol_if_shares_transfer_config: protocol_if_shares_transfer_config.pubkey,
-            state: state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xdb08f660a9795b6e")]
 pub struct UpdateSerumVault {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateSerumVault")]
 pub struct UpdateSerumVaultInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub srm_vault: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumVault {
-    type ArrangedAccounts = UpdateSerumVaultInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, srm_vault, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateSerumVaultInstructionAccounts {
-            state: state.pubkey,
-            admin: admin.pubkey,
-            srm_vault: srm_vault.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
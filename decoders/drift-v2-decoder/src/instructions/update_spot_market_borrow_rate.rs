@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x47efec99d23efe4c")]
 pub struct UpdateSpotMarketBorrowRate {
@@ -11,26 +11,11 @@ pub struct UpdateSpotMarketBorrowRate {
     pub min_borrow_rate: Option<u8>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateSpotMarketBorrowRate")]
 pub struct UpdateSpotMarketBorrowRateInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketBorrowRate {
-    type ArrangedAccounts = UpdateSpotMarketBorrowRateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateSpotMarketBorrowRateInstructionAccounts {
-            admin: admin.pubkey,
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
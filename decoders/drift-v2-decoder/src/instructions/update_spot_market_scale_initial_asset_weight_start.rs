@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd9cccc76cc82e193")]
 pub struct UpdateSpotMarketScaleInitialAssetWeightStart {
@@ -12,6 +12,7 @@ pub struct UpdateSpotMarketScaleInitialAssetWeightStartInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketScaleInitialAssetWeightStart {
@@ -20,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketScaleInitialA
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, _remaining @ ..] = accounts else {
+        let [admin, state, spot_market, remaining_accounts @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketScaleInitialA
                 admin: admin.pubkey,
                 state: state.pubkey,
                 spot_market: spot_market.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
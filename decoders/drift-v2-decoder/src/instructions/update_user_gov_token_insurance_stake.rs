@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8f63ebbb149fb854")]
 pub struct UpdateUserGovTokenInsuranceStake {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateUserGovTokenInsuranceStake")]
 pub struct UpdateUserGovTokenInsuranceStakeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct UpdateUserGovTokenInsuranceStakeInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceStake {
-    type ArrangedAccounts = UpdateUserGovTokenInsuranceStakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, insurance_fund_stake, user_stats, signer, insurance_fund_vault, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(UpdateUserGovTokenInsuranceStakeInstructionAccounts {
-            state: state.pubkey,
-            spot_market: spot_market.pubkey,
-            insurance_fund_stake: insurance_fund_stake.pubkey,
-            user_stats: user_stats.pubkey,
-            signer: signer.pubkey,
-            insurance_fund_vault: insurance_fund_vault.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
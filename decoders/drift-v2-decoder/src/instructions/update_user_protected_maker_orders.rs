@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x72277bc6bb195adb")]
 pub struct UpdateUserProtectedMakerOrders {
@@ -9,29 +9,12 @@ pub struct UpdateUserProtectedMakerOrders {
     pub protected_maker_orders: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateUserProtectedMakerOrders")]
 pub struct UpdateUserProtectedMakerOrdersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub protected_maker_mode_config: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateUserProtectedMakerOrders {
-    type ArrangedAccounts = UpdateUserProtectedMakerOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, protected_maker_mode_config, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(UpdateUserProtectedMakerOrdersInstructionAccounts {
-            state: state.pubkey,
-            user: user.pubkey,
-            authority: authority.pubkey,
-            protected_maker_mode_config: protected_maker_mode_config.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,12 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AMM {
+    #[carbon(serialize_as = "base58")]
     pub oracle: solana_pubkey::Pubkey,
     pub historical_oracle_data: HistoricalOracleData,
     pub base_asset_amount_per_lp: i128,
@@ -89,5 +90,6 @@ pub struct AMM {
     pub net_unsettled_funding_pnl: i64,
     pub quote_asset_amount_with_unsettled_lp: i64,
     pub reference_price_offset: i32,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 12],
 }
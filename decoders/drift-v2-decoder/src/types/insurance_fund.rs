@@ -1,9 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct InsuranceFund {
+    #[carbon(serialize_as = "base58")]
     pub vault: solana_pubkey::Pubkey,
     pub total_shares: u128,
     pub user_shares: u128,
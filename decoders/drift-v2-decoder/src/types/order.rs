@@ -1,9 +1,9 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Order {
     pub slot: u64,
@@ -30,5 +30,6 @@ pub struct Order {
     pub trigger_condition: OrderTriggerCondition,
     pub auction_duration: u8,
     pub posted_slot_tail: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 2],
 }
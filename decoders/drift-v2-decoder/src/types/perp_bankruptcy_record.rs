@@ -1,12 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct PerpBankruptcyRecord {
     pub market_index: u16,
     pub pnl: i128,
     pub if_payment: u128,
+    #[carbon(serialize_as = "base58")]
     pub clawback_user: Option<solana_pubkey::Pubkey>,
     pub clawback_user_payment: Option<u128>,
     pub cumulative_funding_rate_delta: i128,
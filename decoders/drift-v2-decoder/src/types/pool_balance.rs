@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct PoolBalance {
     pub scaled_balance: u128,
     pub market_index: u16,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 6],
 }
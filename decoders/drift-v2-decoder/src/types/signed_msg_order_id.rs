@@ -1,9 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SignedMsgOrderId {
+    #[carbon(serialize_as = "hex")]
     pub uuid: [u8; 8],
     pub max_slot: u64,
     pub order_id: u32,
@@ -1,9 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SignedMsgUserOrdersFixed {
+    #[carbon(serialize_as = "base58")]
     pub user_pubkey: solana_pubkey::Pubkey,
     pub padding: u32,
     pub len: u32,
@@ -1,9 +1,9 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SpotPosition {
     pub scaled_balance: u64,
@@ -13,5 +13,6 @@ pub struct SpotPosition {
     pub market_index: u16,
     pub balance_type: SpotBalanceType,
     pub open_orders: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 4],
 }
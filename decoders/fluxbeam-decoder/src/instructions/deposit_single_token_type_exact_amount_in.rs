@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x04")]
 pub struct DepositSingleTokenTypeExactAmountIn {
@@ -9,6 +9,8 @@ pub struct DepositSingleTokenTypeExactAmountIn {
     pub minimum_pool_token_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositSingleTokenTypeExactAmountIn")]
 pub struct DepositSingleTokenTypeExactAmountInInstructionAccounts {
     pub swap: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -21,32 +23,5 @@ pub struct DepositSingleTokenTypeExactAmountInInstructionAccounts {
     pub source_mint: solana_pubkey::Pubkey,
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositSingleTokenTypeExactAmountIn {
-    type ArrangedAccounts = DepositSingleTokenTypeExactAmountInInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, source_token, swap_token_a, swap_token_b, pool_mint, destination, source_mint, token_a_program, token_b_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositSingleTokenTypeExactAmountInInstructionAccounts {
-            swap: swap.pubkey,
-            authority: authority.pubkey,
-            user_transfer_authority: user_transfer_authority.pubkey,
-            source_token: source_token.pubkey,
-            swap_token_a: swap_token_a.pubkey,
-            swap_token_b: swap_token_b.pubkey,
-            pool_mint: pool_mint.pubkey,
-            destination: destination.pubkey,
-            source_mint: source_mint.pubkey,
-            token_a_program: token_a_program.pubkey,
-            token_b_program: token_b_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -34,6 +34,10 @@ pub enum FluxbeamInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for FluxbeamDecoder {
     type InstructionType = FluxbeamInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -156,6 +160,7 @@ mod tests {
             token_program: solana_pubkey::Pubkey::from_str_const(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -321,6 +326,7 @@ mod tests {
             swap_program: solana_pubkey::Pubkey::from_str_const(
                 "396TeW1MeyQvFGgxjaxJxRFkuiir4Ye4imuxVDcqfE88",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -489,6 +495,7 @@ mod tests {
                 token_b_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
                 ),
+                remaining_accounts: vec![],
             };
 
         // Act
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01")]
 pub struct Swap {
@@ -9,7 +9,8 @@ pub struct Swap {
     pub minimum_amount_out: u64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Swap")]
 pub struct SwapInstructionAccounts {
     pub swap: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -26,36 +27,5 @@ pub struct SwapInstructionAccounts {
     pub destination_token_program: solana_pubkey::Pubkey,
     pub pool_token_program: solana_pubkey::Pubkey,
     pub swap_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Swap {
-    type ArrangedAccounts = SwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, source, swap_source, swap_destination, destination, pool_mint, pool_fee, source_mint, destination_mint, source_token_program, destination_token_program, pool_token_program, swap_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapInstructionAccounts {
-            swap: swap.pubkey,
-            authority: authority.pubkey,
-            user_transfer_authority: user_transfer_authority.pubkey,
-            source: source.pubkey,
-            swap_source: swap_source.pubkey,
-            swap_destination: swap_destination.pubkey,
-            destination: destination.pubkey,
-            pool_mint: pool_mint.pubkey,
-            pool_fee: pool_fee.pubkey,
-            source_mint: source_mint.pubkey,
-            destination_mint: destination_mint.pubkey,
-            source_token_program: source_token_program.pubkey,
-            destination_token_program: destination_token_program.pubkey,
-            pool_token_program: pool_token_program.pubkey,
-            swap_program: swap_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x03")]
 pub struct WithdrawAllTokenTypes {
@@ -10,7 +10,8 @@ pub struct WithdrawAllTokenTypes {
     pub minimum_token_b_amount: u64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "WithdrawAllTokenTypes")]
 pub struct WithdrawAllTokenTypesInstructionAccounts {
     pub swap: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -27,36 +28,5 @@ pub struct WithdrawAllTokenTypesInstructionAccounts {
     pub pool_token_program: solana_pubkey::Pubkey,
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
-    type ArrangedAccounts = WithdrawAllTokenTypesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, pool_mint, source, swap_token_a, swap_token_b, destination_token_a, destination_token_b, fee_account, token_a_mint, token_b_mint, pool_token_program, token_a_program, token_b_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawAllTokenTypesInstructionAccounts {
-            swap: swap.pubkey,
-            authority: authority.pubkey,
-            user_transfer_authority: user_transfer_authority.pubkey,
-            pool_mint: pool_mint.pubkey,
-            source: source.pubkey,
-            swap_token_a: swap_token_a.pubkey,
-            swap_token_b: swap_token_b.pubkey,
-            destination_token_a: destination_token_a.pubkey,
-            destination_token_b: destination_token_b.pubkey,
-            fee_account: fee_account.pubkey,
-            token_a_mint: token_a_mint.pubkey,
-            token_b_mint: token_b_mint.pubkey,
-            pool_token_program: pool_token_program.pubkey,
-            token_a_program: token_a_program.pubkey,
-            token_b_program: token_b_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -0,0 +1,64 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::GooseFxGammaDecoder;
+pub mod pool_state;
+
+pub enum GooseFxGammaAccount {
+    PoolState(pool_state::PoolState),
+}
+
+impl AccountDecoder<'_> for GooseFxGammaDecoder {
+    type AccountType = GooseFxGammaAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = pool_state::PoolState::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: GooseFxGammaAccount::PoolState(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_pool_state_account() {
+        // Act
+        let decoder = GooseFxGammaDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/pool_state_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            GooseFxGammaAccount::PoolState(pool_state) => {
+                assert_eq!(
+                    pool_state.token0_mint,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "So11111111111111111111111111111111111111112"
+                    )
+                );
+                assert_eq!(pool_state.mint1_decimals, 6);
+                assert_eq!(pool_state.lp_supply, 1_000_000_000_000);
+                assert_eq!(pool_state.open_time, 1_720_000_000);
+            }
+        }
+    }
+}
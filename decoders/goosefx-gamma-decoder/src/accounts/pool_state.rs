@@ -0,0 +1,49 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0xf7ede3f5d7c3de46",
+    deserialize_with = "deserialize_pool_state"
+)]
+pub struct PoolState {
+    #[carbon(serialize_as = "base58")]
+    pub amm_config: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub pool_creator: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token0_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token1_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub lp_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token0_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token1_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token0_program: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token1_program: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub observation_key: solana_pubkey::Pubkey,
+    pub auth_bump: u8,
+    /// Bit flags disabling deposits, withdrawals and swaps.
+    pub status: u8,
+    pub lp_mint_decimals: u8,
+    pub mint0_decimals: u8,
+    pub mint1_decimals: u8,
+    pub lp_supply: u64,
+    pub protocol_fees_token0: u64,
+    pub protocol_fees_token1: u64,
+    pub fund_fees_token0: u64,
+    pub fund_fees_token1: u64,
+    pub open_time: u64,
+}
+
+/// PoolState accounts hold more fields after the decoded ones, such as
+/// the oracle observations and padding, which are ignored instead of rejected.
+fn deserialize_pool_state(data: &[u8]) -> Option<PoolState> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
@@ -0,0 +1,206 @@
+use crate::PROGRAM_ID;
+
+use super::GooseFxGammaDecoder;
+pub mod deposit;
+pub mod swap_base_input;
+pub mod swap_base_output;
+pub mod withdraw;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum GooseFxGammaInstruction {
+    SwapBaseInput(swap_base_input::SwapBaseInput),
+    SwapBaseOutput(swap_base_output::SwapBaseOutput),
+    Deposit(deposit::Deposit),
+    Withdraw(withdraw::Withdraw),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for GooseFxGammaDecoder {
+    type InstructionType = GooseFxGammaInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            GooseFxGammaInstruction::SwapBaseInput => swap_base_input::SwapBaseInput,
+            GooseFxGammaInstruction::SwapBaseOutput => swap_base_output::SwapBaseOutput,
+            GooseFxGammaInstruction::Deposit => deposit::Deposit,
+            GooseFxGammaInstruction::Withdraw => withdraw::Withdraw,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_swap_base_input() {
+        // Arrange
+        let expected_ix = GooseFxGammaInstruction::SwapBaseInput(swap_base_input::SwapBaseInput {
+            amount_in: 1_000_000_000,
+            minimum_amount_out: 150_000_000,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5HqgiEaXw9bpMEAEm2nnrwAua79oVjT7cVkymJHs1MCu",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "J1t2RGeDfA8mdV2HZWMMfxLVJaFqPxxTgzoWNDDygWnk",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "sai49KC6DL5MizrqwJT7LkaSNsfcdjrgcmmZBXvaHiq",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5ESgmVYfhAGfj4DqbzxFHUuqhDwhDWCEVR5t3YKbusJk",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "HuV1TY1oufUw6qMEtMJLxbr6vxYcsKfU4i5rcLmP5TUv",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "DqhaTR8SfsfGWHvJxpTsncAZCoZFFJPRqvxSwEnpSAx2",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9fGPdKVRXKvQrNNHVgLiT7GTb66xmGodfKqhAbJVwNMT",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2bqLRHca9E1zsXqUVuDWbWWAbm3CH5zzAd1dVdbwW75U",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "So11111111111111111111111111111111111111112",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "Fob1W9AEe66M5DSQEteHjfhdNAwAShvYi1KqfBprg5e4",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = swap_base_input::SwapBaseInputInstructionAccounts {
+            payer: solana_pubkey::Pubkey::from_str_const(
+                "5HqgiEaXw9bpMEAEm2nnrwAua79oVjT7cVkymJHs1MCu",
+            ),
+            authority: solana_pubkey::Pubkey::from_str_const(
+                "J1t2RGeDfA8mdV2HZWMMfxLVJaFqPxxTgzoWNDDygWnk",
+            ),
+            amm_config: solana_pubkey::Pubkey::from_str_const(
+                "sai49KC6DL5MizrqwJT7LkaSNsfcdjrgcmmZBXvaHiq",
+            ),
+            pool_state: solana_pubkey::Pubkey::from_str_const(
+                "5ESgmVYfhAGfj4DqbzxFHUuqhDwhDWCEVR5t3YKbusJk",
+            ),
+            input_token_account: solana_pubkey::Pubkey::from_str_const(
+                "HuV1TY1oufUw6qMEtMJLxbr6vxYcsKfU4i5rcLmP5TUv",
+            ),
+            output_token_account: solana_pubkey::Pubkey::from_str_const(
+                "DqhaTR8SfsfGWHvJxpTsncAZCoZFFJPRqvxSwEnpSAx2",
+            ),
+            input_vault: solana_pubkey::Pubkey::from_str_const(
+                "9fGPdKVRXKvQrNNHVgLiT7GTb66xmGodfKqhAbJVwNMT",
+            ),
+            output_vault: solana_pubkey::Pubkey::from_str_const(
+                "2bqLRHca9E1zsXqUVuDWbWWAbm3CH5zzAd1dVdbwW75U",
+            ),
+            input_token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            output_token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            input_token_mint: solana_pubkey::Pubkey::from_str_const(
+                "So11111111111111111111111111111111111111112",
+            ),
+            output_token_mint: solana_pubkey::Pubkey::from_str_const(
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            ),
+            observation_state: solana_pubkey::Pubkey::from_str_const(
+                "Fob1W9AEe66M5DSQEteHjfhdNAwAShvYi1KqfBprg5e4",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = GooseFxGammaDecoder;
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/swap_base_input_ix.json")
+                .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            swap_base_input::SwapBaseInput::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,29 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x8fbe5adac41e33de")]
+pub struct SwapBaseInput {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "SwapBaseInput")]
+pub struct SwapBaseInputInstructionAccounts {
+    pub payer: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub amm_config: solana_pubkey::Pubkey,
+    pub pool_state: solana_pubkey::Pubkey,
+    pub input_token_account: solana_pubkey::Pubkey,
+    pub output_token_account: solana_pubkey::Pubkey,
+    pub input_vault: solana_pubkey::Pubkey,
+    pub output_vault: solana_pubkey::Pubkey,
+    pub input_token_program: solana_pubkey::Pubkey,
+    pub output_token_program: solana_pubkey::Pubkey,
+    pub input_token_mint: solana_pubkey::Pubkey,
+    pub output_token_mint: solana_pubkey::Pubkey,
+    pub observation_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,14 @@
+#![no_std]
+
+extern crate alloc;
+use solana_pubkey::Pubkey;
+
+pub struct GooseFxGammaDecoder;
+
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("GAMMA7meSFWaBXF25oSUgmGRwaW6sCMFLmBNiMSdbHVT");
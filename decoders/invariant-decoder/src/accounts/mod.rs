@@ -0,0 +1,64 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::InvariantDecoder;
+pub mod pool;
+
+pub enum InvariantAccount {
+    Pool(pool::Pool),
+}
+
+impl AccountDecoder<'_> for InvariantDecoder {
+    type AccountType = InvariantAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = pool::Pool::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: InvariantAccount::Pool(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_pool_account() {
+        // Act
+        let decoder = InvariantDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/pool_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            InvariantAccount::Pool(pool) => {
+                assert_eq!(
+                    pool.token_x,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "So11111111111111111111111111111111111111112"
+                    )
+                );
+                assert_eq!(pool.tick_spacing, 10);
+                assert_eq!(pool.current_tick_index, -276_324);
+                assert_eq!(pool.fee_protocol_token_x, 42);
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0xf19a6d0411b16dbc",
+    deserialize_with = "deserialize_pool"
+)]
+pub struct Pool {
+    #[carbon(serialize_as = "base58")]
+    pub token_x: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_y: solana_pubkey::Pubkey,
+    /// The reserve token account of token X.
+    #[carbon(serialize_as = "base58")]
+    pub token_x_reserve: solana_pubkey::Pubkey,
+    /// The reserve token account of token Y.
+    #[carbon(serialize_as = "base58")]
+    pub token_y_reserve: solana_pubkey::Pubkey,
+    pub position_iterator: u128,
+    pub tick_spacing: u16,
+    /// The swap fee, as a decimal with 12 digits.
+    pub fee: u128,
+    pub protocol_fee: u128,
+    pub liquidity: u128,
+    /// The square root of the price, as a decimal with 24 digits.
+    pub sqrt_price: u128,
+    pub current_tick_index: i32,
+    #[carbon(serialize_as = "base58")]
+    pub tickmap: solana_pubkey::Pubkey,
+    pub fee_growth_global_x: u128,
+    pub fee_growth_global_y: u128,
+    pub fee_protocol_token_x: u64,
+    pub fee_protocol_token_y: u64,
+    pub seconds_per_liquidity_global: u128,
+    pub start_timestamp: u64,
+    pub last_timestamp: u64,
+}
+
+/// Pool accounts hold more fields after the decoded ones, such as
+/// the oracle and fee receiver, which are ignored instead of rejected.
+fn deserialize_pool(data: &[u8]) -> Option<Pool> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
@@ -0,0 +1,39 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x30d7c59960cbb485")]
+pub struct CreatePosition {
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub liquidity_delta: u128,
+    pub slippage_limit_lower: u128,
+    pub slippage_limit_upper: u128,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "CreatePosition")]
+pub struct CreatePositionInstructionAccounts {
+    pub state: solana_pubkey::Pubkey,
+    pub position: solana_pubkey::Pubkey,
+    pub pool: solana_pubkey::Pubkey,
+    pub position_list: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub lower_tick: solana_pubkey::Pubkey,
+    pub upper_tick: solana_pubkey::Pubkey,
+    pub tickmap: solana_pubkey::Pubkey,
+    pub token_x: solana_pubkey::Pubkey,
+    pub token_y: solana_pubkey::Pubkey,
+    pub account_x: solana_pubkey::Pubkey,
+    pub account_y: solana_pubkey::Pubkey,
+    pub reserve_x: solana_pubkey::Pubkey,
+    pub reserve_y: solana_pubkey::Pubkey,
+    pub program_authority: solana_pubkey::Pubkey,
+    pub token_x_program: solana_pubkey::Pubkey,
+    pub token_y_program: solana_pubkey::Pubkey,
+    pub rent: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
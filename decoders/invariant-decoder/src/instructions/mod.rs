@@ -0,0 +1,206 @@
+use crate::PROGRAM_ID;
+
+use super::InvariantDecoder;
+pub mod claim_fee;
+pub mod create_position;
+pub mod remove_position;
+pub mod swap;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum InvariantInstruction {
+    Swap(swap::Swap),
+    CreatePosition(create_position::CreatePosition),
+    RemovePosition(remove_position::RemovePosition),
+    ClaimFee(claim_fee::ClaimFee),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for InvariantDecoder {
+    type InstructionType = InvariantInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            InvariantInstruction::Swap => swap::Swap,
+            InvariantInstruction::CreatePosition => create_position::CreatePosition,
+            InvariantInstruction::RemovePosition => remove_position::RemovePosition,
+            InvariantInstruction::ClaimFee => claim_fee::ClaimFee,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_swap() {
+        // Arrange
+        let expected_ix = InvariantInstruction::Swap(swap::Swap {
+            x_to_y: true,
+            amount: 5_000_000,
+            by_amount_in: true,
+            sqrt_price_limit: 4_295_048_016,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "GeqYQXBeh1Q7sDc1iK6fUoi9oU2RZ48eBm43WEZnK3mD",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "6Vd37J4ijhgyLjSagHHPgLBvTk5zH3LYwsgYe5wsGjcZ",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "DLGN91rTwvxs8s4kUPUccAkB3y5CWWztYj14Z2uDeMY9",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "So11111111111111111111111111111111111111112",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "8EEb4Mva5cDJhpxxBJdxSAkNTQCbruoamZ3HU8W82h2j",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9mmQzQZSN74BnYj8XiFbrW33YGsaXXi2CXvxzaPaai17",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5jtFMhci6SfQ5hgM5TX1tkfFvoL8QA2vAVy9g2gjwfvy",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "jRegUVqoz5dXsPeUuwSBESF18S5uWngwdNLp3grz22g",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2Scu9MDxvL2hGgrnhzXXzjcW5fEoCeXv1htwnuKMR5Yx",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7d94JNgHtHEnmoGJUEgiPuWTC2DrHnsm76awffVPt92Y",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = swap::SwapInstructionAccounts {
+            state: solana_pubkey::Pubkey::from_str_const(
+                "GeqYQXBeh1Q7sDc1iK6fUoi9oU2RZ48eBm43WEZnK3mD",
+            ),
+            pool: solana_pubkey::Pubkey::from_str_const(
+                "6Vd37J4ijhgyLjSagHHPgLBvTk5zH3LYwsgYe5wsGjcZ",
+            ),
+            tickmap: solana_pubkey::Pubkey::from_str_const(
+                "DLGN91rTwvxs8s4kUPUccAkB3y5CWWztYj14Z2uDeMY9",
+            ),
+            token_x: solana_pubkey::Pubkey::from_str_const(
+                "So11111111111111111111111111111111111111112",
+            ),
+            token_y: solana_pubkey::Pubkey::from_str_const(
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            ),
+            account_x: solana_pubkey::Pubkey::from_str_const(
+                "8EEb4Mva5cDJhpxxBJdxSAkNTQCbruoamZ3HU8W82h2j",
+            ),
+            account_y: solana_pubkey::Pubkey::from_str_const(
+                "9mmQzQZSN74BnYj8XiFbrW33YGsaXXi2CXvxzaPaai17",
+            ),
+            reserve_x: solana_pubkey::Pubkey::from_str_const(
+                "5jtFMhci6SfQ5hgM5TX1tkfFvoL8QA2vAVy9g2gjwfvy",
+            ),
+            reserve_y: solana_pubkey::Pubkey::from_str_const(
+                "jRegUVqoz5dXsPeUuwSBESF18S5uWngwdNLp3grz22g",
+            ),
+            owner: solana_pubkey::Pubkey::from_str_const(
+                "2Scu9MDxvL2hGgrnhzXXzjcW5fEoCeXv1htwnuKMR5Yx",
+            ),
+            program_authority: solana_pubkey::Pubkey::from_str_const(
+                "7d94JNgHtHEnmoGJUEgiPuWTC2DrHnsm76awffVPt92Y",
+            ),
+            token_x_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            token_y_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = InvariantDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/swap_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            swap::Swap::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,35 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xdb18ec6e8a508106")]
+pub struct RemovePosition {
+    pub index: u32,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "RemovePosition")]
+pub struct RemovePositionInstructionAccounts {
+    pub state: solana_pubkey::Pubkey,
+    pub removed_position: solana_pubkey::Pubkey,
+    pub position_list: solana_pubkey::Pubkey,
+    pub last_position: solana_pubkey::Pubkey,
+    pub pool: solana_pubkey::Pubkey,
+    pub tickmap: solana_pubkey::Pubkey,
+    pub lower_tick: solana_pubkey::Pubkey,
+    pub upper_tick: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub token_x: solana_pubkey::Pubkey,
+    pub token_y: solana_pubkey::Pubkey,
+    pub account_x: solana_pubkey::Pubkey,
+    pub account_y: solana_pubkey::Pubkey,
+    pub reserve_x: solana_pubkey::Pubkey,
+    pub reserve_y: solana_pubkey::Pubkey,
+    pub program_authority: solana_pubkey::Pubkey,
+    pub token_x_program: solana_pubkey::Pubkey,
+    pub token_y_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,31 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xf8c69e91e17587c8")]
+pub struct Swap {
+    pub x_to_y: bool,
+    pub amount: u64,
+    pub by_amount_in: bool,
+    pub sqrt_price_limit: u128,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Swap")]
+pub struct SwapInstructionAccounts {
+    pub state: solana_pubkey::Pubkey,
+    pub pool: solana_pubkey::Pubkey,
+    pub tickmap: solana_pubkey::Pubkey,
+    pub token_x: solana_pubkey::Pubkey,
+    pub token_y: solana_pubkey::Pubkey,
+    pub account_x: solana_pubkey::Pubkey,
+    pub account_y: solana_pubkey::Pubkey,
+    pub reserve_x: solana_pubkey::Pubkey,
+    pub reserve_y: solana_pubkey::Pubkey,
+    pub owner: solana_pubkey::Pubkey,
+    pub program_authority: solana_pubkey::Pubkey,
+    pub token_x_program: solana_pubkey::Pubkey,
+    pub token_y_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
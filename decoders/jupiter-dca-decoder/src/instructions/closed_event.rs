@@ -1,14 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d321f579b87dcc3ef")]
 pub struct ClosedEvent {
+    #[carbon(serialize_as = "base58")]
     pub user_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub dca_key: solana_pubkey::Pubkey,
     pub in_deposited: u64,
+    #[carbon(serialize_as = "base58")]
     pub input_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub output_mint: solana_pubkey::Pubkey,
     pub cycle_frequency: i64,
     pub in_amount_per_cycle: u64,
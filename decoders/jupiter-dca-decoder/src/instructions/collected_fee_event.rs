@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d2a88d874b5d16db5")]
 pub struct CollectedFeeEvent {
+    #[carbon(serialize_as = "base58")]
     pub user_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub dca_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub amount: u64,
 }
@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d3ecdf2aff4a98834")]
 pub struct DepositEvent {
+    #[carbon(serialize_as = "base58")]
     pub dca_key: solana_pubkey::Pubkey,
     pub amount: u64,
 }
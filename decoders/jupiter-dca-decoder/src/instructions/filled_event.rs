@@ -1,16 +1,21 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d8604113fdd2db1ad")]
 pub struct FilledEvent {
+    #[carbon(serialize_as = "base58")]
     pub user_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub dca_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub input_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub output_mint: solana_pubkey::Pubkey,
     pub in_amount: u64,
     pub out_amount: u64,
+    #[carbon(serialize_as = "base58")]
     pub fee_mint: solana_pubkey::Pubkey,
     pub fee: u64,
 }
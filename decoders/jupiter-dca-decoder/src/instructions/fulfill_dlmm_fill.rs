@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01e676fb2db165bb")]
 pub struct FulfillDlmmFill {
     pub repay_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FulfillDlmmFill")]
 pub struct FulfillDlmmFillInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub dca: solana_pubkey::Pubkey,
@@ -24,36 +26,5 @@ pub struct FulfillDlmmFillInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FulfillDlmmFill {
-    type ArrangedAccounts = FulfillDlmmFillInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, dca, input_mint, output_mint, keeper_in_ata, in_ata, out_ata, fee_authority, fee_ata, instructions_sysvar, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(FulfillDlmmFillInstructionAccounts {
-            keeper: keeper.pubkey,
-            dca: dca.pubkey,
-            input_mint: input_mint.pubkey,
-            output_mint: output_mint.pubkey,
-            keeper_in_ata: keeper_in_ata.pubkey,
-            in_ata: in_ata.pubkey,
-            out_ata: out_ata.pubkey,
-            fee_authority: fee_authority.pubkey,
-            fee_ata: fee_ata.pubkey,
-            instructions_sysvar: instructions_sysvar.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -2,11 +2,15 @@ use crate::PROGRAM_ID;
 
 use super::JupiterDcaDecoder;
 pub mod close_dca;
+#[cfg(feature = "events")]
 pub mod closed_event;
+#[cfg(feature = "events")]
 pub mod collected_fee_event;
 pub mod deposit;
+#[cfg(feature = "events")]
 pub mod deposit_event;
 pub mod end_and_close;
+#[cfg(feature = "events")]
 pub mod filled_event;
 pub mod fulfill_dlmm_fill;
 pub mod fulfill_flash_fill;
@@ -14,9 +18,11 @@ pub mod initiate_dlmm_fill;
 pub mod initiate_flash_fill;
 pub mod open_dca;
 pub mod open_dca_v2;
+#[cfg(feature = "events")]
 pub mod opened_event;
 pub mod transfer;
 pub mod withdraw;
+#[cfg(feature = "events")]
 pub mod withdraw_event;
 pub mod withdraw_fees;
 
@@ -43,17 +49,27 @@ pub enum JupiterDcaInstruction {
     FulfillDlmmFill(fulfill_dlmm_fill::FulfillDlmmFill),
     Transfer(transfer::Transfer),
     EndAndClose(end_and_close::EndAndClose),
+    #[cfg(feature = "events")]
     CollectedFeeEvent(collected_fee_event::CollectedFeeEvent),
+    #[cfg(feature = "events")]
     FilledEvent(filled_event::FilledEvent),
+    #[cfg(feature = "events")]
     OpenedEvent(opened_event::OpenedEvent),
+    #[cfg(feature = "events")]
     ClosedEvent(closed_event::ClosedEvent),
+    #[cfg(feature = "events")]
     WithdrawEvent(withdraw_event::WithdrawEvent),
+    #[cfg(feature = "events")]
     DepositEvent(deposit_event::DepositEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterDcaDecoder {
     type InstructionType = JupiterDcaInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -75,11 +91,17 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterDcaDecoder {
             JupiterDcaInstruction::FulfillDlmmFill => fulfill_dlmm_fill::FulfillDlmmFill,
             JupiterDcaInstruction::Transfer => transfer::Transfer,
             JupiterDcaInstruction::EndAndClose => end_and_close::EndAndClose,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::CollectedFeeEvent => collected_fee_event::CollectedFeeEvent,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::FilledEvent => filled_event::FilledEvent,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::OpenedEvent => opened_event::OpenedEvent,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::ClosedEvent => closed_event::ClosedEvent,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::WithdrawEvent => withdraw_event::WithdrawEvent,
+            #[cfg(feature = "events")]
             JupiterDcaInstruction::DepositEvent => deposit_event::DepositEvent,
         )
     }
@@ -213,6 +235,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -359,6 +382,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -498,6 +522,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -592,6 +617,7 @@ mod tests {
             associated_token_program: solana_pubkey::Pubkey::from_str_const(
                 "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -707,6 +733,7 @@ mod tests {
                 associated_token_program: solana_pubkey::Pubkey::from_str_const(
                     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = JupiterDcaDecoder;
@@ -868,6 +895,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -1026,6 +1054,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb712469c946da122")]
 pub struct Withdraw {
     pub withdraw_params: WithdrawParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Withdraw")]
 pub struct WithdrawInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub dca: solana_pubkey::Pubkey,
@@ -24,33 +26,5 @@ pub struct WithdrawInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
-    type ArrangedAccounts = WithdrawInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, dca, input_mint, output_mint, dca_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawInstructionAccounts {
-            user: user.pubkey,
-            dca: dca.pubkey,
-            input_mint: input_mint.pubkey,
-            output_mint: output_mint.pubkey,
-            dca_ata: dca_ata.pubkey,
-            user_in_ata: user_in_ata.pubkey,
-            user_out_ata: user_out_ata.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct WithdrawParams {
     pub withdraw_amount: u64,
@@ -1,19 +1,26 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d318e48a6e61d5454")]
 pub struct CreateOrderEvent {
+    #[carbon(serialize_as = "base58")]
     pub order_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub maker: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub input_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub output_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub input_token_program: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub output_token_program: solana_pubkey::Pubkey,
     pub making_amount: u64,
     pub taking_amount: u64,
     pub expired_at: Option<i64>,
     pub fee_bps: u16,
+    #[carbon(serialize_as = "base58")]
     pub fee_account: solana_pubkey::Pubkey,
 }
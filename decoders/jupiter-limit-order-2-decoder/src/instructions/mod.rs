@@ -2,11 +2,14 @@ use crate::PROGRAM_ID;
 
 use super::JupiterLimitOrder2Decoder;
 pub mod cancel_order;
+#[cfg(feature = "events")]
 pub mod cancel_order_event;
+#[cfg(feature = "events")]
 pub mod create_order_event;
 pub mod flash_fill_order;
 pub mod initialize_order;
 pub mod pre_flash_fill_order;
+#[cfg(feature = "events")]
 pub mod trade_event;
 pub mod update_fee;
 pub mod withdraw_fee;
@@ -28,14 +31,21 @@ pub enum JupiterLimitOrder2Instruction {
     CancelOrder(cancel_order::CancelOrder),
     PreFlashFillOrder(pre_flash_fill_order::PreFlashFillOrder),
     FlashFillOrder(flash_fill_order::FlashFillOrder),
+    #[cfg(feature = "events")]
     TradeEvent(trade_event::TradeEvent),
+    #[cfg(feature = "events")]
     CancelOrderEvent(cancel_order_event::CancelOrderEvent),
+    #[cfg(feature = "events")]
     CreateOrderEvent(create_order_event::CreateOrderEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrder2Decoder {
     type InstructionType = JupiterLimitOrder2Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -51,8 +61,11 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrder2Deco
             JupiterLimitOrder2Instruction::CancelOrder => cancel_order::CancelOrder,
             JupiterLimitOrder2Instruction::PreFlashFillOrder => pre_flash_fill_order::PreFlashFillOrder,
             JupiterLimitOrder2Instruction::FlashFillOrder => flash_fill_order::FlashFillOrder,
+            #[cfg(feature = "events")]
             JupiterLimitOrder2Instruction::TradeEvent => trade_event::TradeEvent,
+            #[cfg(feature = "events")]
             JupiterLimitOrder2Instruction::CancelOrderEvent => cancel_order_event::CancelOrderEvent,
+            #[cfg(feature = "events")]
             JupiterLimitOrder2Instruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
         )
     }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf02f99440dbee12a")]
 pub struct PreFlashFillOrder {
     pub params: PreFlashFillOrderParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PreFlashFillOrder")]
 pub struct PreFlashFillOrderInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub order: solana_pubkey::Pubkey,
@@ -19,28 +21,5 @@ pub struct PreFlashFillOrderInstructionAccounts {
     pub input_mint: solana_pubkey::Pubkey,
     pub input_token_program: solana_pubkey::Pubkey,
     pub instruction: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
-    type ArrangedAccounts = PreFlashFillOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [taker, order, input_mint_reserve, taker_input_mint_account, input_mint, input_token_program, instruction, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(PreFlashFillOrderInstructionAccounts {
-            taker: taker.pubkey,
-            order: order.pubkey,
-            input_mint_reserve: input_mint_reserve.pubkey,
-            taker_input_mint_account: taker_input_mint_account.pubkey,
-            input_mint: input_mint.pubkey,
-            input_token_program: input_token_program.pubkey,
-            instruction: instruction.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d318e48a6e61d5454")]
 pub struct CreateOrderEvent {
+    #[carbon(serialize_as = "base58")]
     pub order_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub maker: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub input_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub output_mint: solana_pubkey::Pubkey,
     pub in_amount: u64,
     pub out_amount: u64,
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfc681286a44e128c")]
 pub struct FlashFillOrder {
     pub max_taking_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FlashFillOrder")]
 pub struct FlashFillOrderInstructionAccounts {
     pub order: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
@@ -23,35 +25,5 @@ pub struct FlashFillOrderInstructionAccounts {
     pub output_mint: solana_pubkey::Pubkey,
     pub output_mint_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
-    type ArrangedAccounts = FlashFillOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [order, reserve, maker, taker, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, input_mint, input_mint_token_program, output_mint, output_mint_token_program, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(FlashFillOrderInstructionAccounts {
-            order: order.pubkey,
-            reserve: reserve.pubkey,
-            maker: maker.pubkey,
-            taker: taker.pubkey,
-            maker_output_account: maker_output_account.pubkey,
-            taker_input_account: taker_input_account.pubkey,
-            fee_authority: fee_authority.pubkey,
-            program_fee_account: program_fee_account.pubkey,
-            referral: referral.pubkey,
-            input_mint: input_mint.pubkey,
-            input_mint_token_program: input_mint_token_program.pubkey,
-            output_mint: output_mint.pubkey,
-            output_mint_token_program: output_mint_token_program.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x856e4aaf709ff59f")]
 pub struct InitializeOrder {
@@ -10,6 +10,8 @@ pub struct InitializeOrder {
     pub expired_at: Option<i64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeOrder")]
 pub struct InitializeOrderInstructionAccounts {
     pub base: solana_pubkey::Pubkey,
     pub maker: solana_pubkey::Pubkey,
@@ -23,33 +25,5 @@ pub struct InitializeOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
-    type ArrangedAccounts = InitializeOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [base, maker, order, reserve, maker_input_account, input_mint, maker_output_account, referral, output_mint, system_program, token_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeOrderInstructionAccounts {
-            base: base.pubkey,
-            maker: maker.pubkey,
-            order: order.pubkey,
-            reserve: reserve.pubkey,
-            maker_input_account: maker_input_account.pubkey,
-            input_mint: input_mint.pubkey,
-            maker_output_account: maker_output_account.pubkey,
-            referral: referral.pubkey,
-            output_mint: output_mint.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
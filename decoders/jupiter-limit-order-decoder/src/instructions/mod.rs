@@ -3,13 +3,16 @@ use crate::PROGRAM_ID;
 use super::JupiterLimitOrderDecoder;
 pub mod cancel_expired_order;
 pub mod cancel_order;
+#[cfg(feature = "events")]
 pub mod cancel_order_event;
+#[cfg(feature = "events")]
 pub mod create_order_event;
 pub mod fill_order;
 pub mod flash_fill_order;
 pub mod init_fee;
 pub mod initialize_order;
 pub mod pre_flash_fill_order;
+#[cfg(feature = "events")]
 pub mod trade_event;
 pub mod update_fee;
 pub mod withdraw_fee;
@@ -34,14 +37,21 @@ pub enum JupiterLimitOrderInstruction {
     WithdrawFee(withdraw_fee::WithdrawFee),
     InitFee(init_fee::InitFee),
     UpdateFee(update_fee::UpdateFee),
+    #[cfg(feature = "events")]
     TradeEvent(trade_event::TradeEvent),
+    #[cfg(feature = "events")]
     CancelOrderEvent(cancel_order_event::CancelOrderEvent),
+    #[cfg(feature = "events")]
     CreateOrderEvent(create_order_event::CreateOrderEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrderDecoder {
     type InstructionType = JupiterLimitOrderInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -60,8 +70,11 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrderDecod
             JupiterLimitOrderInstruction::WithdrawFee => withdraw_fee::WithdrawFee,
             JupiterLimitOrderInstruction::InitFee => init_fee::InitFee,
             JupiterLimitOrderInstruction::UpdateFee => update_fee::UpdateFee,
+            #[cfg(feature = "events")]
             JupiterLimitOrderInstruction::TradeEvent => trade_event::TradeEvent,
+            #[cfg(feature = "events")]
             JupiterLimitOrderInstruction::CancelOrderEvent => cancel_order_event::CancelOrderEvent,
+            #[cfg(feature = "events")]
             JupiterLimitOrderInstruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
         )
     }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dbddb7fd34ee661ee")]
 pub struct TradeEvent {
+    #[carbon(serialize_as = "base58")]
     pub order_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub taker: solana_pubkey::Pubkey,
     pub remaining_in_amount: u64,
     pub remaining_out_amount: u64,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe8fdc3f794d449de")]
 pub struct UpdateFee {
@@ -11,24 +11,10 @@ pub struct UpdateFee {
     pub taker_stable_fee: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateFee")]
 pub struct UpdateFeeInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
-    type ArrangedAccounts = UpdateFeeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, fee_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateFeeInstructionAccounts {
-            keeper: keeper.pubkey,
-            fee_authority: fee_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x73e6d4d3af3127a9")]
 pub struct AddPool {
     pub params: AddPoolParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddPool")]
 pub struct AddPoolInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub transfer_authority: solana_pubkey::Pubkey,
@@ -19,29 +21,5 @@ pub struct AddPoolInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddPool {
-    type ArrangedAccounts = AddPoolInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, transfer_authority, perpetuals, pool, lp_token_mint, system_program, token_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(AddPoolInstructionAccounts {
-            admin: admin.pubkey,
-            transfer_authority: transfer_authority.pubkey,
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-            lp_token_mint: lp_token_mint.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
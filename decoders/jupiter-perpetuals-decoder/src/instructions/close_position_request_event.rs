@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d15225c9ee01db4f3")]
 pub struct ClosePositionRequestEvent {
@@ -10,8 +10,11 @@ pub struct ClosePositionRequestEvent {
     pub request_change: u8,
     pub request_type: u8,
     pub side: u8,
+    #[carbon(serialize_as = "base58")]
     pub position_request_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub amount: u64,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6940c952fa0e6d4d")]
 pub struct CreateDecreasePositionRequest2 {
     pub params: CreateDecreasePositionRequest2Params,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateDecreasePositionRequest2")]
 pub struct CreateDecreasePositionRequest2InstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub receiving_account: solana_pubkey::Pubkey,
@@ -29,39 +31,5 @@ pub struct CreateDecreasePositionRequest2InstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionRequest2 {
-    type ArrangedAccounts = CreateDecreasePositionRequest2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateDecreasePositionRequest2InstructionAccounts {
-            owner: owner.pubkey,
-            receiving_account: receiving_account.pubkey,
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-            position: position.pubkey,
-            position_request: position_request.pubkey,
-            position_request_ata: position_request_ata.pubkey,
-            custody: custody.pubkey,
-            custody_doves_price_account: custody_doves_price_account.pubkey,
-            custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
-            collateral_custody: collateral_custody.pubkey,
-            desired_mint: desired_mint.pubkey,
-            referral: referral.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,19 +1,28 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d02ee5e3569d32eba")]
 pub struct CreatePositionRequestEvent {
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_key: solana_pubkey::Pubkey,
     pub position_side: u8,
+    #[carbon(serialize_as = "base58")]
     pub position_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_custody: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_collateral_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_collateral_custody: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_request_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_request_mint: solana_pubkey::Pubkey,
     pub size_usd_delta: u64,
     pub collateral_delta: u64,
@@ -22,5 +31,6 @@ pub struct CreatePositionRequestEvent {
     pub pre_swap_amount: Option<u64>,
     pub request_change: u8,
     pub open_time: i64,
+    #[carbon(serialize_as = "base58")]
     pub referral: Option<solana_pubkey::Pubkey>,
 }
@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d17d210e962f55952")]
 pub struct DecreasePositionPostSwapEvent {
+    #[carbon(serialize_as = "base58")]
     pub position_request_key: solana_pubkey::Pubkey,
     pub swap_amount: u64,
     pub jupiter_minimum_out: Option<u64>,
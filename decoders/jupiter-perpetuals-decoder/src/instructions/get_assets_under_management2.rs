@@ -1,33 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xc1d20df971951d54")]
 pub struct GetAssetsUnderManagement2 {
     pub params: GetAssetsUnderManagement2Params,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "GetAssetsUnderManagement2")]
 pub struct GetAssetsUnderManagement2InstructionAccounts {
     pub perpetuals: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for GetAssetsUnderManagement2 {
-    type ArrangedAccounts = GetAssetsUnderManagement2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [perpetuals, pool, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(GetAssetsUnderManagement2InstructionAccounts {
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x439335172b391043")]
 pub struct IncreasePosition4 {
     pub params: IncreasePosition4Params,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IncreasePosition4")]
 pub struct IncreasePosition4InstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
@@ -27,38 +29,5 @@ pub struct IncreasePosition4InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IncreasePosition4 {
-    type ArrangedAccounts = IncreasePosition4InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(IncreasePosition4InstructionAccounts {
-            keeper: keeper.pubkey,
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-            position_request: position_request.pubkey,
-            position_request_ata: position_request_ata.pubkey,
-            position: position.pubkey,
-            custody: custody.pubkey,
-            custody_doves_price_account: custody_doves_price_account.pubkey,
-            custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
-            collateral_custody: collateral_custody.pubkey,
-            collateral_custody_doves_price_account: collateral_custody_doves_price_account.pubkey,
-            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
-                .pubkey,
-            collateral_custody_token_account: collateral_custody_token_account.pubkey,
-            token_program: token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,22 +1,30 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1df5715534d6bb9984")]
 pub struct IncreasePositionEvent {
+    #[carbon(serialize_as = "base58")]
     pub position_key: solana_pubkey::Pubkey,
     pub position_side: u8,
+    #[carbon(serialize_as = "base58")]
     pub position_custody: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_collateral_custody: solana_pubkey::Pubkey,
     pub position_size_usd: u64,
+    #[carbon(serialize_as = "base58")]
     pub position_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_request_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_request_mint: solana_pubkey::Pubkey,
     pub position_request_change: u8,
     pub position_request_type: u8,
     pub position_request_collateral_delta: u64,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
     pub size_usd_delta: u64,
     pub collateral_usd_delta: u64,
@@ -26,5 +34,6 @@ pub struct IncreasePositionEvent {
     pub fee_token: u64,
     pub fee_usd: u64,
     pub open_time: i64,
+    #[carbon(serialize_as = "base58")]
     pub referral: Option<solana_pubkey::Pubkey>,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x72376a8cc7dd2070")]
 pub struct IncreasePositionWithInternalSwap {
     pub params: IncreasePositionWithInternalSwapParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IncreasePositionWithInternalSwap")]
 pub struct IncreasePositionWithInternalSwapInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
@@ -31,42 +33,5 @@ pub struct IncreasePositionWithInternalSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionWithInternalSwap {
-    type ArrangedAccounts = IncreasePositionWithInternalSwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(IncreasePositionWithInternalSwapInstructionAccounts {
-            keeper: keeper.pubkey,
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-            position_request: position_request.pubkey,
-            position_request_ata: position_request_ata.pubkey,
-            position: position.pubkey,
-            custody: custody.pubkey,
-            custody_doves_price_account: custody_doves_price_account.pubkey,
-            custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
-            collateral_custody: collateral_custody.pubkey,
-            collateral_custody_doves_price_account: collateral_custody_doves_price_account.pubkey,
-            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
-                .pubkey,
-            collateral_custody_token_account: collateral_custody_token_account.pubkey,
-            receiving_custody: receiving_custody.pubkey,
-            receiving_custody_doves_price_account: receiving_custody_doves_price_account.pubkey,
-            receiving_custody_pythnet_price_account: receiving_custody_pythnet_price_account.pubkey,
-            receiving_custody_token_account: receiving_custody_token_account.pubkey,
-            token_program: token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
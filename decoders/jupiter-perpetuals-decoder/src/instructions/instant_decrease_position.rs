@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2e17f02c1e8a5e8c")]
 pub struct InstantDecreasePosition {
     pub params: InstantDecreasePositionParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InstantDecreasePosition")]
 pub struct InstantDecreasePositionInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub api_keeper: solana_pubkey::Pubkey,
@@ -33,44 +35,5 @@ pub struct InstantDecreasePositionInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InstantDecreasePosition {
-    type ArrangedAccounts = InstantDecreasePositionInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, api_keeper, owner, receiving_account, transfer_authority, perpetuals, pool, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InstantDecreasePositionInstructionAccounts {
-            keeper: keeper.pubkey,
-            api_keeper: api_keeper.pubkey,
-            owner: owner.pubkey,
-            receiving_account: receiving_account.pubkey,
-            transfer_authority: transfer_authority.pubkey,
-            perpetuals: perpetuals.pubkey,
-            pool: pool.pubkey,
-            position: position.pubkey,
-            custody: custody.pubkey,
-            custody_doves_price_account: custody_doves_price_account.pubkey,
-            custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
-            collateral_custody: collateral_custody.pubkey,
-            collateral_custody_doves_price_account: collateral_custody_doves_price_account.pubkey,
-            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
-                .pubkey,
-            collateral_custody_token_account: collateral_custody_token_account.pubkey,
-            desired_mint: desired_mint.pubkey,
-            referral: referral.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,17 +1,23 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dcdec3904d16a5745")]
 pub struct InstantIncreasePositionEvent {
+    #[carbon(serialize_as = "base58")]
     pub position_key: solana_pubkey::Pubkey,
     pub position_side: u8,
+    #[carbon(serialize_as = "base58")]
     pub position_custody: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position_collateral_custody: solana_pubkey::Pubkey,
     pub position_size_usd: u64,
+    #[carbon(serialize_as = "base58")]
     pub position_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
     pub size_usd_delta: u64,
     pub collateral_usd_delta: u64,
@@ -21,5 +27,6 @@ pub struct InstantIncreasePositionEvent {
     pub fee_token: u64,
     pub fee_usd: u64,
     pub open_time: i64,
+    #[carbon(serialize_as = "base58")]
     pub referral: Option<solana_pubkey::Pubkey>,
 }
@@ -3,48 +3,64 @@ use crate::PROGRAM_ID;
 use super::PerpetualsDecoder;
 pub mod add_custody;
 pub mod add_liquidity2;
+#[cfg(feature = "events")]
 pub mod add_liquidity_event;
 pub mod add_pool;
 pub mod close_position_request;
+#[cfg(feature = "events")]
 pub mod close_position_request_event;
 pub mod create_decrease_position_market_request;
 pub mod create_decrease_position_request2;
 pub mod create_increase_position_market_request;
+#[cfg(feature = "events")]
 pub mod create_position_request_event;
 pub mod create_token_ledger;
 pub mod create_token_metadata;
 pub mod decrease_position4;
+#[cfg(feature = "events")]
 pub mod decrease_position_event;
+#[cfg(feature = "events")]
 pub mod decrease_position_post_swap_event;
 pub mod decrease_position_with_internal_swap;
 pub mod get_add_liquidity_amount_and_fee2;
 pub mod get_assets_under_management2;
 pub mod get_remove_liquidity_amount_and_fee2;
 pub mod increase_position4;
+#[cfg(feature = "events")]
 pub mod increase_position_event;
 pub mod increase_position_pre_swap;
+#[cfg(feature = "events")]
 pub mod increase_position_pre_swap_event;
 pub mod increase_position_with_internal_swap;
 pub mod init;
 pub mod instant_create_limit_order;
+#[cfg(feature = "events")]
 pub mod instant_create_limit_order_event;
 pub mod instant_create_tpsl;
+#[cfg(feature = "events")]
 pub mod instant_create_tpsl_event;
 pub mod instant_decrease_position;
+#[cfg(feature = "events")]
 pub mod instant_decrease_position_event;
 pub mod instant_increase_position;
+#[cfg(feature = "events")]
 pub mod instant_increase_position_event;
 pub mod instant_update_limit_order;
 pub mod instant_update_tpsl;
+#[cfg(feature = "events")]
 pub mod instant_update_tpsl_event;
 pub mod liquidate_full_position4;
+#[cfg(feature = "events")]
 pub mod liquidate_full_position_event;
 pub mod operator_set_custody_config;
 pub mod operator_set_pool_config;
+#[cfg(feature = "events")]
 pub mod pool_swap_event;
+#[cfg(feature = "events")]
 pub mod pool_swap_exact_out_event;
 pub mod refresh_assets_under_management;
 pub mod remove_liquidity2;
+#[cfg(feature = "events")]
 pub mod remove_liquidity_event;
 pub mod set_custody_config;
 pub mod set_perpetuals_config;
@@ -121,27 +137,47 @@ pub enum PerpetualsInstruction {
         get_remove_liquidity_amount_and_fee2::GetRemoveLiquidityAmountAndFee2,
     ),
     GetAssetsUnderManagement2(get_assets_under_management2::GetAssetsUnderManagement2),
+    #[cfg(feature = "events")]
     CreatePositionRequestEvent(create_position_request_event::CreatePositionRequestEvent),
+    #[cfg(feature = "events")]
     InstantCreateTpslEvent(instant_create_tpsl_event::InstantCreateTpslEvent),
+    #[cfg(feature = "events")]
     InstantUpdateTpslEvent(instant_update_tpsl_event::InstantUpdateTpslEvent),
+    #[cfg(feature = "events")]
     ClosePositionRequestEvent(close_position_request_event::ClosePositionRequestEvent),
+    #[cfg(feature = "events")]
     IncreasePositionEvent(increase_position_event::IncreasePositionEvent),
+    #[cfg(feature = "events")]
     IncreasePositionPreSwapEvent(increase_position_pre_swap_event::IncreasePositionPreSwapEvent),
+    #[cfg(feature = "events")]
     DecreasePositionEvent(decrease_position_event::DecreasePositionEvent),
+    #[cfg(feature = "events")]
     DecreasePositionPostSwapEvent(decrease_position_post_swap_event::DecreasePositionPostSwapEvent),
+    #[cfg(feature = "events")]
     LiquidateFullPositionEvent(liquidate_full_position_event::LiquidateFullPositionEvent),
+    #[cfg(feature = "events")]
     PoolSwapEvent(pool_swap_event::PoolSwapEvent),
+    #[cfg(feature = "events")]
     PoolSwapExactOutEvent(pool_swap_exact_out_event::PoolSwapExactOutEvent),
+    #[cfg(feature = "events")]
     AddLiquidityEvent(add_liquidity_event::AddLiquidityEvent),
+    #[cfg(feature = "events")]
     RemoveLiquidityEvent(remove_liquidity_event::RemoveLiquidityEvent),
+    #[cfg(feature = "events")]
     InstantCreateLimitOrderEvent(instant_create_limit_order_event::InstantCreateLimitOrderEvent),
+    #[cfg(feature = "events")]
     InstantIncreasePositionEvent(instant_increase_position_event::InstantIncreasePositionEvent),
+    #[cfg(feature = "events")]
     InstantDecreasePositionEvent(instant_decrease_position_event::InstantDecreasePositionEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for PerpetualsDecoder {
     type InstructionType = PerpetualsInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -190,21 +226,37 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PerpetualsDecoder {
             PerpetualsInstruction::GetAddLiquidityAmountAndFee2 => get_add_liquidity_amount_and_fee2::GetAddLiquidityAmountAndFee2,
             PerpetualsInstruction::GetRemoveLiquidityAmountAndFee2 => get_remove_liquidity_amount_and_fee2::GetRemoveLiquidityAmountAndFee2,
             PerpetualsInstruction::GetAssetsUnderManagement2 => get_assets_under_management2::GetAssetsUnderManagement2,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::CreatePositionRequestEvent => create_position_request_event::CreatePositionRequestEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::InstantCreateTpslEvent => instant_create_tpsl_event::InstantCreateTpslEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::InstantUpdateTpslEvent => instant_update_tpsl_event::InstantUpdateTpslEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::ClosePositionRequestEvent => close_position_request_event::ClosePositionRequestEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::IncreasePositionEvent => increase_position_event::IncreasePositionEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::IncreasePositionPreSwapEvent => increase_position_pre_swap_event::IncreasePositionPreSwapEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::DecreasePositionEvent => decrease_position_event::DecreasePositionEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::DecreasePositionPostSwapEvent => decrease_position_post_swap_event::DecreasePositionPostSwapEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::LiquidateFullPositionEvent => liquidate_full_position_event::LiquidateFullPositionEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::PoolSwapEvent => pool_swap_event::PoolSwapEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::PoolSwapExactOutEvent => pool_swap_exact_out_event::PoolSwapExactOutEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::AddLiquidityEvent => add_liquidity_event::AddLiquidityEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::RemoveLiquidityEvent => remove_liquidity_event::RemoveLiquidityEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::InstantCreateLimitOrderEvent => instant_create_limit_order_event::InstantCreateLimitOrderEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::InstantIncreasePositionEvent => instant_increase_position_event::InstantIncreasePositionEvent,
+            #[cfg(feature = "events")]
             PerpetualsInstruction::InstantDecreasePositionEvent => instant_decrease_position_event::InstantDecreasePositionEvent,
         )
     }
@@ -1,33 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa6895ccc91e018da")]
 pub struct OperatorSetCustodyConfig {
     pub params: OperatorSetCustodyConfigParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OperatorSetCustodyConfig")]
 pub struct OperatorSetCustodyConfigInstructionAccounts {
     pub operator: solana_pubkey::Pubkey,
     pub custody: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OperatorSetCustodyConfig {
-    type ArrangedAccounts = OperatorSetCustodyConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [operator, custody, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(OperatorSetCustodyConfigInstructionAccounts {
-            operator: operator.pubkey,
-            custody: custody.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,33 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x4cc95012c75cf669")]
 pub struct OperatorSetPoolConfig {
     pub params: OperatorSetPoolConfigParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OperatorSetPoolConfig")]
 pub struct OperatorSetPoolConfigInstructionAccounts {
     pub operator: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OperatorSetPoolConfig {
-    type ArrangedAccounts = OperatorSetPoolConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [operator, pool, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(OperatorSetPoolConfigInstructionAccounts {
-            operator: operator.pubkey,
-            pool: pool.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,18 +1,23 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d286bd41adf8827dc")]
 pub struct PoolSwapEvent {
+    #[carbon(serialize_as = "base58")]
     pub receiving_custody_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub dispensing_custody_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_key: solana_pubkey::Pubkey,
     pub amount_in: u64,
     pub amount_out: u64,
     pub swap_usd_amount: u64,
     pub amount_out_after_fees: u64,
     pub fee_bps: u64,
+    #[carbon(serialize_as = "base58")]
     pub owner_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub receiving_account_key: solana_pubkey::Pubkey,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d8dc7b67b9f5ed766")]
 pub struct RemoveLiquidityEvent {
+    #[carbon(serialize_as = "base58")]
     pub custody_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_key: solana_pubkey::Pubkey,
     pub lp_amount_in: u64,
     pub remove_amount_usd: u64,
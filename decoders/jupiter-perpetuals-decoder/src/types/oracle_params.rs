@@ -1,11 +1,12 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct OracleParams {
+    #[carbon(serialize_as = "base58")]
     pub oracle_account: solana_pubkey::Pubkey,
     pub oracle_type: OracleType,
     pub max_price_error: u64,
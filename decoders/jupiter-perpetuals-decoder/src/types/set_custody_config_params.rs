@@ -1,9 +1,9 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SetCustodyConfigParams {
     pub oracle: OracleParams,
@@ -13,6 +13,7 @@ pub struct SetCustodyConfigParams {
     pub target_ratio_bps: u64,
     pub increase_position_bps: u64,
     pub decrease_position_bps: u64,
+    #[carbon(serialize_as = "base58")]
     pub doves_oracle: solana_pubkey::Pubkey,
     pub max_position_size_usd: u64,
     pub jump_rate: JumpRateState,
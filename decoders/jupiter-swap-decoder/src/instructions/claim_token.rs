@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x74ce1bbfa6130049")]
 pub struct ClaimToken {
     pub id: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ClaimToken")]
 pub struct ClaimTokenInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub wallet: solana_pubkey::Pubkey,
@@ -18,30 +20,5 @@ pub struct ClaimTokenInstructionAccounts {
     pub associated_token_token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ClaimToken {
-    type ArrangedAccounts = ClaimTokenInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, wallet, program_authority, program_token_account, destination_token_account, mint, associated_token_token_program, associated_token_program, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ClaimTokenInstructionAccounts {
-            payer: payer.pubkey,
-            wallet: wallet.pubkey,
-            program_authority: program_authority.pubkey,
-            program_token_account: program_token_account.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            mint: mint.pubkey,
-            associated_token_token_program: associated_token_token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe5c2d4ac080a8693")]
 pub struct CreateOpenOrders {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateOpenOrders")]
 pub struct CreateOpenOrdersInstructionAccounts {
     pub open_orders: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct CreateOpenOrdersInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrders {
-    type ArrangedAccounts = CreateOpenOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [open_orders, payer, dex_program, system_program, rent, market, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateOpenOrdersInstructionAccounts {
-            open_orders: open_orders.pubkey,
-            payer: payer.pubkey,
-            dex_program: dex_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            market: market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
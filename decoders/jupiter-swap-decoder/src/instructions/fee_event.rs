@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d494f4e7fb8d50ddc")]
 pub struct FeeEvent {
+    #[carbon(serialize_as = "base58")]
     pub account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub amount: u64,
 }
@@ -7,6 +7,7 @@ pub mod create_open_orders;
 pub mod create_program_open_orders;
 pub mod create_token_ledger;
 pub mod exact_out_route;
+#[cfg(feature = "events")]
 pub mod fee_event;
 pub mod route;
 pub mod route_with_token_ledger;
@@ -14,6 +15,7 @@ pub mod set_token_ledger;
 pub mod shared_accounts_exact_out_route;
 pub mod shared_accounts_route;
 pub mod shared_accounts_route_with_token_ledger;
+#[cfg(feature = "events")]
 pub mod swap_event;
 
 #[derive(
@@ -41,13 +43,19 @@ pub enum JupiterSwapInstruction {
     SharedAccountsRouteWithTokenLedger(
         shared_accounts_route_with_token_ledger::SharedAccountsRouteWithTokenLedger,
     ),
+    #[cfg(feature = "events")]
     FeeEvent(fee_event::FeeEvent),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterSwapDecoder {
     type InstructionType = JupiterSwapInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -69,7 +77,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterSwapDecoder {
             JupiterSwapInstruction::SharedAccountsExactOutRoute => shared_accounts_exact_out_route::SharedAccountsExactOutRoute,
             JupiterSwapInstruction::SharedAccountsRoute => shared_accounts_route::SharedAccountsRoute,
             JupiterSwapInstruction::SharedAccountsRouteWithTokenLedger => shared_accounts_route_with_token_ledger::SharedAccountsRouteWithTokenLedger,
+            #[cfg(feature = "events")]
             JupiterSwapInstruction::FeeEvent => fee_event::FeeEvent,
+            #[cfg(feature = "events")]
             JupiterSwapInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe517cb977ae3ad2a")]
 pub struct Route {
@@ -15,6 +15,8 @@ pub struct Route {
     pub platform_fee_bps: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Route")]
 pub struct RouteInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub user_transfer_authority: solana_pubkey::Pubkey,
@@ -25,30 +27,5 @@ pub struct RouteInstructionAccounts {
     pub platform_fee_account: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Route {
-    type ArrangedAccounts = RouteInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, destination_mint, platform_fee_account, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RouteInstructionAccounts {
-            token_program: token_program.pubkey,
-            user_transfer_authority: user_transfer_authority.pubkey,
-            user_source_token_account: user_source_token_account.pubkey,
-            user_destination_token_account: user_destination_token_account.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            destination_mint: destination_mint.pubkey,
-            platform_fee_account: platform_fee_account.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x44c8e4e9b820e2bc")]
 pub struct HarvestReward {
     pub reward_index: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "HarvestReward")]
 pub struct HarvestRewardInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
@@ -20,32 +22,5 @@ pub struct HarvestRewardInstructionAccounts {
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for HarvestReward {
-    type ArrangedAccounts = HarvestRewardInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, farm_state, global_config, reward_mint, user_reward_ata, rewards_vault, rewards_treasury_vault, farm_vaults_authority, scope_prices, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(HarvestRewardInstructionAccounts {
-            owner: owner.pubkey,
-            user_state: user_state.pubkey,
-            farm_state: farm_state.pubkey,
-            global_config: global_config.pubkey,
-            reward_mint: reward_mint.pubkey,
-            user_reward_ata: user_reward_ata.pubkey,
-            rewards_vault: rewards_vault.pubkey,
-            rewards_treasury_vault: rewards_treasury_vault.pubkey,
-            farm_vaults_authority: farm_vaults_authority.pubkey,
-            scope_prices: scope_prices.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8250269950d4b6fd")]
 pub struct IdlMissingTypes {
@@ -15,24 +15,10 @@ pub struct IdlMissingTypes {
     pub reward_type: RewardType,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IdlMissingTypes")]
 pub struct IdlMissingTypesInstructionAccounts {
     pub global_admin: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
-    type ArrangedAccounts = IdlMissingTypesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(IdlMissingTypesInstructionAccounts {
-            global_admin: global_admin.pubkey,
-            global_config: global_config.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5f87c0c4f281e644")]
 pub struct InitializeReward {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeReward")]
 pub struct InitializeRewardInstructionAccounts {
     pub farm_admin: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
@@ -18,32 +20,5 @@ pub struct InitializeRewardInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
-    type ArrangedAccounts = InitializeRewardInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, global_config, reward_mint, reward_vault, reward_treasury_vault, farm_vaults_authority, treasury_vaults_authority, token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeRewardInstructionAccounts {
-            farm_admin: farm_admin.pubkey,
-            farm_state: farm_state.pubkey,
-            global_config: global_config.pubkey,
-            reward_mint: reward_mint.pubkey,
-            reward_vault: reward_vault.pubkey,
-            reward_treasury_vault: reward_treasury_vault.pubkey,
-            farm_vaults_authority: farm_vaults_authority.pubkey,
-            treasury_vaults_authority: treasury_vaults_authority.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6f11b9fa3c7a26fe")]
 pub struct InitializeUser {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeUser")]
 pub struct InitializeUserInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
@@ -15,29 +17,5 @@ pub struct InitializeUserInstructionAccounts {
     pub farm_state: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
-    type ArrangedAccounts = InitializeUserInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, payer, owner, delegatee, user_state, farm_state, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeUserInstructionAccounts {
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            owner: owner.pubkey,
-            delegatee: delegatee.pubkey,
-            user_state: user_state.pubkey,
-            farm_state: farm_state.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
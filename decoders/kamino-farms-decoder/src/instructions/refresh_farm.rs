@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd6838ab790c2ac2a")]
 pub struct RefreshFarm {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RefreshFarm")]
 pub struct RefreshFarmInstructionAccounts {
     pub farm_state: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RefreshFarm {
-    type ArrangedAccounts = RefreshFarmInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [farm_state, scope_prices, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RefreshFarmInstructionAccounts {
-            farm_state: farm_state.pubkey,
-            scope_prices: scope_prices.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xdb8939165eba6072")]
 pub struct RewardUserOnce {
@@ -9,26 +9,11 @@ pub struct RewardUserOnce {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RewardUserOnce")]
 pub struct RewardUserOnceInstructionAccounts {
     pub farm_admin: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RewardUserOnce {
-    type ArrangedAccounts = RewardUserOnceInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, user_state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RewardUserOnceInstructionAccounts {
-            farm_admin: farm_admin.pubkey,
-            farm_state: farm_state.pubkey,
-            user_state: user_state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x41b1d749352d632f")]
 pub struct TransferOwnership {
+    #[carbon(serialize_as = "base58")]
     pub new_owner: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TransferOwnership")]
 pub struct TransferOwnershipInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for TransferOwnership {
-    type ArrangedAccounts = TransferOwnershipInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(TransferOwnershipInstructionAccounts {
-            owner: owner.pubkey,
-            user_state: user_state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
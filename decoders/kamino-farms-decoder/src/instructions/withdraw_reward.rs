@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xbfbbb0890919bbf4")]
 pub struct WithdrawReward {
@@ -9,6 +9,8 @@ pub struct WithdrawReward {
     pub reward_index: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawReward")]
 pub struct WithdrawRewardInstructionAccounts {
     pub farm_admin: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
@@ -18,29 +20,5 @@ pub struct WithdrawRewardInstructionAccounts {
     pub admin_reward_token_ata: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawReward {
-    type ArrangedAccounts = WithdrawRewardInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, reward_mint, reward_vault, farm_vaults_authority, admin_reward_token_ata, scope_prices, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawRewardInstructionAccounts {
-            farm_admin: farm_admin.pubkey,
-            farm_state: farm_state.pubkey,
-            reward_mint: reward_mint.pubkey,
-            reward_vault: reward_vault.pubkey,
-            farm_vaults_authority: farm_vaults_authority.pubkey,
-            admin_reward_token_ata: admin_reward_token_ata.pubkey,
-            scope_prices: scope_prices.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xcad9434aac168cd8")]
 pub struct WithdrawSlashedAmount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawSlashedAmount")]
 pub struct WithdrawSlashedAmountInstructionAccounts {
     pub crank: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct WithdrawSlashedAmountInstructionAccounts {
     pub farm_vault: solana_pubkey::Pubkey,
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawSlashedAmount {
-    type ArrangedAccounts = WithdrawSlashedAmountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [crank, farm_state, slashed_amount_spill_address, farm_vault, farm_vaults_authority, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawSlashedAmountInstructionAccounts {
-            crank: crank.pubkey,
-            farm_state: farm_state.pubkey,
-            slashed_amount_spill_address: slashed_amount_spill_address.pubkey,
-            farm_vault: farm_vault.pubkey,
-            farm_vaults_authority: farm_vaults_authority.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
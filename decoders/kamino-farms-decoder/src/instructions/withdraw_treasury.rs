@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x283f7a9e90d85360")]
 pub struct WithdrawTreasury {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawTreasury")]
 pub struct WithdrawTreasuryInstructionAccounts {
     pub global_admin: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
@@ -16,28 +18,5 @@ pub struct WithdrawTreasuryInstructionAccounts {
     pub treasury_vault_authority: solana_pubkey::Pubkey,
     pub withdraw_destination_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawTreasury {
-    type ArrangedAccounts = WithdrawTreasuryInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, reward_mint, reward_treasury_vault, treasury_vault_authority, withdraw_destination_token_account, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawTreasuryInstructionAccounts {
-            global_admin: global_admin.pubkey,
-            global_config: global_config.pubkey,
-            reward_mint: reward_mint.pubkey,
-            reward_treasury_vault: reward_treasury_vault.pubkey,
-            treasury_vault_authority: treasury_vault_authority.pubkey,
-            withdraw_destination_token_account: withdraw_destination_token_account.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,14 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct RewardInfo {
     pub token: TokenInfo,
+    #[carbon(serialize_as = "base58")]
     pub rewards_vault: solana_pubkey::Pubkey,
     pub rewards_available: u64,
     pub reward_schedule_curve: RewardScheduleCurve,
@@ -19,6 +20,7 @@ pub struct RewardInfo {
     pub placeholder0: u64,
     pub reward_type: u8,
     pub rewards_per_second_decimals: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding0: [u8; 6],
     pub padding1: [u64; 20],
 }
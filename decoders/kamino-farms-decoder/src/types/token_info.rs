@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct TokenInfo {
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub decimals: u64,
+    #[carbon(serialize_as = "base58")]
     pub token_program: solana_pubkey::Pubkey,
     pub padding: [u64; 6],
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6cd1044815167685")]
 pub struct DepositObligationCollateral {
     pub collateral_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositObligationCollateral")]
 pub struct DepositObligationCollateralInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub obligation: solana_pubkey::Pubkey,
@@ -17,29 +19,5 @@ pub struct DepositObligationCollateralInstructionAccounts {
     pub user_source_collateral: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositObligationCollateral {
-    type ArrangedAccounts = DepositObligationCollateralInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, deposit_reserve, reserve_destination_collateral, user_source_collateral, token_program, instruction_sysvar_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositObligationCollateralInstructionAccounts {
-            owner: owner.pubkey,
-            obligation: obligation.pubkey,
-            lending_market: lending_market.pubkey,
-            deposit_reserve: deposit_reserve.pubkey,
-            reserve_destination_collateral: reserve_destination_collateral.pubkey,
-            user_source_collateral: user_source_collateral.pubkey,
-            token_program: token_program.pubkey,
-            instruction_sysvar_account: instruction_sysvar_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
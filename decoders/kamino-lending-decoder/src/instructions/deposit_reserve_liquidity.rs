@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa9c91e7e06cd6644")]
 pub struct DepositReserveLiquidity {
     pub liquidity_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositReserveLiquidity")]
 pub struct DepositReserveLiquidityInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
@@ -21,33 +23,5 @@ pub struct DepositReserveLiquidityInstructionAccounts {
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidity {
-    type ArrangedAccounts = DepositReserveLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, reserve, lending_market, lending_market_authority, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, user_source_liquidity, user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositReserveLiquidityInstructionAccounts {
-            owner: owner.pubkey,
-            reserve: reserve.pubkey,
-            lending_market: lending_market.pubkey,
-            lending_market_authority: lending_market_authority.pubkey,
-            reserve_liquidity_mint: reserve_liquidity_mint.pubkey,
-            reserve_liquidity_supply: reserve_liquidity_supply.pubkey,
-            reserve_collateral_mint: reserve_collateral_mint.pubkey,
-            user_source_liquidity: user_source_liquidity.pubkey,
-            user_destination_collateral: user_destination_collateral.pubkey,
-            collateral_token_program: collateral_token_program.pubkey,
-            liquidity_token_program: liquidity_token_program.pubkey,
-            instruction_sysvar_account: instruction_sysvar_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
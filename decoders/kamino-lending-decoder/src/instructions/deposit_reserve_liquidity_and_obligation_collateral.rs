@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x81c70402de271a2e")]
 pub struct DepositReserveLiquidityAndObligationCollateral {
@@ -23,6 +23,7 @@ pub struct DepositReserveLiquidityAndObligationCollateralInstructionAccounts {
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObligationCollateral {
@@ -31,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObl
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, reserve_destination_deposit_collateral, user_source_liquidity, placeholder_user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, reserve_destination_deposit_collateral, user_source_liquidity, placeholder_user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +56,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObl
                 collateral_token_program: collateral_token_program.pubkey,
                 liquidity_token_program: liquidity_token_program.pubkey,
                 instruction_sysvar_account: instruction_sysvar_account.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
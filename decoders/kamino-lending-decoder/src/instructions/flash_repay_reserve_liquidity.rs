@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb97500cb60f5b4ba")]
 pub struct FlashRepayReserveLiquidity {
@@ -9,6 +9,8 @@ pub struct FlashRepayReserveLiquidity {
     pub borrow_instruction_index: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FlashRepayReserveLiquidity")]
 pub struct FlashRepayReserveLiquidityInstructionAccounts {
     pub user_transfer_authority: solana_pubkey::Pubkey,
     pub lending_market_authority: solana_pubkey::Pubkey,
@@ -22,33 +24,5 @@ pub struct FlashRepayReserveLiquidityInstructionAccounts {
     pub referrer_account: solana_pubkey::Pubkey,
     pub sysvar_info: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FlashRepayReserveLiquidity {
-    type ArrangedAccounts = FlashRepayReserveLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user_transfer_authority, lending_market_authority, lending_market, reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, reserve_liquidity_fee_receiver, referrer_token_state, referrer_account, sysvar_info, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(FlashRepayReserveLiquidityInstructionAccounts {
-            user_transfer_authority: user_transfer_authority.pubkey,
-            lending_market_authority: lending_market_authority.pubkey,
-            lending_market: lending_market.pubkey,
-            reserve: reserve.pubkey,
-            reserve_liquidity_mint: reserve_liquidity_mint.pubkey,
-            reserve_destination_liquidity: reserve_destination_liquidity.pubkey,
-            user_source_liquidity: user_source_liquidity.pubkey,
-            reserve_liquidity_fee_receiver: reserve_liquidity_fee_receiver.pubkey,
-            referrer_token_state: referrer_token_state.pubkey,
-            referrer_account: referrer_account.pubkey,
-            sysvar_info: sysvar_info.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
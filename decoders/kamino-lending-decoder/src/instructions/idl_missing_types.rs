@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8250269950d4b6fd")]
 pub struct IdlMissingTypes {
@@ -17,26 +17,11 @@ pub struct IdlMissingTypes {
     pub update_lending_market_config_mode: UpdateLendingMarketMode,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IdlMissingTypes")]
 pub struct IdlMissingTypesInstructionAccounts {
     pub lending_market_owner: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
-    type ArrangedAccounts = IdlMissingTypesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, reserve, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(IdlMissingTypesInstructionAccounts {
-            lending_market_owner: lending_market_owner.pubkey,
-            lending_market: lending_market.pubkey,
-            reserve: reserve.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xda063ee90121e852")]
 pub struct InitFarmsForReserve {
     pub mode: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitFarmsForReserve")]
 pub struct InitFarmsForReserveInstructionAccounts {
     pub lending_market_owner: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
@@ -19,31 +21,5 @@ pub struct InitFarmsForReserveInstructionAccounts {
     pub farms_vault_authority: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitFarmsForReserve {
-    type ArrangedAccounts = InitFarmsForReserveInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, lending_market_authority, reserve, farms_program, farms_global_config, farm_state, farms_vault_authority, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitFarmsForReserveInstructionAccounts {
-            lending_market_owner: lending_market_owner.pubkey,
-            lending_market: lending_market.pubkey,
-            lending_market_authority: lending_market_authority.pubkey,
-            reserve: reserve.pubkey,
-            farms_program: farms_program.pubkey,
-            farms_global_config: farms_global_config.pubkey,
-            farm_state: farm_state.pubkey,
-            farms_vault_authority: farms_vault_authority.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfb0ae74c1b0b9f60")]
 pub struct InitObligation {
     pub args: InitObligationArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitObligation")]
 pub struct InitObligationInstructionAccounts {
     pub obligation_owner: solana_pubkey::Pubkey,
     pub fee_payer: solana_pubkey::Pubkey,
@@ -21,30 +23,5 @@ pub struct InitObligationInstructionAccounts {
     pub owner_user_metadata: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitObligation {
-    type ArrangedAccounts = InitObligationInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [obligation_owner, fee_payer, obligation, lending_market, seed1_account, seed2_account, owner_user_metadata, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitObligationInstructionAccounts {
-            obligation_owner: obligation_owner.pubkey,
-            fee_payer: fee_payer.pubkey,
-            obligation: obligation.pubkey,
-            lending_market: lending_market.pubkey,
-            seed1_account: seed1_account.pubkey,
-            seed2_account: seed2_account.pubkey,
-            owner_user_metadata: owner_user_metadata.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
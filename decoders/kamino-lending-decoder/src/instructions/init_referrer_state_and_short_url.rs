@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa513197f64371f5a")]
 pub struct InitReferrerStateAndShortUrl {
     pub short_url: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitReferrerStateAndShortUrl")]
 pub struct InitReferrerStateAndShortUrlInstructionAccounts {
     pub referrer: solana_pubkey::Pubkey,
     pub referrer_state: solana_pubkey::Pubkey,
@@ -15,27 +17,5 @@ pub struct InitReferrerStateAndShortUrlInstructionAccounts {
     pub referrer_user_metadata: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitReferrerStateAndShortUrl {
-    type ArrangedAccounts = InitReferrerStateAndShortUrlInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [referrer, referrer_state, referrer_short_url, referrer_user_metadata, rent, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitReferrerStateAndShortUrlInstructionAccounts {
-            referrer: referrer.pubkey,
-            referrer_state: referrer_state.pubkey,
-            referrer_short_url: referrer_short_url.pubkey,
-            referrer_user_metadata: referrer_user_metadata.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
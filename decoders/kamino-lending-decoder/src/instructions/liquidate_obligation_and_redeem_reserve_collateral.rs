@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb1479abce2854a37")]
 pub struct LiquidateObligationAndRedeemReserveCollateral {
@@ -31,6 +31,7 @@ pub struct LiquidateObligationAndRedeemReserveCollateralInstructionAccounts {
     pub repay_liquidity_token_program: solana_pubkey::Pubkey,
     pub withdraw_liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemReserveCollateral {
@@ -39,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemR
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [liquidator, obligation, lending_market, lending_market_authority, repay_reserve, repay_reserve_liquidity_mint, repay_reserve_liquidity_supply, withdraw_reserve, withdraw_reserve_liquidity_mint, withdraw_reserve_collateral_mint, withdraw_reserve_collateral_supply, withdraw_reserve_liquidity_supply, withdraw_reserve_liquidity_fee_receiver, user_source_liquidity, user_destination_collateral, user_destination_liquidity, collateral_token_program, repay_liquidity_token_program, withdraw_liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [liquidator, obligation, lending_market, lending_market_authority, repay_reserve, repay_reserve_liquidity_mint, repay_reserve_liquidity_supply, withdraw_reserve, withdraw_reserve_liquidity_mint, withdraw_reserve_collateral_mint, withdraw_reserve_collateral_supply, withdraw_reserve_liquidity_supply, withdraw_reserve_liquidity_fee_receiver, user_source_liquidity, user_destination_collateral, user_destination_liquidity, collateral_token_program, repay_liquidity_token_program, withdraw_liquidity_token_program, instruction_sysvar_account, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -68,6 +69,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemR
                 repay_liquidity_token_program: repay_liquidity_token_program.pubkey,
                 withdraw_liquidity_token_program: withdraw_liquidity_token_program.pubkey,
                 instruction_sysvar_account: instruction_sysvar_account.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
@@ -88,6 +88,10 @@ pub enum KaminoLendingInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLendingDecoder {
     type InstructionType = KaminoLendingInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
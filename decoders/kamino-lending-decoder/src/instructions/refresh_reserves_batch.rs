@@ -1,21 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x906e1a67a2ccfc93")]
 pub struct RefreshReservesBatch {
     pub skip_price_updates: bool,
 }
 
-pub struct RefreshReservesBatchInstructionAccounts {}
-
-impl carbon_core::deserialize::ArrangeAccounts for RefreshReservesBatch {
-    type ArrangedAccounts = RefreshReservesBatchInstructionAccounts;
-
-    fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        Some(RefreshReservesBatchInstructionAccounts {})
-    }
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RefreshReservesBatch")]
+pub struct RefreshReservesBatchInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
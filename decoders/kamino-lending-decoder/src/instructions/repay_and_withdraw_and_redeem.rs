@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0236980394606dda")]
 pub struct RepayAndWithdrawAndRedeem {
@@ -9,24 +9,10 @@ pub struct RepayAndWithdrawAndRedeem {
     pub withdraw_collateral_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RepayAndWithdrawAndRedeem")]
 pub struct RepayAndWithdrawAndRedeemInstructionAccounts {
     pub repay_accounts: solana_pubkey::Pubkey,
     pub withdraw_accounts: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RepayAndWithdrawAndRedeem {
-    type ArrangedAccounts = RepayAndWithdrawAndRedeemInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [repay_accounts, withdraw_accounts, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RepayAndWithdrawAndRedeemInstructionAccounts {
-            repay_accounts: repay_accounts.pubkey,
-            withdraw_accounts: withdraw_accounts.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
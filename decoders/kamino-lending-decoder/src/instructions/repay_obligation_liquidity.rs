@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x91b20de14cf09348")]
 pub struct RepayObligationLiquidity {
     pub liquidity_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RepayObligationLiquidity")]
 pub struct RepayObligationLiquidityInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub obligation: solana_pubkey::Pubkey,
@@ -18,30 +20,5 @@ pub struct RepayObligationLiquidityInstructionAccounts {
     pub user_source_liquidity: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RepayObligationLiquidity {
-    type ArrangedAccounts = RepayObligationLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, repay_reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, token_program, instruction_sysvar_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RepayObligationLiquidityInstructionAccounts {
-            owner: owner.pubkey,
-            obligation: obligation.pubkey,
-            lending_market: lending_market.pubkey,
-            repay_reserve: repay_reserve.pubkey,
-            reserve_liquidity_mint: reserve_liquidity_mint.pubkey,
-            reserve_destination_liquidity: reserve_destination_liquidity.pubkey,
-            user_source_liquidity: user_source_liquidity.pubkey,
-            token_program: token_program.pubkey,
-            instruction_sysvar_account: instruction_sysvar_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
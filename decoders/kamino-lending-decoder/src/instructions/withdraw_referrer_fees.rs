@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xab7679c9e98c17e4")]
 pub struct WithdrawReferrerFees {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawReferrerFees")]
 pub struct WithdrawReferrerFeesInstructionAccounts {
     pub referrer: solana_pubkey::Pubkey,
     pub referrer_token_state: solana_pubkey::Pubkey,
@@ -16,30 +18,5 @@ pub struct WithdrawReferrerFeesInstructionAccounts {
     pub lending_market: solana_pubkey::Pubkey,
     pub lending_market_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawReferrerFees {
-    type ArrangedAccounts = WithdrawReferrerFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [referrer, referrer_token_state, reserve, reserve_liquidity_mint, reserve_supply_liquidity, referrer_token_account, lending_market, lending_market_authority, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawReferrerFeesInstructionAccounts {
-            referrer: referrer.pubkey,
-            referrer_token_state: referrer_token_state.pubkey,
-            reserve: reserve.pubkey,
-            reserve_liquidity_mint: reserve_liquidity_mint.pubkey,
-            reserve_supply_liquidity: reserve_supply_liquidity.pubkey,
-            referrer_token_account: referrer_token_account.pubkey,
-            lending_market: lending_market.pubkey,
-            lending_market_authority: lending_market_authority.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
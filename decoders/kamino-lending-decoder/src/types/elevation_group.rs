@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ElevationGroup {
     pub max_liquidation_bonus_bps: u16,
@@ -11,6 +11,7 @@ pub struct ElevationGroup {
     pub allow_new_loans: u8,
     pub max_reserves_as_collateral: u8,
     pub padding0: u8,
+    #[carbon(serialize_as = "base58")]
     pub debt_reserve: solana_pubkey::Pubkey,
     pub padding1: [u64; 4],
 }
@@ -1,9 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ObligationCollateral {
+    #[carbon(serialize_as = "base58")]
     pub deposit_reserve: solana_pubkey::Pubkey,
     pub deposited_amount: u64,
     pub market_value_sf: u128,
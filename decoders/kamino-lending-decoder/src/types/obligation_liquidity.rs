@@ -1,12 +1,13 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ObligationLiquidity {
+    #[carbon(serialize_as = "base58")]
     pub borrow_reserve: solana_pubkey::Pubkey,
     pub cumulative_borrow_rate_bsf: BigFractionBytes,
     pub padding: u64,
@@ -1,9 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SwitchboardConfiguration {
+    #[carbon(serialize_as = "base58")]
     pub price_aggregator: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub twap_aggregator: solana_pubkey::Pubkey,
 }
@@ -1,12 +1,13 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct TokenInfo {
+    #[carbon(serialize_as = "hex")]
     pub name: [u8; 32],
     pub heuristic: PriceHeuristic,
     pub max_twap_divergence_bps: u64,
@@ -16,6 +17,7 @@ pub struct TokenInfo {
     pub switchboard_configuration: SwitchboardConfiguration,
     pub pyth_configuration: PythConfiguration,
     pub block_price_usage: u8,
+    #[carbon(serialize_as = "hex")]
     pub reserved: [u8; 7],
     pub padding: [u64; 19],
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xcef2d7bb8621e094")]
 pub struct FlashTakeOrderEnd {
@@ -10,6 +10,8 @@ pub struct FlashTakeOrderEnd {
     pub tip_amount_permissionless_taking: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FlashTakeOrderEnd")]
 pub struct FlashTakeOrderEndInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub maker: solana_pubkey::Pubkey,
@@ -34,44 +36,5 @@ pub struct FlashTakeOrderEndInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FlashTakeOrderEnd {
-    type ArrangedAccounts = FlashTakeOrderEndInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [taker, maker, global_config, pda_authority, order, input_mint, output_mint, input_vault, taker_input_ata, taker_output_ata, intermediary_output_token_account, maker_output_ata, express_relay, express_relay_metadata, sysvar_instructions, permission, config_router, input_token_program, output_token_program, system_program, rent, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(FlashTakeOrderEndInstructionAccounts {
-            taker: taker.pubkey,
-            maker: maker.pubkey,
-            global_config: global_config.pubkey,
-            pda_authority: pda_authority.pubkey,
-            order: order.pubkey,
-            input_mint: input_mint.pubkey,
-            output_mint: output_mint.pubkey,
-            input_vault: input_vault.pubkey,
-            taker_input_ata: taker_input_ata.pubkey,
-            taker_output_ata: taker_output_ata.pubkey,
-            intermediary_output_token_account: intermediary_output_token_account.pubkey,
-            maker_output_ata: maker_output_ata.pubkey,
-            express_relay: express_relay.pubkey,
-            express_relay_metadata: express_relay_metadata.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            permission: permission.pubkey,
-            config_router: config_router.pubkey,
-            input_token_program: input_token_program.pubkey,
-            output_token_program: output_token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x23765f4de72e8026")]
 pub struct LogUserSwapBalances {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LogUserSwapBalances")]
 pub struct LogUserSwapBalancesInstructionAccounts {
     pub maker: solana_pubkey::Pubkey,
     pub input_mint: solana_pubkey::Pubkey,
@@ -14,28 +16,5 @@ pub struct LogUserSwapBalancesInstructionAccounts {
     pub output_ta: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LogUserSwapBalances {
-    type ArrangedAccounts = LogUserSwapBalancesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [maker, input_mint, output_mint, input_ta, output_ta, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LogUserSwapBalancesInstructionAccounts {
-            maker: maker.pubkey,
-            input_mint: input_mint.pubkey,
-            output_mint: output_mint.pubkey,
-            input_ta: input_ta.pubkey,
-            output_ta: output_ta.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -6,10 +6,12 @@ pub mod flash_take_order_start;
 pub mod initialize_global_config;
 pub mod initialize_vault;
 pub mod log_user_swap_balances;
+#[cfg(feature = "events")]
 pub mod order_display_event;
 pub mod take_order;
 pub mod update_global_config;
 pub mod update_global_config_admin;
+#[cfg(feature = "events")]
 pub mod user_swap_balances_event;
 pub mod withdraw_host_tip;
 
@@ -35,13 +37,19 @@ pub enum KaminoLimitOrderInstruction {
     UpdateGlobalConfigAdmin(update_global_config_admin::UpdateGlobalConfigAdmin),
     WithdrawHostTip(withdraw_host_tip::WithdrawHostTip),
     LogUserSwapBalances(log_user_swap_balances::LogUserSwapBalances),
+    #[cfg(feature = "events")]
     OrderDisplayEvent(order_display_event::OrderDisplayEvent),
+    #[cfg(feature = "events")]
     UserSwapBalancesEvent(user_swap_balances_event::UserSwapBalancesEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLimitOrderDecoder {
     type InstructionType = KaminoLimitOrderInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -61,7 +69,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLimitOrderDecode
             KaminoLimitOrderInstruction::UpdateGlobalConfigAdmin => update_global_config_admin::UpdateGlobalConfigAdmin,
             KaminoLimitOrderInstruction::WithdrawHostTip => withdraw_host_tip::WithdrawHostTip,
             KaminoLimitOrderInstruction::LogUserSwapBalances => log_user_swap_balances::LogUserSwapBalances,
+            #[cfg(feature = "events")]
             KaminoLimitOrderInstruction::OrderDisplayEvent => order_display_event::OrderDisplayEvent,
+            #[cfg(feature = "events")]
             KaminoLimitOrderInstruction::UserSwapBalancesEvent => user_swap_balances_event::UserSwapBalancesEvent,
         )
     }
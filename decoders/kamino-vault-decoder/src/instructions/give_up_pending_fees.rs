@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb1c878866ed99351")]
 pub struct GiveUpPendingFees {
     pub max_amount_to_give_up: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "GiveUpPendingFees")]
 pub struct GiveUpPendingFeesInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
     pub klend_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for GiveUpPendingFees {
-    type ArrangedAccounts = GiveUpPendingFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, klend_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(GiveUpPendingFeesInstructionAccounts {
-            admin_authority: admin_authority.pubkey,
-            vault_state: vault_state.pubkey,
-            klend_program: klend_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
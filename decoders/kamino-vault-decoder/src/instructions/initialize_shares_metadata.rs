@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x030fac72c8008320")]
 pub struct InitializeSharesMetadata {
@@ -10,6 +10,8 @@ pub struct InitializeSharesMetadata {
     pub uri: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeSharesMetadata")]
 pub struct InitializeSharesMetadataInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
@@ -19,29 +21,5 @@ pub struct InitializeSharesMetadataInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeSharesMetadata {
-    type ArrangedAccounts = InitializeSharesMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, shares_mint, base_vault_authority, shares_metadata, system_program, rent, metadata_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeSharesMetadataInstructionAccounts {
-            admin_authority: admin_authority.pubkey,
-            vault_state: vault_state.pubkey,
-            shares_mint: shares_mint.pubkey,
-            base_vault_authority: base_vault_authority.pubkey,
-            shares_metadata: shares_metadata.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            metadata_program: metadata_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
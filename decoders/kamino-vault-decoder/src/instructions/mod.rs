@@ -42,6 +42,10 @@ pub enum KaminoVaultInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoVaultDecoder {
     type InstructionType = KaminoVaultInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
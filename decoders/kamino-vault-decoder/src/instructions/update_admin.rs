@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa1b028d53cb8b3e4")]
 pub struct UpdateAdmin {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateAdmin")]
 pub struct UpdateAdminInstructionAccounts {
     pub pending_admin: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
-    type ArrangedAccounts = UpdateAdminInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pending_admin, vault_state, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateAdminInstructionAccounts {
-            pending_admin: pending_admin.pubkey,
-            vault_state: vault_state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x9b227aa5f589936b")]
 pub struct UpdateSharesMetadata {
@@ -10,32 +10,13 @@ pub struct UpdateSharesMetadata {
     pub uri: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateSharesMetadata")]
 pub struct UpdateSharesMetadataInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
     pub base_vault_authority: solana_pubkey::Pubkey,
     pub shares_metadata: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateSharesMetadata {
-    type ArrangedAccounts = UpdateSharesMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, base_vault_authority, shares_metadata, metadata_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(UpdateSharesMetadataInstructionAccounts {
-            admin_authority: admin_authority.pubkey,
-            vault_state: vault_state.pubkey,
-            base_vault_authority: base_vault_authority.pubkey,
-            shares_metadata: shares_metadata.pubkey,
-            metadata_program: metadata_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct LastUpdate {
     pub slot: u64,
     pub stale: u8,
     pub price_status: u8,
+    #[carbon(serialize_as = "hex")]
     pub placeholder: [u8; 6],
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ReserveCollateral {
+    #[carbon(serialize_as = "base58")]
     pub mint_pubkey: solana_pubkey::Pubkey,
     pub mint_total_supply: u64,
+    #[carbon(serialize_as = "base58")]
     pub supply_vault: solana_pubkey::Pubkey,
     pub padding1: [u128; 32],
     pub padding2: [u128; 32],
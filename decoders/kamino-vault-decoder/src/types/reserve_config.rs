@@ -1,16 +1,18 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ReserveConfig {
     pub status: u8,
     pub asset_tier: u8,
     pub host_fixed_interest_rate_bps: u16,
+    #[carbon(serialize_as = "hex")]
     pub reserved2: [u8; 2],
+    #[carbon(serialize_as = "hex")]
     pub reserved3: [u8; 8],
     pub protocol_take_rate_pct: u8,
     pub protocol_liquidation_fee_pct: u8,
@@ -29,10 +31,12 @@ pub struct ReserveConfig {
     pub token_info: TokenInfo,
     pub deposit_withdrawal_cap: WithdrawalCaps,
     pub debt_withdrawal_cap: WithdrawalCaps,
+    #[carbon(serialize_as = "hex")]
     pub elevation_groups: [u8; 20],
     pub disable_usage_as_coll_outside_emode: u8,
     pub utilization_limit_block_borrowing_above: u8,
     pub autodeleverage_enabled: u8,
+    #[carbon(serialize_as = "hex")]
     pub reserved1: [u8; 1],
     pub borrow_limit_outside_elevation_group: u64,
     pub borrow_limit_against_this_collateral_in_elevation_group: [u64; 32],
@@ -1,14 +1,17 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ReserveLiquidity {
+    #[carbon(serialize_as = "base58")]
     pub mint_pubkey: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub supply_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub fee_vault: solana_pubkey::Pubkey,
     pub available_amount: u64,
     pub borrowed_amount_sf: u128,
@@ -22,6 +25,7 @@ pub struct ReserveLiquidity {
     pub accumulated_referrer_fees_sf: u128,
     pub pending_referrer_fees_sf: u128,
     pub absolute_referral_rate_sf: u128,
+    #[carbon(serialize_as = "base58")]
     pub token_program: solana_pubkey::Pubkey,
     #[serde(with = "BigArray")]
     pub padding2: [u64; 51],
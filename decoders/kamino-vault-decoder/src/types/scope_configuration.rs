@@ -1,9 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ScopeConfiguration {
+    #[carbon(serialize_as = "base58")]
     pub price_feed: solana_pubkey::Pubkey,
     pub price_chain: [u16; 4],
     pub twap_chain: [u16; 4],
@@ -1,13 +1,15 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct VaultAllocation {
+    #[carbon(serialize_as = "base58")]
     pub reserve: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub ctoken_vault: solana_pubkey::Pubkey,
     pub target_allocation_weight: u64,
     pub token_allocation_cap: u64,
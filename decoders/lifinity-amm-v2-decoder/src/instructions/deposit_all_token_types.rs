@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x205f453c4b4fcdee")]
 pub struct DepositAllTokenTypes {
@@ -10,7 +10,8 @@ pub struct DepositAllTokenTypes {
     pub maximum_token_b_amount: u64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "DepositAllTokenTypes")]
 pub struct DepositAllTokenTypesInstructionAccounts {
     pub amm: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -22,31 +23,5 @@ pub struct DepositAllTokenTypesInstructionAccounts {
     pub pool_mint: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositAllTokenTypes {
-    type ArrangedAccounts = DepositAllTokenTypesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [amm, authority, user_transfer_authority_info, source_a_info, source_b_info, token_a, token_b, pool_mint, destination, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositAllTokenTypesInstructionAccounts {
-            amm: amm.pubkey,
-            authority: authority.pubkey,
-            user_transfer_authority_info: user_transfer_authority_info.pubkey,
-            source_a_info: source_a_info.pubkey,
-            source_b_info: source_b_info.pubkey,
-            token_a: token_a.pubkey,
-            token_b: token_b.pubkey,
-            pool_mint: pool_mint.pubkey,
-            destination: destination.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
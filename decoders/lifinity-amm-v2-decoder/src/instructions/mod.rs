@@ -24,6 +24,10 @@ pub enum LifinityAmmV2Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for LifinityAmmV2Decoder {
     type InstructionType = LifinityAmmV2Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -173,6 +177,7 @@ mod tests {
             oracle_pc_account: solana_pubkey::Pubkey::from_str_const(
                 "3ZDBff7jeQaksmGvmkRix36rU159EBDjYiPThvV8QVZM",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = LifinityAmmV2Decoder;
@@ -293,6 +298,7 @@ mod tests {
                 token_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = LifinityAmmV2Decoder;
@@ -415,6 +421,7 @@ mod tests {
                 token_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = LifinityAmmV2Decoder;
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x047e74353005d41f")]
 pub struct LendingAccountBorrow {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingAccountBorrow")]
 pub struct LendingAccountBorrowInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub marginfi_account: solana_pubkey::Pubkey,
@@ -17,29 +19,5 @@ pub struct LendingAccountBorrowInstructionAccounts {
     pub bank_liquidity_vault_authority: solana_pubkey::Pubkey,
     pub bank_liquidity_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingAccountBorrow {
-    type ArrangedAccounts = LendingAccountBorrowInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LendingAccountBorrowInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            marginfi_account: marginfi_account.pubkey,
-            signer: signer.pubkey,
-            bank: bank.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            bank_liquidity_vault_authority: bank_liquidity_vault_authority.pubkey,
-            bank_liquidity_vault: bank_liquidity_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
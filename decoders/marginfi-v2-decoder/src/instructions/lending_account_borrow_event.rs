@@ -1,15 +1,17 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1ddf60510a9c631a3b")]
 pub struct LendingAccountBorrowEvent {
     pub header: AccountEventHeader,
+    #[carbon(serialize_as = "base58")]
     pub bank: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub amount: u64,
 }
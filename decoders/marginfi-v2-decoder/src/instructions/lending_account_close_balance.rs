@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf5362904f3ca1f11")]
 pub struct LendingAccountCloseBalance {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingAccountCloseBalance")]
 pub struct LendingAccountCloseBalanceInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub marginfi_account: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingAccountCloseBalance {
-    type ArrangedAccounts = LendingAccountCloseBalanceInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(LendingAccountCloseBalanceInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            marginfi_account: marginfi_account.pubkey,
-            signer: signer.pubkey,
-            bank: bank.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x24484a13d2d2c0c0")]
 pub struct LendingAccountWithdraw {
@@ -9,6 +9,8 @@ pub struct LendingAccountWithdraw {
     pub withdraw_all: Option<bool>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingAccountWithdraw")]
 pub struct LendingAccountWithdrawInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub marginfi_account: solana_pubkey::Pubkey,
@@ -18,29 +20,5 @@ pub struct LendingAccountWithdrawInstructionAccounts {
     pub bank_liquidity_vault_authority: solana_pubkey::Pubkey,
     pub bank_liquidity_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdraw {
-    type ArrangedAccounts = LendingAccountWithdrawInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LendingAccountWithdrawInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            marginfi_account: marginfi_account.pubkey,
-            signer: signer.pubkey,
-            bank: bank.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            bank_liquidity_vault_authority: bank_liquidity_vault_authority.pubkey,
-            bank_liquidity_vault: bank_liquidity_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6cc91e572f4161bc")]
 pub struct LendingPoolAccrueBankInterest {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingPoolAccrueBankInterest")]
 pub struct LendingPoolAccrueBankInterestInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAccrueBankInterest {
-    type ArrangedAccounts = LendingPoolAccrueBankInterestInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, bank, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(LendingPoolAccrueBankInterestInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            bank: bank.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
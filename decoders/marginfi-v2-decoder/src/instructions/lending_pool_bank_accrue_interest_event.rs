@@ -1,13 +1,15 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0xe445a52e51cb9a1d6875bb9c6f9a6aba")]
 pub struct LendingPoolBankAccrueInterestEvent {
     pub header: GroupEventHeader,
+    #[carbon(serialize_as = "base58")]
     pub bank: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub delta: u64,
     pub fees_collected: f64,
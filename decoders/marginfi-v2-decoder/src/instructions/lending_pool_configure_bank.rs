@@ -1,36 +1,21 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x79ad9c285d9438ed")]
 pub struct LendingPoolConfigureBank {
     pub bank_config_opt: BankConfigOpt,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingPoolConfigureBank")]
 pub struct LendingPoolConfigureBankInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingPoolConfigureBank {
-    type ArrangedAccounts = LendingPoolConfigureBankInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(LendingPoolConfigureBankInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            admin: admin.pubkey,
-            bank: bank.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x37d5e0a89935c528")]
 pub struct LendingPoolUpdateEmissionsParameters {
@@ -10,6 +10,8 @@ pub struct LendingPoolUpdateEmissionsParameters {
     pub additional_emissions: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LendingPoolUpdateEmissionsParameters")]
 pub struct LendingPoolUpdateEmissionsParametersInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
@@ -18,28 +20,5 @@ pub struct LendingPoolUpdateEmissionsParametersInstructionAccounts {
     pub emissions_token_account: solana_pubkey::Pubkey,
     pub emissions_funding_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LendingPoolUpdateEmissionsParameters {
-    type ArrangedAccounts = LendingPoolUpdateEmissionsParametersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, emissions_mint, emissions_token_account, emissions_funding_account, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LendingPoolUpdateEmissionsParametersInstructionAccounts {
-            marginfi_group: marginfi_group.pubkey,
-            admin: admin.pubkey,
-            bank: bank.pubkey,
-            emissions_mint: emissions_mint.pubkey,
-            emissions_token_account: emissions_token_account.pubkey,
-            emissions_funding_account: emissions_funding_account.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,14 +1,16 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d703d8c84fb5c5aca")]
 pub struct MarginfiAccountTransferAccountAuthorityEvent {
     pub header: AccountEventHeader,
+    #[carbon(serialize_as = "base58")]
     pub old_account_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_account_authority: solana_pubkey::Pubkey,
 }
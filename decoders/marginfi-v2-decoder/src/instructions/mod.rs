@@ -1,37 +1,51 @@
 use {super::MarginfiV2Decoder, crate::PROGRAM_ID};
 pub mod lending_account_borrow;
+#[cfg(feature = "events")]
 pub mod lending_account_borrow_event;
 pub mod lending_account_close_balance;
 pub mod lending_account_deposit;
+#[cfg(feature = "events")]
 pub mod lending_account_deposit_event;
 pub mod lending_account_end_flashloan;
 pub mod lending_account_liquidate;
+#[cfg(feature = "events")]
 pub mod lending_account_liquidate_event;
 pub mod lending_account_repay;
+#[cfg(feature = "events")]
 pub mod lending_account_repay_event;
 pub mod lending_account_settle_emissions;
 pub mod lending_account_start_flashloan;
 pub mod lending_account_withdraw;
 pub mod lending_account_withdraw_emissions;
+#[cfg(feature = "events")]
 pub mod lending_account_withdraw_event;
 pub mod lending_pool_accrue_bank_interest;
 pub mod lending_pool_add_bank;
 pub mod lending_pool_add_bank_with_seed;
+#[cfg(feature = "events")]
 pub mod lending_pool_bank_accrue_interest_event;
+#[cfg(feature = "events")]
 pub mod lending_pool_bank_collect_fees_event;
+#[cfg(feature = "events")]
 pub mod lending_pool_bank_configure_event;
+#[cfg(feature = "events")]
 pub mod lending_pool_bank_create_event;
+#[cfg(feature = "events")]
 pub mod lending_pool_bank_handle_bankruptcy_event;
 pub mod lending_pool_collect_bank_fees;
 pub mod lending_pool_configure_bank;
 pub mod lending_pool_handle_bankruptcy;
 pub mod lending_pool_setup_emissions;
 pub mod lending_pool_update_emissions_parameters;
+#[cfg(feature = "events")]
 pub mod marginfi_account_create_event;
 pub mod marginfi_account_initialize;
+#[cfg(feature = "events")]
 pub mod marginfi_account_transfer_account_authority_event;
 pub mod marginfi_group_configure;
+#[cfg(feature = "events")]
 pub mod marginfi_group_configure_event;
+#[cfg(feature = "events")]
 pub mod marginfi_group_create_event;
 pub mod marginfi_group_initialize;
 pub mod set_account_flag;
@@ -66,25 +80,43 @@ pub enum MarginfiV2Instruction {
     SetAccountFlag(set_account_flag::SetAccountFlag),
     UnsetAccountFlag(unset_account_flag::UnsetAccountFlag),
     SetNewAccountAuthority(set_new_account_authority::SetNewAccountAuthority),
+    #[cfg(feature = "events")]
     MarginfiGroupCreateEvent(marginfi_group_create_event::MarginfiGroupCreateEvent),
+    #[cfg(feature = "events")]
     MarginfiGroupConfigureEvent(marginfi_group_configure_event::MarginfiGroupConfigureEvent),
+    #[cfg(feature = "events")]
     LendingPoolBankCreateEvent(lending_pool_bank_create_event::LendingPoolBankCreateEvent),
+    #[cfg(feature = "events")]
     LendingPoolBankConfigureEvent(lending_pool_bank_configure_event::LendingPoolBankConfigureEvent),
+    #[cfg(feature = "events")]
     LendingPoolBankAccrueInterestEvent(lending_pool_bank_accrue_interest_event::LendingPoolBankAccrueInterestEvent),
+    #[cfg(feature = "events")]
     LendingPoolBankCollectFeesEvent(lending_pool_bank_collect_fees_event::LendingPoolBankCollectFeesEvent),
+    #[cfg(feature = "events")]
     LendingPoolBankHandleBankruptcyEvent(lending_pool_bank_handle_bankruptcy_event::LendingPoolBankHandleBankruptcyEvent),
+    #[cfg(feature = "events")]
     MarginfiAccountCreateEvent(marginfi_account_create_event::MarginfiAccountCreateEvent),
+    #[cfg(feature = "events")]
     LendingAccountDepositEvent(lending_account_deposit_event::LendingAccountDepositEvent),
+    #[cfg(feature = "events")]
     LendingAccountRepayEvent(lending_account_repay_event::LendingAccountRepayEvent),
+    #[cfg(feature = "events")]
     LendingAccountBorrowEvent(lending_account_borrow_event::LendingAccountBorrowEvent),
+    #[cfg(feature = "events")]
     LendingAccountWithdrawEvent(lending_account_withdraw_event::LendingAccountWithdrawEvent),
+    #[cfg(feature = "events")]
     LendingAccountLiquidateEvent(lending_account_liquidate_event::LendingAccountLiquidateEvent),
+    #[cfg(feature = "events")]
     MarginfiAccountTransferAccountAuthorityEvent(marginfi_account_transfer_account_authority_event::MarginfiAccountTransferAccountAuthorityEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MarginfiV2Decoder {
     type InstructionType = MarginfiV2Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -117,19 +149,33 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MarginfiV2Decoder {
             MarginfiV2Instruction::SetAccountFlag => set_account_flag::SetAccountFlag,
             MarginfiV2Instruction::UnsetAccountFlag => unset_account_flag::UnsetAccountFlag,
             MarginfiV2Instruction::SetNewAccountAuthority => set_new_account_authority::SetNewAccountAuthority,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::MarginfiGroupCreateEvent => marginfi_group_create_event::MarginfiGroupCreateEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::MarginfiGroupConfigureEvent => marginfi_group_configure_event::MarginfiGroupConfigureEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingPoolBankCreateEvent => lending_pool_bank_create_event::LendingPoolBankCreateEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingPoolBankConfigureEvent => lending_pool_bank_configure_event::LendingPoolBankConfigureEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingPoolBankAccrueInterestEvent => lending_pool_bank_accrue_interest_event::LendingPoolBankAccrueInterestEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingPoolBankCollectFeesEvent => lending_pool_bank_collect_fees_event::LendingPoolBankCollectFeesEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingPoolBankHandleBankruptcyEvent => lending_pool_bank_handle_bankruptcy_event::LendingPoolBankHandleBankruptcyEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::MarginfiAccountCreateEvent => marginfi_account_create_event::MarginfiAccountCreateEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingAccountDepositEvent => lending_account_deposit_event::LendingAccountDepositEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingAccountRepayEvent => lending_account_repay_event::LendingAccountRepayEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingAccountBorrowEvent => lending_account_borrow_event::LendingAccountBorrowEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingAccountWithdrawEvent => lending_account_withdraw_event::LendingAccountWithdrawEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::LendingAccountLiquidateEvent => lending_account_liquidate_event::LendingAccountLiquidateEvent,
+            #[cfg(feature = "events")]
             MarginfiV2Instruction::MarginfiAccountTransferAccountAuthorityEvent => marginfi_account_transfer_account_authority_event::MarginfiAccountTransferAccountAuthorityEvent,
         )
     }
@@ -1,11 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AccountEventHeader {
+    #[carbon(serialize_as = "base58")]
     pub signer: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub marginfi_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub marginfi_account_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub marginfi_group: solana_pubkey::Pubkey,
 }
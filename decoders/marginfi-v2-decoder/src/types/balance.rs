@@ -1,14 +1,16 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Balance {
     pub active: bool,
+    #[carbon(serialize_as = "base58")]
     pub bank_pk: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "hex")]
     pub auto_padding_0: [u8; 7],
     pub asset_shares: WrappedI80F48,
     pub liability_shares: WrappedI80F48,
@@ -1,10 +1,10 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct BankConfig {
     pub asset_weight_init: WrappedI80F48,
@@ -15,10 +15,13 @@ pub struct BankConfig {
     pub interest_rate_config: InterestRateConfig,
     pub operational_state: BankOperationalState,
     pub oracle_setup: OracleSetup,
+    #[carbon(serialize_as = "base58")]
     pub oracle_keys: [solana_pubkey::Pubkey; 5],
+    #[carbon(serialize_as = "hex")]
     pub auto_padding_0: [u8; 6],
     pub borrow_limit: u64,
     pub risk_tier: RiskTier,
+    #[carbon(serialize_as = "hex")]
     pub auto_padding_1: [u8; 7],
     pub total_asset_value_init_limit: u64,
     pub padding: [u64; 5],
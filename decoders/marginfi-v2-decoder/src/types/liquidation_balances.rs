@@ -1,6 +1,6 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
 pub struct LiquidationBalances {
     pub liquidatee_asset_balance: f64,
     pub liquidatee_liability_balance: f64,
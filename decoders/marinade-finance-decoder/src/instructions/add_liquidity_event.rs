@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d1bb299ba2fc48c2d")]
 pub struct AddLiquidityEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub sol_owner: solana_pubkey::Pubkey,
     pub user_sol_balance: u64,
     pub user_lp_balance: u64,
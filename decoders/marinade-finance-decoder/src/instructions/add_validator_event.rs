@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dbee7aaf40ee38142")]
 pub struct AddValidatorEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub validator: solana_pubkey::Pubkey,
     pub index: u32,
     pub score: u32,
@@ -1,13 +1,14 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1de46f2318bb4ee08a")]
 pub struct ChangeAuthorityEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub admin_change: Option<PubkeyValueChange>,
     pub validator_manager_change: Option<PubkeyValueChange>,
@@ -1,13 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d5d0f46aa308cd4db")]
 pub struct ClaimEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
+    #[carbon(serialize_as = "base58")]
     pub ticket: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub beneficiary: solana_pubkey::Pubkey,
     pub circulating_ticket_balance: u64,
     pub circulating_ticket_count: u64,
@@ -1,34 +1,20 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0a18a8775630e111")]
 pub struct ConfigLp {
     pub params: ConfigLpParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ConfigLp")]
 pub struct ConfigLpInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ConfigLp {
-    type ArrangedAccounts = ConfigLpInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ConfigLpInstructionAccounts {
-            state: state.pubkey,
-            admin_authority: admin_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
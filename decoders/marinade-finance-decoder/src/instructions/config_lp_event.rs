@@ -1,13 +1,14 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d9fccc08a4491e094")]
 pub struct ConfigLpEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub min_fee_change: Option<FeeValueChange>,
     pub max_fee_change: Option<FeeValueChange>,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa59ee561a8dcbbe1")]
 pub struct DeactivateStake {
@@ -9,6 +9,8 @@ pub struct DeactivateStake {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeactivateStake")]
 pub struct DeactivateStakeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub reserve_pda: solana_pubkey::Pubkey,
@@ -24,35 +26,5 @@ pub struct DeactivateStakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeactivateStake {
-    type ArrangedAccounts = DeactivateStakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, reserve_pda, validator_list, stake_list, stake_account, stake_deposit_authority, split_stake_account, split_stake_rent_payer, clock, rent, epoch_schedule, stake_history, system_program, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DeactivateStakeInstructionAccounts {
-            state: state.pubkey,
-            reserve_pda: reserve_pda.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            stake_account: stake_account.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            split_stake_account: split_stake_account.pubkey,
-            split_stake_rent_payer: split_stake_rent_payer.pubkey,
-            clock: clock.pubkey,
-            rent: rent.pubkey,
-            epoch_schedule: epoch_schedule.pubkey,
-            stake_history: stake_history.pubkey,
-            system_program: system_program.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
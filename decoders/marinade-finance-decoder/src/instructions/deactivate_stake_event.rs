@@ -1,20 +1,23 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d0236b8da4eb5a375")]
 pub struct DeactivateStakeEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
     pub stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub stake_account: solana_pubkey::Pubkey,
     pub last_update_stake_delegation: u64,
     pub split_stake_account: Option<SplitStakeAccountInfo>,
     pub validator_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator_vote: solana_pubkey::Pubkey,
     pub total_stake_target: u64,
     pub validator_stake_target: u64,
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6e827329a466023b")]
 pub struct DepositStakeAccount {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DepositStakeAccount")]
 pub struct DepositStakeAccountInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
@@ -24,36 +26,5 @@ pub struct DepositStakeAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DepositStakeAccount {
-    type ArrangedAccounts = DepositStakeAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_list, stake_list, stake_account, stake_authority, duplication_flag, rent_payer, msol_mint, mint_to, msol_mint_authority, clock, rent, system_program, token_program, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DepositStakeAccountInstructionAccounts {
-            state: state.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            stake_account: stake_account.pubkey,
-            stake_authority: stake_authority.pubkey,
-            duplication_flag: duplication_flag.pubkey,
-            rent_payer: rent_payer.pubkey,
-            msol_mint: msol_mint.pubkey,
-            mint_to: mint_to.pubkey,
-            msol_mint_authority: msol_mint_authority.pubkey,
-            clock: clock.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1de7cb76604b7446e4")]
 pub struct DepositStakeAccountEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub stake: solana_pubkey::Pubkey,
     pub delegated: u64,
+    #[carbon(serialize_as = "base58")]
     pub withdrawer: solana_pubkey::Pubkey,
     pub stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator: solana_pubkey::Pubkey,
     pub validator_index: u32,
     pub validator_active_balance: u64,
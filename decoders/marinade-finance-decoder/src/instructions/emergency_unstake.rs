@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7b45a8c3b7d5c7d6")]
 pub struct EmergencyUnstake {
@@ -9,6 +9,8 @@ pub struct EmergencyUnstake {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EmergencyUnstake")]
 pub struct EmergencyUnstakeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub validator_manager_authority: solana_pubkey::Pubkey,
@@ -18,29 +20,5 @@ pub struct EmergencyUnstakeInstructionAccounts {
     pub stake_deposit_authority: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EmergencyUnstake {
-    type ArrangedAccounts = EmergencyUnstakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, clock, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(EmergencyUnstakeInstructionAccounts {
-            state: state.pubkey,
-            validator_manager_authority: validator_manager_authority.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            stake_account: stake_account.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            clock: clock.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
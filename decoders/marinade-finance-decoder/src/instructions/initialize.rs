@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xafaf6d1f0d989bed")]
 pub struct Initialize {
     pub data: InitializeData,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Initialize")]
 pub struct InitializeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub reserve_pda: solana_pubkey::Pubkey,
@@ -22,31 +24,5 @@ pub struct InitializeInstructionAccounts {
     pub treasury_msol_account: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Initialize {
-    type ArrangedAccounts = InitializeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, reserve_pda, stake_list, validator_list, msol_mint, operational_sol_account, liq_pool, treasury_msol_account, clock, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeInstructionAccounts {
-            state: state.pubkey,
-            reserve_pda: reserve_pda.pubkey,
-            stake_list: stake_list.pubkey,
-            validator_list: validator_list.pubkey,
-            msol_mint: msol_mint.pubkey,
-            operational_sol_account: operational_sol_account.pubkey,
-            liq_pool: liq_pool.pubkey,
-            treasury_msol_account: treasury_msol_account.pubkey,
-            clock: clock.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
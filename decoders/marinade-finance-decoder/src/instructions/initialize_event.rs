@@ -1,20 +1,28 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dceafa9d0f1d223dd")]
 pub struct InitializeEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub params: InitializeData,
+    #[carbon(serialize_as = "base58")]
     pub stake_list: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub validator_list: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub msol_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub operational_sol_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub lp_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub lp_msol_leg: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub treasury_msol_account: solana_pubkey::Pubkey,
 }
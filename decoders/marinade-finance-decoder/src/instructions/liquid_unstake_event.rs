@@ -1,14 +1,16 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dad05930f050ec274")]
 pub struct LiquidUnstakeEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub msol_owner: solana_pubkey::Pubkey,
     pub liq_pool_sol_balance: u64,
     pub liq_pool_msol_balance: u64,
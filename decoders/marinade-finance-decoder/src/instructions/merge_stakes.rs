@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd8248de1f34e7ded")]
 pub struct MergeStakes {
@@ -10,6 +10,8 @@ pub struct MergeStakes {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MergeStakes")]
 pub struct MergeStakesInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub stake_list: solana_pubkey::Pubkey,
@@ -22,32 +24,5 @@ pub struct MergeStakesInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub stake_history: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MergeStakes {
-    type ArrangedAccounts = MergeStakesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, stake_list, validator_list, destination_stake, source_stake, stake_deposit_authority, stake_withdraw_authority, operational_sol_account, clock, stake_history, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MergeStakesInstructionAccounts {
-            state: state.pubkey,
-            stake_list: stake_list.pubkey,
-            validator_list: validator_list.pubkey,
-            destination_stake: destination_stake.pubkey,
-            source_stake: source_stake.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            stake_withdraw_authority: stake_withdraw_authority.pubkey,
-            operational_sol_account: operational_sol_account.pubkey,
-            clock: clock.pubkey,
-            stake_history: stake_history.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
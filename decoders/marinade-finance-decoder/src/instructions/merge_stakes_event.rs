@@ -1,19 +1,23 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d499c45e9200e9641")]
 pub struct MergeStakesEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
     pub destination_stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub destination_stake_account: solana_pubkey::Pubkey,
     pub last_update_destination_stake_delegation: u64,
     pub source_stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub source_stake_account: solana_pubkey::Pubkey,
     pub last_update_source_stake_delegation: u64,
     pub validator_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator_vote: solana_pubkey::Pubkey,
     pub extra_delegated: u64,
     pub returned_stake_rent: u64,
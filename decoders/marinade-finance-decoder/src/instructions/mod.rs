@@ -1,56 +1,81 @@
 use {super::MarinadeFinanceDecoder, crate::PROGRAM_ID};
 pub mod add_liquidity;
+#[cfg(feature = "events")]
 pub mod add_liquidity_event;
 pub mod add_validator;
+#[cfg(feature = "events")]
 pub mod add_validator_event;
 pub mod change_authority;
+#[cfg(feature = "events")]
 pub mod change_authority_event;
 pub mod claim;
+#[cfg(feature = "events")]
 pub mod claim_event;
 pub mod config_lp;
+#[cfg(feature = "events")]
 pub mod config_lp_event;
 pub mod config_marinade;
+#[cfg(feature = "events")]
 pub mod config_marinade_event;
 pub mod config_validator_system;
 pub mod deactivate_stake;
+#[cfg(feature = "events")]
 pub mod deactivate_stake_event;
 pub mod deposit;
+#[cfg(feature = "events")]
 pub mod deposit_event;
 pub mod deposit_stake_account;
+#[cfg(feature = "events")]
 pub mod deposit_stake_account_event;
+#[cfg(feature = "events")]
 pub mod emergency_pause_event;
 pub mod emergency_unstake;
 pub mod initialize;
+#[cfg(feature = "events")]
 pub mod initialize_event;
 pub mod liquid_unstake;
+#[cfg(feature = "events")]
 pub mod liquid_unstake_event;
 pub mod merge_stakes;
+#[cfg(feature = "events")]
 pub mod merge_stakes_event;
 pub mod order_unstake;
+#[cfg(feature = "events")]
 pub mod order_unstake_event;
 pub mod partial_unstake;
 pub mod pause;
 pub mod realloc_stake_list;
+#[cfg(feature = "events")]
 pub mod realloc_stake_list_event;
 pub mod realloc_validator_list;
+#[cfg(feature = "events")]
 pub mod realloc_validator_list_event;
 pub mod redelegate;
+#[cfg(feature = "events")]
 pub mod redelegate_event;
 pub mod remove_liquidity;
+#[cfg(feature = "events")]
 pub mod remove_liquidity_event;
 pub mod remove_validator;
+#[cfg(feature = "events")]
 pub mod remove_validator_event;
 pub mod resume;
+#[cfg(feature = "events")]
 pub mod resume_event;
 pub mod set_validator_score;
+#[cfg(feature = "events")]
 pub mod set_validator_score_event;
 pub mod stake_reserve;
+#[cfg(feature = "events")]
 pub mod stake_reserve_event;
 pub mod update_active;
+#[cfg(feature = "events")]
 pub mod update_active_event;
 pub mod update_deactivated;
+#[cfg(feature = "events")]
 pub mod update_deactivated_event;
 pub mod withdraw_stake_account;
+#[cfg(feature = "events")]
 pub mod withdraw_stake_account_event;
 
 #[derive(
@@ -92,36 +117,65 @@ pub enum MarinadeFinanceInstruction {
     WithdrawStakeAccount(withdraw_stake_account::WithdrawStakeAccount),
     ReallocValidatorList(realloc_validator_list::ReallocValidatorList),
     ReallocStakeList(realloc_stake_list::ReallocStakeList),
+    #[cfg(feature = "events")]
     ChangeAuthorityEvent(change_authority_event::ChangeAuthorityEvent),
+    #[cfg(feature = "events")]
     ConfigLpEvent(config_lp_event::ConfigLpEvent),
+    #[cfg(feature = "events")]
     ConfigMarinadeEvent(config_marinade_event::ConfigMarinadeEvent),
+    #[cfg(feature = "events")]
     InitializeEvent(initialize_event::InitializeEvent),
+    #[cfg(feature = "events")]
     EmergencyPauseEvent(emergency_pause_event::EmergencyPauseEvent),
+    #[cfg(feature = "events")]
     ResumeEvent(resume_event::ResumeEvent),
+    #[cfg(feature = "events")]
     ReallocValidatorListEvent(realloc_validator_list_event::ReallocValidatorListEvent),
+    #[cfg(feature = "events")]
     ReallocStakeListEvent(realloc_stake_list_event::ReallocStakeListEvent),
+    #[cfg(feature = "events")]
     DeactivateStakeEvent(deactivate_stake_event::DeactivateStakeEvent),
+    #[cfg(feature = "events")]
     MergeStakesEvent(merge_stakes_event::MergeStakesEvent),
+    #[cfg(feature = "events")]
     RedelegateEvent(redelegate_event::RedelegateEvent),
+    #[cfg(feature = "events")]
     StakeReserveEvent(stake_reserve_event::StakeReserveEvent),
+    #[cfg(feature = "events")]
     UpdateActiveEvent(update_active_event::UpdateActiveEvent),
+    #[cfg(feature = "events")]
     UpdateDeactivatedEvent(update_deactivated_event::UpdateDeactivatedEvent),
+    #[cfg(feature = "events")]
     ClaimEvent(claim_event::ClaimEvent),
+    #[cfg(feature = "events")]
     OrderUnstakeEvent(order_unstake_event::OrderUnstakeEvent),
+    #[cfg(feature = "events")]
     AddLiquidityEvent(add_liquidity_event::AddLiquidityEvent),
+    #[cfg(feature = "events")]
     LiquidUnstakeEvent(liquid_unstake_event::LiquidUnstakeEvent),
+    #[cfg(feature = "events")]
     RemoveLiquidityEvent(remove_liquidity_event::RemoveLiquidityEvent),
+    #[cfg(feature = "events")]
     AddValidatorEvent(add_validator_event::AddValidatorEvent),
+    #[cfg(feature = "events")]
     RemoveValidatorEvent(remove_validator_event::RemoveValidatorEvent),
+    #[cfg(feature = "events")]
     SetValidatorScoreEvent(set_validator_score_event::SetValidatorScoreEvent),
+    #[cfg(feature = "events")]
     DepositStakeAccountEvent(deposit_stake_account_event::DepositStakeAccountEvent),
+    #[cfg(feature = "events")]
     DepositEvent(deposit_event::DepositEvent),
+    #[cfg(feature = "events")]
     WithdrawStakeAccountEvent(withdraw_stake_account_event::WithdrawStakeAccountEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MarinadeFinanceDecoder {
     type InstructionType = MarinadeFinanceInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -158,30 +212,55 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MarinadeFinanceDecoder
             MarinadeFinanceInstruction::WithdrawStakeAccount => withdraw_stake_account::WithdrawStakeAccount,
             MarinadeFinanceInstruction::ReallocValidatorList => realloc_validator_list::ReallocValidatorList,
             MarinadeFinanceInstruction::ReallocStakeList => realloc_stake_list::ReallocStakeList,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ChangeAuthorityEvent => change_authority_event::ChangeAuthorityEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ConfigLpEvent => config_lp_event::ConfigLpEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ConfigMarinadeEvent => config_marinade_event::ConfigMarinadeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::InitializeEvent => initialize_event::InitializeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::EmergencyPauseEvent => emergency_pause_event::EmergencyPauseEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ResumeEvent => resume_event::ResumeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ReallocValidatorListEvent => realloc_validator_list_event::ReallocValidatorListEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ReallocStakeListEvent => realloc_stake_list_event::ReallocStakeListEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::DeactivateStakeEvent => deactivate_stake_event::DeactivateStakeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::MergeStakesEvent => merge_stakes_event::MergeStakesEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::RedelegateEvent => redelegate_event::RedelegateEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::StakeReserveEvent => stake_reserve_event::StakeReserveEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::UpdateActiveEvent => update_active_event::UpdateActiveEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::UpdateDeactivatedEvent => update_deactivated_event::UpdateDeactivatedEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::ClaimEvent => claim_event::ClaimEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::OrderUnstakeEvent => order_unstake_event::OrderUnstakeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::AddLiquidityEvent => add_liquidity_event::AddLiquidityEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::LiquidUnstakeEvent => liquid_unstake_event::LiquidUnstakeEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::RemoveLiquidityEvent => remove_liquidity_event::RemoveLiquidityEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::AddValidatorEvent => add_validator_event::AddValidatorEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::RemoveValidatorEvent => remove_validator_event::RemoveValidatorEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::SetValidatorScoreEvent => set_validator_score_event::SetValidatorScoreEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::DepositStakeAccountEvent => deposit_stake_account_event::DepositStakeAccountEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::DepositEvent => deposit_event::DepositEvent,
+            #[cfg(feature = "events")]
             MarinadeFinanceInstruction::WithdrawStakeAccountEvent => withdraw_stake_account_event::WithdrawStakeAccountEvent,
         )
     }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x61a7906b75be8024")]
 pub struct OrderUnstake {
     pub msol_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OrderUnstake")]
 pub struct OrderUnstakeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub msol_mint: solana_pubkey::Pubkey,
@@ -17,29 +19,5 @@ pub struct OrderUnstakeInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OrderUnstake {
-    type ArrangedAccounts = OrderUnstakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, burn_msol_from, burn_msol_authority, new_ticket_account, clock, rent, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(OrderUnstakeInstructionAccounts {
-            state: state.pubkey,
-            msol_mint: msol_mint.pubkey,
-            burn_msol_from: burn_msol_from.pubkey,
-            burn_msol_authority: burn_msol_authority.pubkey,
-            new_ticket_account: new_ticket_account.pubkey,
-            clock: clock.pubkey,
-            rent: rent.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x37f1cddd2d72cda3")]
 pub struct PartialUnstake {
@@ -10,6 +10,8 @@ pub struct PartialUnstake {
     pub desired_unstake_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PartialUnstake")]
 pub struct PartialUnstakeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub validator_manager_authority: solana_pubkey::Pubkey,
@@ -25,35 +27,5 @@ pub struct PartialUnstakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PartialUnstake {
-    type ArrangedAccounts = PartialUnstakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, clock, rent, stake_history, system_program, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(PartialUnstakeInstructionAccounts {
-            state: state.pubkey,
-            validator_manager_authority: validator_manager_authority.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            stake_account: stake_account.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            reserve_pda: reserve_pda.pubkey,
-            split_stake_account: split_stake_account.pubkey,
-            split_stake_rent_payer: split_stake_rent_payer.pubkey,
-            clock: clock.pubkey,
-            rent: rent.pubkey,
-            stake_history: stake_history.pubkey,
-            system_program: system_program.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
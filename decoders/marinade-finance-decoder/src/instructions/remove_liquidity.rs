@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5055d14818ceb16c")]
 pub struct RemoveLiquidity {
     pub tokens: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RemoveLiquidity")]
 pub struct RemoveLiquidityInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub lp_mint: solana_pubkey::Pubkey,
@@ -20,32 +22,5 @@ pub struct RemoveLiquidityInstructionAccounts {
     pub liq_pool_msol_leg_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidity {
-    type ArrangedAccounts = RemoveLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, lp_mint, burn_from, burn_from_authority, transfer_sol_to, transfer_msol_to, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RemoveLiquidityInstructionAccounts {
-            state: state.pubkey,
-            lp_mint: lp_mint.pubkey,
-            burn_from: burn_from.pubkey,
-            burn_from_authority: burn_from_authority.pubkey,
-            transfer_sol_to: transfer_sol_to.pubkey,
-            transfer_msol_to: transfer_msol_to.pubkey,
-            liq_pool_sol_leg_pda: liq_pool_sol_leg_pda.pubkey,
-            liq_pool_msol_leg: liq_pool_msol_leg.pubkey,
-            liq_pool_msol_leg_authority: liq_pool_msol_leg_authority.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
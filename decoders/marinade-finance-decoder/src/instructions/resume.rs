@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01a633aa7f208dce")]
 pub struct Resume {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Resume")]
 pub struct ResumeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pause_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Resume {
-    type ArrangedAccounts = ResumeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, pause_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ResumeInstructionAccounts {
-            state: state.pubkey,
-            pause_authority: pause_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
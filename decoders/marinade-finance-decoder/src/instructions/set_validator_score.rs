@@ -1,35 +1,21 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6529ce21d86f194e")]
 pub struct SetValidatorScore {
     pub index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator_vote: solana_pubkey::Pubkey,
     pub score: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetValidatorScore")]
 pub struct SetValidatorScoreInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub manager_authority: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetValidatorScore {
-    type ArrangedAccounts = SetValidatorScoreInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, manager_authority, validator_list, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetValidatorScoreInstructionAccounts {
-            state: state.pubkey,
-            manager_authority: manager_authority.pubkey,
-            validator_list: validator_list.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
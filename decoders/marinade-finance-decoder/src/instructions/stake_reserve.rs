@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x57d917b3cd197181")]
 pub struct StakeReserve {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "StakeReserve")]
 pub struct StakeReserveInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
@@ -24,36 +26,5 @@ pub struct StakeReserveInstructionAccounts {
     pub stake_config: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for StakeReserve {
-    type ArrangedAccounts = StakeReserveInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_list, stake_list, validator_vote, reserve_pda, stake_account, stake_deposit_authority, rent_payer, clock, epoch_schedule, rent, stake_history, stake_config, system_program, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(StakeReserveInstructionAccounts {
-            state: state.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            validator_vote: validator_vote.pubkey,
-            reserve_pda: reserve_pda.pubkey,
-            stake_account: stake_account.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            rent_payer: rent_payer.pubkey,
-            clock: clock.pubkey,
-            epoch_schedule: epoch_schedule.pubkey,
-            rent: rent.pubkey,
-            stake_history: stake_history.pubkey,
-            stake_config: stake_config.pubkey,
-            system_program: system_program.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
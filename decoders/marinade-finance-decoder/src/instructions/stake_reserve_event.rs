@@ -1,15 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d707595b94d77be6a")]
 pub struct StakeReserveEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
     pub stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub stake_account: solana_pubkey::Pubkey,
     pub validator_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator_vote: solana_pubkey::Pubkey,
     pub total_stake_target: u64,
     pub validator_stake_target: u64,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0443514088f55d98")]
 pub struct UpdateActive {
@@ -9,24 +9,10 @@ pub struct UpdateActive {
     pub validator_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateActive")]
 pub struct UpdateActiveInstructionAccounts {
     pub common: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateActive {
-    type ArrangedAccounts = UpdateActiveInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [common, validator_list, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateActiveInstructionAccounts {
-            common: common.pubkey,
-            validator_list: validator_list.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
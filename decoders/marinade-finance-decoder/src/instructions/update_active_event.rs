@@ -1,18 +1,21 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dfb12804bd050ae8c")]
 pub struct UpdateActiveEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
     pub stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub stake_account: solana_pubkey::Pubkey,
     pub validator_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator_vote: solana_pubkey::Pubkey,
     pub delegation_change: U64ValueChange,
     pub delegation_growth_msol_fees: Option<u64>,
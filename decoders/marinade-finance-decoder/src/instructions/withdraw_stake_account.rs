@@ -1,16 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd355b841b7b1e9d9")]
 pub struct WithdrawStakeAccount {
     pub stake_index: u32,
     pub validator_index: u32,
     pub msol_amount: u64,
+    #[carbon(serialize_as = "base58")]
     pub beneficiary: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawStakeAccount")]
 pub struct WithdrawStakeAccountInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub msol_mint: solana_pubkey::Pubkey,
@@ -28,37 +31,5 @@ pub struct WithdrawStakeAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawStakeAccount {
-    type ArrangedAccounts = WithdrawStakeAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, burn_msol_from, burn_msol_authority, treasury_msol_account, validator_list, stake_list, stake_withdraw_authority, stake_deposit_authority, stake_account, split_stake_account, split_stake_rent_payer, clock, system_program, token_program, stake_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawStakeAccountInstructionAccounts {
-            state: state.pubkey,
-            msol_mint: msol_mint.pubkey,
-            burn_msol_from: burn_msol_from.pubkey,
-            burn_msol_authority: burn_msol_authority.pubkey,
-            treasury_msol_account: treasury_msol_account.pubkey,
-            validator_list: validator_list.pubkey,
-            stake_list: stake_list.pubkey,
-            stake_withdraw_authority: stake_withdraw_authority.pubkey,
-            stake_deposit_authority: stake_deposit_authority.pubkey,
-            stake_account: stake_account.pubkey,
-            split_stake_account: split_stake_account.pubkey,
-            split_stake_rent_payer: split_stake_rent_payer.pubkey,
-            clock: clock.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            stake_program: stake_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
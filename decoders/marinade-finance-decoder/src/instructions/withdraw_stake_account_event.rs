@@ -1,22 +1,28 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d83ee27301e1ba51c")]
 pub struct WithdrawStakeAccountEvent {
+    #[carbon(serialize_as = "base58")]
     pub state: solana_pubkey::Pubkey,
     pub epoch: u64,
+    #[carbon(serialize_as = "base58")]
     pub stake: solana_pubkey::Pubkey,
     pub last_update_stake_delegation: u64,
     pub stake_index: u32,
+    #[carbon(serialize_as = "base58")]
     pub validator: solana_pubkey::Pubkey,
     pub validator_index: u32,
     pub user_msol_balance: u64,
+    #[carbon(serialize_as = "base58")]
     pub user_msol_auth: solana_pubkey::Pubkey,
     pub msol_burned: u64,
     pub msol_fees: u64,
+    #[carbon(serialize_as = "base58")]
     pub split_stake: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub beneficiary: solana_pubkey::Pubkey,
     pub split_lamports: u64,
     pub fee_bp_cents: u32,
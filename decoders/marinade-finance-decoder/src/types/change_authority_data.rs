@@ -1,12 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ChangeAuthorityData {
+    #[carbon(serialize_as = "base58")]
     pub admin: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub validator_manager: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub operational_sol_account: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub treasury_msol_account: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub pause_authority: Option<solana_pubkey::Pubkey>,
 }
@@ -1,13 +1,15 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct InitializeData {
+    #[carbon(serialize_as = "base58")]
     pub admin_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub validator_manager_authority: solana_pubkey::Pubkey,
     pub min_stake: u64,
     pub rewards_fee: Fee,
@@ -15,5 +17,6 @@ pub struct InitializeData {
     pub additional_stake_record_space: u32,
     pub additional_validator_record_space: u32,
     pub slots_for_stake_delta: u64,
+    #[carbon(serialize_as = "base58")]
     pub pause_authority: solana_pubkey::Pubkey,
 }
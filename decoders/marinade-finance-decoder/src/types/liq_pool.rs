@@ -1,16 +1,18 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct LiqPool {
+    #[carbon(serialize_as = "base58")]
     pub lp_mint: solana_pubkey::Pubkey,
     pub lp_mint_authority_bump_seed: u8,
     pub sol_leg_bump_seed: u8,
     pub msol_leg_authority_bump_seed: u8,
+    #[carbon(serialize_as = "base58")]
     pub msol_leg: solana_pubkey::Pubkey,
     pub lp_liquidity_target: u64,
     pub lp_max_fee: Fee,
@@ -1,12 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct List {
+    #[carbon(serialize_as = "base58")]
     pub account: solana_pubkey::Pubkey,
     pub item_size: u32,
     pub count: u32,
+    #[carbon(serialize_as = "base58")]
     pub reserved1: solana_pubkey::Pubkey,
     pub reserved2: u32,
 }
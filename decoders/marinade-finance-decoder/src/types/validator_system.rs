@@ -1,13 +1,14 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ValidatorSystem {
     pub validator_list: List,
+    #[carbon(serialize_as = "base58")]
     pub manager_authority: solana_pubkey::Pubkey,
     pub total_validator_score: u32,
     pub total_active_balance: u64,
@@ -0,0 +1,50 @@
+/// A memo attached to a transaction.
+///
+/// The memo program requires memos to be valid UTF-8, but the data of an
+/// instruction that was never executed (or of the legacy program) is not
+/// guaranteed to be, so invalid sequences are replaced with `U+FFFD` and
+/// flagged through `is_valid_utf8`.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct Memo {
+    pub text: String,
+    pub is_valid_utf8: bool,
+    pub data: Vec<u8>,
+}
+
+impl Memo {
+    pub fn from_data(data: &[u8]) -> Self {
+        match std::str::from_utf8(data) {
+            Ok(text) => Memo {
+                text: text.to_string(),
+                is_valid_utf8: true,
+                data: data.to_vec(),
+            },
+            Err(_) => Memo {
+                text: String::from_utf8_lossy(data).into_owned(),
+                is_valid_utf8: false,
+                data: data.to_vec(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MemoInstructionAccounts {
+    pub signers: Vec<solana_pubkey::Pubkey>,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Memo {
+    type ArrangedAccounts = MemoInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        Some(MemoInstructionAccounts {
+            signers: accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.pubkey)
+                .collect(),
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use {crate::MemoProgramDecoder, carbon_core::instruction::DecodedInstruction};
+pub mod memo;
 
 #[derive(
     carbon_core::InstructionType,
@@ -11,7 +12,7 @@ use {crate::MemoProgramDecoder, carbon_core::instruction::DecodedInstruction};
     Hash,
 )]
 pub enum MemoProgramInstruction {
-    Memo(Vec<u8>),
+    Memo(memo::Memo),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MemoProgramDecoder {
@@ -21,14 +22,73 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MemoProgramDecoder {
         &self,
         instruction: &solana_instruction::Instruction,
     ) -> Option<DecodedInstruction<Self::InstructionType>> {
-        if !instruction.program_id.eq(&spl_memo::ID) {
+        if !instruction.program_id.eq(&spl_memo::ID)
+            && !instruction.program_id.eq(&spl_memo::v1::ID)
+        {
             return None;
         }
 
         Some(DecodedInstruction {
-            data: MemoProgramInstruction::Memo(instruction.data.clone()),
+            data: MemoProgramInstruction::Memo(memo::Memo::from_data(&instruction.data)),
             program_id: instruction.program_id,
             accounts: instruction.accounts.clone(),
+            audit: carbon_core::instruction::InstructionAudit::capture(&[], &instruction.data),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::instruction::InstructionDecoder;
+    use solana_instruction::{AccountMeta, Instruction};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_utf8_memo() {
+        // Arrange
+        let instruction = Instruction {
+            program_id: spl_memo::ID,
+            accounts: vec![],
+            data: "gm 🌅".as_bytes().to_vec(),
+        };
+
+        // Act
+        let decoded = MemoProgramDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        let MemoProgramInstruction::Memo(memo) = decoded.data;
+        assert_eq!(memo.text, "gm 🌅");
+        assert!(memo.is_valid_utf8);
+    }
+
+    #[test]
+    fn test_decode_invalid_utf8_memo() {
+        // Arrange
+        let signer = solana_pubkey::Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id: spl_memo::v1::ID,
+            accounts: vec![AccountMeta::new_readonly(signer, true)],
+            data: vec![b'h', b'i', 0xff],
+        };
+
+        // Act
+        let decoded = MemoProgramDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            <memo::Memo as carbon_core::deserialize::ArrangeAccounts>::arrange_accounts(
+                &decoded.accounts,
+            )
+            .expect("arrange accounts");
+
+        // Assert
+        let MemoProgramInstruction::Memo(memo) = decoded.data;
+        assert_eq!(memo.text, "hi\u{fffd}");
+        assert!(!memo.is_valid_utf8);
+        assert_eq!(memo.data, vec![b'h', b'i', 0xff]);
+        assert_eq!(arranged_accounts.signers, vec![signer]);
+    }
+}
@@ -1,2 +1,3 @@
 pub struct MemoProgramDecoder;
+#[cfg(feature = "instructions")]
 pub mod instructions;
@@ -0,0 +1,71 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::MercurialStableSwapDecoder;
+pub mod swap_info;
+
+pub enum MercurialStableSwapAccount {
+    SwapInfo(swap_info::SwapInfo),
+}
+
+impl AccountDecoder<'_> for MercurialStableSwapDecoder {
+    type AccountType = MercurialStableSwapAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if account.data.len() < swap_info::SWAP_INFO_SIZE {
+            return None;
+        }
+
+        let decoded_account = swap_info::SwapInfo::deserialize(account.data.as_slice())?;
+        Some(carbon_core::account::DecodedAccount {
+            lamports: account.lamports,
+            data: MercurialStableSwapAccount::SwapInfo(decoded_account),
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_swap_info_account() {
+        // Act
+        let decoder = MercurialStableSwapDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/swap_info_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            MercurialStableSwapAccount::SwapInfo(swap_info) => {
+                assert!(swap_info.is_initialized);
+                assert_eq!(swap_info.amplification_coefficient, 2_000);
+                assert_eq!(swap_info.fee_numerator, 4_000_000);
+                assert_eq!(swap_info.token_accounts_length, 3);
+                assert_eq!(swap_info.precision_multipliers, [1, 1, 1, 0]);
+                assert_eq!(
+                    swap_info.pool_token_accounts(),
+                    &swap_info.token_accounts[..3]
+                );
+                assert_eq!(
+                    swap_info.pool_mint,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "57h4LEnBooHrKbacYWGCFghmrTzYPVn8PwZkzTzRLvHa"
+                    )
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// The size of the fields of a swap account. Accounts may be allocated
+/// larger, the trailing bytes are ignored.
+pub const SWAP_INFO_SIZE: usize = 263;
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(deserialize_with = "deserialize_swap_info")]
+pub struct SwapInfo {
+    pub version: u8,
+    pub is_initialized: bool,
+    pub nonce: u8,
+    pub amplification_coefficient: u64,
+    pub fee_numerator: u64,
+    pub admin_fee_numerator: u64,
+    /// The number of tokens of the pool, and of used entries in
+    /// `precision_multipliers` and `token_accounts`.
+    pub token_accounts_length: u32,
+    pub precision_factor: u64,
+    pub precision_multipliers: [u64; 4],
+    /// The reserve token account of each token of the pool.
+    #[carbon(serialize_as = "base58")]
+    pub token_accounts: [solana_pubkey::Pubkey; 4],
+    #[carbon(serialize_as = "base58")]
+    pub pool_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub admin_token_mint: solana_pubkey::Pubkey,
+}
+
+impl SwapInfo {
+    /// Returns the reserve token accounts in use.
+    pub fn pool_token_accounts(&self) -> &[solana_pubkey::Pubkey] {
+        let length = (self.token_accounts_length as usize).min(self.token_accounts.len());
+        &self.token_accounts[..length]
+    }
+}
+
+fn deserialize_swap_info(data: &[u8]) -> Option<SwapInfo> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
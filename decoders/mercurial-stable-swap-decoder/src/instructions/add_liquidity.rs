@@ -0,0 +1,77 @@
+use {
+    alloc::vec::Vec,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x01", deserialize_with = "deserialize_add_liquidity")]
+pub struct AddLiquidity {
+    /// The amount deposited of each token of the pool, in pool order.
+    pub deposit_amounts: Vec<u64>,
+    pub min_mint_amount: u64,
+}
+
+/// The deposit amounts are packed without a length prefix, one per token of
+/// the pool, followed by the minimum amount of LP tokens to mint.
+fn deserialize_add_liquidity(data: &[u8]) -> Option<AddLiquidity> {
+    let mut amounts = super::read_u64s(data)?;
+    let min_mint_amount = amounts.pop()?;
+
+    Some(AddLiquidity {
+        deposit_amounts: amounts,
+        min_mint_amount,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AddLiquidityInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub pool_authority: solana_pubkey::Pubkey,
+    pub user_transfer_authority: solana_pubkey::Pubkey,
+    /// The reserve token account of each token of the pool.
+    pub pool_token_accounts: Vec<solana_pubkey::Pubkey>,
+    pub pool_mint: solana_pubkey::Pubkey,
+    /// The source token account of each token of the pool.
+    pub user_token_accounts: Vec<solana_pubkey::Pubkey>,
+    pub user_lp_token_account: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity {
+    type ArrangedAccounts = AddLiquidityInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [swap, token_program, pool_authority, user_transfer_authority, rest @ ..] = accounts
+        else {
+            return None;
+        };
+
+        // The pool token accounts, the pool mint, the user token accounts
+        // and the user LP token account.
+        if rest.len() < 4 || (rest.len() - 2) % 2 != 0 {
+            return None;
+        }
+        let token_count = (rest.len() - 2) / 2;
+        let (pool_token_accounts, rest) = rest.split_at(token_count);
+        let (pool_mint, rest) = rest.split_first()?;
+        let (user_token_accounts, rest) = rest.split_at(token_count);
+        let [user_lp_token_account] = rest else {
+            return None;
+        };
+
+        Some(AddLiquidityInstructionAccounts {
+            swap: swap.pubkey,
+            token_program: token_program.pubkey,
+            pool_authority: pool_authority.pubkey,
+            user_transfer_authority: user_transfer_authority.pubkey,
+            pool_token_accounts: super::pubkeys(pool_token_accounts),
+            pool_mint: pool_mint.pubkey,
+            user_token_accounts: super::pubkeys(user_token_accounts),
+            user_lp_token_account: user_lp_token_account.pubkey,
+        })
+    }
+}
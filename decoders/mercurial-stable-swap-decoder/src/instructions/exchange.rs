@@ -0,0 +1,49 @@
+use {
+    alloc::vec::Vec,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x04")]
+pub struct Exchange {
+    pub in_amount: u64,
+    pub minimum_out_amount: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExchangeInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub pool_authority: solana_pubkey::Pubkey,
+    pub user_transfer_authority: solana_pubkey::Pubkey,
+    /// The reserve token account of each token of the pool.
+    pub pool_token_accounts: Vec<solana_pubkey::Pubkey>,
+    pub source_token_account: solana_pubkey::Pubkey,
+    pub destination_token_account: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Exchange {
+    type ArrangedAccounts = ExchangeInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [swap, token_program, pool_authority, user_transfer_authority, pool_token_accounts @ .., source_token_account, destination_token_account] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExchangeInstructionAccounts {
+            swap: swap.pubkey,
+            token_program: token_program.pubkey,
+            pool_authority: pool_authority.pubkey,
+            user_transfer_authority: user_transfer_authority.pubkey,
+            pool_token_accounts: super::pubkeys(pool_token_accounts),
+            source_token_account: source_token_account.pubkey,
+            destination_token_account: destination_token_account.pubkey,
+        })
+    }
+}
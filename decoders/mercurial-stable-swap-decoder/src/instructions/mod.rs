@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+
+use crate::PROGRAM_ID;
+
+use super::MercurialStableSwapDecoder;
+pub mod add_liquidity;
+pub mod exchange;
+pub mod remove_liquidity;
+pub mod remove_liquidity_one_token;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum MercurialStableSwapInstruction {
+    AddLiquidity(add_liquidity::AddLiquidity),
+    RemoveLiquidity(remove_liquidity::RemoveLiquidity),
+    RemoveLiquidityOneToken(remove_liquidity_one_token::RemoveLiquidityOneToken),
+    Exchange(exchange::Exchange),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for MercurialStableSwapDecoder {
+    type InstructionType = MercurialStableSwapInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            MercurialStableSwapInstruction::AddLiquidity => add_liquidity::AddLiquidity,
+            MercurialStableSwapInstruction::RemoveLiquidity => remove_liquidity::RemoveLiquidity,
+            MercurialStableSwapInstruction::RemoveLiquidityOneToken => remove_liquidity_one_token::RemoveLiquidityOneToken,
+            MercurialStableSwapInstruction::Exchange => exchange::Exchange,
+        )
+    }
+}
+
+/// Reads little endian `u64` values filling `data` exactly.
+fn read_u64s(data: &[u8]) -> Option<Vec<u64>> {
+    if data.len() % 8 != 0 {
+        return None;
+    }
+
+    data.chunks_exact(8)
+        .map(|chunk| chunk.try_into().ok().map(u64::from_le_bytes))
+        .collect()
+}
+
+fn pubkeys(accounts: &[solana_instruction::AccountMeta]) -> Vec<solana_pubkey::Pubkey> {
+    accounts.iter().map(|account| account.pubkey).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{
+        deserialize::{ArrangeAccounts, CarbonDeserialize},
+        instruction::InstructionDecoder,
+    };
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_exchange() {
+        // Arrange
+        let expected_ix = MercurialStableSwapInstruction::Exchange(exchange::Exchange {
+            in_amount: 2_000_000,
+            minimum_out_amount: 1_990_000,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "MAR1zHjHaQcniE2gXsDptkyKUnNfMEsLBVcfP7vLyv7",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "4aSYQ5TisQrqHSNJzLQsn4WkSdHHKBZ4hG8zJZwUfAd6",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7GmDnvXcbmT5B9yoYiYb3UU6S2F1WFvhHNVfj6tgXozV",
+                ),
+                true,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3ne4mWqdYuNiYrYZC9TrA3FcfuFdErghH97vNPbjicr1",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CfWX7o2TswwbxusJ4hCaPobu2jLCb1hfXuXJQjVq3jQF",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EnTrdMMpdhugeH6Ban6gYZWXughWxKtVGfCwFn78ZmY3",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "8yUAgTXQWgpaxK2Ts4ArsvB1LgAvUyVp8WPBAp36amRs",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "XgPzM3S9XKeLMRrcEuoG4jXW7RZQfhs6RUoewGUPvvn",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = exchange::ExchangeInstructionAccounts {
+            swap: solana_pubkey::Pubkey::from_str_const(
+                "MAR1zHjHaQcniE2gXsDptkyKUnNfMEsLBVcfP7vLyv7",
+            ),
+            token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            pool_authority: solana_pubkey::Pubkey::from_str_const(
+                "4aSYQ5TisQrqHSNJzLQsn4WkSdHHKBZ4hG8zJZwUfAd6",
+            ),
+            user_transfer_authority: solana_pubkey::Pubkey::from_str_const(
+                "7GmDnvXcbmT5B9yoYiYb3UU6S2F1WFvhHNVfj6tgXozV",
+            ),
+            pool_token_accounts: vec![
+                solana_pubkey::Pubkey::from_str_const(
+                    "3ne4mWqdYuNiYrYZC9TrA3FcfuFdErghH97vNPbjicr1",
+                ),
+                solana_pubkey::Pubkey::from_str_const(
+                    "CfWX7o2TswwbxusJ4hCaPobu2jLCb1hfXuXJQjVq3jQF",
+                ),
+                solana_pubkey::Pubkey::from_str_const(
+                    "EnTrdMMpdhugeH6Ban6gYZWXughWxKtVGfCwFn78ZmY3",
+                ),
+            ],
+            source_token_account: solana_pubkey::Pubkey::from_str_const(
+                "8yUAgTXQWgpaxK2Ts4ArsvB1LgAvUyVp8WPBAp36amRs",
+            ),
+            destination_token_account: solana_pubkey::Pubkey::from_str_const(
+                "XgPzM3S9XKeLMRrcEuoG4jXW7RZQfhs6RUoewGUPvvn",
+            ),
+        };
+
+        // Act
+        let decoder = MercurialStableSwapDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/exchange_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            exchange::Exchange::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+
+    #[test]
+    fn test_deserialize_unprefixed_amounts() {
+        let mut data = vec![0x01];
+        for amount in [100u64, 200, 300, 590] {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+
+        let add_liquidity =
+            add_liquidity::AddLiquidity::deserialize(&data).expect("deserialize add liquidity");
+        data[0] = 0x02;
+        let remove_liquidity = remove_liquidity::RemoveLiquidity::deserialize(&data)
+            .expect("deserialize remove liquidity");
+
+        assert_eq!(add_liquidity.deposit_amounts, vec![100, 200, 300]);
+        assert_eq!(add_liquidity.min_mint_amount, 590);
+        assert_eq!(remove_liquidity.unmint_amount, 100);
+        assert_eq!(remove_liquidity.minimum_amounts, vec![200, 300, 590]);
+        assert!(remove_liquidity::RemoveLiquidity::deserialize(&data[..12]).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use {
+    alloc::vec::Vec,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0x02",
+    deserialize_with = "deserialize_remove_liquidity"
+)]
+pub struct RemoveLiquidity {
+    pub unmint_amount: u64,
+    /// The minimum amount withdrawn of each token of the pool, in pool order.
+    pub minimum_amounts: Vec<u64>,
+}
+
+/// The minimum amounts are packed without a length prefix, one per token of
+/// the pool, after the amount of LP tokens to burn.
+fn deserialize_remove_liquidity(data: &[u8]) -> Option<RemoveLiquidity> {
+    let mut amounts = super::read_u64s(data)?;
+    if amounts.is_empty() {
+        return None;
+    }
+    let unmint_amount = amounts.remove(0);
+
+    Some(RemoveLiquidity {
+        unmint_amount,
+        minimum_amounts: amounts,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RemoveLiquidityInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub pool_authority: solana_pubkey::Pubkey,
+    pub user_transfer_authority: solana_pubkey::Pubkey,
+    /// The reserve token account of each token of the pool.
+    pub pool_token_accounts: Vec<solana_pubkey::Pubkey>,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub user_lp_token_account: solana_pubkey::Pubkey,
+    /// The destination token account of each token of the pool.
+    pub user_token_accounts: Vec<solana_pubkey::Pubkey>,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidity {
+    type ArrangedAccounts = RemoveLiquidityInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [swap, token_program, pool_authority, user_transfer_authority, rest @ ..] = accounts
+        else {
+            return None;
+        };
+
+        // The pool token accounts, the pool mint, the user LP token account
+        // and the user token accounts.
+        if rest.len() < 4 || (rest.len() - 2) % 2 != 0 {
+            return None;
+        }
+        let token_count = (rest.len() - 2) / 2;
+        let (pool_token_accounts, rest) = rest.split_at(token_count);
+        let [pool_mint, user_lp_token_account, user_token_accounts @ ..] = rest else {
+            return None;
+        };
+
+        Some(RemoveLiquidityInstructionAccounts {
+            swap: swap.pubkey,
+            token_program: token_program.pubkey,
+            pool_authority: pool_authority.pubkey,
+            user_transfer_authority: user_transfer_authority.pubkey,
+            pool_token_accounts: super::pubkeys(pool_token_accounts),
+            pool_mint: pool_mint.pubkey,
+            user_lp_token_account: user_lp_token_account.pubkey,
+            user_token_accounts: super::pubkeys(user_token_accounts),
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use {
+    alloc::vec::Vec,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x03")]
+pub struct RemoveLiquidityOneToken {
+    pub unmint_amount: u64,
+    pub minimum_out_amount: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RemoveLiquidityOneTokenInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub pool_authority: solana_pubkey::Pubkey,
+    pub user_transfer_authority: solana_pubkey::Pubkey,
+    /// The reserve token account of each token of the pool.
+    pub pool_token_accounts: Vec<solana_pubkey::Pubkey>,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub user_lp_token_account: solana_pubkey::Pubkey,
+    pub user_destination_token_account: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidityOneToken {
+    type ArrangedAccounts = RemoveLiquidityOneTokenInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [swap, token_program, pool_authority, user_transfer_authority, pool_token_accounts @ .., pool_mint, user_lp_token_account, user_destination_token_account] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RemoveLiquidityOneTokenInstructionAccounts {
+            swap: swap.pubkey,
+            token_program: token_program.pubkey,
+            pool_authority: pool_authority.pubkey,
+            user_transfer_authority: user_transfer_authority.pubkey,
+            pool_token_accounts: super::pubkeys(pool_token_accounts),
+            pool_mint: pool_mint.pubkey,
+            user_lp_token_account: user_lp_token_account.pubkey,
+            user_destination_token_account: user_destination_token_account.pubkey,
+        })
+    }
+}
@@ -1,6 +1,12 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    crate::PROGRAM_ID,
+    carbon_core::{
+        account::{AccountDecoder, DecodedAccount},
+        borsh::{self, BorshDeserialize},
+        deserialize::LazyArray,
+        CarbonDeserialize,
+    },
 };
 
 #[derive(CarbonDeserialize, Debug)]
@@ -12,3 +18,71 @@ pub struct BinArray {
     pub lb_pair: solana_pubkey::Pubkey,
     pub bins: [Bin; 70],
 }
+
+/// The encoded size of a `Bin`.
+pub const BIN_SIZE: usize = 144;
+
+/// A `BinArray` whose bins are decoded on demand.
+///
+/// The header is decoded eagerly, while each of the 70 bins is only decoded
+/// when read, which saves decoding and allocating every bin on each update of
+/// the account when only a few are needed.
+#[derive(Debug, Clone)]
+pub struct LazyBinArray {
+    pub index: i64,
+    pub version: u8,
+    pub lb_pair: solana_pubkey::Pubkey,
+    pub bins: LazyArray<Bin>,
+}
+
+impl LazyBinArray {
+    pub const DISCRIMINATOR: [u8; 8] = [0x5c, 0x8e, 0x5c, 0xdc, 0x05, 0x94, 0x46, 0xb5];
+
+    /// Decodes the header of a `BinArray` account, keeping the bins encoded.
+    pub fn deserialize(data: &[u8]) -> Option<Self> {
+        let mut data = data.strip_prefix(Self::DISCRIMINATOR.as_slice())?;
+
+        let index = i64::deserialize(&mut data).ok()?;
+        let version = u8::deserialize(&mut data).ok()?;
+        let _padding = <[u8; 7]>::deserialize(&mut data).ok()?;
+        let lb_pair = solana_pubkey::Pubkey::deserialize(&mut data).ok()?;
+        let bins = LazyArray::from_slice(&mut data, BIN_SIZE, 70)?;
+
+        if !data.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            index,
+            version,
+            lb_pair,
+            bins,
+        })
+    }
+}
+
+/// An account decoder for `BinArray` accounts only, decoding their bins on
+/// demand. Use it instead of `MeteoraDlmmDecoder` for pipes following bin
+/// array updates.
+pub struct MeteoraDlmmBinArrayDecoder;
+
+impl AccountDecoder<'_> for MeteoraDlmmBinArrayDecoder {
+    type AccountType = LazyBinArray;
+
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        Some(DecodedAccount {
+            lamports: account.lamports,
+            data: LazyBinArray::deserialize(account.data.as_slice())?,
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        })
+    }
+}
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d1f5e7d5ae3343dba")]
 pub struct AddLiquidityEvent {
+    #[carbon(serialize_as = "base58")]
     pub lb_pair: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub from: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position: solana_pubkey::Pubkey,
     pub amounts: [u64; 2],
     pub active_bin_id: i32,
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d947486cc16ab555f")]
 pub struct ClaimRewardEvent {
+    #[carbon(serialize_as = "base58")]
     pub lb_pair: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub position: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
     pub reward_index: u64,
     pub total_reward: u64,
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x04949164861ab53d")]
 pub struct ClosePresetParameter {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ClosePresetParameter")]
 pub struct ClosePresetParameterInstructionAccounts {
     pub preset_parameter: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub rent_receiver: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ClosePresetParameter {
-    type ArrangedAccounts = ClosePresetParameterInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [preset_parameter, admin, rent_receiver, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ClosePresetParameterInstructionAccounts {
-            preset_parameter: preset_parameter.pubkey,
-            admin: admin.pubkey,
-            rent_receiver: rent_receiver.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
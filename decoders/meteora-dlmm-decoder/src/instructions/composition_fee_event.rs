@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d80977b6a1166718e")]
 pub struct CompositionFeeEvent {
+    #[carbon(serialize_as = "base58")]
     pub from: solana_pubkey::Pubkey,
     pub bin_id: i16,
     pub token_x_fee_amount: u64,
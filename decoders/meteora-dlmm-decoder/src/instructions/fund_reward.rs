@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xbc32f9a55d97263f")]
 pub struct FundReward {
@@ -10,6 +10,8 @@ pub struct FundReward {
     pub carry_forward: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FundReward")]
 pub struct FundRewardInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub reward_vault: solana_pubkey::Pubkey,
@@ -20,30 +22,5 @@ pub struct FundRewardInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FundReward {
-    type ArrangedAccounts = FundRewardInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, reward_vault, reward_mint, funder_token_account, funder, bin_array, token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(FundRewardInstructionAccounts {
-            lb_pair: lb_pair.pubkey,
-            reward_vault: reward_vault.pubkey,
-            reward_mint: reward_mint.pubkey,
-            funder_token_account: funder_token_account.pubkey,
-            funder: funder.pubkey,
-            bin_array: bin_array.pubkey,
-            token_program: token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1df6e43a8291aa4fcc")]
 pub struct FundRewardEvent {
+    #[carbon(serialize_as = "base58")]
     pub lb_pair: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub funder: solana_pubkey::Pubkey,
     pub reward_index: u64,
     pub amount: u64,
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2e2729876fb7c840")]
 pub struct InitializeCustomizablePermissionlessLbPair {
@@ -26,6 +26,7 @@ pub struct InitializeCustomizablePermissionlessLbPairInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeCustomizablePermissionlessLbPair {
@@ -34,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCustomizablePermiss
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, bin_array_bitmap_extension, token_mint_x, token_mint_y, reserve_x, reserve_y, oracle, user_token_x, funder, token_program, system_program, rent, event_authority, program, _remaining @ ..] =
+        let [lb_pair, bin_array_bitmap_extension, token_mint_x, token_mint_y, reserve_x, reserve_y, oracle, user_token_x, funder, token_program, system_program, rent, event_authority, program, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +57,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCustomizablePermiss
                 rent: rent.pubkey,
                 event_authority: event_authority.pubkey,
                 program: program.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
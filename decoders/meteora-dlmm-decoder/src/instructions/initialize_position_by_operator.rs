@@ -1,16 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfbbdbef475fe2394")]
 pub struct InitializePositionByOperator {
     pub lower_bin_id: i32,
     pub width: i32,
+    #[carbon(serialize_as = "base58")]
     pub fee_owner: solana_pubkey::Pubkey,
     pub lock_release_point: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializePositionByOperator")]
 pub struct InitializePositionByOperatorInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub base: solana_pubkey::Pubkey,
@@ -23,32 +26,5 @@ pub struct InitializePositionByOperatorInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializePositionByOperator {
-    type ArrangedAccounts = InitializePositionByOperatorInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base, position, lb_pair, owner, operator, operator_token_x, owner_token_x, system_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializePositionByOperatorInstructionAccounts {
-            payer: payer.pubkey,
-            base: base.pubkey,
-            position: position.pubkey,
-            lb_pair: lb_pair.pubkey,
-            owner: owner.pubkey,
-            operator: operator.pubkey,
-            operator_token_x: operator_token_x.pubkey,
-            owner_token_x: owner_token_x.pubkey,
-            system_program: system_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2e527d92558de499")]
 pub struct InitializePositionPda {
@@ -9,6 +9,8 @@ pub struct InitializePositionPda {
     pub width: i32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializePositionPda")]
 pub struct InitializePositionPdaInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub base: solana_pubkey::Pubkey,
@@ -19,30 +21,5 @@ pub struct InitializePositionPdaInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializePositionPda {
-    type ArrangedAccounts = InitializePositionPdaInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base, position, lb_pair, owner, system_program, rent, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializePositionPdaInstructionAccounts {
-            payer: payer.pubkey,
-            base: base.pubkey,
-            position: position.pubkey,
-            lb_pair: lb_pair.pubkey,
-            owner: owner.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1db94afc7d1bd7bc6f")]
 pub struct LbPairCreateEvent {
+    #[carbon(serialize_as = "base58")]
     pub lb_pair: solana_pubkey::Pubkey,
     pub bin_step: u16,
+    #[carbon(serialize_as = "base58")]
     pub token_x: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_y: solana_pubkey::Pubkey,
 }
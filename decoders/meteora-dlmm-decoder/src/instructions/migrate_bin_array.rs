@@ -1,27 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x11179fd365b829f1")]
 pub struct MigrateBinArray {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MigrateBinArray")]
 pub struct MigrateBinArrayInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MigrateBinArray {
-    type ArrangedAccounts = MigrateBinArrayInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(MigrateBinArrayInstructionAccounts {
-            lb_pair: lb_pair.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
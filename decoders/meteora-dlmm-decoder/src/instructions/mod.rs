@@ -5,21 +5,29 @@ pub mod add_liquidity;
 pub mod add_liquidity_by_strategy;
 pub mod add_liquidity_by_strategy_one_side;
 pub mod add_liquidity_by_weight;
+#[cfg(feature = "events")]
 pub mod add_liquidity_event;
 pub mod add_liquidity_one_side;
 pub mod add_liquidity_one_side_precise;
 pub mod claim_fee;
+#[cfg(feature = "events")]
 pub mod claim_fee_event;
 pub mod claim_reward;
+#[cfg(feature = "events")]
 pub mod claim_reward_event;
 pub mod close_position;
 pub mod close_preset_parameter;
+#[cfg(feature = "events")]
 pub mod composition_fee_event;
+#[cfg(feature = "events")]
 pub mod fee_parameter_update_event;
 pub mod fund_reward;
+#[cfg(feature = "events")]
 pub mod fund_reward_event;
 pub mod go_to_a_bin;
+#[cfg(feature = "events")]
 pub mod go_to_a_bin_event;
+#[cfg(feature = "events")]
 pub mod increase_observation_event;
 pub mod increase_oracle_length;
 pub mod initialize_bin_array;
@@ -32,34 +40,45 @@ pub mod initialize_position_by_operator;
 pub mod initialize_position_pda;
 pub mod initialize_preset_parameter;
 pub mod initialize_reward;
+#[cfg(feature = "events")]
 pub mod initialize_reward_event;
+#[cfg(feature = "events")]
 pub mod lb_pair_create_event;
 pub mod migrate_bin_array;
 pub mod migrate_position;
+#[cfg(feature = "events")]
 pub mod position_close_event;
+#[cfg(feature = "events")]
 pub mod position_create_event;
 pub mod remove_all_liquidity;
 pub mod remove_liquidity;
 pub mod remove_liquidity_by_range;
+#[cfg(feature = "events")]
 pub mod remove_liquidity_event;
 pub mod set_activation_point;
 pub mod set_pre_activation_duration;
 pub mod set_pre_activation_swap_address;
 pub mod swap;
+#[cfg(feature = "events")]
 pub mod swap_event;
 pub mod swap_exact_out;
 pub mod swap_with_price_impact;
 pub mod toggle_pair_status;
 pub mod update_fee_parameters;
 pub mod update_fees_and_rewards;
+#[cfg(feature = "events")]
 pub mod update_position_lock_release_point_event;
 pub mod update_position_operator;
+#[cfg(feature = "events")]
 pub mod update_position_operator_event;
 pub mod update_reward_duration;
+#[cfg(feature = "events")]
 pub mod update_reward_duration_event;
 pub mod update_reward_funder;
+#[cfg(feature = "events")]
 pub mod update_reward_funder_event;
 pub mod withdraw_ineligible_reward;
+#[cfg(feature = "events")]
 pub mod withdraw_ineligible_reward_event;
 pub mod withdraw_protocol_fee;
 
@@ -122,32 +141,55 @@ pub enum MeteoraDlmmInstruction {
     GoToABin(go_to_a_bin::GoToABin),
     SetPreActivationDuration(set_pre_activation_duration::SetPreActivationDuration),
     SetPreActivationSwapAddress(set_pre_activation_swap_address::SetPreActivationSwapAddress),
+    #[cfg(feature = "events")]
     CompositionFeeEvent(composition_fee_event::CompositionFeeEvent),
+    #[cfg(feature = "events")]
     AddLiquidityEvent(add_liquidity_event::AddLiquidityEvent),
+    #[cfg(feature = "events")]
     RemoveLiquidityEvent(remove_liquidity_event::RemoveLiquidityEvent),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
+    #[cfg(feature = "events")]
     ClaimRewardEvent(claim_reward_event::ClaimRewardEvent),
+    #[cfg(feature = "events")]
     FundRewardEvent(fund_reward_event::FundRewardEvent),
+    #[cfg(feature = "events")]
     InitializeRewardEvent(initialize_reward_event::InitializeRewardEvent),
+    #[cfg(feature = "events")]
     UpdateRewardDurationEvent(update_reward_duration_event::UpdateRewardDurationEvent),
+    #[cfg(feature = "events")]
     UpdateRewardFunderEvent(update_reward_funder_event::UpdateRewardFunderEvent),
+    #[cfg(feature = "events")]
     PositionCloseEvent(position_close_event::PositionCloseEvent),
+    #[cfg(feature = "events")]
     ClaimFeeEvent(claim_fee_event::ClaimFeeEvent),
+    #[cfg(feature = "events")]
     LbPairCreateEvent(lb_pair_create_event::LbPairCreateEvent),
+    #[cfg(feature = "events")]
     PositionCreateEvent(position_create_event::PositionCreateEvent),
+    #[cfg(feature = "events")]
     FeeParameterUpdateEvent(fee_parameter_update_event::FeeParameterUpdateEvent),
+    #[cfg(feature = "events")]
     IncreaseObservationEvent(increase_observation_event::IncreaseObservationEvent),
+    #[cfg(feature = "events")]
     WithdrawIneligibleRewardEvent(withdraw_ineligible_reward_event::WithdrawIneligibleRewardEvent),
+    #[cfg(feature = "events")]
     UpdatePositionOperatorEvent(update_position_operator_event::UpdatePositionOperatorEvent),
+    #[cfg(feature = "events")]
     UpdatePositionLockReleasePointEvent(
         update_position_lock_release_point_event::UpdatePositionLockReleasePointEvent,
     ),
+    #[cfg(feature = "events")]
     GoToABinEvent(go_to_a_bin_event::GoToABinEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraDlmmDecoder {
     type InstructionType = MeteoraDlmmInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -199,24 +241,43 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraDlmmDecoder {
             MeteoraDlmmInstruction::GoToABin => go_to_a_bin::GoToABin,
             MeteoraDlmmInstruction::SetPreActivationDuration => set_pre_activation_duration::SetPreActivationDuration,
             MeteoraDlmmInstruction::SetPreActivationSwapAddress => set_pre_activation_swap_address::SetPreActivationSwapAddress,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::CompositionFeeEvent => composition_fee_event::CompositionFeeEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::AddLiquidityEvent => add_liquidity_event::AddLiquidityEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::RemoveLiquidityEvent => remove_liquidity_event::RemoveLiquidityEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::SwapEvent => swap_event::SwapEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::ClaimRewardEvent => claim_reward_event::ClaimRewardEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::FundRewardEvent => fund_reward_event::FundRewardEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::InitializeRewardEvent => initialize_reward_event::InitializeRewardEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::UpdateRewardDurationEvent => update_reward_duration_event::UpdateRewardDurationEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::UpdateRewardFunderEvent => update_reward_funder_event::UpdateRewardFunderEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::PositionCloseEvent => position_close_event::PositionCloseEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::ClaimFeeEvent => claim_fee_event::ClaimFeeEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::LbPairCreateEvent => lb_pair_create_event::LbPairCreateEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::PositionCreateEvent => position_create_event::PositionCreateEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::FeeParameterUpdateEvent => fee_parameter_update_event::FeeParameterUpdateEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::IncreaseObservationEvent => increase_observation_event::IncreaseObservationEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::WithdrawIneligibleRewardEvent => withdraw_ineligible_reward_event::WithdrawIneligibleRewardEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::UpdatePositionOperatorEvent => update_position_operator_event::UpdatePositionOperatorEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::UpdatePositionLockReleasePointEvent => update_position_lock_release_point_event::UpdatePositionLockReleasePointEvent,
+            #[cfg(feature = "events")]
             MeteoraDlmmInstruction::GoToABinEvent => go_to_a_bin_event::GoToABinEvent,
         )
     }
@@ -1,10 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dffc4106b1cca3580")]
 pub struct PositionCloseEvent {
+    #[carbon(serialize_as = "base58")]
     pub position: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
 }
@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x398b2f7bd850df0a")]
 pub struct SetPreActivationSwapAddress {
+    #[carbon(serialize_as = "base58")]
     pub pre_activation_swap_address: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetPreActivationSwapAddress")]
 pub struct SetPreActivationSwapAddressInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub creator: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetPreActivationSwapAddress {
-    type ArrangedAccounts = SetPreActivationSwapAddressInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, creator, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetPreActivationSwapAddressInstructionAccounts {
-            lb_pair: lb_pair.pubkey,
-            creator: creator.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
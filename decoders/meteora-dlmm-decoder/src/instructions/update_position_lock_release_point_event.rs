@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d85d642e0400c07bf")]
 pub struct UpdatePositionLockReleasePointEvent {
+    #[carbon(serialize_as = "base58")]
     pub position: solana_pubkey::Pubkey,
     pub current_point: u64,
     pub new_lock_release_point: u64,
     pub old_lock_release_point: u64,
+    #[carbon(serialize_as = "base58")]
     pub sender: solana_pubkey::Pubkey,
 }
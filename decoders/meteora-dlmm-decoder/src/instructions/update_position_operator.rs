@@ -1,35 +1,20 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xcab8678fb4bf74d9")]
 pub struct UpdatePositionOperator {
+    #[carbon(serialize_as = "base58")]
     pub operator: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdatePositionOperator")]
 pub struct UpdatePositionOperatorInstructionAccounts {
     pub position: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdatePositionOperator {
-    type ArrangedAccounts = UpdatePositionOperatorInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [position, owner, event_authority, program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdatePositionOperatorInstructionAccounts {
-            position: position.pubkey,
-            owner: owner.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d277330ccf62f4239")]
 pub struct UpdatePositionOperatorEvent {
+    #[carbon(serialize_as = "base58")]
     pub position: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub old_operator: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_operator: solana_pubkey::Pubkey,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x8aaec4a9d5ebfe6b")]
 pub struct UpdateRewardDuration {
@@ -9,31 +9,13 @@ pub struct UpdateRewardDuration {
     pub new_duration: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateRewardDuration")]
 pub struct UpdateRewardDurationInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub bin_array: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardDuration {
-    type ArrangedAccounts = UpdateRewardDurationInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, admin, bin_array, event_authority, program, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(UpdateRewardDurationInstructionAccounts {
-            lb_pair: lb_pair.pubkey,
-            admin: admin.pubkey,
-            bin_array: bin_array.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
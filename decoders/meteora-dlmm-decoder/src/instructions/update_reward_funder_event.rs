@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1de0b2ae4afca555b4")]
 pub struct UpdateRewardFunderEvent {
+    #[carbon(serialize_as = "base58")]
     pub lb_pair: solana_pubkey::Pubkey,
     pub reward_index: u64,
+    #[carbon(serialize_as = "base58")]
     pub old_funder: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_funder: solana_pubkey::Pubkey,
 }
@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct RewardInfo {
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub funder: solana_pubkey::Pubkey,
     pub reward_duration: u64,
     pub reward_duration_end: u64,
@@ -1,15 +1,16 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct StrategyParameters {
     pub min_bin_id: i32,
     pub max_bin_id: i32,
     pub strategy_type: StrategyType,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub parameteres: [u8; 64],
 }
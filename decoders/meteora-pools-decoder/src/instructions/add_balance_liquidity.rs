@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa8e3323ebdab54b0")]
 pub struct AddBalanceLiquidity {
@@ -10,6 +10,8 @@ pub struct AddBalanceLiquidity {
     pub maximum_token_b_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddBalanceLiquidity")]
 pub struct AddBalanceLiquidityInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub lp_mint: solana_pubkey::Pubkey,
@@ -27,37 +29,5 @@ pub struct AddBalanceLiquidityInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddBalanceLiquidity {
-    type ArrangedAccounts = AddBalanceLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, user_pool_lp, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, a_token_vault, b_token_vault, user_a_token, user_b_token, user, vault_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(AddBalanceLiquidityInstructionAccounts {
-            pool: pool.pubkey,
-            lp_mint: lp_mint.pubkey,
-            user_pool_lp: user_pool_lp.pubkey,
-            a_vault_lp: a_vault_lp.pubkey,
-            b_vault_lp: b_vault_lp.pubkey,
-            a_vault: a_vault.pubkey,
-            b_vault: b_vault.pubkey,
-            a_vault_lp_mint: a_vault_lp_mint.pubkey,
-            b_vault_lp_mint: b_vault_lp_mint.pubkey,
-            a_token_vault: a_token_vault.pubkey,
-            b_token_vault: b_token_vault.pubkey,
-            user_a_token: user_a_token.pubkey,
-            user_b_token: user_b_token.pubkey,
-            user: user.pubkey,
-            vault_program: vault_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa9204f8988e84689")]
 pub struct ClaimFee {
     pub max_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ClaimFee")]
 pub struct ClaimFeeInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub lp_mint: solana_pubkey::Pubkey,
@@ -27,39 +29,5 @@ pub struct ClaimFeeInstructionAccounts {
     pub user_a_token: solana_pubkey::Pubkey,
     pub user_b_token: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
-    type ArrangedAccounts = ClaimFeeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_token_vault, b_token_vault, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, user_a_token, user_b_token, vault_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ClaimFeeInstructionAccounts {
-            pool: pool.pubkey,
-            lp_mint: lp_mint.pubkey,
-            lock_escrow: lock_escrow.pubkey,
-            owner: owner.pubkey,
-            source_tokens: source_tokens.pubkey,
-            escrow_vault: escrow_vault.pubkey,
-            token_program: token_program.pubkey,
-            a_token_vault: a_token_vault.pubkey,
-            b_token_vault: b_token_vault.pubkey,
-            a_vault: a_vault.pubkey,
-            b_vault: b_vault.pubkey,
-            a_vault_lp: a_vault_lp.pubkey,
-            b_vault_lp: b_vault_lp.pubkey,
-            a_vault_lp_mint: a_vault_lp_mint.pubkey,
-            b_vault_lp_mint: b_vault_lp_mint.pubkey,
-            user_a_token: user_a_token.pubkey,
-            user_b_token: user_b_token.pubkey,
-            vault_program: vault_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x9109489d5f7d3d55")]
 pub struct CloseConfig {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseConfig")]
 pub struct CloseConfigInstructionAccounts {
     pub config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub rent_receiver: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseConfig {
-    type ArrangedAccounts = CloseConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [config, admin, rent_receiver, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CloseConfigInstructionAccounts {
-            config: config.pubkey,
-            admin: admin.pubkey,
-            rent_receiver: rent_receiver.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
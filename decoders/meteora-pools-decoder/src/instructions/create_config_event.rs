@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dc7980a1327279d68")]
 pub struct CreateConfigEvent {
     pub trade_fee_numerator: u64,
     pub protocol_trade_fee_numerator: u64,
+    #[carbon(serialize_as = "base58")]
     pub config: solana_pubkey::Pubkey,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0d46a829fa64945a")]
 pub struct CreateMintMetadata {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateMintMetadata")]
 pub struct CreateMintMetadataInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub lp_mint: solana_pubkey::Pubkey,
@@ -14,28 +16,5 @@ pub struct CreateMintMetadataInstructionAccounts {
     pub metadata_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateMintMetadata {
-    type ArrangedAccounts = CreateMintMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, a_vault_lp, mint_metadata, metadata_program, system_program, payer, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateMintMetadataInstructionAccounts {
-            pool: pool.pubkey,
-            lp_mint: lp_mint.pubkey,
-            a_vault_lp: a_vault_lp.pubkey,
-            mint_metadata: mint_metadata.pubkey,
-            metadata_program: metadata_program.pubkey,
-            system_program: system_program.pubkey,
-            payer: payer.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
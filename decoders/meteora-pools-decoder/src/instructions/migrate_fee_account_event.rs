@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1ddfeae81afc69b47d")]
 pub struct MigrateFeeAccountEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_admin_token_a_fee: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_admin_token_b_fee: solana_pubkey::Pubkey,
     pub token_a_amount: u64,
     pub token_b_amount: u64,
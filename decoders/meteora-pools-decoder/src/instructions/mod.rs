@@ -3,16 +3,22 @@ use crate::PROGRAM_ID;
 use super::MeteoraPoolsDecoder;
 pub mod add_balance_liquidity;
 pub mod add_imbalance_liquidity;
+#[cfg(feature = "events")]
 pub mod add_liquidity_event;
 pub mod bootstrap_liquidity;
+#[cfg(feature = "events")]
 pub mod bootstrap_liquidity_event;
 pub mod claim_fee;
+#[cfg(feature = "events")]
 pub mod claim_fee_event;
 pub mod close_config;
+#[cfg(feature = "events")]
 pub mod close_config_event;
 pub mod create_config;
+#[cfg(feature = "events")]
 pub mod create_config_event;
 pub mod create_lock_escrow;
+#[cfg(feature = "events")]
 pub mod create_lock_escrow_event;
 pub mod create_mint_metadata;
 pub mod enable_or_disable_pool;
@@ -24,26 +30,38 @@ pub mod initialize_permissionless_constant_product_pool_with_config2;
 pub mod initialize_permissionless_pool;
 pub mod initialize_permissionless_pool_with_fee_tier;
 pub mod lock;
+#[cfg(feature = "events")]
 pub mod lock_event;
+#[cfg(feature = "events")]
 pub mod migrate_fee_account_event;
 pub mod override_curve_param;
+#[cfg(feature = "events")]
 pub mod override_curve_param_event;
 pub mod partner_claim_fee;
+#[cfg(feature = "events")]
 pub mod partner_claim_fees_event;
+#[cfg(feature = "events")]
 pub mod pool_created_event;
+#[cfg(feature = "events")]
 pub mod pool_enabled_event;
+#[cfg(feature = "events")]
 pub mod pool_info_event;
 pub mod remove_balance_liquidity;
+#[cfg(feature = "events")]
 pub mod remove_liquidity_event;
 pub mod remove_liquidity_single_side;
 pub mod set_pool_fees;
+#[cfg(feature = "events")]
 pub mod set_pool_fees_event;
 pub mod set_whitelisted_vault;
 pub mod swap;
+#[cfg(feature = "events")]
 pub mod swap_event;
+#[cfg(feature = "events")]
 pub mod transfer_admin_event;
 pub mod update_activation_point;
 pub mod withdraw_protocol_fees;
+#[cfg(feature = "events")]
 pub mod withdraw_protocol_fees_event;
 
 #[derive(
@@ -76,29 +94,51 @@ pub enum MeteoraPoolsProgramInstruction {
     WithdrawProtocolFees(withdraw_protocol_fees::WithdrawProtocolFees),
     SetWhitelistedVault(set_whitelisted_vault::SetWhitelistedVault),
     PartnerClaimFee(partner_claim_fee::PartnerClaimFee),
+    #[cfg(feature = "events")]
     AddLiquidityEvent(add_liquidity_event::AddLiquidityEvent),
+    #[cfg(feature = "events")]
     RemoveLiquidityEvent(remove_liquidity_event::RemoveLiquidityEvent),
+    #[cfg(feature = "events")]
     BootstrapLiquidityEvent(bootstrap_liquidity_event::BootstrapLiquidityEvent),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
+    #[cfg(feature = "events")]
     SetPoolFeesEvent(set_pool_fees_event::SetPoolFeesEvent),
+    #[cfg(feature = "events")]
     PoolInfoEvent(pool_info_event::PoolInfoEvent),
+    #[cfg(feature = "events")]
     TransferAdminEvent(transfer_admin_event::TransferAdminEvent),
+    #[cfg(feature = "events")]
     OverrideCurveParamEvent(override_curve_param_event::OverrideCurveParamEvent),
+    #[cfg(feature = "events")]
     PoolCreatedEvent(pool_created_event::PoolCreatedEvent),
+    #[cfg(feature = "events")]
     PoolEnabledEvent(pool_enabled_event::PoolEnabledEvent),
+    #[cfg(feature = "events")]
     MigrateFeeAccountEvent(migrate_fee_account_event::MigrateFeeAccountEvent),
+    #[cfg(feature = "events")]
     CreateLockEscrowEvent(create_lock_escrow_event::CreateLockEscrowEvent),
+    #[cfg(feature = "events")]
     LockEvent(lock_event::LockEvent),
+    #[cfg(feature = "events")]
     ClaimFeeEvent(claim_fee_event::ClaimFeeEvent),
+    #[cfg(feature = "events")]
     CreateConfigEvent(create_config_event::CreateConfigEvent),
+    #[cfg(feature = "events")]
     CloseConfigEvent(close_config_event::CloseConfigEvent),
+    #[cfg(feature = "events")]
     WithdrawProtocolFeesEvent(withdraw_protocol_fees_event::WithdrawProtocolFeesEvent),
+    #[cfg(feature = "events")]
     PartnerClaimFeesEvent(partner_claim_fees_event::PartnerClaimFeesEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraPoolsDecoder {
     type InstructionType = MeteoraPoolsProgramInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -134,23 +174,41 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraPoolsDecoder {
             MeteoraPoolsProgramInstruction::WithdrawProtocolFees => withdraw_protocol_fees::WithdrawProtocolFees,
             MeteoraPoolsProgramInstruction::SetWhitelistedVault => set_whitelisted_vault::SetWhitelistedVault,
             MeteoraPoolsProgramInstruction::PartnerClaimFee => partner_claim_fee::PartnerClaimFee,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::AddLiquidityEvent => add_liquidity_event::AddLiquidityEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::RemoveLiquidityEvent => remove_liquidity_event::RemoveLiquidityEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::BootstrapLiquidityEvent => bootstrap_liquidity_event::BootstrapLiquidityEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::SwapEvent => swap_event::SwapEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::SetPoolFeesEvent => set_pool_fees_event::SetPoolFeesEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::PoolInfoEvent => pool_info_event::PoolInfoEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::TransferAdminEvent => transfer_admin_event::TransferAdminEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::OverrideCurveParamEvent => override_curve_param_event::OverrideCurveParamEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::PoolCreatedEvent => pool_created_event::PoolCreatedEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::PoolEnabledEvent => pool_enabled_event::PoolEnabledEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::MigrateFeeAccountEvent => migrate_fee_account_event::MigrateFeeAccountEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::CreateLockEscrowEvent => create_lock_escrow_event::CreateLockEscrowEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::LockEvent => lock_event::LockEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::ClaimFeeEvent => claim_fee_event::ClaimFeeEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::CreateConfigEvent => create_config_event::CreateConfigEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::CloseConfigEvent => close_config_event::CloseConfigEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::WithdrawProtocolFeesEvent => withdraw_protocol_fees_event::WithdrawProtocolFeesEvent,
+            #[cfg(feature = "events")]
             MeteoraPoolsProgramInstruction::PartnerClaimFeesEvent => partner_claim_fees_event::PartnerClaimFeesEvent,
         )
     }
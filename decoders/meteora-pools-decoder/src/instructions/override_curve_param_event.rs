@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1df714a5f84b0536f6")]
 pub struct OverrideCurveParamEvent {
     pub new_amp: u64,
     pub updated_timestamp: u64,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
 }
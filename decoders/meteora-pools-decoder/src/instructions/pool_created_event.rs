@@ -1,15 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dca2c295868dc9d52")]
 pub struct PoolCreatedEvent {
+    #[carbon(serialize_as = "base58")]
     pub lp_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_a_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_b_mint: solana_pubkey::Pubkey,
     pub pool_type: PoolType,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
 }
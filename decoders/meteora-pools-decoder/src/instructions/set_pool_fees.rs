@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x662c9e36cd257e4e")]
 pub struct SetPoolFees {
@@ -11,24 +11,10 @@ pub struct SetPoolFees {
     pub new_partner_fee_numerator: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetPoolFees")]
 pub struct SetPoolFeesInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub fee_operator: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetPoolFees {
-    type ArrangedAccounts = SetPoolFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, fee_operator, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetPoolFeesInstructionAccounts {
-            pool: pool.pubkey,
-            fee_operator: fee_operator.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
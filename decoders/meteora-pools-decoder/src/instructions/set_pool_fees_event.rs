@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1df51ac6a458124b09")]
 pub struct SetPoolFeesEvent {
@@ -9,5 +9,6 @@ pub struct SetPoolFeesEvent {
     pub trade_fee_denominator: u64,
     pub protocol_trade_fee_numerator: u64,
     pub protocol_trade_fee_denominator: u64,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
 }
@@ -1,13 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d1ef0cfc48bef4f1c")]
 pub struct WithdrawProtocolFeesEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
     pub protocol_a_fee: u64,
     pub protocol_b_fee: u64,
+    #[carbon(serialize_as = "base58")]
     pub protocol_a_fee_owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub protocol_b_fee_owner: solana_pubkey::Pubkey,
 }
@@ -2,7 +2,9 @@ use solana_pubkey::Pubkey;
 
 pub struct MeteoraPoolsDecoder;
 
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
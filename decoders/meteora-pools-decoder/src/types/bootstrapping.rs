@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Bootstrapping {
     pub activation_point: u64,
+    #[carbon(serialize_as = "base58")]
     pub whitelisted_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_creator: solana_pubkey::Pubkey,
     pub activation_type: u8,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ConfigParameters {
     pub trade_fee_numerator: u64,
     pub protocol_trade_fee_numerator: u64,
     pub activation_duration: u64,
+    #[carbon(serialize_as = "base58")]
     pub vault_config_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_creator_authority: solana_pubkey::Pubkey,
     pub activation_type: u8,
     pub index: u64,
@@ -1,10 +1,10 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct CustomizableParams {
     pub trade_fee_numerator: u32,
@@ -12,5 +12,6 @@ pub struct CustomizableParams {
     pub has_alpha_vault: bool,
     pub activation_type: u8,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 90],
 }
@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct PartnerInfo {
     pub fee_numerator: u64,
+    #[carbon(serialize_as = "base58")]
     pub partner_authority: solana_pubkey::Pubkey,
     pub pending_fee_a: u64,
     pub pending_fee_b: u64,
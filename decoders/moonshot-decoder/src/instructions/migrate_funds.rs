@@ -1,12 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2ae50ae7bd3ec1ae")]
 pub struct MigrateFunds {}
 
-#[derive(Debug, PartialEq)]
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "MigrateFunds")]
 pub struct MigrateFundsInstructionAccounts {
     pub backend_authority: solana_pubkey::Pubkey,
     pub migration_authority: solana_pubkey::Pubkey,
@@ -20,33 +21,5 @@ pub struct MigrateFundsInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MigrateFunds {
-    type ArrangedAccounts = MigrateFundsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [backend_authority, migration_authority, curve_account, curve_token_account, migration_authority_token_account, mint, dex_fee_account, helio_fee_account, config_account, system_program, token_program, associated_token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MigrateFundsInstructionAccounts {
-            backend_authority: backend_authority.pubkey,
-            migration_authority: migration_authority.pubkey,
-            curve_account: curve_account.pubkey,
-            curve_token_account: curve_token_account.pubkey,
-            migration_authority_token_account: migration_authority_token_account.pubkey,
-            mint: mint.pubkey,
-            dex_fee_account: dex_fee_account.pubkey,
-            helio_fee_account: helio_fee_account.pubkey,
-            config_account: config_account.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -5,9 +5,11 @@ pub mod buy;
 pub mod config_init;
 pub mod config_update;
 pub mod migrate_funds;
+#[cfg(feature = "events")]
 pub mod migration_event;
 pub mod sell;
 pub mod token_mint;
+#[cfg(feature = "events")]
 pub mod trade_event;
 
 #[derive(
@@ -20,13 +22,19 @@ pub enum MoonshotInstruction {
     MigrateFunds(migrate_funds::MigrateFunds),
     ConfigInit(config_init::ConfigInit),
     ConfigUpdate(config_update::ConfigUpdate),
+    #[cfg(feature = "events")]
     TradeEvent(trade_event::TradeEvent),
+    #[cfg(feature = "events")]
     MigrationEvent(migration_event::MigrationEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for MoonshotDecoder {
     type InstructionType = MoonshotInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -42,7 +50,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MoonshotDecoder {
             MoonshotInstruction::MigrateFunds => migrate_funds::MigrateFunds,
             MoonshotInstruction::ConfigInit => config_init::ConfigInit,
             MoonshotInstruction::ConfigUpdate => config_update::ConfigUpdate,
+            #[cfg(feature = "events")]
             MoonshotInstruction::TradeEvent => trade_event::TradeEvent,
+            #[cfg(feature = "events")]
             MoonshotInstruction::MigrationEvent => migration_event::MigrationEvent,
         )
     }
@@ -180,6 +190,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -311,6 +322,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -441,6 +453,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -573,6 +586,7 @@ mod tests {
             associated_token_program: solana_pubkey::Pubkey::from_str_const(
                 "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -1,11 +1,11 @@
 use {
     super::super::types::*,
     alloc::string::String,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dbddb7fd34ee661ee")]
 pub struct TradeEvent {
@@ -14,8 +14,11 @@ pub struct TradeEvent {
     pub dex_fee: u64,
     pub helio_fee: u64,
     pub allocation: u64,
+    #[carbon(serialize_as = "base58")]
     pub curve: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub cost_token: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub sender: solana_pubkey::Pubkey,
     pub trade_type: TradeType,
     pub label: String,
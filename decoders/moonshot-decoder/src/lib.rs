@@ -3,7 +3,9 @@ extern crate alloc;
 use solana_pubkey::Pubkey;
 
 pub struct MoonshotDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
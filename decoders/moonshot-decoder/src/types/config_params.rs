@@ -1,13 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct ConfigParams {
+    #[carbon(serialize_as = "base58")]
     pub migration_authority: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub backend_authority: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub config_authority: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub helio_fee: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub dex_fee: Option<solana_pubkey::Pubkey>,
     pub fee_bps: Option<u16>,
     pub dex_fee_share: Option<u8>,
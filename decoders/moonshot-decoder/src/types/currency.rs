@@ -1,10 +1,10 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[repr(u8)]
 #[derive(
     CarbonDeserialize,
     Debug,
-    serde::Serialize,
+    CarbonSerialize,
     serde::Deserialize,
     PartialEq,
     Eq,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, deserialize::PrefixString, CarbonDeserialize};
+use carbon_core::{borsh, deserialize::PrefixString, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Default,
 )]
 pub struct TokenMintParams {
     pub name: PrefixString,
@@ -12,5 +12,6 @@ pub struct TokenMintParams {
     pub amount: u64,
     pub curve_type: u8,
     pub migration_target: u8,
+    #[carbon(serialize_as = "hex")]
     pub _padding: [u8; 10],
 }
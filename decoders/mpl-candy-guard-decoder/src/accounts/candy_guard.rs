@@ -0,0 +1,38 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0x2ccfc7b8706722b5",
+    deserialize_with = "deserialize_candy_guard"
+)]
+pub struct CandyGuard {
+    /// The key the candy guard address is derived from.
+    #[carbon(serialize_as = "base58")]
+    pub base: solana_pubkey::Pubkey,
+    pub bump: u8,
+    #[carbon(serialize_as = "base58")]
+    pub authority: solana_pubkey::Pubkey,
+    /// The guards, stored after the other fields, or `None` if they use a
+    /// guard this decoder does not know.
+    pub guards: Option<CandyGuardData>,
+}
+
+fn deserialize_candy_guard(data: &[u8]) -> Option<CandyGuard> {
+    let mut data = data;
+    let base = borsh::BorshDeserialize::deserialize(&mut data).ok()?;
+    let bump = borsh::BorshDeserialize::deserialize(&mut data).ok()?;
+    let authority = borsh::BorshDeserialize::deserialize(&mut data).ok()?;
+    let guards = borsh::BorshDeserialize::deserialize(&mut data).ok();
+
+    Some(CandyGuard {
+        base,
+        bump,
+        authority,
+        guards,
+    })
+}
@@ -0,0 +1,76 @@
+use {
+    super::CandyGuardDecoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod candy_guard;
+
+pub enum CandyGuardAccount {
+    CandyGuard(candy_guard::CandyGuard),
+}
+
+impl AccountDecoder<'_> for CandyGuardDecoder {
+    type AccountType = CandyGuardAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = candy_guard::CandyGuard::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: CandyGuardAccount::CandyGuard(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SolPayment, StartDate};
+
+    #[test]
+    fn test_decode_candy_guard_account() {
+        // Act
+        let decoder = CandyGuardDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/candy_guard_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            CandyGuardAccount::CandyGuard(candy_guard) => {
+                let guards = candy_guard.guards.expect("decode guards");
+                assert_eq!(
+                    guards.default.sol_payment,
+                    Some(SolPayment {
+                        lamports: 500_000_000,
+                        destination: solana_pubkey::Pubkey::from_str_const(
+                            "2WSzwtx4zrzjsP6LTr42rUHjoZiDyaS8rsKLL9VKnNmH"
+                        ),
+                    })
+                );
+                assert!(guards.default.bot_tax.is_some());
+                assert!(guards.default.token_gate.is_none());
+                assert_eq!(guards.groups.len(), 1);
+                assert_eq!(guards.groups[0].label(), "OG");
+                assert_eq!(
+                    guards.groups[0].guards.start_date,
+                    Some(StartDate {
+                        date: 1_730_000_000
+                    })
+                );
+            }
+        }
+    }
+}
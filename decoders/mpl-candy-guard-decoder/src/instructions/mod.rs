@@ -0,0 +1,324 @@
+use crate::PROGRAM_ID;
+
+use super::CandyGuardDecoder;
+pub mod initialize;
+pub mod mint;
+pub mod mint_v2;
+pub mod route;
+pub mod set_authority;
+pub mod unwrap;
+pub mod update;
+pub mod withdraw;
+pub mod wrap;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum CandyGuardInstruction {
+    Initialize(initialize::Initialize),
+    Mint(mint::Mint),
+    MintV2(mint_v2::MintV2),
+    Route(route::Route),
+    SetAuthority(set_authority::SetAuthority),
+    Unwrap(unwrap::Unwrap),
+    Update(update::Update),
+    Withdraw(withdraw::Withdraw),
+    Wrap(wrap::Wrap),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for CandyGuardDecoder {
+    type InstructionType = CandyGuardInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            CandyGuardInstruction::Initialize => initialize::Initialize,
+            CandyGuardInstruction::Mint => mint::Mint,
+            CandyGuardInstruction::MintV2 => mint_v2::MintV2,
+            CandyGuardInstruction::Route => route::Route,
+            CandyGuardInstruction::SetAuthority => set_authority::SetAuthority,
+            CandyGuardInstruction::Unwrap => unwrap::Unwrap,
+            CandyGuardInstruction::Update => update::Update,
+            CandyGuardInstruction::Withdraw => withdraw::Withdraw,
+            CandyGuardInstruction::Wrap => wrap::Wrap,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_mint_v2() {
+        // Arrange
+        let expected_ix = CandyGuardInstruction::MintV2(mint_v2::MintV2 {
+            data: vec![],
+            label: Some("public".to_string()),
+        });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "GspBpZGshotVvnYN5MfFknaQSDjQKcQgT6oTrjJC3dve",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EbCLzpWWrssydW3o6pqGNuQwyK9hCaMHE9Ge2G8GP3rQ",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "HTC8qheGiBA4Yenxa2yyhHrAfzUHPTyxSHRtNXgrJFPg",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2VpVqCy6e6EGYUK2Nrr632aEmG7wzMkK5qJshYfAYkK8",
+                ),
+                true,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "BuQWRmVrD2yxssGJSPrfwbik1iq3BS3Q9QnndL7gqiPo",
+                ),
+                true,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "FpmB3Ew2npkcTnDjX3wTMuFw56EmFvC8rEYXLQ4Jqv1k",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5FL64MQZdr7qMR8DkJMVrpXGCdiJMAfDs2mAJjg4ioia",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2bYGpBH9aHSYkyQmyhvNk6pUqyh7AmWpCVFA8ZsYVabX",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "E7xs3p6h19mxuYYLtuVLjCwXFehx9uJUciXu6YyuCVbG",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "6ubzbxdyUJYuia75d8VZdcEBgfjwmxeVAM4meHMtYKTM",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2m6Ut8qRdXz118VdLfyAyVtdiCtVrNibMC8Y1VQgwRjD",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "92epi9LBUKcX1129j7TfDCsS6S2zQsQda39pCHWJL3Ed",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "AVs3hdQVEEWHqkcr2tsiTbzPmHNZym99dCAfzHJMfDiz",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "6gfiDDH2vAMbmFRzJD3k8tvwdTPcSNkukeEdaLjpLKvP",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "BcBmiNVqv4PHiEPv2VdDaSUPrXRnKkHPLd21pDeJuvYK",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EPhgzytD9GDwi2WfZRWTq2uikgZcvArLiNwFJa2oUMT8",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const("11111111111111111111111111111111"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "Sysvar1nstructions1111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "SysvarS1otHashes111111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "cXmdSreCoshricaiR55RzJY74yD82Rk8iQb3pfLW1iE",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "2NyN2GXMhwb18MrQTdEFFQQigKgNmruTd31poaBjhmfz",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = mint_v2::MintV2InstructionAccounts {
+            candy_guard: solana_pubkey::Pubkey::from_str_const(
+                "GspBpZGshotVvnYN5MfFknaQSDjQKcQgT6oTrjJC3dve",
+            ),
+            candy_machine_program: solana_pubkey::Pubkey::from_str_const(
+                "CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR",
+            ),
+            candy_machine: solana_pubkey::Pubkey::from_str_const(
+                "EbCLzpWWrssydW3o6pqGNuQwyK9hCaMHE9Ge2G8GP3rQ",
+            ),
+            candy_machine_authority_pda: solana_pubkey::Pubkey::from_str_const(
+                "HTC8qheGiBA4Yenxa2yyhHrAfzUHPTyxSHRtNXgrJFPg",
+            ),
+            payer: solana_pubkey::Pubkey::from_str_const(
+                "2VpVqCy6e6EGYUK2Nrr632aEmG7wzMkK5qJshYfAYkK8",
+            ),
+            minter: solana_pubkey::Pubkey::from_str_const(
+                "BuQWRmVrD2yxssGJSPrfwbik1iq3BS3Q9QnndL7gqiPo",
+            ),
+            nft_mint: solana_pubkey::Pubkey::from_str_const(
+                "FpmB3Ew2npkcTnDjX3wTMuFw56EmFvC8rEYXLQ4Jqv1k",
+            ),
+            nft_mint_authority: solana_pubkey::Pubkey::from_str_const(
+                "5FL64MQZdr7qMR8DkJMVrpXGCdiJMAfDs2mAJjg4ioia",
+            ),
+            nft_metadata: solana_pubkey::Pubkey::from_str_const(
+                "2bYGpBH9aHSYkyQmyhvNk6pUqyh7AmWpCVFA8ZsYVabX",
+            ),
+            nft_master_edition: solana_pubkey::Pubkey::from_str_const(
+                "E7xs3p6h19mxuYYLtuVLjCwXFehx9uJUciXu6YyuCVbG",
+            ),
+            token: solana_pubkey::Pubkey::from_str_const(
+                "6ubzbxdyUJYuia75d8VZdcEBgfjwmxeVAM4meHMtYKTM",
+            ),
+            token_record: solana_pubkey::Pubkey::from_str_const(
+                "2m6Ut8qRdXz118VdLfyAyVtdiCtVrNibMC8Y1VQgwRjD",
+            ),
+            collection_delegate_record: solana_pubkey::Pubkey::from_str_const(
+                "92epi9LBUKcX1129j7TfDCsS6S2zQsQda39pCHWJL3Ed",
+            ),
+            collection_mint: solana_pubkey::Pubkey::from_str_const(
+                "AVs3hdQVEEWHqkcr2tsiTbzPmHNZym99dCAfzHJMfDiz",
+            ),
+            collection_metadata: solana_pubkey::Pubkey::from_str_const(
+                "6gfiDDH2vAMbmFRzJD3k8tvwdTPcSNkukeEdaLjpLKvP",
+            ),
+            collection_master_edition: solana_pubkey::Pubkey::from_str_const(
+                "BcBmiNVqv4PHiEPv2VdDaSUPrXRnKkHPLd21pDeJuvYK",
+            ),
+            collection_update_authority: solana_pubkey::Pubkey::from_str_const(
+                "EPhgzytD9GDwi2WfZRWTq2uikgZcvArLiNwFJa2oUMT8",
+            ),
+            token_metadata_program: solana_pubkey::Pubkey::from_str_const(
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+            ),
+            spl_token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            spl_ata_program: solana_pubkey::Pubkey::from_str_const(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+            ),
+            system_program: solana_pubkey::Pubkey::from_str_const(
+                "11111111111111111111111111111111",
+            ),
+            sysvar_instructions: solana_pubkey::Pubkey::from_str_const(
+                "Sysvar1nstructions1111111111111111111111111",
+            ),
+            recent_slothashes: solana_pubkey::Pubkey::from_str_const(
+                "SysvarS1otHashes111111111111111111111111111",
+            ),
+            authorization_rules_program: solana_pubkey::Pubkey::from_str_const(
+                "cXmdSreCoshricaiR55RzJY74yD82Rk8iQb3pfLW1iE",
+            ),
+            authorization_rules: solana_pubkey::Pubkey::from_str_const(
+                "2NyN2GXMhwb18MrQTdEFFQQigKgNmruTd31poaBjhmfz",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = CandyGuardDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/mint_v2_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            mint_v2::MintV2::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,21 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xe517cb977ae3ad2a")]
+pub struct Route {
+    pub args: RouteArgs,
+    pub label: Option<String>,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Route")]
+pub struct RouteInstructionAccounts {
+    pub candy_guard: solana_pubkey::Pubkey,
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x85fa25156ea31a79")]
+pub struct SetAuthority {
+    #[carbon(serialize_as = "base58")]
+    pub new_authority: solana_pubkey::Pubkey,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "SetAuthority")]
+pub struct SetAuthorityInstructionAccounts {
+    pub candy_guard: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,31 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xdbc858b09e3ffd7f")]
+pub struct Update {
+    /// The serialized `CandyGuardData`.
+    #[carbon(serialize_as = "hex")]
+    pub data: Vec<u8>,
+}
+
+impl Update {
+    /// Decodes the guards in `data`, or returns `None` if they use a guard
+    /// this decoder does not know.
+    pub fn guards(&self) -> Option<CandyGuardData> {
+        <CandyGuardData as borsh::BorshDeserialize>::try_from_slice(&self.data).ok()
+    }
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Update")]
+pub struct UpdateInstructionAccounts {
+    pub candy_guard: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xb2280abde481ba8c")]
+pub struct Wrap {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Wrap")]
+pub struct WrapInstructionAccounts {
+    pub candy_guard: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub candy_machine_program: solana_pubkey::Pubkey,
+    pub candy_machine_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,11 @@
+use solana_pubkey::Pubkey;
+
+pub struct CandyGuardDecoder;
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey =
+    solana_pubkey::Pubkey::from_str_const("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g");
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Restricts minting to a single wallet.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct AddressGate {
+    #[carbon(serialize_as = "base58")]
+    pub address: solana_pubkey::Pubkey,
+}
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Limits the number of mints of a group.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Allocation {
+    pub id: u8,
+    pub limit: u32,
+}
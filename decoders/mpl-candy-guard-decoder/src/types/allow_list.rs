@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Restricts minting to wallets in a Merkle tree.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct AllowList {
+    #[carbon(serialize_as = "hex")]
+    pub merkle_root: [u8; 32],
+}
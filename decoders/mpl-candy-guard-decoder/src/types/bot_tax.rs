@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Charges a fee, instead of failing, when a mint is rejected by another guard.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct BotTax {
+    pub lamports: u64,
+    pub last_instruction: bool,
+}
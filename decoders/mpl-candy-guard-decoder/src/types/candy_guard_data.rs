@@ -0,0 +1,13 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// The guards of a candy guard: the default guards, applying to every mint,
+/// and the groups, each adding guards for the mints using its label.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct CandyGuardData {
+    pub default: GuardSet,
+    pub groups: Vec<Group>,
+}
@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Closes minting at a Unix timestamp.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct EndDate {
+    pub date: i64,
+}
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Requires a valid gateway token of a gatekeeper network.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Gatekeeper {
+    #[carbon(serialize_as = "base58")]
+    pub gatekeeper_network: solana_pubkey::Pubkey,
+    pub expire_on_use: bool,
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// A named set of guards, minted from by passing its label.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Group {
+    /// The label, padded with zeros to 6 bytes.
+    #[carbon(serialize_as = "hex")]
+    pub label: [u8; 6],
+    pub guards: GuardSet,
+}
+
+impl Group {
+    /// Returns the label without its zero padding.
+    pub fn label(&self) -> String {
+        String::from_utf8_lossy(&self.label)
+            .trim_end_matches('\0')
+            .to_string()
+    }
+}
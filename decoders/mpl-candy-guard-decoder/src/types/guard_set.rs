@@ -0,0 +1,90 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonSerialize};
+
+/// The number of guards this decoder knows, the bits of a guard set above
+/// them being unknown guards.
+pub const GUARD_COUNT: u32 = 21;
+
+/// A set of guards, each enabled when present.
+///
+/// Guard sets are serialized as a `u64` with a bit set for each enabled guard,
+/// followed by the enabled guards in bit order.
+#[derive(Debug, Default, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct GuardSet {
+    pub bot_tax: Option<BotTax>,
+    pub sol_payment: Option<SolPayment>,
+    pub token_payment: Option<TokenPayment>,
+    pub start_date: Option<StartDate>,
+    pub third_party_signer: Option<ThirdPartySigner>,
+    pub token_gate: Option<TokenGate>,
+    pub gatekeeper: Option<Gatekeeper>,
+    pub end_date: Option<EndDate>,
+    pub allow_list: Option<AllowList>,
+    pub mint_limit: Option<MintLimit>,
+    pub nft_payment: Option<NftPayment>,
+    pub redeemed_amount: Option<RedeemedAmount>,
+    pub address_gate: Option<AddressGate>,
+    pub nft_gate: Option<NftGate>,
+    pub nft_burn: Option<NftBurn>,
+    pub token_burn: Option<TokenBurn>,
+    pub freeze_sol_payment: Option<FreezeSolPayment>,
+    pub freeze_token_payment: Option<FreezeTokenPayment>,
+    pub program_gate: Option<ProgramGate>,
+    pub allocation: Option<Allocation>,
+    pub token2022_payment: Option<Token2022Payment>,
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for GuardSet {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        borsh::BorshDeserialize::try_from_slice(data).ok()
+    }
+}
+
+impl borsh::BorshDeserialize for GuardSet {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> Result<Self, borsh::maybestd::io::Error> {
+        let features = <u64 as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        if features >> GUARD_COUNT != 0 {
+            return Err(borsh::maybestd::io::ErrorKind::InvalidData.into());
+        }
+
+        // Fields are initialized in declaration order, which is the bit order.
+        Ok(Self {
+            bot_tax: read_guard(reader, features, GuardType::BotTax)?,
+            sol_payment: read_guard(reader, features, GuardType::SolPayment)?,
+            token_payment: read_guard(reader, features, GuardType::TokenPayment)?,
+            start_date: read_guard(reader, features, GuardType::StartDate)?,
+            third_party_signer: read_guard(reader, features, GuardType::ThirdPartySigner)?,
+            token_gate: read_guard(reader, features, GuardType::TokenGate)?,
+            gatekeeper: read_guard(reader, features, GuardType::Gatekeeper)?,
+            end_date: read_guard(reader, features, GuardType::EndDate)?,
+            allow_list: read_guard(reader, features, GuardType::AllowList)?,
+            mint_limit: read_guard(reader, features, GuardType::MintLimit)?,
+            nft_payment: read_guard(reader, features, GuardType::NftPayment)?,
+            redeemed_amount: read_guard(reader, features, GuardType::RedeemedAmount)?,
+            address_gate: read_guard(reader, features, GuardType::AddressGate)?,
+            nft_gate: read_guard(reader, features, GuardType::NftGate)?,
+            nft_burn: read_guard(reader, features, GuardType::NftBurn)?,
+            token_burn: read_guard(reader, features, GuardType::TokenBurn)?,
+            freeze_sol_payment: read_guard(reader, features, GuardType::FreezeSolPayment)?,
+            freeze_token_payment: read_guard(reader, features, GuardType::FreezeTokenPayment)?,
+            program_gate: read_guard(reader, features, GuardType::ProgramGate)?,
+            allocation: read_guard(reader, features, GuardType::Allocation)?,
+            token2022_payment: read_guard(reader, features, GuardType::Token2022Payment)?,
+        })
+    }
+}
+
+fn read_guard<T: borsh::BorshDeserialize, R: borsh::maybestd::io::Read>(
+    reader: &mut R,
+    features: u64,
+    guard: GuardType,
+) -> Result<Option<T>, borsh::maybestd::io::Error> {
+    if features & (1 << guard as u64) == 0 {
+        return Ok(None);
+    }
+
+    T::deserialize_reader(reader).map(Some)
+}
@@ -0,0 +1,29 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// The guards, in the order of their bits in a guard set.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum GuardType {
+    BotTax,
+    SolPayment,
+    TokenPayment,
+    StartDate,
+    ThirdPartySigner,
+    TokenGate,
+    Gatekeeper,
+    EndDate,
+    AllowList,
+    MintLimit,
+    NftPayment,
+    RedeemedAmount,
+    AddressGate,
+    NftGate,
+    NftBurn,
+    TokenBurn,
+    FreezeSolPayment,
+    FreezeTokenPayment,
+    ProgramGate,
+    Allocation,
+    Token2022Payment,
+}
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Limits the number of mints per wallet.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct MintLimit {
+    pub id: u8,
+    pub limit: u16,
+}
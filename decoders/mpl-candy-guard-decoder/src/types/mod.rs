@@ -0,0 +1,52 @@
+pub mod address_gate;
+pub use address_gate::*;
+pub mod allocation;
+pub use allocation::*;
+pub mod allow_list;
+pub use allow_list::*;
+pub mod bot_tax;
+pub use bot_tax::*;
+pub mod candy_guard_data;
+pub use candy_guard_data::*;
+pub mod end_date;
+pub use end_date::*;
+pub mod freeze_sol_payment;
+pub use freeze_sol_payment::*;
+pub mod freeze_token_payment;
+pub use freeze_token_payment::*;
+pub mod gatekeeper;
+pub use gatekeeper::*;
+pub mod group;
+pub use group::*;
+pub mod guard_set;
+pub use guard_set::*;
+pub mod guard_type;
+pub use guard_type::*;
+pub mod mint_limit;
+pub use mint_limit::*;
+pub mod nft_burn;
+pub use nft_burn::*;
+pub mod nft_gate;
+pub use nft_gate::*;
+pub mod nft_payment;
+pub use nft_payment::*;
+pub mod program_gate;
+pub use program_gate::*;
+pub mod redeemed_amount;
+pub use redeemed_amount::*;
+pub mod route_args;
+pub use route_args::*;
+pub mod sol_payment;
+pub use sol_payment::*;
+pub mod start_date;
+pub use start_date::*;
+pub mod third_party_signer;
+pub use third_party_signer::*;
+pub mod token2022_payment;
+pub use token2022_payment::*;
+pub mod token_burn;
+pub use token_burn::*;
+pub mod token_gate;
+pub use token_gate::*;
+pub mod token_payment;
+pub use token_payment::*;
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Burns an NFT of a collection for each mint.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct NftBurn {
+    #[carbon(serialize_as = "base58")]
+    pub required_collection: solana_pubkey::Pubkey,
+}
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Restricts minting to holders of an NFT of a collection.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct NftGate {
+    #[carbon(serialize_as = "base58")]
+    pub required_collection: solana_pubkey::Pubkey,
+}
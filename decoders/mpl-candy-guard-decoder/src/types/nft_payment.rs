@@ -0,0 +1,12 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Charges an NFT of a collection for each mint.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct NftPayment {
+    #[carbon(serialize_as = "base58")]
+    pub required_collection: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub destination: solana_pubkey::Pubkey,
+}
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Restricts the programs the mint transaction may call.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ProgramGate {
+    #[carbon(serialize_as = "base58")]
+    pub additional: Vec<solana_pubkey::Pubkey>,
+}
@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Closes minting once a number of items were minted.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct RedeemedAmount {
+    pub maximum: u64,
+}
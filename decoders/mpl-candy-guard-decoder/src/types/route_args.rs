@@ -0,0 +1,12 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct RouteArgs {
+    pub guard: GuardType,
+    #[carbon(serialize_as = "hex")]
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Charges SOL for each mint.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct SolPayment {
+    pub lamports: u64,
+    #[carbon(serialize_as = "base58")]
+    pub destination: solana_pubkey::Pubkey,
+}
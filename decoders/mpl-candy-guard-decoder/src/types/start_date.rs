@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Opens minting at a Unix timestamp.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct StartDate {
+    pub date: i64,
+}
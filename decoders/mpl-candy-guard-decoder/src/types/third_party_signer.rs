@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Requires a signature of a given key.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ThirdPartySigner {
+    #[carbon(serialize_as = "base58")]
+    pub signer_key: solana_pubkey::Pubkey,
+}
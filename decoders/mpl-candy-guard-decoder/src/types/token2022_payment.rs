@@ -0,0 +1,13 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Charges Token-2022 tokens for each mint.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Token2022Payment {
+    pub amount: u64,
+    #[carbon(serialize_as = "base58")]
+    pub mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub destination_ata: solana_pubkey::Pubkey,
+}
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Burns tokens for each mint.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct TokenBurn {
+    pub amount: u64,
+    #[carbon(serialize_as = "base58")]
+    pub mint: solana_pubkey::Pubkey,
+}
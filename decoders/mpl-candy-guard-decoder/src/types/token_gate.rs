@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// Restricts minting to holders of a token.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct TokenGate {
+    pub amount: u64,
+    #[carbon(serialize_as = "base58")]
+    pub mint: solana_pubkey::Pubkey,
+}
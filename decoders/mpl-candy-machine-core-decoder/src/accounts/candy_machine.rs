@@ -0,0 +1,44 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0x33adb17119f16dbd",
+    deserialize_with = "deserialize_candy_machine"
+)]
+pub struct CandyMachine {
+    pub version: AccountVersion,
+    /// The token standard of the minted NFTs, such as `4` for programmable
+    /// NFTs.
+    pub token_standard: u8,
+    #[carbon(serialize_as = "hex")]
+    pub features: [u8; 6],
+    #[carbon(serialize_as = "base58")]
+    pub authority: solana_pubkey::Pubkey,
+    /// The authority allowed to mint, usually a candy guard.
+    #[carbon(serialize_as = "base58")]
+    pub mint_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub collection_mint: solana_pubkey::Pubkey,
+    pub items_redeemed: u64,
+    pub data: CandyMachineData,
+}
+
+impl CandyMachine {
+    /// Returns the number of items left to mint.
+    pub fn items_remaining(&self) -> u64 {
+        self.data
+            .items_available
+            .saturating_sub(self.items_redeemed)
+    }
+}
+
+/// Candy machine accounts store their config lines and the mint bitmap after
+/// these fields, which are ignored instead of rejected.
+fn deserialize_candy_machine(data: &[u8]) -> Option<CandyMachine> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
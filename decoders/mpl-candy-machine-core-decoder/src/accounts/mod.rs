@@ -0,0 +1,68 @@
+use {
+    super::CandyMachineCoreDecoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod candy_machine;
+
+pub enum CandyMachineCoreAccount {
+    CandyMachine(candy_machine::CandyMachine),
+}
+
+impl AccountDecoder<'_> for CandyMachineCoreDecoder {
+    type AccountType = CandyMachineCoreAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            candy_machine::CandyMachine::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: CandyMachineCoreAccount::CandyMachine(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_candy_machine_account() {
+        // Act
+        let decoder = CandyMachineCoreDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/candy_machine_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            CandyMachineCoreAccount::CandyMachine(candy_machine) => {
+                assert_eq!(candy_machine.data.symbol, "CRBN");
+                assert_eq!(candy_machine.data.items_available, 500);
+                assert_eq!(candy_machine.items_remaining(), 458);
+                assert_eq!(candy_machine.data.creators.len(), 1);
+                assert_eq!(
+                    candy_machine
+                        .data
+                        .config_line_settings
+                        .map(|settings| settings.prefix_uri),
+                    Some("https://arweave.net/".to_string())
+                );
+                assert!(candy_machine.data.hidden_settings.is_none());
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xdf32e0e39708736a")]
+pub struct AddConfigLines {
+    pub index: u32,
+    pub config_lines: Vec<ConfigLine>,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "AddConfigLines")]
+pub struct AddConfigLinesInstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
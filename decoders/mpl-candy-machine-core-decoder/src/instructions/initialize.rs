@@ -0,0 +1,28 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xafaf6d1f0d989bed")]
+pub struct Initialize {
+    pub data: CandyMachineData,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Initialize")]
+pub struct InitializeInstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority_pda: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub collection_metadata: solana_pubkey::Pubkey,
+    pub collection_mint: solana_pubkey::Pubkey,
+    pub collection_master_edition: solana_pubkey::Pubkey,
+    pub collection_update_authority: solana_pubkey::Pubkey,
+    pub collection_authority_record: solana_pubkey::Pubkey,
+    pub token_metadata_program: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,33 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x4399af27da102620")]
+pub struct InitializeV2 {
+    pub data: CandyMachineData,
+    pub token_standard: u8,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "InitializeV2")]
+pub struct InitializeV2InstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority_pda: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub rule_set: solana_pubkey::Pubkey,
+    pub collection_metadata: solana_pubkey::Pubkey,
+    pub collection_mint: solana_pubkey::Pubkey,
+    pub collection_master_edition: solana_pubkey::Pubkey,
+    pub collection_update_authority: solana_pubkey::Pubkey,
+    pub collection_delegate_record: solana_pubkey::Pubkey,
+    pub token_metadata_program: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub sysvar_instructions: solana_pubkey::Pubkey,
+    pub authorization_rules_program: solana_pubkey::Pubkey,
+    pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
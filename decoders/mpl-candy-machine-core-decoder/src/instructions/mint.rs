@@ -0,0 +1,30 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x3339e12fb69289a6")]
+pub struct Mint {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Mint")]
+pub struct MintInstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority_pda: solana_pubkey::Pubkey,
+    pub mint_authority: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub nft_mint: solana_pubkey::Pubkey,
+    pub nft_mint_authority: solana_pubkey::Pubkey,
+    pub nft_metadata: solana_pubkey::Pubkey,
+    pub nft_master_edition: solana_pubkey::Pubkey,
+    pub collection_authority_record: solana_pubkey::Pubkey,
+    pub collection_mint: solana_pubkey::Pubkey,
+    pub collection_metadata: solana_pubkey::Pubkey,
+    pub collection_master_edition: solana_pubkey::Pubkey,
+    pub collection_update_authority: solana_pubkey::Pubkey,
+    pub token_metadata_program: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub recent_slothashes: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,37 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x78791792ad6ec7cd")]
+pub struct MintV2 {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "MintV2")]
+pub struct MintV2InstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority_pda: solana_pubkey::Pubkey,
+    pub mint_authority: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+    pub nft_owner: solana_pubkey::Pubkey,
+    pub nft_mint: solana_pubkey::Pubkey,
+    pub nft_mint_authority: solana_pubkey::Pubkey,
+    pub nft_metadata: solana_pubkey::Pubkey,
+    pub nft_master_edition: solana_pubkey::Pubkey,
+    pub token: solana_pubkey::Pubkey,
+    pub token_record: solana_pubkey::Pubkey,
+    pub collection_delegate_record: solana_pubkey::Pubkey,
+    pub collection_mint: solana_pubkey::Pubkey,
+    pub collection_metadata: solana_pubkey::Pubkey,
+    pub collection_master_edition: solana_pubkey::Pubkey,
+    pub collection_update_authority: solana_pubkey::Pubkey,
+    pub token_metadata_program: solana_pubkey::Pubkey,
+    pub spl_token_program: solana_pubkey::Pubkey,
+    pub spl_ata_program: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub sysvar_instructions: solana_pubkey::Pubkey,
+    pub recent_slothashes: solana_pubkey::Pubkey,
+    pub authorization_rules_program: solana_pubkey::Pubkey,
+    pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,312 @@
+use crate::PROGRAM_ID;
+
+use super::CandyMachineCoreDecoder;
+pub mod add_config_lines;
+pub mod initialize;
+pub mod initialize_v2;
+pub mod mint;
+pub mod mint_v2;
+pub mod set_authority;
+pub mod set_mint_authority;
+pub mod update;
+pub mod withdraw;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum CandyMachineCoreInstruction {
+    Initialize(initialize::Initialize),
+    InitializeV2(initialize_v2::InitializeV2),
+    AddConfigLines(add_config_lines::AddConfigLines),
+    Mint(mint::Mint),
+    MintV2(mint_v2::MintV2),
+    SetAuthority(set_authority::SetAuthority),
+    SetMintAuthority(set_mint_authority::SetMintAuthority),
+    Update(update::Update),
+    Withdraw(withdraw::Withdraw),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for CandyMachineCoreDecoder {
+    type InstructionType = CandyMachineCoreInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            CandyMachineCoreInstruction::Initialize => initialize::Initialize,
+            CandyMachineCoreInstruction::InitializeV2 => initialize_v2::InitializeV2,
+            CandyMachineCoreInstruction::AddConfigLines => add_config_lines::AddConfigLines,
+            CandyMachineCoreInstruction::Mint => mint::Mint,
+            CandyMachineCoreInstruction::MintV2 => mint_v2::MintV2,
+            CandyMachineCoreInstruction::SetAuthority => set_authority::SetAuthority,
+            CandyMachineCoreInstruction::SetMintAuthority => set_mint_authority::SetMintAuthority,
+            CandyMachineCoreInstruction::Update => update::Update,
+            CandyMachineCoreInstruction::Withdraw => withdraw::Withdraw,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_mint_v2() {
+        // Arrange
+        let expected_ix = CandyMachineCoreInstruction::MintV2(mint_v2::MintV2 {});
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "8UrctCMoMq6Cb7y1SnYvZPxHJRiki7MU8Ctdeimom23q",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "FiByh3ie62UzUZ8YUEL4o7XGCJ41Pjhs6BUthMuBLHB2",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7Ja4VYrPvwW9WC2tnLpy5Pc8YdrD9VbvZbGaKMei814F",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "H8QhfVgvuc1yWPvvUhaDH4qFQr2MSzwmv4J6zhBQW12p",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5tFJDo8SdT28fA7NnitSTkiA842r24Ew3EtrycQVFnpF",
+                ),
+                true,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "4LifYb2r93NrY468iaZMvbAjZnAaRq1hUsK6D65NRq1W",
+                ),
+                true,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "76omxYWLpFhJbpzUTLFiBVfdvyskH4yrKBMQhE54yqH9",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9izN7rgmHDUKNM2Yoq1eqiQR9qGjCt67wpco5VbeoAkr",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CCRmjVTFvwmNtfgJHRZaGPpeKFP4EJKRuHHdiL95QyYp",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "BWJ2nLeCnvBsFAHJh2wJhxcY171b6cwuAgH3oWXaWbQ8",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3C9oY1pca32TDbg5VW7imeYEm5vrW2wLW5wV1jJcgUkp",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CoKQC7BtXLdByo6goF8bGKLi8aAkZkufLiWpRpEqJsna",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "DCmJ77eQ9PHmGz3ypkagA7HdvFfpzanwQ4hxZPs3NuBy",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "G3d6sJwHoamKUzRH4RUuMTUNmv6GUtsHNimKc8ufWirL",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7n3Xzrb7wS1cCYYiXNMJG2ADfQzKCX9LEfKygvsaHDk5",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "8ue1NKngmDxDtvGT3YEhsG3cPVRbxtwEg7sAb9GXrPyF",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const("11111111111111111111111111111111"),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "Sysvar1nstructions1111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "SysvarS1otHashes111111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "D4tEo4DQfyG5o3Cpg4doWpnYnSy5Rxir29XpYgR5Vsrd",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3QG8eRpxRbX7Dho84vtR4aNT8hbQ7FXqLNeP6XqEyMJ2",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = mint_v2::MintV2InstructionAccounts {
+            candy_machine: solana_pubkey::Pubkey::from_str_const(
+                "8UrctCMoMq6Cb7y1SnYvZPxHJRiki7MU8Ctdeimom23q",
+            ),
+            authority_pda: solana_pubkey::Pubkey::from_str_const(
+                "FiByh3ie62UzUZ8YUEL4o7XGCJ41Pjhs6BUthMuBLHB2",
+            ),
+            mint_authority: solana_pubkey::Pubkey::from_str_const(
+                "7Ja4VYrPvwW9WC2tnLpy5Pc8YdrD9VbvZbGaKMei814F",
+            ),
+            payer: solana_pubkey::Pubkey::from_str_const(
+                "H8QhfVgvuc1yWPvvUhaDH4qFQr2MSzwmv4J6zhBQW12p",
+            ),
+            nft_owner: solana_pubkey::Pubkey::from_str_const(
+                "5tFJDo8SdT28fA7NnitSTkiA842r24Ew3EtrycQVFnpF",
+            ),
+            nft_mint: solana_pubkey::Pubkey::from_str_const(
+                "4LifYb2r93NrY468iaZMvbAjZnAaRq1hUsK6D65NRq1W",
+            ),
+            nft_mint_authority: solana_pubkey::Pubkey::from_str_const(
+                "76omxYWLpFhJbpzUTLFiBVfdvyskH4yrKBMQhE54yqH9",
+            ),
+            nft_metadata: solana_pubkey::Pubkey::from_str_const(
+                "9izN7rgmHDUKNM2Yoq1eqiQR9qGjCt67wpco5VbeoAkr",
+            ),
+            nft_master_edition: solana_pubkey::Pubkey::from_str_const(
+                "CCRmjVTFvwmNtfgJHRZaGPpeKFP4EJKRuHHdiL95QyYp",
+            ),
+            token: solana_pubkey::Pubkey::from_str_const(
+                "BWJ2nLeCnvBsFAHJh2wJhxcY171b6cwuAgH3oWXaWbQ8",
+            ),
+            token_record: solana_pubkey::Pubkey::from_str_const(
+                "3C9oY1pca32TDbg5VW7imeYEm5vrW2wLW5wV1jJcgUkp",
+            ),
+            collection_delegate_record: solana_pubkey::Pubkey::from_str_const(
+                "CoKQC7BtXLdByo6goF8bGKLi8aAkZkufLiWpRpEqJsna",
+            ),
+            collection_mint: solana_pubkey::Pubkey::from_str_const(
+                "DCmJ77eQ9PHmGz3ypkagA7HdvFfpzanwQ4hxZPs3NuBy",
+            ),
+            collection_metadata: solana_pubkey::Pubkey::from_str_const(
+                "G3d6sJwHoamKUzRH4RUuMTUNmv6GUtsHNimKc8ufWirL",
+            ),
+            collection_master_edition: solana_pubkey::Pubkey::from_str_const(
+                "7n3Xzrb7wS1cCYYiXNMJG2ADfQzKCX9LEfKygvsaHDk5",
+            ),
+            collection_update_authority: solana_pubkey::Pubkey::from_str_const(
+                "8ue1NKngmDxDtvGT3YEhsG3cPVRbxtwEg7sAb9GXrPyF",
+            ),
+            token_metadata_program: solana_pubkey::Pubkey::from_str_const(
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+            ),
+            spl_token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            spl_ata_program: solana_pubkey::Pubkey::from_str_const(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+            ),
+            system_program: solana_pubkey::Pubkey::from_str_const(
+                "11111111111111111111111111111111",
+            ),
+            sysvar_instructions: solana_pubkey::Pubkey::from_str_const(
+                "Sysvar1nstructions1111111111111111111111111",
+            ),
+            recent_slothashes: solana_pubkey::Pubkey::from_str_const(
+                "SysvarS1otHashes111111111111111111111111111",
+            ),
+            authorization_rules_program: solana_pubkey::Pubkey::from_str_const(
+                "D4tEo4DQfyG5o3Cpg4doWpnYnSy5Rxir29XpYgR5Vsrd",
+            ),
+            authorization_rules: solana_pubkey::Pubkey::from_str_const(
+                "3QG8eRpxRbX7Dho84vtR4aNT8hbQ7FXqLNeP6XqEyMJ2",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = CandyMachineCoreDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/mint_v2_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            mint_v2::MintV2::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x437f9bbb64ae6779")]
+pub struct SetMintAuthority {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "SetMintAuthority")]
+pub struct SetMintAuthorityInstructionAccounts {
+    pub candy_machine: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub mint_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
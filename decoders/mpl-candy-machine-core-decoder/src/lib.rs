@@ -0,0 +1,11 @@
+use solana_pubkey::Pubkey;
+
+pub struct CandyMachineCoreDecoder;
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey =
+    solana_pubkey::Pubkey::from_str_const("CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR");
@@ -0,0 +1,15 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ConfigLineSettings {
+    /// The prefix shared by the names of all items.
+    pub prefix_name: String,
+    pub name_length: u32,
+    /// The prefix shared by the URIs of all items.
+    pub prefix_uri: String,
+    pub uri_length: u32,
+    /// Whether items are minted in order rather than at random.
+    pub is_sequential: bool,
+}
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Creator {
+    #[carbon(serialize_as = "base58")]
+    pub address: solana_pubkey::Pubkey,
+    pub verified: bool,
+    pub percentage_share: u8,
+}
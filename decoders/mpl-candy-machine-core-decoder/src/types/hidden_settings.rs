@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct HiddenSettings {
+    pub name: String,
+    pub uri: String,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+}
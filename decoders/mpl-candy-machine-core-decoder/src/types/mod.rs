@@ -0,0 +1,12 @@
+pub mod account_version;
+pub use account_version::*;
+pub mod candy_machine_data;
+pub use candy_machine_data::*;
+pub mod config_line;
+pub use config_line::*;
+pub mod config_line_settings;
+pub use config_line_settings::*;
+pub mod creator;
+pub use creator::*;
+pub mod hidden_settings;
+pub use hidden_settings::*;
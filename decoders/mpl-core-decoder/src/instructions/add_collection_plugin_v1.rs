@@ -1,41 +1,23 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x03")]
 pub struct AddCollectionPluginV1 {
     pub add_collection_plugin_v1_args: AddCollectionPluginV1Args,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddCollectionPluginV1")]
 pub struct AddCollectionPluginV1InstructionAccounts {
     pub collection: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddCollectionPluginV1 {
-    type ArrangedAccounts = AddCollectionPluginV1InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [collection, payer, authority, system_program, log_wrapper, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(AddCollectionPluginV1InstructionAccounts {
-            collection: collection.pubkey,
-            payer: payer.pubkey,
-            authority: authority.pubkey,
-            system_program: system_program.pubkey,
-            log_wrapper: log_wrapper.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x02")]
 pub struct AddPluginV1 {
     pub add_plugin_v1_args: AddPluginV1Args,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddPluginV1")]
 pub struct AddPluginV1InstructionAccounts {
     pub asset: solana_pubkey::Pubkey,
     pub collection: solana_pubkey::Pubkey,
@@ -18,27 +20,5 @@ pub struct AddPluginV1InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddPluginV1 {
-    type ArrangedAccounts = AddPluginV1InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [asset, collection, payer, authority, system_program, log_wrapper, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(AddPluginV1InstructionAccounts {
-            asset: asset.pubkey,
-            collection: collection.pubkey,
-            payer: payer.pubkey,
-            authority: authority.pubkey,
-            system_program: system_program.pubkey,
-            log_wrapper: log_wrapper.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x13")]
 pub struct Collect {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Collect")]
 pub struct CollectInstructionAccounts {
     pub recipient1: solana_pubkey::Pubkey,
     pub recipient2: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Collect {
-    type ArrangedAccounts = CollectInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [recipient1, recipient2, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CollectInstructionAccounts {
-            recipient1: recipient1.pubkey,
-            recipient2: recipient2.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1d")]
 pub struct WriteCollectionExternalPluginAdapterDataV1 {
@@ -19,6 +19,7 @@ pub struct WriteCollectionExternalPluginAdapterDataV1InstructionAccounts {
     pub buffer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPluginAdapterDataV1 {
@@ -27,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPlugin
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection, payer, authority, buffer, system_program, log_wrapper, _remaining @ ..] =
+        let [collection, payer, authority, buffer, system_program, log_wrapper, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPlugin
                 buffer: buffer.pubkey,
                 system_program: system_program.pubkey,
                 log_wrapper: log_wrapper.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
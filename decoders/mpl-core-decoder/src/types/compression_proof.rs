@@ -1,12 +1,13 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct CompressionProof {
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
     pub update_authority: UpdateAuthority,
     pub name: String,
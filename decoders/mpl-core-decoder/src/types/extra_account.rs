@@ -1,10 +1,10 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub enum ExtraAccount {
     PreconfiguredProgram {
@@ -29,11 +29,13 @@ pub enum ExtraAccount {
     },
     CustomPda {
         seeds: Vec<Seed>,
+        #[carbon(serialize_as = "base58")]
         custom_program_id: Option<solana_pubkey::Pubkey>,
         is_signer: bool,
         is_writable: bool,
     },
     Address {
+        #[carbon(serialize_as = "base58")]
         address: solana_pubkey::Pubkey,
         is_signer: bool,
         is_writable: bool,
@@ -1,12 +1,13 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct LinkedLifecycleHookInitInfo {
+    #[carbon(serialize_as = "base58")]
     pub hooked_program: solana_pubkey::Pubkey,
     pub init_plugin_authority: Option<Authority>,
     pub lifecycle_checks: Vec<(HookableLifecycleEvent, ExternalCheckResult)>,
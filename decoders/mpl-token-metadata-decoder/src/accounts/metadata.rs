@@ -4,6 +4,7 @@ use {
 };
 
 #[derive(CarbonDeserialize, Debug)]
+#[carbon(deserialize_with = "deserialize_metadata")]
 pub struct Metadata {
     pub key: Key,
     pub update_authority: solana_pubkey::Pubkey,
@@ -18,3 +19,12 @@ pub struct Metadata {
     pub collection_details: Option<CollectionDetails>,
     pub programmable_config: Option<ProgrammableConfig>,
 }
+
+/// Metadata accounts are allocated at a fixed size and zero padded past the
+/// last field, so the trailing bytes are ignored instead of rejected.
+fn deserialize_metadata(data: &[u8]) -> Option<Metadata> {
+    let mut data = data;
+    <Metadata as borsh::BorshDeserialize>::deserialize(&mut data)
+        .ok()
+        .filter(|metadata| metadata.key == Key::MetadataV1)
+}
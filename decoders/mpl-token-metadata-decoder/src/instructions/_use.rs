@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x33")]
 pub struct Use {
     pub use_args: UseArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Use")]
 pub struct UseInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub delegate_record: solana_pubkey::Pubkey,
@@ -24,33 +26,5 @@ pub struct UseInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Use {
-    type ArrangedAccounts = UseInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, delegate_record, token, mint, metadata, edition, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(UseInstructionAccounts {
-            authority: authority.pubkey,
-            delegate_record: delegate_record.pubkey,
-            token: token.pubkey,
-            mint: mint.pubkey,
-            metadata: metadata.pubkey,
-            edition: edition.pubkey,
-            payer: payer.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-            authorization_rules_program: authorization_rules_program.pubkey,
-            authorization_rules: authorization_rules.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x17")]
 pub struct ApproveCollectionAuthority {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApproveCollectionAuthority")]
 pub struct ApproveCollectionAuthorityInstructionAccounts {
     pub collection_authority_record: solana_pubkey::Pubkey,
     pub new_collection_authority: solana_pubkey::Pubkey,
@@ -15,29 +17,5 @@ pub struct ApproveCollectionAuthorityInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ApproveCollectionAuthority {
-    type ArrangedAccounts = ApproveCollectionAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [collection_authority_record, new_collection_authority, update_authority, payer, metadata, mint, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ApproveCollectionAuthorityInstructionAccounts {
-            collection_authority_record: collection_authority_record.pubkey,
-            new_collection_authority: new_collection_authority.pubkey,
-            update_authority: update_authority.pubkey,
-            payer: payer.pubkey,
-            metadata: metadata.pubkey,
-            mint: mint.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
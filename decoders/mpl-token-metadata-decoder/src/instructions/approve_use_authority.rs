@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x14")]
 pub struct ApproveUseAuthority {
     pub approve_use_authority_args: ApproveUseAuthorityArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApproveUseAuthority")]
 pub struct ApproveUseAuthorityInstructionAccounts {
     pub use_authority_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -23,32 +25,5 @@ pub struct ApproveUseAuthorityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ApproveUseAuthority {
-    type ArrangedAccounts = ApproveUseAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [use_authority_record, owner, payer, user, owner_token_account, metadata, mint, burner, token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ApproveUseAuthorityInstructionAccounts {
-            use_authority_record: use_authority_record.pubkey,
-            owner: owner.pubkey,
-            payer: payer.pubkey,
-            user: user.pubkey,
-            owner_token_account: owner_token_account.pubkey,
-            metadata: metadata.pubkey,
-            mint: mint.pubkey,
-            burner: burner.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
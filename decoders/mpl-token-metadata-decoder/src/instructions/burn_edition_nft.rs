@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x25")]
 pub struct BurnEditionNft {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "BurnEditionNft")]
 pub struct BurnEditionNftInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -17,31 +19,5 @@ pub struct BurnEditionNftInstructionAccounts {
     pub print_edition_account: solana_pubkey::Pubkey,
     pub edition_marker_account: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for BurnEditionNft {
-    type ArrangedAccounts = BurnEditionNftInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, owner, print_edition_mint, master_edition_mint, print_edition_token_account, master_edition_token_account, master_edition_account, print_edition_account, edition_marker_account, spl_token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(BurnEditionNftInstructionAccounts {
-            metadata: metadata.pubkey,
-            owner: owner.pubkey,
-            print_edition_mint: print_edition_mint.pubkey,
-            master_edition_mint: master_edition_mint.pubkey,
-            print_edition_token_account: print_edition_token_account.pubkey,
-            master_edition_token_account: master_edition_token_account.pubkey,
-            master_edition_account: master_edition_account.pubkey,
-            print_edition_account: print_edition_account.pubkey,
-            edition_marker_account: edition_marker_account.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
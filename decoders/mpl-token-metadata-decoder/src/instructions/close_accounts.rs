@@ -1,35 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x39")]
 pub struct CloseAccounts {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseAccounts")]
 pub struct CloseAccountsInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub edition: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseAccounts {
-    type ArrangedAccounts = CloseAccountsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, mint, authority, destination, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CloseAccountsInstructionAccounts {
-            metadata: metadata.pubkey,
-            edition: edition.pubkey,
-            mint: mint.pubkey,
-            authority: authority.pubkey,
-            destination: destination.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x27")]
 pub struct CloseEscrowAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseEscrowAccount")]
 pub struct CloseEscrowAccountInstructionAccounts {
     pub escrow: solana_pubkey::Pubkey,
     pub metadata: solana_pubkey::Pubkey,
@@ -15,29 +17,5 @@ pub struct CloseEscrowAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseEscrowAccount {
-    type ArrangedAccounts = CloseEscrowAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [escrow, metadata, mint, token_account, edition, payer, system_program, sysvar_instructions, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CloseEscrowAccountInstructionAccounts {
-            escrow: escrow.pubkey,
-            metadata: metadata.pubkey,
-            mint: mint.pubkey,
-            token_account: token_account.pubkey,
-            edition: edition.pubkey,
-            payer: payer.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x36")]
 pub struct Collect {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Collect")]
 pub struct CollectInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub recipient: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Collect {
-    type ArrangedAccounts = CollectInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, recipient, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CollectInstructionAccounts {
-            authority: authority.pubkey,
-            recipient: recipient.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2a")]
 pub struct Create {
     pub create_args: CreateArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Create")]
 pub struct CreateInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub master_edition: solana_pubkey::Pubkey,
@@ -21,30 +23,5 @@ pub struct CreateInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Create {
-    type ArrangedAccounts = CreateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, master_edition, mint, authority, payer, update_authority, system_program, sysvar_instructions, spl_token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateInstructionAccounts {
-            metadata: metadata.pubkey,
-            master_edition: master_edition.pubkey,
-            mint: mint.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            update_authority: update_authority.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x26")]
 pub struct CreateEscrowAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateEscrowAccount")]
 pub struct CreateEscrowAccountInstructionAccounts {
     pub escrow: solana_pubkey::Pubkey,
     pub metadata: solana_pubkey::Pubkey,
@@ -16,30 +18,5 @@ pub struct CreateEscrowAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateEscrowAccount {
-    type ArrangedAccounts = CreateEscrowAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [escrow, metadata, mint, token_account, edition, payer, system_program, sysvar_instructions, authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateEscrowAccountInstructionAccounts {
-            escrow: escrow.pubkey,
-            metadata: metadata.pubkey,
-            mint: mint.pubkey,
-            token_account: token_account.pubkey,
-            edition: edition.pubkey,
-            payer: payer.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
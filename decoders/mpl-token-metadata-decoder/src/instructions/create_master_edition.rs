@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0a")]
 pub struct CreateMasterEdition {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateMasterEdition")]
 pub struct CreateMasterEditionInstructionAccounts {
     pub edition: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -16,30 +18,5 @@ pub struct CreateMasterEditionInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateMasterEdition {
-    type ArrangedAccounts = CreateMasterEditionInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [edition, mint, update_authority, mint_authority, payer, metadata, token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateMasterEditionInstructionAccounts {
-            edition: edition.pubkey,
-            mint: mint.pubkey,
-            update_authority: update_authority.pubkey,
-            mint_authority: mint_authority.pubkey,
-            payer: payer.pubkey,
-            metadata: metadata.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x21")]
 pub struct CreateMetadataAccountV3 {
     pub create_metadata_account_args_v3: CreateMetadataAccountArgsV3,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateMetadataAccountV3")]
 pub struct CreateMetadataAccountV3InstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -19,28 +21,5 @@ pub struct CreateMetadataAccountV3InstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateMetadataAccountV3 {
-    type ArrangedAccounts = CreateMetadataAccountV3InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, mint, mint_authority, payer, update_authority, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateMetadataAccountV3InstructionAccounts {
-            metadata: metadata.pubkey,
-            mint: mint.pubkey,
-            mint_authority: mint_authority.pubkey,
-            payer: payer.pubkey,
-            update_authority: update_authority.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2c")]
 pub struct Delegate {
     pub delegate_args: DelegateArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Delegate")]
 pub struct DelegateInstructionAccounts {
     pub delegate_record: solana_pubkey::Pubkey,
     pub delegate: solana_pubkey::Pubkey,
@@ -26,35 +28,5 @@ pub struct DelegateInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Delegate {
-    type ArrangedAccounts = DelegateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DelegateInstructionAccounts {
-            delegate_record: delegate_record.pubkey,
-            delegate: delegate.pubkey,
-            metadata: metadata.pubkey,
-            master_edition: master_edition.pubkey,
-            token_record: token_record.pubkey,
-            mint: mint.pubkey,
-            token: token.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-            authorization_rules_program: authorization_rules_program.pubkey,
-            authorization_rules: authorization_rules.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
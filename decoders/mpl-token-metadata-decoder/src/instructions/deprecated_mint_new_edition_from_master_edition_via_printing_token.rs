@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x03")]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingToken {}
@@ -23,6 +23,7 @@ pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenInstructionA
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub reservation_list: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts
@@ -34,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, master_edition, mint, mint_authority, printing_mint, master_token_account, edition_marker, burn_authority, payer, master_update_authority, master_metadata, token_program, system_program, rent, reservation_list, _remaining @ ..] =
+        let [metadata, edition, master_edition, mint, mint_authority, printing_mint, master_token_account, edition_marker, burn_authority, payer, master_update_authority, master_metadata, token_program, system_program, rent, reservation_list, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +59,7 @@ impl carbon_core::deserialize::ArrangeAccounts
                 system_program: system_program.pubkey,
                 rent: rent.pubkey,
                 reservation_list: reservation_list.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x05")]
 pub struct DeprecatedSetReservationList {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeprecatedSetReservationList")]
 pub struct DeprecatedSetReservationListInstructionAccounts {
     pub master_edition: solana_pubkey::Pubkey,
     pub reservation_list: solana_pubkey::Pubkey,
     pub resource: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeprecatedSetReservationList {
-    type ArrangedAccounts = DeprecatedSetReservationListInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [master_edition, reservation_list, resource, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(DeprecatedSetReservationListInstructionAccounts {
-            master_edition: master_edition.pubkey,
-            reservation_list: reservation_list.pubkey,
-            resource: resource.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,36 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1a")]
 pub struct FreezeDelegatedAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "FreezeDelegatedAccount")]
 pub struct FreezeDelegatedAccountInstructionAccounts {
     pub delegate: solana_pubkey::Pubkey,
     pub token_account: solana_pubkey::Pubkey,
     pub edition: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for FreezeDelegatedAccount {
-    type ArrangedAccounts = FreezeDelegatedAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [delegate, token_account, edition, mint, token_program, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(FreezeDelegatedAccountInstructionAccounts {
-            delegate: delegate.pubkey,
-            token_account: token_account.pubkey,
-            edition: edition.pubkey,
-            mint: mint.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
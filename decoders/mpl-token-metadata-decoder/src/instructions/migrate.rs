@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x30")]
 pub struct Migrate {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Migrate")]
 pub struct MigrateInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub edition: solana_pubkey::Pubkey,
@@ -22,36 +24,5 @@ pub struct MigrateInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Migrate {
-    type ArrangedAccounts = MigrateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, token, token_owner, mint, payer, authority, collection_metadata, delegate_record, token_record, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MigrateInstructionAccounts {
-            metadata: metadata.pubkey,
-            edition: edition.pubkey,
-            token: token.pubkey,
-            token_owner: token_owner.pubkey,
-            mint: mint.pubkey,
-            payer: payer.pubkey,
-            authority: authority.pubkey,
-            collection_metadata: collection_metadata.pubkey,
-            delegate_record: delegate_record.pubkey,
-            token_record: token_record.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-            authorization_rules_program: authorization_rules_program.pubkey,
-            authorization_rules: authorization_rules.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0b")]
 pub struct MintNewEditionFromMasterEditionViaToken {
@@ -12,6 +12,8 @@ pub struct MintNewEditionFromMasterEditionViaToken {
         MintNewEditionFromMasterEditionViaTokenArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MintNewEditionFromMasterEditionViaToken")]
 pub struct MintNewEditionFromMasterEditionViaTokenInstructionAccounts {
     pub new_metadata: solana_pubkey::Pubkey,
     pub new_edition: solana_pubkey::Pubkey,
@@ -27,35 +29,5 @@ pub struct MintNewEditionFromMasterEditionViaTokenInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditionViaToken {
-    type ArrangedAccounts = MintNewEditionFromMasterEditionViaTokenInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, token_account_owner, token_account, new_metadata_update_authority, metadata, token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MintNewEditionFromMasterEditionViaTokenInstructionAccounts {
-            new_metadata: new_metadata.pubkey,
-            new_edition: new_edition.pubkey,
-            master_edition: master_edition.pubkey,
-            new_mint: new_mint.pubkey,
-            edition_mark_pda: edition_mark_pda.pubkey,
-            new_mint_authority: new_mint_authority.pubkey,
-            payer: payer.pubkey,
-            token_account_owner: token_account_owner.pubkey,
-            token_account: token_account.pubkey,
-            new_metadata_update_authority: new_metadata_update_authority.pubkey,
-            metadata: metadata.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -134,6 +134,10 @@ pub enum TokenMetadataInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for TokenMetadataDecoder {
     type InstructionType = TokenMetadataInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x37")]
 pub struct Print {
     pub print_args: PrintArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Print")]
 pub struct PrintInstructionAccounts {
     pub edition_metadata: solana_pubkey::Pubkey,
     pub edition: solana_pubkey::Pubkey,
@@ -30,39 +32,5 @@ pub struct PrintInstructionAccounts {
     pub spl_ata_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Print {
-    type ArrangedAccounts = PrintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [edition_metadata, edition, edition_mint, edition_token_account_owner, edition_token_account, edition_mint_authority, edition_token_record, master_edition, edition_marker_pda, payer, master_token_account_owner, master_token_account, master_metadata, update_authority, spl_token_program, spl_ata_program, sysvar_instructions, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(PrintInstructionAccounts {
-            edition_metadata: edition_metadata.pubkey,
-            edition: edition.pubkey,
-            edition_mint: edition_mint.pubkey,
-            edition_token_account_owner: edition_token_account_owner.pubkey,
-            edition_token_account: edition_token_account.pubkey,
-            edition_mint_authority: edition_mint_authority.pubkey,
-            edition_token_record: edition_token_record.pubkey,
-            master_edition: master_edition.pubkey,
-            edition_marker_pda: edition_marker_pda.pubkey,
-            payer: payer.pubkey,
-            master_token_account_owner: master_token_account_owner.pubkey,
-            master_token_account: master_token_account.pubkey,
-            master_metadata: master_metadata.pubkey,
-            update_authority: update_authority.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-            spl_ata_program: spl_ata_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
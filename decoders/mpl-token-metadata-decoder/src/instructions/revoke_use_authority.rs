@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x15")]
 pub struct RevokeUseAuthority {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RevokeUseAuthority")]
 pub struct RevokeUseAuthorityInstructionAccounts {
     pub use_authority_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -16,30 +18,5 @@ pub struct RevokeUseAuthorityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RevokeUseAuthority {
-    type ArrangedAccounts = RevokeUseAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [use_authority_record, owner, user, owner_token_account, mint, metadata, token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RevokeUseAuthorityInstructionAccounts {
-            use_authority_record: use_authority_record.pubkey,
-            owner: owner.pubkey,
-            user: user.pubkey,
-            owner_token_account: owner_token_account.pubkey,
-            mint: mint.pubkey,
-            metadata: metadata.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
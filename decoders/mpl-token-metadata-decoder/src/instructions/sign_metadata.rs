@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x07")]
 pub struct SignMetadata {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SignMetadata")]
 pub struct SignMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub creator: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SignMetadata {
-    type ArrangedAccounts = SignMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, creator, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SignMetadataInstructionAccounts {
-            metadata: metadata.pubkey,
-            creator: creator.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
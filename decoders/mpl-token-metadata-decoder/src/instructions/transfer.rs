@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x31")]
 pub struct Transfer {
     pub transfer_args: TransferArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Transfer")]
 pub struct TransferInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub token_owner: solana_pubkey::Pubkey,
@@ -29,38 +31,5 @@ pub struct TransferInstructionAccounts {
     pub spl_ata_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Transfer {
-    type ArrangedAccounts = TransferInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token, token_owner, destination, destination_owner, mint, metadata, edition, owner_token_record, destination_token_record, authority, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(TransferInstructionAccounts {
-            token: token.pubkey,
-            token_owner: token_owner.pubkey,
-            destination: destination.pubkey,
-            destination_owner: destination_owner.pubkey,
-            mint: mint.pubkey,
-            metadata: metadata.pubkey,
-            edition: edition.pubkey,
-            owner_token_record: owner_token_record.pubkey,
-            destination_token_record: destination_token_record.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            system_program: system_program.pubkey,
-            sysvar_instructions: sysvar_instructions.pubkey,
-            spl_token_program: spl_token_program.pubkey,
-            spl_ata_program: spl_ata_program.pubkey,
-            authorization_rules_program: authorization_rules_program.pubkey,
-            authorization_rules: authorization_rules.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AssetData {
     pub name: String,
@@ -18,5 +18,6 @@ pub struct AssetData {
     pub collection: Option<Collection>,
     pub uses: Option<Uses>,
     pub collection_details: Option<CollectionDetails>,
+    #[carbon(serialize_as = "base58")]
     pub rule_set: Option<solana_pubkey::Pubkey>,
 }
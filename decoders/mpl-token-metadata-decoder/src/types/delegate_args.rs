@@ -1,10 +1,10 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub enum DelegateArgs {
     CollectionV1 {
@@ -34,6 +34,7 @@ pub enum DelegateArgs {
     },
     LockedTransferV1 {
         amount: u64,
+        #[carbon(serialize_as = "base58")]
         locked_address: solana_pubkey::Pubkey,
         authorization_data: Option<AuthorizationData>,
     },
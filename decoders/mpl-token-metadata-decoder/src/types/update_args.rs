@@ -1,13 +1,14 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub enum UpdateArgs {
     V1 {
+        #[carbon(serialize_as = "base58")]
         new_update_authority: Option<solana_pubkey::Pubkey>,
         data: Option<Data>,
         primary_sale_happened: Option<bool>,
@@ -19,6 +20,7 @@ pub enum UpdateArgs {
         authorization_data: Option<AuthorizationData>,
     },
     AsUpdateAuthorityV2 {
+        #[carbon(serialize_as = "base58")]
         new_update_authority: Option<solana_pubkey::Pubkey>,
         data: Option<Data>,
         primary_sale_happened: Option<bool>,
@@ -31,6 +33,7 @@ pub enum UpdateArgs {
         authorization_data: Option<AuthorizationData>,
     },
     AsAuthorityItemDelegateV2 {
+        #[carbon(serialize_as = "base58")]
         new_update_authority: Option<solana_pubkey::Pubkey>,
         primary_sale_happened: Option<bool>,
         is_mutable: Option<bool>,
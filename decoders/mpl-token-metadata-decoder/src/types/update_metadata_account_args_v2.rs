@@ -1,13 +1,14 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct UpdateMetadataAccountArgsV2 {
     pub data: Option<DataV2>,
+    #[carbon(serialize_as = "base58")]
     pub update_authority: Option<solana_pubkey::Pubkey>,
     pub primary_sale_happened: Option<bool>,
     pub is_mutable: Option<bool>,
@@ -34,3 +34,38 @@ impl AccountDecoder<'_> for NameDecoder {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_name_record_account() {
+        // Arrange
+        let mut expected_data = b"bonfida".to_vec();
+        expected_data.resize(32, 0);
+        let expected_account = name_record_header::NameRecordHeader {
+            parent_name: solana_pubkey::Pubkey::from_str_const(
+                "58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx",
+            ),
+            owner: solana_pubkey::Pubkey::from_str_const(
+                "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA",
+            ),
+            class: solana_pubkey::Pubkey::default(),
+            data: expected_data,
+        };
+
+        // Act
+        let decoder = NameDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/name_record_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            NameAccount::NameRecordHeader(name_record) => {
+                assert_eq!(name_record, expected_account);
+            }
+        }
+    }
+}
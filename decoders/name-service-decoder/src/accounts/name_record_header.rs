@@ -1,9 +1,44 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::borsh::{self, BorshDeserialize};
 
-#[derive(CarbonDeserialize, Debug)]
-#[carbon(discriminator = "0x319893826f292f59")]
+/// Size of the header that prefixes every name record, the record data
+/// follows it.
+pub const NAME_RECORD_HEADER_LEN: usize = 96;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
 pub struct NameRecordHeader {
     pub parent_name: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub class: solana_pubkey::Pubkey,
+    pub data: Vec<u8>,
+}
+
+// Name records have no discriminator, the header is followed by the record
+// data, which spans the rest of the account.
+impl BorshDeserialize for NameRecordHeader {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let parent_name = solana_pubkey::Pubkey::deserialize_reader(reader)?;
+        let owner = solana_pubkey::Pubkey::deserialize_reader(reader)?;
+        let class = solana_pubkey::Pubkey::deserialize_reader(reader)?;
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Ok(Self {
+            parent_name,
+            owner,
+            class,
+            data,
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for NameRecordHeader {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        if data.len() < NAME_RECORD_HEADER_LEN {
+            return None;
+        }
+
+        borsh::BorshDeserialize::try_from_slice(data).ok()
+    }
 }
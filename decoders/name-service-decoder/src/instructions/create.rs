@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x00")]
 pub struct Create {
@@ -10,6 +10,8 @@ pub struct Create {
     pub space: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Create")]
 pub struct CreateInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub funding_account: solana_pubkey::Pubkey,
@@ -17,27 +19,5 @@ pub struct CreateInstructionAccounts {
     pub account_class: solana_pubkey::Pubkey,
     pub parent_name_record: solana_pubkey::Pubkey,
     pub parent_name_record_class: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Create {
-    type ArrangedAccounts = CreateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [system_program, funding_account, name_record, account_class, parent_name_record, parent_name_record_class, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateInstructionAccounts {
-            system_program: system_program.pubkey,
-            funding_account: funding_account.pubkey,
-            name_record: name_record.pubkey,
-            account_class: account_class.pubkey,
-            parent_name_record: parent_name_record.pubkey,
-            parent_name_record_class: parent_name_record_class.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,9 @@
 use solana_pubkey::Pubkey;
 
 pub struct NameDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
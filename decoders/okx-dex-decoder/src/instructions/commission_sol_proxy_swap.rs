@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1e21d05b1f9d2512")]
 pub struct CommissionSolProxySwap {
@@ -13,6 +13,8 @@ pub struct CommissionSolProxySwap {
     pub order_id: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CommissionSolProxySwap")]
 pub struct CommissionSolProxySwapInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub source_token_account: solana_pubkey::Pubkey,
@@ -27,34 +29,5 @@ pub struct CommissionSolProxySwapInstructionAccounts {
     pub destination_token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CommissionSolProxySwap {
-    type ArrangedAccounts = CommissionSolProxySwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_account, sa_authority, source_token_sa, destination_token_sa, source_token_program, destination_token_program, associated_token_program, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CommissionSolProxySwapInstructionAccounts {
-            payer: payer.pubkey,
-            source_token_account: source_token_account.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            source_mint: source_mint.pubkey,
-            destination_mint: destination_mint.pubkey,
-            commission_account: commission_account.pubkey,
-            sa_authority: sa_authority.pubkey,
-            source_token_sa: source_token_sa.pubkey,
-            destination_token_sa: destination_token_sa.pubkey,
-            source_token_program: source_token_program.pubkey,
-            destination_token_program: destination_token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
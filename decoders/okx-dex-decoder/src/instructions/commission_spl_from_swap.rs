@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x054d9032dee4e9ab")]
 pub struct CommissionSplFromSwap {
@@ -14,6 +14,8 @@ pub struct CommissionSplFromSwap {
     pub len: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CommissionSplFromSwap")]
 pub struct CommissionSplFromSwapInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub source_token_account: solana_pubkey::Pubkey,
@@ -26,32 +28,5 @@ pub struct CommissionSplFromSwapInstructionAccounts {
     pub token_2022_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub commission_token_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CommissionSplFromSwap {
-    type ArrangedAccounts = CommissionSplFromSwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, bridge_program, associated_token_program, token_program, token_2022_program, system_program, commission_token_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CommissionSplFromSwapInstructionAccounts {
-            payer: payer.pubkey,
-            source_token_account: source_token_account.pubkey,
-            destination_token_account: destination_token_account.pubkey,
-            source_mint: source_mint.pubkey,
-            destination_mint: destination_mint.pubkey,
-            bridge_program: bridge_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            token_program: token_program.pubkey,
-            token_2022_program: token_2022_program.pubkey,
-            system_program: system_program.pubkey,
-            commission_token_account: commission_token_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
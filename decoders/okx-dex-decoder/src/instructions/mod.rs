@@ -13,6 +13,7 @@ pub mod from_swap_log;
 pub mod proxy_swap;
 pub mod swap;
 pub mod swap2;
+#[cfg(feature = "events")]
 pub mod swap_event;
 
 #[derive(
@@ -38,12 +39,17 @@ pub enum OkxDexInstruction {
     ProxySwap(proxy_swap::ProxySwap),
     Swap(swap::Swap),
     Swap2(swap2::Swap2),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for OkxDexDecoder {
     type InstructionType = OkxDexInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -65,6 +71,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OkxDexDecoder {
             OkxDexInstruction::ProxySwap => proxy_swap::ProxySwap,
             OkxDexInstruction::Swap => swap::Swap,
             OkxDexInstruction::Swap2 => swap2::Swap2,
+            #[cfg(feature = "events")]
             OkxDexInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
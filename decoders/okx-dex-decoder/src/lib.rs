@@ -1,6 +1,8 @@
 use solana_pubkey::Pubkey;
 pub struct OkxDexDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x809bde3cba28e132")]
 pub struct CancelAllAndPlaceOrders {
@@ -14,6 +14,8 @@ pub struct CancelAllAndPlaceOrders {
     pub limit: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CancelAllAndPlaceOrders")]
 pub struct CancelAllAndPlaceOrdersInstructionAccounts {
     pub signer: solana_pubkey::Pubkey,
     pub open_orders_account: solana_pubkey::Pubkey,
@@ -29,35 +31,5 @@ pub struct CancelAllAndPlaceOrdersInstructionAccounts {
     pub oracle_a: solana_pubkey::Pubkey,
     pub oracle_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CancelAllAndPlaceOrders {
-    type ArrangedAccounts = CancelAllAndPlaceOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [signer, open_orders_account, open_orders_admin, user_quote_account, user_base_account, market, bids, asks, event_heap, market_quote_vault, market_base_vault, oracle_a, oracle_b, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CancelAllAndPlaceOrdersInstructionAccounts {
-            signer: signer.pubkey,
-            open_orders_account: open_orders_account.pubkey,
-            open_orders_admin: open_orders_admin.pubkey,
-            user_quote_account: user_quote_account.pubkey,
-            user_base_account: user_base_account.pubkey,
-            market: market.pubkey,
-            bids: bids.pubkey,
-            asks: asks.pubkey,
-            event_heap: event_heap.pubkey,
-            market_quote_vault: market_quote_vault.pubkey,
-            market_base_vault: market_base_vault.pubkey,
-            oracle_a: oracle_a.pubkey,
-            oracle_b: oracle_b.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,35 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x67f9e5e7f7fdc588")]
 pub struct CloseOpenOrdersIndexer {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseOpenOrdersIndexer")]
 pub struct CloseOpenOrdersIndexerInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub open_orders_indexer: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersIndexer {
-    type ArrangedAccounts = CloseOpenOrdersIndexerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, open_orders_indexer, sol_destination, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CloseOpenOrdersIndexerInstructionAccounts {
-            owner: owner.pubkey,
-            open_orders_indexer: open_orders_indexer.pubkey,
-            sol_destination: sol_destination.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0x67e261ebc8bcfbfe")]
 pub struct CreateMarket {
     pub name: String,
@@ -15,6 +15,8 @@ pub struct CreateMarket {
     pub time_expiry: i64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateMarket")]
 pub struct CreateMarketInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub market_authority: solana_pubkey::Pubkey,
@@ -37,42 +39,5 @@ pub struct CreateMarketInstructionAccounts {
     pub close_market_admin: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateMarket {
-    type ArrangedAccounts = CreateMarketInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [market, market_authority, bids, asks, event_heap, payer, market_base_vault, market_quote_vault, base_mint, quote_mint, system_program, token_program, associated_token_program, oracle_a, oracle_b, collect_fee_admin, open_orders_admin, consume_events_admin, close_market_admin, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateMarketInstructionAccounts {
-            market: market.pubkey,
-            market_authority: market_authority.pubkey,
-            bids: bids.pubkey,
-            asks: asks.pubkey,
-            event_heap: event_heap.pubkey,
-            payer: payer.pubkey,
-            market_base_vault: market_base_vault.pubkey,
-            market_quote_vault: market_quote_vault.pubkey,
-            base_mint: base_mint.pubkey,
-            quote_mint: quote_mint.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            oracle_a: oracle_a.pubkey,
-            oracle_b: oracle_b.pubkey,
-            collect_fee_admin: collect_fee_admin.pubkey,
-            open_orders_admin: open_orders_admin.pubkey,
-            consume_events_admin: consume_events_admin.pubkey,
-            close_market_admin: close_market_admin.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
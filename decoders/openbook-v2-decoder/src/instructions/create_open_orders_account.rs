@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xccb5afde287dbc47")]
 pub struct CreateOpenOrdersAccount {
     pub name: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateOpenOrdersAccount")]
 pub struct CreateOpenOrdersAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -16,28 +18,5 @@ pub struct CreateOpenOrdersAccountInstructionAccounts {
     pub open_orders_account: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersAccount {
-    type ArrangedAccounts = CreateOpenOrdersAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, delegate_account, open_orders_indexer, open_orders_account, market, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreateOpenOrdersAccountInstructionAccounts {
-            payer: payer.pubkey,
-            owner: owner.pubkey,
-            delegate_account: delegate_account.pubkey,
-            open_orders_indexer: open_orders_indexer.pubkey,
-            open_orders_account: open_orders_account.pubkey,
-            market: market.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
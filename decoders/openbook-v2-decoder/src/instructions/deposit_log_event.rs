@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d8dbaa8fc6c8d485e")]
 pub struct DepositLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub open_orders_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub signer: solana_pubkey::Pubkey,
     pub base_amount: u64,
     pub quote_amount: u64,
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfed0761dadf8c846")]
 pub struct EditOrder {
@@ -13,6 +13,8 @@ pub struct EditOrder {
     pub place_order: PlaceOrderArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EditOrder")]
 pub struct EditOrderInstructionAccounts {
     pub signer: solana_pubkey::Pubkey,
     pub open_orders_account: solana_pubkey::Pubkey,
@@ -26,33 +28,5 @@ pub struct EditOrderInstructionAccounts {
     pub oracle_a: solana_pubkey::Pubkey,
     pub oracle_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EditOrder {
-    type ArrangedAccounts = EditOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [signer, open_orders_account, open_orders_admin, user_token_account, market, bids, asks, event_heap, market_vault, oracle_a, oracle_b, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(EditOrderInstructionAccounts {
-            signer: signer.pubkey,
-            open_orders_account: open_orders_account.pubkey,
-            open_orders_admin: open_orders_admin.pubkey,
-            user_token_account: user_token_account.pubkey,
-            market: market.pubkey,
-            bids: bids.pubkey,
-            asks: asks.pubkey,
-            event_heap: event_heap.pubkey,
-            market_vault: market_vault.pubkey,
-            oracle_a: oracle_a.pubkey,
-            oracle_b: oracle_b.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,20 +1,23 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d9617299498a2d740")]
 pub struct FillLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
     pub taker_side: u8,
     pub maker_slot: u8,
     pub maker_out: bool,
     pub timestamp: u64,
     pub seq_num: u64,
+    #[carbon(serialize_as = "base58")]
     pub maker: solana_pubkey::Pubkey,
     pub maker_client_order_id: u64,
     pub maker_fee: u64,
     pub maker_timestamp: u64,
+    #[carbon(serialize_as = "base58")]
     pub taker: solana_pubkey::Pubkey,
     pub taker_client_order_id: u64,
     pub taker_fee_ceil: u64,
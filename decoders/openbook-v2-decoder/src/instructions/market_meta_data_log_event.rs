@@ -1,13 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dd157d4eca43a3c75")]
 pub struct MarketMetaDataLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
     pub name: String,
+    #[carbon(serialize_as = "base58")]
     pub base_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub quote_mint: solana_pubkey::Pubkey,
     pub base_decimals: u8,
     pub quote_decimals: u8,
@@ -14,11 +14,15 @@ pub mod create_market;
 pub mod create_open_orders_account;
 pub mod create_open_orders_indexer;
 pub mod deposit;
+#[cfg(feature = "events")]
 pub mod deposit_log_event;
 pub mod edit_order;
 pub mod edit_order_pegged;
+#[cfg(feature = "events")]
 pub mod fill_log_event;
+#[cfg(feature = "events")]
 pub mod market_meta_data_log_event;
+#[cfg(feature = "events")]
 pub mod open_orders_position_log_event;
 pub mod place_order;
 pub mod place_order_pegged;
@@ -27,16 +31,20 @@ pub mod place_take_order;
 pub mod prune_orders;
 pub mod refill;
 pub mod set_delegate;
+#[cfg(feature = "events")]
 pub mod set_delegate_log_event;
 pub mod set_market_expired;
 pub mod settle_funds;
 pub mod settle_funds_expired;
+#[cfg(feature = "events")]
 pub mod settle_funds_log_event;
 pub mod stub_oracle_close;
 pub mod stub_oracle_create;
 pub mod stub_oracle_set;
 pub mod sweep_fees;
+#[cfg(feature = "events")]
 pub mod sweep_fees_log_event;
+#[cfg(feature = "events")]
 pub mod total_order_fill_event;
 
 #[derive(
@@ -72,19 +80,31 @@ pub enum OpenbookV2Instruction {
     StubOracleCreate(stub_oracle_create::StubOracleCreate),
     StubOracleClose(stub_oracle_close::StubOracleClose),
     StubOracleSet(stub_oracle_set::StubOracleSet),
+    #[cfg(feature = "events")]
     DepositLogEvent(deposit_log_event::DepositLogEvent),
+    #[cfg(feature = "events")]
     FillLogEvent(fill_log_event::FillLogEvent),
+    #[cfg(feature = "events")]
     MarketMetaDataLogEvent(market_meta_data_log_event::MarketMetaDataLogEvent),
+    #[cfg(feature = "events")]
     TotalOrderFillEvent(total_order_fill_event::TotalOrderFillEvent),
+    #[cfg(feature = "events")]
     SetDelegateLogEvent(set_delegate_log_event::SetDelegateLogEvent),
+    #[cfg(feature = "events")]
     SettleFundsLogEvent(settle_funds_log_event::SettleFundsLogEvent),
+    #[cfg(feature = "events")]
     SweepFeesLogEvent(sweep_fees_log_event::SweepFeesLogEvent),
+    #[cfg(feature = "events")]
     OpenOrdersPositionLogEvent(open_orders_position_log_event::OpenOrdersPositionLogEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for OpenbookV2Decoder {
     type InstructionType = OpenbookV2Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -123,13 +143,21 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OpenbookV2Decoder {
             OpenbookV2Instruction::StubOracleCreate => stub_oracle_create::StubOracleCreate,
             OpenbookV2Instruction::StubOracleClose => stub_oracle_close::StubOracleClose,
             OpenbookV2Instruction::StubOracleSet => stub_oracle_set::StubOracleSet,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::DepositLogEvent => deposit_log_event::DepositLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::FillLogEvent => fill_log_event::FillLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::MarketMetaDataLogEvent => market_meta_data_log_event::MarketMetaDataLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::TotalOrderFillEvent => total_order_fill_event::TotalOrderFillEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::SetDelegateLogEvent => set_delegate_log_event::SetDelegateLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::SettleFundsLogEvent => settle_funds_log_event::SettleFundsLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::SweepFeesLogEvent => sweep_fees_log_event::SweepFeesLogEvent,
+            #[cfg(feature = "events")]
             OpenbookV2Instruction::OpenOrdersPositionLogEvent => open_orders_position_log_event::OpenOrdersPositionLogEvent,
         )
     }
@@ -1,12 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dc4f99421a8e44906")]
 pub struct OpenOrdersPositionLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
     pub open_orders_account_num: u32,
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
     pub bids_base_lots: i64,
     pub bids_quote_lots: i64,
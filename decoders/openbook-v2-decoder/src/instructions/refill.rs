@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x80cf8e0b36e826c9")]
 pub struct Refill {
@@ -9,6 +9,8 @@ pub struct Refill {
     pub quote_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Refill")]
 pub struct RefillInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub user_base_account: solana_pubkey::Pubkey,
@@ -18,29 +20,5 @@ pub struct RefillInstructionAccounts {
     pub market_base_vault: solana_pubkey::Pubkey,
     pub market_quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Refill {
-    type ArrangedAccounts = RefillInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_base_account, user_quote_account, open_orders_account, market, market_base_vault, market_quote_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RefillInstructionAccounts {
-            owner: owner.pubkey,
-            user_base_account: user_base_account.pubkey,
-            user_quote_account: user_quote_account.pubkey,
-            open_orders_account: open_orders_account.pubkey,
-            market: market.pubkey,
-            market_base_vault: market_base_vault.pubkey,
-            market_quote_vault: market_quote_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
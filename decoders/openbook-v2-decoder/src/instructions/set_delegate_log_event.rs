@@ -1,10 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d3582975c6d399170")]
 pub struct SetDelegateLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub open_orders_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub delegate: Option<solana_pubkey::Pubkey>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d0a32f075ed43e6e9")]
 pub struct SettleFundsLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub open_orders_account: solana_pubkey::Pubkey,
     pub base_native: u64,
     pub quote_native: u64,
     pub referrer_rebate: u64,
+    #[carbon(serialize_as = "base58")]
     pub referrer: Option<solana_pubkey::Pubkey>,
 }
@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5c892d032d3c75e0")]
 pub struct StubOracleClose {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "StubOracleClose")]
 pub struct StubOracleCloseInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for StubOracleClose {
-    type ArrangedAccounts = StubOracleCloseInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, oracle, sol_destination, token_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(StubOracleCloseInstructionAccounts {
-            owner: owner.pubkey,
-            oracle: oracle.pubkey,
-            sol_destination: sol_destination.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,35 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0xac3f65538d4cc7d8")]
 pub struct StubOracleCreate {
     pub price: f64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "StubOracleCreate")]
 pub struct StubOracleCreateInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for StubOracleCreate {
-    type ArrangedAccounts = StubOracleCreateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, oracle, mint, system_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(StubOracleCreateInstructionAccounts {
-            payer: payer.pubkey,
-            owner: owner.pubkey,
-            oracle: oracle.pubkey,
-            mint: mint.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
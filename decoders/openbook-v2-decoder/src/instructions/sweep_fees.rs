@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xafe1624776422294")]
 pub struct SweepFees {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SweepFees")]
 pub struct SweepFeesInstructionAccounts {
     pub collect_fee_admin: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct SweepFeesInstructionAccounts {
     pub market_quote_vault: solana_pubkey::Pubkey,
     pub token_receiver_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SweepFees {
-    type ArrangedAccounts = SweepFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [collect_fee_admin, market, market_authority, market_quote_vault, token_receiver_account, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SweepFeesInstructionAccounts {
-            collect_fee_admin: collect_fee_admin.pubkey,
-            market: market.pubkey,
-            market_authority: market_authority.pubkey,
-            market_quote_vault: market_quote_vault.pubkey,
-            token_receiver_account: token_receiver_account.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dd2f21a4d5e30ff3d")]
 pub struct SweepFeesLogEvent {
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
     pub amount: u64,
+    #[carbon(serialize_as = "base58")]
     pub receiver: solana_pubkey::Pubkey,
 }
@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d08eb303aae4c9c69")]
 pub struct TotalOrderFillEvent {
     pub side: u8,
+    #[carbon(serialize_as = "base58")]
     pub taker: solana_pubkey::Pubkey,
     pub total_quantity_paid: u64,
     pub total_quantity_received: u64,
@@ -1,13 +1,14 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AnyEvent {
     pub event_type: u8,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 143],
 }
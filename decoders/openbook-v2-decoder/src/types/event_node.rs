@@ -1,14 +1,15 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct EventNode {
     pub next: u16,
     pub prev: u16,
+    #[carbon(serialize_as = "hex")]
     pub pad: [u8; 4],
     pub event: AnyEvent,
 }
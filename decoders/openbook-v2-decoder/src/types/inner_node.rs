@@ -1,18 +1,20 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct InnerNode {
     pub tag: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 3],
     pub prefix_len: u32,
     pub key: u128,
     pub children: [u32; 2],
     pub child_earliest_expiry: [u64; 2],
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub reserved: [u8; 40],
 }
@@ -1,14 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct LeafNode {
     pub tag: u8,
     pub owner_slot: u8,
     pub time_in_force: u16,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 4],
     pub key: u128,
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
     pub quantity: i64,
     pub timestamp: u64,
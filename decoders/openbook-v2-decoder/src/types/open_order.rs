@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct OpenOrder {
     pub id: u128,
@@ -9,5 +9,6 @@ pub struct OpenOrder {
     pub locked_price: i64,
     pub is_free: u8,
     pub side_and_tree: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 6],
 }
@@ -1,12 +1,13 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, Clone)]
 pub struct OracleConfig {
     pub conf_filter: f64,
     pub max_staleness_slots: i64,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub reserved: [u8; 72],
 }
@@ -1,18 +1,20 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct OrderTreeNodes {
     pub order_tree_type: u8,
+    #[carbon(serialize_as = "hex")]
     pub padding: [u8; 3],
     pub bump_index: u32,
     pub free_list_len: u32,
     pub free_list_head: u32,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub reserved: [u8; 512],
     #[serde(with = "BigArray")]
     pub nodes: [AnyNode; 1024],
@@ -1,10 +1,10 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Position {
     pub bids_base_lots: i64,
@@ -18,5 +18,6 @@ pub struct Position {
     pub taker_volume: u128,
     pub bids_quote_lots: i64,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub reserved: [u8; 64],
 }
@@ -1,6 +1,12 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    crate::PROGRAM_ID,
+    carbon_core::{
+        account::{AccountDecoder, DecodedAccount},
+        borsh::{self, BorshDeserialize},
+        deserialize::LazyArray,
+        CarbonDeserialize,
+    },
 };
 
 #[derive(CarbonDeserialize, Debug)]
@@ -10,3 +16,79 @@ pub struct TickArray {
     pub ticks: [Tick; 88],
     pub whirlpool: solana_pubkey::Pubkey,
 }
+
+/// The encoded size of a `Tick`.
+pub const TICK_SIZE: usize = 113;
+
+/// A `TickArray` whose ticks are decoded on demand.
+///
+/// The start tick index and the whirlpool are decoded eagerly, while each of
+/// the 88 ticks is only decoded when read, which saves decoding and
+/// allocating every tick on each update of the account when only a few are
+/// needed.
+#[derive(Debug, Clone)]
+pub struct LazyTickArray {
+    pub start_tick_index: i32,
+    pub ticks: LazyArray<Tick>,
+    pub whirlpool: solana_pubkey::Pubkey,
+}
+
+impl LazyTickArray {
+    pub const DISCRIMINATOR: [u8; 8] = [0x45, 0x61, 0xbd, 0xbe, 0x6e, 0x07, 0x42, 0xbb];
+
+    /// Decodes the header of a `TickArray` account, keeping the ticks encoded.
+    pub fn deserialize(data: &[u8]) -> Option<Self> {
+        let mut data = data.strip_prefix(Self::DISCRIMINATOR.as_slice())?;
+
+        let start_tick_index = i32::deserialize(&mut data).ok()?;
+        let ticks = LazyArray::from_slice(&mut data, TICK_SIZE, 88)?;
+        let whirlpool = solana_pubkey::Pubkey::deserialize(&mut data).ok()?;
+
+        if !data.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            start_tick_index,
+            ticks,
+            whirlpool,
+        })
+    }
+
+    /// Decodes the tick at `tick_index`, or returns `None` if it is outside
+    /// this array, given the `tick_spacing` of the whirlpool.
+    pub fn tick(&self, tick_index: i32, tick_spacing: u16) -> Option<Tick> {
+        let offset = tick_index.checked_sub(self.start_tick_index)?;
+        if tick_spacing == 0 || offset < 0 || offset % tick_spacing as i32 != 0 {
+            return None;
+        }
+
+        self.ticks.get((offset / tick_spacing as i32) as usize)
+    }
+}
+
+/// An account decoder for `TickArray` accounts only, decoding their ticks on
+/// demand. Use it instead of `OrcaWhirlpoolDecoder` for pipes following tick
+/// array updates.
+pub struct OrcaWhirlpoolTickArrayDecoder;
+
+impl AccountDecoder<'_> for OrcaWhirlpoolTickArrayDecoder {
+    type AccountType = LazyTickArray;
+
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        Some(DecodedAccount {
+            lamports: account.lamports,
+            data: LazyTickArray::deserialize(account.data.as_slice())?,
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        })
+    }
+}
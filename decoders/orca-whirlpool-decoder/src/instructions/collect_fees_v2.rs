@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xcf755fbfe5b4e20f")]
 pub struct CollectFeesV2 {
     pub remaining_accounts_info: Option<RemainingAccountsInfo>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CollectFeesV2")]
 pub struct CollectFeesV2InstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub position_authority: solana_pubkey::Pubkey,
@@ -25,34 +27,5 @@ pub struct CollectFeesV2InstructionAccounts {
     pub token_program_a: solana_pubkey::Pubkey,
     pub token_program_b: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CollectFeesV2 {
-    type ArrangedAccounts = CollectFeesV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program_a, token_program_b, memo_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CollectFeesV2InstructionAccounts {
-            whirlpool: whirlpool.pubkey,
-            position_authority: position_authority.pubkey,
-            position: position.pubkey,
-            position_token_account: position_token_account.pubkey,
-            token_mint_a: token_mint_a.pubkey,
-            token_mint_b: token_mint_b.pubkey,
-            token_owner_account_a: token_owner_account_a.pubkey,
-            token_vault_a: token_vault_a.pubkey,
-            token_owner_account_b: token_owner_account_b.pubkey,
-            token_vault_b: token_vault_b.pubkey,
-            token_program_a: token_program_a.pubkey,
-            token_program_b: token_program_b.pubkey,
-            memo_program: memo_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb16b25b4a01331d1")]
 pub struct CollectRewardV2 {
@@ -12,6 +12,8 @@ pub struct CollectRewardV2 {
     pub remaining_accounts_info: Option<RemainingAccountsInfo>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CollectRewardV2")]
 pub struct CollectRewardV2InstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub position_authority: solana_pubkey::Pubkey,
@@ -22,30 +24,5 @@ pub struct CollectRewardV2InstructionAccounts {
     pub reward_vault: solana_pubkey::Pubkey,
     pub reward_token_program: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CollectRewardV2 {
-    type ArrangedAccounts = CollectRewardV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_mint, reward_vault, reward_token_program, memo_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CollectRewardV2InstructionAccounts {
-            whirlpool: whirlpool.pubkey,
-            position_authority: position_authority.pubkey,
-            position: position.pubkey,
-            position_token_account: position_token_account.pubkey,
-            reward_owner_account: reward_owner_account.pubkey,
-            reward_mint: reward_mint.pubkey,
-            reward_vault: reward_vault.pubkey,
-            reward_token_program: reward_token_program.pubkey,
-            memo_program: memo_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x35924408127511b9")]
 pub struct DeleteTokenBadge {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeleteTokenBadge")]
 pub struct DeleteTokenBadgeInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub whirlpools_config_extension: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct DeleteTokenBadgeInstructionAccounts {
     pub token_mint: solana_pubkey::Pubkey,
     pub token_badge: solana_pubkey::Pubkey,
     pub receiver: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeleteTokenBadge {
-    type ArrangedAccounts = DeleteTokenBadgeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, receiver, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DeleteTokenBadgeInstructionAccounts {
-            whirlpools_config: whirlpools_config.pubkey,
-            whirlpools_config_extension: whirlpools_config_extension.pubkey,
-            token_badge_authority: token_badge_authority.pubkey,
-            token_mint: token_mint.pubkey,
-            token_badge: token_badge.pubkey,
-            receiver: receiver.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2e9cf3760dcdfbb2")]
 pub struct IncreaseLiquidity {
@@ -10,6 +10,8 @@ pub struct IncreaseLiquidity {
     pub token_max_b: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IncreaseLiquidity")]
 pub struct IncreaseLiquidityInstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
@@ -22,32 +24,5 @@ pub struct IncreaseLiquidityInstructionAccounts {
     pub token_vault_b: solana_pubkey::Pubkey,
     pub tick_array_lower: solana_pubkey::Pubkey,
     pub tick_array_upper: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidity {
-    type ArrangedAccounts = IncreaseLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, token_program, position_authority, position, position_token_account, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(IncreaseLiquidityInstructionAccounts {
-            whirlpool: whirlpool.pubkey,
-            token_program: token_program.pubkey,
-            position_authority: position_authority.pubkey,
-            position: position.pubkey,
-            position_token_account: position_token_account.pubkey,
-            token_owner_account_a: token_owner_account_a.pubkey,
-            token_owner_account_b: token_owner_account_b.pubkey,
-            token_vault_a: token_vault_a.pubkey,
-            token_vault_b: token_vault_b.pubkey,
-            tick_array_lower: tick_array_lower.pubkey,
-            tick_array_upper: tick_array_upper.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,36 +1,24 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd07f1501c2bec446")]
 pub struct InitializeConfig {
+    #[carbon(serialize_as = "base58")]
     pub fee_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub collect_protocol_fees_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub reward_emissions_super_authority: solana_pubkey::Pubkey,
     pub default_protocol_fee_rate: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeConfig")]
 pub struct InitializeConfigInstructionAccounts {
     pub config: solana_pubkey::Pubkey,
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeConfig {
-    type ArrangedAccounts = InitializeConfigInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [config, funder, system_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeConfigInstructionAccounts {
-            config: config.pubkey,
-            funder: funder.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfd4dcd5f1be059df")]
 pub struct InitializeTokenBadge {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeTokenBadge")]
 pub struct InitializeTokenBadgeInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub whirlpools_config_extension: solana_pubkey::Pubkey,
@@ -14,28 +16,5 @@ pub struct InitializeTokenBadgeInstructionAccounts {
     pub token_badge: solana_pubkey::Pubkey,
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenBadge {
-    type ArrangedAccounts = InitializeTokenBadgeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, funder, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeTokenBadgeInstructionAccounts {
-            whirlpools_config: whirlpools_config.pubkey,
-            whirlpools_config_extension: whirlpools_config_extension.pubkey,
-            token_badge_authority: token_badge_authority.pubkey,
-            token_mint: token_mint.pubkey,
-            token_badge: token_badge.pubkey,
-            funder: funder.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
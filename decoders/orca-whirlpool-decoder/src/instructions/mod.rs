@@ -118,6 +118,10 @@ pub enum OrcaWhirlpoolInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for OrcaWhirlpoolDecoder {
     type InstructionType = OrcaWhirlpoolInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
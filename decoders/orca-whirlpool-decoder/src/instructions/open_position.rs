@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x87802f4d0f98f031")]
 pub struct OpenPosition {
@@ -13,6 +13,8 @@ pub struct OpenPosition {
     pub tick_upper_index: i32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OpenPosition")]
 pub struct OpenPositionInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -24,31 +26,5 @@ pub struct OpenPositionInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OpenPosition {
-    type ArrangedAccounts = OpenPositionInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [funder, owner, position, position_mint, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(OpenPositionInstructionAccounts {
-            funder: funder.pubkey,
-            owner: owner.pubkey,
-            position: position.pubkey,
-            position_mint: position_mint.pubkey,
-            position_token_account: position_token_account.pubkey,
-            whirlpool: whirlpool.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
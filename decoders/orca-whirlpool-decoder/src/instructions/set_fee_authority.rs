@@ -1,32 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1f013257ed656184")]
 pub struct SetFeeAuthority {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetFeeAuthority")]
 pub struct SetFeeAuthorityInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub new_fee_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetFeeAuthority {
-    type ArrangedAccounts = SetFeeAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, fee_authority, new_fee_authority, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(SetFeeAuthorityInstructionAccounts {
-            whirlpools_config: whirlpools_config.pubkey,
-            fee_authority: fee_authority.pubkey,
-            new_fee_authority: new_fee_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,37 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf09ac9c6945d3819")]
 pub struct SetRewardAuthorityBySuperAuthority {
     pub reward_index: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetRewardAuthorityBySuperAuthority")]
 pub struct SetRewardAuthorityBySuperAuthorityInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_emissions_super_authority: solana_pubkey::Pubkey,
     pub new_reward_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthorityBySuperAuthority {
-    type ArrangedAccounts = SetRewardAuthorityBySuperAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpool, reward_emissions_super_authority, new_reward_authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SetRewardAuthorityBySuperAuthorityInstructionAccounts {
-            whirlpools_config: whirlpools_config.pubkey,
-            whirlpool: whirlpool.pubkey,
-            reward_emissions_super_authority: reward_emissions_super_authority.pubkey,
-            new_reward_authority: new_reward_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0dc556a86db01bf4")]
 pub struct SetRewardEmissions {
@@ -9,26 +9,11 @@ pub struct SetRewardEmissions {
     pub emissions_per_second_x64: u128,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetRewardEmissions")]
 pub struct SetRewardEmissionsInstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_authority: solana_pubkey::Pubkey,
     pub reward_vault: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissions {
-    type ArrangedAccounts = SetRewardEmissionsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, reward_authority, reward_vault, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetRewardEmissionsInstructionAccounts {
-            whirlpool: whirlpool.pubkey,
-            reward_authority: reward_authority.pubkey,
-            reward_vault: reward_vault.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf8c69e91e17587c8")]
 pub struct Swap {
@@ -12,6 +12,8 @@ pub struct Swap {
     pub a_to_b: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Swap")]
 pub struct SwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub token_authority: solana_pubkey::Pubkey,
@@ -26,32 +28,3 @@ pub struct SwapInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for Swap {
-    type ArrangedAccounts = SwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, token_authority, whirlpool, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, tick_array0, tick_array1, tick_array2, oracle, remaining_accounts @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapInstructionAccounts {
-            token_program: token_program.pubkey,
-            token_authority: token_authority.pubkey,
-            whirlpool: whirlpool.pubkey,
-            token_owner_account_a: token_owner_account_a.pubkey,
-            token_vault_a: token_vault_a.pubkey,
-            token_owner_account_b: token_owner_account_b.pubkey,
-            token_vault_b: token_vault_b.pubkey,
-            tick_array0: tick_array0.pubkey,
-            tick_array1: tick_array1.pubkey,
-            tick_array2: tick_array2.pubkey,
-            oracle: oracle.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2b04ed0b1ac91e62")]
 pub struct SwapV2 {
@@ -16,6 +16,8 @@ pub struct SwapV2 {
     pub remaining_accounts_info: Option<RemainingAccountsInfo>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SwapV2")]
 pub struct SwapV2InstructionAccounts {
     pub token_program_a: solana_pubkey::Pubkey,
     pub token_program_b: solana_pubkey::Pubkey,
@@ -34,36 +36,3 @@ pub struct SwapV2InstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
-    type ArrangedAccounts = SwapV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program_a, token_program_b, memo_program, token_authority, whirlpool, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, tick_array0, tick_array1, tick_array2, oracle, remaining_accounts @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapV2InstructionAccounts {
-            token_program_a: token_program_a.pubkey,
-            token_program_b: token_program_b.pubkey,
-            memo_program: memo_program.pubkey,
-            token_authority: token_authority.pubkey,
-            whirlpool: whirlpool.pubkey,
-            token_mint_a: token_mint_a.pubkey,
-            token_mint_b: token_mint_b.pubkey,
-            token_owner_account_a: token_owner_account_a.pubkey,
-            token_vault_a: token_vault_a.pubkey,
-            token_owner_account_b: token_owner_account_b.pubkey,
-            token_vault_b: token_vault_b.pubkey,
-            tick_array0: tick_array0.pubkey,
-            tick_array1: tick_array1.pubkey,
-            tick_array2: tick_array2.pubkey,
-            oracle: oracle.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
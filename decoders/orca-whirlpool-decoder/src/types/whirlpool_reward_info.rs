@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct WhirlpoolRewardInfo {
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
     pub emissions_per_second_x64: u128,
     pub growth_global_x64: u128,
@@ -1,14 +1,16 @@
 use super::super::types::*;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0a")]
 pub struct CancelMultipleOrdersById {
     pub params: CancelMultipleOrdersByIdParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CancelMultipleOrdersById")]
 pub struct CancelMultipleOrdersByIdInstructionAccounts {
     pub phoenix_program: solana_pubkey::Pubkey,
     pub log_authority: solana_pubkey::Pubkey,
@@ -19,30 +21,5 @@ pub struct CancelMultipleOrdersByIdInstructionAccounts {
     pub base_vault: solana_pubkey::Pubkey,
     pub quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersById {
-    type ArrangedAccounts = CancelMultipleOrdersByIdInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, base_account, quote_account, base_vault, quote_vault, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CancelMultipleOrdersByIdInstructionAccounts {
-            phoenix_program: phoenix_program.pubkey,
-            log_authority: log_authority.pubkey,
-            market: market.pubkey,
-            trader: trader.pubkey,
-            base_account: base_account.pubkey,
-            quote_account: quote_account.pubkey,
-            base_vault: base_vault.pubkey,
-            quote_vault: quote_vault.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
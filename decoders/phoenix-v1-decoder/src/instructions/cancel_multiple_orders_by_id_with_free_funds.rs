@@ -1,36 +1,20 @@
 use super::super::types::*;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0b")]
 pub struct CancelMultipleOrdersByIdWithFreeFunds {
     pub params: CancelMultipleOrdersByIdParams,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CancelMultipleOrdersByIdWithFreeFunds")]
 pub struct CancelMultipleOrdersByIdWithFreeFundsInstructionAccounts {
     pub phoenix_program: solana_pubkey::Pubkey,
     pub log_authority: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub trader: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersByIdWithFreeFunds {
-    type ArrangedAccounts = CancelMultipleOrdersByIdWithFreeFundsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CancelMultipleOrdersByIdWithFreeFundsInstructionAccounts {
-            phoenix_program: phoenix_program.pubkey,
-            log_authority: log_authority.pubkey,
-            market: market.pubkey,
-            trader: trader.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
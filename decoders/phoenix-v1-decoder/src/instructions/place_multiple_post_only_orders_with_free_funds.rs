@@ -1,8 +1,8 @@
 use super::super::types::*;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x11")]
 pub struct PlaceMultiplePostOnlyOrdersWithFreeFunds {
@@ -15,6 +15,7 @@ pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub trader: solana_pubkey::Pubkey,
     pub seat: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWithFreeFunds {
@@ -23,7 +24,8 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, seat, _remaining @ ..] = accounts
+        let [phoenix_program, log_authority, market, trader, seat, remaining_accounts @ ..] =
+            accounts
         else {
             return None;
         };
@@ -35,6 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWi
                 market: market.pubkey,
                 trader: trader.pubkey,
                 seat: seat.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
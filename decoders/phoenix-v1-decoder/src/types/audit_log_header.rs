@@ -1,14 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AuditLogHeader {
     pub instruction: u8,
     pub sequence_number: u64,
     pub timestamp: i64,
     pub slot: u64,
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub signer: solana_pubkey::Pubkey,
     pub total_events: u16,
 }
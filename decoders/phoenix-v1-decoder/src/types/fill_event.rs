@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct FillEvent {
     pub index: u16,
+    #[carbon(serialize_as = "base58")]
     pub maker_id: solana_pubkey::Pubkey,
     pub order_sequence_number: u64,
     pub price_in_ticks: u64,
@@ -1,9 +1,9 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct InitializeParams {
     pub market_size_params: MarketSizeParams,
@@ -11,6 +11,7 @@ pub struct InitializeParams {
     pub tick_size_in_quote_lots_per_base_unit: u64,
     pub num_base_lots_per_base_unit: u64,
     pub taker_fee_bps: u16,
+    #[carbon(serialize_as = "base58")]
     pub fee_collector: solana_pubkey::Pubkey,
     pub raw_base_units_per_base_unit: Option<u32>,
 }
@@ -1,9 +1,9 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct MarketHeader {
     pub discriminant: u64,
@@ -14,9 +14,12 @@ pub struct MarketHeader {
     pub quote_params: TokenParams,
     pub quote_lot_size: u64,
     pub tick_size_in_quote_atoms_per_base_unit: u64,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub fee_recipient: solana_pubkey::Pubkey,
     pub market_sequence_number: u64,
+    #[carbon(serialize_as = "base58")]
     pub successor: solana_pubkey::Pubkey,
     pub raw_base_units_per_base_unit: u32,
     pub padding1: u32,
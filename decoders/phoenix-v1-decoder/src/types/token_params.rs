@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct TokenParams {
     pub decimals: u32,
     pub vault_bump: u32,
+    #[carbon(serialize_as = "base58")]
     pub mint_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub vault_key: solana_pubkey::Pubkey,
 }
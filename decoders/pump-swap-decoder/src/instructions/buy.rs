@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x66063d1201daebea")]
 pub struct Buy {
@@ -9,6 +9,8 @@ pub struct Buy {
     pub max_quote_amount_in: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Buy")]
 pub struct BuyInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
@@ -27,38 +29,5 @@ pub struct BuyInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Buy {
-    type ArrangedAccounts = BuyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, user, global_config, base_mint, quote_mint, user_base_token_account, user_quote_token_account, pool_base_token_account, pool_quote_token_account, protocol_fee_recipient, protocol_fee_recipient_token_account, base_token_program, quote_token_program, system_program, associated_token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(BuyInstructionAccounts {
-            pool: pool.pubkey,
-            user: user.pubkey,
-            global_config: global_config.pubkey,
-            base_mint: base_mint.pubkey,
-            quote_mint: quote_mint.pubkey,
-            user_base_token_account: user_base_token_account.pubkey,
-            user_quote_token_account: user_quote_token_account.pubkey,
-            pool_base_token_account: pool_base_token_account.pubkey,
-            pool_quote_token_account: pool_quote_token_account.pubkey,
-            protocol_fee_recipient: protocol_fee_recipient.pubkey,
-            protocol_fee_recipient_token_account: protocol_fee_recipient_token_account.pubkey,
-            base_token_program: base_token_program.pubkey,
-            quote_token_program: quote_token_program.pubkey,
-            system_program: system_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d67f4521f2cf57777")]
 pub struct BuyEvent {
@@ -19,10 +19,16 @@ pub struct BuyEvent {
     pub protocol_fee: u64,
     pub quote_amount_in_with_lp_fee: u64,
     pub user_quote_amount_in: u64,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_base_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_quote_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub protocol_fee_recipient: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub protocol_fee_recipient_token_account: solana_pubkey::Pubkey,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe992d18ecf6840bc")]
 pub struct CreatePool {
@@ -10,6 +10,8 @@ pub struct CreatePool {
     pub quote_amount_in: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreatePool")]
 pub struct CreatePoolInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
@@ -29,39 +31,5 @@ pub struct CreatePoolInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreatePool {
-    type ArrangedAccounts = CreatePoolInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, global_config, creator, base_mint, quote_mint, lp_mint, user_base_token_account, user_quote_token_account, user_pool_token_account, pool_base_token_account, pool_quote_token_account, system_program, token_2022_program, base_token_program, quote_token_program, associated_token_program, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreatePoolInstructionAccounts {
-            pool: pool.pubkey,
-            global_config: global_config.pubkey,
-            creator: creator.pubkey,
-            base_mint: base_mint.pubkey,
-            quote_mint: quote_mint.pubkey,
-            lp_mint: lp_mint.pubkey,
-            user_base_token_account: user_base_token_account.pubkey,
-            user_quote_token_account: user_quote_token_account.pubkey,
-            user_pool_token_account: user_pool_token_account.pubkey,
-            pool_base_token_account: pool_base_token_account.pubkey,
-            pool_quote_token_account: pool_quote_token_account.pubkey,
-            system_program: system_program.pubkey,
-            token_2022_program: token_2022_program.pubkey,
-            base_token_program: base_token_program.pubkey,
-            quote_token_program: quote_token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
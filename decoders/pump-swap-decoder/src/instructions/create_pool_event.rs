@@ -1,14 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1db1310cd2a076a774")]
 pub struct CreatePoolEvent {
     pub timestamp: i64,
     pub index: u16,
+    #[carbon(serialize_as = "base58")]
     pub creator: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub base_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub quote_mint: solana_pubkey::Pubkey,
     pub base_mint_decimals: u8,
     pub quote_mint_decimals: u8,
@@ -20,8 +23,12 @@ pub struct CreatePoolEvent {
     pub initial_liquidity: u64,
     pub lp_token_amount_out: u64,
     pub pool_bump: u8,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub lp_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_base_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_quote_token_account: solana_pubkey::Pubkey,
 }
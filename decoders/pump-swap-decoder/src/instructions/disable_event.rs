@@ -1,11 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d6bfdc14ce4ca1b68")]
 pub struct DisableEvent {
     pub timestamp: i64,
+    #[carbon(serialize_as = "base58")]
     pub admin: solana_pubkey::Pubkey,
     pub disable_create_pool: bool,
     pub disable_deposit: bool,
@@ -2,24 +2,34 @@ use crate::PROGRAM_ID;
 
 use super::PumpSwapDecoder;
 pub mod buy;
+#[cfg(feature = "events")]
 pub mod buy_event;
 pub mod create_config;
+#[cfg(feature = "events")]
 pub mod create_config_event;
 pub mod create_pool;
+#[cfg(feature = "events")]
 pub mod create_pool_event;
 pub mod deposit;
+#[cfg(feature = "events")]
 pub mod deposit_event;
 pub mod disable;
+#[cfg(feature = "events")]
 pub mod disable_event;
 pub mod extend_account;
+#[cfg(feature = "events")]
 pub mod extend_account_event;
 pub mod sell;
+#[cfg(feature = "events")]
 pub mod sell_event;
 pub mod update_admin;
+#[cfg(feature = "events")]
 pub mod update_admin_event;
 pub mod update_fee_config;
+#[cfg(feature = "events")]
 pub mod update_fee_config_event;
 pub mod withdraw;
+#[cfg(feature = "events")]
 pub mod withdraw_event;
 
 #[derive(
@@ -43,21 +53,35 @@ pub enum PumpSwapInstruction {
     UpdateAdmin(update_admin::UpdateAdmin),
     UpdateFeeConfig(update_fee_config::UpdateFeeConfig),
     Withdraw(withdraw::Withdraw),
+    #[cfg(feature = "events")]
     BuyEvent(buy_event::BuyEvent),
+    #[cfg(feature = "events")]
     CreateConfigEvent(create_config_event::CreateConfigEvent),
+    #[cfg(feature = "events")]
     CreatePoolEvent(create_pool_event::CreatePoolEvent),
+    #[cfg(feature = "events")]
     DepositEvent(deposit_event::DepositEvent),
+    #[cfg(feature = "events")]
     DisableEvent(disable_event::DisableEvent),
+    #[cfg(feature = "events")]
     ExtendAccountEvent(extend_account_event::ExtendAccountEvent),
+    #[cfg(feature = "events")]
     SellEvent(sell_event::SellEvent),
+    #[cfg(feature = "events")]
     UpdateAdminEvent(update_admin_event::UpdateAdminEvent),
+    #[cfg(feature = "events")]
     UpdateFeeConfigEvent(update_fee_config_event::UpdateFeeConfigEvent),
+    #[cfg(feature = "events")]
     WithdrawEvent(withdraw_event::WithdrawEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for PumpSwapDecoder {
     type InstructionType = PumpSwapInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -77,15 +101,25 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PumpSwapDecoder {
             PumpSwapInstruction::UpdateAdmin => update_admin::UpdateAdmin,
             PumpSwapInstruction::UpdateFeeConfig => update_fee_config::UpdateFeeConfig,
             PumpSwapInstruction::Withdraw => withdraw::Withdraw,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::BuyEvent => buy_event::BuyEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::CreateConfigEvent => create_config_event::CreateConfigEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::CreatePoolEvent => create_pool_event::CreatePoolEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::DepositEvent => deposit_event::DepositEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::DisableEvent => disable_event::DisableEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::ExtendAccountEvent => extend_account_event::ExtendAccountEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::SellEvent => sell_event::SellEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::UpdateAdminEvent => update_admin_event::UpdateAdminEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::UpdateFeeConfigEvent => update_fee_config_event::UpdateFeeConfigEvent,
+            #[cfg(feature = "events")]
             PumpSwapInstruction::WithdrawEvent => withdraw_event::WithdrawEvent,
         )
     }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1de198ab57f63f42ea")]
 pub struct UpdateAdminEvent {
     pub timestamp: i64,
+    #[carbon(serialize_as = "base58")]
     pub admin: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_admin: solana_pubkey::Pubkey,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d1609851aa02c47c0")]
 pub struct WithdrawEvent {
@@ -16,9 +16,14 @@ pub struct WithdrawEvent {
     pub base_amount_out: u64,
     pub quote_amount_out: u64,
     pub lp_mint_supply: u64,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_base_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_quote_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_pool_token_account: solana_pubkey::Pubkey,
 }
@@ -1,12 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct CreateConfigEvent {
     pub timestamp: i64,
+    #[carbon(serialize_as = "base58")]
     pub admin: solana_pubkey::Pubkey,
     pub lp_fee_basis_points: u64,
     pub protocol_fee_basis_points: u64,
+    #[carbon(serialize_as = "base58")]
     pub protocol_fee_recipients: [solana_pubkey::Pubkey; 8],
 }
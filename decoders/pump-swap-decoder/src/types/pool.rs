@@ -1,16 +1,22 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Pool {
     pub pool_bump: u8,
     pub index: u16,
+    #[carbon(serialize_as = "base58")]
     pub creator: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub base_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub quote_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub lp_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_base_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub pool_quote_token_account: solana_pubkey::Pubkey,
     pub lp_supply: u64,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct SellEvent {
     pub timestamp: i64,
@@ -18,10 +18,16 @@ pub struct SellEvent {
     pub protocol_fee: u64,
     pub quote_amount_out_without_lp_fee: u64,
     pub user_quote_amount_out: u64,
+    #[carbon(serialize_as = "base58")]
     pub pool: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_base_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user_quote_token_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub protocol_fee_recipient: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub protocol_fee_recipient_token_account: solana_pubkey::Pubkey,
 }
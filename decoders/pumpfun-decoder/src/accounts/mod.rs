@@ -116,6 +116,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bonding_curve_size_matches_account() {
+        // Arrange
+        let account = carbon_test_utils::read_account("tests/fixtures/bonding_curve_account.json")
+            .expect("read fixture");
+
+        // Act
+        let size = <bonding_curve::BondingCurve as CarbonDeserialize>::SIZE;
+
+        // Assert
+        assert_eq!(size, Some(account.data.len()));
+        assert_eq!(
+            <bonding_curve::BondingCurve as CarbonDeserialize>::DISCRIMINATOR,
+            &account.data[..8]
+        );
+    }
+
     #[test]
     fn test_decode_global_account() {
         // Arrange
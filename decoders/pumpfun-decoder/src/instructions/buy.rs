@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x66063d1201daebea")]
 pub struct Buy {
@@ -9,7 +9,8 @@ pub struct Buy {
     pub max_sol_cost: u64,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Buy")]
 pub struct BuyInstructionAccounts {
     pub global: solana_pubkey::Pubkey,
     pub fee_recipient: solana_pubkey::Pubkey,
@@ -23,33 +24,5 @@ pub struct BuyInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Buy {
-    type ArrangedAccounts = BuyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [global, fee_recipient, mint, bonding_curve, associated_bonding_curve, associated_user, user, system_program, token_program, rent, event_authority, program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(BuyInstructionAccounts {
-            global: global.pubkey,
-            fee_recipient: fee_recipient.pubkey,
-            mint: mint.pubkey,
-            bonding_curve: bonding_curve.pubkey,
-            associated_bonding_curve: associated_bonding_curve.pubkey,
-            associated_user: associated_user.pubkey,
-            user: user.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            rent: rent.pubkey,
-            event_authority: event_authority.pubkey,
-            program: program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,17 +1,21 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 use alloc::string::String;
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d1b72a94ddeeb6376")]
 pub struct CreateEvent {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub bonding_curve: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub creator: solana_pubkey::Pubkey,
     pub timestamp: i64,
 }
@@ -2,19 +2,26 @@ use crate::PROGRAM_ID;
 
 use super::PumpfunDecoder;
 pub mod buy;
+#[cfg(feature = "events")]
 pub mod complete_event;
+#[cfg(feature = "events")]
 pub mod complete_pump_amm_migration_event;
 pub mod create;
+#[cfg(feature = "events")]
 pub mod create_event;
 pub mod extend_account;
+#[cfg(feature = "events")]
 pub mod extend_account_event;
 pub mod initialize;
 pub mod migrate;
 pub mod sell;
 pub mod set_params;
+#[cfg(feature = "events")]
 pub mod set_params_event;
+#[cfg(feature = "events")]
 pub mod trade_event;
 pub mod update_global_authority;
+#[cfg(feature = "events")]
 pub mod update_global_authority_event;
 pub mod withdraw;
 
@@ -38,18 +45,29 @@ pub enum PumpfunInstruction {
     SetParams(set_params::SetParams),
     UpdateGlobalAuthority(update_global_authority::UpdateGlobalAuthority),
     Withdraw(withdraw::Withdraw),
+    #[cfg(feature = "events")]
     CompleteEvent(complete_event::CompleteEvent),
+    #[cfg(feature = "events")]
     CompletePumpAmmMigrationEvent(complete_pump_amm_migration_event::CompletePumpAmmMigrationEvent),
+    #[cfg(feature = "events")]
     CreateEvent(create_event::CreateEvent),
+    #[cfg(feature = "events")]
     ExtendAccountEvent(extend_account_event::ExtendAccountEvent),
+    #[cfg(feature = "events")]
     SetParamsEvent(set_params_event::SetParamsEvent),
+    #[cfg(feature = "events")]
     TradeEvent(trade_event::TradeEvent),
+    #[cfg(feature = "events")]
     UpdateGlobalAuthorityEvent(update_global_authority_event::UpdateGlobalAuthorityEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for PumpfunDecoder {
     type InstructionType = PumpfunInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -68,12 +86,19 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PumpfunDecoder {
             PumpfunInstruction::SetParams => set_params::SetParams,
             PumpfunInstruction::UpdateGlobalAuthority => update_global_authority::UpdateGlobalAuthority,
             PumpfunInstruction::Withdraw => withdraw::Withdraw,
+            #[cfg(feature = "events")]
             PumpfunInstruction::CompleteEvent => complete_event::CompleteEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::CompletePumpAmmMigrationEvent => complete_pump_amm_migration_event::CompletePumpAmmMigrationEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::CreateEvent => create_event::CreateEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::ExtendAccountEvent => extend_account_event::ExtendAccountEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::SetParamsEvent => set_params_event::SetParamsEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::TradeEvent => trade_event::TradeEvent,
+            #[cfg(feature = "events")]
             PumpfunInstruction::UpdateGlobalAuthorityEvent => update_global_authority_event::UpdateGlobalAuthorityEvent,
         )
     }
@@ -160,6 +185,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -253,6 +279,7 @@ mod tests {
             token_program: Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -360,6 +387,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -448,6 +476,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -466,4 +495,32 @@ mod tests {
         assert_eq!(decoded.program_id, PROGRAM_ID);
         assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
     }
+
+    #[test]
+    fn test_decode_fixtures_match_patterns() {
+        let fixtures =
+            carbon_test_utils::read_instructions("tests/fixtures").expect("read fixtures");
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/buy_ix.json")
+            .expect("read fixture");
+
+        assert_eq!(
+            fixtures
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<vec::Vec<_>>(),
+            ["buy", "create", "sell", "withdraw"]
+        );
+        carbon_test_utils::assert_decodes!(
+            PumpfunDecoder,
+            instruction,
+            PumpfunInstruction::Buy(buy::Buy {
+                amount: 1_000_000_000..,
+                max_sol_cost,
+            }) if *max_sol_cost == 204000000
+        );
+        carbon_test_utils::assert_not_decodes!(
+            PumpfunDecoder,
+            carbon_test_utils::instruction_from_data(PROGRAM_ID, vec![0; 8])
+        );
+    }
 }
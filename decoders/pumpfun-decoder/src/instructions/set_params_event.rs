@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1ddfc39ff63e308f83")]
 pub struct SetParamsEvent {
@@ -11,10 +11,12 @@ pub struct SetParamsEvent {
     pub final_real_sol_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[carbon(serialize_as = "base58")]
     pub withdraw_authority: solana_pubkey::Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee: u64,
+    #[carbon(serialize_as = "base58")]
     pub fee_recipients: [solana_pubkey::Pubkey; 8],
     pub timestamp: i64,
 }
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1db6c3892a23cecff7")]
 pub struct UpdateGlobalAuthorityEvent {
+    #[carbon(serialize_as = "base58")]
     pub global: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_authority: solana_pubkey::Pubkey,
     pub timestamp: i64,
 }
@@ -5,8 +5,12 @@ extern crate alloc;
 use solana_pubkey::Pubkey;
 
 pub struct PumpfunDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
+#[cfg(feature = "events")]
+pub mod replay;
 pub mod types;
 
 pub const PROGRAM_ID: Pubkey =
@@ -0,0 +1,102 @@
+use {
+    crate::instructions::{buy::Buy, create::Create, sell::Sell, PumpfunInstruction},
+    alloc::{collections::BTreeMap, vec, vec::Vec},
+    carbon_core::{
+        deserialize::ArrangeAccounts,
+        error::CarbonResult,
+        instruction::{DecodedInstruction, InstructionMetadata},
+        replay::StateModel,
+    },
+    solana_pubkey::Pubkey,
+};
+
+/// The state of a bonding curve account, as rebuilt from trade events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BondingCurveState {
+    pub mint: Pubkey,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub complete: bool,
+}
+
+/// Rebuilds the history of bonding curves from Pumpfun instructions and
+/// events, for use with a `ReplayEngine`.
+///
+/// Trade events carry the reserves of the curve after each trade, and
+/// complete events mark the end of the curve. The curve of a mint is learned
+/// from the `create`, `buy` and `sell` instructions preceding the events, so
+/// the pipe must receive both instructions and events.
+#[derive(Debug, Default)]
+pub struct BondingCurveReplay {
+    curves: BTreeMap<Pubkey, BondingCurveState>,
+    curves_by_mint: BTreeMap<Pubkey, Pubkey>,
+}
+
+impl BondingCurveReplay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the latest replayed state of `bonding_curve`.
+    pub fn curve(&self, bonding_curve: &Pubkey) -> Option<&BondingCurveState> {
+        self.curves.get(bonding_curve)
+    }
+}
+
+impl StateModel for BondingCurveReplay {
+    type Instruction = PumpfunInstruction;
+    type State = BondingCurveState;
+
+    fn apply(
+        &mut self,
+        _metadata: &InstructionMetadata,
+        instruction: &DecodedInstruction<PumpfunInstruction>,
+    ) -> CarbonResult<Vec<(Pubkey, BondingCurveState)>> {
+        let accounts = &instruction.accounts;
+
+        let (mint, bonding_curve) = match &instruction.data {
+            PumpfunInstruction::Create(_) => match Create::arrange_accounts(accounts) {
+                Some(accounts) => (accounts.mint, accounts.bonding_curve),
+                None => return Ok(Vec::new()),
+            },
+            PumpfunInstruction::Buy(_) => match Buy::arrange_accounts(accounts) {
+                Some(accounts) => (accounts.mint, accounts.bonding_curve),
+                None => return Ok(Vec::new()),
+            },
+            PumpfunInstruction::Sell(_) => match Sell::arrange_accounts(accounts) {
+                Some(accounts) => (accounts.mint, accounts.bonding_curve),
+                None => return Ok(Vec::new()),
+            },
+            PumpfunInstruction::CreateEvent(event) => (event.mint, event.bonding_curve),
+            PumpfunInstruction::TradeEvent(event) => {
+                let Some(bonding_curve) = self.curves_by_mint.get(&event.mint).copied() else {
+                    return Ok(Vec::new());
+                };
+
+                let curve = self.curves.entry(bonding_curve).or_default();
+                curve.mint = event.mint;
+                curve.virtual_token_reserves = event.virtual_token_reserves;
+                curve.virtual_sol_reserves = event.virtual_sol_reserves;
+                curve.real_token_reserves = event.real_token_reserves;
+                curve.real_sol_reserves = event.real_sol_reserves;
+
+                return Ok(vec![(bonding_curve, curve.clone())]);
+            }
+            PumpfunInstruction::CompleteEvent(event) => {
+                let Some(curve) = self.curves.get_mut(&event.bonding_curve) else {
+                    return Ok(Vec::new());
+                };
+
+                curve.complete = true;
+
+                return Ok(vec![(event.bonding_curve, curve.clone())]);
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        self.curves_by_mint.insert(mint, bonding_curve);
+        Ok(Vec::new())
+    }
+}
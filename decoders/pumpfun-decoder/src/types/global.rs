@@ -1,20 +1,24 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Global {
     pub initialized: bool,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub fee_recipient: solana_pubkey::Pubkey,
     pub initial_virtual_token_reserves: u64,
     pub initial_virtual_sol_reserves: u64,
     pub initial_real_token_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[carbon(serialize_as = "base58")]
     pub withdraw_authority: solana_pubkey::Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee: u64,
+    #[carbon(serialize_as = "base58")]
     pub fee_recipients: [solana_pubkey::Pubkey; 7],
 }
@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct UpdateGlobalAuthorityEvent {
+    #[carbon(serialize_as = "base58")]
     pub global: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub new_authority: solana_pubkey::Pubkey,
     pub timestamp: i64,
 }
@@ -0,0 +1,87 @@
+use {
+    super::PythOracleDecoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod price_account;
+
+pub enum PythOracleAccount {
+    PriceAccount(price_account::PriceAccount),
+}
+
+impl AccountDecoder<'_> for PythOracleDecoder {
+    type AccountType = PythOracleAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            price_account::PriceAccount::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythOracleAccount::PriceAccount(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Price, PriceStatus};
+
+    #[test]
+    fn test_decode_price_account() {
+        // Act
+        let decoder = PythOracleDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/price_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            PythOracleAccount::PriceAccount(price_account) => {
+                assert_eq!(price_account.agg.status, PriceStatus::Trading);
+                assert_eq!(
+                    price_account.price(),
+                    Some(Price {
+                        price: 14_512_345_678,
+                        conf: 7_250_000,
+                        exponent: -8,
+                        publish_time: 1_730_000_000,
+                    })
+                );
+                assert_eq!(price_account.ema_price().price, 14_498_000_000);
+                assert_eq!(
+                    price_account.prod,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "ALP8SdU9oARYVLgLR7LrqMNCYBnhtnQz1cj6bwgwQmgj"
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_price_f64() {
+        let price = Price {
+            price: 6_425,
+            conf: 15,
+            exponent: -2,
+            publish_time: 0,
+        };
+
+        assert_eq!(price.price_f64(), 64.25);
+        assert_eq!(price.conf_f64(), 0.15);
+    }
+}
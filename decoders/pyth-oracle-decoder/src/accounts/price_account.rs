@@ -0,0 +1,83 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+/// The magic number starting every account of the oracle program.
+pub const MAGIC: u32 = 0xa1b2c3d4;
+
+/// The account type of price accounts, as opposed to mapping and product
+/// accounts.
+pub const PRICE_ACCOUNT_TYPE: u32 = 3;
+
+/// A price feed, with the aggregate of its publishers' prices.
+///
+/// The publishers' components following the aggregate are not decoded.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(deserialize_with = "deserialize_price_account")]
+pub struct PriceAccount {
+    pub magic: u32,
+    pub ver: u32,
+    pub atype: u32,
+    pub size: u32,
+    pub ptype: u32,
+    pub expo: i32,
+    /// The number of publishers of the feed.
+    pub num: u32,
+    /// The number of publishers in the latest aggregate.
+    pub num_qt: u32,
+    pub last_slot: u64,
+    pub valid_slot: u64,
+    pub ema_price: Rational,
+    pub ema_conf: Rational,
+    pub timestamp: i64,
+    pub min_pub: u8,
+    pub drv2: u8,
+    pub drv3: u16,
+    pub drv4: u32,
+    #[carbon(serialize_as = "base58")]
+    pub prod: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub next: solana_pubkey::Pubkey,
+    pub prev_slot: u64,
+    pub prev_price: i64,
+    pub prev_conf: u64,
+    pub prev_timestamp: i64,
+    pub agg: PriceInfo,
+}
+
+impl PriceAccount {
+    /// Returns the aggregate price, or `None` when the feed is not trading,
+    /// in which case the aggregate should not be relied upon.
+    pub fn price(&self) -> Option<Price> {
+        if self.agg.status != PriceStatus::Trading {
+            return None;
+        }
+
+        Some(Price {
+            price: self.agg.price,
+            conf: self.agg.conf,
+            exponent: self.expo,
+            publish_time: self.timestamp,
+        })
+    }
+
+    /// Returns the exponential moving average of the price.
+    pub fn ema_price(&self) -> Price {
+        Price {
+            price: self.ema_price.val,
+            conf: self.ema_conf.val as u64,
+            exponent: self.expo,
+            publish_time: self.timestamp,
+        }
+    }
+}
+
+fn deserialize_price_account(data: &[u8]) -> Option<PriceAccount> {
+    let mut data = data;
+    <PriceAccount as borsh::BorshDeserialize>::deserialize(&mut data)
+        .ok()
+        .filter(|account| account.magic == MAGIC && account.atype == PRICE_ACCOUNT_TYPE)
+}
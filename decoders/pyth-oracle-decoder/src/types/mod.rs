@@ -0,0 +1,8 @@
+pub mod price;
+pub use price::*;
+pub mod price_info;
+pub use price_info::*;
+pub mod price_status;
+pub use price_status::*;
+pub mod rational;
+pub use rational::*;
@@ -0,0 +1,16 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct PriceInfo {
+    pub price: i64,
+    pub conf: u64,
+    #[carbon(deserialize_with = "read_price_status")]
+    pub status: PriceStatus,
+    pub corp_act: u32,
+    pub pub_slot: u64,
+}
@@ -0,0 +1,26 @@
+use carbon_core::borsh;
+
+/// The status of a price, stored as a `u32`.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PriceStatus {
+    Unknown,
+    Trading,
+    Halted,
+    Auction,
+    Ignored,
+}
+
+/// Reads a `u32` status, unknown values reading as `PriceStatus::Unknown`.
+pub(crate) fn read_price_status<R: borsh::maybestd::io::Read>(
+    reader: &mut R,
+) -> Result<PriceStatus, borsh::maybestd::io::Error> {
+    let status: u32 = borsh::BorshDeserialize::deserialize_reader(reader)?;
+
+    Ok(match status {
+        1 => PriceStatus::Trading,
+        2 => PriceStatus::Halted,
+        3 => PriceStatus::Auction,
+        4 => PriceStatus::Ignored,
+        _ => PriceStatus::Unknown,
+    })
+}
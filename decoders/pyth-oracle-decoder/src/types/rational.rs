@@ -0,0 +1,12 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// An exponential moving average, as `numer / denom`, with `val` its
+/// rounded value.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Rational {
+    pub val: i64,
+    pub numer: i64,
+    pub denom: i64,
+}
@@ -0,0 +1,70 @@
+use {
+    super::PythReceiverDecoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod price_update_v2;
+
+pub enum PythReceiverAccount {
+    PriceUpdateV2(price_update_v2::PriceUpdateV2),
+}
+
+impl AccountDecoder<'_> for PythReceiverDecoder {
+    type AccountType = PythReceiverAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            price_update_v2::PriceUpdateV2::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: PythReceiverAccount::PriceUpdateV2(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Price;
+
+    #[test]
+    fn test_decode_price_update_v2_account() {
+        // Act
+        let decoder = PythReceiverDecoder;
+        let account =
+            carbon_test_utils::read_account("tests/fixtures/price_update_v2_account.json")
+                .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            PythReceiverAccount::PriceUpdateV2(price_update) => {
+                assert!(price_update.is_fully_verified());
+                assert_eq!(
+                    price_update.price(),
+                    Price {
+                        price: 17_325_000_000,
+                        conf: 9_500_000,
+                        exponent: -8,
+                        publish_time: 1_730_000_100,
+                    }
+                );
+                assert_eq!(price_update.price().price_f64(), 173.25);
+                assert_eq!(price_update.posted_slot, 300_000_250);
+            }
+        }
+    }
+}
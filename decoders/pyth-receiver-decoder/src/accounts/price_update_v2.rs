@@ -0,0 +1,54 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+/// A price update posted by the receiver program, holding the latest price
+/// of a feed.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0x22f123639d7ef4cd",
+    deserialize_with = "deserialize_price_update_v2"
+)]
+pub struct PriceUpdateV2 {
+    #[carbon(serialize_as = "base58")]
+    pub write_authority: solana_pubkey::Pubkey,
+    pub verification_level: VerificationLevel,
+    pub price_message: PriceFeedMessage,
+    pub posted_slot: u64,
+}
+
+impl PriceUpdateV2 {
+    /// Returns the price of the update.
+    pub fn price(&self) -> Price {
+        Price {
+            price: self.price_message.price,
+            conf: self.price_message.conf,
+            exponent: self.price_message.exponent,
+            publish_time: self.price_message.publish_time,
+        }
+    }
+
+    /// Returns the exponential moving average of the price.
+    pub fn ema_price(&self) -> Price {
+        Price {
+            price: self.price_message.ema_price,
+            conf: self.price_message.ema_conf,
+            exponent: self.price_message.exponent,
+            publish_time: self.price_message.publish_time,
+        }
+    }
+
+    /// Returns `true` if every guardian signature was verified.
+    pub fn is_fully_verified(&self) -> bool {
+        self.verification_level == VerificationLevel::Full
+    }
+}
+
+/// Accounts are sized for a partial verification level, so a full one leaves
+/// a trailing byte, which is ignored instead of rejected.
+fn deserialize_price_update_v2(data: &[u8]) -> Option<PriceUpdateV2> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
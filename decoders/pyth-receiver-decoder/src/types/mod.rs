@@ -0,0 +1,6 @@
+pub mod price;
+pub use price::*;
+pub mod price_feed_message;
+pub use price_feed_message::*;
+pub mod verification_level;
+pub use verification_level::*;
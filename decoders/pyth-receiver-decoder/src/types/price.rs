@@ -0,0 +1,33 @@
+/// A price and its confidence interval, as integers scaled by
+/// `10^exponent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Price {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    /// The Unix timestamp the price was published at.
+    pub publish_time: i64,
+}
+
+impl Price {
+    /// Returns the price as a float, such as `64.25` for a price of `6425`
+    /// with an exponent of `-2`.
+    pub fn price_f64(&self) -> f64 {
+        scale(self.price as f64, self.exponent)
+    }
+
+    /// Returns the confidence interval as a float.
+    pub fn conf_f64(&self) -> f64 {
+        scale(self.conf as f64, self.exponent)
+    }
+}
+
+fn scale(value: f64, exponent: i32) -> f64 {
+    (0..exponent.unsigned_abs()).fold(value, |value, _| {
+        if exponent < 0 {
+            value / 10.0
+        } else {
+            value * 10.0
+        }
+    })
+}
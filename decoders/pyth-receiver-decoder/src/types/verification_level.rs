@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// How many Wormhole guardian signatures were verified for a price update.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum VerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
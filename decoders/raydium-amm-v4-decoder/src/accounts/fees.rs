@@ -1,9 +1,9 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 pub const FEES_SIZE: usize = core::mem::size_of::<Fees>();
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, serde::Deserialize, CarbonSerialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Fees {
     pub min_separate_numerator: u64,
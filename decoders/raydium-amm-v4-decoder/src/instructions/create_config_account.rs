@@ -1,35 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0e")]
 pub struct CreateConfigAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateConfigAccount")]
 pub struct CreateConfigAccountInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateConfigAccount {
-    type ArrangedAccounts = CreateConfigAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, amm_config, owner, system_program, rent, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CreateConfigAccountInstructionAccounts {
-            admin: admin.pubkey,
-            amm_config: amm_config.pubkey,
-            owner: owner.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01")]
 pub struct Initialize2 {
@@ -11,6 +11,8 @@ pub struct Initialize2 {
     pub init_coin_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Initialize2")]
 pub struct Initialize2InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub spl_associated_token_account: solana_pubkey::Pubkey,
@@ -33,42 +35,5 @@ pub struct Initialize2InstructionAccounts {
     pub user_token_coin: solana_pubkey::Pubkey,
     pub user_token_pc: solana_pubkey::Pubkey,
     pub user_lp_token_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Initialize2 {
-    type ArrangedAccounts = Initialize2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, spl_associated_token_account, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint, coin_mint, pc_mint, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, amm_target_orders, pool_temp_lp, serum_program, serum_market, user_wallet, user_token_coin, user_token_pc, user_lp_token_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(Initialize2InstructionAccounts {
-            token_program: token_program.pubkey,
-            spl_associated_token_account: spl_associated_token_account.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            amm: amm.pubkey,
-            amm_authority: amm_authority.pubkey,
-            amm_open_orders: amm_open_orders.pubkey,
-            lp_mint: lp_mint.pubkey,
-            coin_mint: coin_mint.pubkey,
-            pc_mint: pc_mint.pubkey,
-            pool_coin_token_account: pool_coin_token_account.pubkey,
-            pool_pc_token_account: pool_pc_token_account.pubkey,
-            pool_withdraw_queue: pool_withdraw_queue.pubkey,
-            amm_target_orders: amm_target_orders.pubkey,
-            pool_temp_lp: pool_temp_lp.pubkey,
-            serum_program: serum_program.pubkey,
-            serum_market: serum_market.pubkey,
-            user_wallet: user_wallet.pubkey,
-            user_token_coin: user_token_coin.pubkey,
-            user_token_pc: user_token_pc.pubkey,
-            user_lp_token_account: user_lp_token_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
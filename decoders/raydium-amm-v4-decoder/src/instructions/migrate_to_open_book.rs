@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x05")]
 pub struct MigrateToOpenBook {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MigrateToOpenBook")]
 pub struct MigrateToOpenBookInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
@@ -28,42 +30,5 @@ pub struct MigrateToOpenBookInstructionAccounts {
     pub new_serum_program: solana_pubkey::Pubkey,
     pub new_serum_market: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MigrateToOpenBook {
-    type ArrangedAccounts = MigrateToOpenBookInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, amm_token_coin, amm_token_pc, amm_target_orders, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault, serum_pc_vault, serum_vault_signer, new_amm_open_orders, new_serum_program, new_serum_market, admin, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MigrateToOpenBookInstructionAccounts {
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-            amm: amm.pubkey,
-            amm_authority: amm_authority.pubkey,
-            amm_open_orders: amm_open_orders.pubkey,
-            amm_token_coin: amm_token_coin.pubkey,
-            amm_token_pc: amm_token_pc.pubkey,
-            amm_target_orders: amm_target_orders.pubkey,
-            serum_program: serum_program.pubkey,
-            serum_market: serum_market.pubkey,
-            serum_bids: serum_bids.pubkey,
-            serum_asks: serum_asks.pubkey,
-            serum_event_queue: serum_event_queue.pubkey,
-            serum_coin_vault: serum_coin_vault.pubkey,
-            serum_pc_vault: serum_pc_vault.pubkey,
-            serum_vault_signer: serum_vault_signer.pubkey,
-            new_amm_open_orders: new_amm_open_orders.pubkey,
-            new_serum_program: new_serum_program.pubkey,
-            new_serum_market: new_serum_market.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -50,6 +50,10 @@ pub enum RaydiumAmmV4Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumAmmV4Decoder {
     type InstructionType = RaydiumAmmV4Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
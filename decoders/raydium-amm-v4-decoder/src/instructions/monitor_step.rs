@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x02")]
 pub struct MonitorStep {
@@ -10,6 +10,8 @@ pub struct MonitorStep {
     pub cancel_order_limit: u16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MonitorStep")]
 pub struct MonitorStepInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
@@ -30,40 +32,5 @@ pub struct MonitorStepInstructionAccounts {
     pub serum_event_q: solana_pubkey::Pubkey,
     pub serum_bids: solana_pubkey::Pubkey,
     pub serum_asks: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MonitorStep {
-    type ArrangedAccounts = MonitorStepInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, rent, clock, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_req_q, serum_event_q, serum_bids, serum_asks, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(MonitorStepInstructionAccounts {
-            token_program: token_program.pubkey,
-            rent: rent.pubkey,
-            clock: clock.pubkey,
-            amm: amm.pubkey,
-            amm_authority: amm_authority.pubkey,
-            amm_open_orders: amm_open_orders.pubkey,
-            amm_target_orders: amm_target_orders.pubkey,
-            pool_coin_token_account: pool_coin_token_account.pubkey,
-            pool_pc_token_account: pool_pc_token_account.pubkey,
-            pool_withdraw_queue: pool_withdraw_queue.pubkey,
-            serum_program: serum_program.pubkey,
-            serum_market: serum_market.pubkey,
-            serum_coin_vault_account: serum_coin_vault_account.pubkey,
-            serum_pc_vault_account: serum_pc_vault_account.pubkey,
-            serum_vault_signer: serum_vault_signer.pubkey,
-            serum_req_q: serum_req_q.pubkey,
-            serum_event_q: serum_event_q.pubkey,
-            serum_bids: serum_bids.pubkey,
-            serum_asks: serum_asks.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
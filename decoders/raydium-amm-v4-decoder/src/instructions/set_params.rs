@@ -1,16 +1,17 @@
 use {
     super::super::types::*,
     crate::accounts::fees::Fees,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x06")]
 pub struct SetParams {
     pub param: u8,
     pub value: Option<u64>,
+    #[carbon(serialize_as = "base58")]
     pub new_pubkey: Option<solana_pubkey::Pubkey>,
     pub fees: Option<Fees>,
     pub last_order_distance: Option<LastOrderDistance>,
@@ -35,6 +36,7 @@ pub struct SetParamsInstructionAccounts {
     pub serum_asks: solana_pubkey::Pubkey,
     pub amm_admin_account: solana_pubkey::Pubkey,
     pub new_amm_open_orders_account: Option<solana_pubkey::Pubkey>,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetParams {
@@ -43,12 +45,12 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, amm_coin_vault, amm_pc_vault, serum_program, serum_market, serum_coin_vault, serum_pc_vault, serum_vault_signer, serum_event_queue, serum_bids, serum_asks, amm_admin_account, _remaining @ ..] =
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, amm_coin_vault, amm_pc_vault, serum_program, serum_market, serum_coin_vault, serum_pc_vault, serum_vault_signer, serum_event_queue, serum_bids, serum_asks, amm_admin_account, remaining_accounts @ ..] =
             accounts
         else {
             return None;
         };
-        let new_amm_open_orders_account = accounts.get(16);
+        let new_amm_open_orders_account = remaining_accounts.first();
 
         Some(SetParamsInstructionAccounts {
             token_program: token_program.pubkey,
@@ -68,6 +70,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
             serum_asks: serum_asks.pubkey,
             amm_admin_account: amm_admin_account.pubkey,
             new_amm_open_orders_account: new_amm_open_orders_account.map(|a| a.pubkey),
+            remaining_accounts: remaining_accounts.iter().skip(1).cloned().collect(),
         })
     }
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0c")]
 pub struct SimulateInfo {
@@ -13,6 +13,8 @@ pub struct SimulateInfo {
     pub swap_base_out_value: Option<SwapInstructionBaseOut>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SimulateInfo")]
 pub struct SimulateInfoInstructionAccounts {
     pub amm: solana_pubkey::Pubkey,
     pub amm_authority: solana_pubkey::Pubkey,
@@ -22,29 +24,5 @@ pub struct SimulateInfoInstructionAccounts {
     pub lp_mint_address: solana_pubkey::Pubkey,
     pub serum_market: solana_pubkey::Pubkey,
     pub serum_event_queue: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SimulateInfo {
-    type ArrangedAccounts = SimulateInfoInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, lp_mint_address, serum_market, serum_event_queue, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SimulateInfoInstructionAccounts {
-            amm: amm.pubkey,
-            amm_authority: amm_authority.pubkey,
-            amm_open_orders: amm_open_orders.pubkey,
-            pool_coin_token_account: pool_coin_token_account.pubkey,
-            pool_pc_token_account: pool_pc_token_account.pubkey,
-            lp_mint_address: lp_mint_address.pubkey,
-            serum_market: serum_market.pubkey,
-            serum_event_queue: serum_event_queue.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x09")]
 pub struct SwapBaseIn {
@@ -29,6 +29,7 @@ pub struct SwapBaseInInstructionAccounts {
     pub uer_source_token_account: solana_pubkey::Pubkey,
     pub uer_destination_token_account: solana_pubkey::Pubkey,
     pub user_source_owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
@@ -39,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
     ) -> Option<Self::ArrangedAccounts> {
         match accounts.len() {
             17 => {
-                let [token_program, amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, _remaining @ ..] =
+                let [token_program, amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining_accounts @ ..] =
                     accounts
                 else {
                     return None;
@@ -64,10 +65,11 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
                     uer_source_token_account: uer_source_token_account.pubkey,
                     uer_destination_token_account: uer_destination_token_account.pubkey,
                     user_source_owner: user_source_owner.pubkey,
+                    remaining_accounts: remaining_accounts.to_vec(),
                 })
             }
             18 => {
-                let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, _remaining @ ..] =
+                let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining_accounts @ ..] =
                     accounts
                 else {
                     return None;
@@ -92,6 +94,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
                     uer_source_token_account: uer_source_token_account.pubkey,
                     uer_destination_token_account: uer_destination_token_account.pubkey,
                     user_source_owner: user_source_owner.pubkey,
+                    remaining_accounts: remaining_accounts.to_vec(),
                 })
             }
             _ => None,
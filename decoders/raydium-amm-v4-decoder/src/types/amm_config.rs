@@ -1,10 +1,12 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct AmmConfig {
+    #[carbon(serialize_as = "base58")]
     pub pnl_owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub cancel_owner: solana_pubkey::Pubkey,
     pub pending1: [u64; 28],
     pub pending2: [u64; 32],
@@ -1,12 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct WithdrawDestToken {
     pub withdraw_amount: u64,
     pub coin_amount: u64,
     pub pc_amount: u64,
+    #[carbon(serialize_as = "base58")]
     pub dest_token_coin: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub dest_token_pc: solana_pubkey::Pubkey,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7b86510031446262")]
 pub struct ClosePosition {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ClosePosition")]
 pub struct ClosePositionInstructionAccounts {
     pub nft_owner: solana_pubkey::Pubkey,
     pub position_nft_mint: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct ClosePositionInstructionAccounts {
     pub personal_position: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
-    type ArrangedAccounts = ClosePositionInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, position_nft_mint, position_nft_account, personal_position, system_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ClosePositionInstructionAccounts {
-            nft_owner: nft_owner.pubkey,
-            position_nft_mint: position_nft_mint.pubkey,
-            position_nft_account: position_nft_account.pubkey,
-            personal_position: personal_position.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1da6ae69c051a15369")]
 pub struct CollectPersonalFeeEvent {
+    #[carbon(serialize_as = "base58")]
     pub position_nft_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub recipient_token_account0: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub recipient_token_account1: solana_pubkey::Pubkey,
     pub amount0: u64,
     pub amount1: u64,
@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dce57114f2d29d53d")]
 pub struct CollectProtocolFeeEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool_state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub recipient_token_account0: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub recipient_token_account1: solana_pubkey::Pubkey,
     pub amount0: u64,
     pub amount1: u64,
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x12eda6c52210d590")]
 pub struct CollectRemainingRewards {
     pub reward_index: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CollectRemainingRewards")]
 pub struct CollectRemainingRewardsInstructionAccounts {
     pub reward_funder: solana_pubkey::Pubkey,
     pub funder_token_account: solana_pubkey::Pubkey,
@@ -17,29 +19,5 @@ pub struct CollectRemainingRewardsInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CollectRemainingRewards {
-    type ArrangedAccounts = CollectRemainingRewardsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [reward_funder, funder_token_account, pool_state, reward_token_vault, reward_vault_mint, token_program, token_program2022, memo_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CollectRemainingRewardsInstructionAccounts {
-            reward_funder: reward_funder.pubkey,
-            funder_token_account: funder_token_account.pubkey,
-            pool_state: pool_state.pubkey,
-            reward_token_vault: reward_token_vault.pubkey,
-            reward_vault_mint: reward_vault_mint.pubkey,
-            token_program: token_program.pubkey,
-            token_program2022: token_program2022.pubkey,
-            memo_program: memo_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
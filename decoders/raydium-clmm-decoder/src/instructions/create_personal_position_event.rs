@@ -1,12 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d641e57f9c4df9ace")]
 pub struct CreatePersonalPositionEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool_state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub minter: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub nft_owner: solana_pubkey::Pubkey,
     pub tick_lower_index: i32,
     pub tick_upper_index: i32,
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe992d18ecf6840bc")]
 pub struct CreatePool {
@@ -9,6 +9,8 @@ pub struct CreatePool {
     pub open_time: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreatePool")]
 pub struct CreatePoolInstructionAccounts {
     pub pool_creator: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
@@ -23,34 +25,5 @@ pub struct CreatePoolInstructionAccounts {
     pub token_program1: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreatePool {
-    type ArrangedAccounts = CreatePoolInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool_creator, amm_config, pool_state, token_mint0, token_mint1, token_vault0, token_vault1, observation_state, tick_array_bitmap, token_program0, token_program1, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CreatePoolInstructionAccounts {
-            pool_creator: pool_creator.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            token_mint0: token_mint0.pubkey,
-            token_mint1: token_mint1.pubkey,
-            token_vault0: token_vault0.pubkey,
-            token_vault1: token_vault1.pubkey,
-            observation_state: observation_state.pubkey,
-            tick_array_bitmap: tick_array_bitmap.pubkey,
-            token_program0: token_program0.pubkey,
-            token_program1: token_program1.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
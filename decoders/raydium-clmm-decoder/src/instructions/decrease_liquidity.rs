@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa026d06f685b2c01")]
 pub struct DecreaseLiquidity {
@@ -10,6 +10,8 @@ pub struct DecreaseLiquidity {
     pub amount1_min: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DecreaseLiquidity")]
 pub struct DecreaseLiquidityInstructionAccounts {
     pub nft_owner: solana_pubkey::Pubkey,
     pub nft_account: solana_pubkey::Pubkey,
@@ -23,33 +25,5 @@ pub struct DecreaseLiquidityInstructionAccounts {
     pub recipient_token_account0: solana_pubkey::Pubkey,
     pub recipient_token_account1: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidity {
-    type ArrangedAccounts = DecreaseLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DecreaseLiquidityInstructionAccounts {
-            nft_owner: nft_owner.pubkey,
-            nft_account: nft_account.pubkey,
-            personal_position: personal_position.pubkey,
-            pool_state: pool_state.pubkey,
-            protocol_position: protocol_position.pubkey,
-            token_vault0: token_vault0.pubkey,
-            token_vault1: token_vault1.pubkey,
-            tick_array_lower: tick_array_lower.pubkey,
-            tick_array_upper: tick_array_upper.pubkey,
-            recipient_token_account0: recipient_token_account0.pubkey,
-            recipient_token_account1: recipient_token_account1.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
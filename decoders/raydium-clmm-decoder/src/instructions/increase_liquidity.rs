@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2e9cf3760dcdfbb2")]
 pub struct IncreaseLiquidity {
@@ -10,6 +10,8 @@ pub struct IncreaseLiquidity {
     pub amount1_max: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "IncreaseLiquidity")]
 pub struct IncreaseLiquidityInstructionAccounts {
     pub nft_owner: solana_pubkey::Pubkey,
     pub nft_account: solana_pubkey::Pubkey,
@@ -23,33 +25,5 @@ pub struct IncreaseLiquidityInstructionAccounts {
     pub token_vault0: solana_pubkey::Pubkey,
     pub token_vault1: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidity {
-    type ArrangedAccounts = IncreaseLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, nft_account, pool_state, protocol_position, personal_position, tick_array_lower, tick_array_upper, token_account0, token_account1, token_vault0, token_vault1, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(IncreaseLiquidityInstructionAccounts {
-            nft_owner: nft_owner.pubkey,
-            nft_account: nft_account.pubkey,
-            pool_state: pool_state.pubkey,
-            protocol_position: protocol_position.pubkey,
-            personal_position: personal_position.pubkey,
-            tick_array_lower: tick_array_lower.pubkey,
-            tick_array_upper: tick_array_upper.pubkey,
-            token_account0: token_account0.pubkey,
-            token_account1: token_account1.pubkey,
-            token_vault0: token_vault0.pubkey,
-            token_vault1: token_vault1.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
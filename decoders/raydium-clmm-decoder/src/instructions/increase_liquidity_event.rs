@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d314f69d420221e54")]
 pub struct IncreaseLiquidityEvent {
+    #[carbon(serialize_as = "base58")]
     pub position_nft_mint: solana_pubkey::Pubkey,
     pub liquidity: u128,
     pub amount0: u64,
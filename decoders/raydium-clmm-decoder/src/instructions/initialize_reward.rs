@@ -1,16 +1,18 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5f87c0c4f281e644")]
 pub struct InitializeReward {
     pub param: InitializeRewardParam,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeReward")]
 pub struct InitializeRewardInstructionAccounts {
     pub reward_funder: solana_pubkey::Pubkey,
     pub funder_token_account: solana_pubkey::Pubkey,
@@ -22,31 +24,5 @@ pub struct InitializeRewardInstructionAccounts {
     pub reward_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
-    type ArrangedAccounts = InitializeRewardInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [reward_funder, funder_token_account, amm_config, pool_state, operation_state, reward_token_mint, reward_token_vault, reward_token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeRewardInstructionAccounts {
-            reward_funder: reward_funder.pubkey,
-            funder_token_account: funder_token_account.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            operation_state: operation_state.pubkey,
-            reward_token_mint: reward_token_mint.pubkey,
-            reward_token_vault: reward_token_vault.pubkey,
-            reward_token_program: reward_token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d7ef0afce9e58996b")]
 pub struct LiquidityChangeEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool_state: solana_pubkey::Pubkey,
     pub tick: i32,
     pub tick_lower: i32,
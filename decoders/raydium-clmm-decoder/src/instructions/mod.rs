@@ -3,30 +3,40 @@ use crate::PROGRAM_ID;
 use super::RaydiumClmmDecoder;
 pub mod close_position;
 pub mod collect_fund_fee;
+#[cfg(feature = "events")]
 pub mod collect_personal_fee_event;
 pub mod collect_protocol_fee;
+#[cfg(feature = "events")]
 pub mod collect_protocol_fee_event;
 pub mod collect_remaining_rewards;
+#[cfg(feature = "events")]
 pub mod config_change_event;
 pub mod create_amm_config;
 pub mod create_operation_account;
+#[cfg(feature = "events")]
 pub mod create_personal_position_event;
 pub mod create_pool;
 pub mod decrease_liquidity;
+#[cfg(feature = "events")]
 pub mod decrease_liquidity_event;
 pub mod decrease_liquidity_v2;
 pub mod increase_liquidity;
+#[cfg(feature = "events")]
 pub mod increase_liquidity_event;
 pub mod increase_liquidity_v2;
 pub mod initialize_reward;
+#[cfg(feature = "events")]
 pub mod liquidity_calculate_event;
+#[cfg(feature = "events")]
 pub mod liquidity_change_event;
 pub mod open_position;
 pub mod open_position_v2;
 pub mod open_position_with_token22_nft;
+#[cfg(feature = "events")]
 pub mod pool_created_event;
 pub mod set_reward_params;
 pub mod swap;
+#[cfg(feature = "events")]
 pub mod swap_event;
 pub mod swap_router_base_in;
 pub mod swap_v2;
@@ -35,6 +45,7 @@ pub mod update_amm_config;
 pub mod update_operation_account;
 pub mod update_pool_status;
 pub mod update_reward_infos;
+#[cfg(feature = "events")]
 pub mod update_reward_infos_event;
 
 #[derive(
@@ -72,22 +83,37 @@ pub enum RaydiumClmmInstruction {
     Swap(swap::Swap),
     SwapV2(swap_v2::SwapV2),
     SwapRouterBaseIn(swap_router_base_in::SwapRouterBaseIn),
+    #[cfg(feature = "events")]
     ConfigChangeEvent(config_change_event::ConfigChangeEvent),
+    #[cfg(feature = "events")]
     CreatePersonalPositionEvent(create_personal_position_event::CreatePersonalPositionEvent),
+    #[cfg(feature = "events")]
     IncreaseLiquidityEvent(increase_liquidity_event::IncreaseLiquidityEvent),
+    #[cfg(feature = "events")]
     DecreaseLiquidityEvent(decrease_liquidity_event::DecreaseLiquidityEvent),
+    #[cfg(feature = "events")]
     LiquidityCalculateEvent(liquidity_calculate_event::LiquidityCalculateEvent),
+    #[cfg(feature = "events")]
     CollectPersonalFeeEvent(collect_personal_fee_event::CollectPersonalFeeEvent),
+    #[cfg(feature = "events")]
     UpdateRewardInfosEvent(update_reward_infos_event::UpdateRewardInfosEvent),
+    #[cfg(feature = "events")]
     PoolCreatedEvent(pool_created_event::PoolCreatedEvent),
+    #[cfg(feature = "events")]
     CollectProtocolFeeEvent(collect_protocol_fee_event::CollectProtocolFeeEvent),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
+    #[cfg(feature = "events")]
     LiquidityChangeEvent(liquidity_change_event::LiquidityChangeEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumClmmDecoder {
     type InstructionType = RaydiumClmmInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -121,16 +147,27 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumClmmDecoder {
             RaydiumClmmInstruction::Swap => swap::Swap,
             RaydiumClmmInstruction::SwapV2 => swap_v2::SwapV2,
             RaydiumClmmInstruction::SwapRouterBaseIn => swap_router_base_in::SwapRouterBaseIn,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::ConfigChangeEvent => config_change_event::ConfigChangeEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::CreatePersonalPositionEvent => create_personal_position_event::CreatePersonalPositionEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::IncreaseLiquidityEvent => increase_liquidity_event::IncreaseLiquidityEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::DecreaseLiquidityEvent => decrease_liquidity_event::DecreaseLiquidityEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::LiquidityCalculateEvent => liquidity_calculate_event::LiquidityCalculateEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::CollectPersonalFeeEvent => collect_personal_fee_event::CollectPersonalFeeEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::UpdateRewardInfosEvent => update_reward_infos_event::UpdateRewardInfosEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::PoolCreatedEvent => pool_created_event::PoolCreatedEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::CollectProtocolFeeEvent => collect_protocol_fee_event::CollectProtocolFeeEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::SwapEvent => swap_event::SwapEvent,
+            #[cfg(feature = "events")]
             RaydiumClmmInstruction::LiquidityChangeEvent => liquidity_change_event::LiquidityChangeEvent,
         )
     }
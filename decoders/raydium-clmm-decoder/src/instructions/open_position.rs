@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x87802f4d0f98f031")]
 pub struct OpenPosition {
@@ -14,6 +14,8 @@ pub struct OpenPosition {
     pub amount1_max: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OpenPosition")]
 pub struct OpenPositionInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub position_nft_owner: solana_pubkey::Pubkey,
@@ -34,40 +36,5 @@ pub struct OpenPositionInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OpenPosition {
-    type ArrangedAccounts = OpenPositionInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, position_nft_owner, position_nft_mint, position_nft_account, metadata_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, metadata_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(OpenPositionInstructionAccounts {
-            payer: payer.pubkey,
-            position_nft_owner: position_nft_owner.pubkey,
-            position_nft_mint: position_nft_mint.pubkey,
-            position_nft_account: position_nft_account.pubkey,
-            metadata_account: metadata_account.pubkey,
-            pool_state: pool_state.pubkey,
-            protocol_position: protocol_position.pubkey,
-            tick_array_lower: tick_array_lower.pubkey,
-            tick_array_upper: tick_array_upper.pubkey,
-            personal_position: personal_position.pubkey,
-            token_account0: token_account0.pubkey,
-            token_account1: token_account1.pubkey,
-            token_vault0: token_vault0.pubkey,
-            token_vault1: token_vault1.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            metadata_program: metadata_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,21 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d195e4b2f7063353f")]
 pub struct PoolCreatedEvent {
+    #[carbon(serialize_as = "base58")]
     pub token_mint0: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_mint1: solana_pubkey::Pubkey,
     pub tick_spacing: u16,
+    #[carbon(serialize_as = "base58")]
     pub pool_state: solana_pubkey::Pubkey,
     pub sqrt_price_x64: u128,
     pub tick: i32,
+    #[carbon(serialize_as = "base58")]
     pub token_vault0: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_vault1: solana_pubkey::Pubkey,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7034a74b20c9d389")]
 pub struct SetRewardParams {
@@ -11,6 +11,8 @@ pub struct SetRewardParams {
     pub end_time: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetRewardParams")]
 pub struct SetRewardParamsInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
@@ -18,27 +20,5 @@ pub struct SetRewardParamsInstructionAccounts {
     pub operation_state: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetRewardParams {
-    type ArrangedAccounts = SetRewardParamsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, amm_config, pool_state, operation_state, token_program, token_program2022, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SetRewardParamsInstructionAccounts {
-            authority: authority.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            operation_state: operation_state.pubkey,
-            token_program: token_program.pubkey,
-            token_program2022: token_program2022.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf8c69e91e17587c8")]
 pub struct Swap {
@@ -12,6 +12,8 @@ pub struct Swap {
     pub is_base_input: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Swap")]
 pub struct SwapInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
@@ -25,31 +27,3 @@ pub struct SwapInstructionAccounts {
     pub tick_array: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for Swap {
-    type ArrangedAccounts = SwapInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, observation_state, token_program, tick_array, remaining_accounts @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapInstructionAccounts {
-            payer: payer.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            input_token_account: input_token_account.pubkey,
-            output_token_account: output_token_account.pubkey,
-            input_vault: input_vault.pubkey,
-            output_vault: output_vault.pubkey,
-            observation_state: observation_state.pubkey,
-            token_program: token_program.pubkey,
-            tick_array: tick_array.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -1,13 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d40c6cde8260871e2")]
 pub struct SwapEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool_state: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub sender: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_account0: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_account1: solana_pubkey::Pubkey,
     pub amount0: u64,
     pub transfer_fee0: u64,
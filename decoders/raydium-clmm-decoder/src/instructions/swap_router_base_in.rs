@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x457d73daf5baf2c4")]
 pub struct SwapRouterBaseIn {
@@ -10,6 +10,8 @@ pub struct SwapRouterBaseIn {
     pub amount_out_minimum: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SwapRouterBaseIn")]
 pub struct SwapRouterBaseInInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub input_token_account: solana_pubkey::Pubkey,
@@ -19,27 +21,3 @@ pub struct SwapRouterBaseInInstructionAccounts {
     pub memo_program: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for SwapRouterBaseIn {
-    type ArrangedAccounts = SwapRouterBaseInInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, input_token_account, input_token_mint, token_program, token_program2022, memo_program, remaining_accounts @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapRouterBaseInInstructionAccounts {
-            payer: payer.pubkey,
-            input_token_account: input_token_account.pubkey,
-            input_token_mint: input_token_mint.pubkey,
-            token_program: token_program.pubkey,
-            token_program2022: token_program2022.pubkey,
-            memo_program: memo_program.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
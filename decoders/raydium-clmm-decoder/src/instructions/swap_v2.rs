@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2b04ed0b1ac91e62")]
 pub struct SwapV2 {
@@ -12,7 +12,8 @@ pub struct SwapV2 {
     pub is_base_input: bool,
 }
 
-#[derive(Debug)]
+#[derive(carbon_core::ArrangeAccounts, Debug)]
+#[carbon(instruction = "SwapV2")]
 pub struct SwapV2InstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
@@ -29,34 +30,3 @@ pub struct SwapV2InstructionAccounts {
     pub output_vault_mint: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
-    type ArrangedAccounts = SwapV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, observation_state, token_program, token_program2022, memo_program, input_vault_mint, output_vault_mint, remaining_accounts @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapV2InstructionAccounts {
-            payer: payer.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            input_token_account: input_token_account.pubkey,
-            output_token_account: output_token_account.pubkey,
-            input_vault: input_vault.pubkey,
-            output_vault: output_vault.pubkey,
-            observation_state: observation_state.pubkey,
-            token_program: token_program.pubkey,
-            token_program2022: token_program2022.pubkey,
-            memo_program: memo_program.pubkey,
-            input_vault_mint: input_vault_mint.pubkey,
-            output_vault_mint: output_vault_mint.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
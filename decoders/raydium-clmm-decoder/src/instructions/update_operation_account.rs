@@ -1,35 +1,21 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7f467728bce33d07")]
 pub struct UpdateOperationAccount {
     pub param: u8,
+    #[carbon(serialize_as = "base58")]
     pub keys: Vec<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateOperationAccount")]
 pub struct UpdateOperationAccountInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub operation_state: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateOperationAccount {
-    type ArrangedAccounts = UpdateOperationAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, operation_state, system_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateOperationAccountInstructionAccounts {
-            owner: owner.pubkey,
-            operation_state: operation_state.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
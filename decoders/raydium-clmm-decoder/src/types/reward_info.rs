@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct RewardInfo {
     pub reward_state: u8,
@@ -11,8 +11,11 @@ pub struct RewardInfo {
     pub emissions_per_second_x64: u128,
     pub reward_total_emissioned: u64,
     pub reward_claimed: u64,
+    #[carbon(serialize_as = "base58")]
     pub token_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub token_vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub authority: solana_pubkey::Pubkey,
     pub reward_growth_global_x64: u128,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xafaf6d1f0d989bed")]
 pub struct Initialize {
@@ -10,6 +10,8 @@ pub struct Initialize {
     pub open_time: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Initialize")]
 pub struct InitializeInstructionAccounts {
     pub creator: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
@@ -31,41 +33,5 @@ pub struct InitializeInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Initialize {
-    type ArrangedAccounts = InitializeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [creator, amm_config, authority, pool_state, token0_mint, token1_mint, lp_mint, creator_token0, creator_token1, creator_lp_token, token0_vault, token1_vault, create_pool_fee, observation_state, token_program, token0_program, token1_program, associated_token_program, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeInstructionAccounts {
-            creator: creator.pubkey,
-            amm_config: amm_config.pubkey,
-            authority: authority.pubkey,
-            pool_state: pool_state.pubkey,
-            token0_mint: token0_mint.pubkey,
-            token1_mint: token1_mint.pubkey,
-            lp_mint: lp_mint.pubkey,
-            creator_token0: creator_token0.pubkey,
-            creator_token1: creator_token1.pubkey,
-            creator_lp_token: creator_lp_token.pubkey,
-            token0_vault: token0_vault.pubkey,
-            token1_vault: token1_vault.pubkey,
-            create_pool_fee: create_pool_fee.pubkey,
-            observation_state: observation_state.pubkey,
-            token_program: token_program.pubkey,
-            token0_program: token0_program.pubkey,
-            token1_program: token1_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d79a3cdc939da753c")]
 pub struct LpChangeEvent {
+    #[carbon(serialize_as = "base58")]
     pub pool_id: solana_pubkey::Pubkey,
     pub lp_amount_before: u64,
     pub token0_vault_before: u64,
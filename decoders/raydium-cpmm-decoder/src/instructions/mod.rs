@@ -6,9 +6,11 @@ pub mod collect_protocol_fee;
 pub mod create_amm_config;
 pub mod deposit;
 pub mod initialize;
+#[cfg(feature = "events")]
 pub mod lp_change_event;
 pub mod swap_base_input;
 pub mod swap_base_output;
+#[cfg(feature = "events")]
 pub mod swap_event;
 pub mod update_amm_config;
 pub mod update_pool_status;
@@ -35,13 +37,19 @@ pub enum RaydiumCpmmInstruction {
     Withdraw(withdraw::Withdraw),
     SwapBaseInput(swap_base_input::SwapBaseInput),
     SwapBaseOutput(swap_base_output::SwapBaseOutput),
+    #[cfg(feature = "events")]
     LpChangeEvent(lp_change_event::LpChangeEvent),
+    #[cfg(feature = "events")]
     SwapEvent(swap_event::SwapEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumCpmmDecoder {
     type InstructionType = RaydiumCpmmInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -61,7 +69,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumCpmmDecoder {
             RaydiumCpmmInstruction::Withdraw => withdraw::Withdraw,
             RaydiumCpmmInstruction::SwapBaseInput => swap_base_input::SwapBaseInput,
             RaydiumCpmmInstruction::SwapBaseOutput => swap_base_output::SwapBaseOutput,
+            #[cfg(feature = "events")]
             RaydiumCpmmInstruction::LpChangeEvent => lp_change_event::LpChangeEvent,
+            #[cfg(feature = "events")]
             RaydiumCpmmInstruction::SwapEvent => swap_event::SwapEvent,
         )
     }
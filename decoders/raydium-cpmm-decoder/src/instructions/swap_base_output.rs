@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x37d96256a34ab4ad")]
 pub struct SwapBaseOutput {
@@ -9,6 +9,8 @@ pub struct SwapBaseOutput {
     pub amount_out: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SwapBaseOutput")]
 pub struct SwapBaseOutputInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -23,34 +25,5 @@ pub struct SwapBaseOutputInstructionAccounts {
     pub input_token_mint: solana_pubkey::Pubkey,
     pub output_token_mint: solana_pubkey::Pubkey,
     pub observation_state: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SwapBaseOutput {
-    type ArrangedAccounts = SwapBaseOutputInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, authority, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, input_token_program, output_token_program, input_token_mint, output_token_mint, observation_state, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapBaseOutputInstructionAccounts {
-            payer: payer.pubkey,
-            authority: authority.pubkey,
-            amm_config: amm_config.pubkey,
-            pool_state: pool_state.pubkey,
-            input_token_account: input_token_account.pubkey,
-            output_token_account: output_token_account.pubkey,
-            input_vault: input_vault.pubkey,
-            output_vault: output_vault.pubkey,
-            input_token_program: input_token_program.pubkey,
-            output_token_program: output_token_program.pubkey,
-            input_token_mint: input_token_mint.pubkey,
-            output_token_mint: output_token_mint.pubkey,
-            observation_state: observation_state.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -3,7 +3,9 @@
 use solana_pubkey::Pubkey;
 
 pub struct RaydiumCpmmDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
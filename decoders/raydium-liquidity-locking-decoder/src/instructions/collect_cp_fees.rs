@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x081e33c7d1b8f785")]
 pub struct CollectCpFees {
     pub fee_lp_amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CollectCpFees")]
 pub struct CollectCpFeesInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub fee_nft_owner: solana_pubkey::Pubkey,
@@ -27,39 +29,5 @@ pub struct CollectCpFeesInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CollectCpFees {
-    type ArrangedAccounts = CollectCpFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, fee_nft_owner, fee_nft_account, locked_liquidity, cp_swap_program, cp_authority, pool_state, lp_mint, recipient_token0_account, recipient_token1_account, token0_vault, token1_vault, vault0_mint, vault1_mint, locked_lp_vault, token_program, token_program2022, memo_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CollectCpFeesInstructionAccounts {
-            authority: authority.pubkey,
-            fee_nft_owner: fee_nft_owner.pubkey,
-            fee_nft_account: fee_nft_account.pubkey,
-            locked_liquidity: locked_liquidity.pubkey,
-            cp_swap_program: cp_swap_program.pubkey,
-            cp_authority: cp_authority.pubkey,
-            pool_state: pool_state.pubkey,
-            lp_mint: lp_mint.pubkey,
-            recipient_token0_account: recipient_token0_account.pubkey,
-            recipient_token1_account: recipient_token1_account.pubkey,
-            token0_vault: token0_vault.pubkey,
-            token1_vault: token1_vault.pubkey,
-            vault0_mint: vault0_mint.pubkey,
-            vault1_mint: vault1_mint.pubkey,
-            locked_lp_vault: locked_lp_vault.pubkey,
-            token_program: token_program.pubkey,
-            token_program2022: token_program2022.pubkey,
-            memo_program: memo_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd89d1d4e26331f1a")]
 pub struct LockCpLiquidity {
@@ -9,6 +9,8 @@ pub struct LockCpLiquidity {
     pub with_metadata: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LockCpLiquidity")]
 pub struct LockCpLiquidityInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
@@ -29,40 +31,5 @@ pub struct LockCpLiquidityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LockCpLiquidity {
-    type ArrangedAccounts = LockCpLiquidityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, payer, liquidity_owner, fee_nft_owner, fee_nft_mint, fee_nft_account, pool_state, locked_liquidity, lp_mint, liquidity_owner_lp, locked_lp_vault, token0_vault, token1_vault, metadata_account, rent, system_program, token_program, associated_token_program, metadata_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LockCpLiquidityInstructionAccounts {
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            liquidity_owner: liquidity_owner.pubkey,
-            fee_nft_owner: fee_nft_owner.pubkey,
-            fee_nft_mint: fee_nft_mint.pubkey,
-            fee_nft_account: fee_nft_account.pubkey,
-            pool_state: pool_state.pubkey,
-            locked_liquidity: locked_liquidity.pubkey,
-            lp_mint: lp_mint.pubkey,
-            liquidity_owner_lp: liquidity_owner_lp.pubkey,
-            locked_lp_vault: locked_lp_vault.pubkey,
-            token0_vault: token0_vault.pubkey,
-            token1_vault: token1_vault.pubkey,
-            metadata_account: metadata_account.pubkey,
-            rent: rent.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            metadata_program: metadata_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
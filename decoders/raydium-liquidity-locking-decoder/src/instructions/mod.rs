@@ -5,6 +5,7 @@ pub mod collect_clmm_fees_and_rewards;
 pub mod collect_cp_fees;
 pub mod lock_clmm_position;
 pub mod lock_cp_liquidity;
+#[cfg(feature = "events")]
 pub mod settle_cp_fee_event;
 
 #[derive(
@@ -22,12 +23,17 @@ pub enum RaydiumLiquidityLockingInstruction {
     CollectClmmFeesAndRewards(collect_clmm_fees_and_rewards::CollectClmmFeesAndRewards),
     LockCpLiquidity(lock_cp_liquidity::LockCpLiquidity),
     CollectCpFees(collect_cp_fees::CollectCpFees),
+    #[cfg(feature = "events")]
     SettleCpFeeEvent(settle_cp_fee_event::SettleCpFeeEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumLiquidityLockingDecoder {
     type InstructionType = RaydiumLiquidityLockingInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -41,6 +47,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumLiquidityLockin
             RaydiumLiquidityLockingInstruction::CollectClmmFeesAndRewards => collect_clmm_fees_and_rewards::CollectClmmFeesAndRewards,
             RaydiumLiquidityLockingInstruction::LockCpLiquidity => lock_cp_liquidity::LockCpLiquidity,
             RaydiumLiquidityLockingInstruction::CollectCpFees => collect_cp_fees::CollectCpFees,
+            #[cfg(feature = "events")]
             RaydiumLiquidityLockingInstruction::SettleCpFeeEvent => settle_cp_fee_event::SettleCpFeeEvent,
         )
     }
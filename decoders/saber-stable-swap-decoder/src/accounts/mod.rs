@@ -0,0 +1,74 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::SaberStableSwapDecoder;
+pub mod swap_info;
+
+pub enum SaberStableSwapAccount {
+    SwapInfo(swap_info::SwapInfo),
+}
+
+impl AccountDecoder<'_> for SaberStableSwapDecoder {
+    type AccountType = SaberStableSwapAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if account.data.len() != swap_info::SWAP_INFO_SIZE {
+            return None;
+        }
+
+        let decoded_account = swap_info::SwapInfo::deserialize(account.data.as_slice())?;
+        Some(carbon_core::account::DecodedAccount {
+            lamports: account.lamports,
+            data: SaberStableSwapAccount::SwapInfo(decoded_account),
+            owner: account.owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_swap_info_account() {
+        // Act
+        let decoder = SaberStableSwapDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/swap_info_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            SaberStableSwapAccount::SwapInfo(swap_info) => {
+                assert!(swap_info.is_initialized);
+                assert!(!swap_info.is_paused);
+                assert_eq!(swap_info.initial_amp_factor, 100);
+                assert_eq!(swap_info.target_amp_factor, 100);
+                assert_eq!(
+                    swap_info.token_a_mint,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+                    )
+                );
+                assert_eq!(
+                    swap_info.token_b_mint,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"
+                    )
+                );
+                assert_eq!(swap_info.fees.trade_fee_numerator, 4);
+                assert_eq!(swap_info.fees.trade_fee_denominator, 10_000);
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+/// The packed size of a swap account. Fields are packed without padding, so
+/// this differs from the size of the struct.
+pub const SWAP_INFO_SIZE: usize = 395;
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct SwapInfo {
+    pub is_initialized: bool,
+    pub is_paused: bool,
+    pub nonce: u8,
+    pub initial_amp_factor: u64,
+    pub target_amp_factor: u64,
+    pub start_ramp_ts: i64,
+    pub stop_ramp_ts: i64,
+    pub future_admin_deadline: i64,
+    #[carbon(serialize_as = "base58")]
+    pub future_admin_key: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub admin_key: solana_pubkey::Pubkey,
+    /// The reserve token account of token A.
+    #[carbon(serialize_as = "base58")]
+    pub token_a: solana_pubkey::Pubkey,
+    /// The reserve token account of token B.
+    #[carbon(serialize_as = "base58")]
+    pub token_b: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub pool_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_a_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub token_b_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub admin_fee_key_a: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub admin_fee_key_b: solana_pubkey::Pubkey,
+    pub fees: Fees,
+}
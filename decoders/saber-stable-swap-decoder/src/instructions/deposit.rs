@@ -0,0 +1,27 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x02")]
+pub struct Deposit {
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub min_mint_amount: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Deposit")]
+pub struct DepositInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub swap_authority: solana_pubkey::Pubkey,
+    pub user_authority: solana_pubkey::Pubkey,
+    pub user_token_a: solana_pubkey::Pubkey,
+    pub user_token_b: solana_pubkey::Pubkey,
+    pub pool_token_a: solana_pubkey::Pubkey,
+    pub pool_token_b: solana_pubkey::Pubkey,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub output_lp: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,32 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x00")]
+pub struct Initialize {
+    pub nonce: u8,
+    pub amp_factor: u64,
+    pub fees: Fees,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Initialize")]
+pub struct InitializeInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub swap_authority: solana_pubkey::Pubkey,
+    pub admin: solana_pubkey::Pubkey,
+    pub admin_fee_a: solana_pubkey::Pubkey,
+    pub admin_fee_b: solana_pubkey::Pubkey,
+    pub token_a_mint: solana_pubkey::Pubkey,
+    pub token_a: solana_pubkey::Pubkey,
+    pub token_b_mint: solana_pubkey::Pubkey,
+    pub token_b: solana_pubkey::Pubkey,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub output_lp: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,195 @@
+use crate::PROGRAM_ID;
+
+use super::SaberStableSwapDecoder;
+pub mod apply_new_admin;
+pub mod commit_new_admin;
+pub mod deposit;
+pub mod initialize;
+pub mod pause;
+pub mod ramp_a;
+pub mod set_fee_account;
+pub mod set_new_fees;
+pub mod stop_ramp_a;
+pub mod swap;
+pub mod unpause;
+pub mod withdraw;
+pub mod withdraw_one;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SaberStableSwapInstruction {
+    Initialize(initialize::Initialize),
+    Swap(swap::Swap),
+    Deposit(deposit::Deposit),
+    Withdraw(withdraw::Withdraw),
+    WithdrawOne(withdraw_one::WithdrawOne),
+    RampA(ramp_a::RampA),
+    StopRampA(stop_ramp_a::StopRampA),
+    Pause(pause::Pause),
+    Unpause(unpause::Unpause),
+    SetFeeAccount(set_fee_account::SetFeeAccount),
+    ApplyNewAdmin(apply_new_admin::ApplyNewAdmin),
+    CommitNewAdmin(commit_new_admin::CommitNewAdmin),
+    SetNewFees(set_new_fees::SetNewFees),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SaberStableSwapDecoder {
+    type InstructionType = SaberStableSwapInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SaberStableSwapInstruction::Initialize => initialize::Initialize,
+            SaberStableSwapInstruction::Swap => swap::Swap,
+            SaberStableSwapInstruction::Deposit => deposit::Deposit,
+            SaberStableSwapInstruction::Withdraw => withdraw::Withdraw,
+            SaberStableSwapInstruction::WithdrawOne => withdraw_one::WithdrawOne,
+            SaberStableSwapInstruction::RampA => ramp_a::RampA,
+            SaberStableSwapInstruction::StopRampA => stop_ramp_a::StopRampA,
+            SaberStableSwapInstruction::Pause => pause::Pause,
+            SaberStableSwapInstruction::Unpause => unpause::Unpause,
+            SaberStableSwapInstruction::SetFeeAccount => set_fee_account::SetFeeAccount,
+            SaberStableSwapInstruction::ApplyNewAdmin => apply_new_admin::ApplyNewAdmin,
+            SaberStableSwapInstruction::CommitNewAdmin => commit_new_admin::CommitNewAdmin,
+            SaberStableSwapInstruction::SetNewFees => set_new_fees::SetNewFees,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_swap() {
+        // Arrange
+        let expected_ix = SaberStableSwapInstruction::Swap(swap::Swap {
+            amount_in: 1_000_000,
+            minimum_amount_out: 995_000,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "YAkoNb6HKmSxQN9L8hiBE5tPJRsniSSMzND1boHmZxe",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "5C1k9yV7y4CjMnKv8eGYDgWND8P89Pdfj79Trk2qmfGo",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7GmDnvXcbmT5B9yoYiYb3UU6S2F1WFvhHNVfj6tgXozV",
+                ),
+                true,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3ne4mWqdYuNiYrYZC9TrA3FcfuFdErghH97vNPbjicr1",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CfWX7o2TswwbxusJ4hCaPobu2jLCb1hfXuXJQjVq3jQF",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "EnTrdMMpdhugeH6Ban6gYZWXughWxKtVGfCwFn78ZmY3",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "8yUAgTXQWgpaxK2Ts4ArsvB1LgAvUyVp8WPBAp36amRs",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "XgPzM3S9XKeLMRrcEuoG4jXW7RZQfhs6RUoewGUPvvn",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                ),
+                false,
+            ),
+        ];
+        let expected_arranged_accounts = swap::SwapInstructionAccounts {
+            swap: solana_pubkey::Pubkey::from_str_const(
+                "YAkoNb6HKmSxQN9L8hiBE5tPJRsniSSMzND1boHmZxe",
+            ),
+            swap_authority: solana_pubkey::Pubkey::from_str_const(
+                "5C1k9yV7y4CjMnKv8eGYDgWND8P89Pdfj79Trk2qmfGo",
+            ),
+            user_authority: solana_pubkey::Pubkey::from_str_const(
+                "7GmDnvXcbmT5B9yoYiYb3UU6S2F1WFvhHNVfj6tgXozV",
+            ),
+            user_source: solana_pubkey::Pubkey::from_str_const(
+                "3ne4mWqdYuNiYrYZC9TrA3FcfuFdErghH97vNPbjicr1",
+            ),
+            pool_source: solana_pubkey::Pubkey::from_str_const(
+                "CfWX7o2TswwbxusJ4hCaPobu2jLCb1hfXuXJQjVq3jQF",
+            ),
+            pool_destination: solana_pubkey::Pubkey::from_str_const(
+                "EnTrdMMpdhugeH6Ban6gYZWXughWxKtVGfCwFn78ZmY3",
+            ),
+            user_destination: solana_pubkey::Pubkey::from_str_const(
+                "8yUAgTXQWgpaxK2Ts4ArsvB1LgAvUyVp8WPBAp36amRs",
+            ),
+            admin_fee_destination: solana_pubkey::Pubkey::from_str_const(
+                "XgPzM3S9XKeLMRrcEuoG4jXW7RZQfhs6RUoewGUPvvn",
+            ),
+            token_program: solana_pubkey::Pubkey::from_str_const(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = SaberStableSwapDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/swap_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            swap::Swap::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x64")]
+pub struct RampA {
+    pub target_amp: u64,
+    pub stop_ramp_ts: i64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "RampA")]
+pub struct RampAInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x68")]
+pub struct SetFeeAccount {}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "SetFeeAccount")]
+pub struct SetFeeAccountInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub admin: solana_pubkey::Pubkey,
+    pub new_fee_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
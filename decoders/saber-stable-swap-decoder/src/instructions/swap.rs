@@ -0,0 +1,25 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x01")]
+pub struct Swap {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Swap")]
+pub struct SwapInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub swap_authority: solana_pubkey::Pubkey,
+    pub user_authority: solana_pubkey::Pubkey,
+    pub user_source: solana_pubkey::Pubkey,
+    pub pool_source: solana_pubkey::Pubkey,
+    pub pool_destination: solana_pubkey::Pubkey,
+    pub user_destination: solana_pubkey::Pubkey,
+    pub admin_fee_destination: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,29 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x03")]
+pub struct Withdraw {
+    pub pool_token_amount: u64,
+    pub minimum_token_a_amount: u64,
+    pub minimum_token_b_amount: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "Withdraw")]
+pub struct WithdrawInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub swap_authority: solana_pubkey::Pubkey,
+    pub user_authority: solana_pubkey::Pubkey,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub input_lp: solana_pubkey::Pubkey,
+    pub pool_token_a: solana_pubkey::Pubkey,
+    pub pool_token_b: solana_pubkey::Pubkey,
+    pub user_token_a: solana_pubkey::Pubkey,
+    pub user_token_b: solana_pubkey::Pubkey,
+    pub admin_fee_a: solana_pubkey::Pubkey,
+    pub admin_fee_b: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
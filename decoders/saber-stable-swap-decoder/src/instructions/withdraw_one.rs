@@ -0,0 +1,26 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x04")]
+pub struct WithdrawOne {
+    pub pool_token_amount: u64,
+    pub minimum_token_amount: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "WithdrawOne")]
+pub struct WithdrawOneInstructionAccounts {
+    pub swap: solana_pubkey::Pubkey,
+    pub swap_authority: solana_pubkey::Pubkey,
+    pub user_authority: solana_pubkey::Pubkey,
+    pub pool_mint: solana_pubkey::Pubkey,
+    pub input_lp: solana_pubkey::Pubkey,
+    pub pool_base_token: solana_pubkey::Pubkey,
+    pub pool_quote_token: solana_pubkey::Pubkey,
+    pub user_destination: solana_pubkey::Pubkey,
+    pub admin_fee_destination: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
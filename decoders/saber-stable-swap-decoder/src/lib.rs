@@ -0,0 +1,15 @@
+#![no_std]
+
+extern crate alloc;
+use solana_pubkey::Pubkey;
+
+pub struct SaberStableSwapDecoder;
+
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ");
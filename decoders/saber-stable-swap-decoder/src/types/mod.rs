@@ -0,0 +1,2 @@
+pub mod fees;
+pub use fees::*;
@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x23087952da4efca2")]
 pub struct CloseNftList {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseNftList")]
 pub struct CloseNftListInstructionAccounts {
     pub nft_list: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseNftList {
-    type ArrangedAccounts = CloseNftListInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nft_list, payer, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CloseNftListInstructionAccounts {
-            nft_list: nft_list.pubkey,
-            payer: payer.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
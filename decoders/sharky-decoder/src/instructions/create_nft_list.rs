@@ -1,31 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf326c64cac407f18")]
 pub struct CreateNftList {
     pub collection_name: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateNftList")]
 pub struct CreateNftListInstructionAccounts {
     pub nft_list: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateNftList {
-    type ArrangedAccounts = CreateNftListInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nft_list, payer, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CreateNftListInstructionAccounts {
-            nft_list: nft_list.pubkey,
-            payer: payer.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
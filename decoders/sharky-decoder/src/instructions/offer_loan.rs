@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2c0c4c90d2d0ef55")]
 pub struct OfferLoan {
@@ -13,6 +13,8 @@ pub struct OfferLoan {
     pub terms_choice: Option<LoanTermsSpec>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "OfferLoan")]
 pub struct OfferLoanInstructionAccounts {
     pub lender: solana_pubkey::Pubkey,
     pub lender_value_token_account: solana_pubkey::Pubkey,
@@ -25,32 +27,5 @@ pub struct OfferLoanInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for OfferLoan {
-    type ArrangedAccounts = OfferLoanInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lender, lender_value_token_account, value_mint, loan, escrow, escrow_token_account, order_book, system_program, token_program, associated_token_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(OfferLoanInstructionAccounts {
-            lender: lender.pubkey,
-            lender_value_token_account: lender_value_token_account.pubkey,
-            value_mint: value_mint.pubkey,
-            loan: loan.pubkey,
-            escrow: escrow.pubkey,
-            escrow_token_account: escrow_token_account.pubkey,
-            order_book: order_book.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
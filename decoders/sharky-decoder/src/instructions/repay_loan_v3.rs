@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x617b55364c103d9d")]
 pub struct RepayLoanV3 {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RepayLoanV3")]
 pub struct RepayLoanV3InstructionAccounts {
     pub loan: solana_pubkey::Pubkey,
     pub borrower: solana_pubkey::Pubkey,
@@ -24,38 +26,5 @@ pub struct RepayLoanV3InstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub mpl_token_metadata_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3 {
-    type ArrangedAccounts = RepayLoanV3InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [loan, borrower, borrower_collateral_token_account, lender, escrow, escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RepayLoanV3InstructionAccounts {
-            loan: loan.pubkey,
-            borrower: borrower.pubkey,
-            borrower_collateral_token_account: borrower_collateral_token_account.pubkey,
-            lender: lender.pubkey,
-            escrow: escrow.pubkey,
-            escrow_collateral_token_account: escrow_collateral_token_account.pubkey,
-            value_mint: value_mint.pubkey,
-            collateral_mint: collateral_mint.pubkey,
-            order_book: order_book.pubkey,
-            fee_authority: fee_authority.pubkey,
-            metadata: metadata.pubkey,
-            edition: edition.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            rent: rent.pubkey,
-            mpl_token_metadata_program: mpl_token_metadata_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,17 +1,22 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x9f9ff5a8bf9a6406")]
 pub struct RepayLoanV3Compressed {
+    #[carbon(serialize_as = "hex")]
     pub cnft_root: [u8; 32],
+    #[carbon(serialize_as = "hex")]
     pub cnft_data_hash: [u8; 32],
+    #[carbon(serialize_as = "hex")]
     pub cnft_creator_hash: [u8; 32],
     pub cnft_nonce: u64,
     pub cnft_index: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RepayLoanV3Compressed")]
 pub struct RepayLoanV3CompressedInstructionAccounts {
     pub loan: solana_pubkey::Pubkey,
     pub borrower: solana_pubkey::Pubkey,
@@ -28,36 +33,5 @@ pub struct RepayLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3Compressed {
-    type ArrangedAccounts = RepayLoanV3CompressedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [loan, borrower, lender, escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(RepayLoanV3CompressedInstructionAccounts {
-            loan: loan.pubkey,
-            borrower: borrower.pubkey,
-            lender: lender.pubkey,
-            escrow: escrow.pubkey,
-            value_mint: value_mint.pubkey,
-            order_book: order_book.pubkey,
-            fee_authority: fee_authority.pubkey,
-            tree_authority: tree_authority.pubkey,
-            log_wrapper: log_wrapper.pubkey,
-            merkle_tree: merkle_tree.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
-            compression_program: compression_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf1726a4f1059e97d")]
 pub struct TakeLoanV3Compressed {
@@ -13,6 +13,8 @@ pub struct TakeLoanV3Compressed {
     pub cnft_args: CnftArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TakeLoanV3Compressed")]
 pub struct TakeLoanV3CompressedInstructionAccounts {
     pub lender: solana_pubkey::Pubkey,
     pub borrower: solana_pubkey::Pubkey,
@@ -28,35 +30,5 @@ pub struct TakeLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for TakeLoanV3Compressed {
-    type ArrangedAccounts = TakeLoanV3CompressedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [lender, borrower, loan, escrow, order_book, collateral_mint, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(TakeLoanV3CompressedInstructionAccounts {
-            lender: lender.pubkey,
-            borrower: borrower.pubkey,
-            loan: loan.pubkey,
-            escrow: escrow.pubkey,
-            order_book: order_book.pubkey,
-            collateral_mint: collateral_mint.pubkey,
-            tree_authority: tree_authority.pubkey,
-            log_wrapper: log_wrapper.pubkey,
-            merkle_tree: merkle_tree.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
-            compression_program: compression_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
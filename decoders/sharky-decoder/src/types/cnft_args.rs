@@ -1,11 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct CnftArgs {
+    #[carbon(serialize_as = "hex")]
     pub cnft_root: [u8; 32],
+    #[carbon(serialize_as = "hex")]
     pub cnft_data_hash: [u8; 32],
+    #[carbon(serialize_as = "hex")]
     pub cnft_creator_hash: [u8; 32],
     pub cnft_nonce: u64,
     pub cnft_index: u32,
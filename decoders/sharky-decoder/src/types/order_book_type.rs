@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub enum OrderBookType {
     Collection {
+        #[carbon(serialize_as = "base58")]
         collection_key: solana_pubkey::Pubkey,
     },
     NFTList {
+        #[carbon(serialize_as = "base58")]
         list_account: solana_pubkey::Pubkey,
     },
 }
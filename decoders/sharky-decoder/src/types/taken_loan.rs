@@ -1,14 +1,17 @@
 use {
     super::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct TakenLoan {
+    #[carbon(serialize_as = "base58")]
     pub nft_collateral_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub lender_note_mint: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub borrower_note_mint: solana_pubkey::Pubkey,
     pub apy: APY,
     pub terms: LoanTerms,
@@ -24,6 +24,10 @@ pub enum SolayerRestakingProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SolayerRestakingProgramDecoder {
     type InstructionType = SolayerRestakingProgramInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0ab1a1eefa257818")]
 pub struct Unrestake {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Unrestake")]
 pub struct UnrestakeInstructionAccounts {
     pub signer: solana_pubkey::Pubkey,
     pub lst_mint: solana_pubkey::Pubkey,
@@ -19,31 +21,5 @@ pub struct UnrestakeInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Unrestake {
-    type ArrangedAccounts = UnrestakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [signer, lst_mint, lst_ata, rst_ata, rst_mint, vault, pool, associated_token_program, token_program, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(UnrestakeInstructionAccounts {
-            signer: signer.pubkey,
-            lst_mint: lst_mint.pubkey,
-            lst_ata: lst_ata.pubkey,
-            rst_ata: rst_ata.pubkey,
-            rst_mint: rst_mint.pubkey,
-            vault: vault.pubkey,
-            pool: pool.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            token_program: token_program.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
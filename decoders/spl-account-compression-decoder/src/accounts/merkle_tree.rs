@@ -0,0 +1,62 @@
+use {
+    super::super::types::*,
+    carbon_core::borsh::{
+        self,
+        maybestd::io::{Error, ErrorKind, Read, Result},
+        BorshDeserialize,
+    },
+};
+
+/// A concurrent merkle tree account: its header, the tree, and the canopy of
+/// cached upper nodes filling the rest of the account.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct MerkleTree {
+    pub header: ConcurrentMerkleTreeHeader,
+    pub tree: ConcurrentMerkleTree,
+    pub canopy: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    /// Returns the current root of the tree.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.tree.root()
+    }
+}
+
+impl BorshDeserialize for MerkleTree {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let header = ConcurrentMerkleTreeHeader::deserialize_reader(reader)?;
+        if header.account_type != CompressionAccountType::ConcurrentMerkleTree {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "uninitialized merkle tree account",
+            ));
+        }
+
+        let tree =
+            ConcurrentMerkleTree::read(reader, header.max_depth(), header.max_buffer_size())?;
+
+        let mut canopy = Vec::new();
+        reader.read_to_end(&mut canopy)?;
+        let canopy = canopy
+            .chunks_exact(32)
+            .map(|node| {
+                let mut bytes = [0; 32];
+                bytes.copy_from_slice(node);
+                bytes
+            })
+            .collect();
+
+        Ok(Self {
+            header,
+            tree,
+            canopy,
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for MerkleTree {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        borsh::BorshDeserialize::try_from_slice(data).ok()
+    }
+}
@@ -0,0 +1,83 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::SplAccountCompressionDecoder;
+pub mod merkle_tree;
+
+pub enum SplAccountCompressionAccount {
+    MerkleTree(merkle_tree::MerkleTree),
+}
+
+impl AccountDecoder<'_> for SplAccountCompressionDecoder {
+    type AccountType = SplAccountCompressionAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = merkle_tree::MerkleTree::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: SplAccountCompressionAccount::MerkleTree(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_merkle_tree() {
+        // Arrange
+        let authority = solana_pubkey::Pubkey::new_unique();
+        let (max_depth, max_buffer_size) = (3u32, 2u32);
+        let mut data = vec![1, 0];
+        data.extend_from_slice(&max_buffer_size.to_le_bytes());
+        data.extend_from_slice(&max_depth.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&[0; 6]);
+        for value in [5u64, 1, 2] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        for root in [[1; 32], [2; 32]] {
+            data.extend_from_slice(&root);
+            data.extend_from_slice(&[0; 32 * 3]);
+            data.extend_from_slice(&[0; 8]);
+        }
+        data.extend_from_slice(&[0; 32 * 3 + 32 + 8]);
+        data.extend_from_slice(&[9; 32 * 2]);
+        let account = solana_account::Account {
+            lamports: 1,
+            data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        // Act
+        let decoded = SplAccountCompressionDecoder
+            .decode_account(&account)
+            .expect("decode account");
+
+        // Assert
+        let SplAccountCompressionAccount::MerkleTree(merkle_tree) = decoded.data;
+        assert_eq!(merkle_tree.header.authority(), authority);
+        assert_eq!(merkle_tree.tree.sequence_number, 5);
+        assert_eq!(merkle_tree.root(), Some([2; 32]));
+        assert_eq!(merkle_tree.canopy, vec![[9; 32]; 2]);
+    }
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x957812deece158cb")]
+pub struct Append {
+    pub leaf: [u8; 32],
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Append")]
+pub struct AppendInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub noop: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x320edb6b4e671067")]
+pub struct CloseEmptyTree {}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseEmptyTree")]
+pub struct CloseEmptyTreeInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub recipient: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
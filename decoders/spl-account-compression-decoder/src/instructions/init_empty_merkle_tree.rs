@@ -0,0 +1,19 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xbf0b7707b46bdc6e")]
+pub struct InitEmptyMerkleTree {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitEmptyMerkleTree")]
+pub struct InitEmptyMerkleTreeInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub noop: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,20 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x062a32be336db2a8")]
+pub struct InsertOrAppend {
+    pub root: [u8; 32],
+    pub leaf: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InsertOrAppend")]
+pub struct InsertOrAppendInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub noop: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
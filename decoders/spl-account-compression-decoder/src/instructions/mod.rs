@@ -0,0 +1,100 @@
+use crate::PROGRAM_ID;
+
+use super::SplAccountCompressionDecoder;
+pub mod append;
+pub mod close_empty_tree;
+pub mod init_empty_merkle_tree;
+pub mod insert_or_append;
+pub mod replace_leaf;
+pub mod transfer_authority;
+pub mod verify_leaf;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SplAccountCompressionInstruction {
+    InitEmptyMerkleTree(init_empty_merkle_tree::InitEmptyMerkleTree),
+    ReplaceLeaf(replace_leaf::ReplaceLeaf),
+    TransferAuthority(transfer_authority::TransferAuthority),
+    VerifyLeaf(verify_leaf::VerifyLeaf),
+    Append(append::Append),
+    InsertOrAppend(insert_or_append::InsertOrAppend),
+    CloseEmptyTree(close_empty_tree::CloseEmptyTree),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SplAccountCompressionDecoder {
+    type InstructionType = SplAccountCompressionInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SplAccountCompressionInstruction::InitEmptyMerkleTree => init_empty_merkle_tree::InitEmptyMerkleTree,
+            SplAccountCompressionInstruction::ReplaceLeaf => replace_leaf::ReplaceLeaf,
+            SplAccountCompressionInstruction::TransferAuthority => transfer_authority::TransferAuthority,
+            SplAccountCompressionInstruction::VerifyLeaf => verify_leaf::VerifyLeaf,
+            SplAccountCompressionInstruction::Append => append::Append,
+            SplAccountCompressionInstruction::InsertOrAppend => insert_or_append::InsertOrAppend,
+            SplAccountCompressionInstruction::CloseEmptyTree => close_empty_tree::CloseEmptyTree,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::{AccountMeta, Instruction};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_append() {
+        // Arrange
+        let (merkle_tree, authority) = (
+            solana_pubkey::Pubkey::new_unique(),
+            solana_pubkey::Pubkey::new_unique(),
+        );
+        let mut data = vec![0x95, 0x78, 0x12, 0xde, 0xec, 0xe1, 0x58, 0xcb];
+        data.extend_from_slice(&[7; 32]);
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(merkle_tree, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(crate::NOOP_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        // Act
+        let decoded = SplAccountCompressionDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            append::Append::arrange_accounts(&decoded.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            SplAccountCompressionInstruction::Append(append::Append { leaf: [7; 32] })
+        );
+        assert_eq!(arranged_accounts.merkle_tree, merkle_tree);
+        assert_eq!(arranged_accounts.authority, authority);
+    }
+}
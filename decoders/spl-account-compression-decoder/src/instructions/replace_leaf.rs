@@ -0,0 +1,21 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0xcca54c6449930080")]
+pub struct ReplaceLeaf {
+    pub root: [u8; 32],
+    pub previous_leaf: [u8; 32],
+    pub new_leaf: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ReplaceLeaf")]
+pub struct ReplaceLeafInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub noop: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
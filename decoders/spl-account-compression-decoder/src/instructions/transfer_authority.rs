@@ -0,0 +1,17 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x30a94c48e5b437a1")]
+pub struct TransferAuthority {
+    pub new_authority: solana_pubkey::Pubkey,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TransferAuthority")]
+pub struct TransferAuthorityInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
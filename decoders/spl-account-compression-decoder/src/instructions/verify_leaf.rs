@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x7cdc16df680afae0")]
+pub struct VerifyLeaf {
+    pub root: [u8; 32],
+    pub leaf: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "VerifyLeaf")]
+pub struct VerifyLeafInstructionAccounts {
+    pub merkle_tree: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
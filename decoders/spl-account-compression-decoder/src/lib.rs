@@ -0,0 +1,15 @@
+use solana_pubkey::Pubkey;
+
+pub struct SplAccountCompressionDecoder;
+#[cfg(feature = "accounts")]
+pub mod accounts;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// The SPL noop program, through which the program logs its change logs.
+pub const NOOP_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
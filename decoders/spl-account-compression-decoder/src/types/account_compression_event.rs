@@ -0,0 +1,54 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+/// An event logged through the noop program by account compression, or by
+/// programs using its trees.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum AccountCompressionEvent {
+    ChangeLog(ChangeLogEvent),
+    ApplicationData(ApplicationDataEvent),
+}
+
+impl AccountCompressionEvent {
+    /// Parses the data of a noop instruction, as decoded by the SPL noop
+    /// decoder.
+    pub fn from_noop_data(data: &[u8]) -> Option<Self> {
+        <Self as carbon_core::deserialize::CarbonDeserialize>::deserialize(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_change_log_from_noop_data() {
+        // Arrange
+        let tree = solana_pubkey::Pubkey::new_unique();
+        let mut data = vec![0, 0];
+        data.extend_from_slice(tree.as_ref());
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for (node, index) in [([3; 32], 4u32), ([8; 32], 1)] {
+            data.extend_from_slice(&node);
+            data.extend_from_slice(&index.to_le_bytes());
+        }
+        data.extend_from_slice(&11u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+
+        // Act
+        let event = AccountCompressionEvent::from_noop_data(&data).expect("parse event");
+
+        // Assert
+        let AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(change_log)) = event else {
+            panic!("expected a change log");
+        };
+        assert_eq!(change_log.id, tree);
+        assert_eq!(change_log.seq, 11);
+        assert_eq!(change_log.leaf(), Some([3; 32]));
+        assert_eq!(change_log.root(), Some([8; 32]));
+    }
+}
@@ -0,0 +1,17 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum ApplicationDataEvent {
+    V1(ApplicationDataEventV1),
+}
+
+/// Data logged by a program using the tree, such as the leaf schema of a
+/// Bubblegum asset, left for that program's decoder to parse.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ApplicationDataEventV1 {
+    pub application_data: Vec<u8>,
+}
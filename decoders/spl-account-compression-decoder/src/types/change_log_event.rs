@@ -0,0 +1,38 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum ChangeLogEvent {
+    V1(ChangeLogEventV1),
+}
+
+/// A change of a leaf of a concurrent merkle tree.
+///
+/// `path` goes from the new leaf to the new root of the tree, and `seq` is
+/// the sequence number of the tree after the change, which orders the
+/// changes of a tree.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ChangeLogEventV1 {
+    pub id: solana_pubkey::Pubkey,
+    pub path: Vec<PathNode>,
+    pub seq: u64,
+    pub index: u32,
+}
+
+impl ChangeLogEventV1 {
+    /// Returns the new value of the changed leaf.
+    pub fn leaf(&self) -> Option<[u8; 32]> {
+        self.path.first().map(|path_node| path_node.node)
+    }
+
+    /// Returns the root of the tree after the change.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.path.last().map(|path_node| path_node.node)
+    }
+}
@@ -0,0 +1,83 @@
+use carbon_core::borsh::{self, maybestd::io::Read, BorshDeserialize};
+
+/// A concurrent merkle tree, whose change log buffer lets several changes
+/// computed against recent roots be applied in the same slot.
+///
+/// The sizes of `change_logs` and of the paths depend on the `max_buffer_size`
+/// and `max_depth` of the tree's header.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct ConcurrentMerkleTree {
+    pub sequence_number: u64,
+    pub active_index: u64,
+    pub buffer_size: u64,
+    pub change_logs: Vec<ChangeLog>,
+    pub rightmost_proof: Path,
+}
+
+/// A change of the tree kept in its buffer: the root after the change, and
+/// the path from the changed leaf up to it.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct ChangeLog {
+    pub root: [u8; 32],
+    pub path: Vec<[u8; 32]>,
+    pub index: u32,
+}
+
+/// The proof of the rightmost leaf of the tree, used to append leaves.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct Path {
+    pub proof: Vec<[u8; 32]>,
+    pub leaf: [u8; 32],
+    pub index: u32,
+}
+
+fn read_nodes<R: Read>(reader: &mut R, count: u32) -> borsh::maybestd::io::Result<Vec<[u8; 32]>> {
+    (0..count)
+        .map(|_| <[u8; 32]>::deserialize_reader(reader))
+        .collect()
+}
+
+impl ConcurrentMerkleTree {
+    /// Reads a tree of `max_depth` and `max_buffer_size`, as stored after the
+    /// header of its account.
+    pub fn read<R: Read>(
+        reader: &mut R,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let sequence_number = u64::deserialize_reader(reader)?;
+        let active_index = u64::deserialize_reader(reader)?;
+        let buffer_size = u64::deserialize_reader(reader)?;
+
+        let change_logs = (0..max_buffer_size)
+            .map(|_| {
+                let root = <[u8; 32]>::deserialize_reader(reader)?;
+                let path = read_nodes(reader, max_depth)?;
+                let index = u32::deserialize_reader(reader)?;
+                let _padding = u32::deserialize_reader(reader)?;
+
+                Ok(ChangeLog { root, path, index })
+            })
+            .collect::<borsh::maybestd::io::Result<Vec<_>>>()?;
+
+        let proof = read_nodes(reader, max_depth)?;
+        let leaf = <[u8; 32]>::deserialize_reader(reader)?;
+        let index = u32::deserialize_reader(reader)?;
+        let _padding = u32::deserialize_reader(reader)?;
+
+        Ok(Self {
+            sequence_number,
+            active_index,
+            buffer_size,
+            change_logs,
+            rightmost_proof: Path { proof, leaf, index },
+        })
+    }
+
+    /// Returns the current root of the tree.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.change_logs
+            .get(self.active_index as usize)
+            .map(|change_log| change_log.root)
+    }
+}
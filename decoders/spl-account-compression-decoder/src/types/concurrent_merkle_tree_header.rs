@@ -0,0 +1,52 @@
+use {
+    super::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+};
+
+/// The header at the start of a merkle tree account, sizing the tree after it.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ConcurrentMerkleTreeHeader {
+    pub account_type: CompressionAccountType,
+    pub header: ConcurrentMerkleTreeHeaderData,
+}
+
+impl ConcurrentMerkleTreeHeader {
+    pub fn max_depth(&self) -> u32 {
+        match &self.header {
+            ConcurrentMerkleTreeHeaderData::V1(header) => header.max_depth,
+        }
+    }
+
+    pub fn max_buffer_size(&self) -> u32 {
+        match &self.header {
+            ConcurrentMerkleTreeHeaderData::V1(header) => header.max_buffer_size,
+        }
+    }
+
+    pub fn authority(&self) -> solana_pubkey::Pubkey {
+        match &self.header {
+            ConcurrentMerkleTreeHeaderData::V1(header) => header.authority,
+        }
+    }
+}
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum ConcurrentMerkleTreeHeaderData {
+    V1(ConcurrentMerkleTreeHeaderDataV1),
+}
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct ConcurrentMerkleTreeHeaderDataV1 {
+    pub max_buffer_size: u32,
+    pub max_depth: u32,
+    pub authority: solana_pubkey::Pubkey,
+    pub creation_slot: u64,
+    pub is_batch_initialized: bool,
+    pub padding: [u8; 5],
+}
@@ -0,0 +1,14 @@
+pub mod account_compression_event;
+pub use account_compression_event::*;
+pub mod application_data_event;
+pub use application_data_event::*;
+pub mod change_log_event;
+pub use change_log_event::*;
+pub mod compression_account_type;
+pub use compression_account_type::*;
+pub mod concurrent_merkle_tree;
+pub use concurrent_merkle_tree::*;
+pub mod concurrent_merkle_tree_header;
+pub use concurrent_merkle_tree_header::*;
+pub mod path_node;
+pub use path_node::*;
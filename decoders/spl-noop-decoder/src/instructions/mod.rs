@@ -0,0 +1,88 @@
+use {
+    crate::{SplNoopDecoder, PROGRAM_ID},
+    carbon_core::instruction::DecodedInstruction,
+};
+pub mod noop_instruction;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SplNoopInstruction {
+    NoopInstruction(noop_instruction::NoopInstruction),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SplNoopDecoder {
+    type InstructionType = SplNoopInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        Some(DecodedInstruction {
+            data: SplNoopInstruction::NoopInstruction(noop_instruction::NoopInstruction {
+                data: instruction.data.clone(),
+            }),
+            program_id: instruction.program_id,
+            accounts: instruction.accounts.clone(),
+            audit: carbon_core::instruction::InstructionAudit::capture(&[], &instruction.data),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::instruction::InstructionDecoder;
+    use solana_instruction::Instruction;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_noop_instruction() {
+        // Arrange
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data: vec![1, 0, 7, 7],
+        };
+
+        // Act
+        let decoded = SplNoopDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        let SplNoopInstruction::NoopInstruction(noop) = decoded.data;
+        assert_eq!(noop.data, vec![1, 0, 7, 7]);
+    }
+
+    #[test]
+    fn test_ignore_other_programs() {
+        // Arrange
+        let instruction = Instruction {
+            program_id: solana_pubkey::Pubkey::new_unique(),
+            accounts: vec![],
+            data: vec![],
+        };
+
+        // Act
+        let decoded = SplNoopDecoder.decode_instruction(&instruction);
+
+        // Assert
+        assert!(decoded.is_none());
+    }
+}
@@ -0,0 +1,27 @@
+/// The data a program logged by invoking the noop program.
+///
+/// The noop program does nothing with its data, so it is returned as-is:
+/// parsing it is up to the decoder of the program that logged it, such as
+/// `AccountCompressionEvent::from_noop_data` for account compression change
+/// logs.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct NoopInstruction {
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NoopInstructionInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for NoopInstruction {
+    type ArrangedAccounts = NoopInstructionInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        Some(NoopInstructionInstructionAccounts {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use solana_pubkey::Pubkey;
+
+pub struct SplNoopDecoder;
+#[cfg(feature = "instructions")]
+pub mod instructions;
+
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
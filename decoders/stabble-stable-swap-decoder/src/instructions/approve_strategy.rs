@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x078da23c47731a92")]
 pub struct ApproveStrategy {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApproveStrategy")]
 pub struct ApproveStrategyInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub strategy: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ApproveStrategy {
-    type ArrangedAccounts = ApproveStrategyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, vault, admin, strategy, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ApproveStrategyInstructionAccounts {
-            pool: pool.pubkey,
-            vault: vault.pubkey,
-            admin: admin.pubkey,
-            strategy: strategy.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x38eebd23c89d2a42")]
 pub struct ChangeAmpFactor {
@@ -9,26 +9,11 @@ pub struct ChangeAmpFactor {
     pub ramp_duration: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ChangeAmpFactor")]
 pub struct ChangeAmpFactorInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ChangeAmpFactor {
-    type ArrangedAccounts = ChangeAmpFactorInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pool, vault, admin, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ChangeAmpFactorInstructionAccounts {
-            pool: pool.pubkey,
-            vault: vault.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
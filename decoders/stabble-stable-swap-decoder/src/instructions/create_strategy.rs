@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x98a06b94f5be7fe0")]
 pub struct CreateStrategy {
@@ -13,24 +13,10 @@ pub struct CreateStrategy {
     pub ramp_max_duration: u32,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateStrategy")]
 pub struct CreateStrategyInstructionAccounts {
     pub owner_only: solana_pubkey::Pubkey,
     pub strategy: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateStrategy {
-    type ArrangedAccounts = CreateStrategyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner_only, strategy, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CreateStrategyInstructionAccounts {
-            owner_only: owner_only.pubkey,
-            strategy: strategy.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -11,7 +11,9 @@ pub mod deposit;
 pub mod exec_strategy;
 pub mod initialize;
 pub mod pause;
+#[cfg(feature = "events")]
 pub mod pool_balance_updated_event;
+#[cfg(feature = "events")]
 pub mod pool_updated_event;
 pub mod reject_owner;
 pub mod shutdown;
@@ -49,13 +51,19 @@ pub enum StableSwapInstruction {
     TransferOwner(transfer_owner::TransferOwner),
     Unpause(unpause::Unpause),
     Withdraw(withdraw::Withdraw),
+    #[cfg(feature = "events")]
     PoolBalanceUpdatedEvent(pool_balance_updated_event::PoolBalanceUpdatedEvent),
+    #[cfg(feature = "events")]
     PoolUpdatedEvent(pool_updated_event::PoolUpdatedEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for StableSwapDecoder {
     type InstructionType = StableSwapInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -82,7 +90,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for StableSwapDecoder {
             StableSwapInstruction::TransferOwner => transfer_owner::TransferOwner,
             StableSwapInstruction::Unpause => unpause::Unpause,
             StableSwapInstruction::Withdraw => withdraw::Withdraw,
+            #[cfg(feature = "events")]
             StableSwapInstruction::PoolBalanceUpdatedEvent => pool_balance_updated_event::PoolBalanceUpdatedEvent,
+            #[cfg(feature = "events")]
             StableSwapInstruction::PoolUpdatedEvent => pool_updated_event::PoolUpdatedEvent,
         )
     }
@@ -1,12 +1,13 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d80275edde6de7f8d")]
 pub struct PoolUpdatedEvent {
+    #[carbon(serialize_as = "base58")]
     pub pubkey: solana_pubkey::Pubkey,
     pub data: PoolUpdatedData,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x2b04ed0b1ac91e62")]
 pub struct SwapV2 {
@@ -9,6 +9,8 @@ pub struct SwapV2 {
     pub minimum_amount_out: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SwapV2")]
 pub struct SwapV2InstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub mint_in: solana_pubkey::Pubkey,
@@ -25,36 +27,5 @@ pub struct SwapV2InstructionAccounts {
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_2022_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
-    type ArrangedAccounts = SwapV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, mint_in, mint_out, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_2022_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SwapV2InstructionAccounts {
-            user: user.pubkey,
-            mint_in: mint_in.pubkey,
-            mint_out: mint_out.pubkey,
-            user_token_in: user_token_in.pubkey,
-            user_token_out: user_token_out.pubkey,
-            vault_token_in: vault_token_in.pubkey,
-            vault_token_out: vault_token_out.pubkey,
-            beneficiary_token_out: beneficiary_token_out.pubkey,
-            pool: pool.pubkey,
-            withdraw_authority: withdraw_authority.pubkey,
-            vault: vault.pubkey,
-            vault_authority: vault_authority.pubkey,
-            vault_program: vault_program.pubkey,
-            token_program: token_program.pubkey,
-            token_2022_program: token_2022_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
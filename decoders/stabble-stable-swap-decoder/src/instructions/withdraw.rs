@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb712469c946da122")]
 pub struct Withdraw {
@@ -9,6 +9,8 @@ pub struct Withdraw {
     pub minimum_amounts_out: Vec<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Withdraw")]
 pub struct WithdrawInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub user_pool_token: solana_pubkey::Pubkey,
@@ -20,31 +22,5 @@ pub struct WithdrawInstructionAccounts {
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program_2022: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
-    type ArrangedAccounts = WithdrawInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_pool_token, mint, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_program_2022, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(WithdrawInstructionAccounts {
-            user: user.pubkey,
-            user_pool_token: user_pool_token.pubkey,
-            mint: mint.pubkey,
-            pool: pool.pubkey,
-            withdraw_authority: withdraw_authority.pubkey,
-            vault: vault.pubkey,
-            vault_authority: vault_authority.pubkey,
-            vault_program: vault_program.pubkey,
-            token_program: token_program.pubkey,
-            token_program_2022: token_program_2022.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
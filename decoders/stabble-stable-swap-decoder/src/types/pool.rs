@@ -1,13 +1,16 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Pool {
+    #[carbon(serialize_as = "base58")]
     pub owner: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub vault: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub mint: solana_pubkey::Pubkey,
     pub authority_bump: u8,
     pub is_active: bool,
@@ -17,6 +20,7 @@ pub struct Pool {
     pub ramp_stop_ts: i64,
     pub swap_fee: u64,
     pub tokens: Vec<PoolToken>,
+    #[carbon(serialize_as = "base58")]
     pub pending_owner: Option<solana_pubkey::Pubkey>,
     pub max_supply: u64,
 }
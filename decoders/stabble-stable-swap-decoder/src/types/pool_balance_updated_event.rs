@@ -1,11 +1,12 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct PoolBalanceUpdatedEvent {
+    #[carbon(serialize_as = "base58")]
     pub pubkey: solana_pubkey::Pubkey,
     pub data: PoolBalanceUpdatedData,
 }
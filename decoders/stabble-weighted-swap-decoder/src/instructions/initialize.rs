@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xafaf6d1f0d989bed")]
 pub struct Initialize {
@@ -10,6 +10,8 @@ pub struct Initialize {
     pub max_caps: Vec<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Initialize")]
 pub struct InitializeInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -17,27 +19,5 @@ pub struct InitializeInstructionAccounts {
     pub pool_authority: solana_pubkey::Pubkey,
     pub withdraw_authority: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Initialize {
-    type ArrangedAccounts = InitializeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, mint, pool, pool_authority, withdraw_authority, vault, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeInstructionAccounts {
-            owner: owner.pubkey,
-            mint: mint.pubkey,
-            pool: pool.pubkey,
-            pool_authority: pool_authority.pubkey,
-            withdraw_authority: withdraw_authority.pubkey,
-            vault: vault.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
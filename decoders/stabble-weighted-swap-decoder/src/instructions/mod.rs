@@ -7,7 +7,9 @@ pub mod change_swap_fee;
 pub mod deposit;
 pub mod initialize;
 pub mod pause;
+#[cfg(feature = "events")]
 pub mod pool_balance_updated_event;
+#[cfg(feature = "events")]
 pub mod pool_updated_event;
 pub mod reject_owner;
 pub mod shutdown;
@@ -41,13 +43,19 @@ pub enum WeightedSwapInstruction {
     TransferOwner(transfer_owner::TransferOwner),
     Unpause(unpause::Unpause),
     Withdraw(withdraw::Withdraw),
+    #[cfg(feature = "events")]
     PoolBalanceUpdatedEvent(pool_balance_updated_event::PoolBalanceUpdatedEvent),
+    #[cfg(feature = "events")]
     PoolUpdatedEvent(pool_updated_event::PoolUpdatedEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for WeightedSwapDecoder {
     type InstructionType = WeightedSwapInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -70,7 +78,9 @@ impl carbon_core::instruction::InstructionDecoder<'_> for WeightedSwapDecoder {
             WeightedSwapInstruction::TransferOwner => transfer_owner::TransferOwner,
             WeightedSwapInstruction::Unpause => unpause::Unpause,
             WeightedSwapInstruction::Withdraw => withdraw::Withdraw,
+            #[cfg(feature = "events")]
             WeightedSwapInstruction::PoolBalanceUpdatedEvent => pool_balance_updated_event::PoolBalanceUpdatedEvent,
+            #[cfg(feature = "events")]
             WeightedSwapInstruction::PoolUpdatedEvent => pool_updated_event::PoolUpdatedEvent,
         )
     }
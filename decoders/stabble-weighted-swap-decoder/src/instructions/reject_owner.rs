@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xeecec6d733b285e4")]
 pub struct RejectOwner {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RejectOwner")]
 pub struct RejectOwnerInstructionAccounts {
     pub pending_owner: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RejectOwner {
-    type ArrangedAccounts = RejectOwnerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pending_owner, pool, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RejectOwnerInstructionAccounts {
-            pending_owner: pending_owner.pubkey,
-            pool: pool.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct Vault {
+    #[carbon(serialize_as = "base58")]
     pub admin: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub withdraw_authority: solana_pubkey::Pubkey,
     pub withdraw_authority_bump: u8,
     pub authority_bump: u8,
     pub is_active: bool,
+    #[carbon(serialize_as = "base58")]
     pub beneficiary: solana_pubkey::Pubkey,
     pub beneficiary_fee: u64,
+    #[carbon(serialize_as = "base58")]
     pub pending_admin: Option<solana_pubkey::Pubkey>,
 }
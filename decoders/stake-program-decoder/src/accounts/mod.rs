@@ -1,15 +1,89 @@
 use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
 
 use super::StakeProgramDecoder;
+pub mod stake_state_v2;
 
-pub enum StakeProgramAccount {}
+pub enum StakeProgramAccount {
+    StakeState(stake_state_v2::StakeStateV2),
+}
 
 impl AccountDecoder<'_> for StakeProgramDecoder {
     type AccountType = StakeProgramAccount;
     fn decode_account(
         &self,
-        _account: &solana_account::Account,
+        account: &solana_account::Account,
     ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            stake_state_v2::StakeStateV2::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: StakeProgramAccount::StakeState(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Authorized, Delegation, Lockup, Meta, Stake, StakeFlags};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_stake_account() {
+        // Arrange
+        let authority =
+            solana_pubkey::Pubkey::from_str_const("9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K");
+        let expected_state = stake_state_v2::StakeStateV2::Stake(
+            Meta {
+                rent_exempt_reserve: 2282880,
+                authorized: Authorized {
+                    staker: authority,
+                    withdrawer: authority,
+                },
+                lockup: Lockup {
+                    unix_timestamp: 0,
+                    epoch: 0,
+                    custodian: solana_pubkey::Pubkey::default(),
+                },
+            },
+            Stake {
+                delegation: Delegation {
+                    voter_pubkey: solana_pubkey::Pubkey::from_str_const(
+                        "CertusDeBmqN8ZawdkxK5kFGMwBXdudvWHYwtNgNhvLu",
+                    ),
+                    stake: 1997717120,
+                    activation_epoch: 512,
+                    deactivation_epoch: u64::MAX,
+                    warmup_cooldown_rate: 0.25,
+                },
+                credits_observed: 81234567,
+            },
+            StakeFlags::default(),
+        );
+
+        // Act
+        let decoder = StakeProgramDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/stake_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            StakeProgramAccount::StakeState(state) => assert_eq!(state, expected_state),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::super::types::*;
+
+use carbon_core::{
+    borsh::{maybestd::io, BorshDeserialize},
+    CarbonSerialize,
+};
+
+#[derive(Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
+pub enum StakeStateV2 {
+    Uninitialized,
+    Initialized(Meta),
+    Stake(Meta, Stake, StakeFlags),
+    RewardsPool,
+}
+
+// Stake accounts are bincode encoded with a `u32` variant tag, and are
+// allocated at a fixed size, so the state is usually followed by padding.
+impl BorshDeserialize for StakeStateV2 {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        match u32::deserialize_reader(reader)? {
+            0 => Ok(Self::Uninitialized),
+            1 => Ok(Self::Initialized(Meta::deserialize_reader(reader)?)),
+            2 => Ok(Self::Stake(
+                Meta::deserialize_reader(reader)?,
+                Stake::deserialize_reader(reader)?,
+                StakeFlags::deserialize_reader(reader)?,
+            )),
+            3 => Ok(Self::RewardsPool),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid StakeStateV2 variant",
+            )),
+        }
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for StakeStateV2 {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        let mut data = data;
+        Self::deserialize_reader(&mut data).ok()
+    }
+}
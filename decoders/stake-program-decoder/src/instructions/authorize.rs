@@ -1,36 +1,22 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0xadc166d2db897178")]
+#[carbon(discriminator = "0x01000000")]
 pub struct Authorize {
+    #[carbon(serialize_as = "base58")]
     pub new_authority: solana_pubkey::Pubkey,
     pub stake_authorize: StakeAuthorize,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Authorize")]
 pub struct AuthorizeInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Authorize {
-    type ArrangedAccounts = AuthorizeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, clock, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AuthorizeInstructionAccounts {
-            stake: stake.pubkey,
-            clock: clock.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,39 +1,25 @@
 use super::super::types::*;
 
-use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x0712d3294c53733d")]
+#[carbon(discriminator = "0x08000000")]
 pub struct AuthorizeWithSeed {
+    #[carbon(serialize_as = "base58")]
     pub new_authority: solana_pubkey::Pubkey,
     pub stake_authorize: StakeAuthorize,
-    pub authority_seed: String,
+    pub authority_seed: U64PrefixString,
+    #[carbon(serialize_as = "base58")]
     pub authority_owner: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "AuthorizeWithSeed")]
 pub struct AuthorizeWithSeedInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority_base: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AuthorizeWithSeed {
-    type ArrangedAccounts = AuthorizeWithSeedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority_base, clock, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AuthorizeWithSeedInstructionAccounts {
-            stake: stake.pubkey,
-            authority_base: authority_base.pubkey,
-            clock: clock.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x2c7021ac711c8e0d")]
+#[carbon(discriminator = "0x05000000")]
 pub struct Deactivate {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Deactivate")]
 pub struct DeactivateInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Deactivate {
-    type ArrangedAccounts = DeactivateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, clock, stake_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(DeactivateInstructionAccounts {
-            stake: stake.pubkey,
-            clock: clock.pubkey,
-            stake_authority: stake_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
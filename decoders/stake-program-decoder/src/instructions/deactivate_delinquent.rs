@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x0671c68ae4a39fdd")]
+#[carbon(discriminator = "0x0e000000")]
 pub struct DeactivateDelinquent {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DeactivateDelinquent")]
 pub struct DeactivateDelinquentInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub vote: solana_pubkey::Pubkey,
     pub reference_vote: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DeactivateDelinquent {
-    type ArrangedAccounts = DeactivateDelinquentInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, vote, reference_vote, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(DeactivateDelinquentInstructionAccounts {
-            stake: stake.pubkey,
-            vote: vote.pubkey,
-            reference_vote: reference_vote.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x326e5fb3c24b8cf6")]
+#[carbon(discriminator = "0x02000000")]
 pub struct DelegateStake {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "DelegateStake")]
 pub struct DelegateStakeInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub vote: solana_pubkey::Pubkey,
@@ -13,27 +15,5 @@ pub struct DelegateStakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub stake_config: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for DelegateStake {
-    type ArrangedAccounts = DelegateStakeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, vote, clock, stake_history, stake_config, stake_authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(DelegateStakeInstructionAccounts {
-            stake: stake.pubkey,
-            vote: vote.pubkey,
-            clock: clock.pubkey,
-            stake_history: stake_history.pubkey,
-            stake_config: stake_config.pubkey,
-            stake_authority: stake_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,19 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0xc541074997698569")]
+#[carbon(discriminator = "0x0d000000")]
 pub struct GetMinimumDelegation {}
 
-pub struct GetMinimumDelegationInstructionAccounts {}
-
-impl carbon_core::deserialize::ArrangeAccounts for GetMinimumDelegation {
-    type ArrangedAccounts = GetMinimumDelegationInstructionAccounts;
-
-    fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        Some(GetMinimumDelegationInstructionAccounts {})
-    }
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "GetMinimumDelegation")]
+pub struct GetMinimumDelegationInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
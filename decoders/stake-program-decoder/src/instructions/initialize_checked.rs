@@ -1,33 +1,17 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0xdb5a3aa18b58f61c")]
+#[carbon(discriminator = "0x09000000")]
 pub struct InitializeChecked {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeChecked")]
 pub struct InitializeCheckedInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
     pub withdraw_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeChecked {
-    type ArrangedAccounts = InitializeCheckedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, rent, stake_authority, withdraw_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeCheckedInstructionAccounts {
-            stake: stake.pubkey,
-            rent: rent.pubkey,
-            stake_authority: stake_authority.pubkey,
-            withdraw_authority: withdraw_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -12,6 +12,8 @@ pub mod get_minimum_delegation;
 pub mod initialize;
 pub mod initialize_checked;
 pub mod merge;
+pub mod move_lamports;
+pub mod move_stake;
 pub mod set_lockup;
 pub mod set_lockup_checked;
 pub mod split;
@@ -43,11 +45,17 @@ pub enum StakeProgramInstruction {
     SetLockupChecked(set_lockup_checked::SetLockupChecked),
     GetMinimumDelegation(get_minimum_delegation::GetMinimumDelegation),
     DeactivateDelinquent(deactivate_delinquent::DeactivateDelinquent),
+    MoveStake(move_stake::MoveStake),
+    MoveLamports(move_lamports::MoveLamports),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for StakeProgramDecoder {
     type InstructionType = StakeProgramInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -72,6 +80,142 @@ impl carbon_core::instruction::InstructionDecoder<'_> for StakeProgramDecoder {
             StakeProgramInstruction::SetLockupChecked => set_lockup_checked::SetLockupChecked,
             StakeProgramInstruction::GetMinimumDelegation => get_minimum_delegation::GetMinimumDelegation,
             StakeProgramInstruction::DeactivateDelinquent => deactivate_delinquent::DeactivateDelinquent,
+            StakeProgramInstruction::MoveStake => move_stake::MoveStake,
+            StakeProgramInstruction::MoveLamports => move_lamports::MoveLamports,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+    use carbon_core::{
+        deserialize::{ArrangeAccounts, U64PrefixString},
+        instruction::InstructionDecoder,
+    };
+    use solana_instruction::AccountMeta;
+
+    use crate::types::StakeAuthorize;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_withdraw() {
+        // Arrange
+        let expected_ix = StakeProgramInstruction::Withdraw(withdraw::Withdraw {
+            lamports: 1500000000,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7mz1ZSqvuTxPuGTMH5Kz9RQgSgxRJXbaANRPTnRYQ8Sc",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "4fYNw3dojWmQ4dXtSGE9epjRGy9pFSx62YypT7avPYvA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "SysvarC1ock11111111111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "SysvarStakeHistory1111111111111111111111111",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+                ),
+                true,
+            ),
+        ];
+        let expected_arranged_accounts = withdraw::WithdrawInstructionAccounts {
+            from: solana_pubkey::Pubkey::from_str_const(
+                "7mz1ZSqvuTxPuGTMH5Kz9RQgSgxRJXbaANRPTnRYQ8Sc",
+            ),
+            to: solana_pubkey::Pubkey::from_str_const(
+                "4fYNw3dojWmQ4dXtSGE9epjRGy9pFSx62YypT7avPYvA",
+            ),
+            clock: solana_pubkey::Pubkey::from_str_const(
+                "SysvarC1ock11111111111111111111111111111111",
+            ),
+            stake_history: solana_pubkey::Pubkey::from_str_const(
+                "SysvarStakeHistory1111111111111111111111111",
+            ),
+            withdraw_authority: solana_pubkey::Pubkey::from_str_const(
+                "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = StakeProgramDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/withdraw_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            withdraw::Withdraw::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+
+    #[test]
+    fn test_decode_authorize_with_seed() {
+        // Arrange
+        let expected_ix =
+            StakeProgramInstruction::AuthorizeWithSeed(authorize_with_seed::AuthorizeWithSeed {
+                new_authority: solana_pubkey::Pubkey::from_str_const(
+                    "GAPvsBHZZmkRjPfGX1N9vgEJ8dDq4T8Nsc5xXsDYaDkn",
+                ),
+                stake_authorize: StakeAuthorize::Withdrawer,
+                authority_seed: U64PrefixString("stake:0".to_string()),
+                authority_owner: solana_pubkey::Pubkey::from_str_const(
+                    "11111111111111111111111111111111",
+                ),
+            });
+        let expected_arranged_accounts =
+            authorize_with_seed::AuthorizeWithSeedInstructionAccounts {
+                stake: solana_pubkey::Pubkey::from_str_const(
+                    "7mz1ZSqvuTxPuGTMH5Kz9RQgSgxRJXbaANRPTnRYQ8Sc",
+                ),
+                authority_base: solana_pubkey::Pubkey::from_str_const(
+                    "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+                ),
+                clock: solana_pubkey::Pubkey::from_str_const(
+                    "SysvarC1ock11111111111111111111111111111111",
+                ),
+                remaining_accounts: vec![],
+            };
+
+        // Act
+        let decoder = StakeProgramDecoder;
+        let instruction =
+            carbon_test_utils::read_instruction("tests/fixtures/authorize_with_seed_ix.json")
+                .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            authorize_with_seed::AuthorizeWithSeed::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x11000000")]
+pub struct MoveLamports {
+    pub lamports: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MoveLamports")]
+pub struct MoveLamportsInstructionAccounts {
+    pub source_stake: solana_pubkey::Pubkey,
+    pub destination_stake: solana_pubkey::Pubkey,
+    pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,18 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x10000000")]
+pub struct MoveStake {
+    pub lamports: u64,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MoveStake")]
+pub struct MoveStakeInstructionAccounts {
+    pub source_stake: solana_pubkey::Pubkey,
+    pub destination_stake: solana_pubkey::Pubkey,
+    pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -1,33 +1,20 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x2caabd28807bfcc9")]
+#[carbon(discriminator = "0x06000000")]
 pub struct SetLockup {
     pub unix_timestamp: Option<i64>,
     pub epoch: Option<u64>,
+    #[carbon(serialize_as = "base58")]
     pub custodian: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetLockup")]
 pub struct SetLockupInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetLockup {
-    type ArrangedAccounts = SetLockupInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetLockupInstructionAccounts {
-            stake: stake.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,32 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
-#[carbon(discriminator = "0x169e0cb7765e9cff")]
+#[carbon(discriminator = "0x0c000000")]
 pub struct SetLockupChecked {
     pub unix_timestamp: Option<i64>,
     pub epoch: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetLockupChecked")]
 pub struct SetLockupCheckedInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetLockupChecked {
-    type ArrangedAccounts = SetLockupCheckedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetLockupCheckedInstructionAccounts {
-            stake: stake.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -5,7 +5,9 @@ use solana_pubkey::Pubkey;
 
 pub struct StakeProgramDecoder;
 
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
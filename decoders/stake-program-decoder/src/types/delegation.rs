@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Delegation {
+    #[carbon(serialize_as = "base58")]
+    pub voter_pubkey: solana_pubkey::Pubkey,
+    pub stake: u64,
+    pub activation_epoch: u64,
+    pub deactivation_epoch: u64,
+    pub warmup_cooldown_rate: f64,
+}
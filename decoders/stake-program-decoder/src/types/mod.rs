@@ -1,6 +1,14 @@
 pub mod authorized;
 pub use authorized::*;
+pub mod delegation;
+pub use delegation::*;
 pub mod lockup;
 pub use lockup::*;
+pub mod meta;
+pub use meta::*;
+pub mod stake;
+pub use stake::*;
 pub mod stake_authorize;
 pub use stake_authorize::*;
+pub mod stake_flags;
+pub use stake_flags::*;
@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize,
+    Debug,
+    CarbonSerialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash,
+    Default,
+)]
+pub struct StakeFlags {
+    pub bits: u8,
+}
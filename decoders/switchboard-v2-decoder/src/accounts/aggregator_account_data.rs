@@ -0,0 +1,69 @@
+use {
+    super::super::types::*,
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
+    serde_big_array::BigArray,
+};
+
+/// An aggregator, the feed combining the responses of oracles into a value,
+/// up to its latest confirmed round.
+///
+/// The fields following the result of the latest confirmed round, such as
+/// the oracle responses and the job definitions, are not decoded.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(
+    discriminator = "0xd9e64165c9a21b7d",
+    deserialize_with = "deserialize_aggregator"
+)]
+pub struct AggregatorAccountData {
+    #[carbon(serialize_as = "hex")]
+    pub name: [u8; 32],
+    #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
+    pub metadata: [u8; 128],
+    #[carbon(serialize_as = "hex")]
+    pub reserved1: [u8; 32],
+    #[carbon(serialize_as = "base58")]
+    pub queue_pubkey: solana_pubkey::Pubkey,
+    pub oracle_request_batch_size: u32,
+    pub min_oracle_results: u32,
+    pub min_job_results: u32,
+    pub min_update_delay_seconds: u32,
+    pub start_after: i64,
+    pub variance_threshold: SwitchboardDecimal,
+    pub force_report_period: i64,
+    pub expiration: i64,
+    pub consecutive_failure_count: u64,
+    pub next_allowed_update_time: i64,
+    pub is_locked: bool,
+    #[carbon(serialize_as = "base58")]
+    pub crank_pubkey: solana_pubkey::Pubkey,
+    pub latest_confirmed_round: AggregatorRound,
+}
+
+impl AggregatorAccountData {
+    /// Returns the name of the aggregator, without its zero padding.
+    pub fn name(&self) -> &str {
+        let length = self
+            .name
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(self.name.len());
+        core::str::from_utf8(&self.name[..length]).unwrap_or_default()
+    }
+
+    /// Returns the result of the latest confirmed round, or `None` if no
+    /// round succeeded yet.
+    pub fn latest_result(&self) -> Option<f64> {
+        if self.latest_confirmed_round.num_success == 0 {
+            return None;
+        }
+
+        Some(self.latest_confirmed_round.result.to_f64())
+    }
+}
+
+fn deserialize_aggregator(data: &[u8]) -> Option<AggregatorAccountData> {
+    carbon_core::deserialize::deserialize_prefix(data)
+}
@@ -0,0 +1,64 @@
+use {
+    super::SwitchboardV2Decoder,
+    crate::PROGRAM_ID,
+    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+};
+pub mod aggregator_account_data;
+
+pub enum SwitchboardV2Account {
+    AggregatorAccountData(aggregator_account_data::AggregatorAccountData),
+}
+
+impl AccountDecoder<'_> for SwitchboardV2Decoder {
+    type AccountType = SwitchboardV2Account;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) =
+            aggregator_account_data::AggregatorAccountData::deserialize(account.data.as_slice())
+        {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: SwitchboardV2Account::AggregatorAccountData(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_aggregator_account() {
+        // Act
+        let decoder = SwitchboardV2Decoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/aggregator_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            SwitchboardV2Account::AggregatorAccountData(aggregator) => {
+                assert_eq!(aggregator.name(), "SOL_USD");
+                assert_eq!(aggregator.min_oracle_results, 2);
+                assert_eq!(aggregator.latest_confirmed_round.num_success, 3);
+                assert_eq!(aggregator.latest_result(), Some(173.25));
+                assert_eq!(
+                    aggregator.latest_confirmed_round.round_open_slot,
+                    300_000_300
+                );
+            }
+        }
+    }
+}
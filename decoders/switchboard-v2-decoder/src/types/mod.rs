@@ -0,0 +1,4 @@
+pub mod aggregator_round;
+pub use aggregator_round::*;
+pub mod switchboard_decimal;
+pub use switchboard_decimal::*;
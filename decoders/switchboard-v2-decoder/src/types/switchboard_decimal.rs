@@ -0,0 +1,17 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+/// A decimal number, equal to `mantissa / 10^scale`.
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct SwitchboardDecimal {
+    pub mantissa: i128,
+    pub scale: u32,
+}
+
+impl SwitchboardDecimal {
+    /// Returns the number as a float.
+    pub fn to_f64(&self) -> f64 {
+        (0..self.scale).fold(self.mantissa as f64, |value, _| value / 10.0)
+    }
+}
@@ -1,32 +1,20 @@
-use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
+use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize, CarbonSerialize};
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x09000000")]
 pub struct AllocateWithSeed {
+    #[carbon(serialize_as = "base58")]
     pub base: solana_pubkey::Pubkey,
     pub seed: U64PrefixString,
     pub space: u64,
+    #[carbon(serialize_as = "base58")]
     pub program_address: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AllocateWithSeed")]
 pub struct AllocateWithSeedInstructionAccounts {
     pub new_account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AllocateWithSeed {
-    type ArrangedAccounts = AllocateWithSeedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [new_account, base_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AllocateWithSeedInstructionAccounts {
-            new_account: new_account.pubkey,
-            base_account: base_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
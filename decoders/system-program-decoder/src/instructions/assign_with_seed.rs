@@ -1,31 +1,19 @@
-use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
+use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize, CarbonSerialize};
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x0a000000")]
 pub struct AssignWithSeed {
+    #[carbon(serialize_as = "base58")]
     pub base: solana_pubkey::Pubkey,
     pub seed: U64PrefixString,
+    #[carbon(serialize_as = "base58")]
     pub program_address: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AssignWithSeed")]
 pub struct AssignWithSeedInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AssignWithSeed {
-    type ArrangedAccounts = AssignWithSeedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [account, base_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AssignWithSeedInstructionAccounts {
-            account: account.pubkey,
-            base_account: base_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
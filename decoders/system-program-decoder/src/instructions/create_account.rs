@@ -1,33 +1,20 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x00000000")]
 pub struct CreateAccount {
     pub lamports: u64,
     pub space: u64,
+    #[carbon(serialize_as = "base58")]
     pub program_address: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateAccount")]
 pub struct CreateAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub new_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateAccount {
-    type ArrangedAccounts = CreateAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, new_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CreateAccountInstructionAccounts {
-            payer: payer.pubkey,
-            new_account: new_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,12 +1,14 @@
-use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
+use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize, CarbonSerialize};
 
-#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[derive(CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0x03000000")]
 pub struct CreateAccountWithSeed {
+    #[carbon(serialize_as = "base58")]
     pub base: solana_pubkey::Pubkey,
     pub seed: U64PrefixString,
     pub amount: u64,
     pub space: u64,
+    #[carbon(serialize_as = "base58")]
     pub program_address: solana_pubkey::Pubkey,
 }
 
@@ -15,6 +17,7 @@ pub struct CreateAccountWithSeedInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub new_account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateAccountWithSeed {
@@ -23,14 +26,15 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAccountWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, new_account, _remaining @ ..] = accounts else {
+        let [payer, new_account, remaining_accounts @ ..] = accounts else {
             return None;
         };
 
         Some(CreateAccountWithSeedInstructionAccounts {
             payer: payer.pubkey,
             new_account: new_account.pubkey,
-            base_account: accounts.get(2).unwrap_or(payer).pubkey,
+            base_account: remaining_accounts.first().unwrap_or(payer).pubkey,
+            remaining_accounts: remaining_accounts.iter().skip(1).cloned().collect(),
         })
     }
 }
@@ -1,34 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x06000000")]
 pub struct InitializeNonceAccount {
+    #[carbon(serialize_as = "base58")]
     pub nonce_authority: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeNonceAccount")]
 pub struct InitializeNonceAccountInstructionAccounts {
     pub nonce_account: solana_pubkey::Pubkey,
     pub recent_blockhashes_sysvar: solana_pubkey::Pubkey,
     pub rent_sysvar: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeNonceAccount {
-    type ArrangedAccounts = InitializeNonceAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, recent_blockhashes_sysvar, rent_sysvar, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeNonceAccountInstructionAccounts {
-            nonce_account: nonce_account.pubkey,
-            recent_blockhashes_sysvar: recent_blockhashes_sysvar.pubkey,
-            rent_sysvar: rent_sysvar.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
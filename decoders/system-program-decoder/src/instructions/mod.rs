@@ -35,6 +35,10 @@ pub enum SystemProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SystemProgramDecoder {
     type InstructionType = SystemProgramInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(solana_program::system_program::id())
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -116,6 +120,7 @@ mod tests {
                 base_account: solana_pubkey::Pubkey::from_str_const(
                     "6bBmDxYqXeFbXN8SmtjTpiA3SrEDKsxK8RG6yhPGpa9G",
                 ),
+                remaining_accounts: vec![],
             };
 
         // Act
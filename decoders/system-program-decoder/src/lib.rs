@@ -2,6 +2,8 @@
 extern crate alloc;
 
 pub struct SystemProgramDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
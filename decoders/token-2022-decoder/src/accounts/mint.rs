@@ -1,11 +1,40 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    crate::{
+        extensions::{self, AccountType, Reader},
+        types::Extension,
+    },
+    alloc::vec::Vec,
+};
 
-#[derive(CarbonDeserialize, Debug)]
-#[carbon(discriminator = "0x6a5edd53c00a4a4a")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Mint {
     pub mint_authority: Option<solana_pubkey::Pubkey>,
     pub supply: u64,
     pub decimals: u8,
     pub is_initialized: bool,
     pub freeze_authority: Option<solana_pubkey::Pubkey>,
+    pub extensions: Vec<Extension>,
+}
+
+impl Mint {
+    pub const LEN: usize = 82;
+
+    /// Unpacks a mint and its extensions from the data of a Token-2022
+    /// account.
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN && extensions::account_type(data) != Some(AccountType::Mint) {
+            return None;
+        }
+
+        let mut reader = Reader::new(data);
+
+        Some(Self {
+            mint_authority: reader.coption_pubkey()?,
+            supply: reader.u64()?,
+            decimals: reader.u8()?,
+            is_initialized: reader.bool()?,
+            freeze_authority: reader.coption_pubkey()?,
+            extensions: extensions::parse_extensions(data),
+        })
+    }
 }
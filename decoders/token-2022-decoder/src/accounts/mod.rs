@@ -23,7 +23,7 @@ impl AccountDecoder<'_> for Token2022Decoder {
             return None;
         }
 
-        if let Some(decoded_account) = mint::Mint::deserialize(account.data.as_slice()) {
+        if let Some(decoded_account) = mint::Mint::unpack(account.data.as_slice()) {
             return Some(carbon_core::account::DecodedAccount {
                 lamports: account.lamports,
                 data: Token2022Account::Mint(decoded_account),
@@ -33,7 +33,7 @@ impl AccountDecoder<'_> for Token2022Decoder {
             });
         }
 
-        if let Some(decoded_account) = token::Token::deserialize(account.data.as_slice()) {
+        if let Some(decoded_account) = token::Token::unpack(account.data.as_slice()) {
             return Some(carbon_core::account::DecodedAccount {
                 lamports: account.lamports,
                 data: Token2022Account::Token(decoded_account),
@@ -1,10 +1,12 @@
 use {
-    super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    crate::{
+        extensions::{self, AccountType, Reader},
+        types::{AccountState, Extension},
+    },
+    alloc::vec::Vec,
 };
 
-#[derive(CarbonDeserialize, Debug)]
-#[carbon(discriminator = "0xc971463ece9dd1b2")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Token {
     pub mint: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
@@ -14,4 +16,31 @@ pub struct Token {
     pub is_native: Option<u64>,
     pub delegated_amount: u64,
     pub close_authority: Option<solana_pubkey::Pubkey>,
+    pub extensions: Vec<Extension>,
+}
+
+impl Token {
+    pub const LEN: usize = extensions::BASE_ACCOUNT_LENGTH;
+
+    /// Unpacks a token account and its extensions from the data of a
+    /// Token-2022 account.
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN && extensions::account_type(data) != Some(AccountType::Account) {
+            return None;
+        }
+
+        let mut reader = Reader::new(data);
+
+        Some(Self {
+            mint: reader.pubkey()?,
+            owner: reader.pubkey()?,
+            amount: reader.u64()?,
+            delegate: reader.coption_pubkey()?,
+            state: reader.account_state()?,
+            is_native: reader.coption_u64()?,
+            delegated_amount: reader.u64()?,
+            close_authority: reader.coption_pubkey()?,
+            extensions: extensions::parse_extensions(data),
+        })
+    }
 }
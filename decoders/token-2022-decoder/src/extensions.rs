@@ -0,0 +1,352 @@
+//! Parses the extensions stored after the base state of Token-2022 mints and
+//! token accounts.
+//!
+//! A mint or token account with extensions is padded to the length of a base
+//! token account, followed by a byte holding the account type and by the
+//! extensions, each encoded as a type, a length and a value (TLV). Known
+//! extensions are parsed into [`Extension`] variants; unknown ones are skipped.
+
+use {
+    crate::types::{AccountState, Extension, TransferFee},
+    alloc::{string::String, vec::Vec},
+    solana_pubkey::Pubkey,
+};
+
+/// The length of a base token account, which mints with extensions are padded
+/// to.
+pub const BASE_ACCOUNT_LENGTH: usize = 165;
+
+/// The length of a multisig account, which never has extensions.
+pub const MULTISIG_LENGTH: usize = 355;
+
+/// The type of an account with extensions, stored right after the base state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountType {
+    Mint,
+    Account,
+}
+
+/// Returns the type of an account with extensions, or `None` for accounts
+/// without extensions.
+pub fn account_type(data: &[u8]) -> Option<AccountType> {
+    if data.len() <= BASE_ACCOUNT_LENGTH || data.len() == MULTISIG_LENGTH {
+        return None;
+    }
+
+    match data[BASE_ACCOUNT_LENGTH] {
+        1 => Some(AccountType::Mint),
+        2 => Some(AccountType::Account),
+        _ => None,
+    }
+}
+
+/// Parses the extensions of a mint or token account.
+pub fn parse_extensions(data: &[u8]) -> Vec<Extension> {
+    let mut extensions = Vec::new();
+
+    if account_type(data).is_none() {
+        return extensions;
+    }
+
+    let mut tlv = Reader::new(&data[BASE_ACCOUNT_LENGTH + 1..]);
+    while let (Some(extension_type), Some(length)) = (tlv.u16(), tlv.u16()) {
+        // The rest of the account is zeroed once the extensions are written.
+        if extension_type == 0 {
+            break;
+        }
+
+        let Some(value) = tlv.bytes(length as usize) else {
+            break;
+        };
+
+        if let Some(extension) = parse_extension(extension_type, value) {
+            extensions.push(extension);
+        }
+    }
+
+    extensions
+}
+
+/// Parses the value of an extension, following the numbering of
+/// `ExtensionType`.
+pub fn parse_extension(extension_type: u16, value: &[u8]) -> Option<Extension> {
+    let mut value = Reader::new(value);
+
+    Some(match extension_type {
+        1 => Extension::TransferFeeConfig {
+            transfer_fee_config_authority: value.pubkey()?,
+            withdraw_withheld_authority: value.pubkey()?,
+            withheld_amount: value.u64()?,
+            older_transfer_fee: value.transfer_fee()?,
+            newer_transfer_fee: value.transfer_fee()?,
+        },
+        2 => Extension::TransferFeeAmount {
+            withheld_amount: value.u64()?,
+        },
+        3 => Extension::MintCloseAuthority {
+            close_authority: value.pubkey()?,
+        },
+        4 => Extension::ConfidentialTransferMint {
+            authority: value.optional_pubkey()?,
+            auto_approve_new_accounts: value.bool()?,
+            auditor_elgamal_pubkey: value.optional_pubkey()?,
+        },
+        5 => Extension::ConfidentialTransferAccount {
+            approved: value.bool()?,
+            elgamal_pubkey: value.pubkey()?,
+            pending_balance_low: value.array()?,
+            pending_balance_high: value.array()?,
+            available_balance: value.array()?,
+            decryptable_available_balance: value.array()?,
+            allow_confidential_credits: value.bool()?,
+            allow_non_confidential_credits: value.bool()?,
+            pending_balance_credit_counter: value.u64()?,
+            maximum_pending_balance_credit_counter: value.u64()?,
+            expected_pending_balance_credit_counter: value.u64()?,
+            actual_pending_balance_credit_counter: value.u64()?,
+        },
+        6 => Extension::DefaultAccountState {
+            state: value.account_state()?,
+        },
+        7 => Extension::ImmutableOwner {},
+        8 => Extension::MemoTransfer {
+            require_incoming_transfer_memos: value.bool()?,
+        },
+        9 => Extension::NonTransferable {},
+        10 => Extension::InterestBearingConfig {
+            rate_authority: value.pubkey()?,
+            initialization_timestamp: value.u64()?,
+            pre_update_average_rate: value.i16()?,
+            last_update_timestamp: value.u64()?,
+            current_rate: value.i16()?,
+        },
+        11 => Extension::CpiGuard {
+            lock_cpi: value.bool()?,
+        },
+        12 => Extension::PermanentDelegate {
+            delegate: value.pubkey()?,
+        },
+        13 => Extension::NonTransferableAccount {},
+        14 => Extension::TransferHook {
+            authority: value.pubkey()?,
+            program_id: value.pubkey()?,
+        },
+        15 => Extension::TransferHookAccount {
+            transferring: value.bool()?,
+        },
+        16 => Extension::ConfidentialTransferFee {
+            authority: value.optional_pubkey()?,
+            elgamal_pubkey: value.pubkey()?,
+            harvest_to_mint_enabled: value.bool()?,
+            withheld_amount: value.array()?,
+        },
+        17 => Extension::ConfidentialTransferFeeAmount {
+            withheld_amount: value.array()?,
+        },
+        18 => Extension::MetadataPointer {
+            authority: value.optional_pubkey()?,
+            metadata_address: value.optional_pubkey()?,
+        },
+        19 => Extension::TokenMetadata {
+            update_authority: value.optional_pubkey()?,
+            mint: value.pubkey()?,
+            name: value.string()?,
+            symbol: value.string()?,
+            uri: value.string()?,
+            additional_metadata: {
+                let count = value.u32()?;
+                let mut additional_metadata = Vec::new();
+                for _ in 0..count {
+                    additional_metadata.push((value.string()?, value.string()?));
+                }
+                additional_metadata
+            },
+        },
+        20 => Extension::GroupPointer {
+            authority: value.optional_pubkey()?,
+            group_address: value.optional_pubkey()?,
+        },
+        21 => Extension::TokenGroup {
+            update_authority: value.optional_pubkey()?,
+            mint: value.pubkey()?,
+            size: value.u64()?,
+            max_size: value.u64()?,
+        },
+        22 => Extension::GroupMemberPointer {
+            authority: value.optional_pubkey()?,
+            member_address: value.optional_pubkey()?,
+        },
+        23 => Extension::TokenGroupMember {
+            mint: value.pubkey()?,
+            group: value.pubkey()?,
+            member_number: value.u64()?,
+        },
+        _ => return None,
+    })
+}
+
+/// Reads little-endian values from account data.
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn bytes(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.data.len() < length {
+            return None;
+        }
+
+        let (bytes, rest) = self.data.split_at(length);
+        self.data = rest;
+        Some(bytes)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        Some(self.array::<1>()?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> Option<bool> {
+        Some(self.u8()? != 0)
+    }
+
+    pub(crate) fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn i16(&mut self) -> Option<i16> {
+        Some(i16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn pubkey(&mut self) -> Option<Pubkey> {
+        Some(Pubkey::new_from_array(self.array()?))
+    }
+
+    /// Reads a pubkey where all zeroes mean `None`, as in extensions.
+    pub(crate) fn optional_pubkey(&mut self) -> Option<Option<Pubkey>> {
+        let pubkey = self.pubkey()?;
+        Some((pubkey != Pubkey::default()).then_some(pubkey))
+    }
+
+    /// Reads a `COption<Pubkey>`, tagged with four bytes, as in base states.
+    pub(crate) fn coption_pubkey(&mut self) -> Option<Option<Pubkey>> {
+        let tag = self.u32()?;
+        let pubkey = self.pubkey()?;
+        Some((tag == 1).then_some(pubkey))
+    }
+
+    /// Reads a `COption<u64>`, tagged with four bytes, as in base states.
+    pub(crate) fn coption_u64(&mut self) -> Option<Option<u64>> {
+        let tag = self.u32()?;
+        let value = self.u64()?;
+        Some((tag == 1).then_some(value))
+    }
+
+    pub(crate) fn account_state(&mut self) -> Option<AccountState> {
+        match self.u8()? {
+            0 => Some(AccountState::Uninitialized),
+            1 => Some(AccountState::Initialized),
+            2 => Some(AccountState::Frozen),
+            _ => None,
+        }
+    }
+
+    fn transfer_fee(&mut self) -> Option<TransferFee> {
+        Some(TransferFee {
+            epoch: self.u64()?,
+            maximum_fee: self.u64()?,
+            transfer_fee_basis_points: self.u16()?,
+        })
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let length = self.u32()? as usize;
+        String::from_utf8(self.bytes(length)?.to_vec()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, alloc::vec};
+
+    fn tlv(extension_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut entry = Vec::new();
+        entry.extend_from_slice(&extension_type.to_le_bytes());
+        entry.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        entry.extend_from_slice(value);
+        entry
+    }
+
+    #[test]
+    fn test_parse_mint_extensions() {
+        // Arrange
+        let authority = Pubkey::new_unique();
+        let hook_program = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let mut data = vec![0; BASE_ACCOUNT_LENGTH];
+        data.push(1);
+        data.extend(tlv(
+            14,
+            &[authority.to_bytes(), hook_program.to_bytes()].concat(),
+        ));
+        data.extend(tlv(12, &delegate.to_bytes()));
+        data.extend(tlv(
+            10,
+            &[
+                authority.to_bytes().as_slice(),
+                &100u64.to_le_bytes(),
+                &25i16.to_le_bytes(),
+                &200u64.to_le_bytes(),
+                &(-5i16).to_le_bytes(),
+            ]
+            .concat(),
+        ));
+        data.extend(tlv(999, &[1, 2, 3]));
+        data.extend([0; 8]);
+
+        // Act
+        let extensions = parse_extensions(&data);
+
+        // Assert
+        assert_eq!(account_type(&data), Some(AccountType::Mint));
+        assert_eq!(
+            extensions,
+            vec![
+                Extension::TransferHook {
+                    authority,
+                    program_id: hook_program,
+                },
+                Extension::PermanentDelegate { delegate },
+                Extension::InterestBearingConfig {
+                    rate_authority: authority,
+                    initialization_timestamp: 100,
+                    pre_update_average_rate: 25,
+                    last_update_timestamp: 200,
+                    current_rate: -5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_extensions_of_base_account() {
+        assert_eq!(parse_extensions(&[0; BASE_ACCOUNT_LENGTH]), vec![]);
+        assert_eq!(parse_extensions(&[1; MULTISIG_LENGTH]), vec![]);
+    }
+}
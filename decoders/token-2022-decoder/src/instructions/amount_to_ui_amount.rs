@@ -1,27 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x17")]
 pub struct AmountToUiAmount {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AmountToUiAmount")]
 pub struct AmountToUiAmountInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AmountToUiAmount {
-    type ArrangedAccounts = AmountToUiAmountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AmountToUiAmountInstructionAccounts { mint: mint.pubkey })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,37 +1,24 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b")]
 pub struct ApplyConfidentialPendingBalance {
     pub confidential_transfer_discriminator: u8,
     pub expected_pending_balance_credit_counter: u64,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub new_decryptable_available_balance: [u8; 36],
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApplyConfidentialPendingBalance")]
 pub struct ApplyConfidentialPendingBalanceInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ApplyConfidentialPendingBalance {
-    type ArrangedAccounts = ApplyConfidentialPendingBalanceInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ApplyConfidentialPendingBalanceInstructionAccounts {
-            token: token.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
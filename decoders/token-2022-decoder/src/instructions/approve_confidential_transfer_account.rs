@@ -1,33 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b")]
 pub struct ApproveConfidentialTransferAccount {
     pub confidential_transfer_discriminator: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApproveConfidentialTransferAccount")]
 pub struct ApproveConfidentialTransferAccountInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ApproveConfidentialTransferAccount {
-    type ArrangedAccounts = ApproveConfidentialTransferAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ApproveConfidentialTransferAccountInstructionAccounts {
-            token: token.pubkey,
-            mint: mint.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
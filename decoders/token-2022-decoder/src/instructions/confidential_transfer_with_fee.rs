@@ -1,15 +1,16 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b")]
 pub struct ConfidentialTransferWithFee {
     pub confidential_transfer_discriminator: u8,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub new_source_decryptable_available_balance: [u8; 36],
     pub equality_proof_instruction_offset: i8,
     pub transfer_amount_ciphertext_validity_proof_instruction_offset: i8,
@@ -18,6 +19,8 @@ pub struct ConfidentialTransferWithFee {
     pub range_proof_instruction_offset: i8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ConfidentialTransferWithFee")]
 pub struct ConfidentialTransferWithFeeInstructionAccounts {
     pub source_token: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -29,32 +32,5 @@ pub struct ConfidentialTransferWithFeeInstructionAccounts {
     pub fee_ciphertext_validity_record: solana_pubkey::Pubkey,
     pub range_record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ConfidentialTransferWithFee {
-    type ArrangedAccounts = ConfidentialTransferWithFeeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [source_token, mint, destination_token, instructions_sysvar, equality_record, transfer_amount_ciphertext_validity_record, fee_sigma_record, fee_ciphertext_validity_record, range_record, authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ConfidentialTransferWithFeeInstructionAccounts {
-            source_token: source_token.pubkey,
-            mint: mint.pubkey,
-            destination_token: destination_token.pubkey,
-            instructions_sysvar: instructions_sysvar.pubkey,
-            equality_record: equality_record.pubkey,
-            transfer_amount_ciphertext_validity_record: transfer_amount_ciphertext_validity_record
-                .pubkey,
-            fee_sigma_record: fee_sigma_record.pubkey,
-            fee_ciphertext_validity_record: fee_ciphertext_validity_record.pubkey,
-            range_record: range_record.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b")]
 pub struct ConfidentialWithdraw {
@@ -12,11 +12,14 @@ pub struct ConfidentialWithdraw {
     pub amount: u64,
     pub decimals: u8,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub new_decryptable_available_balance: [u8; 36],
     pub equality_proof_instruction_offset: i8,
     pub range_proof_instruction_offset: i8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ConfidentialWithdraw")]
 pub struct ConfidentialWithdrawInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -24,27 +27,5 @@ pub struct ConfidentialWithdrawInstructionAccounts {
     pub equality_record: solana_pubkey::Pubkey,
     pub range_record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ConfidentialWithdraw {
-    type ArrangedAccounts = ConfidentialWithdrawInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, instructions_sysvar, equality_record, range_record, authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ConfidentialWithdrawInstructionAccounts {
-            token: token.pubkey,
-            mint: mint.pubkey,
-            instructions_sysvar: instructions_sysvar.pubkey,
-            equality_record: equality_record.pubkey,
-            range_record: range_record.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
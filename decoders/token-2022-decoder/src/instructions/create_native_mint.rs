@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1f")]
 pub struct CreateNativeMint {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CreateNativeMint")]
 pub struct CreateNativeMintInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub native_mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CreateNativeMint {
-    type ArrangedAccounts = CreateNativeMintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, native_mint, system_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CreateNativeMintInstructionAccounts {
-            payer: payer.pubkey,
-            native_mint: native_mint.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xfaa6b4fa0d0cb846")]
 pub struct EmitTokenMetadata {
@@ -9,22 +9,9 @@ pub struct EmitTokenMetadata {
     pub end: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EmitTokenMetadata")]
 pub struct EmitTokenMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EmitTokenMetadata {
-    type ArrangedAccounts = EmitTokenMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(EmitTokenMetadataInstructionAccounts {
-            metadata: metadata.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,25 +1,14 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x15")]
 pub struct GetAccountDataSize {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "GetAccountDataSize")]
 pub struct GetAccountDataSizeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for GetAccountDataSize {
-    type ArrangedAccounts = GetAccountDataSizeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(GetAccountDataSizeInstructionAccounts { mint: mint.pubkey })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
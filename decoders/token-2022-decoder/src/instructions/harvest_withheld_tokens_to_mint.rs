@@ -1,27 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1a")]
 pub struct HarvestWithheldTokensToMint {
     pub transfer_fee_discriminator: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "HarvestWithheldTokensToMint")]
 pub struct HarvestWithheldTokensToMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for HarvestWithheldTokensToMint {
-    type ArrangedAccounts = HarvestWithheldTokensToMintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(HarvestWithheldTokensToMintInstructionAccounts { mint: mint.pubkey })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x25")]
 pub struct HarvestWithheldTokensToMintForConfidentialTransferFee {
@@ -10,6 +10,7 @@ pub struct HarvestWithheldTokensToMintForConfidentialTransferFee {
 
 pub struct HarvestWithheldTokensToMintForConfidentialTransferFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts
@@ -21,13 +22,14 @@ impl carbon_core::deserialize::ArrangeAccounts
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
+        let [mint, remaining_accounts @ ..] = accounts else {
             return None;
         };
 
         Some(
             HarvestWithheldTokensToMintForConfidentialTransferFeeInstructionAccounts {
                 mint: mint.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
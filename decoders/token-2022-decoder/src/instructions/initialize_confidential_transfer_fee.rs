@@ -1,29 +1,20 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x25")]
 pub struct InitializeConfidentialTransferFee {
     pub confidential_transfer_fee_discriminator: u8,
+    #[carbon(serialize_as = "base58")]
     pub authority: Option<solana_pubkey::Pubkey>,
+    #[carbon(serialize_as = "base58")]
     pub withdraw_withheld_authority_el_gamal_pubkey: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeConfidentialTransferFee")]
 pub struct InitializeConfidentialTransferFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransferFee {
-    type ArrangedAccounts = InitializeConfidentialTransferFeeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeConfidentialTransferFeeInstructionAccounts { mint: mint.pubkey })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,30 +1,21 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b")]
 pub struct InitializeConfidentialTransferMint {
     pub confidential_transfer_discriminator: u8,
+    #[carbon(serialize_as = "base58")]
     pub authority: Option<solana_pubkey::Pubkey>,
     pub auto_approve_new_accounts: bool,
+    #[carbon(serialize_as = "base58")]
     pub auditor_elgamal_pubkey: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeConfidentialTransferMint")]
 pub struct InitializeConfidentialTransferMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransferMint {
-    type ArrangedAccounts = InitializeConfidentialTransferMintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeConfidentialTransferMintInstructionAccounts { mint: mint.pubkey })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,34 +1,20 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x79716c2736330004")]
 pub struct InitializeTokenGroup {
+    #[carbon(serialize_as = "base58")]
     pub update_authority: Option<solana_pubkey::Pubkey>,
     pub max_size: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeTokenGroup")]
 pub struct InitializeTokenGroupInstructionAccounts {
     pub group: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroup {
-    type ArrangedAccounts = InitializeTokenGroupInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [group, mint, mint_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeTokenGroupInstructionAccounts {
-            group: group.pubkey,
-            mint: mint.pubkey,
-            mint_authority: mint_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
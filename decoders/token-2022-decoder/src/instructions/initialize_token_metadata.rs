@@ -1,8 +1,8 @@
 use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xd2e11ea258b84d8d")]
 pub struct InitializeTokenMetadata {
@@ -11,28 +11,12 @@ pub struct InitializeTokenMetadata {
     pub uri: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeTokenMetadata")]
 pub struct InitializeTokenMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenMetadata {
-    type ArrangedAccounts = InitializeTokenMetadataInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, mint, mint_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(InitializeTokenMetadataInstructionAccounts {
-            metadata: metadata.pubkey,
-            update_authority: update_authority.pubkey,
-            mint: mint.pubkey,
-            mint_authority: mint_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
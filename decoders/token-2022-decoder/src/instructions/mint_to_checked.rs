@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0e")]
 pub struct MintToChecked {
@@ -9,26 +9,11 @@ pub struct MintToChecked {
     pub decimals: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MintToChecked")]
 pub struct MintToCheckedInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub token: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MintToChecked {
-    type ArrangedAccounts = MintToCheckedInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, token, mint_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(MintToCheckedInstructionAccounts {
-            mint: mint.pubkey,
-            token: token.pubkey,
-            mint_authority: mint_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
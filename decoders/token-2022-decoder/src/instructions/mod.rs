@@ -182,6 +182,10 @@ pub enum Token2022Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for Token2022Decoder {
     type InstructionType = Token2022Instruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
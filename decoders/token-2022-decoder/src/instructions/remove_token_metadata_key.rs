@@ -1,8 +1,8 @@
 use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xea122038598d25b5")]
 pub struct RemoveTokenMetadataKey {
@@ -10,24 +10,10 @@ pub struct RemoveTokenMetadataKey {
     pub key: String,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "RemoveTokenMetadataKey")]
 pub struct RemoveTokenMetadataKeyInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for RemoveTokenMetadataKey {
-    type ArrangedAccounts = RemoveTokenMetadataKeyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(RemoveTokenMetadataKeyInstructionAccounts {
-            metadata: metadata.pubkey,
-            update_authority: update_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1a")]
 pub struct SetTransferFee {
@@ -10,24 +10,10 @@ pub struct SetTransferFee {
     pub maximum_fee: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetTransferFee")]
 pub struct SetTransferFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub transfer_fee_config_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SetTransferFee {
-    type ArrangedAccounts = SetTransferFeeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, transfer_fee_config_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetTransferFeeInstructionAccounts {
-            mint: mint.pubkey,
-            transfer_fee_config_authority: transfer_fee_config_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
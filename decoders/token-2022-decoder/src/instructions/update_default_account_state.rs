@@ -1,10 +1,10 @@
 use {
     super::super::types::*,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1c")]
 pub struct UpdateDefaultAccountState {
@@ -12,24 +12,10 @@ pub struct UpdateDefaultAccountState {
     pub state: AccountState,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateDefaultAccountState")]
 pub struct UpdateDefaultAccountStateInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub freeze_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateDefaultAccountState {
-    type ArrangedAccounts = UpdateDefaultAccountStateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, freeze_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateDefaultAccountStateInstructionAccounts {
-            mint: mint.pubkey,
-            freeze_authority: freeze_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
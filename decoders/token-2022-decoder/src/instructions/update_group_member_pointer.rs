@@ -1,32 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x29")]
 pub struct UpdateGroupMemberPointer {
     pub group_member_pointer_discriminator: u8,
+    #[carbon(serialize_as = "base58")]
     pub member_address: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateGroupMemberPointer")]
 pub struct UpdateGroupMemberPointerInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub group_member_pointer_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateGroupMemberPointer {
-    type ArrangedAccounts = UpdateGroupMemberPointerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, group_member_pointer_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateGroupMemberPointerInstructionAccounts {
-            mint: mint.pubkey,
-            group_member_pointer_authority: group_member_pointer_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,32 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x28")]
 pub struct UpdateGroupPointer {
     pub group_pointer_discriminator: u8,
+    #[carbon(serialize_as = "base58")]
     pub group_address: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateGroupPointer")]
 pub struct UpdateGroupPointerInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub group_pointer_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateGroupPointer {
-    type ArrangedAccounts = UpdateGroupPointerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, group_pointer_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateGroupPointerInstructionAccounts {
-            mint: mint.pubkey,
-            group_pointer_authority: group_pointer_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,32 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x27")]
 pub struct UpdateMetadataPointer {
     pub metadata_pointer_discriminator: u8,
+    #[carbon(serialize_as = "base58")]
     pub metadata_address: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateMetadataPointer")]
 pub struct UpdateMetadataPointerInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub metadata_pointer_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataPointer {
-    type ArrangedAccounts = UpdateMetadataPointerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, metadata_pointer_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateMetadataPointerInstructionAccounts {
-            mint: mint.pubkey,
-            metadata_pointer_authority: metadata_pointer_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
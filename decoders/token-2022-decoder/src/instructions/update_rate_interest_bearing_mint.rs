@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x21")]
 pub struct UpdateRateInterestBearingMint {
@@ -9,24 +9,10 @@ pub struct UpdateRateInterestBearingMint {
     pub rate: i16,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateRateInterestBearingMint")]
 pub struct UpdateRateInterestBearingMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub rate_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateRateInterestBearingMint {
-    type ArrangedAccounts = UpdateRateInterestBearingMintInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, rate_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateRateInterestBearingMintInstructionAccounts {
-            mint: mint.pubkey,
-            rate_authority: rate_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa1695801edddd8cb")]
 pub struct UpdateTokenGroupUpdateAuthority {
+    #[carbon(serialize_as = "base58")]
     pub new_update_authority: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateTokenGroupUpdateAuthority")]
 pub struct UpdateTokenGroupUpdateAuthorityInstructionAccounts {
     pub group: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateTokenGroupUpdateAuthority {
-    type ArrangedAccounts = UpdateTokenGroupUpdateAuthorityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [group, update_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateTokenGroupUpdateAuthorityInstructionAccounts {
-            group: group.pubkey,
-            update_authority: update_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x26")]
 pub struct WithdrawExcessLamports {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawExcessLamports")]
 pub struct WithdrawExcessLamportsInstructionAccounts {
     pub source_account: solana_pubkey::Pubkey,
     pub destination_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawExcessLamports {
-    type ArrangedAccounts = WithdrawExcessLamportsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [source_account, destination_account, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(WithdrawExcessLamportsInstructionAccounts {
-            source_account: source_account.pubkey,
-            destination_account: destination_account.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1a")]
 pub struct WithdrawWithheldTokensFromAccounts {
@@ -9,26 +9,11 @@ pub struct WithdrawWithheldTokensFromAccounts {
     pub num_token_accounts: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "WithdrawWithheldTokensFromAccounts")]
 pub struct WithdrawWithheldTokensFromAccountsInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub fee_receiver: solana_pubkey::Pubkey,
     pub withdraw_withheld_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromAccounts {
-    type ArrangedAccounts = WithdrawWithheldTokensFromAccountsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, fee_receiver, withdraw_withheld_authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(WithdrawWithheldTokensFromAccountsInstructionAccounts {
-            mint: mint.pubkey,
-            fee_receiver: fee_receiver.pubkey,
-            withdraw_withheld_authority: withdraw_withheld_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,16 +1,17 @@
 use {
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
     serde_big_array::BigArray,
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x25")]
 pub struct WithdrawWithheldTokensFromMintForConfidentialTransferFee {
     pub confidential_transfer_fee_discriminator: u8,
     pub proof_instruction_offset: i8,
     #[serde(with = "BigArray")]
+    #[carbon(serialize_as = "hex")]
     pub new_decryptable_available_balance: [u8; 36],
 }
 
@@ -20,6 +21,7 @@ pub struct WithdrawWithheldTokensFromMintForConfidentialTransferFeeInstructionAc
     pub instructions_sysvar_or_context_state: solana_pubkey::Pubkey,
     pub record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, destination, instructions_sysvar_or_context_state, record, authority, _remaining @ ..] =
+        let [mint, destination, instructions_sysvar_or_context_state, record, authority, remaining_accounts @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,7 @@ impl carbon_core::deserialize::ArrangeAccounts
                 instructions_sysvar_or_context_state: instructions_sysvar_or_context_state.pubkey,
                 record: record.pubkey,
                 authority: authority.pubkey,
+                remaining_accounts: remaining_accounts.to_vec(),
             },
         )
     }
@@ -3,7 +3,10 @@ extern crate alloc;
 
 use solana_pubkey::Pubkey;
 pub struct Token2022Decoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+pub mod extensions;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
@@ -1,16 +1,18 @@
 use {
     super::*,
     alloc::{string::String, vec::Vec},
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub enum Extension {
     Uninitialized,
     TransferFeeConfig {
+        #[carbon(serialize_as = "base58")]
         transfer_fee_config_authority: solana_pubkey::Pubkey,
+        #[carbon(serialize_as = "base58")]
         withdraw_withheld_authority: solana_pubkey::Pubkey,
         withheld_amount: u64,
         older_transfer_fee: TransferFee,
@@ -20,23 +22,31 @@ pub enum Extension {
         withheld_amount: u64,
     },
     MintCloseAuthority {
+        #[carbon(serialize_as = "base58")]
         close_authority: solana_pubkey::Pubkey,
     },
     ConfidentialTransferMint {
+        #[carbon(serialize_as = "base58")]
         authority: Option<solana_pubkey::Pubkey>,
         auto_approve_new_accounts: bool,
+        #[carbon(serialize_as = "base58")]
         auditor_elgamal_pubkey: Option<solana_pubkey::Pubkey>,
     },
     ConfidentialTransferAccount {
         approved: bool,
+        #[carbon(serialize_as = "base58")]
         elgamal_pubkey: solana_pubkey::Pubkey,
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         pending_balance_low: [u8; 64],
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         pending_balance_high: [u8; 64],
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         available_balance: [u8; 64],
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         decryptable_available_balance: [u8; 36],
         allow_confidential_credits: bool,
         allow_non_confidential_credits: bool,
@@ -54,6 +64,7 @@ pub enum Extension {
     },
     NonTransferable {},
     InterestBearingConfig {
+        #[carbon(serialize_as = "base58")]
         rate_authority: solana_pubkey::Pubkey,
         initialization_timestamp: u64,
         pre_update_average_rate: i16,
@@ -64,33 +75,44 @@ pub enum Extension {
         lock_cpi: bool,
     },
     PermanentDelegate {
+        #[carbon(serialize_as = "base58")]
         delegate: solana_pubkey::Pubkey,
     },
     NonTransferableAccount {},
     TransferHook {
+        #[carbon(serialize_as = "base58")]
         authority: solana_pubkey::Pubkey,
+        #[carbon(serialize_as = "base58")]
         program_id: solana_pubkey::Pubkey,
     },
     TransferHookAccount {
         transferring: bool,
     },
     ConfidentialTransferFee {
+        #[carbon(serialize_as = "base58")]
         authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         elgamal_pubkey: solana_pubkey::Pubkey,
         harvest_to_mint_enabled: bool,
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         withheld_amount: [u8; 64],
     },
     ConfidentialTransferFeeAmount {
         #[serde(with = "BigArray")]
+        #[carbon(serialize_as = "hex")]
         withheld_amount: [u8; 64],
     },
     MetadataPointer {
+        #[carbon(serialize_as = "base58")]
         authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         metadata_address: Option<solana_pubkey::Pubkey>,
     },
     TokenMetadata {
+        #[carbon(serialize_as = "base58")]
         update_authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         mint: solana_pubkey::Pubkey,
         name: String,
         symbol: String,
@@ -98,21 +120,29 @@ pub enum Extension {
         additional_metadata: Vec<(String, String)>,
     },
     GroupPointer {
+        #[carbon(serialize_as = "base58")]
         authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         group_address: Option<solana_pubkey::Pubkey>,
     },
     TokenGroup {
+        #[carbon(serialize_as = "base58")]
         update_authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         mint: solana_pubkey::Pubkey,
         size: u64,
         max_size: u64,
     },
     GroupMemberPointer {
+        #[carbon(serialize_as = "base58")]
         authority: Option<solana_pubkey::Pubkey>,
+        #[carbon(serialize_as = "base58")]
         member_address: Option<solana_pubkey::Pubkey>,
     },
     TokenGroupMember {
+        #[carbon(serialize_as = "base58")]
         mint: solana_pubkey::Pubkey,
+        #[carbon(serialize_as = "base58")]
         group: solana_pubkey::Pubkey,
         member_number: u64,
     },
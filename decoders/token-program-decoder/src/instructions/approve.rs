@@ -1,35 +1,18 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x04")]
 pub struct Approve {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Approve")]
 pub struct ApproveAccounts {
     pub source: solana_pubkey::Pubkey,
     pub delegate: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for Approve {
-    type ArrangedAccounts = ApproveAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [source, delegate, owner, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ApproveAccounts {
-            source: source.pubkey,
-            delegate: delegate.pubkey,
-            owner: owner.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0d")]
 pub struct ApproveChecked {
@@ -9,6 +9,8 @@ pub struct ApproveChecked {
     pub decimals: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ApproveChecked")]
 pub struct ApproveCheckedAccounts {
     pub source: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
@@ -16,23 +18,3 @@ pub struct ApproveCheckedAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for ApproveChecked {
-    type ArrangedAccounts = ApproveCheckedAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [source, mint, delegate, owner, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ApproveCheckedAccounts {
-            source: source.pubkey,
-            mint: mint.pubkey,
-            delegate: delegate.pubkey,
-            owner: owner.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0f")]
 pub struct BurnChecked {
@@ -9,28 +9,11 @@ pub struct BurnChecked {
     pub decimals: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "BurnChecked")]
 pub struct BurnCheckedAccounts {
     pub account: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for BurnChecked {
-    type ArrangedAccounts = BurnCheckedAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, owner, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(BurnCheckedAccounts {
-            account: account.pubkey,
-            mint: mint.pubkey,
-            owner: owner.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
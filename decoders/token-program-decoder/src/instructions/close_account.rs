@@ -1,33 +1,16 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x09")]
 pub struct CloseAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseAccount")]
 pub struct CloseAccountAccounts {
     pub account: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseAccount {
-    type ArrangedAccounts = CloseAccountAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [account, destination, owner, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CloseAccountAccounts {
-            account: account.pubkey,
-            destination: destination.pubkey,
-            owner: owner.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x14")]
 pub struct InitializeMint2 {
     pub decimals: u8,
+    #[carbon(serialize_as = "base58")]
     pub mint_authority: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub freeze_authority: Option<solana_pubkey::Pubkey>,
 }
 
@@ -1,29 +1,16 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x13")]
 pub struct InitializeMultisig2 {
     pub m: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeMultisig2")]
 pub struct InitializeMultisig2Accounts {
     pub account: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeMultisig2 {
-    type ArrangedAccounts = InitializeMultisig2Accounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let account = accounts.first()?;
-
-        Some(InitializeMultisig2Accounts {
-            account: account.pubkey,
-            remaining_accounts: accounts.get(1..).unwrap_or_default().to_vec(),
-        })
-    }
-}
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0e")]
 pub struct MintToChecked {
@@ -9,28 +9,11 @@ pub struct MintToChecked {
     pub decimals: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MintToChecked")]
 pub struct MintToCheckedAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for MintToChecked {
-    type ArrangedAccounts = MintToCheckedAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [mint, account, authority, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(MintToCheckedAccounts {
-            mint: mint.pubkey,
-            account: account.pubkey,
-            authority: authority.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -67,6 +67,10 @@ pub enum TokenProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for TokenProgramDecoder {
     type InstructionType = TokenProgramInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(spl_token::id())
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -1,37 +1,22 @@
 use {
     crate::types::*,
     alloc::vec::Vec,
-    carbon_core::{borsh, CarbonDeserialize},
+    carbon_core::{borsh, CarbonDeserialize, CarbonSerialize},
 };
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x06")]
 pub struct SetAuthority {
     pub authority_type: AuthorityType,
+    #[carbon(serialize_as = "base58")]
     pub new_authority: Option<solana_pubkey::Pubkey>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SetAuthority")]
 pub struct SetAuthorityAccounts {
     pub account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
-
-impl carbon_core::deserialize::ArrangeAccounts for SetAuthority {
-    type ArrangedAccounts = SetAuthorityAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [account, authority, remaining_accounts @ ..] = accounts else {
-            return None;
-        };
-
-        Some(SetAuthorityAccounts {
-            account: account.pubkey,
-            authority: authority.pubkey,
-            remaining_accounts: remaining_accounts.to_vec(),
-        })
-    }
-}
@@ -4,6 +4,8 @@ extern crate alloc;
 
 pub struct TokenProgramDecoder;
 
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
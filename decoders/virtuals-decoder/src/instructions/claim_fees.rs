@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x52fbe99c0c34b8ca")]
 pub struct ClaimFees {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ClaimFees")]
 pub struct ClaimFeesInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub vpool: solana_pubkey::Pubkey,
@@ -35,49 +37,5 @@ pub struct ClaimFeesInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub dynamic_amm_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ClaimFees {
-    type ArrangedAccounts = ClaimFeesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [payer, vpool, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, platform, platform_virtuals_ata, platform_token_ata, creator_virtuals_ata, creator_token_ata, pool, lp_mint, lock_escrow, escrow_vault, token_program, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, vault_program, associated_token_program, system_program, dynamic_amm_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ClaimFeesInstructionAccounts {
-            payer: payer.pubkey,
-            vpool: vpool.pubkey,
-            virtuals_mint: virtuals_mint.pubkey,
-            token_mint: token_mint.pubkey,
-            vpool_virtuals_ata: vpool_virtuals_ata.pubkey,
-            vpool_token_ata: vpool_token_ata.pubkey,
-            platform: platform.pubkey,
-            platform_virtuals_ata: platform_virtuals_ata.pubkey,
-            platform_token_ata: platform_token_ata.pubkey,
-            creator_virtuals_ata: creator_virtuals_ata.pubkey,
-            creator_token_ata: creator_token_ata.pubkey,
-            pool: pool.pubkey,
-            lp_mint: lp_mint.pubkey,
-            lock_escrow: lock_escrow.pubkey,
-            escrow_vault: escrow_vault.pubkey,
-            token_program: token_program.pubkey,
-            virtuals_vault: virtuals_vault.pubkey,
-            token_vault: token_vault.pubkey,
-            virtuals_token_vault: virtuals_token_vault.pubkey,
-            token_token_vault: token_token_vault.pubkey,
-            virtuals_vault_lp_mint: virtuals_vault_lp_mint.pubkey,
-            token_vault_lp_mint: token_vault_lp_mint.pubkey,
-            virtuals_vault_lp: virtuals_vault_lp.pubkey,
-            token_vault_lp: token_vault_lp.pubkey,
-            vault_program: vault_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-            dynamic_amm_program: dynamic_amm_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x350c769efdefb9d6")]
 pub struct InitializeMeteoraAccounts {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeMeteoraAccounts")]
 pub struct InitializeMeteoraAccountsInstructionAccounts {
     pub vpool: solana_pubkey::Pubkey,
     pub meteora_deployer: solana_pubkey::Pubkey,
@@ -43,57 +45,5 @@ pub struct InitializeMeteoraAccountsInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub dynamic_amm_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeMeteoraAccounts {
-    type ArrangedAccounts = InitializeMeteoraAccountsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [vpool, meteora_deployer, meteora_deployer_virtuals_ata, meteora_deployer_token_ata, vpool_virtuals_ata, vpool_token_ata, lock_escrow, escrow_vault, pool, config, lp_mint, virtuals_mint, token_mint, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, pool_virtuals_ata, pool_token_ata, meteora_deployer_pool_lp, protocol_virtuals_fee, protocol_token_fee, payer, token_metadata, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, dynamic_amm_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeMeteoraAccountsInstructionAccounts {
-            vpool: vpool.pubkey,
-            meteora_deployer: meteora_deployer.pubkey,
-            meteora_deployer_virtuals_ata: meteora_deployer_virtuals_ata.pubkey,
-            meteora_deployer_token_ata: meteora_deployer_token_ata.pubkey,
-            vpool_virtuals_ata: vpool_virtuals_ata.pubkey,
-            vpool_token_ata: vpool_token_ata.pubkey,
-            lock_escrow: lock_escrow.pubkey,
-            escrow_vault: escrow_vault.pubkey,
-            pool: pool.pubkey,
-            config: config.pubkey,
-            lp_mint: lp_mint.pubkey,
-            virtuals_mint: virtuals_mint.pubkey,
-            token_mint: token_mint.pubkey,
-            virtuals_vault: virtuals_vault.pubkey,
-            token_vault: token_vault.pubkey,
-            virtuals_token_vault: virtuals_token_vault.pubkey,
-            token_token_vault: token_token_vault.pubkey,
-            virtuals_vault_lp_mint: virtuals_vault_lp_mint.pubkey,
-            token_vault_lp_mint: token_vault_lp_mint.pubkey,
-            virtuals_vault_lp: virtuals_vault_lp.pubkey,
-            token_vault_lp: token_vault_lp.pubkey,
-            pool_virtuals_ata: pool_virtuals_ata.pubkey,
-            pool_token_ata: pool_token_ata.pubkey,
-            meteora_deployer_pool_lp: meteora_deployer_pool_lp.pubkey,
-            protocol_virtuals_fee: protocol_virtuals_fee.pubkey,
-            protocol_token_fee: protocol_token_fee.pubkey,
-            payer: payer.pubkey,
-            token_metadata: token_metadata.pubkey,
-            rent: rent.pubkey,
-            mint_metadata: mint_metadata.pubkey,
-            metadata_program: metadata_program.pubkey,
-            vault_program: vault_program.pubkey,
-            token_program: token_program.pubkey,
-            associated_token_program: associated_token_program.pubkey,
-            system_program: system_program.pubkey,
-            dynamic_amm_program: dynamic_amm_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
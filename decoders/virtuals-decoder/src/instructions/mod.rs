@@ -2,15 +2,19 @@ use crate::PROGRAM_ID;
 
 use super::VirtualsDecoder;
 pub mod buy;
+#[cfg(feature = "events")]
 pub mod buy_event;
 pub mod claim_fees;
 pub mod create_meteora_pool;
+#[cfg(feature = "events")]
 pub mod graduation_event;
 pub mod initialize;
 pub mod initialize_meteora_accounts;
 pub mod launch;
+#[cfg(feature = "events")]
 pub mod launch_event;
 pub mod sell;
+#[cfg(feature = "events")]
 pub mod sell_event;
 pub mod update_pool_creator;
 
@@ -33,15 +37,23 @@ pub enum VirtualsInstruction {
     Launch(launch::Launch),
     Sell(sell::Sell),
     UpdatePoolCreator(update_pool_creator::UpdatePoolCreator),
+    #[cfg(feature = "events")]
     BuyEvent(buy_event::BuyEvent),
+    #[cfg(feature = "events")]
     GraduationEvent(graduation_event::GraduationEvent),
+    #[cfg(feature = "events")]
     LaunchEvent(launch_event::LaunchEvent),
+    #[cfg(feature = "events")]
     SellEvent(sell_event::SellEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for VirtualsDecoder {
     type InstructionType = VirtualsInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -59,9 +71,13 @@ impl carbon_core::instruction::InstructionDecoder<'_> for VirtualsDecoder {
             VirtualsInstruction::Launch => launch::Launch,
             VirtualsInstruction::Sell => sell::Sell,
             VirtualsInstruction::UpdatePoolCreator => update_pool_creator::UpdatePoolCreator,
+            #[cfg(feature = "events")]
             VirtualsInstruction::BuyEvent => buy_event::BuyEvent,
+            #[cfg(feature = "events")]
             VirtualsInstruction::GraduationEvent => graduation_event::GraduationEvent,
+            #[cfg(feature = "events")]
             VirtualsInstruction::LaunchEvent => launch_event::LaunchEvent,
+            #[cfg(feature = "events")]
             VirtualsInstruction::SellEvent => sell_event::SellEvent,
         )
     }
@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x33e685a4017f83ad")]
 pub struct Sell {
@@ -9,6 +9,8 @@ pub struct Sell {
     pub min_amount_out: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Sell")]
 pub struct SellInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub vpool: solana_pubkey::Pubkey,
@@ -20,31 +22,5 @@ pub struct SellInstructionAccounts {
     pub platform_prototype_virtuals_ata: solana_pubkey::Pubkey,
     pub vpool_virtuals_ata: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Sell {
-    type ArrangedAccounts = SellInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [user, vpool, token_mint, user_virtuals_ata, user_token_ata, vpool_token_ata, platform_prototype, platform_prototype_virtuals_ata, vpool_virtuals_ata, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SellInstructionAccounts {
-            user: user.pubkey,
-            vpool: vpool.pubkey,
-            token_mint: token_mint.pubkey,
-            user_virtuals_ata: user_virtuals_ata.pubkey,
-            user_token_ata: user_token_ata.pubkey,
-            vpool_token_ata: vpool_token_ata.pubkey,
-            platform_prototype: platform_prototype.pubkey,
-            platform_prototype_virtuals_ata: platform_prototype_virtuals_ata.pubkey,
-            vpool_virtuals_ata: vpool_virtuals_ata.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
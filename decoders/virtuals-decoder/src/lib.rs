@@ -5,7 +5,9 @@ extern crate alloc;
 use solana_pubkey::Pubkey;
 
 pub struct VirtualsDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
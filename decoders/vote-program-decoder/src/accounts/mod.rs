@@ -0,0 +1,98 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use crate::PROGRAM_ID;
+
+use super::VoteProgramDecoder;
+pub mod vote_state;
+
+pub enum VoteProgramAccount {
+    VoteState(vote_state::VoteState),
+}
+
+impl AccountDecoder<'_> for VoteProgramDecoder {
+    type AccountType = VoteProgramAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if !account.owner.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        if let Some(decoded_account) = vote_state::VoteState::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: VoteProgramAccount::VoteState(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{AuthorizedVoter, EpochCredits, LandedVote, Lockout};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_vote_account() {
+        // Arrange
+        let authority =
+            solana_pubkey::Pubkey::from_str_const("9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K");
+
+        // Act
+        let decoder = VoteProgramDecoder;
+        let account = carbon_test_utils::read_account("tests/fixtures/vote_account.json")
+            .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        match decoded_account.data {
+            VoteProgramAccount::VoteState(vote_state) => {
+                assert_eq!(
+                    vote_state.node_pubkey,
+                    solana_pubkey::Pubkey::from_str_const(
+                        "GAPvsBHZZmkRjPfGX1N9vgEJ8dDq4T8Nsc5xXsDYaDkn"
+                    )
+                );
+                assert_eq!(vote_state.authorized_withdrawer, authority);
+                assert_eq!(vote_state.commission, 5);
+                assert_eq!(
+                    vote_state.votes.last(),
+                    Some(&LandedVote {
+                        latency: 1,
+                        lockout: Lockout {
+                            slot: 300000002,
+                            confirmation_count: 1,
+                        },
+                    })
+                );
+                assert_eq!(vote_state.root_slot, Some(300000000));
+                assert_eq!(
+                    vote_state.authorized_voters,
+                    [AuthorizedVoter {
+                        epoch: 700,
+                        pubkey: authority,
+                    }]
+                );
+                assert_eq!(vote_state.prior_voters.len(), 32);
+                assert_eq!(
+                    vote_state.epoch_credits.last(),
+                    Some(&EpochCredits {
+                        epoch: 700,
+                        credits: 1400,
+                        prev_credits: 1000,
+                    })
+                );
+                assert_eq!(vote_state.last_timestamp.slot, 300000002);
+                assert_eq!(vote_state.last_timestamp.timestamp, 1730000001);
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::super::types::*;
+
+use alloc::vec::Vec;
+use carbon_core::{
+    borsh::{maybestd::io, BorshDeserialize},
+    deserialize::U64PrefixVec,
+    CarbonSerialize,
+};
+
+/// Number of entries in the vote state's fixed-size `prior_voters` ring buffer.
+const MAX_PRIOR_VOTERS: usize = 32;
+
+#[derive(Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct VoteState {
+    #[carbon(serialize_as = "base58")]
+    pub node_pubkey: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub authorized_withdrawer: solana_pubkey::Pubkey,
+    pub commission: u8,
+    pub votes: Vec<LandedVote>,
+    pub root_slot: Option<u64>,
+    pub authorized_voters: Vec<AuthorizedVoter>,
+    pub prior_voters: Vec<PriorVoter>,
+    pub prior_voters_idx: u64,
+    pub prior_voters_is_empty: bool,
+    pub epoch_credits: Vec<EpochCredits>,
+    pub last_timestamp: BlockTimestamp,
+}
+
+// Vote accounts hold a bincode encoded `VoteStateVersions`, tagged with a
+// `u32`, and are allocated with trailing space for the state to grow into.
+// Both the current layout and the `V1_14_11` layout, whose votes carry no
+// latency, are decoded into the current `VoteState`.
+impl BorshDeserialize for VoteState {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let version = u32::deserialize_reader(reader)?;
+        if version != 1 && version != 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unsupported VoteStateVersions variant",
+            ));
+        }
+
+        let node_pubkey = solana_pubkey::Pubkey::deserialize_reader(reader)?;
+        let authorized_withdrawer = solana_pubkey::Pubkey::deserialize_reader(reader)?;
+        let commission = u8::deserialize_reader(reader)?;
+        let votes = if version == 2 {
+            U64PrefixVec::<LandedVote>::deserialize_reader(reader)?.0
+        } else {
+            U64PrefixVec::<Lockout>::deserialize_reader(reader)?
+                .0
+                .into_iter()
+                .map(|lockout| LandedVote {
+                    latency: 0,
+                    lockout,
+                })
+                .collect()
+        };
+        let root_slot = Option::<u64>::deserialize_reader(reader)?;
+        let authorized_voters = U64PrefixVec::<AuthorizedVoter>::deserialize_reader(reader)?.0;
+
+        let mut prior_voters = Vec::with_capacity(MAX_PRIOR_VOTERS);
+        for _ in 0..MAX_PRIOR_VOTERS {
+            prior_voters.push(PriorVoter::deserialize_reader(reader)?);
+        }
+        let prior_voters_idx = u64::deserialize_reader(reader)?;
+        let prior_voters_is_empty = bool::deserialize_reader(reader)?;
+
+        let epoch_credits = U64PrefixVec::<EpochCredits>::deserialize_reader(reader)?.0;
+        let last_timestamp = BlockTimestamp::deserialize_reader(reader)?;
+
+        Ok(Self {
+            node_pubkey,
+            authorized_withdrawer,
+            commission,
+            votes,
+            root_slot,
+            authorized_voters,
+            prior_voters,
+            prior_voters_idx,
+            prior_voters_is_empty,
+            epoch_credits,
+            last_timestamp,
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for VoteState {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        let mut data = data;
+        Self::deserialize_reader(&mut data).ok()
+    }
+}
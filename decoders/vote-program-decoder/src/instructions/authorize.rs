@@ -0,0 +1,22 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x01000000")]
+pub struct Authorize {
+    #[carbon(serialize_as = "base58")]
+    pub new_authority: solana_pubkey::Pubkey,
+    pub vote_authorize: VoteAuthorize,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Authorize")]
+pub struct AuthorizeInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,21 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0b000000")]
+pub struct AuthorizeCheckedWithSeed {
+    pub args: VoteAuthorizeCheckedWithSeedArgs,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AuthorizeCheckedWithSeed")]
+pub struct AuthorizeCheckedWithSeedInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub authority_base: solana_pubkey::Pubkey,
+    pub new_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,20 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0a000000")]
+pub struct AuthorizeWithSeed {
+    pub args: VoteAuthorizeWithSeedArgs,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AuthorizeWithSeed")]
+pub struct AuthorizeWithSeedInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub authority_base: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
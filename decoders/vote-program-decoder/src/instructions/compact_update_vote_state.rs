@@ -0,0 +1,19 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0c000000")]
+pub struct CompactUpdateVoteState {
+    pub vote_state_update: CompactVoteStateUpdate,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CompactUpdateVoteState")]
+pub struct CompactUpdateVoteStateInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
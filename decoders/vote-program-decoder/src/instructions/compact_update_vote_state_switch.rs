@@ -0,0 +1,21 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0d000000")]
+pub struct CompactUpdateVoteStateSwitch {
+    pub vote_state_update: CompactVoteStateUpdate,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CompactUpdateVoteStateSwitch")]
+pub struct CompactUpdateVoteStateSwitchInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,21 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x00000000")]
+pub struct InitializeAccount {
+    pub vote_init: VoteInit,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeAccount")]
+pub struct InitializeAccountInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub rent: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub node: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,203 @@
+use crate::PROGRAM_ID;
+
+use super::VoteProgramDecoder;
+pub mod authorize;
+pub mod authorize_checked;
+pub mod authorize_checked_with_seed;
+pub mod authorize_with_seed;
+pub mod compact_update_vote_state;
+pub mod compact_update_vote_state_switch;
+pub mod initialize_account;
+pub mod tower_sync;
+pub mod tower_sync_switch;
+pub mod update_commission;
+pub mod update_validator_identity;
+pub mod update_vote_state;
+pub mod update_vote_state_switch;
+pub mod vote;
+pub mod vote_switch;
+pub mod withdraw;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum VoteProgramInstruction {
+    InitializeAccount(initialize_account::InitializeAccount),
+    Authorize(authorize::Authorize),
+    Vote(vote::Vote),
+    Withdraw(withdraw::Withdraw),
+    UpdateValidatorIdentity(update_validator_identity::UpdateValidatorIdentity),
+    UpdateCommission(update_commission::UpdateCommission),
+    VoteSwitch(vote_switch::VoteSwitch),
+    AuthorizeChecked(authorize_checked::AuthorizeChecked),
+    UpdateVoteState(update_vote_state::UpdateVoteState),
+    UpdateVoteStateSwitch(update_vote_state_switch::UpdateVoteStateSwitch),
+    AuthorizeWithSeed(authorize_with_seed::AuthorizeWithSeed),
+    AuthorizeCheckedWithSeed(authorize_checked_with_seed::AuthorizeCheckedWithSeed),
+    CompactUpdateVoteState(compact_update_vote_state::CompactUpdateVoteState),
+    CompactUpdateVoteStateSwitch(compact_update_vote_state_switch::CompactUpdateVoteStateSwitch),
+    TowerSync(tower_sync::TowerSync),
+    TowerSyncSwitch(tower_sync_switch::TowerSyncSwitch),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for VoteProgramDecoder {
+    type InstructionType = VoteProgramInstruction;
+
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            VoteProgramInstruction::InitializeAccount => initialize_account::InitializeAccount,
+            VoteProgramInstruction::Authorize => authorize::Authorize,
+            VoteProgramInstruction::Vote => vote::Vote,
+            VoteProgramInstruction::Withdraw => withdraw::Withdraw,
+            VoteProgramInstruction::UpdateValidatorIdentity => update_validator_identity::UpdateValidatorIdentity,
+            VoteProgramInstruction::UpdateCommission => update_commission::UpdateCommission,
+            VoteProgramInstruction::VoteSwitch => vote_switch::VoteSwitch,
+            VoteProgramInstruction::AuthorizeChecked => authorize_checked::AuthorizeChecked,
+            VoteProgramInstruction::UpdateVoteState => update_vote_state::UpdateVoteState,
+            VoteProgramInstruction::UpdateVoteStateSwitch => update_vote_state_switch::UpdateVoteStateSwitch,
+            VoteProgramInstruction::AuthorizeWithSeed => authorize_with_seed::AuthorizeWithSeed,
+            VoteProgramInstruction::AuthorizeCheckedWithSeed => authorize_checked_with_seed::AuthorizeCheckedWithSeed,
+            VoteProgramInstruction::CompactUpdateVoteState => compact_update_vote_state::CompactUpdateVoteState,
+            VoteProgramInstruction::CompactUpdateVoteStateSwitch => compact_update_vote_state_switch::CompactUpdateVoteStateSwitch,
+            VoteProgramInstruction::TowerSync => tower_sync::TowerSync,
+            VoteProgramInstruction::TowerSyncSwitch => tower_sync_switch::TowerSyncSwitch,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use crate::types::Lockout;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_withdraw() {
+        // Arrange
+        let expected_ix = VoteProgramInstruction::Withdraw(withdraw::Withdraw {
+            lamports: 250000000,
+        });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "CertusDeBmqN8ZawdkxK5kFGMwBXdudvWHYwtNgNhvLu",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "4fYNw3dojWmQ4dXtSGE9epjRGy9pFSx62YypT7avPYvA",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+                ),
+                true,
+            ),
+        ];
+        let expected_arranged_accounts = withdraw::WithdrawInstructionAccounts {
+            vote_account: solana_pubkey::Pubkey::from_str_const(
+                "CertusDeBmqN8ZawdkxK5kFGMwBXdudvWHYwtNgNhvLu",
+            ),
+            recipient: solana_pubkey::Pubkey::from_str_const(
+                "4fYNw3dojWmQ4dXtSGE9epjRGy9pFSx62YypT7avPYvA",
+            ),
+            withdraw_authority: solana_pubkey::Pubkey::from_str_const(
+                "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = VoteProgramDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/withdraw_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            withdraw::Withdraw::arrange_accounts(&instruction.accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+
+    #[test]
+    fn test_decode_tower_sync() {
+        // Arrange
+        let expected_ix = VoteProgramInstruction::TowerSync(tower_sync::TowerSync {
+            tower_sync: crate::types::TowerSync {
+                lockouts: vec![
+                    Lockout {
+                        slot: 300000001,
+                        confirmation_count: 3,
+                    },
+                    Lockout {
+                        slot: 300000002,
+                        confirmation_count: 2,
+                    },
+                    Lockout {
+                        slot: 300000202,
+                        confirmation_count: 1,
+                    },
+                ],
+                root: Some(300000000),
+                hash: [7; 32],
+                timestamp: Some(1730000000),
+                block_id: [9; 32],
+            },
+        });
+        let expected_arranged_accounts = tower_sync::TowerSyncInstructionAccounts {
+            vote_account: solana_pubkey::Pubkey::from_str_const(
+                "CertusDeBmqN8ZawdkxK5kFGMwBXdudvWHYwtNgNhvLu",
+            ),
+            vote_authority: solana_pubkey::Pubkey::from_str_const(
+                "9gzpMN3hLqGpiYgLHkUphSqs6gagPmwvHGDGxXAQ5r5K",
+            ),
+            remaining_accounts: vec![],
+        };
+
+        // Act
+        let decoder = VoteProgramDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/tower_sync_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            tower_sync::TowerSync::arrange_accounts(&instruction.accounts)
+                .expect("arrange accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.program_id, PROGRAM_ID);
+        assert_eq!(decoded_arranged_accounts, expected_arranged_accounts);
+    }
+}
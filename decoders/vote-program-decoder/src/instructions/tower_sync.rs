@@ -0,0 +1,19 @@
+use crate::types;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0e000000")]
+pub struct TowerSync {
+    pub tower_sync: types::TowerSync,
+}
+
+#[derive(carbon_core::ArrangeAccounts, Debug, PartialEq)]
+#[carbon(instruction = "TowerSync")]
+pub struct TowerSyncInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,21 @@
+use crate::types;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0f000000")]
+pub struct TowerSyncSwitch {
+    pub tower_sync: types::TowerSync,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TowerSyncSwitch")]
+pub struct TowerSyncSwitchInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
@@ -0,0 +1,17 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x05000000")]
+pub struct UpdateCommission {
+    pub commission: u8,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateCommission")]
+pub struct UpdateCommissionInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub withdraw_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
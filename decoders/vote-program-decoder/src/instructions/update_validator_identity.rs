@@ -0,0 +1,16 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x04000000")]
+pub struct UpdateValidatorIdentity {}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateValidatorIdentity")]
+pub struct UpdateValidatorIdentityInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub new_identity: solana_pubkey::Pubkey,
+    pub withdraw_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
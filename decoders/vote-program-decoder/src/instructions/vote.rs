@@ -0,0 +1,21 @@
+use crate::types;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x02000000")]
+pub struct Vote {
+    pub vote: types::Vote,
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Vote")]
+pub struct VoteInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub slot_hashes: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
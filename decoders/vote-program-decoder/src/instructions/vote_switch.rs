@@ -0,0 +1,23 @@
+use crate::types;
+
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x06000000")]
+pub struct VoteSwitch {
+    pub vote: types::Vote,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+}
+
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "VoteSwitch")]
+pub struct VoteSwitchInstructionAccounts {
+    pub vote_account: solana_pubkey::Pubkey,
+    pub slot_hashes: solana_pubkey::Pubkey,
+    pub clock: solana_pubkey::Pubkey,
+    pub vote_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
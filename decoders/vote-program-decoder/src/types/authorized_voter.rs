@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct AuthorizedVoter {
+    pub epoch: u64,
+    #[carbon(serialize_as = "base58")]
+    pub pubkey: solana_pubkey::Pubkey,
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+use carbon_core::borsh::{maybestd::io, BorshDeserialize};
+
+use super::Lockout;
+
+/// Reads a `short_vec` length, a little-endian base-128 `u16` of at most
+/// three bytes.
+pub(crate) fn read_short_vec_len<R: io::Read>(reader: &mut R) -> io::Result<usize> {
+    let mut len: usize = 0;
+    for i in 0..3 {
+        let byte = u8::deserialize_reader(reader)?;
+        len |= ((byte & 0x7f) as usize) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok(len);
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "Invalid short_vec length",
+    ))
+}
+
+/// Reads a `serde_varint` encoded `u64`.
+pub(crate) fn read_varint_u64<R: io::Read>(reader: &mut R) -> io::Result<u64> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    while shift < u64::BITS {
+        let byte = u8::deserialize_reader(reader)?;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+
+    Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid varint"))
+}
+
+/// Reads the compact vote state layout shared by `CompactUpdateVoteState` and
+/// `TowerSync`: a root slot (`u64::MAX` when unset) followed by lockouts stored
+/// as slot offsets from the previous lockout.
+pub(crate) fn read_compact_lockouts<R: io::Read>(
+    reader: &mut R,
+) -> io::Result<(Option<u64>, Vec<Lockout>)> {
+    let root = u64::deserialize_reader(reader)?;
+    let root = (root != u64::MAX).then_some(root);
+
+    let len = read_short_vec_len(reader)?;
+    let mut lockouts = Vec::with_capacity(len);
+    let mut slot = root.unwrap_or_default();
+    for _ in 0..len {
+        let offset = read_varint_u64(reader)?;
+        let confirmation_count = u8::deserialize_reader(reader)?;
+        slot = slot
+            .checked_add(offset)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid lockout offset"))?;
+        lockouts.push(Lockout {
+            slot,
+            confirmation_count: confirmation_count as u32,
+        });
+    }
+
+    Ok((root, lockouts))
+}
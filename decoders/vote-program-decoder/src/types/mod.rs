@@ -0,0 +1,29 @@
+pub mod authorized_voter;
+pub use authorized_voter::*;
+pub mod block_timestamp;
+pub use block_timestamp::*;
+mod compact;
+pub mod compact_vote_state_update;
+pub use compact_vote_state_update::*;
+pub mod epoch_credits;
+pub use epoch_credits::*;
+pub mod landed_vote;
+pub use landed_vote::*;
+pub mod lockout;
+pub use lockout::*;
+pub mod prior_voter;
+pub use prior_voter::*;
+pub mod tower_sync;
+pub use tower_sync::*;
+pub mod vote;
+pub use vote::*;
+pub mod vote_authorize;
+pub use vote_authorize::*;
+pub mod vote_authorize_checked_with_seed_args;
+pub use vote_authorize_checked_with_seed_args::*;
+pub mod vote_authorize_with_seed_args;
+pub use vote_authorize_with_seed_args::*;
+pub mod vote_init;
+pub use vote_init::*;
+pub mod vote_state_update;
+pub use vote_state_update::*;
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct PriorVoter {
+    #[carbon(serialize_as = "base58")]
+    pub pubkey: solana_pubkey::Pubkey,
+    pub epoch_start: u64,
+    pub epoch_end: u64,
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+use alloc::vec::Vec;
+use carbon_core::borsh::{self, maybestd::io, BorshDeserialize};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub struct TowerSync {
+    pub lockouts: Vec<Lockout>,
+    pub root: Option<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+    pub block_id: [u8; 32],
+}
+
+impl BorshDeserialize for TowerSync {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let (root, lockouts) = compact::read_compact_lockouts(reader)?;
+
+        Ok(Self {
+            lockouts,
+            root,
+            hash: <[u8; 32]>::deserialize_reader(reader)?,
+            timestamp: Option::<i64>::deserialize_reader(reader)?,
+            block_id: <[u8; 32]>::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for TowerSync {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        borsh::BorshDeserialize::try_from_slice(data).ok()
+    }
+}
@@ -0,0 +1,11 @@
+use carbon_core::{borsh, deserialize::U64PrefixVec, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Vote {
+    pub slots: U64PrefixVec<u64>,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
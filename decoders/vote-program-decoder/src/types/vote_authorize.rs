@@ -0,0 +1,28 @@
+use carbon_core::borsh::{self, maybestd::io, BorshDeserialize};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash)]
+pub enum VoteAuthorize {
+    Voter,
+    Withdrawer,
+}
+
+// The vote program encodes its instructions with bincode, which writes enum
+// variants as a `u32` tag instead of the `u8` tag borsh would derive.
+impl BorshDeserialize for VoteAuthorize {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        match u32::deserialize_reader(reader)? {
+            0 => Ok(Self::Voter),
+            1 => Ok(Self::Withdrawer),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid VoteAuthorize variant",
+            )),
+        }
+    }
+}
+
+impl carbon_core::deserialize::CarbonDeserialize for VoteAuthorize {
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        borsh::BorshDeserialize::try_from_slice(data).ok()
+    }
+}
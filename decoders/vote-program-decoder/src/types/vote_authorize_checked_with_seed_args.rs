@@ -0,0 +1,13 @@
+use super::*;
+
+use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct VoteAuthorizeCheckedWithSeedArgs {
+    pub authorization_type: VoteAuthorize,
+    #[carbon(serialize_as = "base58")]
+    pub current_authority_derived_key_owner: solana_pubkey::Pubkey,
+    pub current_authority_derived_key_seed: U64PrefixString,
+}
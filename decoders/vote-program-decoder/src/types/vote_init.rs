@@ -0,0 +1,14 @@
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct VoteInit {
+    #[carbon(serialize_as = "base58")]
+    pub node_pubkey: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub authorized_voter: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
+    pub authorized_withdrawer: solana_pubkey::Pubkey,
+    pub commission: u8,
+}
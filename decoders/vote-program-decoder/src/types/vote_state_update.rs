@@ -0,0 +1,14 @@
+use super::*;
+
+use carbon_core::{borsh, deserialize::U64PrefixVec, CarbonDeserialize, CarbonSerialize};
+
+#[derive(
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct VoteStateUpdate {
+    pub lockouts: U64PrefixVec<Lockout>,
+    pub root: Option<u64>,
+    #[carbon(serialize_as = "hex")]
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
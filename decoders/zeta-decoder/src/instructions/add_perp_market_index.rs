@@ -1,33 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7a280e40a912e788")]
 pub struct AddPerpMarketIndex {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AddPerpMarketIndex")]
 pub struct AddPerpMarketIndexInstructionAccounts {
     pub market_indexes: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AddPerpMarketIndex {
-    type ArrangedAccounts = AddPerpMarketIndexInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [market_indexes, pricing, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AddPerpMarketIndexInstructionAccounts {
-            market_indexes: market_indexes.pubkey,
-            pricing: pricing.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
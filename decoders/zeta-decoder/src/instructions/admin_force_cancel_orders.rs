@@ -1,33 +1,19 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x43347cc0bf20da5b")]
 pub struct AdminForceCancelOrders {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AdminForceCancelOrders")]
 pub struct AdminForceCancelOrdersInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AdminForceCancelOrders {
-    type ArrangedAccounts = AdminForceCancelOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, cancel_accounts, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(AdminForceCancelOrdersInstructionAccounts {
-            authority: authority.pubkey,
-            cancel_accounts: cancel_accounts.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x73c65a11d28bc1ee")]
 pub struct AdminResetDexOpenOrders {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "AdminResetDexOpenOrders")]
 pub struct AdminResetDexOpenOrdersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
@@ -22,32 +24,5 @@ pub struct AdminResetDexOpenOrdersInstructionAccounts {
     pub asks: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub event_queue: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for AdminResetDexOpenOrders {
-    type ArrangedAccounts = AdminResetDexOpenOrdersInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, dex_program, open_orders, cross_margin_account, pricing_admin, authority, market, bids, asks, serum_authority, event_queue, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(AdminResetDexOpenOrdersInstructionAccounts {
-            state: state.pubkey,
-            dex_program: dex_program.pubkey,
-            open_orders: open_orders.pubkey,
-            cross_margin_account: cross_margin_account.pubkey,
-            pricing_admin: pricing_admin.pubkey,
-            authority: authority.pubkey,
-            market: market.pubkey,
-            bids: bids.pubkey,
-            asks: asks.pubkey,
-            serum_authority: serum_authority.pubkey,
-            event_queue: event_queue.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
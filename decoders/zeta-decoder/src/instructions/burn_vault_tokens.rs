@@ -1,35 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe9cba5c9af2bbc9f")]
 pub struct BurnVaultTokens {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "BurnVaultTokens")]
 pub struct BurnVaultTokensInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for BurnVaultTokens {
-    type ArrangedAccounts = BurnVaultTokensInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, mint, vault, serum_authority, token_program, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(BurnVaultTokensInstructionAccounts {
-            state: state.pubkey,
-            mint: mint.pubkey,
-            vault: vault.pubkey,
-            serum_authority: serum_authority.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x7a7f315944e4559d")]
 pub struct CleanZetaMarkets {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CleanZetaMarkets")]
 pub struct CleanZetaMarketsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarkets {
-    type ArrangedAccounts = CleanZetaMarketsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CleanZetaMarketsInstructionAccounts {
-            state: state.pubkey,
-            zeta_group: zeta_group.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
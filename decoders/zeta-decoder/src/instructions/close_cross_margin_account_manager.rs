@@ -1,29 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe8b6b689565876fc")]
 pub struct CloseCrossMarginAccountManager {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseCrossMarginAccountManager")]
 pub struct CloseCrossMarginAccountManagerInstructionAccounts {
     pub cross_margin_account_manager: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccountManager {
-    type ArrangedAccounts = CloseCrossMarginAccountManagerInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account_manager, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(CloseCrossMarginAccountManagerInstructionAccounts {
-            cross_margin_account_manager: cross_margin_account_manager.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xcf0fc64ac5e4b01e")]
 pub struct CloseOpenOrdersV3 {
@@ -11,6 +11,8 @@ pub struct CloseOpenOrdersV3 {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "CloseOpenOrdersV3")]
 pub struct CloseOpenOrdersV3InstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
@@ -21,30 +23,5 @@ pub struct CloseOpenOrdersV3InstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV3 {
-    type ArrangedAccounts = CloseOpenOrdersV3InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, dex_program, open_orders, cross_margin_account, authority, market, serum_authority, open_orders_map, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(CloseOpenOrdersV3InstructionAccounts {
-            state: state.pubkey,
-            pricing: pricing.pubkey,
-            dex_program: dex_program.pubkey,
-            open_orders: open_orders.pubkey,
-            cross_margin_account: cross_margin_account.pubkey,
-            authority: authority.pubkey,
-            market: market.pubkey,
-            serum_authority: serum_authority.pubkey,
-            open_orders_map: open_orders_map.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
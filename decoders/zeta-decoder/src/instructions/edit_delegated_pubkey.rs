@@ -1,31 +1,18 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x89f547592ef91635")]
 pub struct EditDelegatedPubkey {
+    #[carbon(serialize_as = "base58")]
     pub new_key: solana_pubkey::Pubkey,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EditDelegatedPubkey")]
 pub struct EditDelegatedPubkeyInstructionAccounts {
     pub margin_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EditDelegatedPubkey {
-    type ArrangedAccounts = EditDelegatedPubkeyInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [margin_account, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(EditDelegatedPubkeyInstructionAccounts {
-            margin_account: margin_account.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,35 +1,20 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe7d03332de934c4e")]
 pub struct EditMaType {
     pub ma_type: MarginAccountType,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EditMaType")]
 pub struct EditMaTypeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EditMaType {
-    type ArrangedAccounts = EditMaTypeInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, margin_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(EditMaTypeInstructionAccounts {
-            state: state.pubkey,
-            admin: admin.pubkey,
-            margin_account: margin_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xb42bd770fe741485")]
 pub struct EditTriggerOrder {
@@ -17,24 +17,10 @@ pub struct EditTriggerOrder {
     pub reduce_only: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EditTriggerOrder")]
 pub struct EditTriggerOrderInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrder {
-    type ArrangedAccounts = EditTriggerOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, trigger_order, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(EditTriggerOrderInstructionAccounts {
-            owner: owner.pubkey,
-            trigger_order: trigger_order.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
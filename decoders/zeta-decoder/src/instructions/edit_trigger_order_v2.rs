@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x499fcdb12b557589")]
 pub struct EditTriggerOrderV2 {
@@ -17,28 +17,12 @@ pub struct EditTriggerOrderV2 {
     pub reduce_only: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "EditTriggerOrderV2")]
 pub struct EditTriggerOrderV2InstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrderV2 {
-    type ArrangedAccounts = EditTriggerOrderV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [owner, trigger_order, state, margin_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(EditTriggerOrderV2InstructionAccounts {
-            owner: owner.pubkey,
-            trigger_order: trigger_order.pubkey,
-            state: state.pubkey,
-            margin_account: margin_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,23 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x6816227b56e08246")]
 pub struct ExpireSeriesOverride {
     pub args: ExpireSeriesOverrideArgs,
 }
 
-pub struct ExpireSeriesOverrideInstructionAccounts {}
-
-impl carbon_core::deserialize::ArrangeAccounts for ExpireSeriesOverride {
-    type ArrangedAccounts = ExpireSeriesOverrideInstructionAccounts;
-
-    fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        Some(ExpireSeriesOverrideInstructionAccounts {})
-    }
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ExpireSeriesOverride")]
+pub struct ExpireSeriesOverrideInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
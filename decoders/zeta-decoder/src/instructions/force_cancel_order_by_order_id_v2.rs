@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x51f33c5adec929de")]
 pub struct ForceCancelOrderByOrderIdV2 {
@@ -12,32 +12,13 @@ pub struct ForceCancelOrderByOrderIdV2 {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ForceCancelOrderByOrderIdV2")]
 pub struct ForceCancelOrderByOrderIdV2InstructionAccounts {
     pub pricing: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrderByOrderIdV2 {
-    type ArrangedAccounts = ForceCancelOrderByOrderIdV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [pricing, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(ForceCancelOrderByOrderIdV2InstructionAccounts {
-            pricing: pricing.pubkey,
-            oracle: oracle.pubkey,
-            oracle_backup_feed: oracle_backup_feed.pubkey,
-            oracle_backup_program: oracle_backup_program.pubkey,
-            cancel_accounts: cancel_accounts.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
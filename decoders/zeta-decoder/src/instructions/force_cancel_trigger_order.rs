@@ -1,7 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x78ecd81cc04fffbc")]
 pub struct ForceCancelTriggerOrder {
@@ -9,28 +9,12 @@ pub struct ForceCancelTriggerOrder {
     pub enforce_tpsl_conditions: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "ForceCancelTriggerOrder")]
 pub struct ForceCancelTriggerOrderInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for ForceCancelTriggerOrder {
-    type ArrangedAccounts = ForceCancelTriggerOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, trigger_order, margin_account, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(ForceCancelTriggerOrderInstructionAccounts {
-            state: state.pubkey,
-            admin: admin.pubkey,
-            trigger_order: trigger_order.pubkey,
-            margin_account: margin_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x1b1ae432d2d3cd5e")]
 pub struct InitializeCrossMarginAccount {
     pub subaccount_index: u8,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeCrossMarginAccount")]
 pub struct InitializeCrossMarginAccountInstructionAccounts {
     pub cross_margin_account: solana_pubkey::Pubkey,
     pub cross_margin_account_manager: solana_pubkey::Pubkey,
@@ -15,27 +17,5 @@ pub struct InitializeCrossMarginAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub zeta_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccount {
-    type ArrangedAccounts = InitializeCrossMarginAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, cross_margin_account_manager, authority, payer, zeta_program, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeCrossMarginAccountInstructionAccounts {
-            cross_margin_account: cross_margin_account.pubkey,
-            cross_margin_account_manager: cross_margin_account_manager.pubkey,
-            authority: authority.pubkey,
-            payer: payer.pubkey,
-            zeta_program: zeta_program.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x5b3fcd901453b178")]
 pub struct InitializeMarketIndexes {
@@ -11,31 +11,13 @@ pub struct InitializeMarketIndexes {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeMarketIndexes")]
 pub struct InitializeMarketIndexesInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub market_indexes: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketIndexes {
-    type ArrangedAccounts = InitializeMarketIndexesInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, admin, system_program, pricing, _remaining @ ..] = accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeMarketIndexesInstructionAccounts {
-            state: state.pubkey,
-            market_indexes: market_indexes.pubkey,
-            admin: admin.pubkey,
-            system_program: system_program.pubkey,
-            pricing: pricing.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x057864bae186080d")]
 pub struct InitializeMarketPda {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeMarketPda")]
 pub struct InitializeMarketPdaInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub market_indexes: solana_pubkey::Pubkey,
@@ -18,28 +20,5 @@ pub struct InitializeMarketPdaInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketPda {
-    type ArrangedAccounts = InitializeMarketPdaInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, pricing, admin, market, system_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeMarketPdaInstructionAccounts {
-            state: state.pubkey,
-            market_indexes: market_indexes.pubkey,
-            pricing: pricing.pubkey,
-            admin: admin.pubkey,
-            market: market.pubkey,
-            system_program: system_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
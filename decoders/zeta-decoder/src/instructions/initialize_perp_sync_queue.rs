@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0a379ae081aea108")]
 pub struct InitializePerpSyncQueue {
@@ -11,6 +11,8 @@ pub struct InitializePerpSyncQueue {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializePerpSyncQueue")]
 pub struct InitializePerpSyncQueueInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub zeta_program: solana_pubkey::Pubkey,
@@ -18,27 +20,5 @@ pub struct InitializePerpSyncQueueInstructionAccounts {
     pub perp_sync_queue: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializePerpSyncQueue {
-    type ArrangedAccounts = InitializePerpSyncQueueInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, zeta_program, state, perp_sync_queue, pricing, system_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializePerpSyncQueueInstructionAccounts {
-            admin: admin.pubkey,
-            zeta_program: zeta_program.pubkey,
-            state: state.pubkey,
-            perp_sync_queue: perp_sync_queue.pubkey,
-            pricing: pricing.pubkey,
-            system_program: system_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x726cd55caf7c2b13")]
 pub struct InitializeUnderlying {
     pub flex_underlying: bool,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeUnderlying")]
 pub struct InitializeUnderlyingInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub zeta_program: solana_pubkey::Pubkey,
@@ -15,27 +17,5 @@ pub struct InitializeUnderlyingInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub underlying: solana_pubkey::Pubkey,
     pub underlying_mint: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeUnderlying {
-    type ArrangedAccounts = InitializeUnderlyingInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [admin, zeta_program, state, system_program, underlying, underlying_mint, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeUnderlyingInstructionAccounts {
-            admin: admin.pubkey,
-            zeta_program: zeta_program.pubkey,
-            state: state.pubkey,
-            system_program: system_program.pubkey,
-            underlying: underlying.pubkey,
-            underlying_mint: underlying_mint.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
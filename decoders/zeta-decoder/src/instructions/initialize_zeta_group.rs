@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x068724e82327fa47")]
 pub struct InitializeZetaGroup {
     pub args: InitializeZetaGroupArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeZetaGroup")]
 pub struct InitializeZetaGroupInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
@@ -29,39 +31,5 @@ pub struct InitializeZetaGroupInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub usdc_mint: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaGroup {
-    type ArrangedAccounts = InitializeZetaGroupInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, system_program, underlying_mint, zeta_program, oracle, oracle_backup_feed, oracle_backup_program, zeta_group, greeks, perp_sync_queue, underlying, vault, insurance_vault, socialized_loss_account, token_program, usdc_mint, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeZetaGroupInstructionAccounts {
-            state: state.pubkey,
-            admin: admin.pubkey,
-            system_program: system_program.pubkey,
-            underlying_mint: underlying_mint.pubkey,
-            zeta_program: zeta_program.pubkey,
-            oracle: oracle.pubkey,
-            oracle_backup_feed: oracle_backup_feed.pubkey,
-            oracle_backup_program: oracle_backup_program.pubkey,
-            zeta_group: zeta_group.pubkey,
-            greeks: greeks.pubkey,
-            perp_sync_queue: perp_sync_queue.pubkey,
-            underlying: underlying.pubkey,
-            vault: vault.pubkey,
-            insurance_vault: insurance_vault.pubkey,
-            socialized_loss_account: socialized_loss_account.pubkey,
-            token_program: token_program.pubkey,
-            usdc_mint: usdc_mint.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
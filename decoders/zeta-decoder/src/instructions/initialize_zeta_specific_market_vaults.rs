@@ -1,15 +1,17 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xf945ba92886b4f71")]
 pub struct InitializeZetaSpecificMarketVaults {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "InitializeZetaSpecificMarketVaults")]
 pub struct InitializeZetaSpecificMarketVaultsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub market_indexes: solana_pubkey::Pubkey,
@@ -24,34 +26,5 @@ pub struct InitializeZetaSpecificMarketVaultsInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaSpecificMarketVaults {
-    type ArrangedAccounts = InitializeZetaSpecificMarketVaultsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, pricing, admin, market, base_mint, quote_mint, zeta_base_vault, zeta_quote_vault, serum_authority, system_program, token_program, rent, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(InitializeZetaSpecificMarketVaultsInstructionAccounts {
-            state: state.pubkey,
-            market_indexes: market_indexes.pubkey,
-            pricing: pricing.pubkey,
-            admin: admin.pubkey,
-            market: market.pubkey,
-            base_mint: base_mint.pubkey,
-            quote_mint: quote_mint.pubkey,
-            zeta_base_vault: zeta_base_vault.pubkey,
-            zeta_quote_vault: zeta_quote_vault.pubkey,
-            serum_authority: serum_authority.pubkey,
-            system_program: system_program.pubkey,
-            token_program: token_program.pubkey,
-            rent: rent.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,13 +1,15 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xdfb3e27d302e274a")]
 pub struct Liquidate {
     pub size: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "Liquidate")]
 pub struct LiquidateInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub liquidator: solana_pubkey::Pubkey,
@@ -19,31 +21,5 @@ pub struct LiquidateInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub liquidated_margin_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for Liquidate {
-    type ArrangedAccounts = LiquidateInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, liquidator, liquidator_margin_account, greeks, oracle, oracle_backup_feed, oracle_backup_program, market, zeta_group, liquidated_margin_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LiquidateInstructionAccounts {
-            state: state.pubkey,
-            liquidator: liquidator.pubkey,
-            liquidator_margin_account: liquidator_margin_account.pubkey,
-            greeks: greeks.pubkey,
-            oracle: oracle.pubkey,
-            oracle_backup_feed: oracle_backup_feed.pubkey,
-            oracle_backup_program: oracle_backup_program.pubkey,
-            market: market.pubkey,
-            zeta_group: zeta_group.pubkey,
-            liquidated_margin_account: liquidated_margin_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
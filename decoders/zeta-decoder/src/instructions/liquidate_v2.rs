@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0f56553702e1a1eb")]
 pub struct LiquidateV2 {
@@ -11,6 +11,8 @@ pub struct LiquidateV2 {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "LiquidateV2")]
 pub struct LiquidateV2InstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub liquidator: solana_pubkey::Pubkey,
@@ -21,30 +23,5 @@ pub struct LiquidateV2InstructionAccounts {
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub liquidated_account: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for LiquidateV2 {
-    type ArrangedAccounts = LiquidateV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, liquidator, liquidator_account, pricing, oracle, oracle_backup_feed, oracle_backup_program, market, liquidated_account, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(LiquidateV2InstructionAccounts {
-            state: state.pubkey,
-            liquidator: liquidator.pubkey,
-            liquidator_account: liquidator_account.pubkey,
-            pricing: pricing.pubkey,
-            oracle: oracle.pubkey,
-            oracle_backup_feed: oracle_backup_feed.pubkey,
-            oracle_backup_program: oracle_backup_program.pubkey,
-            market: market.pubkey,
-            liquidated_account: liquidated_account.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
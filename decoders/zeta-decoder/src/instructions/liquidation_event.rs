@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d030d155dad884890")]
 pub struct LiquidationEvent {
@@ -15,9 +15,13 @@ pub struct LiquidationEvent {
     pub remaining_liquidator_balance: u64,
     pub mark_price: u64,
     pub underlying_price: u64,
+    #[carbon(serialize_as = "base58")]
     pub liquidatee: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub liquidator: solana_pubkey::Pubkey,
     pub asset: Asset,
+    #[carbon(serialize_as = "base58")]
     pub liquidatee_margin_account: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub liquidator_margin_account: solana_pubkey::Pubkey,
 }
@@ -1,31 +1,16 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x9d356b68b8bd64dc")]
 pub struct MigrateToCrossMarginAccount {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "MigrateToCrossMarginAccount")]
 pub struct MigrateToCrossMarginAccountInstructionAccounts {
     pub cross_margin_account: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for MigrateToCrossMarginAccount {
-    type ArrangedAccounts = MigrateToCrossMarginAccountInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, pricing, authority, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(MigrateToCrossMarginAccountInstructionAccounts {
-            cross_margin_account: cross_margin_account.pubkey,
-            pricing: pricing.pubkey,
-            authority: authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -7,6 +7,7 @@ pub mod admin_crank_event_queue;
 pub mod admin_force_cancel_orders;
 pub mod admin_reset_dex_open_orders;
 pub mod admin_set_order_state;
+#[cfg(feature = "events")]
 pub mod apply_funding_event;
 pub mod apply_perp_funding;
 pub mod burn_vault_tokens;
@@ -84,14 +85,18 @@ pub mod initialize_zeta_state;
 pub mod initialize_zeta_treasury_wallet;
 pub mod liquidate;
 pub mod liquidate_v2;
+#[cfg(feature = "events")]
 pub mod liquidation_event;
 pub mod migrate_to_cross_margin_account;
 pub mod migrate_to_new_cross_margin_account;
+#[cfg(feature = "events")]
 pub mod order_complete_event;
 pub mod override_expiry;
 pub mod place_multi_orders;
+#[cfg(feature = "events")]
 pub mod place_multi_orders_event;
 pub mod place_order;
+#[cfg(feature = "events")]
 pub mod place_order_event;
 pub mod place_order_v2;
 pub mod place_order_v3;
@@ -103,6 +108,7 @@ pub mod place_perp_order_v4;
 pub mod place_perp_order_v5;
 pub mod place_trigger_order;
 pub mod position_movement;
+#[cfg(feature = "events")]
 pub mod position_movement_event;
 pub mod prune_expired_tif_orders;
 pub mod prune_expired_tif_orders_v2;
@@ -114,8 +120,11 @@ pub mod settle_positions_halted;
 pub mod take_trigger_order;
 pub mod toggle_market_maker;
 pub mod toggle_zeta_group_perps_only;
+#[cfg(feature = "events")]
 pub mod trade_event;
+#[cfg(feature = "events")]
 pub mod trade_event_v2_event;
+#[cfg(feature = "events")]
 pub mod trade_event_v3_event;
 pub mod transfer_excess_spread_balance;
 pub mod treasury_movement;
@@ -338,20 +347,33 @@ pub enum ZetaInstruction {
     EditMaType(edit_ma_type::EditMaType),
     EditDelegatedPubkey(edit_delegated_pubkey::EditDelegatedPubkey),
     ResetNumFlexUnderlyings(reset_num_flex_underlyings::ResetNumFlexUnderlyings),
+    #[cfg(feature = "events")]
     TradeEvent(trade_event::TradeEvent),
+    #[cfg(feature = "events")]
     TradeEventV2Event(trade_event_v2_event::TradeEventV2Event),
+    #[cfg(feature = "events")]
     TradeEventV3Event(trade_event_v3_event::TradeEventV3Event),
+    #[cfg(feature = "events")]
     PositionMovementEvent(position_movement_event::PositionMovementEvent),
+    #[cfg(feature = "events")]
     PlaceOrderEvent(place_order_event::PlaceOrderEvent),
+    #[cfg(feature = "events")]
     LiquidationEvent(liquidation_event::LiquidationEvent),
+    #[cfg(feature = "events")]
     OrderCompleteEvent(order_complete_event::OrderCompleteEvent),
+    #[cfg(feature = "events")]
     ApplyFundingEvent(apply_funding_event::ApplyFundingEvent),
+    #[cfg(feature = "events")]
     PlaceMultiOrdersEvent(place_multi_orders_event::PlaceMultiOrdersEvent),
 }
 
 impl carbon_core::instruction::InstructionDecoder<'_> for ZetaDecoder {
     type InstructionType = ZetaInstruction;
 
+    fn program_id(&self) -> Option<solana_pubkey::Pubkey> {
+        Some(PROGRAM_ID)
+    }
+
     fn decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
@@ -501,14 +523,23 @@ impl carbon_core::instruction::InstructionDecoder<'_> for ZetaDecoder {
             ZetaInstruction::EditMaType => edit_ma_type::EditMaType,
             ZetaInstruction::EditDelegatedPubkey => edit_delegated_pubkey::EditDelegatedPubkey,
             ZetaInstruction::ResetNumFlexUnderlyings => reset_num_flex_underlyings::ResetNumFlexUnderlyings,
+            #[cfg(feature = "events")]
             ZetaInstruction::TradeEvent => trade_event::TradeEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::TradeEventV2Event => trade_event_v2_event::TradeEventV2Event,
+            #[cfg(feature = "events")]
             ZetaInstruction::TradeEventV3Event => trade_event_v3_event::TradeEventV3Event,
+            #[cfg(feature = "events")]
             ZetaInstruction::PositionMovementEvent => position_movement_event::PositionMovementEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::PlaceOrderEvent => place_order_event::PlaceOrderEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::LiquidationEvent => liquidation_event::LiquidationEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::OrderCompleteEvent => order_complete_event::OrderCompleteEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::ApplyFundingEvent => apply_funding_event::ApplyFundingEvent,
+            #[cfg(feature = "events")]
             ZetaInstruction::PlaceMultiOrdersEvent => place_multi_orders_event::PlaceMultiOrdersEvent,
         )
     }
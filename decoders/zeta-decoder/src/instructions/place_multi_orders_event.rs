@@ -1,10 +1,10 @@
 use super::super::types::*;
 
 use alloc::vec::Vec;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1dee081207a23b6891")]
 pub struct PlaceMultiOrdersEvent {
@@ -12,7 +12,9 @@ pub struct PlaceMultiOrdersEvent {
     pub order_ids: Vec<u128>,
     pub expiry_tss: Vec<u64>,
     pub asset: Asset,
+    #[carbon(serialize_as = "base58")]
     pub margin_account: solana_pubkey::Pubkey,
     pub client_order_ids: Vec<u64>,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d41bf195b1bfcc028")]
 pub struct PlaceOrderEvent {
@@ -12,7 +12,9 @@ pub struct PlaceOrderEvent {
     pub order_id: u128,
     pub expiry_ts: u64,
     pub asset: Asset,
+    #[carbon(serialize_as = "base58")]
     pub margin_account: solana_pubkey::Pubkey,
     pub client_order_id: u64,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
 }
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe86f73c4ed8f3ecc")]
 pub struct PlaceOrderV2 {
@@ -14,6 +14,8 @@ pub struct PlaceOrderV2 {
     pub client_order_id: Option<u64>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlaceOrderV2")]
 pub struct PlaceOrderV2InstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
@@ -32,38 +34,5 @@ pub struct PlaceOrderV2InstructionAccounts {
     pub market_node: solana_pubkey::Pubkey,
     pub market_mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlaceOrderV2 {
-    type ArrangedAccounts = PlaceOrderV2InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, margin_account, authority, dex_program, token_program, serum_authority, greeks, open_orders, rent, market_accounts, oracle, oracle_backup_feed, oracle_backup_program, market_node, market_mint, mint_authority, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(PlaceOrderV2InstructionAccounts {
-            state: state.pubkey,
-            zeta_group: zeta_group.pubkey,
-            margin_account: margin_account.pubkey,
-            authority: authority.pubkey,
-            dex_program: dex_program.pubkey,
-            token_program: token_program.pubkey,
-            serum_authority: serum_authority.pubkey,
-            greeks: greeks.pubkey,
-            open_orders: open_orders.pubkey,
-            rent: rent.pubkey,
-            market_accounts: market_accounts.pubkey,
-            oracle: oracle.pubkey,
-            oracle_backup_feed: oracle_backup_feed.pubkey,
-            oracle_backup_program: oracle_backup_program.pubkey,
-            market_node: market_node.pubkey,
-            market_mint: market_mint.pubkey,
-            mint_authority: mint_authority.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use super::super::types::*;
 
 use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa68acd645a6ebf5b")]
 pub struct PlacePerpOrderV4 {
@@ -19,24 +19,10 @@ pub struct PlacePerpOrderV4 {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlacePerpOrderV4")]
 pub struct PlacePerpOrderV4InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub place_order_accounts: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrderV4 {
-    type ArrangedAccounts = PlacePerpOrderV4InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, place_order_accounts, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(PlacePerpOrderV4InstructionAccounts {
-            authority: authority.pubkey,
-            place_order_accounts: place_order_accounts.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,10 +1,10 @@
 use super::super::types::*;
 
 use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0718b6199b904b32")]
 pub struct PlacePerpOrderV5 {
@@ -20,24 +20,10 @@ pub struct PlacePerpOrderV5 {
     pub self_trade_behavior: Option<SelfTradeBehaviorZeta>,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlacePerpOrderV5")]
 pub struct PlacePerpOrderV5InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub place_order_accounts: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrderV5 {
-    type ArrangedAccounts = PlacePerpOrderV5InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [authority, place_order_accounts, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(PlacePerpOrderV5InstructionAccounts {
-            authority: authority.pubkey,
-            place_order_accounts: place_order_accounts.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
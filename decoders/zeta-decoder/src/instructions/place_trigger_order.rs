@@ -1,10 +1,10 @@
 use super::super::types::*;
 
 use alloc::string::String;
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x209c32bce89f70ec")]
 pub struct PlaceTriggerOrder {
@@ -21,6 +21,8 @@ pub struct PlaceTriggerOrder {
     pub asset: Asset,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "PlaceTriggerOrder")]
 pub struct PlaceTriggerOrderInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub open_orders: solana_pubkey::Pubkey,
@@ -31,30 +33,5 @@ pub struct PlaceTriggerOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for PlaceTriggerOrder {
-    type ArrangedAccounts = PlaceTriggerOrderInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, open_orders, authority, margin_account, pricing, trigger_order, system_program, dex_program, market, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(PlaceTriggerOrderInstructionAccounts {
-            state: state.pubkey,
-            open_orders: open_orders.pubkey,
-            authority: authority.pubkey,
-            margin_account: margin_account.pubkey,
-            pricing: pricing.pubkey,
-            trigger_order: trigger_order.pubkey,
-            system_program: system_program.pubkey,
-            dex_program: dex_program.pubkey,
-            market: market.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,11 +1,13 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xa5678e26d3a60ee2")]
 pub struct SettleDexFunds {}
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "SettleDexFunds")]
 pub struct SettleDexFundsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
@@ -18,32 +20,5 @@ pub struct SettleDexFundsInstructionAccounts {
     pub serum_authority: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for SettleDexFunds {
-    type ArrangedAccounts = SettleDexFundsInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, market, zeta_base_vault, zeta_quote_vault, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, token_program, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(SettleDexFundsInstructionAccounts {
-            state: state.pubkey,
-            market: market.pubkey,
-            zeta_base_vault: zeta_base_vault.pubkey,
-            zeta_quote_vault: zeta_quote_vault.pubkey,
-            dex_base_vault: dex_base_vault.pubkey,
-            dex_quote_vault: dex_quote_vault.pubkey,
-            vault_owner: vault_owner.pubkey,
-            mint_authority: mint_authority.pubkey,
-            serum_authority: serum_authority.pubkey,
-            dex_program: dex_program.pubkey,
-            token_program: token_program.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -1,12 +1,13 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xe445a52e51cb9a1d72a23b2154866c3e")]
 pub struct TradeEventV3Event {
+    #[carbon(serialize_as = "base58")]
     pub margin_account: solana_pubkey::Pubkey,
     pub index: u8,
     pub size: u64,
@@ -15,6 +16,7 @@ pub struct TradeEventV3Event {
     pub client_order_id: u64,
     pub order_id: u128,
     pub asset: Asset,
+    #[carbon(serialize_as = "base58")]
     pub user: solana_pubkey::Pubkey,
     pub is_taker: bool,
     pub sequence_number: u64,
@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x0122f269d7d39d12")]
 pub struct TreasuryMovement {
@@ -11,6 +11,8 @@ pub struct TreasuryMovement {
     pub amount: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "TreasuryMovement")]
 pub struct TreasuryMovementInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub insurance_vault: solana_pubkey::Pubkey,
@@ -18,27 +20,5 @@ pub struct TreasuryMovementInstructionAccounts {
     pub referrals_rewards_wallet: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for TreasuryMovement {
-    type ArrangedAccounts = TreasuryMovementInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, insurance_vault, treasury_wallet, referrals_rewards_wallet, token_program, admin, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(TreasuryMovementInstructionAccounts {
-            state: state.pubkey,
-            insurance_vault: insurance_vault.pubkey,
-            treasury_wallet: treasury_wallet.pubkey,
-            referrals_rewards_wallet: referrals_rewards_wallet.pubkey,
-            token_program: token_program.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
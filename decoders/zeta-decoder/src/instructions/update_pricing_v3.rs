@@ -1,9 +1,9 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xdf3ab45666fbed52")]
 pub struct UpdatePricingV3 {
@@ -12,6 +12,8 @@ pub struct UpdatePricingV3 {
     pub timestamp: u64,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdatePricingV3")]
 pub struct UpdatePricingV3InstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
@@ -20,28 +22,5 @@ pub struct UpdatePricingV3InstructionAccounts {
     pub perp_bids: solana_pubkey::Pubkey,
     pub perp_asks: solana_pubkey::Pubkey,
     pub pricing_admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV3 {
-    type ArrangedAccounts = UpdatePricingV3InstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, oracle, perp_market, perp_bids, perp_asks, pricing_admin, _remaining @ ..] =
-            accounts
-        else {
-            return None;
-        };
-
-        Some(UpdatePricingV3InstructionAccounts {
-            state: state.pubkey,
-            pricing: pricing.pubkey,
-            oracle: oracle.pubkey,
-            perp_market: perp_market.pubkey,
-            perp_bids: perp_bids.pubkey,
-            perp_asks: perp_asks.pubkey,
-            pricing_admin: pricing_admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
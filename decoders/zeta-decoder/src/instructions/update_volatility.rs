@@ -1,37 +1,21 @@
 use super::super::types::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xbe6974dde5c6d053")]
 pub struct UpdateVolatility {
     pub args: UpdateVolatilityArgs,
 }
 
+#[derive(carbon_core::ArrangeAccounts)]
+#[carbon(instruction = "UpdateVolatility")]
 pub struct UpdateVolatilityInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub greeks: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
-}
-
-impl carbon_core::deserialize::ArrangeAccounts for UpdateVolatility {
-    type ArrangedAccounts = UpdateVolatilityInstructionAccounts;
-
-    fn arrange_accounts(
-        accounts: &[solana_instruction::AccountMeta],
-    ) -> Option<Self::ArrangedAccounts> {
-        let [state, greeks, zeta_group, admin, _remaining @ ..] = accounts else {
-            return None;
-        };
-
-        Some(UpdateVolatilityInstructionAccounts {
-            state: state.pubkey,
-            greeks: greeks.pubkey,
-            zeta_group: zeta_group.pubkey,
-            admin: admin.pubkey,
-        })
-    }
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -4,7 +4,9 @@ extern crate alloc;
 use solana_pubkey::Pubkey;
 
 pub struct ZetaDecoder;
+#[cfg(feature = "accounts")]
 pub mod accounts;
+#[cfg(feature = "instructions")]
 pub mod instructions;
 pub mod types;
 
@@ -1,15 +1,19 @@
 use super::*;
 
-use carbon_core::{borsh, CarbonDeserialize};
+use carbon_core::{borsh, CarbonDeserialize, CarbonSerialize};
 
 #[derive(
-    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+    CarbonDeserialize, Debug, CarbonSerialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 pub struct UpdateZetaPricingPubkeysArgs {
     pub asset: Asset,
+    #[carbon(serialize_as = "base58")]
     pub oracle: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub market: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub perp_sync_queue: solana_pubkey::Pubkey,
+    #[carbon(serialize_as = "base58")]
     pub zeta_group_key: solana_pubkey::Pubkey,
     pub reset_oracle: bool,
 }
@@ -0,0 +1,72 @@
+//! Indexes the swaps of Jupiter, Meteora DLMM, Orca Whirlpool and Raydium
+//! into a single PostgreSQL table.
+//!
+//! The transactions of the DEX programs are streamed from a Yellowstone gRPC
+//! endpoint, their swap instructions are normalized by `carbon-swaps`, and
+//! the swaps are written to the `swaps` table by `carbon-postgres-sink`,
+//! keyed by the signature and position of their instruction so a replayed
+//! slot does not duplicate them.
+//!
+//! The last processed slot is checkpointed to `CHECKPOINT_PATH`, and a
+//! restarted indexer resumes from it. On Ctrl-C, the datasource stops, the
+//! queued transactions are processed and the final checkpoint is written
+//! before the indexer exits.
+
+use {
+    carbon_core::{
+        error::{CarbonResult, Error},
+        pipeline::ShutdownStrategy,
+    },
+    carbon_jupiter_swap_decoder::JupiterSwapDecoder,
+    carbon_meteora_dlmm_decoder::MeteoraDlmmDecoder,
+    carbon_orca_whirlpool_decoder::OrcaWhirlpoolDecoder,
+    carbon_postgres_sink::PostgresSink,
+    carbon_prometheus_metrics::PrometheusMetrics,
+    carbon_raydium_amm_v4_decoder::RaydiumAmmV4Decoder,
+    carbon_raydium_clmm_decoder::RaydiumClmmDecoder,
+    carbon_raydium_cpmm_decoder::RaydiumCpmmDecoder,
+    carbon_swaps::{Swap, SwapNormalizer},
+    carbon_yellowstone_grpc_datasource::{GeyserEndpoint, Quickstart},
+    std::{env, sync::Arc},
+};
+
+/// The table the swaps of every venue are written to.
+const SWAPS_TABLE: &str = "swaps";
+
+#[tokio::main]
+pub async fn main() -> CarbonResult<()> {
+    env_logger::init();
+    dotenv::dotenv().ok();
+
+    let database_url = env::var("DATABASE_URL").map_err(|_| {
+        Error::Custom("Missing database: set the DATABASE_URL environment variable".to_string())
+    })?;
+    let checkpoint_path =
+        env::var("CHECKPOINT_PATH").unwrap_or_else(|_| "dex-indexer.checkpoint".to_string());
+
+    let sink = PostgresSink::connect(&database_url).await?;
+    let swaps = || {
+        sink.records(
+            SWAPS_TABLE,
+            &["signature", "path"],
+            carbon_postgres_sink::json::<Swap>(),
+        )
+    };
+
+    let handle = Quickstart::new(GeyserEndpoint::from_env()?)
+        .with_decoder(JupiterSwapDecoder, SwapNormalizer::new(swaps()))
+        .with_decoder(MeteoraDlmmDecoder, SwapNormalizer::new(swaps()))
+        .with_decoder(OrcaWhirlpoolDecoder, SwapNormalizer::new(swaps()))
+        .with_decoder(RaydiumAmmV4Decoder, SwapNormalizer::new(swaps()))
+        .with_decoder(RaydiumClmmDecoder, SwapNormalizer::new(swaps()))
+        .with_decoder(RaydiumCpmmDecoder, SwapNormalizer::new(swaps()))
+        .checkpoint_path(checkpoint_path)
+        .configure(|builder| {
+            builder
+                .metrics(Arc::new(PrometheusMetrics::new()))
+                .shutdown_strategy(ShutdownStrategy::ProcessPending)
+        })
+        .start()?;
+
+    handle.join().await
+}
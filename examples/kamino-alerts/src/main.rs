@@ -11,10 +11,9 @@ use {
         accounts::KaminoLendingAccount, instructions::KaminoLendingInstruction,
         KaminoLendingDecoder, PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID,
     },
-    carbon_yellowstone_grpc_datasource::YellowstoneGrpcGeyserClient,
+    carbon_yellowstone_grpc_datasource::{GeyserEndpoint, YellowstoneGrpcGeyserClient},
     std::{
         collections::{HashMap, HashSet},
-        env,
         sync::Arc,
     },
     tokio::sync::RwLock,
@@ -54,8 +53,7 @@ pub async fn main() -> CarbonResult<()> {
     transaction_filters.insert("kamino_transaction_filter".to_string(), transaction_filter);
 
     let yellowstone_grpc = YellowstoneGrpcGeyserClient::new(
-        env::var("GEYSER_URL").unwrap_or_default(),
-        env::var("X_TOKEN").ok(),
+        GeyserEndpoint::from_env()?,
         Some(CommitmentLevel::Confirmed),
         account_filters,
         transaction_filters,
@@ -2,7 +2,7 @@ use {
     async_trait::async_trait,
     carbon_core::{
         account::AccountProcessorInputType,
-        datasource::{AccountUpdate, Datasource, Update, UpdateType},
+        datasource::{AccountUpdate, Capabilities, Capability, Datasource, Update},
         error::CarbonResult,
         instruction::InstructionDecoder,
         instruction_decoder_collection,
@@ -84,6 +84,8 @@ impl Datasource for GpaBackfillDatasource {
                 pubkey,
                 account,
                 slot,
+                write_version: None,
+                commitment: None,
             })) {
                 log::error!("Failed to send account update: {:?}", e);
             }
@@ -92,8 +94,8 @@ impl Datasource for GpaBackfillDatasource {
         Ok(())
     }
 
-    fn update_types(&self) -> Vec<carbon_core::datasource::UpdateType> {
-        vec![UpdateType::AccountUpdate]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::new().with(Capability::AccountUpdates)
     }
 }
 
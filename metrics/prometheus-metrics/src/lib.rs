@@ -2,7 +2,7 @@ use {
     async_trait::async_trait,
     carbon_core::{
         error::{CarbonResult, Error},
-        metrics::Metrics,
+        metrics::{MetricLabel, Metrics},
     },
     metrics::{counter, gauge, histogram},
     metrics_exporter_prometheus::PrometheusBuilder,
@@ -31,6 +31,13 @@ impl PrometheusMetrics {
     }
 }
 
+fn prometheus_labels(labels: &[MetricLabel]) -> Vec<metrics::Label> {
+    labels
+        .iter()
+        .map(|label| metrics::Label::new(label.name.clone(), label.value.clone()))
+        .collect()
+}
+
 #[async_trait]
 impl Metrics for PrometheusMetrics {
     async fn initialize(&self) -> CarbonResult<()> {
@@ -105,4 +112,49 @@ impl Metrics for PrometheusMetrics {
 
         Ok(())
     }
+
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        if labels.is_empty() {
+            return self.update_gauge(name, value).await;
+        }
+
+        gauge!(name.to_string(), prometheus_labels(labels)).set(value);
+
+        Ok(())
+    }
+
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        value: u64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        if labels.is_empty() {
+            return self.increment_counter(name, value).await;
+        }
+
+        counter!(name.to_string(), prometheus_labels(labels)).increment(value);
+
+        Ok(())
+    }
+
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        value: f64,
+        labels: &[MetricLabel],
+    ) -> CarbonResult<()> {
+        if labels.is_empty() {
+            return self.record_histogram(name, value).await;
+        }
+
+        histogram!(name.to_string(), prometheus_labels(labels)).record(value);
+
+        Ok(())
+    }
 }
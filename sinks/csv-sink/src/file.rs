@@ -0,0 +1,307 @@
+use {
+    carbon_core::{row::Row, serializer::csv_field},
+    flate2::{write::GzEncoder, Compression},
+    serde_json::Value,
+    std::{
+        collections::HashMap,
+        fs::{File, OpenOptions},
+        io::{self, BufWriter, Write},
+        path::PathBuf,
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// The CSV files being written, one per row table, and their rotation
+/// settings.
+pub(crate) struct CsvFiles {
+    dir: PathBuf,
+    pub columns: HashMap<String, Vec<String>>,
+    pub max_file_size: Option<u64>,
+    pub max_file_age: Option<Duration>,
+    pub gzip: bool,
+    started_at: u128,
+    next_index: HashMap<String, u32>,
+    open: HashMap<String, CsvFile>,
+}
+
+impl CsvFiles {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            columns: HashMap::new(),
+            max_file_size: None,
+            max_file_age: None,
+            gzip: false,
+            started_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis(),
+            next_index: HashMap::new(),
+            open: HashMap::new(),
+        }
+    }
+
+    /// Writes a row, rotating its file first if it is full or too old.
+    /// Returns whether a file was rotated.
+    pub(crate) fn write(&mut self, row: &Row) -> io::Result<bool> {
+        let mut rotated = false;
+        if let Some(file) = self.open.remove(&row.table) {
+            if self.is_due(&file) {
+                file.finish()?;
+                rotated = true;
+            } else {
+                self.open.insert(row.table.clone(), file);
+            }
+        }
+
+        if !self.open.contains_key(&row.table) {
+            let file = self.create(row)?;
+            self.open.insert(row.table.clone(), file);
+        }
+
+        self.open
+            .get_mut(&row.table)
+            .expect("file is open")
+            .write_row(&row.columns)?;
+
+        Ok(rotated)
+    }
+
+    /// Flushes the open files, closing those older than `max_file_age`.
+    /// Returns how many files were closed.
+    pub(crate) fn flush(&mut self) -> io::Result<usize> {
+        let due: Vec<String> = self
+            .open
+            .iter()
+            .filter(|(_, file)| self.is_due(file))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        for name in &due {
+            if let Some(file) = self.open.remove(name) {
+                file.finish()?;
+            }
+        }
+
+        for file in self.open.values_mut() {
+            file.output.flush()?;
+        }
+
+        Ok(due.len())
+    }
+
+    /// Closes every open file. Rows written afterwards go to new files.
+    pub(crate) fn close(&mut self) -> io::Result<()> {
+        for (_, file) in self.open.drain() {
+            file.finish()?;
+        }
+
+        Ok(())
+    }
+
+    fn is_due(&self, file: &CsvFile) -> bool {
+        self.max_file_size
+            .is_some_and(|max_file_size| file.bytes_written >= max_file_size)
+            || self
+                .max_file_age
+                .is_some_and(|max_file_age| file.opened_at.elapsed() >= max_file_age)
+    }
+
+    fn create(&mut self, row: &Row) -> io::Result<CsvFile> {
+        let index = self.next_index.entry(row.table.clone()).or_insert(0);
+        let path = self.dir.join(format!(
+            "{}-{}-{:04}.csv{}",
+            row.table,
+            self.started_at,
+            index,
+            if self.gzip { ".gz" } else { "" }
+        ));
+        *index += 1;
+
+        let file = BufWriter::new(OpenOptions::new().write(true).create_new(true).open(path)?);
+        let output = if self.gzip {
+            Output::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            Output::Plain(file)
+        };
+
+        let columns = self
+            .columns
+            .get(&row.table)
+            .cloned()
+            .unwrap_or_else(|| row.columns.iter().map(|(name, _)| name.clone()).collect());
+
+        let mut file = CsvFile {
+            output,
+            columns,
+            bytes_written: 0,
+            opened_at: Instant::now(),
+        };
+        let header: Vec<String> = file
+            .columns
+            .iter()
+            .map(|column| csv_field(&Value::String(column.clone())))
+            .collect();
+        file.write_line(&header)?;
+
+        Ok(file)
+    }
+}
+
+struct CsvFile {
+    output: Output,
+    columns: Vec<String>,
+    /// The bytes written before compression.
+    bytes_written: u64,
+    opened_at: Instant,
+}
+
+impl CsvFile {
+    /// Writes the values of the file's columns, leaving missing ones empty
+    /// and dropping the others.
+    fn write_row(&mut self, values: &[(String, Value)]) -> io::Result<()> {
+        let fields: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| {
+                values
+                    .iter()
+                    .find(|(name, _)| name == column)
+                    .map(|(_, value)| csv_field(value))
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        self.write_line(&fields)
+    }
+
+    fn write_line(&mut self, fields: &[String]) -> io::Result<()> {
+        let mut line = fields.join(",");
+        line.push('\n');
+
+        self.output.write_all(line.as_bytes())?;
+        self.bytes_written += line.len() as u64;
+
+        Ok(())
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self.output {
+            Output::Plain(mut file) => file.flush(),
+            Output::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+enum Output {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl Output {
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        match self {
+            Output::Plain(file) => file.write_all(bytes),
+            Output::Gzip(encoder) => encoder.write_all(bytes),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Output::Plain(file) => file.flush(),
+            Output::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        flate2::read::GzDecoder,
+        serde_json::json,
+        std::{fs, io::Read, path::Path},
+    };
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("carbon-csv-sink-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_files(dir: &Path) -> Vec<String> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let mut contents = String::new();
+                if path.extension().is_some_and(|extension| extension == "gz") {
+                    GzDecoder::new(File::open(path).unwrap())
+                        .read_to_string(&mut contents)
+                        .unwrap();
+                } else {
+                    contents = fs::read_to_string(path).unwrap();
+                }
+                contents
+            })
+            .collect()
+    }
+
+    fn buy(slot: u64, amount: u64) -> Row {
+        Row::new(
+            "instructions",
+            vec![("slot", json!(slot))],
+            json!({ "Buy": { "amount": amount, "slot": "x" } }),
+        )
+    }
+
+    #[test]
+    fn test_rotate_by_size() {
+        // Arrange
+        let dir = test_dir("size");
+        let mut files = CsvFiles::new(dir.clone());
+        files.max_file_size = Some(30);
+
+        // Act
+        let rotated: Vec<bool> = (1..=3)
+            .map(|slot| files.write(&buy(slot, slot * 10)).unwrap())
+            .collect();
+        files.close().unwrap();
+
+        // Assert
+        assert_eq!(rotated, vec![false, false, true]);
+        assert_eq!(
+            read_files(&dir),
+            vec![
+                "slot,amount,data_slot\n1,10,x\n2,20,x\n".to_string(),
+                "slot,amount,data_slot\n3,30,x\n".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gzip_with_configured_columns() {
+        // Arrange
+        let dir = test_dir("gzip");
+        let mut files = CsvFiles::new(dir.clone());
+        files.gzip = true;
+        files.columns.insert(
+            "buy".to_string(),
+            vec!["amount".to_string(), "missing".to_string()],
+        );
+
+        // Act
+        files.write(&buy(1, 10)).unwrap();
+        files.close().unwrap();
+
+        // Assert
+        assert_eq!(read_files(&dir), vec!["amount,missing\n10,\n".to_string()]);
+    }
+}
@@ -0,0 +1,292 @@
+//! Writes decoded accounts and instructions from a Carbon pipeline to CSV
+//! files, for analysts who want flat files out of a backfill.
+//!
+//! Each variant of a decoded type is written to its own files, named after the
+//! variant in snake case, such as `buy` for `PumpfunInstruction::Buy`. Each
+//! file starts with a header. By default, the columns are the metadata columns
+//! followed by the fields of the variant, in snake case as in the SQL sinks,
+//! and can be chosen per variant with `with_columns`. Nested values are
+//! written as JSON text.
+//!
+//! - Account rows start with the `pubkey`, `slot`, `lamports` and `owner` of
+//!   the account.
+//! - Instruction rows start with the `signature`, `path`, `slot` and
+//!   `program_id` of the instruction, `path` being the position of the
+//!   instruction in its transaction.
+//!
+//! # Rotation
+//!
+//! Files are named `<variant>-<start>-<index>.csv`, `start` being when the
+//! sink was created, in milliseconds since the epoch. A new file is started
+//! once the current one holds `max_file_size` bytes, before compression, or
+//! is older than `max_file_age`. With `with_gzip`, files are compressed and
+//! named `.csv.gz`.
+//!
+//! Files are flushed on each flush of the pipeline, and closed when it shuts
+//! down.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = CsvSink::new("exports")?
+//!     .with_max_file_size(100 * 1024 * 1024)
+//!     .with_max_file_age(Duration::from_secs(3600))
+//!     .with_gzip(true)
+//!     .with_columns("buy", ["slot", "signature", "amount", "max_sol_cost"]);
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, sink.instructions(carbon_csv_sink::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+        row::{identifier, Row},
+    },
+    file::CsvFiles,
+    serde::Serialize,
+    serde_json::json,
+    std::{
+        fs,
+        path::PathBuf,
+        sync::{Arc, Mutex, MutexGuard},
+        time::Duration,
+    },
+};
+
+mod file;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the update.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+/// A handle to a directory of CSV files, shared by the account and instruction
+/// processors writing to it.
+#[derive(Clone)]
+pub struct CsvSink {
+    files: Arc<Mutex<CsvFiles>>,
+}
+
+impl CsvSink {
+    /// Writes to the directory at `dir`, creating it if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> CarbonResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|error| {
+            Error::Custom(format!(
+                "Failed to create CSV directory {}: {}",
+                dir.display(),
+                error
+            ))
+        })?;
+
+        Ok(Self {
+            files: Arc::new(Mutex::new(CsvFiles::new(dir))),
+        })
+    }
+
+    /// Sets the columns of the files of a variant, such as `buy`, in order.
+    /// Values of other fields are dropped, and missing ones left empty.
+    pub fn with_columns(
+        self,
+        variant: &str,
+        columns: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let columns = columns.into_iter().map(Into::into).collect();
+        if let Ok(mut files) = self.files.lock() {
+            files.columns.insert(identifier(variant), columns);
+        }
+        self
+    }
+
+    /// Starts a new file once the current one holds `max_file_size` bytes,
+    /// before compression.
+    pub fn with_max_file_size(self, max_file_size: u64) -> Self {
+        if let Ok(mut files) = self.files.lock() {
+            files.max_file_size = Some(max_file_size);
+        }
+        self
+    }
+
+    /// Starts a new file once the current one is older than `max_file_age`.
+    pub fn with_max_file_age(self, max_file_age: Duration) -> Self {
+        if let Ok(mut files) = self.files.lock() {
+            files.max_file_age = Some(max_file_age);
+        }
+        self
+    }
+
+    /// Compresses the files with gzip. Disabled by default.
+    pub fn with_gzip(self, gzip: bool) -> Self {
+        if let Ok(mut files) = self.files.lock() {
+            files.gzip = gzip;
+        }
+        self
+    }
+
+    /// Returns a processor writing decoded accounts to this directory.
+    pub fn accounts<T>(&self, encoder: Encoder<T>) -> CsvAccountSink<T> {
+        CsvAccountSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor writing decoded instructions to this directory.
+    pub fn instructions<T>(&self, encoder: Encoder<T>) -> CsvInstructionSink<T> {
+        CsvInstructionSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    fn lock(&self) -> CarbonResult<MutexGuard<'_, CsvFiles>> {
+        self.files
+            .lock()
+            .map_err(|_| Error::Custom("CSV sink lock poisoned".to_string()))
+    }
+
+    async fn write(&self, row: Row, metrics: &MetricsCollection) -> CarbonResult<()> {
+        let rotated = self.lock()?.write(&row).map_err(csv_error)?;
+
+        metrics
+            .increment_counter("csv_sink_rows_written", 1)
+            .await?;
+        if rotated {
+            metrics
+                .increment_counter("csv_sink_files_rotated", 1)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&self, metrics: &MetricsCollection) -> CarbonResult<()> {
+        let closed = self.lock()?.flush().map_err(csv_error)?;
+
+        if closed > 0 {
+            metrics
+                .increment_counter("csv_sink_files_rotated", closed as u64)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn close(&self) -> CarbonResult<()> {
+        self.lock()?.close().map_err(csv_error)
+    }
+}
+
+/// Writes decoded accounts, one row per update.
+pub struct CsvAccountSink<T> {
+    sink: CsvSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for CsvAccountSink<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let row = Row::new(
+            "accounts",
+            vec![
+                ("pubkey", json!(metadata.pubkey.to_string())),
+                ("slot", json!(metadata.slot)),
+                ("lamports", json!(decoded_account.lamports)),
+                ("owner", json!(decoded_account.owner.to_string())),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.flush(&metrics).await
+    }
+
+    async fn teardown(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.close()
+    }
+}
+
+/// Writes decoded instructions, one row per instruction.
+pub struct CsvInstructionSink<T> {
+    sink: CsvSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for CsvInstructionSink<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let path = metadata
+            .absolute_path
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(":");
+
+        let row = Row::new(
+            "instructions",
+            vec![
+                (
+                    "signature",
+                    json!(metadata.transaction_metadata.signature.to_string()),
+                ),
+                ("path", json!(path)),
+                ("slot", json!(metadata.transaction_metadata.slot)),
+                (
+                    "program_id",
+                    json!(decoded_instruction.program_id.to_string()),
+                ),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.flush(&metrics).await
+    }
+
+    async fn teardown(&mut self, _metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.close()
+    }
+}
+
+fn csv_error(error: std::io::Error) -> Error {
+    Error::Custom(format!("CSV sink error: {}", error))
+}
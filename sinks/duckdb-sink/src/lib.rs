@@ -0,0 +1,345 @@
+//! Writes decoded accounts and instructions from a Carbon pipeline to a local
+//! DuckDB database, so decoded history can be queried with SQL right after a
+//! backfill, without a database server.
+//!
+//! The schema is generated from the decoded data, as in the SQLite sink. Each
+//! variant of a decoded type gets its own table, named after the variant in
+//! snake case, with one column per field. New fields, for example after a
+//! program upgrade, are added as new columns.
+//!
+//! - Account tables are keyed by `pubkey` and hold the latest state of each
+//!   account, with its `slot`, `lamports` and `owner`. Closed accounts are
+//!   deleted by the processor returned by `DuckDbSink::account_deletions`.
+//! - Instruction tables are keyed by `signature` and `path`, the position of
+//!   the instruction in its transaction, so reprocessing a transaction does
+//!   not duplicate rows.
+//!
+//! Integers are stored as `HUGEINT`, and nested values as JSON text.
+//!
+//! # Parquet
+//!
+//! With `with_parquet_export`, every table is also written to
+//! `<dir>/<table>.parquet` when the pipeline shuts down, for tools reading
+//! Parquet directly. Combined with `open_in_memory`, the sink only writes
+//! Parquet files.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = DuckDbSink::open("pumpfun.duckdb")?.with_parquet_export("exports");
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, sink.instructions(carbon_duckdb_sink::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType,
+        account_lifecycle::AccountLifecycleEvent,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+        row::{Row, SchemaWriter},
+    },
+    duckdb::Connection,
+    schema::DuckDb,
+    serde::Serialize,
+    serde_json::json,
+    solana_pubkey::Pubkey,
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex},
+    },
+};
+
+mod schema;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the update.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+struct Database {
+    connection: Connection,
+    schema: SchemaWriter,
+}
+
+/// A handle to a DuckDB database, shared by the account and instruction
+/// processors writing to it.
+#[derive(Clone)]
+pub struct DuckDbSink {
+    database: Arc<Mutex<Database>>,
+    parquet_dir: Option<PathBuf>,
+}
+
+impl DuckDbSink {
+    /// Opens or creates the database file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> CarbonResult<Self> {
+        Ok(Self::from_connection(
+            Connection::open(path).map_err(duckdb_error)?,
+        ))
+    }
+
+    /// Opens a database held in memory, discarded when the sink is dropped.
+    pub fn open_in_memory() -> CarbonResult<Self> {
+        Ok(Self::from_connection(
+            Connection::open_in_memory().map_err(duckdb_error)?,
+        ))
+    }
+
+    fn from_connection(connection: Connection) -> Self {
+        Self {
+            database: Arc::new(Mutex::new(Database {
+                connection,
+                schema: SchemaWriter::default(),
+            })),
+            parquet_dir: None,
+        }
+    }
+
+    /// Writes every table to `<dir>/<table>.parquet` when the pipeline shuts
+    /// down. The directory is created if needed.
+    pub fn with_parquet_export(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.parquet_dir = Some(dir.into());
+        self
+    }
+
+    /// Returns a processor writing decoded accounts to this database.
+    pub fn accounts<T>(&self, encoder: Encoder<T>) -> DuckDbAccountSink<T> {
+        DuckDbAccountSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor writing decoded instructions to this database.
+    pub fn instructions<T>(&self, encoder: Encoder<T>) -> DuckDbInstructionSink<T> {
+        DuckDbInstructionSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor deleting the rows of the accounts closed,
+    /// reassigned or deleted, for `PipelineBuilder::account_lifecycle`.
+    pub fn account_deletions(&self) -> DuckDbAccountDeletions {
+        DuckDbAccountDeletions { sink: self.clone() }
+    }
+
+    /// Deletes the rows of `pubkey` from every account table, returning the
+    /// number of rows deleted.
+    pub async fn delete_account(&self, pubkey: &Pubkey) -> CarbonResult<usize> {
+        let pubkey = json!(pubkey.to_string());
+        let database = self.database.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let database = database
+                .lock()
+                .map_err(|_| Error::Custom("DuckDB sink lock poisoned".to_string()))?;
+
+            schema::delete_account(&database.connection, pubkey).map_err(duckdb_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("DuckDB delete task failed: {}", error)))?
+    }
+
+    /// Writes every table written so far to `<dir>/<table>.parquet`,
+    /// overwriting previous exports, and returns the number of tables.
+    pub async fn export_parquet(&self, dir: impl Into<PathBuf>) -> CarbonResult<usize> {
+        let database = self.database.clone();
+        let dir = dir.into();
+
+        tokio::task::spawn_blocking(move || {
+            std::fs::create_dir_all(&dir).map_err(|error| {
+                Error::Custom(format!(
+                    "Failed to create Parquet directory {}: {}",
+                    dir.display(),
+                    error
+                ))
+            })?;
+
+            let database = database
+                .lock()
+                .map_err(|_| Error::Custom("DuckDB sink lock poisoned".to_string()))?;
+
+            schema::export_parquet(&database.connection, database.schema.tables(), &dir)
+                .map_err(duckdb_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("DuckDB export task failed: {}", error)))?
+    }
+
+    async fn write(
+        &self,
+        row: Row,
+        primary_key: &'static [&'static str],
+        replace: bool,
+    ) -> CarbonResult<()> {
+        let database = self.database.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut database = database
+                .lock()
+                .map_err(|_| Error::Custom("DuckDB sink lock poisoned".to_string()))?;
+            let Database { connection, schema } = &mut *database;
+
+            schema
+                .write(&DuckDb(connection), row, primary_key, replace)
+                .map_err(duckdb_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("DuckDB write task failed: {}", error)))?
+    }
+
+    async fn teardown(&self, metrics: &MetricsCollection) -> CarbonResult<()> {
+        let Some(dir) = &self.parquet_dir else {
+            return Ok(());
+        };
+
+        let exported = self.export_parquet(dir.clone()).await?;
+
+        metrics
+            .increment_counter("duckdb_sink_parquet_files_written", exported as u64)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Writes the latest state of each decoded account.
+pub struct DuckDbAccountSink<T> {
+    sink: DuckDbSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for DuckDbAccountSink<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let row = Row::new(
+            "accounts",
+            vec![
+                ("pubkey", json!(metadata.pubkey.to_string())),
+                ("slot", json!(metadata.slot)),
+                ("lamports", json!(decoded_account.lamports)),
+                ("owner", json!(decoded_account.owner.to_string())),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["pubkey"], true).await?;
+
+        metrics
+            .increment_counter("duckdb_sink_accounts_written", 1)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.teardown(&metrics).await
+    }
+}
+
+/// Deletes the rows of an account on each of its lifecycle events.
+pub struct DuckDbAccountDeletions {
+    sink: DuckDbSink,
+}
+
+#[async_trait]
+impl Processor for DuckDbAccountDeletions {
+    type InputType = AccountLifecycleEvent;
+
+    async fn process(
+        &mut self,
+        event: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let deleted = self.sink.delete_account(&event.pubkey()).await?;
+
+        metrics
+            .increment_counter("duckdb_sink_accounts_deleted", deleted as u64)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Writes each decoded instruction once.
+pub struct DuckDbInstructionSink<T> {
+    sink: DuckDbSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for DuckDbInstructionSink<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let path = metadata
+            .absolute_path
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(":");
+
+        let row = Row::new(
+            "instructions",
+            vec![
+                (
+                    "signature",
+                    json!(metadata.transaction_metadata.signature.to_string()),
+                ),
+                ("path", json!(path)),
+                ("slot", json!(metadata.transaction_metadata.slot)),
+                (
+                    "program_id",
+                    json!(decoded_instruction.program_id.to_string()),
+                ),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["signature", "path"], false).await?;
+
+        metrics
+            .increment_counter("duckdb_sink_instructions_written", 1)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.sink.teardown(&metrics).await
+    }
+}
+
+fn duckdb_error(error: duckdb::Error) -> Error {
+    Error::Custom(format!("DuckDB error: {}", error))
+}
@@ -0,0 +1,198 @@
+use {
+    carbon_core::row::SqlDatabase,
+    duckdb::{params_from_iter, types::Value as SqlValue, Connection},
+    serde_json::Value,
+    std::{collections::HashSet, path::Path},
+};
+
+/// The DuckDB dialect of the `SchemaWriter` writing the rows.
+pub(crate) struct DuckDb<'a>(pub &'a Connection);
+
+impl SqlDatabase for DuckDb<'_> {
+    type Error = duckdb::Error;
+
+    /// Unlike SQLite, DuckDB columns are strictly typed, so integers use
+    /// `HUGEINT` to hold both `i64` and `u64` values, and columns first seen
+    /// as null are `VARCHAR`.
+    fn column_type(value: &Value) -> &'static str {
+        match value {
+            Value::Bool(_) => "BOOLEAN",
+            Value::Number(number) if number.is_f64() => "DOUBLE",
+            Value::Number(_) => "HUGEINT",
+            _ => "VARCHAR",
+        }
+    }
+
+    fn table_columns(&self, table: &str) -> duckdb::Result<HashSet<String>> {
+        let mut statement = self
+            .0
+            .prepare("SELECT column_name FROM information_schema.columns WHERE table_name = ?")?;
+        let columns = statement
+            .query_map(duckdb::params![table], |column| column.get::<_, String>(0))?
+            .collect::<duckdb::Result<HashSet<String>>>()?;
+
+        Ok(columns)
+    }
+
+    fn execute(&self, sql: &str, params: Vec<Value>) -> duckdb::Result<usize> {
+        self.0
+            .execute(sql, params_from_iter(params.into_iter().map(sql_value)))
+    }
+}
+
+/// Writes each of `tables` to `<dir>/<table>.parquet`, overwriting previous
+/// exports. Returns the number of tables written.
+pub(crate) fn export_parquet<'a>(
+    connection: &Connection,
+    tables: impl Iterator<Item = &'a str>,
+    dir: &Path,
+) -> duckdb::Result<usize> {
+    let mut exported = 0;
+    for table in tables {
+        let path = dir.join(format!("{}.parquet", table));
+
+        connection.execute(
+            &format!(
+                "COPY \"{}\" TO '{}' (FORMAT PARQUET)",
+                table,
+                path.display().to_string().replace('\'', "''")
+            ),
+            [],
+        )?;
+        exported += 1;
+    }
+
+    Ok(exported)
+}
+
+/// Deletes the rows of `pubkey` from the account tables, the tables with
+/// `pubkey`, `lamports` and `owner` columns. Returns the number of rows
+/// deleted.
+pub(crate) fn delete_account(connection: &Connection, pubkey: Value) -> duckdb::Result<usize> {
+    let mut statement = connection.prepare(
+        "SELECT table_name FROM information_schema.columns \
+         WHERE column_name IN ('pubkey', 'lamports', 'owner') \
+         GROUP BY table_name HAVING COUNT(*) = 3",
+    )?;
+    let tables = statement
+        .query_map([], |table| table.get::<_, String>(0))?
+        .collect::<duckdb::Result<Vec<String>>>()?;
+
+    let pubkey = sql_value(pubkey);
+    let mut deleted = 0;
+    for table in tables {
+        deleted += connection.execute(
+            &format!("DELETE FROM \"{}\" WHERE pubkey = ?", table),
+            [&pubkey],
+        )?;
+    }
+
+    Ok(deleted)
+}
+
+/// Converts a JSON value into a DuckDB value. Nested values are stored as
+/// JSON text.
+fn sql_value(value: Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(value) => SqlValue::Boolean(value),
+        Value::Number(number) => match (number.as_i64(), number.as_u64(), number.as_f64()) {
+            (Some(integer), _, _) => SqlValue::HugeInt(integer as i128),
+            (None, Some(integer), _) => SqlValue::HugeInt(integer as i128),
+            (None, None, Some(float)) => SqlValue::Double(float),
+            _ => SqlValue::Text(number.to_string()),
+        },
+        Value::String(value) => SqlValue::Text(value),
+        value => SqlValue::Text(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::row::{Row, SchemaWriter},
+        serde_json::json,
+    };
+
+    fn pool(fields: Value) -> Row {
+        Row::new(
+            "accounts",
+            vec![("pubkey", json!("a"))],
+            json!({ "Pool": fields }),
+        )
+    }
+
+    #[test]
+    fn test_write_adds_new_columns() {
+        let connection = Connection::open_in_memory().unwrap();
+        let mut writer = SchemaWriter::default();
+
+        writer
+            .write(
+                &DuckDb(&connection),
+                pool(json!({ "fee": 1 })),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+        writer
+            .write(
+                &DuckDb(&connection),
+                pool(json!({ "fee": u64::MAX, "paused": true })),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+
+        let (fee, paused): (i128, bool) = connection
+            .query_row(
+                "SELECT fee, paused FROM pool WHERE pubkey = 'a'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        let count: i64 = connection
+            .query_row("SELECT COUNT(*) FROM pool", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!((fee, paused), (u64::MAX as i128, true));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_export_parquet() {
+        // Arrange
+        let dir = std::env::temp_dir().join(format!("carbon-duckdb-sink-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let connection = Connection::open_in_memory().unwrap();
+        let mut writer = SchemaWriter::default();
+        writer
+            .write(
+                &DuckDb(&connection),
+                pool(json!({ "fee": 7 })),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+
+        // Act
+        let exported = export_parquet(&connection, writer.tables(), &dir).unwrap();
+
+        // Assert
+        let fee: i128 = connection
+            .query_row(
+                &format!(
+                    "SELECT fee FROM read_parquet('{}')",
+                    dir.join("pool.parquet").display()
+                ),
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        assert_eq!(exported, 1);
+        assert_eq!(fee, 7);
+    }
+}
@@ -0,0 +1,241 @@
+//! Publishes decoded instructions from a Carbon pipeline to NATS JetStream.
+//!
+//! [`NatsPublisher`] is a `Processor` publishing each decoded instruction as a
+//! JSON event on a subject built from a template, such as
+//! `carbon.{program}.{event_type}`. Events are stored in a durable JetStream
+//! stream, created with [`ensure_stream`], so consumers can replay them.
+//!
+//! # Subject templates
+//!
+//! - `{program}`: The program ID of the instruction.
+//! - `{event_type}`: The variant of the decoded instruction, such as `Buy` for
+//!   `PumpfunInstruction::Buy`. This is the tag of the externally tagged JSON
+//!   produced by the encoder, or `unknown` if there is none.
+//!
+//! # Serialization
+//!
+//! Events are published as JSON by default. Use `with_serializer` to publish
+//! them in another format, such as CSV with `CsvSerializer`. The MIME type of
+//! the format is set in the `Content-Type` header of each message.
+//!
+//! # Backpressure
+//!
+//! Publishing does not wait for each acknowledgement. Up to
+//! `max_pending_acks` acknowledgements are awaited lazily; once the limit is
+//! reached, the publisher waits for the oldest one before publishing again,
+//! which slows the pipeline down instead of buffering without bound.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let client = async_nats::connect("nats://127.0.0.1:4222").await?;
+//! let jetstream = async_nats::jetstream::new(client);
+//! carbon_nats_sink::ensure_stream(&jetstream, "CARBON", "carbon.>").await?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         NatsPublisher::new(jetstream, "carbon.{program}.{event_type}", carbon_nats_sink::json()),
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_nats::jetstream::{self, context::PublishAckFuture},
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+        serializer::{JsonSerializer, SinkSerializer},
+    },
+    serde::Serialize,
+    std::{collections::VecDeque, sync::Arc},
+};
+
+const MAX_PENDING_ACKS: usize = 256;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the event.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+/// Creates the durable stream `name` capturing `subjects`, unless it already
+/// exists.
+pub async fn ensure_stream(
+    context: &jetstream::Context,
+    name: &str,
+    subjects: &str,
+) -> CarbonResult<()> {
+    context
+        .get_or_create_stream(jetstream::stream::Config {
+            name: name.to_string(),
+            subjects: vec![subjects.to_string()],
+            ..Default::default()
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("Failed to create NATS stream: {}", error)))?;
+
+    Ok(())
+}
+
+/// Publishes decoded instructions as JSON events on NATS JetStream.
+///
+/// Each event is an object with the `signature`, `slot`, `program_id` and
+/// `path` of the instruction, and the `data` produced by the encoder,
+/// serialized as JSON unless another serializer is set.
+pub struct NatsPublisher<T> {
+    context: jetstream::Context,
+    subject_template: String,
+    encoder: Encoder<T>,
+    serializer: Box<dyn SinkSerializer>,
+    pending_acks: VecDeque<PublishAckFuture>,
+    max_pending_acks: usize,
+}
+
+impl<T> NatsPublisher<T> {
+    pub fn new(
+        context: jetstream::Context,
+        subject_template: impl Into<String>,
+        encoder: Encoder<T>,
+    ) -> Self {
+        Self {
+            context,
+            subject_template: subject_template.into(),
+            encoder,
+            serializer: Box::new(JsonSerializer),
+            pending_acks: VecDeque::new(),
+            max_pending_acks: MAX_PENDING_ACKS,
+        }
+    }
+
+    /// Sets how many acknowledgements may be pending before publishing waits.
+    /// Defaults to 256.
+    pub fn with_max_pending_acks(mut self, max_pending_acks: usize) -> Self {
+        self.max_pending_acks = max_pending_acks.max(1);
+        self
+    }
+
+    /// Sets the format of the published events. Defaults to JSON.
+    pub fn with_serializer(mut self, serializer: impl SinkSerializer + 'static) -> Self {
+        self.serializer = Box::new(serializer);
+        self
+    }
+
+    async fn wait_for_oldest_ack(&mut self) -> CarbonResult<()> {
+        if let Some(ack) = self.pending_acks.pop_front() {
+            ack.await.map_err(|error| {
+                Error::Custom(format!("NATS publish was not acknowledged: {}", error))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for NatsPublisher<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let program_id = decoded_instruction.program_id.to_string();
+        let subject = render_subject(
+            &self.subject_template,
+            &program_id,
+            event_type(&data).unwrap_or("unknown"),
+        );
+
+        let event = serde_json::json!({
+            "signature": metadata.transaction_metadata.signature.to_string(),
+            "slot": metadata.transaction_metadata.slot,
+            "program_id": program_id,
+            "path": metadata.absolute_path,
+            "data": data,
+        });
+
+        while self.pending_acks.len() >= self.max_pending_acks {
+            metrics
+                .increment_counter("nats_sink_backpressure_waits", 1)
+                .await?;
+            self.wait_for_oldest_ack().await?;
+        }
+
+        let payload = self.serializer.serialize(&event)?;
+        let mut headers = async_nats::HeaderMap::new();
+        headers.insert("Content-Type", self.serializer.content_type());
+
+        let ack = self
+            .context
+            .publish_with_headers(subject, headers, payload.into())
+            .await
+            .map_err(|error| Error::Custom(format!("Failed to publish to NATS: {}", error)))?;
+        self.pending_acks.push_back(ack);
+
+        metrics
+            .increment_counter("nats_sink_events_published", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Returns the tag of an externally tagged enum value, such as `Buy` for
+/// `{"Buy": {...}}`.
+fn event_type(data: &serde_json::Value) -> Option<&str> {
+    match data {
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        serde_json::Value::String(tag) => Some(tag),
+        _ => None,
+    }
+}
+
+fn render_subject(template: &str, program: &str, event_type: &str) -> String {
+    template
+        .replace("{program}", program)
+        .replace("{event_type}", event_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_subject() {
+        let data = serde_json::json!({ "Buy": { "amount": 1 } });
+
+        let subject = render_subject(
+            "carbon.{program}.{event_type}",
+            "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+            event_type(&data).unwrap_or("unknown"),
+        );
+
+        assert_eq!(
+            subject,
+            "carbon.6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P.Buy"
+        );
+    }
+
+    #[test]
+    fn test_event_type_without_tag() {
+        assert_eq!(event_type(&serde_json::json!({ "a": 1, "b": 2 })), None);
+        assert_eq!(
+            event_type(&serde_json::json!("Initialize")),
+            Some("Initialize")
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Sends decoded instructions from a Carbon pipeline to Telegram or Discord.
+//!
+//! [`NotificationSink`] is a `Processor` rendering each decoded instruction
+//! with a template and sending it through a [`Notifier`], to a Telegram chat
+//! through a bot, or to a Discord channel through a webhook.
+//!
+//! # Templates
+//!
+//! - `{signature}`, `{slot}`: The transaction of the instruction.
+//! - `{program}`: The program ID of the instruction.
+//! - `{event_type}`: The variant of the decoded instruction, such as `Buy` for
+//!   `PumpfunInstruction::Buy`, or `unknown` if there is none.
+//! - `{data}`: The JSON produced by the encoder.
+//! - `{data.Buy.amount}`: A field of the JSON produced by the encoder.
+//!
+//! Unknown placeholders are left as they are.
+//!
+//! # Batching and rate limiting
+//!
+//! Messages are queued and sent in batches of up to `max_batch_size`
+//! messages, joined by newlines, when the batch is full and on each flush of
+//! the pipeline. Batches are sent at most `max_messages` times per `period`,
+//! and wait as long as Telegram or Discord ask when they rate limit the sink.
+//! Queued messages past `max_queued` are dropped, oldest first.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let notifier = Notifier::new(Channel::discord(webhook_url)).with_max_batch_size(5);
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         NotificationSink::new(
+//!             notifier,
+//!             "{event_type} https://solscan.io/tx/{signature}",
+//!             carbon_notify_sink::json(),
+//!         ),
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult, instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection, processor::Processor,
+    },
+    serde::Serialize,
+    serde_json::Value,
+    std::sync::Arc,
+};
+
+pub use notifier::{Channel, Notifier};
+
+mod notifier;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the event.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+/// Sends decoded instructions, rendered with a template, as notifications.
+///
+/// Queued messages are sent on each flush of the pipeline, and when it shuts
+/// down.
+pub struct NotificationSink<T> {
+    notifier: Notifier,
+    template: String,
+    encoder: Encoder<T>,
+}
+
+impl<T> NotificationSink<T> {
+    pub fn new(notifier: Notifier, template: impl Into<String>, encoder: Encoder<T>) -> Self {
+        Self {
+            notifier,
+            template: template.into(),
+            encoder,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for NotificationSink<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let message = render_template(
+            &self.template,
+            &[
+                (
+                    "signature",
+                    metadata.transaction_metadata.signature.to_string(),
+                ),
+                ("slot", metadata.transaction_metadata.slot.to_string()),
+                ("program", decoded_instruction.program_id.to_string()),
+                (
+                    "event_type",
+                    event_type(&data).unwrap_or("unknown").to_string(),
+                ),
+            ],
+            &data,
+        );
+
+        self.notifier.push(message, &metrics).await
+    }
+
+    async fn flush(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.notifier.send_queued(&metrics).await
+    }
+
+    async fn teardown(&mut self, metrics: Arc<MetricsCollection>) -> CarbonResult<()> {
+        self.notifier.send_queued(&metrics).await
+    }
+}
+
+/// Returns the tag of an externally tagged enum value, such as `Buy` for
+/// `{"Buy": {...}}`.
+fn event_type(data: &Value) -> Option<&str> {
+    match data {
+        Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        Value::String(tag) => Some(tag),
+        _ => None,
+    }
+}
+
+/// Replaces the `{name}` placeholders of `template` with their value, and the
+/// `{data}` and `{data.field}` placeholders with `data` and its fields.
+fn render_template(template: &str, values: &[(&str, String)], data: &Value) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let placeholder = &rest[1..end];
+
+        match placeholder_value(placeholder, values, data) {
+            Some(value) => rendered.push_str(&value),
+            None => rendered.push_str(&rest[..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+fn placeholder_value(placeholder: &str, values: &[(&str, String)], data: &Value) -> Option<String> {
+    if let Some((_, value)) = values.iter().find(|(name, _)| *name == placeholder) {
+        return Some(value.clone());
+    }
+
+    let mut value = data;
+    let mut path = placeholder.split('.');
+    if path.next() != Some("data") {
+        return None;
+    }
+    for field in path {
+        value = match value {
+            Value::Array(items) => items.get(field.parse::<usize>().ok()?)?,
+            value => value.get(field)?,
+        };
+    }
+
+    Some(match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let data = serde_json::json!({ "Buy": { "amount": 1000, "mints": ["abc"] } });
+
+        let message = render_template(
+            "{event_type} of {data.Buy.amount} {data.Buy.mints.0} in {signature} {unknown}",
+            &[
+                ("signature", "5h6x".to_string()),
+                (
+                    "event_type",
+                    event_type(&data).unwrap_or("unknown").to_string(),
+                ),
+            ],
+            &data,
+        );
+
+        assert_eq!(message, "Buy of 1000 abc in 5h6x {unknown}");
+    }
+
+    #[test]
+    fn test_render_template_with_unclosed_placeholder() {
+        let message = render_template("slot {slot", &[("slot", "1".to_string())], &Value::Null);
+
+        assert_eq!(message, "slot {slot");
+    }
+}
@@ -0,0 +1,313 @@
+use {
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    reqwest::StatusCode,
+    serde_json::Value,
+    std::{
+        collections::VecDeque,
+        time::{Duration, Instant},
+    },
+};
+
+const DEFAULT_MAX_BATCH_SIZE: usize = 10;
+const DEFAULT_MAX_QUEUED: usize = 1_000;
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Where notifications are sent.
+#[derive(Debug, Clone)]
+pub enum Channel {
+    /// A Telegram chat, through the bot with the token `bot_token`.
+    Telegram { bot_token: String, chat_id: String },
+    /// A Discord channel, through one of its webhooks.
+    Discord { webhook_url: String },
+}
+
+impl Channel {
+    pub fn telegram(bot_token: impl Into<String>, chat_id: impl Into<String>) -> Self {
+        Channel::Telegram {
+            bot_token: bot_token.into(),
+            chat_id: chat_id.into(),
+        }
+    }
+
+    pub fn discord(webhook_url: impl Into<String>) -> Self {
+        Channel::Discord {
+            webhook_url: webhook_url.into(),
+        }
+    }
+
+    fn max_message_length(&self) -> usize {
+        match self {
+            Channel::Telegram { .. } => 4096,
+            Channel::Discord { .. } => 2000,
+        }
+    }
+
+    /// The documented limits of a bot posting in a group, and of a webhook.
+    fn default_rate_limit(&self) -> (u32, Duration) {
+        match self {
+            Channel::Telegram { .. } => (20, Duration::from_secs(60)),
+            Channel::Discord { .. } => (30, Duration::from_secs(60)),
+        }
+    }
+
+    fn request(&self, client: &reqwest::Client, text: &str) -> reqwest::RequestBuilder {
+        match self {
+            Channel::Telegram { bot_token, chat_id } => client
+                .post(format!(
+                    "https://api.telegram.org/bot{}/sendMessage",
+                    bot_token
+                ))
+                .json(&serde_json::json!({
+                    "chat_id": chat_id,
+                    "text": text,
+                    "disable_web_page_preview": true,
+                })),
+            Channel::Discord { webhook_url } => client
+                .post(webhook_url)
+                .json(&serde_json::json!({ "content": text })),
+        }
+    }
+}
+
+/// Queues messages and sends them to a [`Channel`] in rate limited batches.
+pub struct Notifier {
+    client: reqwest::Client,
+    channel: Channel,
+    max_batch_size: usize,
+    max_queued: usize,
+    max_messages: u32,
+    period: Duration,
+    queued: VecDeque<String>,
+    window_start: Instant,
+    window_count: u32,
+    blocked_until: Option<Instant>,
+}
+
+impl Notifier {
+    pub fn new(channel: Channel) -> Self {
+        let (max_messages, period) = channel.default_rate_limit();
+
+        Self {
+            client: reqwest::Client::new(),
+            channel,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            max_queued: DEFAULT_MAX_QUEUED,
+            max_messages,
+            period,
+            queued: VecDeque::new(),
+            window_start: Instant::now(),
+            window_count: 0,
+            blocked_until: None,
+        }
+    }
+
+    /// Sets how many messages are joined into one notification. Defaults to
+    /// 10.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size.max(1);
+        self
+    }
+
+    /// Sets how many notifications are sent per `period`. Defaults to 20 per
+    /// minute for Telegram and 30 per minute for Discord.
+    pub fn with_rate_limit(mut self, max_messages: u32, period: Duration) -> Self {
+        self.max_messages = max_messages.max(1);
+        self.period = period;
+        self
+    }
+
+    /// Sets how many messages may wait to be sent before the oldest ones are
+    /// dropped. Defaults to 1000.
+    pub fn with_max_queued(mut self, max_queued: usize) -> Self {
+        self.max_queued = max_queued.max(1);
+        self
+    }
+
+    /// Queues a message, sending the queued messages once a batch is full.
+    pub async fn push(&mut self, message: String, metrics: &MetricsCollection) -> CarbonResult<()> {
+        if self.queued.len() >= self.max_queued {
+            self.queued.pop_front();
+            metrics
+                .increment_counter("notify_sink_messages_dropped", 1)
+                .await?;
+        }
+        self.queued.push_back(message);
+
+        if self.queued.len() >= self.max_batch_size {
+            self.send_queued(metrics).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the queued messages, as far as the rate limit allows. The others
+    /// stay queued until the next call.
+    pub async fn send_queued(&mut self, metrics: &MetricsCollection) -> CarbonResult<()> {
+        while !self.queued.is_empty() && self.acquire() {
+            let (count, text) = next_batch(
+                &self.queued,
+                self.max_batch_size,
+                self.channel.max_message_length(),
+            );
+
+            match self.send(&text).await {
+                Ok(None) => {
+                    self.queued.drain(..count);
+                    metrics
+                        .increment_counter("notify_sink_notifications_sent", 1)
+                        .await?;
+                    metrics
+                        .increment_counter("notify_sink_messages_sent", count as u64)
+                        .await?;
+                }
+                Ok(Some(retry_after)) => {
+                    log::warn!("Notifications rate limited for {:?}", retry_after);
+                    self.blocked_until = Some(Instant::now() + retry_after);
+                    metrics
+                        .increment_counter("notify_sink_rate_limited", 1)
+                        .await?;
+                    break;
+                }
+                Err(error) => {
+                    self.queued.drain(..count);
+                    metrics.increment_counter("notify_sink_errors", 1).await?;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Takes a slot of the rate limit, unless it is exhausted or the channel
+    /// asked to wait.
+    fn acquire(&mut self) -> bool {
+        let now = Instant::now();
+
+        if let Some(blocked_until) = self.blocked_until {
+            if now < blocked_until {
+                return false;
+            }
+            self.blocked_until = None;
+        }
+
+        if now.duration_since(self.window_start) >= self.period {
+            self.window_start = now;
+            self.window_count = 0;
+        }
+
+        if self.window_count < self.max_messages {
+            self.window_count += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Sends a notification, returning how long to wait if it was rate
+    /// limited.
+    async fn send(&self, text: &str) -> CarbonResult<Option<Duration>> {
+        let response = self
+            .channel
+            .request(&self.client, text)
+            .send()
+            .await
+            .map_err(|error| Error::Custom(format!("Failed to send notification: {}", error)))?;
+
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let body = response.json::<Value>().await.unwrap_or(Value::Null);
+            return Ok(Some(retry_after(&body)));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Custom(format!(
+                "Notification rejected ({}): {}",
+                status, body
+            )));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Joins the oldest queued messages into one notification of at most
+/// `max_length` characters, returning how many messages it contains. A
+/// message longer than `max_length` is truncated.
+fn next_batch(
+    queued: &VecDeque<String>,
+    max_batch_size: usize,
+    max_length: usize,
+) -> (usize, String) {
+    let mut text = String::new();
+    let mut length = 0;
+    let mut count = 0;
+
+    for message in queued.iter().take(max_batch_size) {
+        let message_length = message.chars().count();
+
+        if count == 0 {
+            if message_length > max_length {
+                text = message.chars().take(max_length.saturating_sub(1)).collect();
+                text.push('…');
+                return (1, text);
+            }
+        } else if length + 1 + message_length > max_length {
+            break;
+        } else {
+            text.push('\n');
+            length += 1;
+        }
+
+        text.push_str(message);
+        length += message_length;
+        count += 1;
+    }
+
+    (count, text)
+}
+
+/// Reads how long to wait from a rate limited response, `retry_after` being in
+/// the `parameters` of Telegram responses and at the root of Discord ones.
+fn retry_after(body: &Value) -> Duration {
+    body.get("parameters")
+        .and_then(|parameters| parameters.get("retry_after"))
+        .or_else(|| body.get("retry_after"))
+        .and_then(Value::as_f64)
+        .map(Duration::from_secs_f64)
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_batch_fits_max_length() {
+        let queued: VecDeque<String> = ["first", "second", "third"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(
+            next_batch(&queued, 10, 12),
+            (2, "first\nsecond".to_string())
+        );
+        assert_eq!(next_batch(&queued, 1, 100), (1, "first".to_string()));
+        assert_eq!(next_batch(&queued, 10, 4), (1, "fir…".to_string()));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let telegram = serde_json::json!({ "ok": false, "parameters": { "retry_after": 7 } });
+        let discord = serde_json::json!({ "retry_after": 0.5, "global": false });
+
+        assert_eq!(retry_after(&telegram), Duration::from_secs(7));
+        assert_eq!(retry_after(&discord), Duration::from_millis(500));
+        assert_eq!(retry_after(&Value::Null), DEFAULT_RETRY_AFTER);
+    }
+}
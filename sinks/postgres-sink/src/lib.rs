@@ -0,0 +1,286 @@
+//! Writes decoded accounts, instructions and records from a Carbon pipeline
+//! to a PostgreSQL database.
+//!
+//! The schema is generated from the decoded data, as in the SQLite sink. Each
+//! variant of a decoded type gets its own table, named after the variant in
+//! snake case, with one column per field. New fields, for example after a
+//! program upgrade, are added as new columns.
+//!
+//! - Account tables are keyed by `pubkey` and hold the latest state of each
+//!   account, with its `slot`, `lamports` and `owner`.
+//! - Instruction tables are keyed by `signature` and `path`, the position of
+//!   the instruction in its transaction, so reprocessing a transaction does
+//!   not duplicate rows.
+//! - Record tables, written by `PostgresSink::records`, hold any serializable
+//!   type, such as the normalized swaps of `carbon-swaps`, keyed by the
+//!   columns given.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = PostgresSink::connect("host=localhost user=postgres dbname=indexer").await?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, sink.instructions(carbon_postgres_sink::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+//!
+//! # Notes
+//!
+//! - The connection is not encrypted, so the database should be reached
+//!   through a trusted network.
+//! - Integers are stored as `NUMERIC`, so `u64` amounts are kept exactly,
+//!   and nested values as `JSONB`.
+//! - Rows are written as they are processed, one statement each.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+        row::{Row, SchemaWriter},
+    },
+    schema::Postgres,
+    serde::Serialize,
+    serde_json::json,
+    std::sync::{Arc, Mutex},
+    tokio::runtime::Handle,
+    tokio_postgres::{Client, NoTls},
+};
+
+mod schema;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the update.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+struct Database {
+    client: Client,
+    schema: SchemaWriter,
+}
+
+/// A handle to a PostgreSQL database, shared by the processors writing to
+/// it.
+#[derive(Clone)]
+pub struct PostgresSink {
+    database: Arc<Mutex<Database>>,
+}
+
+impl PostgresSink {
+    /// Connects to the database described by `params`, either a
+    /// `postgresql://` URL or `key=value` pairs.
+    pub async fn connect(params: &str) -> CarbonResult<Self> {
+        let (client, connection) = tokio_postgres::connect(params, NoTls)
+            .await
+            .map_err(postgres_error)?;
+
+        tokio::spawn(async move {
+            if let Err(error) = connection.await {
+                log::error!("PostgreSQL connection error: {}", error);
+            }
+        });
+
+        Ok(Self {
+            database: Arc::new(Mutex::new(Database {
+                client,
+                schema: SchemaWriter::default(),
+            })),
+        })
+    }
+
+    /// Returns a processor writing decoded accounts to this database.
+    pub fn accounts<T>(&self, encoder: Encoder<T>) -> PostgresAccountSink<T> {
+        PostgresAccountSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor writing decoded instructions to this database.
+    pub fn instructions<T>(&self, encoder: Encoder<T>) -> PostgresInstructionSink<T> {
+        PostgresInstructionSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor writing each record to `table`, or to the table
+    /// of its variant for enums, keyed by the `primary_key` columns of the
+    /// encoded record. Records already written are left unchanged.
+    pub fn records<T>(
+        &self,
+        table: &'static str,
+        primary_key: &'static [&'static str],
+        encoder: Encoder<T>,
+    ) -> PostgresRecordSink<T> {
+        PostgresRecordSink {
+            sink: self.clone(),
+            table,
+            primary_key,
+            encoder,
+        }
+    }
+
+    async fn write(
+        &self,
+        row: Row,
+        primary_key: &'static [&'static str],
+        replace: bool,
+    ) -> CarbonResult<()> {
+        let database = self.database.clone();
+        let handle = Handle::current();
+
+        tokio::task::spawn_blocking(move || {
+            let mut database = database
+                .lock()
+                .map_err(|_| Error::Custom("PostgreSQL sink lock poisoned".to_string()))?;
+            let Database { client, schema } = &mut *database;
+
+            schema
+                .write(&Postgres { client, handle }, row, primary_key, replace)
+                .map_err(postgres_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("PostgreSQL write task failed: {}", error)))?
+    }
+}
+
+/// Writes the latest state of each decoded account.
+pub struct PostgresAccountSink<T> {
+    sink: PostgresSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for PostgresAccountSink<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let row = Row::new(
+            "accounts",
+            vec![
+                ("pubkey", json!(metadata.pubkey.to_string())),
+                ("slot", json!(metadata.slot)),
+                ("lamports", json!(decoded_account.lamports)),
+                ("owner", json!(decoded_account.owner.to_string())),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["pubkey"], true).await?;
+
+        metrics
+            .increment_counter("postgres_sink_accounts_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Writes each decoded instruction once.
+pub struct PostgresInstructionSink<T> {
+    sink: PostgresSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for PostgresInstructionSink<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let path = metadata
+            .absolute_path
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(":");
+
+        let row = Row::new(
+            "instructions",
+            vec![
+                (
+                    "signature",
+                    json!(metadata.transaction_metadata.signature.to_string()),
+                ),
+                ("path", json!(path)),
+                ("slot", json!(metadata.transaction_metadata.slot)),
+                (
+                    "program_id",
+                    json!(decoded_instruction.program_id.to_string()),
+                ),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["signature", "path"], false).await?;
+
+        metrics
+            .increment_counter("postgres_sink_instructions_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Writes each record once.
+pub struct PostgresRecordSink<T> {
+    sink: PostgresSink,
+    table: &'static str,
+    primary_key: &'static [&'static str],
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for PostgresRecordSink<T> {
+    type InputType = T;
+
+    async fn process(
+        &mut self,
+        record: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&record) else {
+            return Ok(());
+        };
+
+        let row = Row::new(self.table, Vec::new(), data);
+
+        self.sink.write(row, self.primary_key, false).await?;
+
+        metrics
+            .increment_counter("postgres_sink_records_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn postgres_error(error: tokio_postgres::Error) -> Error {
+    Error::Custom(format!("PostgreSQL error: {}", error))
+}
@@ -0,0 +1,124 @@
+use {
+    carbon_core::row::SqlDatabase,
+    serde_json::Value,
+    std::collections::HashSet,
+    tokio::runtime::Handle,
+    tokio_postgres::{types::ToSql, Client},
+};
+
+/// The PostgreSQL dialect of the `SchemaWriter` writing the rows.
+///
+/// The statements are run on the runtime of `handle`, blocking the calling
+/// thread, so it must be a blocking thread of that runtime.
+pub(crate) struct Postgres<'a> {
+    pub client: &'a Client,
+    pub handle: Handle,
+}
+
+impl SqlDatabase for Postgres<'_> {
+    type Error = tokio_postgres::Error;
+
+    fn column_type(value: &Value) -> &'static str {
+        match value {
+            Value::Bool(_) => "BOOLEAN",
+            Value::Number(number) if number.is_f64() => "DOUBLE PRECISION",
+            Value::Number(_) => "NUMERIC",
+            Value::Array(_) | Value::Object(_) => "JSONB",
+            Value::String(_) | Value::Null => "TEXT",
+        }
+    }
+
+    /// Inserts the row as a JSON object, converted to the types of the
+    /// columns by `json_populate_record`.
+    fn insert(
+        table: &str,
+        columns: Vec<(String, Value)>,
+        primary_key: &[&str],
+        replace: bool,
+    ) -> (String, Vec<Value>) {
+        let names: Vec<String> = columns
+            .iter()
+            .map(|(name, _)| format!("\"{}\"", name))
+            .collect();
+        let updates: Vec<String> = columns
+            .iter()
+            .filter(|(name, _)| !primary_key.contains(&name.as_str()))
+            .map(|(name, _)| format!("\"{0}\" = EXCLUDED.\"{0}\"", name))
+            .collect();
+        let primary_key: Vec<String> = primary_key
+            .iter()
+            .map(|name| format!("\"{}\"", name))
+            .collect();
+
+        let conflict = if replace && !updates.is_empty() {
+            format!("DO UPDATE SET {}", updates.join(", "))
+        } else {
+            "DO NOTHING".to_string()
+        };
+        let sql = format!(
+            "INSERT INTO \"{0}\" ({1}) SELECT {1} FROM json_populate_record(NULL::\"{0}\", $1::json) \
+             ON CONFLICT ({2}) {3}",
+            table,
+            names.join(", "),
+            primary_key.join(", "),
+            conflict
+        );
+
+        (sql, vec![Value::Object(columns.into_iter().collect())])
+    }
+
+    fn table_columns(&self, table: &str) -> Result<HashSet<String>, tokio_postgres::Error> {
+        let rows = self.handle.block_on(self.client.query(
+            "SELECT column_name::text FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = $1",
+            &[&table],
+        ))?;
+
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    fn execute(&self, sql: &str, params: Vec<Value>) -> Result<usize, tokio_postgres::Error> {
+        let params: Vec<&(dyn ToSql + Sync)> = params
+            .iter()
+            .map(|param| param as &(dyn ToSql + Sync))
+            .collect();
+
+        let changed = self.handle.block_on(self.client.execute(sql, &params))?;
+
+        Ok(changed as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    #[test]
+    fn test_insert_upserts_a_json_record() {
+        // Arrange
+        let columns = vec![
+            ("signature".to_string(), json!("sig")),
+            ("path".to_string(), json!("0")),
+            ("amount".to_string(), json!(5)),
+        ];
+
+        // Act
+        let (replace_sql, params) =
+            Postgres::insert("swap", columns.clone(), &["signature", "path"], true);
+        let (ignore_sql, _) = Postgres::insert("swap", columns, &["signature", "path"], false);
+
+        // Assert
+        assert_eq!(
+            replace_sql,
+            "INSERT INTO \"swap\" (\"signature\", \"path\", \"amount\") \
+             SELECT \"signature\", \"path\", \"amount\" \
+             FROM json_populate_record(NULL::\"swap\", $1::json) \
+             ON CONFLICT (\"signature\", \"path\") DO UPDATE SET \"amount\" = EXCLUDED.\"amount\""
+        );
+        assert!(ignore_sql.ends_with("ON CONFLICT (\"signature\", \"path\") DO NOTHING"));
+        assert_eq!(
+            params,
+            vec![json!({ "signature": "sig", "path": "0", "amount": 5 })]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use {
+    crate::{query_with_retries, Encoder},
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType, account_lifecycle::AccountLifecycleEvent,
+        error::CarbonResult, metrics::MetricsCollection, processor::Processor,
+    },
+    redis::aio::ConnectionManager,
+    solana_pubkey::Pubkey,
+    std::{sync::Arc, time::Duration},
+};
+
+/// Stores the latest decoded state of each account in a Redis hash.
+///
+/// Each account is written to the hash `{key_prefix}:{pubkey}` with the
+/// fields `slot`, `lamports`, `owner` and `data`, where `data` is the JSON
+/// produced by the encoder. The fields are written in a single pipeline,
+/// together with the expiry when a TTL is set.
+///
+/// Closed accounts are not decoded, so their hash is not updated anymore:
+/// add the processor returned by `deletions` as an account lifecycle pipe to
+/// delete it.
+pub struct RedisAccountSink<T> {
+    connection: ConnectionManager,
+    key_prefix: String,
+    encoder: Encoder<T>,
+    ttl: Option<Duration>,
+}
+
+impl<T> RedisAccountSink<T> {
+    pub fn new(
+        connection: ConnectionManager,
+        key_prefix: impl Into<String>,
+        encoder: Encoder<T>,
+    ) -> Self {
+        Self {
+            connection,
+            key_prefix: key_prefix.into(),
+            encoder,
+            ttl: None,
+        }
+    }
+
+    /// Expires the hash of an account if it is not updated within `ttl`.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Returns a processor deleting the hash of the accounts closed,
+    /// reassigned or deleted, for `PipelineBuilder::account_lifecycle`.
+    pub fn deletions(&self) -> RedisAccountDeletions {
+        RedisAccountDeletions {
+            connection: self.connection.clone(),
+            key_prefix: self.key_prefix.clone(),
+        }
+    }
+}
+
+/// Deletes the hash of an account from Redis on each of its lifecycle events.
+pub struct RedisAccountDeletions {
+    connection: ConnectionManager,
+    key_prefix: String,
+}
+
+impl RedisAccountDeletions {
+    /// Deletes the hash of `pubkey`, if any.
+    pub async fn delete(&mut self, pubkey: &Pubkey) -> CarbonResult<()> {
+        let mut pipeline = redis::pipe();
+        pipeline
+            .del(format!("{}:{}", self.key_prefix, pubkey))
+            .ignore();
+
+        query_with_retries(&mut self.connection, &pipeline).await
+    }
+}
+
+#[async_trait]
+impl Processor for RedisAccountDeletions {
+    type InputType = AccountLifecycleEvent;
+
+    async fn process(
+        &mut self,
+        event: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if let Err(error) = self.delete(&event.pubkey()).await {
+            metrics.increment_counter("redis_sink_errors", 1).await?;
+            return Err(error);
+        }
+
+        metrics
+            .increment_counter("redis_sink_accounts_deleted", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for RedisAccountSink<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let key = format!("{}:{}", self.key_prefix, metadata.pubkey);
+
+        let mut pipeline = redis::pipe();
+        pipeline
+            .hset_multiple(
+                &key,
+                &[
+                    ("slot", metadata.slot.to_string()),
+                    ("lamports", decoded_account.lamports.to_string()),
+                    ("owner", decoded_account.owner.to_string()),
+                    ("data", data.to_string()),
+                ],
+            )
+            .ignore();
+
+        if let Some(ttl) = self.ttl {
+            pipeline.expire(&key, ttl.as_secs() as i64).ignore();
+        }
+
+        if let Err(error) = query_with_retries(&mut self.connection, &pipeline).await {
+            metrics.increment_counter("redis_sink_errors", 1).await?;
+            return Err(error);
+        }
+
+        metrics
+            .increment_counter("redis_sink_accounts_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    crate::redis_error,
+    async_trait::async_trait,
+    carbon_core::{error::CarbonResult, kv_store::KeyValueStore},
+    redis::aio::ConnectionManager,
+    std::time::Duration,
+};
+
+/// A `KeyValueStore` keeping its entries in Redis, under `{key_prefix}:{key}`.
+///
+/// TTLs are set with the `PX` option of `SET`, so Redis expires the entries
+/// itself, and `set_if_absent` is a single `SET NX`.
+#[derive(Clone)]
+pub struct RedisKeyValueStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+}
+
+impl RedisKeyValueStore {
+    pub fn new(connection: ConnectionManager, key_prefix: impl Into<String>) -> Self {
+        Self {
+            connection,
+            key_prefix: key_prefix.into(),
+        }
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}:{}", self.key_prefix, key)
+    }
+
+    fn set_command(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> redis::Cmd {
+        let mut command = redis::cmd("SET");
+        command.arg(self.key(key)).arg(value);
+        if let Some(ttl) = ttl {
+            command.arg("PX").arg(ttl.as_millis().max(1) as u64);
+        }
+        command
+    }
+}
+
+#[async_trait]
+impl KeyValueStore for RedisKeyValueStore {
+    async fn get(&self, key: &str) -> CarbonResult<Option<Vec<u8>>> {
+        let mut connection = self.connection.clone();
+
+        redis::cmd("GET")
+            .arg(self.key(key))
+            .query_async(&mut connection)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> CarbonResult<()> {
+        let mut connection = self.connection.clone();
+
+        self.set_command(key, value, ttl)
+            .query_async(&mut connection)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn set_if_absent(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> CarbonResult<bool> {
+        let mut connection = self.connection.clone();
+
+        // `SET NX` replies `OK` when the key was set, and nil otherwise.
+        let reply: Option<String> = self
+            .set_command(key, value, ttl)
+            .arg("NX")
+            .query_async(&mut connection)
+            .await
+            .map_err(redis_error)?;
+
+        Ok(reply.is_some())
+    }
+
+    async fn delete(&self, key: &str) -> CarbonResult<()> {
+        let mut connection = self.connection.clone();
+
+        redis::cmd("DEL")
+            .arg(self.key(key))
+            .query_async(&mut connection)
+            .await
+            .map_err(redis_error)
+    }
+}
@@ -0,0 +1,101 @@
+//! Writes decoded data from a Carbon pipeline to Redis.
+//!
+//! - [`RedisAccountSink`] keeps the latest decoded state of each account in a
+//!   Redis hash, for fast reads by bots and services. Its
+//!   [`RedisAccountDeletions`] deletes the hash of closed accounts.
+//! - [`RedisEventPublisher`] publishes decoded instructions as JSON events on a
+//!   pub/sub channel.
+//! - [`RedisKeyValueStore`] implements `KeyValueStore`, sharing checkpoints,
+//!   deduplication keys and mint decimals between pipeline replicas.
+//!
+//! The sinks are `Processor`s sharing a [`ConnectionManager`], which reconnects
+//! automatically when the connection drops. Failed commands are retried with a
+//! short backoff before the error is returned to the pipeline.
+//!
+//! Decoder types rarely implement `Serialize`, so both sinks take an
+//! [`Encoder`] converting the decoded data into JSON. Use [`json`] when the
+//! type implements `Serialize`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let connection = carbon_redis_sink::connect("redis://127.0.0.1/").await?;
+//! let accounts =
+//!     RedisAccountSink::new(connection.clone(), "pumpfun", Arc::new(encode_pump_account));
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .account_lifecycle(accounts.deletions())
+//!     .account(PumpfunDecoder, accounts)
+//!     .instruction(
+//!         PumpfunDecoder,
+//!         RedisEventPublisher::new(connection, "pumpfun:events", carbon_redis_sink::json()),
+//!     )
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    carbon_core::error::{CarbonResult, Error},
+    redis::{aio::ConnectionManager, Pipeline, RedisError},
+    serde::Serialize,
+    std::{sync::Arc, time::Duration},
+};
+
+pub use {
+    account::{RedisAccountDeletions, RedisAccountSink},
+    kv_store::RedisKeyValueStore,
+    publisher::RedisEventPublisher,
+};
+
+mod account;
+mod kv_store;
+mod publisher;
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Converts decoded data into a JSON value. Returning `None` skips the update.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+/// Opens a [`ConnectionManager`] to the Redis server at `url`.
+pub async fn connect(url: &str) -> CarbonResult<ConnectionManager> {
+    let client = redis::Client::open(url).map_err(redis_error)?;
+
+    ConnectionManager::new(client).await.map_err(redis_error)
+}
+
+/// Sends the pipeline, retrying while the connection manager reconnects.
+async fn query_with_retries(
+    connection: &mut ConnectionManager,
+    pipeline: &Pipeline,
+) -> CarbonResult<()> {
+    let mut attempt = 0;
+
+    loop {
+        match pipeline.query_async::<()>(connection).await {
+            Ok(()) => return Ok(()),
+            Err(error) if attempt < MAX_RETRIES => {
+                attempt += 1;
+                log::warn!(
+                    "Redis command failed (attempt {}/{}): {}",
+                    attempt,
+                    MAX_RETRIES,
+                    error
+                );
+                tokio::time::sleep(RETRY_DELAY * attempt).await;
+            }
+            Err(error) => return Err(redis_error(error)),
+        }
+    }
+}
+
+fn redis_error(error: RedisError) -> Error {
+    Error::Custom(format!("Redis error: {}", error))
+}
@@ -0,0 +1,86 @@
+use {
+    crate::{query_with_retries, Encoder},
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult,
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+        serializer::{JsonSerializer, SinkSerializer},
+    },
+    redis::aio::ConnectionManager,
+    std::sync::Arc,
+};
+
+/// Publishes decoded instructions on a Redis pub/sub channel.
+///
+/// Each event is an object with the `signature`, `slot`, `program_id` and
+/// `path` of the instruction, the path being its position in the transaction,
+/// and the `data` produced by the encoder. Events are serialized as JSON unless
+/// another serializer is set.
+pub struct RedisEventPublisher<T> {
+    connection: ConnectionManager,
+    channel: String,
+    encoder: Encoder<T>,
+    serializer: Box<dyn SinkSerializer>,
+}
+
+impl<T> RedisEventPublisher<T> {
+    pub fn new(
+        connection: ConnectionManager,
+        channel: impl Into<String>,
+        encoder: Encoder<T>,
+    ) -> Self {
+        Self {
+            connection,
+            channel: channel.into(),
+            encoder,
+            serializer: Box::new(JsonSerializer),
+        }
+    }
+
+    /// Sets the format of the published events. Defaults to JSON.
+    pub fn with_serializer(mut self, serializer: impl SinkSerializer + 'static) -> Self {
+        self.serializer = Box::new(serializer);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for RedisEventPublisher<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let event = serde_json::json!({
+            "signature": metadata.transaction_metadata.signature.to_string(),
+            "slot": metadata.transaction_metadata.slot,
+            "program_id": decoded_instruction.program_id.to_string(),
+            "path": metadata.absolute_path,
+            "data": data,
+        });
+
+        let payload = self.serializer.serialize(&event)?;
+
+        let mut pipeline = redis::pipe();
+        pipeline.publish(&self.channel, payload).ignore();
+
+        if let Err(error) = query_with_retries(&mut self.connection, &pipeline).await {
+            metrics.increment_counter("redis_sink_errors", 1).await?;
+            return Err(error);
+        }
+
+        metrics
+            .increment_counter("redis_sink_events_published", 1)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+//! Writes decoded accounts and instructions from a Carbon pipeline to a local
+//! SQLite database, without any infrastructure to set up.
+//!
+//! The schema is generated from the decoded data. Each variant of a decoded
+//! type gets its own table, named after the variant in snake case, with one
+//! column per field. New fields, for example after a program upgrade, are
+//! added as new columns.
+//!
+//! - Account tables are keyed by `pubkey` and hold the latest state of each
+//!   account, with its `slot`, `lamports` and `owner`. Closed accounts are
+//!   deleted by the processor returned by `SqliteSink::account_deletions`.
+//! - Instruction tables are keyed by `signature` and `path`, the position of
+//!   the instruction in its transaction, so reprocessing a transaction does
+//!   not duplicate rows.
+//!
+//! Nested values are stored as JSON text, and integers that do not fit in an
+//! `i64` are stored as text.
+//!
+//! With `SqliteSink::intern_pubkeys`, the `pubkey`, `owner` and `program_id`
+//! columns hold integer ids instead of base58 text, resolved through the
+//! `pubkeys` table, which shrinks the database and speeds up joins.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let sink = SqliteSink::open("pumpfun.db")?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, sink.instructions(carbon_sqlite_sink::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType,
+        account_lifecycle::AccountLifecycleEvent,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        intern::{PubkeyIdStore, PubkeyInterner},
+        metrics::MetricsCollection,
+        processor::Processor,
+        row::{Row, SchemaWriter},
+    },
+    rusqlite::Connection,
+    schema::Sqlite,
+    serde::Serialize,
+    serde_json::{json, Value},
+    solana_pubkey::Pubkey,
+    std::{
+        path::Path,
+        sync::{Arc, Mutex},
+    },
+};
+
+mod schema;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the update.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+struct Database {
+    connection: Connection,
+    schema: SchemaWriter,
+}
+
+/// A handle to a SQLite database, shared by the account and instruction
+/// processors writing to it.
+#[derive(Clone)]
+pub struct SqliteSink {
+    database: Arc<Mutex<Database>>,
+    interner: Option<PubkeyInterner>,
+}
+
+impl SqliteSink {
+    /// Opens or creates the database file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> CarbonResult<Self> {
+        let connection = Connection::open(path).map_err(sqlite_error)?;
+        connection
+            .pragma_update(None, "journal_mode", "WAL")
+            .map_err(sqlite_error)?;
+
+        Ok(Self::from_connection(connection))
+    }
+
+    /// Opens a database held in memory, discarded when the sink is dropped.
+    pub fn open_in_memory() -> CarbonResult<Self> {
+        Ok(Self::from_connection(
+            Connection::open_in_memory().map_err(sqlite_error)?,
+        ))
+    }
+
+    fn from_connection(connection: Connection) -> Self {
+        Self {
+            database: Arc::new(Mutex::new(Database {
+                connection,
+                schema: SchemaWriter::default(),
+            })),
+            interner: None,
+        }
+    }
+
+    /// Writes pubkeys as integer ids, mapped to their pubkeys in the
+    /// `pubkeys` table.
+    ///
+    /// Ids already in the table are reused, so a database must either always
+    /// or never be written with interned pubkeys.
+    pub async fn intern_pubkeys(mut self) -> CarbonResult<Self> {
+        let store = SqlitePubkeyIdStore {
+            database: self.database.clone(),
+        };
+        self.interner = Some(PubkeyInterner::with_store(Arc::new(store)).await?);
+        Ok(self)
+    }
+
+    /// Returns the interner of the sink, if pubkeys are interned.
+    pub fn interner(&self) -> Option<&PubkeyInterner> {
+        self.interner.as_ref()
+    }
+
+    async fn pubkey_value(&self, pubkey: &Pubkey) -> CarbonResult<Value> {
+        match &self.interner {
+            Some(interner) => Ok(json!(interner.intern(pubkey).await?)),
+            None => Ok(json!(pubkey.to_string())),
+        }
+    }
+
+    /// Returns a processor writing decoded accounts to this database.
+    pub fn accounts<T>(&self, encoder: Encoder<T>) -> SqliteAccountSink<T> {
+        SqliteAccountSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor writing decoded instructions to this database.
+    pub fn instructions<T>(&self, encoder: Encoder<T>) -> SqliteInstructionSink<T> {
+        SqliteInstructionSink {
+            sink: self.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor deleting the rows of the accounts closed,
+    /// reassigned or deleted, for `PipelineBuilder::account_lifecycle`.
+    pub fn account_deletions(&self) -> SqliteAccountDeletions {
+        SqliteAccountDeletions { sink: self.clone() }
+    }
+
+    /// Deletes the rows of `pubkey` from every account table, returning the
+    /// number of rows deleted.
+    pub async fn delete_account(&self, pubkey: &Pubkey) -> CarbonResult<usize> {
+        let pubkey = self.pubkey_value(pubkey).await?;
+        let database = self.database.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let database = database
+                .lock()
+                .map_err(|_| Error::Custom("SQLite sink lock poisoned".to_string()))?;
+
+            schema::delete_account(&database.connection, pubkey).map_err(sqlite_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("SQLite delete task failed: {}", error)))?
+    }
+
+    async fn write(
+        &self,
+        row: Row,
+        primary_key: &'static [&'static str],
+        replace: bool,
+    ) -> CarbonResult<()> {
+        let database = self.database.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut database = database
+                .lock()
+                .map_err(|_| Error::Custom("SQLite sink lock poisoned".to_string()))?;
+            let Database { connection, schema } = &mut *database;
+
+            schema
+                .write(&Sqlite(connection), row, primary_key, replace)
+                .map_err(sqlite_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("SQLite write task failed: {}", error)))?
+    }
+}
+
+/// Writes the latest state of each decoded account.
+pub struct SqliteAccountSink<T> {
+    sink: SqliteSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for SqliteAccountSink<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let row = Row::new(
+            "accounts",
+            vec![
+                ("pubkey", self.sink.pubkey_value(&metadata.pubkey).await?),
+                ("slot", json!(metadata.slot)),
+                ("lamports", json!(decoded_account.lamports)),
+                (
+                    "owner",
+                    self.sink.pubkey_value(&decoded_account.owner).await?,
+                ),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["pubkey"], true).await?;
+
+        metrics
+            .increment_counter("sqlite_sink_accounts_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Deletes the rows of an account on each of its lifecycle events.
+pub struct SqliteAccountDeletions {
+    sink: SqliteSink,
+}
+
+#[async_trait]
+impl Processor for SqliteAccountDeletions {
+    type InputType = AccountLifecycleEvent;
+
+    async fn process(
+        &mut self,
+        event: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let deleted = self.sink.delete_account(&event.pubkey()).await?;
+
+        metrics
+            .increment_counter("sqlite_sink_accounts_deleted", deleted as u64)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Writes each decoded instruction once.
+pub struct SqliteInstructionSink<T> {
+    sink: SqliteSink,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for SqliteInstructionSink<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let path = metadata
+            .absolute_path
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(":");
+
+        let row = Row::new(
+            "instructions",
+            vec![
+                (
+                    "signature",
+                    json!(metadata.transaction_metadata.signature.to_string()),
+                ),
+                ("path", json!(path)),
+                ("slot", json!(metadata.transaction_metadata.slot)),
+                (
+                    "program_id",
+                    self.sink
+                        .pubkey_value(&decoded_instruction.program_id)
+                        .await?,
+                ),
+            ],
+            data,
+        );
+
+        self.sink.write(row, &["signature", "path"], false).await?;
+
+        metrics
+            .increment_counter("sqlite_sink_instructions_written", 1)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Persists the ids of interned pubkeys in the `pubkeys` table.
+struct SqlitePubkeyIdStore {
+    database: Arc<Mutex<Database>>,
+}
+
+impl SqlitePubkeyIdStore {
+    async fn with_connection<T: Send + 'static>(
+        &self,
+        run: impl FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+    ) -> CarbonResult<T> {
+        let database = self.database.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let database = database
+                .lock()
+                .map_err(|_| Error::Custom("SQLite sink lock poisoned".to_string()))?;
+            database.connection.execute(
+                "CREATE TABLE IF NOT EXISTS pubkeys (id INTEGER PRIMARY KEY, pubkey TEXT NOT NULL UNIQUE)",
+                [],
+            ).map_err(sqlite_error)?;
+
+            run(&database.connection).map_err(sqlite_error)
+        })
+        .await
+        .map_err(|error| Error::Custom(format!("SQLite write task failed: {}", error)))?
+    }
+}
+
+#[async_trait]
+impl PubkeyIdStore for SqlitePubkeyIdStore {
+    async fn load(&self) -> CarbonResult<Vec<(Pubkey, u64)>> {
+        let rows = self
+            .with_connection(|connection| {
+                let mut statement = connection.prepare("SELECT pubkey, id FROM pubkeys")?;
+                let rows = statement
+                    .query_map([], |row| {
+                        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+                    })?
+                    .collect::<rusqlite::Result<Vec<_>>>()?;
+                Ok(rows)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|(pubkey, id)| {
+                let pubkey = pubkey.parse::<Pubkey>().map_err(|error| {
+                    Error::Custom(format!(
+                        "Invalid pubkey {:?} in pubkeys table: {}",
+                        pubkey, error
+                    ))
+                })?;
+                Ok((pubkey, id as u64))
+            })
+            .collect()
+    }
+
+    async fn store(&self, pubkey: Pubkey, id: u64) -> CarbonResult<()> {
+        self.with_connection(move |connection| {
+            connection.execute(
+                "INSERT INTO pubkeys (id, pubkey) VALUES (?1, ?2)",
+                rusqlite::params![id as i64, pubkey.to_string()],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn sqlite_error(error: rusqlite::Error) -> Error {
+    Error::Custom(format!("SQLite error: {}", error))
+}
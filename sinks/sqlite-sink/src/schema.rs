@@ -0,0 +1,181 @@
+use {
+    carbon_core::row::SqlDatabase,
+    rusqlite::{params_from_iter, types::Value as SqlValue, Connection},
+    serde_json::Value,
+    std::collections::HashSet,
+};
+
+/// The SQLite dialect of the `SchemaWriter` writing the rows.
+pub(crate) struct Sqlite<'a>(pub &'a Connection);
+
+impl SqlDatabase for Sqlite<'_> {
+    type Error = rusqlite::Error;
+
+    fn column_type(value: &Value) -> &'static str {
+        match value {
+            Value::Bool(_) => "INTEGER",
+            Value::Number(number) if number.is_i64() => "INTEGER",
+            Value::Number(number) if number.is_f64() => "REAL",
+            Value::Null => "",
+            _ => "TEXT",
+        }
+    }
+
+    fn table_columns(&self, table: &str) -> rusqlite::Result<HashSet<String>> {
+        let mut statement = self
+            .0
+            .prepare(&format!("PRAGMA table_info(\"{}\")", table))?;
+        let columns = statement
+            .query_map([], |column| column.get::<_, String>(1))?
+            .collect::<rusqlite::Result<HashSet<String>>>()?;
+
+        Ok(columns)
+    }
+
+    fn execute(&self, sql: &str, params: Vec<Value>) -> rusqlite::Result<usize> {
+        self.0
+            .execute(sql, params_from_iter(params.into_iter().map(sql_value)))
+    }
+}
+
+/// Deletes the rows of `pubkey` from the account tables, the tables with
+/// `pubkey`, `lamports` and `owner` columns. Returns the number of rows
+/// deleted.
+pub(crate) fn delete_account(connection: &Connection, pubkey: Value) -> rusqlite::Result<usize> {
+    let mut statement = connection.prepare(
+        "SELECT m.name FROM sqlite_master AS m WHERE m.type = 'table' AND \
+         (SELECT COUNT(*) FROM pragma_table_info(m.name) AS c \
+         WHERE c.name IN ('pubkey', 'lamports', 'owner')) = 3",
+    )?;
+    let tables = statement
+        .query_map([], |table| table.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+
+    let pubkey = sql_value(pubkey);
+    let mut deleted = 0;
+    for table in tables {
+        deleted += connection.execute(
+            &format!("DELETE FROM \"{}\" WHERE pubkey = ?1", table),
+            [&pubkey],
+        )?;
+    }
+
+    Ok(deleted)
+}
+
+/// Converts a JSON value into a SQLite value. Integers beyond `i64` are kept
+/// as text to avoid losing precision.
+fn sql_value(value: Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(value) => SqlValue::Integer(value as i64),
+        Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+            (Some(integer), _) => SqlValue::Integer(integer),
+            (None, Some(float)) if !number.is_u64() => SqlValue::Real(float),
+            _ => SqlValue::Text(number.to_string()),
+        },
+        Value::String(value) => SqlValue::Text(value),
+        value => SqlValue::Text(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::row::{Row, SchemaWriter},
+        serde_json::json,
+    };
+
+    #[test]
+    fn test_write_adds_new_columns() {
+        let connection = Connection::open_in_memory().unwrap();
+        let mut writer = SchemaWriter::default();
+
+        writer
+            .write(
+                &Sqlite(&connection),
+                Row::new(
+                    "accounts",
+                    vec![("pubkey", json!("a"))],
+                    json!({ "Pool": { "fee": 1 } }),
+                ),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+        writer
+            .write(
+                &Sqlite(&connection),
+                Row::new(
+                    "accounts",
+                    vec![("pubkey", json!("a"))],
+                    json!({ "Pool": { "fee": 2, "paused": true } }),
+                ),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+
+        let (fee, paused): (i64, i64) = connection
+            .query_row(
+                "SELECT fee, paused FROM pool WHERE pubkey = 'a'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        let count: i64 = connection
+            .query_row("SELECT COUNT(*) FROM pool", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!((fee, paused), (2, 1));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_delete_account_only_deletes_account_rows() {
+        let connection = Connection::open_in_memory().unwrap();
+        let mut writer = SchemaWriter::default();
+
+        writer
+            .write(
+                &Sqlite(&connection),
+                Row::new(
+                    "accounts",
+                    vec![
+                        ("pubkey", json!("a")),
+                        ("lamports", json!(0)),
+                        ("owner", json!("program")),
+                    ],
+                    json!({ "Pool": { "fee": 1 } }),
+                ),
+                &["pubkey"],
+                true,
+            )
+            .unwrap();
+        writer
+            .write(
+                &Sqlite(&connection),
+                Row::new(
+                    "instructions",
+                    vec![("signature", json!("sig")), ("path", json!("0"))],
+                    json!({ "Swap": { "pubkey": "a" } }),
+                ),
+                &["signature", "path"],
+                false,
+            )
+            .unwrap();
+
+        let deleted = delete_account(&connection, json!("a")).unwrap();
+
+        let pools: i64 = connection
+            .query_row("SELECT COUNT(*) FROM pool", [], |row| row.get(0))
+            .unwrap();
+        let swaps: i64 = connection
+            .query_row("SELECT COUNT(*) FROM swap", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert_eq!((pools, swaps), (0, 1));
+    }
+}
@@ -0,0 +1,88 @@
+use {serde::Deserialize, std::collections::HashSet};
+
+/// A decoded event ready to be broadcast, with the fields clients filter on.
+#[derive(Debug, Clone)]
+pub(crate) struct Event {
+    pub program_id: String,
+    pub accounts: Vec<String>,
+    pub event_type: Option<String>,
+    pub payload: String,
+}
+
+/// The filter a client sends to subscribe, such as
+/// `{"programs": ["6EF8..."], "event_types": ["Buy"]}`.
+///
+/// An empty or missing list matches everything, so `{}` subscribes to all
+/// events. An event must match every non-empty list.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Filter {
+    #[serde(default)]
+    pub programs: HashSet<String>,
+    #[serde(default)]
+    pub accounts: HashSet<String>,
+    #[serde(default)]
+    pub event_types: HashSet<String>,
+}
+
+impl Filter {
+    pub(crate) fn matches(&self, event: &Event) -> bool {
+        (self.programs.is_empty() || self.programs.contains(&event.program_id))
+            && (self.accounts.is_empty()
+                || event
+                    .accounts
+                    .iter()
+                    .any(|account| self.accounts.contains(account)))
+            && (self.event_types.is_empty()
+                || event
+                    .event_type
+                    .as_ref()
+                    .is_some_and(|event_type| self.event_types.contains(event_type)))
+    }
+}
+
+/// Returns the tag of an externally tagged enum value, such as `Buy` for
+/// `{"Buy": {...}}`.
+pub(crate) fn event_type(data: &serde_json::Value) -> Option<String> {
+    match data {
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().cloned(),
+        serde_json::Value::String(tag) => Some(tag.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> Event {
+        Event {
+            program_id: "program".to_string(),
+            accounts: vec!["mint".to_string(), "user".to_string()],
+            event_type: event_type(&serde_json::json!({ "Buy": { "amount": 1 } })),
+            payload: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter: Filter = serde_json::from_str("{}").unwrap();
+
+        assert!(filter.matches(&event()));
+    }
+
+    #[test]
+    fn test_filter_requires_every_list_to_match() {
+        let filter: Filter = serde_json::from_str(
+            r#"{"programs": ["program"], "accounts": ["user"], "event_types": ["Buy"]}"#,
+        )
+        .unwrap();
+        let other_type: Filter =
+            serde_json::from_str(r#"{"programs": ["program"], "event_types": ["Sell"]}"#).unwrap();
+        let other_account: Filter = serde_json::from_str(r#"{"accounts": ["pool"]}"#).unwrap();
+
+        assert!(filter.matches(&event()));
+        assert!(!other_type.matches(&event()));
+        assert!(!other_account.matches(&event()));
+    }
+}
@@ -0,0 +1,301 @@
+//! Serves decoded events from a Carbon pipeline over WebSocket, so front-ends
+//! can consume a pipeline directly.
+//!
+//! [`WsServer`] accepts WebSocket clients and broadcasts the events produced
+//! by its processors, [`WsInstructionBroadcaster`] and
+//! [`WsAccountBroadcaster`], to every client whose filter matches.
+//!
+//! # Subscribing
+//!
+//! Clients receive nothing until they send a JSON filter:
+//!
+//! ```json
+//! {"programs": ["6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"], "accounts": [], "event_types": ["Buy", "Sell"]}
+//! ```
+//!
+//! - `programs`: Program IDs of instructions, or owners of accounts.
+//! - `accounts`: Accounts referenced by instructions, or account pubkeys.
+//! - `event_types`: Variants of the decoded data, such as `Buy` for
+//!   `PumpfunInstruction::Buy`.
+//!
+//! Empty or missing lists match everything, so `{}` subscribes to all
+//! events. Sending a new filter replaces the previous one. Invalid filters are
+//! answered with `{"error": "..."}`.
+//!
+//! Slow clients skip the events they could not keep up with, rather than
+//! slowing the pipeline down.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let server = WsServer::bind("0.0.0.0:8900").await?;
+//!
+//! Pipeline::builder()
+//!     .datasource(my_datasource)
+//!     .instruction(PumpfunDecoder, server.instructions(carbon_ws_server::json()))
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! ```
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        account::AccountProcessorInputType,
+        error::{CarbonResult, Error},
+        instruction::InstructionProcessorInputType,
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    filter::{event_type, Event, Filter},
+    futures_util::{SinkExt, StreamExt},
+    serde::Serialize,
+    std::{net::SocketAddr, sync::Arc},
+    tokio::{
+        net::{TcpListener, TcpStream, ToSocketAddrs},
+        sync::broadcast::{self, error::RecvError},
+    },
+    tokio_tungstenite::tungstenite::Message,
+};
+
+mod filter;
+
+const EVENT_BUFFER_SIZE: usize = 1024;
+
+/// Converts decoded data into a JSON value. Returning `None` skips the event.
+pub type Encoder<T> = Arc<dyn Fn(&T) -> Option<serde_json::Value> + Send + Sync>;
+
+/// Returns an [`Encoder`] for types implementing `Serialize`.
+pub fn json<T: Serialize>() -> Encoder<T> {
+    Arc::new(|data: &T| serde_json::to_value(data).ok())
+}
+
+/// A WebSocket server broadcasting decoded events to subscribed clients.
+#[derive(Clone)]
+pub struct WsServer {
+    sender: broadcast::Sender<Arc<Event>>,
+    local_addr: SocketAddr,
+}
+
+impl WsServer {
+    /// Binds the server to `addr` and starts accepting clients in the
+    /// background.
+    pub async fn bind(addr: impl ToSocketAddrs) -> CarbonResult<Self> {
+        let listener = TcpListener::bind(addr).await.map_err(|error| {
+            Error::Custom(format!("Failed to bind WebSocket server: {}", error))
+        })?;
+        let local_addr = listener.local_addr().map_err(|error| {
+            Error::Custom(format!("Failed to bind WebSocket server: {}", error))
+        })?;
+        let (sender, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+
+        let accept_sender = sender.clone();
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(serve_client(stream, peer, accept_sender.subscribe()));
+                    }
+                    Err(error) => log::error!("Failed to accept WebSocket client: {}", error),
+                }
+            }
+        });
+
+        log::info!("WebSocket server listening on {}", local_addr);
+
+        Ok(Self { sender, local_addr })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Returns a processor broadcasting decoded instructions.
+    pub fn instructions<T>(&self, encoder: Encoder<T>) -> WsInstructionBroadcaster<T> {
+        WsInstructionBroadcaster {
+            sender: self.sender.clone(),
+            encoder,
+        }
+    }
+
+    /// Returns a processor broadcasting decoded accounts.
+    pub fn accounts<T>(&self, encoder: Encoder<T>) -> WsAccountBroadcaster<T> {
+        WsAccountBroadcaster {
+            sender: self.sender.clone(),
+            encoder,
+        }
+    }
+}
+
+/// Broadcasts decoded instructions as JSON events with the `signature`,
+/// `slot`, `program_id`, `path` and `accounts` of the instruction, and the
+/// `data` produced by the encoder.
+pub struct WsInstructionBroadcaster<T> {
+    sender: broadcast::Sender<Arc<Event>>,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for WsInstructionBroadcaster<T> {
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_instruction, _nested_instructions): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if self.sender.receiver_count() == 0 {
+            return Ok(());
+        }
+
+        let Some(data) = (self.encoder)(&decoded_instruction.data) else {
+            return Ok(());
+        };
+
+        let program_id = decoded_instruction.program_id.to_string();
+        let accounts: Vec<String> = decoded_instruction
+            .accounts
+            .iter()
+            .map(|account| account.pubkey.to_string())
+            .collect();
+
+        let payload = serde_json::json!({
+            "signature": metadata.transaction_metadata.signature.to_string(),
+            "slot": metadata.transaction_metadata.slot,
+            "program_id": program_id,
+            "path": metadata.absolute_path,
+            "accounts": accounts,
+            "data": data,
+        });
+
+        broadcast(
+            &self.sender,
+            Event {
+                program_id,
+                accounts,
+                event_type: event_type(&data),
+                payload: payload.to_string(),
+            },
+            &metrics,
+        )
+        .await
+    }
+}
+
+/// Broadcasts decoded accounts as JSON events with the `pubkey`, `slot`,
+/// `lamports` and `owner` of the account, and the `data` produced by the
+/// encoder.
+pub struct WsAccountBroadcaster<T> {
+    sender: broadcast::Sender<Arc<Event>>,
+    encoder: Encoder<T>,
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Processor for WsAccountBroadcaster<T> {
+    type InputType = AccountProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, decoded_account): Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if self.sender.receiver_count() == 0 {
+            return Ok(());
+        }
+
+        let Some(data) = (self.encoder)(&decoded_account.data) else {
+            return Ok(());
+        };
+
+        let pubkey = metadata.pubkey.to_string();
+        let owner = decoded_account.owner.to_string();
+
+        let payload = serde_json::json!({
+            "pubkey": pubkey,
+            "slot": metadata.slot,
+            "lamports": decoded_account.lamports,
+            "owner": owner,
+            "data": data,
+        });
+
+        broadcast(
+            &self.sender,
+            Event {
+                program_id: owner,
+                accounts: vec![pubkey],
+                event_type: event_type(&data),
+                payload: payload.to_string(),
+            },
+            &metrics,
+        )
+        .await
+    }
+}
+
+async fn broadcast(
+    sender: &broadcast::Sender<Arc<Event>>,
+    event: Event,
+    metrics: &MetricsCollection,
+) -> CarbonResult<()> {
+    // Sending only fails when every client disconnected in the meantime.
+    if sender.send(Arc::new(event)).is_ok() {
+        metrics
+            .increment_counter("ws_server_events_broadcast", 1)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn serve_client(
+    stream: TcpStream,
+    peer: SocketAddr,
+    mut events: broadcast::Receiver<Arc<Event>>,
+) {
+    let websocket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(websocket) => websocket,
+        Err(error) => {
+            log::warn!("WebSocket handshake with {} failed: {}", peer, error);
+            return;
+        }
+    };
+    let (mut outgoing, mut incoming) = websocket.split();
+    let mut filter: Option<Filter> = None;
+
+    log::debug!("WebSocket client {} connected", peer);
+
+    loop {
+        tokio::select! {
+            message = incoming.next() => match message {
+                Some(Ok(Message::Text(text))) => match serde_json::from_str::<Filter>(text.as_str()) {
+                    Ok(new_filter) => filter = Some(new_filter),
+                    Err(error) => {
+                        let reply = serde_json::json!({ "error": format!("Invalid filter: {}", error) });
+                        if outgoing.send(Message::text(reply.to_string())).await.is_err() {
+                            break;
+                        }
+                    }
+                },
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+            event = events.recv() => match event {
+                Ok(event) => {
+                    if !filter.as_ref().is_some_and(|filter| filter.matches(&event)) {
+                        continue;
+                    }
+                    if outgoing.send(Message::text(event.payload.clone())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    log::warn!("WebSocket client {} skipped {} events", peer, skipped);
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+
+    log::debug!("WebSocket client {} disconnected", peer);
+}